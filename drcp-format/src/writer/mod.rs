@@ -51,7 +51,7 @@ use crate::steps::StepId;
 #[derive(Debug)]
 pub struct ProofWriter<W: Write, Literals> {
     /// The writer to the underlying sink.
-    writer: BufWriter<W>,
+    writer: CountingWriter<BufWriter<W>>,
     /// The format in which to log the proof.
     format: Format,
     /// A container for all the literals which are seen in the proof. Unseen literals need not be
@@ -61,6 +61,8 @@ pub struct ProofWriter<W: Write, Literals> {
     next_step_id: StepId,
     /// The number of logged inferences.
     number_of_inferences: usize,
+    /// The number of logged nogoods.
+    number_of_nogoods: usize,
 }
 
 impl<W: Write, Literals> ProofWriter<W, Literals> {
@@ -69,10 +71,14 @@ impl<W: Write, Literals> ProofWriter<W, Literals> {
     pub fn new(format: Format, writer: W, encountered_literals: Literals) -> Self {
         Self {
             format,
-            writer: BufWriter::new(writer),
+            writer: CountingWriter {
+                inner: BufWriter::new(writer),
+                bytes_written: 0,
+            },
             encountered_literals,
             next_step_id: NonZeroU64::new(1).unwrap(),
             number_of_inferences: 0,
+            number_of_nogoods: 0,
         }
     }
 }
@@ -96,6 +102,8 @@ where
         nogood: impl IntoIterator<Item = Literals::Literal>,
         propagation_hints: Option<impl IntoIterator<Item = StepId>>,
     ) -> std::io::Result<StepId> {
+        self.number_of_nogoods += 1;
+
         let id = self.next_step_id();
 
         let nogood = Nogood {
@@ -191,6 +199,36 @@ where
     pub fn get_number_of_inferences(&self) -> usize {
         self.number_of_inferences
     }
+
+    /// Get the number of logged nogoods.
+    pub fn get_number_of_nogoods(&self) -> usize {
+        self.number_of_nogoods
+    }
+
+    /// Get the number of bytes of the proof which have been written so far. This includes the
+    /// bytes which are still buffered and have not yet reached the underlying sink.
+    pub fn get_number_of_bytes_written(&self) -> u64 {
+        self.writer.bytes_written
+    }
+}
+
+/// Wraps a [`Write`] and keeps track of the number of bytes which are written to it.
+#[derive(Debug)]
+struct CountingWriter<W> {
+    inner: W,
+    bytes_written: u64,
+}
+
+impl<W: Write> Write for CountingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let num_bytes = self.inner.write(buf)?;
+        self.bytes_written += num_bytes as u64;
+        Ok(num_bytes)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
 }
 
 trait WritableProofStep: Sized {
@@ -385,6 +423,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn counts_steps_and_bytes_before_flushing() {
+        let mut writer = ProofWriter::new(Format::Text, Vec::new(), std::convert::identity);
+
+        let _ = writer
+            .log_inference(None, None, [lit(2), lit(-3)], Some(lit(1)))
+            .unwrap();
+        let _ = writer
+            .log_nogood([lit(2), lit(-3)], None::<[StepId; 0]>)
+            .unwrap();
+
+        assert_eq!(writer.get_number_of_inferences(), 1);
+        assert_eq!(writer.get_number_of_nogoods(), 1);
+        assert_eq!(
+            writer.get_number_of_bytes_written(),
+            ("i 1 2 -3 0 1\n".len() + "n 2 2 -3\n".len()) as u64
+        );
+    }
+
     fn lit(num: i32) -> NonZero<i32> {
         NonZero::new(num).unwrap()
     }
@@ -0,0 +1,129 @@
+//! Models a small product configuration problem using the API of the [`Solver`] directly (i.e.
+//! without the runner). It enumerates every valid configuration and then explains why a set of
+//! requirements of a customer cannot be met.
+//!
+//! # Model
+//! A machine is configured by choosing its number of cores, its amount of memory (in GB) and its
+//! number of GPUs, of which only some amounts are sold. A machine is a workstation exactly when
+//! `4 * cores + memory >= 64` (i.e. when it has 8 cores and 32 GB of memory), and only
+//! workstations can be equipped with a GPU. The price of a machine, `50 * cores + 10 * memory +
+//! 400 * gpus`, is at most 1200.
+
+use std::num::NonZero;
+
+use munchkin::predicates::NamedDisplay;
+use munchkin::predicates::Predicate;
+use munchkin::prelude::*;
+use munchkin::results::solution_iterator::IteratedSolution;
+use munchkin::results::SatisfactionResultUnderAssumptions;
+
+struct Configuration {
+    cores: DomainId,
+    memory: DomainId,
+    gpus: DomainId,
+    workstation: Literal,
+    /// The variables which are branched over; the literal is included as a 0-1 integer.
+    decision_variables: Vec<DomainId>,
+}
+
+fn create_model(solver: &mut Solver) -> Configuration {
+    let cores = solver.new_named_sparse_integer(vec![2, 4, 8], "cores");
+    let memory = solver.new_named_sparse_integer(vec![8, 16, 32], "memory");
+    let gpus = solver.new_named_bounded_integer(0, 1, "gpus");
+    let workstation = solver.new_named_literal("workstation");
+    let workstation_as_integer = solver.literal_as_integer(workstation);
+
+    // workstation <-> 4 * cores + memory >= 64
+    solver
+        .add_constraint(constraints::less_than_or_equals(
+            [cores.scaled(-4), memory.scaled(-1)],
+            -64,
+        ))
+        .reify(workstation, NonZero::new(1).unwrap())
+        .expect("the reified constraint is consistent");
+
+    // [gpus >= 1] -> workstation
+    let has_gpu = solver.get_literal(predicate![gpus >= 1]);
+    solver
+        .add_clause([!has_gpu, workstation])
+        .expect("the clause is consistent");
+
+    // 50 * cores + 10 * memory + 400 * gpus <= 1200
+    solver
+        .add_constraint(constraints::less_than_or_equals(
+            [cores.scaled(50), memory.scaled(10), gpus.scaled(400)],
+            1200,
+        ))
+        .post(NonZero::new(2).unwrap())
+        .expect("the budget constraint is consistent");
+
+    Configuration {
+        cores,
+        memory,
+        gpus,
+        workstation,
+        decision_variables: vec![cores, memory, gpus, workstation_as_integer],
+    }
+}
+
+fn main() {
+    let mut solver = Solver::default();
+    let configuration = create_model(&mut solver);
+
+    let mut brancher = IndependentVariableValueBrancher::new(
+        InputOrder::new(configuration.decision_variables.clone()),
+        InDomainMin,
+    );
+
+    // The solution iterator blocks the solutions it finds by adding clauses; these are removed
+    // again by rolling back to a snapshot which is taken beforehand.
+    let snapshot = solver.snapshot();
+
+    println!("Valid configurations:");
+    {
+        let mut termination = Indefinite;
+        let mut solution_iterator = solver.get_solution_iterator(&mut brancher, &mut termination);
+        while let IteratedSolution::Solution(solution) = solution_iterator.next_solution() {
+            println!(
+                "- {} cores, {} GB memory, {} GPUs, workstation: {}",
+                solution.get_integer_value(configuration.cores),
+                solution.get_integer_value(configuration.memory),
+                solution.get_integer_value(configuration.gpus),
+                solution.get_literal_value(configuration.workstation),
+            );
+        }
+    }
+    solver.rollback_to(snapshot);
+
+    // The requirements of the customer, which are posted as assumptions
+    let requirements: [Predicate; 3] = [
+        predicate![configuration.gpus >= 1],
+        predicate![configuration.memory <= 16],
+        predicate![configuration.cores >= 4],
+    ];
+    let assumptions = requirements.map(|requirement| solver.get_literal(requirement));
+
+    let core = match solver.satisfy_under_assumptions(&mut brancher, &mut Indefinite, &assumptions)
+    {
+        SatisfactionResultUnderAssumptions::UnsatisfiableUnderAssumptions(mut result) => {
+            result.extract_core()
+        }
+        SatisfactionResultUnderAssumptions::Satisfiable(_) => {
+            println!("The requirements of the customer can be met.");
+            return;
+        }
+        SatisfactionResultUnderAssumptions::Unsatisfiable
+        | SatisfactionResultUnderAssumptions::Unknown(_) => {
+            println!("The model is unexpectedly not solved.");
+            return;
+        }
+    };
+
+    // The core is a clause which contains the negations of the conflicting assumptions
+    println!("The following requirements of the customer cannot be met together:");
+    for (requirement, assumption) in requirements.iter().zip(assumptions) {
+        if core.contains(&!assumption) {
+            println!("- {}", NamedDisplay::new(requirement, &solver));
+        }
+    }
+}
@@ -1,15 +1,7 @@
 use std::num::NonZero;
 
 use clap::Parser;
-use munchkin::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
-use munchkin::branching::InDomainMin;
-use munchkin::branching::InputOrder;
-use munchkin::constraints;
-use munchkin::results::ProblemSolution;
-use munchkin::results::SatisfactionResult;
-use munchkin::termination::Indefinite;
-use munchkin::variables::TransformableVariable;
-use munchkin::Solver;
+use munchkin::prelude::*;
 
 #[derive(Debug, Parser)]
 struct Cli {
@@ -80,7 +72,7 @@ fn main() {
         SatisfactionResult::Unsatisfiable => {
             println!("{n}-queens is unsatisfiable.");
         }
-        SatisfactionResult::Unknown => {
+        SatisfactionResult::Unknown(_) => {
             println!("Timeout.");
         }
     }
@@ -1,21 +1,16 @@
 use std::collections::HashSet;
 
 use clap::ValueEnum;
-use dzn_rs::DataFile;
-use dzn_rs::ShapedArray;
 use fnv::FnvBuildHasher;
-use munchkin::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
-use munchkin::branching::Brancher;
-use munchkin::branching::InDomainMin;
-use munchkin::branching::InputOrder;
 use munchkin::model::Constraint;
 use munchkin::model::IntVariable;
 use munchkin::model::IntVariableArray;
 use munchkin::model::Model;
 use munchkin::model::Output;
 use munchkin::model::VariableMap;
+use munchkin::prelude::*;
+use munchkin::runner::dzn::DznContext;
 use munchkin::runner::Problem;
-use munchkin::Solver;
 
 munchkin::entry_point!(problem = Rcpsp, search_strategies = SearchStrategies);
 
@@ -31,54 +26,33 @@ struct Rcpsp {
 }
 
 impl Problem<SearchStrategies> for Rcpsp {
-    fn create(data: DataFile<i32>) -> anyhow::Result<(Self, Model)> {
+    fn create_from_dzn(data: &DznContext) -> anyhow::Result<(Self, Model)> {
         let mut model = Model::default();
 
-        let num_resources = data
-            .get::<i32>("n_res")
-            .copied()
-            .ok_or_else(|| anyhow::anyhow!("Missing int 'n_res' in data file."))?;
-        let num_resources_usize = usize::try_from(num_resources)?;
-
-        let num_tasks = data
-            .get::<i32>("n_tasks")
-            .copied()
-            .ok_or_else(|| anyhow::anyhow!("Missing int 'n_tasks' in data file."))?;
-        let num_tasks_usize = usize::try_from(num_tasks)?;
-
-        let durations = data
-            .array_1d::<i32>("d", num_tasks_usize)
-            .ok_or_else(|| anyhow::anyhow!("Missing int array 'd' in data file."))?;
-        let durations: Vec<_> = iterate(durations)
-            .copied()
+        let num_resources_usize = data.usize("n_res")?;
+        let num_tasks_usize = data.usize("n_tasks")?;
+
+        let durations: Vec<_> = data
+            .array_1d_i32("d", num_tasks_usize)?
+            .into_iter()
             .map(u32::try_from)
             .collect::<Result<_, _>>()?;
 
-        let resource_requirements = data
-            .array_2d::<i32>("rr", [num_resources_usize, num_tasks_usize])
-            .ok_or_else(|| anyhow::anyhow!("Missing 2d int array 'rr' in data file."))?;
-
-        let resource_capacities = data
-            .array_1d::<i32>("rc", num_resources_usize)
-            .ok_or_else(|| anyhow::anyhow!("Missing int array 'rc' in data file."))?;
-
-        let successors = data
-            .array_1d::<HashSet<i32>>("suc", num_tasks_usize)
-            .ok_or_else(|| anyhow::anyhow!("Missing set of int array 'suc' in data file."))?;
+        let resource_requirements =
+            data.array_2d_i32("rr", [num_resources_usize, num_tasks_usize])?;
+        let resource_capacities = data.array_1d_i32("rc", num_resources_usize)?;
+        let successors = data.array_1d_set("suc", num_tasks_usize)?;
 
         let horizon = durations.iter().sum::<u32>() as i32;
 
         let start_times = model.new_interval_variable_array("Start", 0, horizon, num_tasks_usize);
 
         for resource in 0..num_resources_usize {
-            let resource_capacity = resource_capacities
-                .get([resource])
-                .copied()
-                .unwrap()
-                .try_into()?;
+            let resource_capacity = resource_capacities[resource].try_into()?;
 
-            let resource_requirements: Vec<_> = slice_row(resource_requirements, resource)
-                .into_iter()
+            let resource_requirements: Vec<_> = resource_requirements[resource]
+                .iter()
+                .copied()
                 .map(u32::try_from)
                 .collect::<Result<_, _>>()?;
 
@@ -94,7 +68,7 @@ impl Problem<SearchStrategies> for Rcpsp {
         let start_times_array: Vec<_> = start_times.as_array(&model).collect();
         for task in 0..num_tasks_usize {
             let task_successors: HashSet<_, FnvBuildHasher> =
-                successors.get([task]).unwrap().iter().copied().collect();
+                successors[task].iter().copied().collect();
 
             for successor in task_successors.iter() {
                 // The instance is 1-indexed.
@@ -166,23 +140,3 @@ impl Problem<SearchStrategies> for Rcpsp {
         .into_iter()
     }
 }
-
-fn iterate<T>(array: &ShapedArray<T, 1>) -> impl Iterator<Item = &T> {
-    let [len] = *array.shape();
-
-    (0..len).map(|i| array.get([i]).unwrap())
-}
-
-/// Extract a row from the 2d array.
-fn slice_row(array: &ShapedArray<i32, 2>, row: usize) -> Vec<i32> {
-    let [_, n_cols] = *array.shape();
-
-    (0..n_cols)
-        .map(move |col| {
-            array
-                .get([row, col])
-                .copied()
-                .expect("index is within range")
-        })
-        .collect()
-}
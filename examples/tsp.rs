@@ -11,28 +11,29 @@
 //! % `Successor[i]` denotes the node which succeeds the node `i`.
 //! array [1..N] of var 1..N: Successor;
 //!
+//! % `Predecessor[i]` denotes the node which precedes the node `i`.
+//! array [1..N] of var 1..N: Predecessor;
+//!
 //! % Enforce a Hamiltonian cycle.
 //! constraint circuit(Successor);
 //!
+//! % Redundant: the predecessors are the inverse of the successors.
+//! constraint inverse(Successor, Predecessor);
+//!
 //! % Optimize for the shortest tour length.
 //! solve minimize sum([Dist[node, Successor[node]] | node in 1..N]);
 //! ```
 
 use clap::ValueEnum;
-use dzn_rs::DataFile;
-use dzn_rs::ShapedArray;
-use munchkin::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
-use munchkin::branching::Brancher;
-use munchkin::branching::InDomainMin;
-use munchkin::branching::InputOrder;
 use munchkin::model::Constraint;
 use munchkin::model::IntVariable;
 use munchkin::model::IntVariableArray;
 use munchkin::model::Model;
 use munchkin::model::Output;
 use munchkin::model::VariableMap;
+use munchkin::prelude::*;
+use munchkin::runner::dzn::DznContext;
 use munchkin::runner::Problem;
-use munchkin::Solver;
 
 munchkin::entry_point!(
     problem = TravellingSalesperson,
@@ -55,26 +56,34 @@ struct TravellingSalesperson {
 }
 
 impl Problem<SearchStrategies> for TravellingSalesperson {
-    fn create(data: DataFile<i32>) -> anyhow::Result<(Self, Model)> {
+    fn create_from_dzn(data: &DznContext) -> anyhow::Result<(Self, Model)> {
         let mut model = Model::default();
 
-        let (n, dist) = extract_data(&data)?;
+        let n = data.int("N")?;
+        let n_usize = data.usize("N")?;
+        let dist = data.array_2d_i32("Dist", [n_usize, n_usize])?;
 
-        let successors = model.new_interval_variable_array("Successor", 1, n, n as usize);
+        let successors = model.new_interval_variable_array("Successor", 1, n, n_usize);
         let successors_array: Vec<_> = successors.as_array(&model).collect();
 
         model.add_constraint(Constraint::Circuit(successors.as_array(&model).collect()));
 
+        let predecessors = model.new_interval_variable_array("Predecessor", 1, n, n_usize);
+        model.add_constraint(Constraint::Inverse {
+            f: successors_array.clone(),
+            g: predecessors.as_array(&model).collect(),
+        });
+
         // The upper bound for the objective variable is a very lax upper bound, as it
         // is a summation over all elements in the distance matrix.
-        let max_objective = iterate(dist).sum();
+        let max_objective = dist.iter().flatten().sum();
         let objective = model.new_interval_variable("Objective", 0, max_objective);
 
         let outgoing_costs = model.new_interval_variable_array(
             "_OutgoingCost",
             0,
-            iterate(dist).max().unwrap(),
-            n as usize,
+            dist.iter().flatten().copied().max().unwrap(),
+            n_usize,
         );
         let outgoing_costs_array: Vec<_> = outgoing_costs.as_array(&model).collect();
 
@@ -83,8 +92,9 @@ impl Problem<SearchStrategies> for TravellingSalesperson {
             .enumerate()
             .for_each(|(node, successor)| {
                 // The costs of going from `node` to any of the other nodes.
-                let distances_from_node = slice_row(dist, node)
-                    .into_iter()
+                let distances_from_node = dist[node]
+                    .iter()
+                    .copied()
                     .enumerate()
                     .map(|(idx, dist)| {
                         model.new_interval_variable(
@@ -155,41 +165,3 @@ impl Problem<SearchStrategies> for TravellingSalesperson {
         .into_iter()
     }
 }
-
-fn extract_data(data: &DataFile<i32>) -> anyhow::Result<(i32, &ShapedArray<i32, 2>)> {
-    let n: i32 = data
-        .get("N")
-        .copied()
-        .ok_or_else(|| anyhow::anyhow!("Missing int parameter 'N' in data."))?;
-
-    let n_usize: usize = n
-        .try_into()
-        .map_err(|_| anyhow::anyhow!("'N' should be an unsigned integer."))?;
-    let dist = data
-        .array_2d::<i32>("Dist", [n_usize, n_usize])
-        .ok_or_else(|| anyhow::anyhow!("Missing 2d int array 'dist'."))?;
-
-    Ok((n, dist))
-}
-
-/// Extract a row from the 2d array.
-fn slice_row(array: &ShapedArray<i32, 2>, row: usize) -> Vec<i32> {
-    let [_, n_cols] = *array.shape();
-
-    (0..n_cols)
-        .map(move |col| {
-            array
-                .get([row, col])
-                .copied()
-                .expect("index is within range")
-        })
-        .collect()
-}
-
-/// Iterate over the elements in `array`.
-fn iterate(array: &ShapedArray<i32, 2>) -> impl Iterator<Item = i32> + '_ {
-    let [n_rows, n_cols] = *array.shape();
-
-    (0..n_rows)
-        .flat_map(move |row| (0..n_cols).map(move |col| array.get([row, col]).copied().unwrap()))
-}
@@ -1,3 +1,4 @@
+pub mod optimisation;
 mod outputs;
 pub(crate) mod solver;
 
@@ -16,18 +17,90 @@ pub mod results {
     //! a core consisting of the assumptions using [`UnsatisfiableUnderAssumptions::extract_core`].
     pub use crate::api::outputs::solution_iterator;
     pub use crate::api::outputs::unsatisfiable;
+    pub use crate::api::outputs::BoundedSolveResult;
     pub use crate::api::outputs::OptimisationResult;
     pub use crate::api::outputs::ProblemSolution;
+    pub use crate::api::outputs::PropagationProbeResult;
+    pub use crate::api::outputs::RootPropagationSummary;
     pub use crate::api::outputs::SatisfactionResult;
     pub use crate::api::outputs::SatisfactionResultUnderAssumptions;
+    pub use crate::api::outputs::SolutionCallbackArguments;
     pub use crate::api::outputs::SolutionReference;
+    pub use crate::api::outputs::SuspendedSearch;
     pub use crate::basic_types::Solution;
+    pub use crate::basic_types::UnfixedVariable;
     #[cfg(doc)]
     use crate::results::unsatisfiable::UnsatisfiableUnderAssumptions;
     #[cfg(doc)]
     use crate::Solver;
 }
 
+pub mod prelude {
+    //! Re-exports the items which are needed for most uses of the [`Solver`]; these can be
+    //! imported at once using `use munchkin::prelude::*;`.
+    //!
+    //! # Example
+    //! ```rust
+    //! use munchkin::prelude::*;
+    //! # use std::num::NonZero;
+    //!
+    //! let mut solver = Solver::default();
+    //! let x = solver.new_bounded_integer(0, 3);
+    //! let y = solver.new_bounded_integer(0, 3);
+    //! let objective = solver.new_bounded_integer(-6, 0);
+    //!
+    //! // x + y >= 3, and the objective is -(x + y)
+    //! solver
+    //!     .add_constraint(constraints::less_than_or_equals([x.scaled(-1), y.scaled(-1)], -3))
+    //!     .post(NonZero::new(1).unwrap())
+    //!     .expect("the constraint is consistent");
+    //! solver
+    //!     .add_constraint(constraints::equals([x.into(), y.into(), objective.scaled(1)], 0))
+    //!     .post(NonZero::new(2).unwrap())
+    //!     .expect("the constraint is consistent");
+    //!
+    //! let mut brancher =
+    //!     IndependentVariableValueBrancher::new(InputOrder::new(vec![x, y, objective]), InDomainMin);
+    //! let mut termination = TimeBudget::starting_now(std::time::Duration::from_secs(10));
+    //! assert!(!termination.should_stop());
+    //!
+    //! let result = solver.satisfy(&mut brancher, &mut Indefinite);
+    //! let SatisfactionResult::Satisfiable(solution) = result else {
+    //!     panic!("x + y >= 3 is satisfiable");
+    //! };
+    //! assert!(solution.get_integer_value(x) + solution.get_integer_value(y) >= 3);
+    //!
+    //! // The predicate macros are exported as well
+    //! let x_is_three: Literal = solver.get_literal(predicate![x == 3]);
+    //! assert_eq!(solver.get_literal_value(x_is_three), None);
+    //! assert_eq!(conjunction!([x >= 1] & [y <= 2]).num_predicates(), 2);
+    //!
+    //! let result = solver.minimise(&mut brancher, &mut termination, objective);
+    //! assert!(matches!(result, OptimisationResult::Optimal(_)));
+    //! assert_eq!(result.objective_value(&objective), Some(-6));
+    //! ```
+    pub use crate::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
+    pub use crate::branching::Brancher;
+    pub use crate::branching::InDomainMin;
+    pub use crate::branching::InputOrder;
+    pub use crate::conjunction;
+    pub use crate::constraints;
+    pub use crate::constraints::Constraint;
+    pub use crate::predicate;
+    pub use crate::results::OptimisationResult;
+    pub use crate::results::ProblemSolution;
+    pub use crate::results::SatisfactionResult;
+    pub use crate::results::Solution;
+    pub use crate::termination::Indefinite;
+    pub use crate::termination::TerminationCondition;
+    pub use crate::termination::TimeBudget;
+    pub use crate::variables::DomainId;
+    pub use crate::variables::IntegerVariable;
+    pub use crate::variables::Literal;
+    pub use crate::variables::TransformableVariable;
+    pub use crate::Solver;
+}
+
 pub mod variables {
     //! Contains the variables which are used by the [`Solver`].
     //!
@@ -56,6 +129,21 @@ pub mod variables {
     use crate::Solver;
 }
 
+pub mod conflict_resolution {
+    //! Contains the [`ConflictResolver`]s which determine how the [`Solver`] analyses conflicts.
+    //!
+    //! A resolver can be passed to [`Solver::with_options_and_conflict_resolver`]; the built-in
+    //! resolvers are [`UniqueImplicationPoint`], [`AllDecisionLearning`] and [`NoLearning`].
+    pub use crate::engine::conflict_analysis::AllDecisionLearning;
+    pub use crate::engine::conflict_analysis::ConflictAnalysisContext;
+    pub use crate::engine::conflict_analysis::ConflictResolver;
+    pub use crate::engine::conflict_analysis::LearnedNogood;
+    pub use crate::engine::conflict_analysis::NoLearning;
+    pub use crate::engine::conflict_analysis::UniqueImplicationPoint;
+    #[cfg(doc)]
+    use crate::Solver;
+}
+
 pub mod options {
     //! Contains the options which can be passed to the [`Solver`].
     //!
@@ -63,7 +151,14 @@ pub mod options {
     //! - The restart strategy of the solver
     //! - The learned clause database management approach
     //! - The proof logging
+    //! - The level of the internal assertions
+    pub use crate::asserts::AssertLevel;
+    pub use crate::engine::constraint_satisfaction_solver::ConflictResolutionStrategy;
+    pub use crate::engine::constraint_satisfaction_solver::NogoodMinimisationStrategy;
+    pub use crate::engine::InvalidOptions;
+    pub use crate::engine::ProbingOptions;
     pub use crate::engine::SatisfactionSolverOptions as SolverOptions;
+    pub use crate::engine::SolverOptionsBuilder;
     #[cfg(doc)]
     use crate::Solver;
 }
@@ -79,10 +174,14 @@ pub mod termination {
     //! The most common example would be [`TimeBudget`], which terminates the [`Solver`] whenever
     //! the time budget is exceeded.
     pub use crate::engine::termination::combinator::*;
+    pub use crate::engine::termination::conflict_budget::*;
     pub use crate::engine::termination::indefinite::*;
+    pub use crate::engine::termination::memory_budget::*;
     pub use crate::engine::termination::os_signal::*;
     pub use crate::engine::termination::time_budget::*;
+    pub use crate::engine::termination::SolverSizeSnapshot;
     pub use crate::engine::termination::TerminationCondition;
+    pub use crate::engine::termination::TerminationReason;
     #[cfg(doc)]
     use crate::Solver;
 }
@@ -90,7 +189,13 @@ pub mod termination {
 pub mod statistics {
     //! Contains functions which configure the logging and allow the logging of statistics
     //! themselves.
+    pub use crate::api::optimisation::OptimisationIteration;
     pub use crate::basic_types::statistic_logging::statistic_logger::*;
+    pub use crate::basic_types::StatisticAccumulator;
+    pub use crate::engine::constraint_satisfaction_solver::ModelSummary;
+    pub use crate::engine::constraint_satisfaction_solver::PropagatorCounters;
+    pub use crate::engine::constraint_satisfaction_solver::SearchStatistics;
+    pub use crate::proof::ProofStatistics;
 }
 
 pub mod predicates {
@@ -107,7 +212,10 @@ pub mod predicates {
     //! In general, these [`Predicate`]s are used to represent propagations, explanations or
     //! decisions.
     pub use crate::basic_types::PropositionalConjunction;
+    pub use crate::engine::predicates::bound::Bound;
     pub use crate::engine::predicates::integer_predicate::IntegerPredicate;
+    pub use crate::engine::predicates::named_display::NamedDisplay;
+    pub use crate::engine::predicates::named_display::VariableNameLookup;
     pub use crate::engine::predicates::predicate::Predicate;
     pub use crate::engine::predicates::predicate_constructor::PredicateConstructor;
     #[cfg(doc)]
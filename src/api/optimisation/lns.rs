@@ -0,0 +1,231 @@
+//! Contains the [`LnsPolisher`] which tries to improve a solution using large neighbourhood
+//! search.
+
+use crate::basic_types::Solution;
+use crate::branching::Brancher;
+use crate::engine::termination::TerminationCondition;
+use crate::engine::variables::DomainId;
+use crate::engine::variables::IntegerVariable;
+#[cfg(doc)]
+use crate::options::SolverOptions;
+use crate::predicate;
+use crate::results::ProblemSolution;
+use crate::Solver;
+
+/// Improves a solution by searching its neighbourhood, i.e. by fixing all but a random subset of
+/// the variables to their values in the solution and solving the (small) residual problem with
+/// the requirement that the objective improves.
+///
+/// The neighbourhood consists of `neighbourhood_size` of the provided variables, which are
+/// selected using the random generator of the [`Solver`] (see [`SolverOptions::random_seed`]).
+/// The residual problem is solved with a budget of `conflict_budget` conflicts.
+///
+/// The polisher can be used on its own through [`LnsPolisher::polish`], or it can be run after
+/// every improving solution of [`Solver::minimise`] and [`Solver::maximise`] by adding it to the
+/// solver using [`Solver::with_solution_polisher`].
+#[derive(Clone, Debug)]
+pub struct LnsPolisher {
+    /// The variables which are fixed to their values in the solution if they are not part of the
+    /// neighbourhood.
+    variables: Vec<DomainId>,
+    /// The number of variables which are not fixed.
+    neighbourhood_size: usize,
+    /// The number of conflicts which the solver may encounter when solving the residual problem.
+    conflict_budget: u64,
+}
+
+impl LnsPolisher {
+    pub fn new(variables: Vec<DomainId>, neighbourhood_size: usize, conflict_budget: u64) -> Self {
+        LnsPolisher {
+            variables,
+            neighbourhood_size,
+            conflict_budget,
+        }
+    }
+
+    /// Attempts to find a solution with a lower value for `objective` in the neighbourhood of
+    /// `solution`. If such a solution is found, then `solution` is replaced by it and `true` is
+    /// returned; otherwise `solution` is left untouched and `false` is returned.
+    ///
+    /// In either case, the state of the `solver` is restored to the root afterwards.
+    pub fn polish(
+        &mut self,
+        solver: &mut Solver,
+        brancher: &mut impl Brancher,
+        termination: &mut impl TerminationCondition,
+        solution: &mut Solution,
+        objective: impl IntegerVariable,
+    ) -> bool {
+        let objective_value = solution.get_integer_value(objective.clone());
+
+        // Move the variables in the neighbourhood to the front using a partial Fisher-Yates
+        // shuffle; the remaining variables are fixed.
+        let neighbourhood_size = self.neighbourhood_size.min(self.variables.len());
+        for index in 0..neighbourhood_size {
+            let selected = solver
+                .random()
+                .generate_usize_in_range(index..self.variables.len());
+            self.variables.swap(index, selected);
+        }
+
+        let assumptions = self.variables[neighbourhood_size..]
+            .iter()
+            .map(|&variable| {
+                let value = solution.get_integer_value(variable);
+                solver.get_literal(predicate![variable == value])
+            })
+            .chain(std::iter::once(solver.get_literal(
+                objective.upper_bound_predicate(objective_value - 1),
+            )))
+            .collect::<Vec<_>>();
+
+        match solver.solve_neighbourhood(&assumptions, self.conflict_budget, termination, brancher)
+        {
+            Some(improved_solution) => {
+                *solution = improved_solution;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::cell::RefCell;
+    use std::num::NonZero;
+    use std::rc::Rc;
+
+    use super::LnsPolisher;
+    use crate::basic_types::Solution;
+    use crate::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
+    use crate::branching::InDomainMin;
+    use crate::branching::InDomainSplitReversed;
+    use crate::branching::InputOrder;
+    use crate::constraints;
+    use crate::predicate;
+    use crate::results::OptimisationResult;
+    use crate::results::ProblemSolution;
+    use crate::results::SatisfactionResultUnderAssumptions;
+    use crate::termination::Indefinite;
+    use crate::variables::DomainId;
+    use crate::Solver;
+
+    /// Creates the problem of minimising `x + y` with `x, y \in [0, 5]`.
+    fn sum_problem() -> (Solver, DomainId, DomainId, DomainId) {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 5);
+        let y = solver.new_bounded_integer(0, 5);
+        let objective = solver.new_bounded_integer(0, 10);
+        let _ = solver
+            .add_constraint(constraints::sum([x, y]).minus(objective).equals(0))
+            .post(NonZero::new(1).unwrap());
+
+        (solver, x, y, objective)
+    }
+
+    fn solution_with(solver: &mut Solver, x: DomainId, y: DomainId, value: i32) -> Solution {
+        let mut brancher =
+            IndependentVariableValueBrancher::new(InputOrder::new(vec![x, y]), InDomainMin);
+        let assumptions = [
+            solver.get_literal(predicate![x == value]),
+            solver.get_literal(predicate![y == value]),
+        ];
+        let SatisfactionResultUnderAssumptions::Satisfiable(solution) =
+            solver.satisfy_under_assumptions(&mut brancher, &mut Indefinite, &assumptions)
+        else {
+            panic!("the assignment should be a solution");
+        };
+        solution
+    }
+
+    #[test]
+    fn changing_a_single_variable_improves_the_solution() {
+        let (mut solver, x, y, objective) = sum_problem();
+        let mut solution = solution_with(&mut solver, x, y, 2);
+
+        let mut polisher = LnsPolisher::new(vec![x, y], 1, 100);
+        let mut brancher =
+            IndependentVariableValueBrancher::new(InputOrder::new(vec![x, y]), InDomainMin);
+        let is_improved = polisher.polish(
+            &mut solver,
+            &mut brancher,
+            &mut Indefinite,
+            &mut solution,
+            objective,
+        );
+
+        assert!(is_improved);
+        assert_eq!(solution.get_integer_value(objective), 2);
+        // One of the variables kept its value, whereas the other one was minimised
+        let mut values = [solution.get_integer_value(x), solution.get_integer_value(y)];
+        values.sort();
+        assert_eq!(values, [0, 2]);
+    }
+
+    #[test]
+    fn polishing_restores_the_state_at_the_root() {
+        let (mut solver, x, y, objective) = sum_problem();
+        let mut solution = solution_with(&mut solver, x, y, 0);
+
+        let mut polisher = LnsPolisher::new(vec![x, y], 1, 100);
+        let mut brancher =
+            IndependentVariableValueBrancher::new(InputOrder::new(vec![x, y]), InDomainMin);
+        let is_improved = polisher.polish(
+            &mut solver,
+            &mut brancher,
+            &mut Indefinite,
+            &mut solution,
+            objective,
+        );
+
+        // The solution is optimal, so it cannot be improved
+        assert!(!is_improved);
+        assert_eq!(solution.get_integer_value(objective), 0);
+
+        for variable in [x, y] {
+            assert_eq!(solver.lower_bound(&variable), 0);
+            assert_eq!(solver.upper_bound(&variable), 5);
+        }
+        assert_eq!(solver.lower_bound(&objective), 0);
+        assert_eq!(solver.upper_bound(&objective), 10);
+    }
+
+    #[test]
+    fn polisher_is_run_on_the_solutions_of_minimise() {
+        let (mut solver, x, y, objective) = sum_problem();
+        solver.with_solution_polisher(LnsPolisher::new(vec![x, y], 2, 100));
+
+        let objective_values = Rc::new(RefCell::new(vec![]));
+        let callback_values = Rc::clone(&objective_values);
+        solver.with_solution_callback(move |arguments| {
+            callback_values
+                .borrow_mut()
+                .push(arguments.solution.get_integer_value(objective));
+        });
+        let num_iterations = Rc::new(Cell::new(0));
+        let callback_iterations = Rc::clone(&num_iterations);
+        solver.with_iteration_callback(move |_| {
+            callback_iterations.set(callback_iterations.get() + 1);
+        });
+
+        let mut brancher = IndependentVariableValueBrancher::new(
+            InputOrder::new(vec![x, y]),
+            InDomainSplitReversed,
+        );
+        let result = solver.minimise(&mut brancher, &mut Indefinite, objective);
+        let OptimisationResult::Optimal(solution) = result else {
+            panic!("expected an optimal solution, got {result:?}");
+        };
+        assert_eq!(solution.get_integer_value(objective), 0);
+
+        // Every solution is reported, including the ones found by the polisher; these are found
+        // without starting a new iteration
+        assert_eq!(
+            *objective_values.borrow(),
+            (0..=10).rev().collect::<Vec<_>>()
+        );
+        assert!(num_iterations.get() < objective_values.borrow().len());
+    }
+}
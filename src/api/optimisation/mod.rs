@@ -0,0 +1,113 @@
+//! Contains the [`OptimisationIteration`] which describes the progress of the optimisation
+//! procedures of the [`Solver`], the [`OptimisationStrategy`] which selects between these
+//! procedures, and the [`LnsPolisher`] which improves the solutions that they find.
+
+pub mod lns;
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::time::Duration;
+use std::time::Instant;
+
+use clap::ValueEnum;
+
+#[cfg(doc)]
+use crate::optimisation::lns::LnsPolisher;
+#[cfg(doc)]
+use crate::options::SolverOptions;
+use crate::statistics::log_statistic;
+#[cfg(doc)]
+use crate::Solver;
+
+/// The procedure which is used to optimise the objective.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum OptimisationStrategy {
+    /// Repeatedly finds a solution and then requires the next solution to be strictly better
+    /// (see [`Solver::minimise`]).
+    #[default]
+    LinearSatUnsat,
+    /// Halves the interval of possible objective values with every solve call by assuming that
+    /// the objective is at most its midpoint (see [`Solver::minimise_dichotomic`]).
+    Dichotomic,
+}
+
+impl Display for OptimisationStrategy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OptimisationStrategy::LinearSatUnsat => write!(f, "linear-sat-unsat"),
+            OptimisationStrategy::Dichotomic => write!(f, "dichotomic"),
+        }
+    }
+}
+
+/// The state of an optimisation procedure at the end of one of its iterations, i.e. after a call
+/// to the underlying satisfaction solver has finished; see [`Solver::with_iteration_callback`].
+///
+/// The bounds are expressed in terms of the objective as provided by the user, i.e. when
+/// maximising, the `lower_bound` is the value of the best solution found so far.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OptimisationIteration {
+    /// The index of the iteration, starting from 0.
+    pub iteration: usize,
+    /// The lower bound on the objective value of an optimal solution.
+    pub lower_bound: i64,
+    /// The upper bound on the objective value of an optimal solution.
+    pub upper_bound: i64,
+    /// The time since the start of the optimisation procedure.
+    pub elapsed: Duration,
+    /// The number of conflicts which were encountered during the iteration.
+    pub conflicts: u64,
+}
+
+/// Keeps track of the iterations of an optimisation procedure and (if
+/// [`SolverOptions::verbose_optimisation`] is set) logs them as statistics with the names
+/// `iterIndex`, `iterLowerBound`, `iterUpperBound`, `iterElapsedMs` and `iterConflicts`.
+#[derive(Debug)]
+pub(crate) struct BoundEventLogger {
+    start: Instant,
+    iteration: usize,
+    conflicts_at_iteration_start: u64,
+    is_verbose: bool,
+}
+
+impl BoundEventLogger {
+    pub(crate) fn new(is_verbose: bool, conflicts: u64) -> Self {
+        BoundEventLogger {
+            start: Instant::now(),
+            iteration: 0,
+            conflicts_at_iteration_start: conflicts,
+            is_verbose,
+        }
+    }
+
+    /// Finishes the current iteration with the provided bounds, where `conflicts` is the total
+    /// number of conflicts encountered by the solver, and returns the [`OptimisationIteration`]
+    /// describing it.
+    pub(crate) fn finish_iteration(
+        &mut self,
+        lower_bound: i64,
+        upper_bound: i64,
+        conflicts: u64,
+    ) -> OptimisationIteration {
+        let record = OptimisationIteration {
+            iteration: self.iteration,
+            lower_bound,
+            upper_bound,
+            elapsed: self.start.elapsed(),
+            conflicts: conflicts - self.conflicts_at_iteration_start,
+        };
+
+        if self.is_verbose {
+            log_statistic("iterIndex", record.iteration);
+            log_statistic("iterLowerBound", record.lower_bound);
+            log_statistic("iterUpperBound", record.upper_bound);
+            log_statistic("iterElapsedMs", record.elapsed.as_millis());
+            log_statistic("iterConflicts", record.conflicts);
+        }
+
+        self.iteration += 1;
+        self.conflicts_at_iteration_start = conflicts;
+
+        record
+    }
+}
@@ -5,13 +5,21 @@ pub use crate::basic_types::SolutionReference;
 pub mod solution_iterator;
 pub mod unsatisfiable;
 use crate::branching::Brancher;
+use crate::engine::cp::propagation::propagation_context::HasAssignments;
+use crate::engine::predicates::predicate::Predicate;
+use crate::engine::variables::DomainId;
+use crate::engine::variables::IntegerVariable;
+use crate::engine::variables::Literal;
 #[cfg(doc)]
 use crate::termination::TerminationCondition;
+use crate::termination::TerminationReason;
 #[cfg(doc)]
+use crate::ConstraintOperationError;
 use crate::Solver;
 
 /// The result of a call to [`Solver::satisfy`].
 #[derive(Debug)]
+#[must_use]
 #[allow(clippy::large_enum_variant)]
 pub enum SatisfactionResult {
     /// Indicates that a solution was found and provides the corresponding [`Solution`].
@@ -19,8 +27,9 @@ pub enum SatisfactionResult {
     /// Indicates that there is no solution to the satisfaction problem.
     Unsatisfiable,
     /// Indicates that it is not known whether a solution exists. This is likely due to a
-    /// [`TerminationCondition`] triggering.
-    Unknown,
+    /// [`TerminationCondition`] triggering; the [`TerminationReason`] describes why the search
+    /// was stopped.
+    Unknown(TerminationReason),
 }
 
 /// The result of a call to [`Solver::satisfy_under_assumptions`].
@@ -36,12 +45,87 @@ pub enum SatisfactionResultUnderAssumptions<'solver, 'brancher, B: Brancher> {
     /// Indicates that there is no solution to the satisfaction problem.
     Unsatisfiable,
     /// Indicates that it is not known whether a solution exists. This is likely due to a
-    /// [`TerminationCondition`] triggering.
-    Unknown,
+    /// [`TerminationCondition`] triggering; the [`TerminationReason`] describes why the search
+    /// was stopped.
+    Unknown(TerminationReason),
+}
+
+/// The result of a call to [`Solver::solve_bounded`] or [`Solver::resume_bounded`].
+#[derive(Debug)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum BoundedSolveResult {
+    /// Indicates that a solution was found and provides the corresponding [`Solution`].
+    Satisfiable(Solution),
+    /// Indicates that there is no solution to the satisfaction problem.
+    Unsatisfiable,
+    /// Indicates that the decision limit was reached (or that the [`TerminationCondition`]
+    /// triggered) before the search finished; the [`SuspendedSearch`] can be passed to
+    /// [`Solver::resume_bounded`] to continue the search.
+    Suspended(SuspendedSearch),
+}
+
+/// A search which was suspended by [`Solver::solve_bounded`] or [`Solver::resume_bounded`].
+///
+/// While a search is suspended, the [`Solver`] is not restored to the root; its trail and learned
+/// clauses are retained such that [`Solver::resume_bounded`] continues from the exact state in
+/// which the search was suspended. Constraints cannot be added to the solver while a search is
+/// suspended (adding them results in [`ConstraintOperationError::SuspendedSearch`]); the
+/// suspended search is abandoned by starting a new search or by restoring the solver to the root
+/// with [`Solver::abandon_bounded`].
+///
+/// Since the brancher is owned by the caller, the same brancher should be passed to every call
+/// which continues the search.
+#[derive(Debug)]
+#[must_use]
+#[allow(
+    missing_copy_implementations,
+    reason = "a suspended search can only be resumed once"
+)]
+pub struct SuspendedSearch {
+    /// Identifies the suspension, such that a search which has been abandoned cannot be resumed.
+    pub(crate) suspension_id: u64,
+    /// The number of decisions which were made in the search before the first call.
+    pub(crate) num_decisions_at_start: u64,
+    /// The number of decisions which were made in the search before the suspension.
+    pub(crate) num_decisions: u64,
+}
+
+impl SuspendedSearch {
+    /// Returns the number of decisions which have been made by the search (over all of its calls)
+    /// until it was suspended.
+    pub fn num_decisions(&self) -> u64 {
+        self.num_decisions - self.num_decisions_at_start
+    }
+}
+
+/// The result of a call to [`Solver::propagate_under_assumptions`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PropagationProbeResult {
+    /// Propagating the assumptions led to a conflict; it provides the assumptions which are
+    /// responsible for the conflict. This is empty if the problem is infeasible without any
+    /// assumptions.
+    Conflict(Vec<Literal>),
+    /// Propagating the assumptions did not lead to a conflict; it provides the literals which were
+    /// implied by the assumptions.
+    Consistent { implied: Vec<Literal> },
+}
+
+/// The result of a call to [`Solver::propagate`]; it describes what is known at the root after
+/// propagating to a fixed point.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RootPropagationSummary {
+    /// The bounds of the integer variables which are tighter than the bounds with which the
+    /// variables were created.
+    pub tightened_bounds: Vec<Predicate>,
+    /// The literals which are assigned true at the root; the literals which represent predicates
+    /// over integer variables are not included (these are reflected in `tightened_bounds`).
+    pub fixed_literals: Vec<Literal>,
 }
 
 /// The result of a call to [`Solver::maximise`] or [`Solver::minimise`].
 #[derive(Debug)]
+#[must_use]
 pub enum OptimisationResult {
     /// Indicates that an optimal solution has been found and proven to be optimal. It provides an
     /// instance of [`Solution`] which contains the optimal solution.
@@ -52,6 +136,239 @@ pub enum OptimisationResult {
     /// Indicates that there is no solution to the problem.
     Unsatisfiable,
     /// Indicates that it is not known whether a solution exists. This is likely due to a
-    /// [`TerminationCondition`] triggering.
-    Unknown,
+    /// [`TerminationCondition`] triggering; the [`TerminationReason`] describes why the search
+    /// was stopped.
+    Unknown(TerminationReason),
+}
+
+impl SatisfactionResult {
+    /// Returns the [`Solution`] which was found or [`None`] if no solution was found.
+    pub fn solution(&self) -> Option<&Solution> {
+        match self {
+            SatisfactionResult::Satisfiable(solution) => Some(solution),
+            SatisfactionResult::Unsatisfiable | SatisfactionResult::Unknown(_) => None,
+        }
+    }
+
+    /// Consumes the result and returns the [`Solution`] which was found or [`None`] if no solution
+    /// was found.
+    pub fn into_solution(self) -> Option<Solution> {
+        match self {
+            SatisfactionResult::Satisfiable(solution) => Some(solution),
+            SatisfactionResult::Unsatisfiable | SatisfactionResult::Unknown(_) => None,
+        }
+    }
+}
+
+impl OptimisationResult {
+    /// Returns the best [`Solution`] which was found (regardless of whether it was proven to be
+    /// optimal) or [`None`] if no solution was found.
+    pub fn solution(&self) -> Option<&Solution> {
+        match self {
+            OptimisationResult::Optimal(solution) | OptimisationResult::Satisfiable(solution) => {
+                Some(solution)
+            }
+            OptimisationResult::Unsatisfiable | OptimisationResult::Unknown(_) => None,
+        }
+    }
+
+    /// Consumes the result and returns the best [`Solution`] which was found (regardless of
+    /// whether it was proven to be optimal) or [`None`] if no solution was found.
+    pub fn into_solution(self) -> Option<Solution> {
+        match self {
+            OptimisationResult::Optimal(solution) | OptimisationResult::Satisfiable(solution) => {
+                Some(solution)
+            }
+            OptimisationResult::Unsatisfiable | OptimisationResult::Unknown(_) => None,
+        }
+    }
+
+    /// Returns whether the solution was proven to be optimal.
+    pub fn is_optimal(&self) -> bool {
+        matches!(self, OptimisationResult::Optimal(_))
+    }
+
+    /// Returns the value of the provided `objective` in the best [`Solution`] which was found or
+    /// [`None`] if no solution was found.
+    pub fn objective_value(&self, objective: &impl IntegerVariable) -> Option<i32> {
+        self.solution()
+            .map(|solution| solution.get_integer_value(objective.clone()))
+    }
+}
+
+/// The arguments which are passed to the solution callbacks of the [`Solver`]; see
+/// [`Solver::with_solution_callback`] and [`Solver::with_final_solution_callback`].
+#[derive(Clone, Copy, Debug)]
+pub struct SolutionCallbackArguments<'a> {
+    /// The solution which was found.
+    pub solution: &'a Solution,
+    /// Whether the solution is the final solution of the optimisation procedure, i.e. the
+    /// solution which is returned in [`OptimisationResult::Optimal`] or
+    /// [`OptimisationResult::Satisfiable`].
+    pub is_final: bool,
+    /// The solver which found the solution; during search, it is not necessarily at the root.
+    pub(crate) solver: &'a Solver,
+}
+
+impl<'a> SolutionCallbackArguments<'a> {
+    /// Returns the optimality gap of the solution when minimising `objective`, i.e. the difference
+    /// between the value of `objective` in the solution and its lower bound at the root (see
+    /// [`Solver::root_lower_bound`]). When maximising, the gap is obtained by providing the
+    /// negated objective (`objective.scaled(-1)`).
+    ///
+    /// The bound at the root holds for every solution which can still be found, even when the
+    /// callback is called deep in the search; a gap of 0 means that the solution is optimal.
+    pub fn optimality_gap(&self, objective: &impl IntegerVariable) -> i64 {
+        let value = self.solution.get_integer_value(objective.clone());
+        value as i64 - self.solver.root_lower_bound(objective) as i64
+    }
+
+    /// Returns the [`DomainId`]s whose domain in [`SolutionCallbackArguments::solution`] differs
+    /// from their domain in the `previous` solution (including the domains which do not exist in
+    /// the `previous` solution).
+    ///
+    /// This allows a callback which keeps track of the previously reported solution to only
+    /// report the variables which have changed.
+    pub fn changed_since_last<'b>(
+        &'b self,
+        previous: &'b Solution,
+    ) -> impl Iterator<Item = DomainId> + 'b {
+        let bounds = |solution: &Solution, domain: DomainId| {
+            let assignments = solution.assignments_integer();
+            (
+                domain.lower_bound(assignments),
+                domain.upper_bound(assignments),
+            )
+        };
+
+        (0..self.solution.num_domains() as u32)
+            .map(DomainId::new)
+            .filter(move |&domain| {
+                domain.id as usize >= previous.num_domains()
+                    || bounds(self.solution, domain) != bounds(previous, domain)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
+    use crate::branching::InDomainMin;
+    use crate::branching::InputOrder;
+    use crate::engine::cp::AssignmentsInteger;
+    use crate::engine::sat::AssignmentsPropositional;
+    use crate::engine::variables::DomainId;
+    use crate::termination::Indefinite;
+    use crate::Solver;
+
+    /// Returns a [`Solution`] in which the returned variable is assigned to 3.
+    fn solution() -> (Solution, DomainId) {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(3, 3);
+        let mut brancher =
+            IndependentVariableValueBrancher::new(InputOrder::new(vec![x]), InDomainMin);
+
+        let solution = solver
+            .satisfy(&mut brancher, &mut Indefinite)
+            .into_solution()
+            .expect("the problem should be satisfiable");
+        (solution, x)
+    }
+
+    #[test]
+    fn satisfaction_result_helpers_on_satisfiable() {
+        let (solution, x) = solution();
+        let result = SatisfactionResult::Satisfiable(solution);
+
+        assert_eq!(result.solution().map(|s| s.get_integer_value(x)), Some(3));
+        assert_eq!(
+            result.into_solution().map(|s| s.get_integer_value(x)),
+            Some(3)
+        );
+    }
+
+    #[test]
+    fn satisfaction_result_helpers_without_solution() {
+        for result in [
+            SatisfactionResult::Unsatisfiable,
+            SatisfactionResult::Unknown(TerminationReason::TimeBudget),
+        ] {
+            assert!(result.solution().is_none());
+            assert!(result.into_solution().is_none());
+        }
+    }
+
+    #[test]
+    fn optimisation_result_helpers_on_optimal() {
+        let (solution, x) = solution();
+        let result = OptimisationResult::Optimal(solution);
+
+        assert!(result.is_optimal());
+        assert_eq!(result.objective_value(&x), Some(3));
+        assert_eq!(result.solution().map(|s| s.get_integer_value(x)), Some(3));
+        assert_eq!(
+            result.into_solution().map(|s| s.get_integer_value(x)),
+            Some(3)
+        );
+    }
+
+    #[test]
+    fn optimisation_result_helpers_on_satisfiable() {
+        let (solution, x) = solution();
+        let result = OptimisationResult::Satisfiable(solution);
+
+        assert!(!result.is_optimal());
+        assert_eq!(result.objective_value(&x), Some(3));
+        assert_eq!(result.solution().map(|s| s.get_integer_value(x)), Some(3));
+        assert_eq!(
+            result.into_solution().map(|s| s.get_integer_value(x)),
+            Some(3)
+        );
+    }
+
+    #[test]
+    fn optimisation_result_helpers_without_solution() {
+        let x = DomainId::new(0);
+
+        for result in [
+            OptimisationResult::Unsatisfiable,
+            OptimisationResult::Unknown(TerminationReason::TimeBudget),
+        ] {
+            assert!(!result.is_optimal());
+            assert_eq!(result.objective_value(&x), None);
+            assert!(result.solution().is_none());
+            assert!(result.into_solution().is_none());
+        }
+    }
+
+    /// Creates a [`Solution`] in which the variable with index `i` has the domain `domains[i]`.
+    fn solution_with_domains(domains: &[(i32, i32)]) -> Solution {
+        let mut assignments_integer = AssignmentsInteger::default();
+        for &(lower_bound, upper_bound) in domains {
+            let _ = assignments_integer.grow(lower_bound, upper_bound);
+        }
+
+        Solution::new(AssignmentsPropositional::default(), assignments_integer)
+    }
+
+    #[test]
+    fn changed_since_last_reports_exactly_the_changed_domains() {
+        let previous = solution_with_domains(&[(1, 1), (2, 2), (3, 3), (0, 4)]);
+        let solution = solution_with_domains(&[(1, 1), (5, 5), (3, 3), (0, 3), (7, 7)]);
+        let solver = Solver::default();
+        let arguments = SolutionCallbackArguments {
+            solution: &solution,
+            is_final: false,
+            solver: &solver,
+        };
+
+        let changed = arguments.changed_since_last(&previous).collect::<Vec<_>>();
+        assert_eq!(
+            changed,
+            vec![DomainId::new(1), DomainId::new(3), DomainId::new(4)]
+        );
+
+        assert_eq!(arguments.changed_since_last(&solution).count(), 0);
+    }
 }
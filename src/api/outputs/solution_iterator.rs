@@ -5,11 +5,21 @@ use crate::basic_types::CSPSolverExecutionFlag;
 use crate::branching::Brancher;
 use crate::engine::ConstraintSatisfactionSolver;
 use crate::termination::TerminationCondition;
+use crate::termination::TerminationReason;
 use crate::variables::Literal;
 #[cfg(doc)]
 use crate::Solver;
 
 /// A struct which allows the retrieval of multiple solutions to a satisfaction problem.
+///
+/// A returned solution is blocked by adding a (permanent) clause to the solver when the next
+/// solution is requested. When the iterator is dropped, the solver is restored to the root; the
+/// blocked solutions remain blocked, but the last returned solution is not blocked (so it remains
+/// a solution of the solver, see [`Solver::verify_solution`]). A subsequent call to e.g.
+/// [`Solver::minimise`] thus only considers the last returned solution and the solutions which
+/// were not returned by the iterator. To unblock the solutions again, take a snapshot before
+/// iterating (see [`Solver::snapshot`]) and roll back to it afterwards (see
+/// [`Solver::rollback_to`]).
 #[derive(Debug)]
 pub struct SolutionIterator<'solver, 'brancher, 'termination, B: Brancher, T> {
     solver: &'solver mut ConstraintSatisfactionSolver,
@@ -38,30 +48,79 @@ impl<'solver, 'brancher, 'termination, B: Brancher, T: TerminationCondition>
 
     /// Find a new solution by blocking the previous solution from being found. Also calls the
     /// [`Brancher::on_solution`] method from the [`Brancher`] used to run the initial solve.
-    pub fn next_solution(&mut self) -> IteratedSolution {
-        if let Some(blocking_clause) = self.next_blocking_clause.take() {
-            self.solver.restore_state_at_root(self.brancher);
-            if self.solver.add_clause(blocking_clause).is_err() {
-                return IteratedSolution::Finished;
-            }
-        }
-        match self.solver.solve(self.termination, self.brancher) {
+    pub fn next_solution(&mut self) -> IteratedSolution<'_> {
+        match self.find_next_solution() {
             CSPSolverExecutionFlag::Feasible => {
-                self.has_solution = true;
-                self.brancher
-                    .on_solution(self.solver.get_solution_reference());
-                let solution = self.solver.get_solution_reference();
-                self.next_blocking_clause = Some(self.get_blocking_clause());
-                IteratedSolution::Solution(solution)
+                IteratedSolution::Solution(self.solver.get_solution_reference())
             }
             CSPSolverExecutionFlag::Infeasible if !self.has_solution => {
                 IteratedSolution::Unsatisfiable
             }
             CSPSolverExecutionFlag::Infeasible => IteratedSolution::Finished,
-            CSPSolverExecutionFlag::Timeout => IteratedSolution::Unknown,
+            CSPSolverExecutionFlag::Timeout => {
+                IteratedSolution::Unknown(self.termination.reason().unwrap_or_default())
+            }
+        }
+    }
+
+    /// Counts the solutions which have not been returned by [`SolutionIterator::next_solution`]
+    /// yet.
+    ///
+    /// This is faster than iterating over the solutions, as no solution is handed out. If the
+    /// termination condition triggers, then [`SolutionCount::Unknown`] is returned with the
+    /// number of solutions which were counted so far.
+    pub fn count_remaining(self) -> SolutionCount {
+        self.count(None)
+    }
+
+    /// Counts the solutions which have not been returned by [`SolutionIterator::next_solution`]
+    /// yet, but stops once `cap` solutions have been counted; in that case,
+    /// [`SolutionCount::AtLeast`] is returned.
+    ///
+    /// This is useful if only a lower bound on the number of solutions is of interest (e.g. to
+    /// check whether a solution is unique). Otherwise, this is the same as
+    /// [`SolutionIterator::count_remaining`].
+    pub fn count_up_to(self, cap: u64) -> SolutionCount {
+        self.count(Some(cap))
+    }
+
+    fn count(mut self, cap: Option<u64>) -> SolutionCount {
+        let mut num_solutions = 0;
+
+        loop {
+            if cap.is_some_and(|cap| num_solutions >= cap) {
+                return SolutionCount::AtLeast(num_solutions);
+            }
+
+            match self.find_next_solution() {
+                CSPSolverExecutionFlag::Feasible => num_solutions += 1,
+                CSPSolverExecutionFlag::Infeasible => return SolutionCount::Exact(num_solutions),
+                CSPSolverExecutionFlag::Timeout => return SolutionCount::Unknown(num_solutions),
+            }
         }
     }
 
+    /// Searches for the next solution after blocking the previous one; if a solution is found,
+    /// then it is left in the solver and the clause blocking it is prepared.
+    fn find_next_solution(&mut self) -> CSPSolverExecutionFlag {
+        if let Some(blocking_clause) = self.next_blocking_clause.take() {
+            self.solver.restore_state_at_root(self.brancher);
+            if self.solver.add_clause(blocking_clause).is_err() {
+                return CSPSolverExecutionFlag::Infeasible;
+            }
+        }
+
+        let flag = self.solver.solve(self.termination, self.brancher);
+        if let CSPSolverExecutionFlag::Feasible = flag {
+            self.has_solution = true;
+            self.brancher
+                .on_solution(self.solver.get_solution_reference());
+            self.next_blocking_clause = Some(self.get_blocking_clause());
+        }
+
+        flag
+    }
+
     /// Creates a clause which prevents the current solution from occurring again by going over the
     /// defined output variables and creating a clause which prevents those values from
     /// being assigned. This method is used when attempting to find multiple solutions. It restores
@@ -92,8 +151,14 @@ impl<'solver, 'brancher, 'termination, B: Brancher, T: TerminationCondition>
     }
 }
 
+impl<B: Brancher, T> Drop for SolutionIterator<'_, '_, '_, B, T> {
+    fn drop(&mut self) {
+        self.solver.restore_state_at_root(self.brancher)
+    }
+}
+
 /// Enum which specifies the status of the call to [`SolutionIterator::next_solution`].
-#[allow(clippy::large_enum_variant)]
+#[allow(clippy::large_enum_variant, variant_size_differences)]
 #[derive(Debug)]
 pub enum IteratedSolution<'solver> {
     /// A new solution was identified.
@@ -102,9 +167,23 @@ pub enum IteratedSolution<'solver> {
     /// No more solutions exist.
     Finished,
 
-    /// The solver was terminated during search.
-    Unknown,
+    /// The solver was terminated during search; the [`TerminationReason`] describes why.
+    Unknown(TerminationReason),
 
     /// There exists no solution
     Unsatisfiable,
 }
+
+/// The number of solutions counted by [`SolutionIterator::count_remaining`] or
+/// [`SolutionIterator::count_up_to`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolutionCount {
+    /// All solutions were counted.
+    Exact(u64),
+
+    /// The cap was reached; there are at least this many solutions.
+    AtLeast(u64),
+
+    /// The solver was terminated during search after counting this many solutions.
+    Unknown(u64),
+}
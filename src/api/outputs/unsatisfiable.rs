@@ -1,6 +1,9 @@
 //! Contains the representation of a unsatisfiable solution.
 
+use std::num::NonZero;
+
 use crate::branching::Brancher;
+use crate::engine::variables::Literal;
 use crate::engine::ConstraintSatisfactionSolver;
 #[cfg(doc)]
 use crate::Solver;
@@ -14,6 +17,45 @@ pub struct UnsatisfiableUnderAssumptions<'solver, 'brancher, B: Brancher> {
     pub(crate) brancher: &'brancher mut B,
 }
 
+/// An explanation of why a problem is unsatisfiable in terms of the constraints which were posted
+/// to the [`Solver`]; see [`Solver::explain_unsat`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnsatExplanation {
+    /// The tags of the constraints which participate in the final conflict, in increasing order.
+    pub tags: Vec<NonZero<u32>>,
+    /// The name of the constraint with the tag at the same position in `tags`, if one was attached
+    /// using [`Solver::name_constraint`].
+    pub names: Vec<Option<String>>,
+}
+
+/// Options which determine how a core is extracted by
+/// [`UnsatisfiableUnderAssumptions::extract_core_options`].
+#[derive(Clone, Copy, Debug)]
+pub struct CoreExtractionOptions {
+    /// Whether literals which occur multiple times in the core are only reported once.
+    pub deduplicate: bool,
+    /// Whether the core is made (subset-)minimal using deletion-based minimisation; i.e. for every
+    /// literal in the core, the solver checks whether the remaining assumptions are still
+    /// unsatisfiable without it.
+    ///
+    /// Note that this requires the solver to solve under (a subset of) the assumptions again.
+    pub minimise: bool,
+    /// The maximum number of conflicts which can be spent on minimising the core; if the budget
+    /// is exhausted, then the core which has been minimised up to that point is returned. If it
+    /// is [`None`], then there is no limit.
+    pub minimisation_conflict_budget: Option<u64>,
+}
+
+impl Default for CoreExtractionOptions {
+    fn default() -> Self {
+        CoreExtractionOptions {
+            deduplicate: true,
+            minimise: false,
+            minimisation_conflict_budget: None,
+        }
+    }
+}
+
 impl<'solver, 'brancher, B: Brancher> UnsatisfiableUnderAssumptions<'solver, 'brancher, B> {
     pub fn new(
         solver: &'solver mut ConstraintSatisfactionSolver,
@@ -21,6 +63,28 @@ impl<'solver, 'brancher, B: Brancher> UnsatisfiableUnderAssumptions<'solver, 'br
     ) -> Self {
         UnsatisfiableUnderAssumptions { solver, brancher }
     }
+
+    /// Extracts an unsatisfiable core in terms of the assumptions; the core is returned as a
+    /// clause, i.e. it consists of the negations of the assumptions which together are
+    /// unsatisfiable.
+    ///
+    /// If an assumption is already false at the root, then the core consists only of (the negation
+    /// of) that assumption. This uses the default [`CoreExtractionOptions`], see
+    /// [`UnsatisfiableUnderAssumptions::extract_core_options`] to configure the extraction.
+    pub fn extract_core(&mut self) -> Box<[Literal]> {
+        self.extract_core_options(CoreExtractionOptions::default())
+    }
+
+    /// Extracts an unsatisfiable core in terms of the assumptions according to the provided
+    /// [`CoreExtractionOptions`]; see [`UnsatisfiableUnderAssumptions::extract_core`].
+    ///
+    /// Note that minimising the core resets the state of the solver, which means that the core
+    /// can only be extracted once when [`CoreExtractionOptions::minimise`] is set.
+    pub fn extract_core_options(&mut self, options: CoreExtractionOptions) -> Box<[Literal]> {
+        self.solver
+            .extract_clausal_core(options, self.brancher)
+            .into()
+    }
 }
 
 impl<B: Brancher> Drop for UnsatisfiableUnderAssumptions<'_, '_, B> {
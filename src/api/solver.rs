@@ -1,31 +1,69 @@
+use std::io;
+use std::io::Write;
 use std::num::NonZero;
 
+use super::results::BoundedSolveResult;
 use super::results::OptimisationResult;
 use super::results::SatisfactionResult;
 use super::results::SatisfactionResultUnderAssumptions;
+use crate::api::optimisation::lns::LnsPolisher;
+use crate::api::optimisation::BoundEventLogger;
+use crate::api::optimisation::OptimisationStrategy;
 use crate::basic_types::CSPSolverExecutionFlag;
 use crate::basic_types::ConstraintOperationError;
+use crate::basic_types::HashMap;
 use crate::basic_types::HashSet;
+use crate::basic_types::Random;
 use crate::basic_types::Solution;
+use crate::basic_types::ViolatedConstraint;
+#[cfg(doc)]
+use crate::branching::branchers::propagator_guided_brancher::PropagatorGuidedBrancher;
+use crate::branching::branchers::propagator_guided_brancher::PropagatorRegistry;
 #[cfg(doc)]
 use crate::branching::value_selection::ValueSelector;
 #[cfg(doc)]
 use crate::branching::variable_selection::VariableSelector;
 use crate::branching::Brancher;
+use crate::constraints::Constraint;
+use crate::constraints::ConstraintHandle;
 use crate::constraints::ConstraintPoster;
+use crate::engine::conflict_analysis::ConflictResolver;
+#[cfg(doc)]
+use crate::engine::constraint_satisfaction_solver::ConflictResolutionStrategy;
+use crate::engine::cp::propagation::propagation_context::HasAssignments;
 use crate::engine::cp::propagation::Propagator;
+use crate::engine::predicates::bound::Bound;
+use crate::engine::predicates::integer_predicate::IntegerPredicate;
+use crate::engine::predicates::named_display::VariableNameLookup;
 use crate::engine::predicates::predicate::Predicate;
 use crate::engine::termination::TerminationCondition;
 use crate::engine::variables::DomainId;
 use crate::engine::variables::IntegerVariable;
 use crate::engine::variables::Literal;
 use crate::engine::ConstraintSatisfactionSolver;
+use crate::engine::SnapshotId;
 use crate::munchkin_assert_simple;
 use crate::options::SolverOptions;
+use crate::predicate;
+use crate::proof::TagArtifacts;
 use crate::results::solution_iterator::SolutionIterator;
+#[cfg(doc)]
+use crate::results::unsatisfiable::CoreExtractionOptions;
+use crate::results::unsatisfiable::UnsatExplanation;
 use crate::results::unsatisfiable::UnsatisfiableUnderAssumptions;
+use crate::results::ProblemSolution;
+use crate::results::PropagationProbeResult;
+use crate::results::RootPropagationSummary;
+use crate::results::SolutionCallbackArguments;
+use crate::results::SuspendedSearch;
 use crate::statistics::log_statistic;
 use crate::statistics::log_statistic_postfix;
+use crate::statistics::ModelSummary;
+use crate::statistics::OptimisationIteration;
+use crate::statistics::ProofStatistics;
+use crate::statistics::PropagatorCounters;
+use crate::statistics::SearchStatistics;
+use crate::statistics::StatisticAccumulator;
 
 /// The main interaction point which allows the creation of variables, the addition of constraints,
 /// and solving problems.
@@ -81,20 +119,40 @@ pub struct Solver {
     satisfaction_solver: ConstraintSatisfactionSolver,
     /// The function is called whenever an optimisation function finds a solution; see
     /// [`Solver::with_solution_callback`].
-    solution_callback: Box<dyn Fn(&Solution)>,
+    solution_callback: Box<dyn Fn(SolutionCallbackArguments)>,
+    /// The function is called with the solution which is returned by an optimisation function;
+    /// see [`Solver::with_final_solution_callback`].
+    final_solution_callback: Box<dyn Fn(SolutionCallbackArguments)>,
+    /// The function is called at the end of every iteration of an optimisation function; see
+    /// [`Solver::with_iteration_callback`].
+    iteration_callback: Box<dyn Fn(&OptimisationIteration)>,
+    /// The names which are attached to the tags of the constraints; see
+    /// [`Solver::name_constraint`].
+    constraint_names: HashMap<NonZero<u32>, String>,
+    /// Polishes every improving solution of an optimisation function; see
+    /// [`Solver::with_solution_polisher`].
+    solution_polisher: Option<LnsPolisher>,
+    /// The number of searches which have been suspended; this identifies the currently suspended
+    /// search (see [`Solver::solve_bounded`]).
+    num_suspended_searches: u64,
 }
 
 impl Default for Solver {
     fn default() -> Self {
         Self {
             satisfaction_solver: Default::default(),
-            solution_callback: create_empty_function(),
+            solution_callback: Box::new(|_| {}),
+            final_solution_callback: Box::new(|_| {}),
+            iteration_callback: create_empty_function(),
+            constraint_names: HashMap::default(),
+            solution_polisher: None,
+            num_suspended_searches: 0,
         }
     }
 }
 
-/// Creates a place-holder empty function which does not do anything when a solution is found.
-fn create_empty_function() -> Box<dyn Fn(&Solution)> {
+/// Creates a place-holder empty function which does not do anything when it is called.
+fn create_empty_function<T: ?Sized>() -> Box<dyn Fn(&T)> {
     Box::new(|_| {})
 }
 
@@ -111,17 +169,56 @@ impl Solver {
     pub fn with_options(solver_options: SolverOptions) -> Self {
         Solver {
             satisfaction_solver: ConstraintSatisfactionSolver::new(solver_options),
-            solution_callback: create_empty_function(),
+            solution_callback: Box::new(|_| {}),
+            final_solution_callback: Box::new(|_| {}),
+            iteration_callback: create_empty_function(),
+            constraint_names: HashMap::default(),
+            solution_polisher: None,
+            num_suspended_searches: 0,
         }
     }
 
-    pub fn with_options_and_conflict_resolver(solver_options: SolverOptions) -> Self {
+    /// Creates a solver with the provided [`SolverOptions`] which resolves conflicts using
+    /// `conflict_resolver` rather than the resolver corresponding to
+    /// [`SolverOptions::conflict_resolver`].
+    ///
+    /// Note that the strategy in the options still determines whether the solver restarts, since
+    /// restarts are not performed with [`ConflictResolutionStrategy::NoLearning`].
+    ///
+    /// # Example
+    /// ```
+    /// # use munchkin::conflict_resolution::AllDecisionLearning;
+    /// # use munchkin::options::SolverOptions;
+    /// # use munchkin::Solver;
+    /// let solver = Solver::with_options_and_conflict_resolver(
+    ///     SolverOptions::default(),
+    ///     Box::new(AllDecisionLearning::default()),
+    /// );
+    /// ```
+    pub fn with_options_and_conflict_resolver(
+        solver_options: SolverOptions,
+        conflict_resolver: Box<dyn ConflictResolver>,
+    ) -> Self {
         Solver {
-            satisfaction_solver: ConstraintSatisfactionSolver::new(solver_options),
-            solution_callback: create_empty_function(),
+            satisfaction_solver: ConstraintSatisfactionSolver::with_conflict_resolver(
+                solver_options,
+                conflict_resolver,
+            ),
+            solution_callback: Box::new(|_| {}),
+            final_solution_callback: Box::new(|_| {}),
+            iteration_callback: create_empty_function(),
+            constraint_names: HashMap::default(),
+            solution_polisher: None,
+            num_suspended_searches: 0,
         }
     }
 
+    /// Returns the [`SolverOptions`] with which the solver was created; see
+    /// [`SolverOptions::builder`] for creating options which are consistent with each other.
+    pub fn options(&self) -> &SolverOptions {
+        self.satisfaction_solver.options()
+    }
+
     /// Conclude the proof with the given bound on the objective variable.
     pub(crate) fn conclude_proof_optimal(&mut self, bound: Literal) {
         self.satisfaction_solver.conclude_proof_optimal(bound);
@@ -137,17 +234,201 @@ impl Solver {
     ///
     /// Note that this will also
     /// perform the call-back on the optimal solution which is returned in
-    /// [`OptimisationResult::Optimal`].
-    pub fn with_solution_callback(&mut self, solution_callback: impl Fn(&Solution) + 'static) {
+    /// [`OptimisationResult::Optimal`]; however, at that point it is not yet known that the
+    /// solution is optimal, so [`SolutionCallbackArguments::is_final`] is always `false`.
+    pub fn with_solution_callback(
+        &mut self,
+        solution_callback: impl Fn(SolutionCallbackArguments) + 'static,
+    ) {
         self.solution_callback = Box::new(solution_callback);
     }
 
+    /// Adds a call-back to the [`Solver`] which is called once when an optimisation function (e.g.
+    /// [`Solver::minimise`]) returns a solution, i.e. with the solution in
+    /// [`OptimisationResult::Optimal`] or [`OptimisationResult::Satisfiable`]. The
+    /// [`SolutionCallbackArguments::is_final`] flag is always `true`.
+    ///
+    /// In contrast to [`Solver::with_solution_callback`], this call-back is not called for the
+    /// intermediate solutions; this avoids the overhead of reporting every improving solution
+    /// when only the best solution is of interest.
+    pub fn with_final_solution_callback(
+        &mut self,
+        final_solution_callback: impl Fn(SolutionCallbackArguments) + 'static,
+    ) {
+        self.final_solution_callback = Box::new(final_solution_callback);
+    }
+
+    /// Adds a call-back to the [`Solver`] which is called at the end of every iteration of
+    /// [`Solver::maximise`] or [`Solver::minimise`] (i.e. every time that the underlying search
+    /// finishes) with the bounds on the objective at that point.
+    ///
+    /// The iterations are also logged as statistics if [`SolverOptions::verbose_optimisation`] is
+    /// set.
+    pub fn with_iteration_callback(
+        &mut self,
+        iteration_callback: impl Fn(&OptimisationIteration) + 'static,
+    ) {
+        self.iteration_callback = Box::new(iteration_callback);
+    }
+
+    /// Adds an [`LnsPolisher`] to the [`Solver`] which is run after every improving solution which
+    /// is found when optimising using [`Solver::maximise`] or [`Solver::minimise`].
+    ///
+    /// If the polisher improves the solution, then the improved solution is reported to the
+    /// solution callback (see [`Solver::with_solution_callback`]) and the search continues from it.
+    pub fn with_solution_polisher(&mut self, polisher: LnsPolisher) {
+        self.solution_polisher = Some(polisher);
+    }
+
     /// Logs the statistics currently present in the solver with the provided objective value.
     pub fn log_statistics_with_objective(&self, objective_value: i64) {
         log_statistic("objective", objective_value);
         self.log_statistics();
     }
 
+    /// Returns a snapshot of the search effort counters (e.g. the number of conflicts and
+    /// decisions) of the solver.
+    ///
+    /// The counters are updated live during search, which makes this suitable for adaptive
+    /// procedures which decide on their next step based on the effort spent so far.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use munchkin::Solver;
+    /// let solver = Solver::default();
+    ///
+    /// let statistics = solver.statistics();
+    /// assert_eq!(statistics.conflicts, 0);
+    /// ```
+    pub fn statistics(&self) -> SearchStatistics {
+        self.satisfaction_solver.statistics()
+    }
+
+    /// Returns the [`ProofStatistics`] (e.g. the number of steps and bytes written) of the proof
+    /// which is being logged, or [`None`] if no proof is being logged.
+    pub fn proof_statistics(&self) -> Option<ProofStatistics> {
+        self.satisfaction_solver.proof_statistics()
+    }
+
+    /// Returns the [`TagArtifacts`] (i.e. the clauses, propagators and root unit literals) which
+    /// were created by posting the constraints with the provided `tag`; this relates the
+    /// constraint hints in a proof to the parts of the solver which are responsible for them.
+    ///
+    /// The artifacts are only recorded while a proof is logged, otherwise [`None`] is returned.
+    pub fn tag_artifacts(&self, tag: NonZero<u32>) -> Option<&TagArtifacts> {
+        self.satisfaction_solver.tag_artifacts(tag)
+    }
+
+    /// Runs `post` while recording the created artifacts under `tag`; see
+    /// [`Solver::tag_artifacts`].
+    pub(crate) fn post_with_tag<T>(
+        &mut self,
+        tag: NonZero<u32>,
+        post: impl FnOnce(&mut Solver) -> T,
+    ) -> T {
+        let previous_tag = self.satisfaction_solver.set_posting_tag(Some(tag));
+        let result = post(self);
+        let _ = self.satisfaction_solver.set_posting_tag(previous_tag);
+        result
+    }
+
+    /// Returns the statistic which keeps track of the number of literals which were removed from
+    /// the cores minimised using [`CoreExtractionOptions::minimise`]; a term is added for every
+    /// minimised core.
+    pub fn get_minimisation_statistics(&mut self) -> &mut StatisticAccumulator {
+        self.satisfaction_solver.get_minimisation_statistics()
+    }
+
+    /// Returns the name and [`PropagatorCounters`] of every propagator in the solver, sorted by
+    /// the time spent in the propagator in descending order.
+    ///
+    /// Note that the time is only measured if [`SolverOptions::time_propagators`] is set.
+    pub fn propagator_statistics(&self) -> Vec<(&str, PropagatorCounters)> {
+        self.satisfaction_solver.propagator_statistics()
+    }
+
+    /// The number of integer variables in the solver.
+    pub fn num_integer_variables(&self) -> usize {
+        self.satisfaction_solver.assignments_integer.num_domains() as usize
+    }
+
+    /// The number of propositional variables in the solver; this includes the propositional
+    /// variables which encode the domains of the integer variables (see [`ModelSummary`] for the
+    /// distinction).
+    pub fn num_propositional_variables(&self) -> usize {
+        self.satisfaction_solver
+            .assignments_propositional
+            .num_propositional_variables() as usize
+    }
+
+    /// The number of propagators in the solver.
+    pub fn num_propagators(&self) -> usize {
+        self.satisfaction_solver.num_propagators()
+    }
+
+    /// The number of clauses stored in the solver, both the original and the learned clauses.
+    pub fn num_clauses(&self) -> usize {
+        let summary = self.model_summary();
+        summary.num_original_clauses + summary.num_learned_clauses
+    }
+
+    /// Returns a summary of the problem in the solver, which can be displayed before solving.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use munchkin::Solver;
+    /// # use munchkin::constraints;
+    /// # use std::num::NonZero;
+    /// let mut solver = Solver::default();
+    ///
+    /// let x = solver.new_bounded_integer(0, 5);
+    /// let y = solver.new_bounded_integer(0, 5);
+    /// let _ = solver
+    ///     .add_constraint(constraints::binary_less_than(x, y, 0))
+    ///     .post(NonZero::new(1).unwrap());
+    ///
+    /// let summary = solver.model_summary();
+    /// assert_eq!(summary.num_integer_variables, 2);
+    /// assert_eq!(summary.num_propagators(), 1);
+    /// println!("{summary}");
+    /// ```
+    pub fn model_summary(&self) -> ModelSummary {
+        self.satisfaction_solver.model_summary()
+    }
+
+    /// Writes what the posted constraints were lowered to: every propagator with the tag (and
+    /// the name, see [`Solver::name_constraint`]) of the constraint it was posted for, in the
+    /// order in which they were added, followed by the number of original clauses of every
+    /// length. Consecutive propagators with the same name and tag are listed once, together
+    /// with how many of them there are.
+    ///
+    /// The output is deterministic, such that it can be compared against
+    /// [`Model::dump`](crate::model::Model::dump) line by line.
+    pub fn dump_posted(&self, mut writer: impl Write) -> io::Result<()> {
+        let mut propagators = self.satisfaction_solver.tagged_propagators().peekable();
+        while let Some((name, tag)) = propagators.next() {
+            let mut count = 1;
+            while propagators.next_if_eq(&(name, tag)).is_some() {
+                count += 1;
+            }
+
+            write!(writer, "propagator {name}; % tag {tag}")?;
+            if let Some(constraint_name) = self.constraint_names.get(&tag) {
+                write!(writer, " ({constraint_name})")?;
+            }
+            if count > 1 {
+                write!(writer, ", {count} times")?;
+            }
+            writeln!(writer)?;
+        }
+
+        for (length, count) in self.satisfaction_solver.original_clause_length_histogram() {
+            writeln!(writer, "clauses of length {length}: {count}")?;
+        }
+
+        Ok(())
+    }
+
     /// Logs the statistics currently present in the solver.
     pub fn log_statistics(&self) {
         self.satisfaction_solver.log_statistics();
@@ -184,6 +465,91 @@ impl Solver {
         self.satisfaction_solver.get_literal(predicate)
     }
 
+    /// Returns the literals `[variable == v]` for the provided `values`; i.e. the `i`-th literal is
+    /// true if and only if `variable` is assigned to the `i`-th (distinct) value.
+    ///
+    /// The literals are the ones which are used internally to represent the domain of `variable`,
+    /// so no propositional variables or clauses are created. Duplicated values only occur once in
+    /// the result (in the order of their first occurrence), and values outside of the initial
+    /// domain of `variable` are mapped to the false literal.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use munchkin::Solver;
+    /// let mut solver = Solver::default();
+    ///
+    /// let x = solver.new_bounded_integer(0, 10);
+    /// let indicators = solver.channel_indicators(x, &[1, 5, 5, 11]);
+    ///
+    /// assert_eq!(indicators.len(), 3);
+    /// assert_eq!(indicators[2], solver.get_false_literal());
+    /// ```
+    pub fn channel_indicators(&self, variable: DomainId, values: &[i32]) -> Vec<Literal> {
+        let mut seen: HashSet<i32> = HashSet::default();
+
+        values
+            .iter()
+            .filter(|&&value| seen.insert(value))
+            .map(|&value| self.get_literal(predicate![variable == value]))
+            .collect()
+    }
+
+    /// Get the [`IntegerPredicate`]s which are represented by the given [`Literal`].
+    ///
+    /// The polarity of the literal is respected; the predicates of `!literal` are the negations of
+    /// the predicates of `literal`. Literals which are not linked to an integer variable (e.g.
+    /// those created using [`Solver::new_literal`]) do not have any predicates.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use munchkin::Solver;
+    /// # use munchkin::predicate;
+    /// # use munchkin::predicates::IntegerPredicate;
+    /// let mut solver = Solver::default();
+    ///
+    /// let x = solver.new_bounded_integer(0, 10);
+    /// let literal = solver.get_literal(predicate!(x >= 3));
+    ///
+    /// let predicates = solver.get_predicates(!literal).collect::<Vec<_>>();
+    /// assert!(predicates.contains(&predicate!(x <= 2).try_into().unwrap()));
+    /// ```
+    pub fn get_predicates(&self, literal: Literal) -> impl Iterator<Item = IntegerPredicate> + '_ {
+        self.satisfaction_solver.get_predicates(literal)
+    }
+
+    /// Get the most specific [`IntegerPredicate`] which is represented by the given [`Literal`], or
+    /// [`None`] if the literal does not represent any predicate.
+    ///
+    /// A literal can represent multiple equivalent predicates. For example, over the domain `[0,
+    /// 10]`, the literal for `[x >= 1]` also represents `[x != 0]`, and the literal for `[x >= 10]`
+    /// also represents `[x == 10]`. The predicate is chosen using the following priority:
+    /// 1. An equality predicate (`[x == v]`).
+    /// 2. A bound predicate (`[x >= v]` or `[x <= v]`).
+    /// 3. A disequality predicate (`[x != v]`).
+    ///
+    /// As a result, `get_canonical_predicate(get_literal(p))` returns either `p` or a predicate
+    /// which is equivalent to `p` given the initial domain of the variable. Note that the true and
+    /// false literals are shared between all variables, so the returned predicate for those is
+    /// arbitrary.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use munchkin::Solver;
+    /// # use munchkin::predicate;
+    /// let mut solver = Solver::default();
+    ///
+    /// let x = solver.new_bounded_integer(0, 10);
+    ///
+    /// let literal = solver.get_literal(predicate!(x != 0));
+    /// assert_eq!(
+    ///     solver.get_canonical_predicate(literal),
+    ///     Some(predicate!(x >= 1).try_into().unwrap())
+    /// );
+    /// ```
+    pub fn get_canonical_predicate(&self, literal: Literal) -> Option<IntegerPredicate> {
+        self.satisfaction_solver.get_canonical_predicate(literal)
+    }
+
     /// Get the value of the given [`Literal`] at the root level (after propagation), which could be
     /// unassigned.
     pub fn get_literal_value(&self, literal: Literal) -> Option<bool> {
@@ -209,6 +575,76 @@ impl Solver {
     pub fn upper_bound(&self, variable: &impl IntegerVariable) -> i32 {
         self.satisfaction_solver.get_upper_bound(variable)
     }
+
+    /// Get the lower-bound of the given [`IntegerVariable`] at the root level, regardless of the
+    /// current decision level.
+    ///
+    /// During search (e.g. in a solution callback), [`Solver::lower_bound`] returns the bound at
+    /// the current decision level, whereas this returns the bound which is proven for the
+    /// remainder of the search.
+    pub fn root_lower_bound(&self, variable: &impl IntegerVariable) -> i32 {
+        self.satisfaction_solver.get_root_lower_bound(variable)
+    }
+
+    /// Get the upper-bound of the given [`IntegerVariable`] at the root level, regardless of the
+    /// current decision level; see [`Solver::root_lower_bound`].
+    pub fn root_upper_bound(&self, variable: &impl IntegerVariable) -> i32 {
+        self.satisfaction_solver.get_root_upper_bound(variable)
+    }
+
+    /// Returns the facts about the provided `variables` which are entailed at the root (i.e. at
+    /// decision level 0) but do not follow from their initial domains: the strengthened bounds and
+    /// the values which were removed from the domains. These can be transferred to another solver
+    /// which shares these variables using [`Solver::import_facts`].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use munchkin::Solver;
+    /// # use munchkin::constraints;
+    /// # use munchkin::predicate;
+    /// # use std::num::NonZero;
+    /// let mut solver = Solver::default();
+    /// let x = solver.new_bounded_integer(0, 10);
+    /// let y = solver.new_bounded_integer(0, 10);
+    /// let _ = solver
+    ///     .add_constraint(constraints::sum([x, y]).greater_than_or_equals(15))
+    ///     .post(NonZero::new(1).unwrap());
+    ///
+    /// assert_eq!(
+    ///     solver.export_learned_unit_facts(&[x, y]),
+    ///     vec![predicate![x >= 5], predicate![y >= 5]]
+    /// );
+    /// ```
+    pub fn export_learned_unit_facts(&self, variables: &[DomainId]) -> Vec<Predicate> {
+        munchkin_assert_simple!(
+            self.satisfaction_solver.get_decision_level() == 0,
+            "Facts can only be exported at the root"
+        );
+
+        let assignments = self.satisfaction_solver.get_integer_assignments();
+        let mut facts = vec![];
+
+        for &variable in variables {
+            let lower_bound = assignments.get_lower_bound(variable);
+            let upper_bound = assignments.get_upper_bound(variable);
+
+            if lower_bound > assignments.get_initial_lower_bound(variable) {
+                facts.push(predicate![variable >= lower_bound]);
+            }
+            if upper_bound < assignments.get_initial_upper_bound(variable) {
+                facts.push(predicate![variable <= upper_bound]);
+            }
+
+            facts.extend(
+                assignments
+                    .get_holes(variable)
+                    .filter(|&value| assignments.is_value_in_initial_domain(variable, value))
+                    .map(|value| predicate![variable != value]),
+            );
+        }
+
+        facts
+    }
 }
 
 /// Functions to create and retrieve integer and propositional variables.
@@ -232,6 +668,25 @@ impl Solver {
         std::iter::from_fn(|| Some(self.new_literal()))
     }
 
+    /// Creates `num_literals` fresh propositional variables with consecutive indices and returns
+    /// their literals with positive polarity.
+    ///
+    /// Unlike [`Solver::new_literals`], which creates the variables one at a time, the space for
+    /// the variables is reserved at once; this is preferable when creating many auxiliary literals
+    /// (e.g. for an encoding).
+    ///
+    /// # Example
+    /// ```
+    /// # use munchkin::Solver;
+    /// let mut solver = Solver::default();
+    /// let literals = solver.new_literal_batch(5);
+    ///
+    /// assert_eq!(literals.len(), 5);
+    /// ```
+    pub fn new_literal_batch(&mut self, num_literals: usize) -> Vec<Literal> {
+        self.satisfaction_solver.new_literal_batch(num_literals)
+    }
+
     /// Create a fresh propositional variable and return the literal with positive polarity.
     ///
     /// # Example
@@ -271,6 +726,9 @@ impl Solver {
 
     /// Create a new integer variable with the given bounds.
     ///
+    /// Panics if `lower_bound > upper_bound`, or if the width of the domain (`upper_bound -
+    /// lower_bound`) cannot be represented by an `i32`.
+    ///
     /// # Example
     /// ```rust
     /// # use munchkin::Solver;
@@ -284,8 +742,32 @@ impl Solver {
             .create_new_integer_variable(lower_bound, upper_bound, None)
     }
 
+    /// Returns a 0-1 integer variable which is 1 if and only if `literal` is true; this allows
+    /// literals to be used in constraints over integer variables (e.g. in linear constraints).
+    ///
+    /// The integer variable is represented by the provided `literal` itself, i.e. no additional
+    /// propositional variables or channelling clauses are created for it.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use munchkin::Solver;
+    /// let mut solver = Solver::default();
+    ///
+    /// let literal = solver.new_literal();
+    /// let integer = solver.literal_as_integer(literal);
+    ///
+    /// assert_eq!(solver.lower_bound(&integer), 0);
+    /// assert_eq!(solver.upper_bound(&integer), 1);
+    /// ```
+    pub fn literal_as_integer(&mut self, literal: Literal) -> DomainId {
+        self.satisfaction_solver
+            .create_new_integer_variable_for_literal(literal)
+    }
+
     /// Create a new named integer variable with the given bounds.
     ///
+    /// Panics under the same conditions as [`Solver::new_bounded_integer`].
+    ///
     /// # Example
     /// ```rust
     /// # use munchkin::Solver;
@@ -307,8 +789,8 @@ impl Solver {
         )
     }
 
-    /// Create a new integer variable which has a domain of predefined values. We remove duplicates
-    /// by converting to a hash set
+    /// Create a new integer variable which has a domain of predefined values. Duplicate values are
+    /// ignored.
     ///
     /// # Example
     /// ```rust
@@ -319,10 +801,8 @@ impl Solver {
     /// let mut sparse_integer = solver.new_sparse_integer(vec![0, 3, 5]);
     /// ```
     pub fn new_sparse_integer(&mut self, values: impl Into<Vec<i32>>) -> DomainId {
-        let values: HashSet<i32> = values.into().into_iter().collect();
-
         self.satisfaction_solver
-            .create_new_integer_variable_sparse(values.into_iter().collect(), None)
+            .create_new_integer_variable_sparse(values.into(), None)
     }
 
     /// Create a new named integer variable which has a domain of predefined values.
@@ -358,6 +838,8 @@ impl Solver {
         match self.satisfaction_solver.solve(termination, brancher) {
             CSPSolverExecutionFlag::Feasible => {
                 let solution: Solution = self.satisfaction_solver.get_solution_reference().into();
+                Self::debug_check_variables_are_fixed(&solution);
+                self.debug_verify_solution(&solution);
                 self.satisfaction_solver.restore_state_at_root(brancher);
                 brancher.on_solution(solution.as_reference());
                 SatisfactionResult::Satisfiable(solution)
@@ -370,11 +852,117 @@ impl Solver {
             CSPSolverExecutionFlag::Timeout => {
                 // Reset the state whenever we return a result
                 self.satisfaction_solver.restore_state_at_root(brancher);
-                SatisfactionResult::Unknown
+                SatisfactionResult::Unknown(termination.reason().unwrap_or_default())
+            }
+        }
+    }
+
+    /// Solves the current model in the [`Solver`] like [`Solver::satisfy`], but suspends the
+    /// search once `decision_limit` decisions have been made (or once the provided
+    /// [`TerminationCondition`] triggers), returning [`BoundedSolveResult::Suspended`].
+    ///
+    /// A suspended search is not restored to the root; it can be continued from the exact state in
+    /// which it was suspended using [`Solver::resume_bounded`], which allows orchestrating the
+    /// search (e.g. interleaving it with other solvers) outside of the [`Solver`]. See
+    /// [`SuspendedSearch`] for the invariants which hold while a search is suspended. If another
+    /// search is suspended when this method is called, then that search is abandoned.
+    pub fn solve_bounded<B: Brancher, T: TerminationCondition>(
+        &mut self,
+        brancher: &mut B,
+        termination: &mut T,
+        decision_limit: u64,
+    ) -> BoundedSolveResult {
+        self.satisfaction_solver.restore_state_at_root(brancher);
+
+        let num_decisions_at_start = self.satisfaction_solver.statistics().decisions;
+        self.solve_with_decision_limit(
+            brancher,
+            termination,
+            decision_limit,
+            num_decisions_at_start,
+        )
+    }
+
+    /// Continues the `suspended_search` for at most `decision_limit` further decisions; apart
+    /// from the decisions which are made across the calls, this behaves the same as a single call
+    /// to [`Solver::solve_bounded`] with the combined decision limit. The provided `brancher`
+    /// should be the brancher with which the search was started.
+    ///
+    /// # Panics
+    /// If the search has been abandoned since it was suspended (see [`SuspendedSearch`]).
+    pub fn resume_bounded<B: Brancher, T: TerminationCondition>(
+        &mut self,
+        suspended_search: SuspendedSearch,
+        brancher: &mut B,
+        termination: &mut T,
+        decision_limit: u64,
+    ) -> BoundedSolveResult {
+        assert!(
+            self.satisfaction_solver.is_suspended()
+                && suspended_search.suspension_id == self.num_suspended_searches,
+            "the suspended search has been abandoned"
+        );
+
+        self.solve_with_decision_limit(
+            brancher,
+            termination,
+            decision_limit,
+            suspended_search.num_decisions_at_start,
+        )
+    }
+
+    /// Abandons the `suspended_search` by restoring the solver to the root, after which
+    /// constraints can be added again.
+    pub fn abandon_bounded(
+        &mut self,
+        suspended_search: SuspendedSearch,
+        brancher: &mut impl Brancher,
+    ) {
+        if suspended_search.suspension_id == self.num_suspended_searches {
+            self.satisfaction_solver.restore_state_at_root(brancher);
+        }
+    }
+
+    fn solve_with_decision_limit<B: Brancher, T: TerminationCondition>(
+        &mut self,
+        brancher: &mut B,
+        termination: &mut T,
+        decision_limit: u64,
+        num_decisions_at_start: u64,
+    ) -> BoundedSolveResult {
+        match self.satisfaction_solver.solve_with_decision_budget(
+            decision_limit,
+            termination,
+            brancher,
+        ) {
+            CSPSolverExecutionFlag::Feasible => {
+                let solution: Solution = self.satisfaction_solver.get_solution_reference().into();
+                Self::debug_check_variables_are_fixed(&solution);
+                self.debug_verify_solution(&solution);
+                self.satisfaction_solver.restore_state_at_root(brancher);
+                brancher.on_solution(solution.as_reference());
+                BoundedSolveResult::Satisfiable(solution)
+            }
+            CSPSolverExecutionFlag::Infeasible => {
+                self.satisfaction_solver.restore_state_at_root(brancher);
+                BoundedSolveResult::Unsatisfiable
+            }
+            CSPSolverExecutionFlag::Timeout => {
+                self.num_suspended_searches += 1;
+                BoundedSolveResult::Suspended(SuspendedSearch {
+                    suspension_id: self.num_suspended_searches,
+                    num_decisions_at_start,
+                    num_decisions: self.satisfaction_solver.statistics().decisions,
+                })
             }
         }
     }
 
+    /// Returns a [`SolutionIterator`] which can be used to retrieve multiple solutions of the
+    /// satisfaction problem.
+    ///
+    /// When the iterator is dropped, the solver is restored to the root and the solutions which
+    /// were blocked by the iterator remain blocked; see [`SolutionIterator`].
     pub fn get_solution_iterator<
         'this,
         'brancher,
@@ -396,7 +984,15 @@ impl Solver {
     /// This method takes as input a list of [`Literal`]s which represent so-called assumptions (see
     /// \[1\] for a more detailed explanation). The [`Literal`]s corresponding to [`Predicate`]s
     /// over [`IntegerVariable`]s (e.g. lower-bound predicates) can be retrieved from the [`Solver`]
-    /// using [`Solver::get_literal`].
+    /// using [`Solver::get_literal`]; for a [`Predicate::Literal`] this returns the literal itself
+    /// with the same polarity.
+    ///
+    /// An assumption which is true at the root (e.g. the literal of [`Predicate::True`]) is
+    /// skipped, whereas an assumption which is false at the root (e.g. the literal of
+    /// [`Predicate::False`]) immediately makes the problem unsatisfiable under the assumptions, with
+    /// that assumption as the core. Note that a core is returned as a clause, i.e. it contains the
+    /// _negations_ of the responsible assumptions (see
+    /// [`UnsatisfiableUnderAssumptions::extract_core`]).
     ///
     /// # Bibliography
     /// \[1\] N. Eén and N. Sörensson, ‘Temporal induction by incremental SAT solving’, Electronic
@@ -437,86 +1033,411 @@ impl Solver {
             CSPSolverExecutionFlag::Timeout => {
                 // Reset the state whenever we return a result
                 self.satisfaction_solver.restore_state_at_root(brancher);
-                SatisfactionResultUnderAssumptions::Unknown
+                SatisfactionResultUnderAssumptions::Unknown(
+                    termination.reason().unwrap_or_default(),
+                )
             }
         }
     }
 
-    /// Solves the model currently in the [`Solver`] to optimality where the provided
-    /// `objective_variable` is minimised (or is indicated to terminate by the provided
-    /// [`TerminationCondition`]).
+    /// Determines whether the provided assumptions are consistent with the model according to
+    /// propagation alone; no search is performed and no clauses are learned. This is a cheap,
+    /// incomplete check which is useful for e.g. probing, or for checking whether a candidate
+    /// hitting set is consistent with the cores found so far.
     ///
-    /// It returns an [`OptimisationResult`] which can be used to retrieve the optimal solution if
-    /// it exists.
-    pub fn minimise(
-        &mut self,
-        brancher: &mut impl Brancher,
-        termination: &mut impl TerminationCondition,
-        objective_variable: impl IntegerVariable,
-    ) -> OptimisationResult {
-        self.minimise_internal(brancher, termination, objective_variable, false)
-    }
-
-    /// Solves the model currently in the [`Solver`] to optimality where the provided
-    /// `objective_variable` is maximised (or is indicated to terminate by the provided
-    /// [`TerminationCondition`]).
+    /// Every assumption is enqueued as a decision, after which the clauses and propagators are
+    /// propagated to a fixed point. If this leads to a conflict, then
+    /// [`PropagationProbeResult::Conflict`] is returned with the assumptions which are responsible
+    /// for it; otherwise, [`PropagationProbeResult::Consistent`] is returned with the literals
+    /// which are implied by the assumptions. In both cases, the state of the [`Solver`] is
+    /// restored to the state before the call.
     ///
-    /// It returns an [`OptimisationResult`] which can be used to retrieve the optimal solution if
-    /// it exists.
-    pub fn maximise(
+    /// # Example
+    /// ```rust
+    /// # use munchkin::Solver;
+    /// # use munchkin::results::PropagationProbeResult;
+    /// let mut solver = Solver::default();
+    /// let a = solver.new_literal();
+    /// let b = solver.new_literal();
+    /// let c = solver.new_literal();
+    /// let _ = solver.add_clause([!a, b]);
+    /// let _ = solver.add_clause([!b, !c]);
+    ///
+    /// assert_eq!(
+    ///     solver.propagate_under_assumptions(&[a]),
+    ///     PropagationProbeResult::Consistent {
+    ///         implied: vec![b, !c]
+    ///     }
+    /// );
+    /// assert_eq!(
+    ///     solver.propagate_under_assumptions(&[c, a]),
+    ///     PropagationProbeResult::Conflict(vec![c, a])
+    /// );
+    /// ```
+    pub fn propagate_under_assumptions(
         &mut self,
-        brancher: &mut impl Brancher,
-        termination: &mut impl TerminationCondition,
-        objective_variable: impl IntegerVariable,
-    ) -> OptimisationResult {
-        self.minimise_internal(brancher, termination, objective_variable.scaled(-1), true)
+        assumptions: &[Literal],
+    ) -> PropagationProbeResult {
+        self.satisfaction_solver
+            .propagate_under_assumptions(assumptions)
     }
 
-    /// The internal method which optimizes the objective function, this function takes an extra
-    /// argument (`is_maximising`) as compared to [`Solver::maximise`] and [`Solver::minimise`]
-    /// which determines whether the logged objective value should be scaled by `-1` or not.
+    /// Propagates the clauses and the constraints in the [`Solver`] to a fixed point at the root
+    /// (i.e. at decision level 0) and returns a [`RootPropagationSummary`] containing the bounds
+    /// of the integer variables which are tighter than the bounds with which they were created and
+    /// the literals which are fixed at the root. This can be called repeatedly, also after adding
+    /// new constraints.
+    ///
+    /// If propagation shows that the problem is infeasible at the root, then an error is returned
+    /// and the [`Solver`] is in an infeasible state (as is the case when adding an infeasible
+    /// clause using [`Solver::add_clause`]); subsequent calls to [`Solver::satisfy`] will report
+    /// that the problem is unsatisfiable.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use munchkin::prelude::*;
+    /// # use std::num::NonZero;
+    /// let mut solver = Solver::default();
+    /// let x = solver.new_bounded_integer(0, 10);
+    /// let y = solver.new_bounded_integer(0, 10);
+    /// let _ = solver
+    ///     .add_constraint(constraints::less_than_or_equals([x, y], 3))
+    ///     .post(NonZero::new(1).unwrap());
+    ///
+    /// let summary = solver.propagate().expect("the problem is feasible at the root");
+    /// assert_eq!(
+    ///     summary.tightened_bounds,
+    ///     vec![predicate![x <= 3], predicate![y <= 3]]
+    /// );
+    /// ```
+    pub fn propagate(&mut self) -> Result<RootPropagationSummary, ConstraintOperationError> {
+        self.satisfaction_solver.propagate_at_root()
+    }
+
+    /// Propagates to a fixed point at the root (see [`Solver::propagate`]) and compacts the
+    /// propositional representation of the integer variables whose bounds are tighter than the
+    /// bounds with which they were created: the clauses and implications which keep the literals
+    /// of the values outside of these bounds consistent are satisfied at the root, so they are
+    /// removed. This is useful for variables which are created with very loose bounds, such as an
+    /// objective with a trivial upper bound.
+    ///
+    /// This is done automatically after the first root propagation when solving, but it can be
+    /// repeated after adding constraints which tighten the bounds further. The domains are not
+    /// compacted while there are snapshots (see [`Solver::snapshot`]).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use munchkin::prelude::*;
+    /// # use std::num::NonZero;
+    /// let mut solver = Solver::default();
+    /// let x = solver.new_bounded_integer(0, 1000);
+    /// let _ = solver
+    ///     .add_constraint(constraints::less_than_or_equals([x], 10))
+    ///     .post(NonZero::new(1).unwrap());
+    ///
+    /// let num_clauses = solver.num_clauses();
+    /// solver
+    ///     .compact_domains()
+    ///     .expect("the problem is feasible at the root");
+    /// assert!(solver.num_clauses() < num_clauses);
+    /// ```
+    pub fn compact_domains(&mut self) -> Result<(), ConstraintOperationError> {
+        let _ = self.satisfaction_solver.propagate_at_root()?;
+        self.satisfaction_solver.compact_domains();
+        Ok(())
+    }
+
+    /// Solves the model currently in the [`Solver`] (or is indicated to terminate by the provided
+    /// [`TerminationCondition`]) in order to explain why it is unsatisfiable; this is a debugging
+    /// facility for models which are unexpectedly infeasible.
+    ///
+    /// If the problem is found to be unsatisfiable (either because a conflict was found while
+    /// posting the constraints or during search), then this returns the tags of the constraints
+    /// (as provided when posting them) which participate in the final conflict; this is
+    /// effectively a core in terms of the constraints. The tags are found by going backwards from
+    /// the conflict over the reasons for the propagations, where the literals which were derived
+    /// during conflict resolution are attributed to the constraints in the conflict which derived
+    /// them. Note that the explanation is not necessarily minimal, and that clauses which are
+    /// added using [`Solver::add_clause`] are not tagged and so do not occur in it.
+    ///
+    /// If a solution is found or the search is terminated, then [`None`] is returned.
+    pub fn explain_unsat<B: Brancher, T: TerminationCondition>(
+        &mut self,
+        brancher: &mut B,
+        termination: &mut T,
+    ) -> Option<UnsatExplanation> {
+        let tags = self
+            .satisfaction_solver
+            .explain_unsat(termination, brancher)?;
+        let names = tags
+            .iter()
+            .map(|tag| self.constraint_names.get(tag).cloned())
+            .collect();
+
+        Some(UnsatExplanation { tags, names })
+    }
+
+    /// Checks whether the provided [`Solution`] satisfies all of the constraints which have been
+    /// added to the [`Solver`]; this is a debugging facility which can be used to detect faulty
+    /// propagators.
+    ///
+    /// Every propagator is checked using [`Propagator::check`] and every clause is required to
+    /// contain at least one literal which is true. If a propagator is violated, then its name and
+    /// the tag of the constraint it was posted for are returned; if a clause is violated, then its
+    /// literals are returned. Only the first violated constraint is reported.
+    ///
+    /// In debug builds, this check is performed automatically on every solution which is returned
+    /// by [`Solver::satisfy`] and the optimisation routines.
+    pub fn verify_solution(&self, solution: &Solution) -> Result<(), ViolatedConstraint> {
+        self.satisfaction_solver.verify_solution(solution)
+    }
+
+    /// Solves the model currently in the [`Solver`] to optimality where the provided
+    /// `objective_variable` is minimised (or is indicated to terminate by the provided
+    /// [`TerminationCondition`]).
+    ///
+    /// It returns an [`OptimisationResult`] which can be used to retrieve the optimal solution if
+    /// it exists.
+    pub fn minimise(
+        &mut self,
+        brancher: &mut impl Brancher,
+        termination: &mut impl TerminationCondition,
+        objective_variable: impl IntegerVariable,
+    ) -> OptimisationResult {
+        let result = self.minimise_internal(brancher, termination, objective_variable, false, None);
+        self.report_final_solution(result)
+    }
+
+    /// Solves the model currently in the [`Solver`] to optimality where the provided
+    /// `objective_variable` is maximised (or is indicated to terminate by the provided
+    /// [`TerminationCondition`]).
+    ///
+    /// It returns an [`OptimisationResult`] which can be used to retrieve the optimal solution if
+    /// it exists.
+    pub fn maximise(
+        &mut self,
+        brancher: &mut impl Brancher,
+        termination: &mut impl TerminationCondition,
+        objective_variable: impl IntegerVariable,
+    ) -> OptimisationResult {
+        let result = self.minimise_internal(
+            brancher,
+            termination,
+            objective_variable.scaled(-1),
+            true,
+            None,
+        );
+        self.report_final_solution(result)
+    }
+
+    /// Minimises the provided `objective_variable` similar to [`Solver::minimise`], but starts
+    /// from the provided `initial_solution` (e.g. a solution to a slightly different model).
+    ///
+    /// The initial solution is first verified by assuming the values of all its integer
+    /// variables, where the variables which have a name are matched to the variables of this
+    /// solver with the same name (such that e.g. a deserialised solution can be used); if it is a
+    /// solution to the current model, then its objective value is used as the initial upper bound
+    /// and the initial (unconstrained) solve is skipped. Otherwise, the solver falls back to
+    /// [`Solver::minimise`] and the statistic `initialSolutionRejected` is logged.
+    ///
+    /// Note that the solution callback (see [`Solver::with_solution_callback`]) is only called for
+    /// solutions which improve upon the initial solution.
+    pub fn minimise_with_initial_solution(
+        &mut self,
+        brancher: &mut impl Brancher,
+        termination: &mut impl TerminationCondition,
+        objective_variable: impl IntegerVariable,
+        initial_solution: &Solution,
+    ) -> OptimisationResult {
+        let result = self.minimise_internal(
+            brancher,
+            termination,
+            objective_variable,
+            false,
+            Some(initial_solution),
+        );
+        self.report_final_solution(result)
+    }
+
+    /// Maximises the provided `objective_variable` starting from the provided
+    /// `initial_solution`; see [`Solver::minimise_with_initial_solution`].
+    pub fn maximise_with_initial_solution(
+        &mut self,
+        brancher: &mut impl Brancher,
+        termination: &mut impl TerminationCondition,
+        objective_variable: impl IntegerVariable,
+        initial_solution: &Solution,
+    ) -> OptimisationResult {
+        let result = self.minimise_internal(
+            brancher,
+            termination,
+            objective_variable.scaled(-1),
+            true,
+            Some(initial_solution),
+        );
+        self.report_final_solution(result)
+    }
+
+    /// Solves the model currently in the [`Solver`] to optimality where the provided
+    /// `objective_variable` is minimised using dichotomic (binary) search over the objective.
+    ///
+    /// Rather than requiring every next solution to be strictly better, the interval `[lb, ub]` of
+    /// possible optimal objective values is halved with every solve call by solving under the
+    /// assumption that the objective is at most the midpoint of the interval. Since the bound is
+    /// an assumption, an unsatisfiable midpoint does not remain in the solver; only the optimum is
+    /// posted once it is proven.
+    ///
+    /// If the search is terminated, then the best solution found so far is returned as
+    /// [`OptimisationResult::Satisfiable`] and the last reported [`OptimisationIteration`] contains
+    /// the remaining interval.
+    pub fn minimise_dichotomic(
+        &mut self,
+        brancher: &mut impl Brancher,
+        termination: &mut impl TerminationCondition,
+        objective_variable: impl IntegerVariable,
+    ) -> OptimisationResult {
+        let result =
+            self.minimise_dichotomic_internal(brancher, termination, objective_variable, false);
+        self.report_final_solution(result)
+    }
+
+    /// Maximises the provided `objective_variable` using dichotomic search; see
+    /// [`Solver::minimise_dichotomic`].
+    pub fn maximise_dichotomic(
+        &mut self,
+        brancher: &mut impl Brancher,
+        termination: &mut impl TerminationCondition,
+        objective_variable: impl IntegerVariable,
+    ) -> OptimisationResult {
+        let result = self.minimise_dichotomic_internal(
+            brancher,
+            termination,
+            objective_variable.scaled(-1),
+            true,
+        );
+        self.report_final_solution(result)
+    }
+
+    /// Minimises the provided `objective_variable` using the provided [`OptimisationStrategy`].
+    pub fn minimise_with_strategy(
+        &mut self,
+        brancher: &mut impl Brancher,
+        termination: &mut impl TerminationCondition,
+        objective_variable: impl IntegerVariable,
+        strategy: OptimisationStrategy,
+    ) -> OptimisationResult {
+        match strategy {
+            OptimisationStrategy::LinearSatUnsat => {
+                self.minimise(brancher, termination, objective_variable)
+            }
+            OptimisationStrategy::Dichotomic => {
+                self.minimise_dichotomic(brancher, termination, objective_variable)
+            }
+        }
+    }
+
+    /// Calls the [`Solver::final_solution_callback`] with the solution of the `result` of an
+    /// optimisation function (if any), and returns the `result`.
+    fn report_final_solution(&self, result: OptimisationResult) -> OptimisationResult {
+        if let Some(solution) = result.solution() {
+            (self.final_solution_callback)(SolutionCallbackArguments {
+                solution,
+                is_final: true,
+                solver: self,
+            });
+        }
+
+        result
+    }
+
+    /// The internal method which optimizes the objective function, this function takes an extra
+    /// argument (`is_maximising`) as compared to [`Solver::maximise`] and [`Solver::minimise`]
+    /// which determines whether the logged objective value should be scaled by `-1` or not.
     ///
     /// This is necessary due to the fact that [`Solver::maximise`] simply calls minimise with
     /// the objective variable scaled with `-1` which would lead to incorrect statistic if not
     /// scaled back.
+    ///
+    /// If an `initial_solution` is provided which is verified to be a solution, then the search
+    /// starts from that solution rather than from an initial solve.
     fn minimise_internal(
         &mut self,
         brancher: &mut impl Brancher,
         termination: &mut impl TerminationCondition,
         objective_variable: impl IntegerVariable,
         is_maximising: bool,
+        initial_solution: Option<&Solution>,
     ) -> OptimisationResult {
         // If we are maximising then when we simply scale the variable by -1, however, this will
         // lead to the printed objective value in the statistics to be multiplied by -1; this
         // objective_multiplier ensures that the objective is correctly logged.
         let objective_multiplier = if is_maximising { -1 } else { 1 };
 
-        let initial_solve = self.satisfaction_solver.solve(termination, brancher);
-        match initial_solve {
-            CSPSolverExecutionFlag::Feasible => {}
-            CSPSolverExecutionFlag::Infeasible => {
-                // Reset the state whenever we return a result
-                self.satisfaction_solver.restore_state_at_root(brancher);
-                return OptimisationResult::Unsatisfiable;
-            }
-            CSPSolverExecutionFlag::Timeout => {
-                // Reset the state whenever we return a result
-                self.satisfaction_solver.restore_state_at_root(brancher);
-                return OptimisationResult::Unknown;
-            }
-        }
+        let mut bound_logger = BoundEventLogger::new(
+            self.satisfaction_solver.is_verbose_optimisation(),
+            self.satisfaction_solver.statistics().conflicts,
+        );
+
         let mut best_objective_value = Default::default();
         let mut best_solution = Solution::default();
 
-        self.process_solution(
-            objective_multiplier,
-            &objective_variable,
-            &mut best_objective_value,
-            &mut best_solution,
-            brancher,
-        );
+        let is_initial_solution_verified = initial_solution.is_some_and(|initial_solution| {
+            self.verify_initial_solution(initial_solution, termination, brancher)
+        });
+
+        if is_initial_solution_verified {
+            // The initial solution is not reported to the solution callback since it was not
+            // found by the solver
+            best_objective_value = (objective_multiplier
+                * self
+                    .satisfaction_solver
+                    .get_assigned_integer_value(&objective_variable)
+                    .expect("expected variable to be assigned"))
+                as i64;
+            best_solution = self.satisfaction_solver.get_solution_reference().into();
+            brancher.on_solution(self.satisfaction_solver.get_solution_reference());
+        } else {
+            let initial_solve = self.satisfaction_solver.solve(termination, brancher);
+            match initial_solve {
+                CSPSolverExecutionFlag::Feasible => {}
+                CSPSolverExecutionFlag::Infeasible => {
+                    // Reset the state whenever we return a result
+                    self.satisfaction_solver.restore_state_at_root(brancher);
+                    return OptimisationResult::Unsatisfiable;
+                }
+                CSPSolverExecutionFlag::Timeout => {
+                    // Reset the state whenever we return a result
+                    self.satisfaction_solver.restore_state_at_root(brancher);
+                    return OptimisationResult::Unknown(termination.reason().unwrap_or_default());
+                }
+            }
+
+            self.process_solution(
+                objective_multiplier,
+                &objective_variable,
+                &mut best_objective_value,
+                &mut best_solution,
+                brancher,
+            );
+            self.polish_solution(
+                objective_multiplier,
+                &objective_variable,
+                &mut best_objective_value,
+                &mut best_solution,
+                termination,
+                brancher,
+            );
+        }
+
         loop {
             self.satisfaction_solver.restore_state_at_root(brancher);
+            self.finish_iteration(
+                &mut bound_logger,
+                &objective_variable,
+                objective_multiplier,
+                best_objective_value,
+                false,
+            );
 
             if self
                 .strengthen(
@@ -527,8 +1448,11 @@ impl Solver {
             {
                 // Reset the state whenever we return a result
                 self.satisfaction_solver.restore_state_at_root(brancher);
+                self.debug_verify_solution(&best_solution);
                 return OptimisationResult::Optimal(best_solution);
             }
+            // The strengthened bound can satisfy clauses and entail propagators at the root
+            self.satisfaction_solver.simplify_root();
 
             let solve_result = self.satisfaction_solver.solve(termination, brancher);
             match solve_result {
@@ -544,23 +1468,303 @@ impl Solver {
                         &mut best_solution,
                         brancher,
                     );
+                    self.polish_solution(
+                        objective_multiplier,
+                        &objective_variable,
+                        &mut best_objective_value,
+                        &mut best_solution,
+                        termination,
+                        brancher,
+                    );
                 }
                 CSPSolverExecutionFlag::Infeasible => {
                     {
                         // Reset the state whenever we return a result
                         self.satisfaction_solver.restore_state_at_root(brancher);
+                        self.finish_iteration(
+                            &mut bound_logger,
+                            &objective_variable,
+                            objective_multiplier,
+                            best_objective_value,
+                            true,
+                        );
+                        self.debug_verify_solution(&best_solution);
                         return OptimisationResult::Optimal(best_solution);
                     }
                 }
                 CSPSolverExecutionFlag::Timeout => {
                     // Reset the state whenever we return a result
                     self.satisfaction_solver.restore_state_at_root(brancher);
+                    self.finish_iteration(
+                        &mut bound_logger,
+                        &objective_variable,
+                        objective_multiplier,
+                        best_objective_value,
+                        false,
+                    );
+                    self.debug_verify_solution(&best_solution);
                     return OptimisationResult::Satisfiable(best_solution);
                 }
             }
         }
     }
 
+    /// The internal method of [`Solver::minimise_dichotomic`] and [`Solver::maximise_dichotomic`];
+    /// see [`Solver::minimise_internal`] for the meaning of `is_maximising`.
+    ///
+    /// The bounds `lower_bound` and `upper_bound` are expressed in terms of the (minimised)
+    /// `objective_variable`, whereas `best_objective_value` is expressed in terms of the objective
+    /// as provided by the user.
+    fn minimise_dichotomic_internal(
+        &mut self,
+        brancher: &mut impl Brancher,
+        termination: &mut impl TerminationCondition,
+        objective_variable: impl IntegerVariable,
+        is_maximising: bool,
+    ) -> OptimisationResult {
+        let objective_multiplier = if is_maximising { -1 } else { 1 };
+
+        let mut bound_logger = BoundEventLogger::new(
+            self.satisfaction_solver.is_verbose_optimisation(),
+            self.satisfaction_solver.statistics().conflicts,
+        );
+
+        let mut best_objective_value = Default::default();
+        let mut best_solution = Solution::default();
+
+        match self.satisfaction_solver.solve(termination, brancher) {
+            CSPSolverExecutionFlag::Feasible => {}
+            CSPSolverExecutionFlag::Infeasible => {
+                self.satisfaction_solver.restore_state_at_root(brancher);
+                return OptimisationResult::Unsatisfiable;
+            }
+            CSPSolverExecutionFlag::Timeout => {
+                self.satisfaction_solver.restore_state_at_root(brancher);
+                return OptimisationResult::Unknown(termination.reason().unwrap_or_default());
+            }
+        }
+
+        self.process_solution(
+            objective_multiplier,
+            &objective_variable,
+            &mut best_objective_value,
+            &mut best_solution,
+            brancher,
+        );
+        self.polish_solution(
+            objective_multiplier,
+            &objective_variable,
+            &mut best_objective_value,
+            &mut best_solution,
+            termination,
+            brancher,
+        );
+        self.satisfaction_solver.restore_state_at_root(brancher);
+
+        let mut lower_bound = self.lower_bound(&objective_variable) as i64;
+        let mut upper_bound = best_objective_value * objective_multiplier as i64;
+        self.finish_iteration_with_dual_bound(
+            &mut bound_logger,
+            objective_multiplier,
+            best_objective_value,
+            lower_bound,
+        );
+
+        while lower_bound < upper_bound {
+            let midpoint = lower_bound + (upper_bound - lower_bound) / 2;
+            let assumption =
+                self.get_literal(objective_variable.upper_bound_predicate(midpoint as i32));
+
+            let solve_result = self.satisfaction_solver.solve_under_assumptions(
+                &[assumption],
+                termination,
+                brancher,
+            );
+            match solve_result {
+                CSPSolverExecutionFlag::Feasible => {
+                    self.process_solution(
+                        objective_multiplier,
+                        &objective_variable,
+                        &mut best_objective_value,
+                        &mut best_solution,
+                        brancher,
+                    );
+                    self.polish_solution(
+                        objective_multiplier,
+                        &objective_variable,
+                        &mut best_objective_value,
+                        &mut best_solution,
+                        termination,
+                        brancher,
+                    );
+                    upper_bound = best_objective_value * objective_multiplier as i64;
+                }
+                CSPSolverExecutionFlag::Infeasible => lower_bound = midpoint + 1,
+                CSPSolverExecutionFlag::Timeout => {
+                    // Reset the state whenever we return a result
+                    self.satisfaction_solver.restore_state_at_root(brancher);
+                    self.finish_iteration_with_dual_bound(
+                        &mut bound_logger,
+                        objective_multiplier,
+                        best_objective_value,
+                        lower_bound,
+                    );
+                    self.debug_verify_solution(&best_solution);
+                    return OptimisationResult::Satisfiable(best_solution);
+                }
+            }
+
+            self.satisfaction_solver.restore_state_at_root(brancher);
+            // The root can contain a better lower bound due to clauses learned under the assumption
+            lower_bound = lower_bound.max(self.lower_bound(&objective_variable) as i64);
+            self.finish_iteration_with_dual_bound(
+                &mut bound_logger,
+                objective_multiplier,
+                best_objective_value,
+                lower_bound,
+            );
+        }
+
+        // Only the proven optimum is posted, such that subsequent calls respect it
+        self.satisfaction_solver
+            .add_clause([self
+                .satisfaction_solver
+                .get_literal(objective_variable.upper_bound_predicate(upper_bound as i32))])
+            .expect("the best solution satisfies the optimal bound");
+
+        self.debug_verify_solution(&best_solution);
+        OptimisationResult::Optimal(best_solution)
+    }
+
+    /// Checks whether the provided `initial_solution` is a solution to the current model by
+    /// solving under the assumption that every integer variable takes its value in the initial
+    /// solution.
+    ///
+    /// If this is the case, then the solver is left in the state of the (verified) solution and
+    /// `true` is returned. Otherwise, the state is reset, the statistic `initialSolutionRejected`
+    /// is logged and `false` is returned.
+    fn verify_initial_solution(
+        &mut self,
+        initial_solution: &Solution,
+        termination: &mut impl TerminationCondition,
+        brancher: &mut impl Brancher,
+    ) -> bool {
+        let num_domains = self
+            .satisfaction_solver
+            .get_integer_assignments()
+            .num_domains() as usize;
+
+        // The initial solution can only be verified if all of its variables exist in this solver
+        // and are assigned; named variables are matched by their name (e.g. for a deserialised
+        // solution), and the other variables by their identifier
+        let solution_names = initial_solution.variable_names();
+        let assumptions = initial_solution
+            .assignments_integer()
+            .get_domains()
+            .map(|domain| {
+                let value = initial_solution
+                    .assignments_integer()
+                    .is_domain_assigned(domain)
+                    .then(|| initial_solution.get_integer_value(domain))?;
+
+                let solver_domain = match solution_names.get_int_name(domain) {
+                    Some(name) => self
+                        .satisfaction_solver
+                        .variable_names
+                        .get_domain_by_name(name)?,
+                    None => ((domain.id as usize) < num_domains).then_some(domain)?,
+                };
+                Some(self.get_literal(predicate![solver_domain == value]))
+            })
+            .collect::<Option<Vec<_>>>();
+
+        let is_verified = assumptions.is_some_and(|assumptions| {
+            self.satisfaction_solver
+                .solve_under_assumptions(&assumptions, termination, brancher)
+                == CSPSolverExecutionFlag::Feasible
+        });
+
+        if !is_verified {
+            self.satisfaction_solver.restore_state_at_root(brancher);
+            log_statistic("initialSolutionRejected", true);
+        }
+
+        is_verified
+    }
+
+    /// Runs the [`LnsPolisher`] of the solver (if any) on `best_solution`. If it finds an improving
+    /// solution, then `best_objective_value` and `best_solution` are updated and the solution is
+    /// passed to [`Solver::solution_callback`].
+    fn polish_solution(
+        &mut self,
+        objective_multiplier: i32,
+        objective_variable: &impl IntegerVariable,
+        best_objective_value: &mut i64,
+        best_solution: &mut Solution,
+        termination: &mut impl TerminationCondition,
+        brancher: &mut impl Brancher,
+    ) {
+        let Some(mut polisher) = self.solution_polisher.take() else {
+            return;
+        };
+
+        if polisher.polish(
+            self,
+            brancher,
+            termination,
+            best_solution,
+            objective_variable.clone(),
+        ) {
+            *best_objective_value = (objective_multiplier
+                * best_solution.get_integer_value(objective_variable.clone()))
+                as i64;
+            (self.solution_callback)(SolutionCallbackArguments {
+                solution: best_solution,
+                is_final: false,
+                solver: self,
+            });
+        }
+
+        self.solution_polisher = Some(polisher);
+    }
+
+    /// Solves under the provided `assumptions` with a budget of `conflict_budget` conflicts, and
+    /// returns the solution if one is found. The search starts from the root, and the state is
+    /// restored to the root afterwards.
+    ///
+    /// This is used by the [`LnsPolisher`] to search the neighbourhood of a solution.
+    pub(crate) fn solve_neighbourhood(
+        &mut self,
+        assumptions: &[Literal],
+        conflict_budget: u64,
+        termination: &mut impl TerminationCondition,
+        brancher: &mut impl Brancher,
+    ) -> Option<Solution> {
+        self.satisfaction_solver.restore_state_at_root(brancher);
+        let result = self
+            .satisfaction_solver
+            .solve_under_assumptions_with_conflict_budget(
+                assumptions,
+                conflict_budget,
+                termination,
+                brancher,
+            );
+
+        let solution = (result == CSPSolverExecutionFlag::Feasible).then(|| {
+            let solution = self.satisfaction_solver.get_solution_reference();
+            brancher.on_solution(solution);
+            solution.into()
+        });
+
+        self.satisfaction_solver.restore_state_at_root(brancher);
+        solution
+    }
+
+    /// Returns the random generator of the solver; see [`SolverOptions::random_seed`].
+    pub(crate) fn random(&mut self) -> &mut dyn Random {
+        self.satisfaction_solver.random_generator()
+    }
+
     /// Processes a solution when it is found, it consists of the following procedure:
     /// - Assigning `best_objective_value` the value assigned to `objective_variable` (multiplied by
     ///   `objective_multiplier`).
@@ -585,7 +1789,60 @@ impl Solver {
 
         self.log_statistics_with_objective(*best_objective_value);
         brancher.on_solution(self.satisfaction_solver.get_solution_reference());
-        (self.solution_callback)(best_solution);
+        (self.solution_callback)(SolutionCallbackArguments {
+            solution: best_solution,
+            is_final: false,
+            solver: self,
+        });
+    }
+
+    /// Finishes the current iteration of an optimisation procedure using the provided
+    /// `bound_logger` and calls the [`Solver::iteration_callback`]. The solver is expected to be
+    /// at the root, where the bound of `objective_variable` is the dual bound (unless
+    /// `best_objective_value` is proven to be optimal).
+    fn finish_iteration(
+        &self,
+        bound_logger: &mut BoundEventLogger,
+        objective_variable: &impl IntegerVariable,
+        objective_multiplier: i32,
+        best_objective_value: i64,
+        is_optimal: bool,
+    ) {
+        let dual_bound = if is_optimal {
+            best_objective_value * objective_multiplier as i64
+        } else {
+            self.lower_bound(objective_variable) as i64
+        };
+        self.finish_iteration_with_dual_bound(
+            bound_logger,
+            objective_multiplier,
+            best_objective_value,
+            dual_bound,
+        );
+    }
+
+    /// Finishes the current iteration similar to [`Solver::finish_iteration`], where the provided
+    /// `dual_bound` is the lower bound on the (minimised) objective variable.
+    fn finish_iteration_with_dual_bound(
+        &self,
+        bound_logger: &mut BoundEventLogger,
+        objective_multiplier: i32,
+        best_objective_value: i64,
+        dual_bound: i64,
+    ) {
+        let dual_bound = objective_multiplier as i64 * dual_bound;
+        let (lower_bound, upper_bound) = if objective_multiplier == 1 {
+            (dual_bound, best_objective_value)
+        } else {
+            (best_objective_value, dual_bound)
+        };
+
+        let iteration = bound_logger.finish_iteration(
+            lower_bound,
+            upper_bound,
+            self.satisfaction_solver.statistics().conflicts,
+        );
+        (self.iteration_callback)(&iteration);
     }
 
     /// Given the current objective value `best_objective_value`, it adds a constraint specifying
@@ -623,6 +1880,27 @@ impl Solver {
             )
         );
     }
+
+    fn debug_check_variables_are_fixed(solution: &Solution) {
+        if cfg!(debug_assertions) {
+            if let Some(variable) = (0..solution.num_domains() as u32)
+                .map(DomainId::new)
+                .find(|&variable| !solution.is_fixed(variable))
+            {
+                panic!(
+                    "The variable {variable} is not fixed in the solution, which is likely because the brancher does not branch over it"
+                );
+            }
+        }
+    }
+
+    fn debug_verify_solution(&self, solution: &Solution) {
+        if cfg!(debug_assertions) {
+            if let Err(violation) = self.verify_solution(solution) {
+                panic!("The solver reported a solution which violates a constraint: {violation}");
+            }
+        }
+    }
 }
 
 /// Functions for adding new constraints to the solver.
@@ -651,6 +1929,81 @@ impl Solver {
         ConstraintPoster::new(self, constraint)
     }
 
+    /// Adds a [`Constraint`] which can later be removed using [`Solver::remove_constraint`],
+    /// without losing the clauses which were learned independently of it.
+    ///
+    /// The constraint is posted as `a -> constraint` for a fresh activation literal `a`, which is
+    /// assumed in every subsequent solve call until the constraint is removed. Note that this
+    /// means that the constraint does not propagate at the root and that its activation literal
+    /// is never part of an extracted core.
+    ///
+    /// This method returns a [`ConstraintOperationError`] if the addition of the [`Constraint`] led
+    /// to a root-level conflict.
+    pub fn add_removable_constraint(
+        &mut self,
+        constraint: impl Constraint,
+        tag: NonZero<u32>,
+    ) -> Result<ConstraintHandle, ConstraintOperationError> {
+        let activation_literal = self.new_literal();
+        let num_propagators = self.satisfaction_solver.num_propagators();
+        let num_permanent_clauses = self.satisfaction_solver.num_permanent_clauses();
+
+        self.post_with_tag(tag, |solver| {
+            constraint.implied_by(solver, activation_literal, tag)
+        })?;
+
+        Ok(self.satisfaction_solver.add_removable_constraint(
+            activation_literal,
+            num_propagators,
+            num_permanent_clauses,
+        ))
+    }
+
+    /// Removes a constraint which was added using [`Solver::add_removable_constraint`]; this can
+    /// only be done at the root (i.e. in between solve calls).
+    ///
+    /// The propagators and clauses of the constraint are removed, as are the learned clauses
+    /// which were derived using the constraint.
+    pub fn remove_constraint(
+        &mut self,
+        handle: ConstraintHandle,
+    ) -> Result<(), ConstraintOperationError> {
+        self.satisfaction_solver.remove_constraint(handle)
+    }
+
+    /// Records the current state of the [`Solver`] (i.e. its constraints, variables and root
+    /// assignments); the returned [`SnapshotId`] can be used to return to this state using
+    /// [`Solver::rollback_to`]. This allows exploring alternative sets of constraints on top of a
+    /// base model.
+    ///
+    /// Snapshots can only be taken at the root (i.e. in between solve calls).
+    pub fn snapshot(&mut self) -> SnapshotId {
+        self.satisfaction_solver.snapshot()
+    }
+
+    /// Restores the state of the [`Solver`] to the provided snapshot (see [`Solver::snapshot`]);
+    /// this can only be done at the root (i.e. in between solve calls).
+    ///
+    /// All of the constraints and clauses which were added after the snapshot are removed, as are
+    /// the clauses which were learned after it and the root assignments which were made after it.
+    /// Variables cannot be removed, so the variables which were created after the snapshot remain
+    /// in the solver without any constraints on them. Note that constraints which were removed
+    /// after the snapshot (see [`Solver::remove_constraint`]) are not restored, and that the
+    /// [`ConstraintHandle`]s of the constraints which were added after the snapshot become
+    /// invalid.
+    ///
+    /// Rolling back to a snapshot invalidates the snapshots which were taken after it, while the
+    /// snapshot itself remains valid such that it can be rolled back to multiple times.
+    pub fn rollback_to(&mut self, snapshot: SnapshotId) {
+        self.satisfaction_solver.rollback_to(snapshot)
+    }
+
+    /// Attaches a name to the constraints with the provided `tag`; the name is reported by
+    /// [`Solver::explain_unsat`] when these constraints are part of the explanation.
+    pub fn name_constraint(&mut self, tag: NonZero<u32>, name: impl Into<String>) {
+        let _ = self.constraint_names.insert(tag, name.into());
+    }
+
     /// Creates a clause from `literals` and adds it to the current formula.
     ///
     /// If the formula becomes trivially unsatisfiable, a [`ConstraintOperationError`] will be
@@ -663,6 +2016,118 @@ impl Solver {
         self.satisfaction_solver.add_clause(clause)
     }
 
+    /// Posts every one of the provided `facts` as a unit clause; this is meant for facts which were
+    /// exported from another solver using [`Solver::export_learned_unit_facts`]. The variables of
+    /// the facts should thus refer to the same variables in both solvers.
+    ///
+    /// If a fact conflicts with the facts which hold at the root, then a
+    /// [`ConstraintOperationError`] is returned and the remaining facts are not posted.
+    pub fn import_facts(&mut self, facts: &[Predicate]) -> Result<(), ConstraintOperationError> {
+        facts
+            .iter()
+            .try_for_each(|&fact| self.add_clause([self.get_literal(fact)]))
+    }
+
+    /// Fixes the provided variable to `value` at the root and propagates the consequences.
+    ///
+    /// In contrast to adding the unit clause `[variable == value]` using [`Solver::add_clause`],
+    /// the value is checked against the domain with which the variable was created; if it is not
+    /// part of that domain, then [`ConstraintOperationError::ValueOutsideDomain`] is returned and
+    /// the [`Solver`] is left unchanged. If proof logging is enabled, then the assignment is
+    /// logged as a fact in the proof.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use munchkin::Solver;
+    /// # use munchkin::ConstraintOperationError;
+    /// let mut solver = Solver::default();
+    /// let x = solver.new_bounded_integer(0, 10);
+    ///
+    /// assert!(matches!(
+    ///     solver.fix_integer(x, 11),
+    ///     Err(ConstraintOperationError::ValueOutsideDomain { .. })
+    /// ));
+    ///
+    /// assert!(solver.fix_integer(x, 5).is_ok());
+    /// assert_eq!(solver.lower_bound(&x), 5);
+    /// assert_eq!(solver.upper_bound(&x), 5);
+    /// ```
+    pub fn fix_integer(
+        &mut self,
+        variable: DomainId,
+        value: i32,
+    ) -> Result<(), ConstraintOperationError> {
+        let assignments = self.satisfaction_solver.get_integer_assignments();
+        if !assignments.is_value_in_initial_domain(variable, value) {
+            return Err(self.value_outside_domain(variable, value));
+        }
+
+        self.satisfaction_solver
+            .add_root_fact(predicate![variable == value])
+    }
+
+    /// Imposes the provided [`Bound`] on the variable at the root and propagates the consequences.
+    ///
+    /// If the bound excludes every value of the domain with which the variable was created (e.g.
+    /// a lower bound which exceeds its initial upper bound), then
+    /// [`ConstraintOperationError::ValueOutsideDomain`] is returned and the [`Solver`] is left
+    /// unchanged. If proof logging is enabled, then the bound is logged as a fact in the proof.
+    pub fn add_bound(
+        &mut self,
+        variable: DomainId,
+        bound: Bound,
+    ) -> Result<(), ConstraintOperationError> {
+        let assignments = self.satisfaction_solver.get_integer_assignments();
+        let is_outside_domain = match bound {
+            Bound::Lower(value) => value > assignments.get_initial_upper_bound(variable),
+            Bound::Upper(value) => value < assignments.get_initial_lower_bound(variable),
+        };
+        if is_outside_domain {
+            let (Bound::Lower(value) | Bound::Upper(value)) = bound;
+            return Err(self.value_outside_domain(variable, value));
+        }
+
+        self.satisfaction_solver
+            .add_root_fact(bound.predicate_over(variable))
+    }
+
+    fn value_outside_domain(&self, variable: DomainId, value: i32) -> ConstraintOperationError {
+        let assignments = self.satisfaction_solver.get_integer_assignments();
+        ConstraintOperationError::ValueOutsideDomain {
+            variable,
+            value,
+            lower_bound: assignments.get_initial_lower_bound(variable),
+            upper_bound: assignments.get_initial_upper_bound(variable),
+        }
+    }
+
+    /// Adds all of the provided clauses to the current formula.
+    ///
+    /// This is equivalent to calling [`Solver::add_clause`] for every clause, except that root
+    /// propagation is only performed once after all clauses have been added, which makes it
+    /// considerably cheaper when loading large (e.g. CNF) instances.
+    ///
+    /// If the formula becomes trivially unsatisfiable, a [`ConstraintOperationError`] will be
+    /// returned and the remaining clauses are not added.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use munchkin::Solver;
+    /// let mut solver = Solver::default();
+    ///
+    /// let a = solver.new_literal();
+    /// let b = solver.new_literal();
+    ///
+    /// let result = solver.add_clauses([vec![a, b], vec![!a, b], vec![!b]]);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn add_clauses(
+        &mut self,
+        clauses: impl IntoIterator<Item = Vec<Literal>>,
+    ) -> Result<(), ConstraintOperationError> {
+        self.satisfaction_solver.add_clauses(clauses)
+    }
+
     /// Post a new propagator to the solver. If unsatisfiability can be immediately determined
     /// through propagation, this will return a [`ConstraintOperationError`].
     ///
@@ -680,4 +2145,342 @@ impl Solver {
     ) -> Result<(), ConstraintOperationError> {
         self.satisfaction_solver.add_propagator(propagator, tag)
     }
+
+    /// Returns a [`PropagatorRegistry`] containing the propagators which have been added to the
+    /// solver so far; it is used to create a [`PropagatorGuidedBrancher`], which makes the decisions
+    /// suggested by the propagators.
+    ///
+    /// Propagators which are added after the registry is created are not part of it.
+    pub fn propagator_registry(&self) -> PropagatorRegistry {
+        PropagatorRegistry::new(self.satisfaction_solver.get_propagators_by_priority())
+    }
+}
+
+impl VariableNameLookup for Solver {
+    fn integer_name(&self, domain_id: DomainId) -> Option<String> {
+        self.satisfaction_solver
+            .variable_names
+            .get_int_name(domain_id)
+            .map(str::to_owned)
+    }
+
+    fn literal_predicate(&self, literal: Literal) -> Option<IntegerPredicate> {
+        self.get_canonical_predicate(literal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::num::NonZero;
+    use std::rc::Rc;
+
+    use super::Solver;
+    use crate::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
+    use crate::branching::Brancher;
+    use crate::branching::InDomainMin;
+    use crate::branching::InDomainSplitReversed;
+    use crate::branching::InputOrder;
+    use crate::branching::SelectionContext;
+    use crate::constraints;
+    use crate::engine::cp::propagation::PropagatorId;
+    use crate::optimisation::OptimisationStrategy;
+    use crate::predicate;
+    use crate::predicates::Predicate;
+    use crate::results::solution_iterator::IteratedSolution;
+    use crate::results::OptimisationResult;
+    use crate::results::ProblemSolution;
+    use crate::results::PropagationProbeResult;
+    use crate::results::SatisfactionResult;
+    use crate::results::SatisfactionResultUnderAssumptions;
+    use crate::termination::Indefinite;
+    use crate::termination::MemoryBudget;
+    use crate::termination::TerminationCondition;
+    use crate::termination::TerminationReason;
+    use crate::tests::solver::search::pigeon_hole_solver;
+    use crate::tests::solver::search::propositional_brancher;
+    use crate::variables::DomainId;
+    use crate::variables::Literal;
+    use crate::variables::TransformableVariable;
+
+    fn num_propositional_variables(solver: &Solver) -> usize {
+        solver
+            .satisfaction_solver
+            .get_propositional_assignments()
+            .num_propositional_variables() as usize
+    }
+
+    #[test]
+    fn channel_indicators_reuse_the_equality_literals() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 5);
+
+        let num_variables_before = num_propositional_variables(&solver);
+        let indicators = solver.channel_indicators(x, &[1, 3, 3, 7]);
+        assert_eq!(num_propositional_variables(&solver), num_variables_before);
+
+        assert_eq!(indicators.len(), 3);
+        assert_eq!(indicators[0], solver.get_literal(predicate!(x == 1)));
+        assert_eq!(indicators[1], solver.get_literal(predicate!(x == 3)));
+        assert_eq!(indicators[2], solver.get_false_literal());
+    }
+
+    #[test]
+    fn removing_constraint_purges_the_learned_clauses_which_depend_on_it() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 5);
+        let at_least_two = solver.get_literal(predicate!(x >= 2));
+        let at_most_four = solver.get_literal(predicate!(x <= 4));
+
+        let handle = solver
+            .add_removable_constraint(constraints::not_equals([x], 3), NonZero::new(1).unwrap())
+            .expect("no root conflict");
+
+        let satisfaction_solver = &mut solver.satisfaction_solver;
+        let mut add_learned_clause = |literals: Vec<Literal>| {
+            satisfaction_solver
+                .clausal_propagator
+                .add_clause_unchecked(literals, true, &mut satisfaction_solver.clause_allocator)
+                .unwrap()
+        };
+        let dependent = add_learned_clause(vec![!handle.activation_literal, at_least_two]);
+        let independent = add_learned_clause(vec![at_least_two, at_most_four]);
+
+        solver.remove_constraint(handle).expect("no root conflict");
+
+        let satisfaction_solver = &solver.satisfaction_solver;
+        assert!(satisfaction_solver.clause_allocator[dependent].is_deleted());
+        assert!(!satisfaction_solver.clause_allocator[independent].is_deleted());
+        assert!(!satisfaction_solver
+            .clausal_propagator
+            .permanent_clauses
+            .contains(&dependent));
+    }
+
+    #[test]
+    fn strengthened_bound_entails_propagators_without_changing_the_optimum() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+        let y = solver.new_bounded_integer(0, 10);
+        let objective = solver.new_bounded_integer(0, 20);
+
+        let _ = solver
+            .add_constraint(constraints::sum([x, y]).minus(objective).equals(0))
+            .post(NonZero::new(1).unwrap());
+        let _ = solver
+            .add_constraint(constraints::sum([x, y]).greater_than_or_equals(3))
+            .post(NonZero::new(2).unwrap());
+        let side_constraint = PropagatorId(solver.satisfaction_solver.cp_propagators.len() as u32);
+        let _ = solver
+            .add_constraint(constraints::sum([x, y]).less_than_or_equals(15))
+            .post(NonZero::new(3).unwrap());
+
+        // Splitting towards the upper half ensures that the bound is strengthened several times
+        let mut brancher = IndependentVariableValueBrancher::new(
+            InputOrder::new(vec![x, y]),
+            InDomainSplitReversed,
+        );
+        let result = solver.minimise(&mut brancher, &mut Indefinite, objective);
+        let OptimisationResult::Optimal(solution) = result else {
+            panic!("expected an optimal solution, got {result:?}");
+        };
+        assert_eq!(solution.get_integer_value(objective), 3);
+
+        // Once the objective is at most 7, `x + y <= 15` holds for every assignment
+        assert!(solver
+            .satisfaction_solver
+            .is_propagator_entailed(side_constraint));
+    }
+
+    /// Returns the number of propositional and integer trail entries, and the decision level.
+    fn trail_state(solver: &Solver) -> (usize, usize, usize) {
+        let csp = &solver.satisfaction_solver;
+        (
+            csp.get_propositional_assignments().num_trail_entries(),
+            csp.get_integer_assignments().num_trail_entries(),
+            csp.get_decision_level(),
+        )
+    }
+
+    #[test]
+    fn propagating_under_assumptions_restores_the_state() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 5);
+        let y = solver.new_bounded_integer(0, 5);
+        let _ = solver
+            .add_constraint(constraints::less_than_or_equals([x, y], 5))
+            .post(NonZero::new(1).unwrap());
+        let x_at_least_4 = solver.get_literal(predicate![x >= 4]);
+        let y_at_least_2 = solver.get_literal(predicate![y >= 2]);
+
+        let state_before = trail_state(&solver);
+
+        let result = solver.propagate_under_assumptions(&[x_at_least_4]);
+        assert!(matches!(result, PropagationProbeResult::Consistent { .. }));
+        assert_eq!(trail_state(&solver), state_before);
+
+        let result = solver.propagate_under_assumptions(&[x_at_least_4, y_at_least_2]);
+        assert!(matches!(result, PropagationProbeResult::Conflict(_)));
+        assert_eq!(trail_state(&solver), state_before);
+        assert_eq!(solver.lower_bound(&x), 0);
+        assert_eq!(solver.upper_bound(&y), 5);
+
+        // The solver can still be used to solve the problem afterwards
+        let mut brancher =
+            IndependentVariableValueBrancher::new(InputOrder::new(vec![x, y]), InDomainMin);
+        let result =
+            solver.satisfy_under_assumptions(&mut brancher, &mut Indefinite, &[x_at_least_4]);
+        assert!(matches!(
+            result,
+            SatisfactionResultUnderAssumptions::Satisfiable(_)
+        ));
+    }
+
+    #[test]
+    fn small_memory_budget_terminates_the_search() {
+        let (mut solver, placements) = pigeon_hole_solver(5);
+        let mut brancher = propositional_brancher(&placements);
+        let mut termination = MemoryBudget::with_limit_bytes(1);
+
+        let result = solver.satisfy(&mut brancher, &mut termination);
+
+        assert!(matches!(
+            result,
+            SatisfactionResult::Unknown(TerminationReason::MemoryBudget)
+        ));
+        assert_eq!(
+            termination.describe_trigger(),
+            Some("memory budget (1 bytes)".to_owned())
+        );
+        assert!(solver.satisfaction_solver.approximate_memory_usage() > 1);
+    }
+
+    #[test]
+    fn optimisation_after_dropping_solution_iterator_excludes_blocked_solutions() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 5);
+        let snapshot = solver.snapshot();
+
+        let mut brancher =
+            IndependentVariableValueBrancher::new(InputOrder::new(vec![x]), InDomainMin);
+        {
+            let mut termination = Indefinite;
+            let mut solution_iterator =
+                solver.get_solution_iterator(&mut brancher, &mut termination);
+            for expected_value in [0, 1] {
+                let IteratedSolution::Solution(solution) = solution_iterator.next_solution() else {
+                    panic!("expected another solution");
+                };
+                assert_eq!(solution.get_integer_value(x), expected_value);
+            }
+        }
+
+        assert_eq!(solver.satisfaction_solver.get_decision_level(), 0);
+
+        // Only the first solution was blocked, as the next solution was requested after it
+        let result = solver.minimise_with_strategy(
+            &mut brancher,
+            &mut Indefinite,
+            x,
+            OptimisationStrategy::LinearSatUnsat,
+        );
+        assert!(result.is_optimal());
+        assert_eq!(result.objective_value(&x), Some(1));
+
+        solver.rollback_to(snapshot);
+        let result = solver.minimise(&mut brancher, &mut Indefinite, x);
+        assert_eq!(result.objective_value(&x), Some(0));
+    }
+
+    /// A [`Brancher`] which makes the decisions of its script (skipping those which are already
+    /// assigned), and then assigns the unfixed `variables` to their lower bound.
+    #[derive(Debug)]
+    struct ScriptedBrancher {
+        script: Vec<Predicate>,
+        variables: Vec<DomainId>,
+    }
+
+    impl Brancher for ScriptedBrancher {
+        fn next_decision(&mut self, context: &mut SelectionContext) -> Option<Predicate> {
+            self.script
+                .iter()
+                .copied()
+                .find(|&decision| context.evaluate_predicate(decision).is_none())
+                .or_else(|| {
+                    self.variables
+                        .iter()
+                        .find(|variable| !context.is_integer_fixed(*variable))
+                        .map(|&variable| predicate!(variable <= context.lower_bound(&variable)))
+                })
+        }
+    }
+
+    #[test]
+    fn solution_callback_reports_root_bounds_deep_in_search() {
+        let mut solver = Solver::default();
+        let xs = [0, 1, 2].map(|_| solver.new_bounded_integer(0, 1));
+        let objective = solver.new_bounded_integer(0, 10);
+        let _ = solver
+            .add_constraint(constraints::less_than_or_equals(
+                xs.iter()
+                    .map(|&x| x.scaled(1))
+                    .chain([objective.scaled(-1)])
+                    .collect::<Vec<_>>(),
+                0,
+            ))
+            .post(NonZero::new(1).unwrap());
+
+        // For every solution: the objective value, the bounds of the objective at the current
+        // decision level and at the root, the optimality gap, and the decision level.
+        let reported = Rc::new(RefCell::new(vec![]));
+        let callback_reported = Rc::clone(&reported);
+        solver.with_solution_callback(move |arguments| {
+            let solver = arguments.solver;
+            callback_reported.borrow_mut().push((
+                arguments.solution.get_integer_value(objective),
+                (
+                    solver.lower_bound(&objective),
+                    solver.upper_bound(&objective),
+                ),
+                (
+                    solver.root_lower_bound(&objective),
+                    solver.root_upper_bound(&objective),
+                ),
+                arguments.optimality_gap(&objective),
+                solver.satisfaction_solver.get_decision_level(),
+            ));
+        });
+
+        let mut brancher = ScriptedBrancher {
+            script: xs.iter().map(|&x| predicate!(x >= 1)).collect(),
+            variables: vec![objective],
+        };
+        let result = solver.minimise(&mut brancher, &mut Indefinite, objective);
+        assert_eq!(result.objective_value(&objective), Some(0));
+
+        let reported = reported.borrow();
+        let values = reported.iter().map(|report| report.0).collect::<Vec<_>>();
+        assert_eq!(values, vec![3, 2, 1, 0]);
+
+        for &(value, bounds, root_bounds, gap, decision_level) in reported.iter() {
+            // The solution is found deep in the search, where the objective is fixed
+            assert!(decision_level > 0);
+            assert_eq!(bounds, (value, value));
+
+            // The root bounds are those after strengthening with the previous solution
+            let root_upper_bound = if value == 3 { 10 } else { value };
+            assert_eq!(root_bounds, (0, root_upper_bound));
+            assert_eq!(gap, value as i64);
+        }
+
+        // After the search, the solver is at the root, where both queries agree
+        assert_eq!(
+            solver.root_lower_bound(&objective),
+            solver.lower_bound(&objective)
+        );
+        assert_eq!(
+            solver.root_upper_bound(&objective),
+            solver.upper_bound(&objective)
+        );
+    }
 }
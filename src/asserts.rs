@@ -1,6 +1,22 @@
-#[cfg(all(not(test), not(feature = "debug-checks")))]
+use std::cell::Cell;
+
+#[cfg(doc)]
+use crate::options::SolverOptions;
+
+#[cfg(all(
+    not(test),
+    not(feature = "debug-checks"),
+    not(feature = "explanation-checks")
+))]
 pub(crate) const ASSERT_LEVEL_DEFINITION: u8 = ASSERT_SIMPLE;
 
+#[cfg(all(
+    not(test),
+    not(feature = "debug-checks"),
+    feature = "explanation-checks"
+))]
+pub(crate) const ASSERT_LEVEL_DEFINITION: u8 = ASSERT_ADVANCED;
+
 #[cfg(any(test, feature = "debug-checks"))]
 pub(crate) const ASSERT_LEVEL_DEFINITION: u8 = ASSERT_EXTREME;
 
@@ -9,6 +25,58 @@ pub(crate) const ASSERT_MODERATE: u8 = 2;
 pub(crate) const ASSERT_ADVANCED: u8 = 3;
 pub(crate) const ASSERT_EXTREME: u8 = 4;
 
+/// The level of the (internal) assertions which are checked by the solver; every level includes
+/// the assertions of the levels below it. The higher levels contain checks which are expensive
+/// (e.g. the advanced assertions include checks which are linear in the size of the clause
+/// database), so these slow down the solver considerably.
+///
+/// The simple assertions are always checked. The default level is determined at compile time: it
+/// is [`AssertLevel::Extreme`] when the `debug-checks` feature is enabled, [`AssertLevel::Advanced`]
+/// when only the `explanation-checks` feature is enabled, and [`AssertLevel::Simple`] otherwise.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AssertLevel {
+    /// Only the cheap sanity checks are performed.
+    Simple = ASSERT_SIMPLE as isize,
+    /// Checks which are somewhat more expensive, such as checking the invariants of the
+    /// assignments.
+    Moderate = ASSERT_MODERATE as isize,
+    /// Checks which are expensive, such as checking the state of the clausal propagator and the
+    /// explanations of the propagators.
+    Advanced = ASSERT_ADVANCED as isize,
+    /// Checks which are very expensive, such as checking that propagation reached a fixed point.
+    Extreme = ASSERT_EXTREME as isize,
+}
+
+impl Default for AssertLevel {
+    fn default() -> Self {
+        match ASSERT_LEVEL_DEFINITION {
+            ASSERT_SIMPLE => AssertLevel::Simple,
+            ASSERT_MODERATE => AssertLevel::Moderate,
+            ASSERT_ADVANCED => AssertLevel::Advanced,
+            _ => AssertLevel::Extreme,
+        }
+    }
+}
+
+thread_local! {
+    /// The level of the assertions which is currently active; it is kept per thread such that
+    /// solvers which run on different threads (e.g. in tests) do not affect each other.
+    static ASSERT_LEVEL: Cell<u8> = const { Cell::new(ASSERT_LEVEL_DEFINITION) };
+}
+
+/// Returns the level of the assertions which are currently checked on this thread.
+#[inline]
+pub(crate) fn assert_level() -> u8 {
+    ASSERT_LEVEL.with(Cell::get)
+}
+
+/// Sets the level of the assertions which are checked on this thread; this is done by the solver
+/// based on [`SolverOptions::assert_level`] when it is created and whenever it starts solving.
+pub(crate) fn set_assert_level(level: AssertLevel) {
+    ASSERT_LEVEL.with(|assert_level| assert_level.set(level as u8));
+}
+
 #[macro_export]
 #[doc(hidden)]
 macro_rules! munchkin_assert_simple {
@@ -43,7 +111,7 @@ macro_rules! munchkin_assert_ne_simple {
 #[doc(hidden)]
 macro_rules! munchkin_assert_moderate {
     ($($arg:tt)*) => {
-        if $crate::asserts::ASSERT_LEVEL_DEFINITION >= $crate::asserts::ASSERT_MODERATE {
+        if $crate::asserts::assert_level() >= $crate::asserts::ASSERT_MODERATE {
             assert!($($arg)*);
         }
     };
@@ -53,7 +121,7 @@ macro_rules! munchkin_assert_moderate {
 #[doc(hidden)]
 macro_rules! munchkin_assert_ne_moderate {
     ($($arg:tt)*) => {
-        if $crate::asserts::ASSERT_LEVEL_DEFINITION >= $crate::asserts::ASSERT_MODERATE {
+        if $crate::asserts::assert_level() >= $crate::asserts::ASSERT_MODERATE {
             assert_ne!($($arg)*);
         }
     };
@@ -63,7 +131,7 @@ macro_rules! munchkin_assert_ne_moderate {
 #[doc(hidden)]
 macro_rules! munchkin_assert_advanced {
     ($($arg:tt)*) => {
-        if $crate::asserts::ASSERT_LEVEL_DEFINITION >= $crate::asserts::ASSERT_ADVANCED {
+        if $crate::asserts::assert_level() >= $crate::asserts::ASSERT_ADVANCED {
             assert!($($arg)*);
         }
     };
@@ -73,7 +141,7 @@ macro_rules! munchkin_assert_advanced {
 #[doc(hidden)]
 macro_rules! munchkin_assert_extreme {
     ($($arg:tt)*) => {
-        if $crate::asserts::ASSERT_LEVEL_DEFINITION >= $crate::asserts::ASSERT_EXTREME {
+        if $crate::asserts::assert_level() >= $crate::asserts::ASSERT_EXTREME {
             assert!($($arg)*);
         }
     };
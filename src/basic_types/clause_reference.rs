@@ -10,6 +10,7 @@ use crate::munchkin_assert_moderate;
 
 /// Opaque clause reference that is used by clause allocators (`ClauseAllocatorInterface`).
 #[derive(PartialEq, Eq, Clone, Copy, Hash)]
+#[repr(transparent)]
 pub struct ClauseReference {
     /// A packed representation of either a virtual binary clause of a reference to an allocated
     ///  clause.
@@ -52,7 +53,6 @@ impl ClauseReference {
         ClauseReference { code: id }
     }
 
-    #[cfg(test)]
     /// Creates the reference to indicate that propagation was due to the input literal as part of
     ///  a binary clause.
     pub(crate) fn create_virtual_binary_clause_reference(literal: Literal) -> ClauseReference {
@@ -64,6 +64,15 @@ impl ClauseReference {
         ClauseReference { code }
     }
 
+    /// Creates the reference from the code of a [`ConstraintReference`] which refers to a clause;
+    /// see [`ConstraintReference::try_as_clause_reference`].
+    pub(crate) fn create_from_clause_code(code: u32) -> Self {
+        munchkin_assert_moderate!(
+            code.bit(31) || ClauseReference::are_two_most_significant_bits_zero(code)
+        );
+        ClauseReference { code }
+    }
+
     pub(crate) fn get_code(&self) -> u32 {
         self.code
     }
@@ -95,12 +104,11 @@ impl ClauseReference {
 }
 
 /// A `ConstraintReference` can be a `ClauseReference` with the same internal structure.
+///
+/// Panics if the `ConstraintReference` does not refer to a clause.
 impl From<ConstraintReference> for ClauseReference {
     fn from(constraint_reference: ConstraintReference) -> Self {
-        munchkin_assert_moderate!(constraint_reference.is_clause());
-        ClauseReference {
-            code: constraint_reference.get_code(),
-        }
+        constraint_reference.as_clause_reference()
     }
 }
 
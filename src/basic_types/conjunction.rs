@@ -1,7 +1,6 @@
 use crate::variables::Literal;
 
 pub(crate) struct Conjunction {
-    #[allow(unused, reason = "Will be used in the assignment")]
     pub(crate) literals: Vec<Literal>,
 }
 
@@ -1,5 +1,8 @@
 use thiserror::Error;
 
+use crate::constraints::InvalidAutomaton;
+use crate::constraints::InvalidInverse;
+use crate::engine::variables::DomainId;
 #[cfg(doc)]
 use crate::Solver;
 
@@ -13,7 +16,40 @@ pub enum ConstraintOperationError {
     /// in an infeasible state.
     #[error("Adding constraint failed because the solver is in an infeasible state")]
     InfeasibleState,
+    /// Error which indicates that a constraint was attempted to be added while a search of the
+    /// [`Solver`] was suspended (see [`Solver::solve_bounded`]).
+    #[error("Adding constraint failed because the search of the solver is suspended")]
+    SuspendedSearch,
     /// Error which indicate that adding a propagator led to infeasibility at the root.
     #[error("Adding the constraint failed because it is infeasible at the root")]
     InfeasiblePropagator,
+    /// Error which indicates that propagating to a fixed point at the root (see
+    /// [`Solver::propagate`]) led to infeasibility.
+    #[error("Propagation at the root led to infeasibility")]
+    InfeasibleRoot,
+    /// Error which indicates that a variable was fixed (or bounded) to a value which is not part
+    /// of the domain with which the variable was created (see [`Solver::fix_integer`] and
+    /// [`Solver::add_bound`]).
+    #[error(
+        "The value {value} is outside of the initial domain of {variable}, which has the bounds \
+         [{lower_bound}, {upper_bound}]"
+    )]
+    ValueOutsideDomain {
+        variable: DomainId,
+        value: i32,
+        lower_bound: i32,
+        upper_bound: i32,
+    },
+    /// Error which indicates that a maximum constraint was created over an empty array, for which
+    /// the maximum is undefined.
+    #[error("The maximum of an empty array is undefined")]
+    EmptyMaximum,
+    /// Error which indicates that a regular constraint was created with an automaton which is not
+    /// a valid deterministic finite automaton.
+    #[error("The automaton of the regular constraint is invalid: {0}")]
+    InvalidAutomaton(#[from] InvalidAutomaton),
+    /// Error which indicates that an inverse constraint was created over arrays which are not
+    /// defined over the same values.
+    #[error("The arrays of the inverse constraint are invalid: {0}")]
+    InvalidInverse(#[from] InvalidInverse),
 }
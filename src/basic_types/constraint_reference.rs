@@ -13,6 +13,7 @@ use crate::engine::cp::reason::ReasonRef;
 use crate::munchkin_assert_moderate;
 
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
 pub struct ConstraintReference {
     // the constraint reference may refer to a virtual binary clause, an allocated clause, or a
     // propagator  note that the user can only distinguish between a clause and a propagator
@@ -91,7 +92,7 @@ impl ConstraintReference {
         !self.is_non_reason() && (self.is_virtual_binary_clause() || self.is_allocated_clause())
     }
 
-    fn is_virtual_binary_clause(&self) -> bool {
+    pub fn is_virtual_binary_clause(&self) -> bool {
         !self.is_non_reason() && self.code.bit(31)
     }
 
@@ -116,11 +117,25 @@ impl ConstraintReference {
         self.code
     }
 
+    /// Get the underlying clause reference, or [`None`] if this does not refer to a clause (e.g.
+    /// when it refers to the reason of a propagator).
+    pub fn try_as_clause_reference(self) -> Option<ClauseReference> {
+        self.is_clause()
+            .then(|| ClauseReference::create_from_clause_code(self.code))
+    }
+
     /// Get the underlying clause reference. If this is not a clause reference, but a propagator,
-    /// this method will panic.
+    /// this method will panic; see [`ConstraintReference::try_as_clause_reference`] for the
+    /// checked version.
     pub fn as_clause_reference(self) -> ClauseReference {
-        munchkin_assert_moderate!(self.is_clause());
-        ClauseReference::create_allocated_clause_reference(self.code)
+        self.try_as_clause_reference().unwrap_or_else(|| {
+            panic!(
+                "Constraint reference with code {:#010x} (tag bits {:#04b}) does not refer to a \
+                 clause",
+                self.code,
+                ConstraintReference::two_most_significant_bits(self.code)
+            )
+        })
     }
 }
 
@@ -147,6 +162,14 @@ mod tests {
     use super::*;
     use crate::engine::variables::Literal;
 
+    /// Allocated clause ids and reason indices which are spread over the 30 bits which are
+    /// available to them, including the boundaries.
+    fn sample_ids() -> impl Iterator<Item = u32> {
+        (0..30)
+            .map(|bit| 1 << bit)
+            .chain([0, 3, 10, 12345, (1 << 30) - 1])
+    }
+
     #[test]
     fn test_binary_clause_conversion() {
         for num in [10, 11] {
@@ -187,4 +210,113 @@ mod tests {
             0
         );
     }
+
+    #[test]
+    fn test_references_are_packed_into_a_single_u32() {
+        assert_eq!(size_of::<ConstraintReference>(), 4);
+        assert_eq!(size_of::<ClauseReference>(), 4);
+        // Every bit pattern is in use (`NULL` and `NON_REASON` included), so there is no niche
+        assert_eq!(size_of::<Option<ConstraintReference>>(), 8);
+    }
+
+    #[test]
+    fn test_bit_layout() {
+        // Allocated clause: both tag bits are zero
+        let clause = ClauseReference::create_allocated_clause_reference(10);
+        assert_eq!(ConstraintReference::from(clause).get_code(), 10);
+
+        // Virtual binary clause: the most significant bit is one
+        let literal = Literal::u32_to_literal(11);
+        let binary_clause = ClauseReference::create_virtual_binary_clause_reference(literal);
+        assert_eq!(
+            ConstraintReference::from(binary_clause).get_code(),
+            (1 << 31) | 11
+        );
+
+        // Reason: the most significant bit is zero and the second most significant bit is one
+        let reason = ConstraintReference::create_reason_reference(ReasonRef(10));
+        assert_eq!(reason.get_code(), (1 << 30) | 10);
+
+        assert_eq!(ConstraintReference::NULL.get_code(), 0);
+        assert_eq!(ConstraintReference::NON_REASON.get_code(), u32::MAX);
+        assert!(!ConstraintReference::NON_REASON.is_clause());
+        assert!(!ConstraintReference::NON_REASON.is_cp_reason());
+    }
+
+    #[test]
+    fn test_allocated_clauses_round_trip() {
+        for clause_id in sample_ids() {
+            let clause = ClauseReference::create_allocated_clause_reference(clause_id);
+            let reference = ConstraintReference::from(clause);
+
+            assert!(reference.is_clause());
+            assert!(!reference.is_virtual_binary_clause());
+            assert!(!reference.is_cp_reason());
+            assert_eq!(reference.try_as_clause_reference(), Some(clause));
+            assert_eq!(reference.as_clause_reference().get_code(), clause_id);
+        }
+    }
+
+    #[test]
+    fn test_virtual_binary_clauses_round_trip() {
+        // The literal with code `2^31 - 1` cannot be used, since its reference coincides with
+        // `NON_REASON`
+        for code in sample_ids().chain([(1 << 31) - 2]) {
+            let literal = Literal::u32_to_literal(code);
+            let clause = ClauseReference::create_virtual_binary_clause_reference(literal);
+            let reference = ConstraintReference::from(clause);
+
+            assert!(reference.is_clause());
+            assert!(reference.is_virtual_binary_clause());
+            assert!(!reference.is_cp_reason());
+            assert_eq!(reference.try_as_clause_reference(), Some(clause));
+            assert_eq!(
+                reference
+                    .as_clause_reference()
+                    .get_virtual_binary_clause_literal(),
+                literal
+            );
+        }
+    }
+
+    #[test]
+    fn test_reasons_round_trip() {
+        for reason_index in sample_ids() {
+            let reference = ConstraintReference::create_reason_reference(ReasonRef(reason_index));
+
+            assert!(reference.is_cp_reason());
+            assert!(!reference.is_clause());
+            assert_eq!(reference.try_as_clause_reference(), None);
+            assert_eq!(reference.get_reason_ref(), ReasonRef(reason_index));
+        }
+    }
+
+    #[test]
+    fn test_non_reason_is_not_a_clause() {
+        assert_eq!(
+            ConstraintReference::NON_REASON.try_as_clause_reference(),
+            None
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "code 0x4000000a (tag bits 0b01) does not refer to a clause")]
+    fn test_reason_as_clause_reference_panics() {
+        let reference = ConstraintReference::create_reason_reference(ReasonRef(10));
+        let _ = reference.as_clause_reference();
+    }
+
+    #[test]
+    #[should_panic(expected = "does not refer to a clause")]
+    fn test_reason_into_clause_reference_panics() {
+        let reference = ConstraintReference::create_reason_reference(ReasonRef(10));
+        let _ = ClauseReference::from(reference);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_clause_as_reason_reference_panics_in_debug() {
+        let clause = ClauseReference::create_allocated_clause_reference(10);
+        let _ = ConstraintReference::from(clause).get_reason_ref();
+    }
 }
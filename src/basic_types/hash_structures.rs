@@ -1,3 +1,11 @@
+//! Contains the hash-based collections which are used throughout the solver.
+//!
+//! These use the [FNV](https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function)
+//! hasher, which (contrary to the default hasher of the standard library) is not seeded with a
+//! random state. The iteration order of these collections is thus fully determined by the
+//! sequence of insertions and removals, which ensures that runs with the same
+//! [`SolverOptions::random_seed`](crate::options::SolverOptions::random_seed) are reproducible.
+
 use fnv::FnvBuildHasher;
 
 pub(crate) type HashMap<K, V, Hasher = FnvBuildHasher> = std::collections::HashMap<K, V, Hasher>;
@@ -49,10 +49,34 @@ impl<Key: StorageKey, Value> KeyedVec<Key, Value> {
         self.elements.push(value)
     }
 
+    /// Reserves capacity for at least `additional` more elements; see [`Vec::reserve`].
+    pub(crate) fn reserve(&mut self, additional: usize) {
+        self.elements.reserve(additional)
+    }
+
+    #[cfg(test)]
+    pub(crate) fn capacity(&self) -> usize {
+        self.elements.capacity()
+    }
+
+    pub(crate) fn truncate(&mut self, len: usize) {
+        self.elements.truncate(len)
+    }
+
+    /// Returns the number of bytes allocated for the elements, based on the capacity of the
+    /// underlying storage; memory owned by the elements themselves is not included.
+    pub(crate) fn approximate_memory_usage(&self) -> usize {
+        self.elements.capacity() * size_of::<Value>()
+    }
+
     pub(crate) fn iter(&self) -> impl Iterator<Item = &'_ Value> {
         self.elements.iter()
     }
 
+    pub(crate) fn iter_mut(&mut self) -> impl Iterator<Item = &'_ mut Value> {
+        self.elements.iter_mut()
+    }
+
     pub(crate) fn into_entries(self) -> impl Iterator<Item = (Key, Value)> {
         self.elements
             .into_iter()
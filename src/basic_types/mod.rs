@@ -1,18 +1,20 @@
 mod clause_reference;
-mod conflict_info;
+pub(crate) mod conflict_info;
 mod conjunction;
 mod constraint_operation_error;
 mod constraint_reference;
 mod csp_solver_execution_flag;
 mod hash_structures;
 mod keyed_vec;
-mod propagation_status_cp;
+pub(crate) mod propagation_status_cp;
 mod propagation_status_cp_one_step;
 mod propositional_conjunction;
 mod random;
 mod solution;
+mod statistic_accumulator;
 pub(crate) mod statistic_logging;
 mod trail;
+mod violated_constraint;
 
 pub(crate) use clause_reference::ClauseReference;
 pub(crate) use conflict_info::*;
@@ -30,4 +32,7 @@ pub use random::*;
 pub use solution::ProblemSolution;
 pub use solution::Solution;
 pub use solution::SolutionReference;
+pub use solution::UnfixedVariable;
+pub use statistic_accumulator::StatisticAccumulator;
 pub(crate) use trail::Trail;
+pub use violated_constraint::ViolatedConstraint;
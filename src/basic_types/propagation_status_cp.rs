@@ -5,7 +5,11 @@ use crate::engine::cp::EmptyDomain;
 /// The result of invoking a constraint programming propagator. The propagation can either succeed
 /// or identify a conflict. The necessary conditions for the conflict must be captured in the error
 /// variant, i.e. a propositional conjunction.
-pub(crate) type PropagationStatusCP = Result<(), Inconsistency>;
+#[cfg_attr(
+    not(feature = "test-utilities"),
+    allow(unreachable_pub, reason = "exported by the test utilities")
+)]
+pub type PropagationStatusCP = Result<(), Inconsistency>;
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum Inconsistency {
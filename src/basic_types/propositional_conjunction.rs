@@ -2,50 +2,136 @@ use crate::engine::predicates::predicate::Predicate;
 
 /// A struct which represents a conjunction of [`Predicate`]s (e.g. it can represent `[x >= 5] /\ [y
 /// <= 10]`).
-#[derive(Clone, Default, Eq)]
+///
+/// Most conjunctions (e.g. the explanations of binary and ternary constraints) are small, so up to
+/// [`INLINE_CAPACITY`] predicates are stored without allocating; larger conjunctions are stored on
+/// the heap.
+#[derive(Clone, Default)]
 pub struct PropositionalConjunction {
-    predicates_in_conjunction: Vec<Predicate>,
+    predicates_in_conjunction: Predicates,
+}
+
+/// The number of predicates which can be stored in a [`PropositionalConjunction`] without
+/// allocating.
+const INLINE_CAPACITY: usize = 4;
+
+#[derive(Clone, Copy, Debug)]
+struct InlinePredicates {
+    len: usize,
+    predicates: [Predicate; INLINE_CAPACITY],
+}
+
+#[derive(Clone, Debug)]
+enum Predicates {
+    Inline(InlinePredicates),
+    Heap(Vec<Predicate>),
+}
+
+impl Default for Predicates {
+    fn default() -> Self {
+        Predicates::Inline(InlinePredicates {
+            len: 0,
+            predicates: [Predicate::True; INLINE_CAPACITY],
+        })
+    }
 }
 
 impl PropositionalConjunction {
     pub fn new(predicates_in_conjunction: Vec<Predicate>) -> Self {
+        if predicates_in_conjunction.len() <= INLINE_CAPACITY {
+            predicates_in_conjunction.into_iter().collect()
+        } else {
+            PropositionalConjunction {
+                predicates_in_conjunction: Predicates::Heap(predicates_in_conjunction),
+            }
+        }
+    }
+
+    /// Creates an empty conjunction which stores its predicates in the provided `buffer`, i.e. no
+    /// allocation takes place as long as the capacity of the buffer suffices.
+    pub(crate) fn with_buffer(mut buffer: Vec<Predicate>) -> Self {
+        buffer.clear();
         PropositionalConjunction {
-            predicates_in_conjunction,
+            predicates_in_conjunction: Predicates::Heap(buffer),
+        }
+    }
+
+    /// Returns the allocated buffer of the conjunction (if it has one), so that it can be reused
+    /// using [`PropositionalConjunction::with_buffer`].
+    pub(crate) fn into_buffer(self) -> Option<Vec<Predicate>> {
+        match self.predicates_in_conjunction {
+            Predicates::Inline(_) => None,
+            Predicates::Heap(buffer) => Some(buffer),
         }
     }
 
     pub fn num_predicates(&self) -> u32 {
-        self.predicates_in_conjunction.len() as u32
+        self.as_slice().len() as u32
     }
 
     pub fn add(&mut self, predicate: Predicate) {
-        self.predicates_in_conjunction.push(predicate);
+        match &mut self.predicates_in_conjunction {
+            Predicates::Inline(inline) if inline.len < INLINE_CAPACITY => {
+                inline.predicates[inline.len] = predicate;
+                inline.len += 1;
+            }
+            Predicates::Inline(inline) => {
+                let mut predicates = Vec::with_capacity(2 * INLINE_CAPACITY);
+                predicates.extend_from_slice(&inline.predicates);
+                predicates.push(predicate);
+                self.predicates_in_conjunction = Predicates::Heap(predicates);
+            }
+            Predicates::Heap(predicates) => predicates.push(predicate),
+        }
     }
 
     pub fn iter(&self) -> std::slice::Iter<'_, Predicate> {
-        self.predicates_in_conjunction.iter()
+        self.as_slice().iter()
     }
 
     pub fn pop(&mut self) -> Option<Predicate> {
-        self.predicates_in_conjunction.pop()
+        match &mut self.predicates_in_conjunction {
+            Predicates::Inline(inline) => {
+                if inline.len == 0 {
+                    return None;
+                }
+                inline.len -= 1;
+                Some(inline.predicates[inline.len])
+            }
+            Predicates::Heap(predicates) => predicates.pop(),
+        }
     }
 
     pub fn contains(&self, predicate: &Predicate) -> bool {
-        self.predicates_in_conjunction.contains(predicate)
+        self.as_slice().contains(predicate)
     }
 
     #[cfg(any(feature = "explanation-checks", test))]
     pub(crate) fn is_empty(&self) -> bool {
         self.num_predicates() == 0
     }
+
+    fn as_slice(&self) -> &[Predicate] {
+        match &self.predicates_in_conjunction {
+            Predicates::Inline(inline) => &inline.predicates[..inline.len],
+            Predicates::Heap(predicates) => predicates,
+        }
+    }
+}
+
+impl Extend<Predicate> for PropositionalConjunction {
+    fn extend<T: IntoIterator<Item = Predicate>>(&mut self, iter: T) {
+        for predicate in iter {
+            self.add(predicate);
+        }
+    }
 }
 
 impl FromIterator<Predicate> for PropositionalConjunction {
     fn from_iter<T: IntoIterator<Item = Predicate>>(iter: T) -> Self {
-        let vec = iter.into_iter().collect();
-        PropositionalConjunction {
-            predicates_in_conjunction: vec,
-        }
+        let mut conjunction = PropositionalConjunction::default();
+        conjunction.extend(iter);
+        conjunction
     }
 }
 
@@ -57,25 +143,22 @@ impl From<Vec<Predicate>> for PropositionalConjunction {
 
 impl From<Predicate> for PropositionalConjunction {
     fn from(predicate: Predicate) -> Self {
-        PropositionalConjunction {
-            predicates_in_conjunction: vec![predicate],
-        }
+        std::iter::once(predicate).collect()
     }
 }
 
 impl std::fmt::Display for PropositionalConjunction {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if self.predicates_in_conjunction.is_empty() {
-            write!(f, "{{empty}}")
+        if self.as_slice().is_empty() {
+            write!(f, "true")
         } else {
             write!(
                 f,
                 "{}",
-                self.predicates_in_conjunction
-                    .iter()
+                self.iter()
                     .map(|p| p.to_string())
                     .collect::<Vec<String>>()
-                    .join("; ")
+                    .join(" /\\ ")
             )
         }
     }
@@ -89,16 +172,16 @@ impl std::fmt::Debug for PropositionalConjunction {
 
 impl PartialEq for PropositionalConjunction {
     fn eq(&self, other: &Self) -> bool {
-        if self.predicates_in_conjunction.len() != other.predicates_in_conjunction.len() {
+        if self.num_predicates() != other.num_predicates() {
             return false;
         }
 
-        self.predicates_in_conjunction
-            .iter()
-            .all(|predicate| other.predicates_in_conjunction.contains(predicate))
+        self.iter().all(|predicate| other.contains(predicate))
     }
 }
 
+impl Eq for PropositionalConjunction {}
+
 /// A macro which allows for the creation of a [`PropositionalConjunction`].
 ///
 /// # Example
@@ -124,11 +207,11 @@ macro_rules! conjunction {
     };
 
     (@munch {$($body:tt)*} -> & [$($pred:tt)+] $($rest:tt)*) => {
-        conjunction!(@munch {$crate::predicate![$($pred)+], $($body)*} -> $($rest)*)
+        $crate::conjunction!(@munch {$crate::predicate![$($pred)+], $($body)*} -> $($rest)*)
     };
 
     (@munch {$($body:tt)*} -> ) => {
-        conjunction!(@to_conjunction vec![$($body)*])
+        $crate::conjunction!(@to_conjunction vec![$($body)*])
     };
 
     (@munch {$($body:tt)*} -> $($rest:tt)+) => {
@@ -136,11 +219,11 @@ macro_rules! conjunction {
     };
 
     ($($input:tt)+) => {
-        conjunction!(@munch {} -> & $($input)*)
+        $crate::conjunction!(@munch {} -> & $($input)*)
     };
 
     () => {
-        conjunction!(@to_conjunction vec![])
+        $crate::conjunction!(@to_conjunction vec![])
     };
 }
 
@@ -185,4 +268,34 @@ mod tests {
 
         assert_eq!(conjunction!([w.x == 1]), conjunction);
     }
+
+    #[test]
+    fn conjunction_grows_beyond_inline_capacity() {
+        let x = DomainId { id: 0 };
+        let predicates = (0..2 * INLINE_CAPACITY as i32)
+            .map(|value| predicate![x != value])
+            .collect::<Vec<_>>();
+
+        let mut conjunction = PropositionalConjunction::default();
+        for &predicate in predicates.iter() {
+            conjunction.add(predicate);
+        }
+        assert!(conjunction.iter().eq(predicates.iter()));
+        assert!(conjunction.clone().into_buffer().is_some());
+
+        for &predicate in predicates.iter().rev() {
+            assert_eq!(conjunction.pop(), Some(predicate));
+        }
+        assert_eq!(conjunction.pop(), None);
+    }
+
+    #[test]
+    fn small_conjunction_is_stored_inline() {
+        let x = DomainId { id: 0 };
+        let conjunction =
+            PropositionalConjunction::from(vec![predicate![x >= 1], predicate![x <= 3]]);
+
+        assert_eq!(conjunction.num_predicates(), 2);
+        assert!(conjunction.into_buffer().is_none());
+    }
 }
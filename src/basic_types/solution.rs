@@ -1,8 +1,13 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
 use crate::engine::cp::propagation::propagation_context::HasAssignments;
 use crate::engine::cp::AssignmentsInteger;
 use crate::engine::sat::AssignmentsPropositional;
 use crate::engine::variables::Literal;
 use crate::engine::variables::PropositionalVariable;
+use crate::engine::VariableNames;
 use crate::munchkin_assert_moderate;
 use crate::variables::IntegerVariable;
 
@@ -44,15 +49,50 @@ pub trait ProblemSolution: HasAssignments {
             .is_literal_assigned_true(literal)
     }
 
-    /// Returns the assigned integer value of the provided [`DomainId`].
-    fn get_integer_value(&self, variable: impl IntegerVariable) -> i32 {
-        munchkin_assert_moderate!(
-            variable.is_fixed(self.assignments_integer()),
-            "Expected retrieved integer variable from solution to be assigned"
-        );
+    /// Returns whether the provided variable is fixed (i.e. assigned to a single value) in the
+    /// solution.
+    fn is_fixed(&self, variable: impl IntegerVariable) -> bool {
+        variable.is_fixed(self.assignments_integer())
+    }
 
+    /// Returns the assigned integer value of the provided variable.
+    ///
+    /// A variable is not necessarily fixed in a solution, e.g. when the brancher does not branch
+    /// over it and propagation does not fix it; in this case, its lower bound is returned. Use
+    /// [`ProblemSolution::try_get_integer_value`] to detect this case.
+    fn get_integer_value(&self, variable: impl IntegerVariable) -> i32 {
         variable.lower_bound(self.assignments_integer())
     }
+
+    /// Returns the assigned integer value of the provided variable, or an [`UnfixedVariable`] error
+    /// if the variable is not fixed in the solution.
+    fn try_get_integer_value(
+        &self,
+        variable: impl IntegerVariable,
+    ) -> Result<i32, UnfixedVariable> {
+        let lower_bound = variable.lower_bound(self.assignments_integer());
+        let upper_bound = variable.upper_bound(self.assignments_integer());
+
+        if lower_bound == upper_bound {
+            Ok(lower_bound)
+        } else {
+            Err(UnfixedVariable {
+                lower_bound,
+                upper_bound,
+            })
+        }
+    }
+}
+
+/// The error which is returned by [`ProblemSolution::try_get_integer_value`] when the variable is
+/// not fixed in the solution.
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+#[error("the variable is not fixed in the solution, its domain is [{lower_bound}, {upper_bound}]")]
+pub struct UnfixedVariable {
+    /// The lower bound of the variable in the solution.
+    pub lower_bound: i32,
+    /// The upper bound of the variable in the solution.
+    pub upper_bound: i32,
 }
 
 /// A solution which keeps reference to its inner structures.
@@ -60,6 +100,8 @@ pub trait ProblemSolution: HasAssignments {
 pub struct SolutionReference<'a> {
     assignments_propositional: &'a AssignmentsPropositional,
     assignments_integer: &'a AssignmentsInteger,
+    /// The names of the variables in the solver which created the solution, if they are known.
+    variable_names: Option<&'a Arc<VariableNames>>,
 }
 
 impl<'a> SolutionReference<'a> {
@@ -70,9 +112,20 @@ impl<'a> SolutionReference<'a> {
         SolutionReference {
             assignments_propositional,
             assignments_integer,
+            variable_names: None,
         }
     }
 
+    /// Attaches the names of the variables to the solution; these are used to match the variables
+    /// of a [`Solution`] to those of another solver (see
+    /// [`Solver::minimise_with_initial_solution`]) and to serialise it.
+    ///
+    /// [`Solver::minimise_with_initial_solution`]: crate::Solver::minimise_with_initial_solution
+    pub(crate) fn with_variable_names(mut self, variable_names: &'a Arc<VariableNames>) -> Self {
+        self.variable_names = Some(variable_names);
+        self
+    }
+
     pub fn get_propostional_variables(&self) -> impl Iterator<Item = PropositionalVariable> {
         self.assignments_propositional.get_propositional_variables()
     }
@@ -81,10 +134,20 @@ impl<'a> SolutionReference<'a> {
 impl ProblemSolution for SolutionReference<'_> {}
 
 /// A solution which takes ownership of its inner structures.
-#[derive(Debug, Default)]
+///
+/// With the `serde` feature, a solution can be (de)serialised as a map from the names of the
+/// integer variables to their values, such that it remains meaningful across runs (e.g. to be
+/// used as the initial solution in [`Solver::minimise_with_initial_solution`]). Only the integer
+/// variables which have a name and which are fixed in the solution are serialised.
+///
+/// [`Solver::minimise_with_initial_solution`]: crate::Solver::minimise_with_initial_solution
+#[derive(Clone, Debug, Default)]
 pub struct Solution {
     assignments_propositional: AssignmentsPropositional,
     assignments_integer: AssignmentsInteger,
+    /// The names of the variables; the names are shared between the solutions of a solver, so
+    /// they are not copied for every solution.
+    variable_names: Arc<VariableNames>,
 }
 
 impl Solution {
@@ -95,6 +158,7 @@ impl Solution {
         Self {
             assignments_propositional,
             assignments_integer,
+            variable_names: Arc::default(),
         }
     }
 
@@ -102,8 +166,14 @@ impl Solution {
         SolutionReference {
             assignments_propositional: &self.assignments_propositional,
             assignments_integer: &self.assignments_integer,
+            variable_names: Some(&self.variable_names),
         }
     }
+
+    /// Returns the names of the variables in the solution.
+    pub(crate) fn variable_names(&self) -> &VariableNames {
+        &self.variable_names
+    }
 }
 
 impl ProblemSolution for Solution {}
@@ -113,6 +183,7 @@ impl From<SolutionReference<'_>> for Solution {
         Self {
             assignments_propositional: value.assignments_propositional.clone(),
             assignments_integer: value.assignments_integer.clone(),
+            variable_names: value.variable_names.map(Arc::clone).unwrap_or_default(),
         }
     }
 }
@@ -136,3 +207,45 @@ impl HasAssignments for Solution {
         &self.assignments_propositional
     }
 }
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Solution {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        let mut map = serializer.serialize_map(None)?;
+        for domain in self.assignments_integer.get_domains() {
+            let Some(name) = self.variable_names.get_int_name(domain) else {
+                continue;
+            };
+
+            if let Ok(value) = self.try_get_integer_value(domain) {
+                map.serialize_entry(name, &value)?;
+            }
+        }
+        map.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Solution {
+    /// Deserialises a solution in which every variable is fixed to its value; the identifiers of
+    /// the variables are not related to those of any solver, the variables can only be matched by
+    /// their names.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let values = std::collections::BTreeMap::<String, i32>::deserialize(deserializer)?;
+
+        let mut assignments_integer = AssignmentsInteger::default();
+        let mut variable_names = VariableNames::default();
+        for (name, value) in values {
+            let domain = assignments_integer.grow(value, value);
+            variable_names.add_integer(domain, name);
+        }
+
+        Ok(Solution {
+            assignments_propositional: AssignmentsPropositional::default(),
+            assignments_integer,
+            variable_names: Arc::new(variable_names),
+        })
+    }
+}
@@ -0,0 +1,90 @@
+use super::statistic_logging::statistic_logger::log_statistic;
+
+/// Accumulates the terms of a statistic and keeps track of their count, sum, minimum and maximum,
+/// from which the mean can be derived.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StatisticAccumulator {
+    count: u64,
+    sum: u64,
+    min: Option<u64>,
+    max: Option<u64>,
+}
+
+impl StatisticAccumulator {
+    /// Adds a term to the statistic.
+    pub fn add_term(&mut self, term: u64) {
+        self.count += 1;
+        self.sum += term;
+        self.min = Some(self.min.map_or(term, |min| min.min(term)));
+        self.max = Some(self.max.map_or(term, |max| max.max(term)));
+    }
+
+    /// The number of terms which have been added.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// The sum of all terms which have been added.
+    pub fn sum(&self) -> u64 {
+        self.sum
+    }
+
+    /// The smallest term which has been added or [`None`] if no terms have been added.
+    pub fn min(&self) -> Option<u64> {
+        self.min
+    }
+
+    /// The largest term which has been added or [`None`] if no terms have been added.
+    pub fn max(&self) -> Option<u64> {
+        self.max
+    }
+
+    /// The mean of the terms which have been added; this is 0 if no terms have been added.
+    pub fn mean(&self) -> f64 {
+        if self.count > 0 {
+            self.sum as f64 / self.count as f64
+        } else {
+            0.0
+        }
+    }
+
+    /// Logs the summary of the statistic, where every value is logged with the provided `name`
+    /// followed by the name of the value (e.g. `{name}Mean`).
+    pub(crate) fn log_statistics(&self, name: &str) {
+        log_statistic(format!("{name}Count"), self.count);
+        log_statistic(format!("{name}Sum"), self.sum);
+        log_statistic(format!("{name}Min"), self.min.unwrap_or_default());
+        log_statistic(format!("{name}Max"), self.max.unwrap_or_default());
+        log_statistic(format!("{name}Mean"), self.mean());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StatisticAccumulator;
+
+    #[test]
+    fn empty_accumulator_has_no_extremes() {
+        let accumulator = StatisticAccumulator::default();
+
+        assert_eq!(accumulator.count(), 0);
+        assert_eq!(accumulator.sum(), 0);
+        assert_eq!(accumulator.min(), None);
+        assert_eq!(accumulator.max(), None);
+        assert_eq!(accumulator.mean(), 0.0);
+    }
+
+    #[test]
+    fn accumulator_tracks_summary_of_terms() {
+        let mut accumulator = StatisticAccumulator::default();
+        for term in [3, 1, 4, 0] {
+            accumulator.add_term(term);
+        }
+
+        assert_eq!(accumulator.count(), 4);
+        assert_eq!(accumulator.sum(), 8);
+        assert_eq!(accumulator.min(), Some(0));
+        assert_eq!(accumulator.max(), Some(4));
+        assert_eq!(accumulator.mean(), 2.0);
+    }
+}
@@ -32,7 +32,22 @@ impl<T> Trail<T> {
         self.current_decision_level
     }
 
-    pub(crate) fn synchronise(&mut self, new_decision_level: usize) -> Rev<Drain<T>> {
+    /// Returns the number of bytes allocated for the entries and the decision level delimiters,
+    /// based on the capacity of the underlying storage.
+    pub(crate) fn approximate_memory_usage(&self) -> usize {
+        self.trail.capacity() * size_of::<T>()
+            + self.trail_delimiter.capacity() * size_of::<usize>()
+    }
+
+    /// Returns the number of entries which were pushed onto the trail at the root.
+    pub(crate) fn num_root_entries(&self) -> usize {
+        self.trail_delimiter
+            .first()
+            .copied()
+            .unwrap_or(self.trail.len())
+    }
+
+    pub(crate) fn synchronise(&mut self, new_decision_level: usize) -> Rev<Drain<'_, T>> {
         munchkin_assert_simple!(new_decision_level < self.current_decision_level);
 
         let new_trail_len = self.trail_delimiter[new_decision_level];
@@ -42,6 +57,15 @@ impl<T> Trail<T> {
         self.trail.drain(new_trail_len..).rev()
     }
 
+    /// Removes the entries beyond the first `new_len` entries from the trail, which can only be
+    /// done at the root; this undoes root-level changes rather than backtracking.
+    pub(crate) fn truncate_root(&mut self, new_len: usize) -> Rev<Drain<'_, T>> {
+        munchkin_assert_simple!(self.current_decision_level == 0);
+        munchkin_assert_simple!(new_len <= self.trail.len());
+
+        self.trail.drain(new_len..).rev()
+    }
+
     pub(crate) fn push(&mut self, elem: T) {
         self.trail.push(elem)
     }
@@ -120,4 +144,17 @@ mod tests {
         let popped = trail.synchronise(0).collect::<Vec<_>>();
         assert_eq!(vec![4, 3, 2], popped);
     }
+
+    #[test]
+    fn truncating_at_the_root_removes_the_last_entries() {
+        let mut trail = Trail::default();
+        trail.push(1);
+        trail.push(2);
+        trail.push(3);
+
+        let removed = trail.truncate_root(1).collect::<Vec<_>>();
+
+        assert_eq!(vec![3, 2], removed);
+        assert_eq!(&[1], trail.deref());
+    }
 }
@@ -0,0 +1,23 @@
+use std::num::NonZero;
+
+use thiserror::Error;
+
+use crate::engine::variables::Literal;
+#[cfg(doc)]
+use crate::Solver;
+
+/// A constraint which is violated by a solution; see [`Solver::verify_solution`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ViolatedConstraint {
+    /// The constraint of a propagator is violated.
+    #[error("The solution violates propagator '{name}' of the constraint with tag {tag}")]
+    Propagator {
+        /// The name of the propagator.
+        name: String,
+        /// The tag of the constraint which the propagator was posted for.
+        tag: NonZero<u32>,
+    },
+    /// All literals of a clause are false.
+    #[error("The solution violates the clause {literals:?}")]
+    Clause { literals: Vec<Literal> },
+}
@@ -10,11 +10,14 @@ use crate::branching::value_selection::ValueSelector;
 #[cfg(doc)]
 use crate::branching::variable_selection::VariableSelector;
 use crate::branching::SelectionContext;
+#[cfg(doc)]
+use crate::engine::constraint_satisfaction_solver::ConflictResolutionStrategy;
 use crate::engine::predicates::predicate::Predicate;
 use crate::engine::variables::DomainId;
 use crate::engine::variables::Literal;
 #[cfg(doc)]
 use crate::results::solution_iterator::SolutionIterator;
+use crate::statistics::SearchStatistics;
 
 /// A trait for definining a branching strategy (oftentimes utilising a [`VariableSelector`] and a
 /// [`ValueSelector`]).
@@ -54,6 +57,25 @@ pub trait Brancher {
     /// backtracking operation by the solver.
     fn on_unassign_integer(&mut self, _variable: DomainId, _value: i32) {}
 
+    /// A function which is called after the solver has backtracked chronologically and took the
+    /// other branch of the last decision (see [`ConflictResolutionStrategy::NoLearning`]),
+    /// specifically, it provides `literal` which is the negation of that decision and which is now
+    /// assigned at the previous decision level.
+    fn on_branch_flipped(&mut self, _literal: Literal) {}
+
+    /// Returns whether the current branch should be abandoned without extending it, which is
+    /// called before every decision; if it returns `true`, then the solver backtracks as if the
+    /// current branch led to a conflict.
+    ///
+    /// Since the abandoned branch does not contain a conflict, there is nothing to learn from it,
+    /// so the solver should use chronological backtracking (i.e.
+    /// [`ConflictResolutionStrategy::NoLearning`]). Note that the solver reports that the problem
+    /// is unsatisfiable if the branch is abandoned at the root, or if all branches are exhausted
+    /// after one of them was abandoned.
+    fn refuses_branch(&mut self) -> bool {
+        false
+    }
+
     /// A function which is called when a [`Literal`] appears in a conflict during conflict
     /// analysis.
     fn on_appearance_in_conflict_literal(&mut self, _literal: Literal) {}
@@ -68,8 +90,9 @@ pub trait Brancher {
     /// [`SolutionIterator`].
     fn on_solution(&mut self, _solution: SolutionReference) {}
 
-    /// This method is called whenever a restart is performed.
-    fn on_restart(&mut self) {}
+    /// This method is called whenever a restart is performed; it provides a snapshot of the
+    /// [`SearchStatistics`] at the moment of the restart.
+    fn on_restart(&mut self, _statistics: &SearchStatistics) {}
 }
 
 impl Brancher for Box<dyn Brancher> {
@@ -89,6 +112,14 @@ impl Brancher for Box<dyn Brancher> {
         self.deref_mut().on_unassign_integer(variable, value);
     }
 
+    fn on_branch_flipped(&mut self, literal: Literal) {
+        self.deref_mut().on_branch_flipped(literal);
+    }
+
+    fn refuses_branch(&mut self) -> bool {
+        self.deref_mut().refuses_branch()
+    }
+
     fn on_appearance_in_conflict_literal(&mut self, literal: Literal) {
         self.deref_mut().on_appearance_in_conflict_literal(literal);
     }
@@ -101,7 +132,7 @@ impl Brancher for Box<dyn Brancher> {
         self.deref_mut().on_solution(solution);
     }
 
-    fn on_restart(&mut self) {
-        self.deref_mut().on_restart();
+    fn on_restart(&mut self, statistics: &SearchStatistics) {
+        self.deref_mut().on_restart(statistics);
     }
 }
@@ -0,0 +1,249 @@
+//! A [`Brancher`] which switches between multiple [`Brancher`]s during the search based on the
+//! [`SearchStatistics`] of the solver.
+
+use crate::basic_types::SolutionReference;
+use crate::branching::Brancher;
+use crate::branching::SelectionContext;
+use crate::engine::predicates::predicate::Predicate;
+use crate::engine::variables::DomainId;
+use crate::engine::variables::Literal;
+use crate::munchkin_assert_simple;
+use crate::statistics::SearchStatistics;
+
+/// A policy which determines which of the [`Brancher`]s of a [`DynamicBrancher`] is used.
+pub trait SwitchPolicy {
+    /// Returns the index of the [`Brancher`] which should be used until the next restart; this
+    /// method is only called when a restart is performed.
+    fn select(&mut self, statistics: &SearchStatistics) -> usize;
+}
+
+/// A [`SwitchPolicy`] which uses the first [`Brancher`] until the provided number of conflicts has
+/// been encountered, after which it switches to the second [`Brancher`].
+#[derive(Debug, Clone, Copy)]
+pub struct SwitchAfterConflicts(pub u64);
+
+impl SwitchPolicy for SwitchAfterConflicts {
+    fn select(&mut self, statistics: &SearchStatistics) -> usize {
+        if statistics.conflicts >= self.0 {
+            1
+        } else {
+            0
+        }
+    }
+}
+
+/// An implementation of a [`Brancher`] which makes decisions using one of multiple [`Brancher`]s,
+/// where the [`SwitchPolicy`] determines which one is used.
+///
+/// The [`SwitchPolicy`] is only consulted when a restart is performed (see
+/// [`Brancher::on_restart`]) to ensure that a single search path is not made up of decisions from
+/// different [`Brancher`]s; initially, the first [`Brancher`] is used. Note that the other
+/// callbacks are forwarded to every [`Brancher`], including the ones which are currently not in
+/// use.
+pub struct DynamicBrancher<Policy> {
+    branchers: Vec<Box<dyn Brancher>>,
+    policy: Policy,
+    active_brancher: usize,
+}
+
+impl<Policy> std::fmt::Debug for DynamicBrancher<Policy> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DynamicBrancher")
+            .field("num_branchers", &self.branchers.len())
+            .field("active_brancher", &self.active_brancher)
+            .finish()
+    }
+}
+
+impl<Policy: SwitchPolicy> DynamicBrancher<Policy> {
+    pub fn new(branchers: Vec<Box<dyn Brancher>>, policy: Policy) -> Self {
+        munchkin_assert_simple!(
+            !branchers.is_empty(),
+            "A dynamic brancher requires at least one brancher"
+        );
+
+        DynamicBrancher {
+            branchers,
+            policy,
+            active_brancher: 0,
+        }
+    }
+
+    /// Returns the index of the [`Brancher`] which is currently used to make decisions.
+    pub fn active_brancher(&self) -> usize {
+        self.active_brancher
+    }
+}
+
+impl<Policy: SwitchPolicy> Brancher for DynamicBrancher<Policy> {
+    fn next_decision(&mut self, context: &mut SelectionContext) -> Option<Predicate> {
+        self.branchers[self.active_brancher].next_decision(context)
+    }
+
+    fn on_conflict(&mut self) {
+        self.branchers
+            .iter_mut()
+            .for_each(|brancher| brancher.on_conflict());
+    }
+
+    fn on_unassign_literal(&mut self, literal: Literal) {
+        self.branchers
+            .iter_mut()
+            .for_each(|brancher| brancher.on_unassign_literal(literal));
+    }
+
+    fn on_unassign_integer(&mut self, variable: DomainId, value: i32) {
+        self.branchers
+            .iter_mut()
+            .for_each(|brancher| brancher.on_unassign_integer(variable, value));
+    }
+
+    fn on_branch_flipped(&mut self, literal: Literal) {
+        self.branchers
+            .iter_mut()
+            .for_each(|brancher| brancher.on_branch_flipped(literal));
+    }
+
+    fn refuses_branch(&mut self) -> bool {
+        self.branchers[self.active_brancher].refuses_branch()
+    }
+
+    fn on_appearance_in_conflict_literal(&mut self, literal: Literal) {
+        self.branchers
+            .iter_mut()
+            .for_each(|brancher| brancher.on_appearance_in_conflict_literal(literal));
+    }
+
+    fn on_appearance_in_conflict_integer(&mut self, variable: DomainId) {
+        self.branchers
+            .iter_mut()
+            .for_each(|brancher| brancher.on_appearance_in_conflict_integer(variable));
+    }
+
+    fn on_solution(&mut self, solution: SolutionReference) {
+        self.branchers
+            .iter_mut()
+            .for_each(|brancher| brancher.on_solution(solution));
+    }
+
+    fn on_restart(&mut self, statistics: &SearchStatistics) {
+        self.branchers
+            .iter_mut()
+            .for_each(|brancher| brancher.on_restart(statistics));
+
+        let selected = self.policy.select(statistics);
+        munchkin_assert_simple!(
+            selected < self.branchers.len(),
+            "The switch policy selected brancher {selected} but there are only {} branchers",
+            self.branchers.len()
+        );
+        self.active_brancher = selected;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::basic_types::tests::TestRandom;
+    use crate::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
+    use crate::branching::InDomainMin;
+    use crate::branching::InputOrder;
+
+    /// A [`Brancher`] which records, for every decision, its identifier together with the number
+    /// of conflicts it has been notified of.
+    struct RecordingBrancher {
+        id: usize,
+        num_conflicts: u64,
+        brancher: Box<dyn Brancher>,
+        decisions: Rc<RefCell<Vec<(usize, u64)>>>,
+    }
+
+    impl Brancher for RecordingBrancher {
+        fn next_decision(&mut self, context: &mut SelectionContext) -> Option<Predicate> {
+            let decision = self.brancher.next_decision(context);
+            if decision.is_some() {
+                self.decisions
+                    .borrow_mut()
+                    .push((self.id, self.num_conflicts));
+            }
+            decision
+        }
+
+        fn on_conflict(&mut self) {
+            self.num_conflicts += 1;
+        }
+    }
+
+    #[test]
+    fn switch_after_conflicts_selects_second_brancher_once_reached() {
+        let mut policy = SwitchAfterConflicts(3);
+
+        let statistics = |conflicts| SearchStatistics {
+            conflicts,
+            ..Default::default()
+        };
+        assert_eq!(policy.select(&statistics(0)), 0);
+        assert_eq!(policy.select(&statistics(2)), 0);
+        assert_eq!(policy.select(&statistics(3)), 1);
+        assert_eq!(policy.select(&statistics(10)), 1);
+    }
+
+    #[test]
+    fn decisions_switch_to_second_brancher_after_conflict_count() {
+        let (assignments_integer, assignments_propositional) =
+            SelectionContext::create_for_testing(2, 0, Some(vec![(0, 10), (0, 10)]));
+        let mut test_rng = TestRandom::default();
+        let mut context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut test_rng,
+        );
+        let domain_ids = context.get_domains().collect::<Vec<_>>();
+
+        let decisions = Rc::new(RefCell::new(vec![]));
+        let branchers = (0..2)
+            .map(|id| -> Box<dyn Brancher> {
+                Box::new(RecordingBrancher {
+                    id,
+                    num_conflicts: 0,
+                    brancher: Box::new(IndependentVariableValueBrancher::new(
+                        InputOrder::new(domain_ids.clone()),
+                        InDomainMin,
+                    )),
+                    decisions: Rc::clone(&decisions),
+                })
+            })
+            .collect();
+        let mut brancher = DynamicBrancher::new(branchers, SwitchAfterConflicts(3));
+
+        // Every round makes a decision, encounters a conflict and then restarts
+        let mut statistics = SearchStatistics::default();
+        let mut num_switches = 0;
+        for _ in 0..6 {
+            assert!(brancher.next_decision(&mut context).is_some());
+
+            brancher.on_conflict();
+            statistics.conflicts += 1;
+            statistics.restarts += 1;
+
+            let previous = brancher.active_brancher();
+            brancher.on_restart(&statistics);
+            if brancher.active_brancher() != previous {
+                num_switches += 1;
+            }
+        }
+
+        assert_eq!(num_switches, 1);
+        assert_eq!(brancher.active_brancher(), 1);
+
+        // The conflicts are forwarded to both branchers, so the decisions of the second brancher
+        // are exactly the ones made after the third conflict
+        assert_eq!(
+            decisions.borrow().as_slice(),
+            &[(0, 0), (0, 1), (0, 2), (1, 3), (1, 4), (1, 5)]
+        );
+    }
+}
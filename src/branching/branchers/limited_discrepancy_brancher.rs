@@ -0,0 +1,267 @@
+//! A [`Brancher`] which limits the number of discrepancies along a branch, and the driver
+//! [`lds_satisfy`] which uses it for limited discrepancy search.
+
+use crate::basic_types::HashSet;
+use crate::basic_types::SolutionReference;
+use crate::branching::Brancher;
+use crate::branching::SelectionContext;
+#[cfg(doc)]
+use crate::engine::constraint_satisfaction_solver::ConflictResolutionStrategy;
+use crate::engine::predicates::predicate::Predicate;
+use crate::engine::termination::TerminationCondition;
+use crate::engine::termination::TerminationReason;
+use crate::engine::variables::DomainId;
+use crate::engine::variables::Literal;
+#[cfg(doc)]
+use crate::options::SolverOptions;
+use crate::results::SatisfactionResult;
+use crate::statistics::SearchStatistics;
+use crate::Solver;
+
+/// A [`Brancher`] which makes the decisions of the provided [`Brancher`], but which refuses (see
+/// [`Brancher::refuses_branch`]) every branch which deviates from these decisions more than
+/// `max_discrepancies` times.
+///
+/// A discrepancy is a decision of which the solver took the other branch after backtracking
+/// chronologically (see [`Brancher::on_branch_flipped`]); the discrepancies are counted as long
+/// as the negated decisions remain assigned. The brancher thus relies on chronological
+/// backtracking, i.e. [`ConflictResolutionStrategy::NoLearning`].
+///
+/// The other callbacks are forwarded to the provided [`Brancher`].
+#[derive(Debug)]
+pub struct LimitedDiscrepancyBrancher<B> {
+    brancher: B,
+    max_discrepancies: usize,
+    /// The negated decisions which are currently assigned.
+    discrepancies: HashSet<Literal>,
+    /// Whether a branch was refused since the brancher was created.
+    has_refused_branch: bool,
+}
+
+impl<B: Brancher> LimitedDiscrepancyBrancher<B> {
+    pub fn new(brancher: B, max_discrepancies: usize) -> Self {
+        LimitedDiscrepancyBrancher {
+            brancher,
+            max_discrepancies,
+            discrepancies: HashSet::default(),
+            has_refused_branch: false,
+        }
+    }
+
+    /// Returns whether a branch was refused because it exceeded the maximum number of
+    /// discrepancies; if so, an unsatisfiable result only means that there is no solution within
+    /// the maximum number of discrepancies.
+    pub fn has_refused_branch(&self) -> bool {
+        self.has_refused_branch
+    }
+
+    /// Returns the provided [`Brancher`].
+    pub fn into_inner(self) -> B {
+        self.brancher
+    }
+}
+
+impl<B: Brancher> Brancher for LimitedDiscrepancyBrancher<B> {
+    fn next_decision(&mut self, context: &mut SelectionContext) -> Option<Predicate> {
+        self.brancher.next_decision(context)
+    }
+
+    fn on_conflict(&mut self) {
+        self.brancher.on_conflict();
+    }
+
+    fn on_unassign_literal(&mut self, literal: Literal) {
+        let _ = self.discrepancies.remove(&literal);
+        self.brancher.on_unassign_literal(literal);
+    }
+
+    fn on_unassign_integer(&mut self, variable: DomainId, value: i32) {
+        self.brancher.on_unassign_integer(variable, value);
+    }
+
+    fn on_branch_flipped(&mut self, literal: Literal) {
+        let _ = self.discrepancies.insert(literal);
+        self.brancher.on_branch_flipped(literal);
+    }
+
+    fn refuses_branch(&mut self) -> bool {
+        if self.discrepancies.len() > self.max_discrepancies {
+            self.has_refused_branch = true;
+            return true;
+        }
+
+        self.brancher.refuses_branch()
+    }
+
+    fn on_appearance_in_conflict_literal(&mut self, literal: Literal) {
+        self.brancher.on_appearance_in_conflict_literal(literal);
+    }
+
+    fn on_appearance_in_conflict_integer(&mut self, variable: DomainId) {
+        self.brancher.on_appearance_in_conflict_integer(variable);
+    }
+
+    fn on_solution(&mut self, solution: SolutionReference) {
+        self.brancher.on_solution(solution);
+    }
+
+    fn on_restart(&mut self, statistics: &SearchStatistics) {
+        self.brancher.on_restart(statistics);
+    }
+}
+
+/// Searches for a solution using limited discrepancy search: the problem is solved using a
+/// [`LimitedDiscrepancyBrancher`] around `brancher` with a maximum of `0, 1, ...,
+/// max_discrepancies` discrepancies, until a solution is found or the problem is shown to be
+/// unsatisfiable.
+///
+/// Every iteration starts from the root state of `solver` at the moment this function was
+/// called, since the root assignments derived in an iteration are only valid within its maximum
+/// number of discrepancies. The `solver` should backtrack chronologically (see
+/// [`SolverOptions::conflict_resolver`]).
+///
+/// Returns [`SatisfactionResult::Unknown`] if no solution is found within `max_discrepancies`
+/// discrepancies (with [`TerminationReason::Unspecified`]), or if `termination` is triggered.
+pub fn lds_satisfy<B: Brancher, T: TerminationCondition>(
+    solver: &mut Solver,
+    brancher: B,
+    termination: &mut T,
+    max_discrepancies: usize,
+) -> SatisfactionResult {
+    let snapshot = solver.snapshot();
+    let mut brancher = brancher;
+
+    for discrepancies in 0..=max_discrepancies {
+        let mut lds_brancher = LimitedDiscrepancyBrancher::new(brancher, discrepancies);
+        let result = solver.satisfy(&mut lds_brancher, termination);
+        solver.rollback_to(snapshot);
+
+        match result {
+            SatisfactionResult::Unsatisfiable if lds_brancher.has_refused_branch() => {
+                brancher = lds_brancher.into_inner();
+            }
+            result => return result,
+        }
+    }
+
+    SatisfactionResult::Unknown(TerminationReason::Unspecified)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZero;
+
+    use super::lds_satisfy;
+    use super::LimitedDiscrepancyBrancher;
+    use super::TerminationReason;
+    use crate::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
+    use crate::branching::InDomainMin;
+    use crate::branching::InputOrder;
+    use crate::constraints;
+    use crate::results::ProblemSolution;
+    use crate::results::SatisfactionResult;
+    use crate::termination::Indefinite;
+    use crate::variables::DomainId;
+    use crate::variables::TransformableVariable;
+    use crate::Solver;
+
+    /// Creates a problem over four 0-1 variables with the unique solution `[1, 0, 1, 0]`, which
+    /// the heuristic of assigning the minimum value gets wrong in exactly two positions. The
+    /// constraints only propagate once most of the variables are fixed, so the heuristic is only
+    /// corrected by backtracking.
+    fn two_discrepancy_problem() -> (Solver, Vec<DomainId>) {
+        let mut solver = Solver::default();
+        let xs = (0..4)
+            .map(|_| solver.new_bounded_integer(0, 1))
+            .collect::<Vec<_>>();
+        let tag = NonZero::new(1).unwrap();
+
+        let _ = solver
+            .add_constraint(constraints::equals(xs.clone(), 2))
+            .post(tag);
+        // Every other assignment with two ones is excluded by its binary value
+        let binary_value = [
+            xs[0].scaled(8),
+            xs[1].scaled(4),
+            xs[2].scaled(2),
+            xs[3].scaled(1),
+        ];
+        for excluded in [0b0011, 0b0101, 0b0110, 0b1001, 0b1100] {
+            let _ = solver
+                .add_constraint(constraints::not_equals(binary_value.clone(), excluded))
+                .post(tag);
+        }
+
+        (solver, xs)
+    }
+
+    fn min_value_brancher(
+        xs: &[DomainId],
+    ) -> IndependentVariableValueBrancher<DomainId, InputOrder<DomainId>, InDomainMin> {
+        IndependentVariableValueBrancher::new(InputOrder::new(xs.to_vec()), InDomainMin)
+    }
+
+    #[test]
+    fn solution_is_found_within_two_discrepancies() {
+        let (mut solver, xs) = two_discrepancy_problem();
+        let mut brancher = LimitedDiscrepancyBrancher::new(min_value_brancher(&xs), 2);
+
+        let result = solver.satisfy(&mut brancher, &mut Indefinite);
+
+        let SatisfactionResult::Satisfiable(solution) = result else {
+            panic!("expected a solution, got {result:?}");
+        };
+        let values = xs
+            .iter()
+            .map(|&x| solution.get_integer_value(x))
+            .collect::<Vec<_>>();
+        assert_eq!(values, vec![1, 0, 1, 0]);
+    }
+
+    #[test]
+    fn solution_is_not_found_within_one_discrepancy() {
+        let (mut solver, xs) = two_discrepancy_problem();
+        let mut brancher = LimitedDiscrepancyBrancher::new(min_value_brancher(&xs), 1);
+
+        let result = solver.satisfy(&mut brancher, &mut Indefinite);
+
+        assert!(matches!(result, SatisfactionResult::Unsatisfiable));
+        assert!(brancher.has_refused_branch());
+    }
+
+    #[test]
+    fn lds_satisfy_increases_the_number_of_discrepancies() {
+        let (mut solver, xs) = two_discrepancy_problem();
+
+        let result = lds_satisfy(&mut solver, min_value_brancher(&xs), &mut Indefinite, 2);
+        assert!(matches!(result, SatisfactionResult::Satisfiable(_)));
+
+        let (mut solver, xs) = two_discrepancy_problem();
+
+        let result = lds_satisfy(&mut solver, min_value_brancher(&xs), &mut Indefinite, 1);
+        assert!(matches!(
+            result,
+            SatisfactionResult::Unknown(TerminationReason::Unspecified)
+        ));
+        // The solver is restored, so the solution can still be found afterwards
+        let result = lds_satisfy(&mut solver, min_value_brancher(&xs), &mut Indefinite, 2);
+        assert!(matches!(result, SatisfactionResult::Satisfiable(_)));
+    }
+
+    #[test]
+    fn unsatisfiable_problems_are_reported_without_refusing_branches() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 1);
+        let y = solver.new_bounded_integer(0, 1);
+        let _ = solver
+            .add_constraint(constraints::less_than_or_equals([x, y], 0))
+            .post(NonZero::new(1).unwrap());
+        let _ = solver
+            .add_constraint(constraints::not_equals([x, y], 0))
+            .post(NonZero::new(1).unwrap());
+
+        let result = lds_satisfy(&mut solver, min_value_brancher(&[x, y]), &mut Indefinite, 5);
+
+        assert!(matches!(result, SatisfactionResult::Unsatisfiable));
+    }
+}
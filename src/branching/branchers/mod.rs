@@ -1 +1,5 @@
+pub mod dynamic_brancher;
 pub mod independent_variable_value_brancher;
+pub mod limited_discrepancy_brancher;
+pub mod propagator_guided_brancher;
+pub mod random_brancher;
@@ -0,0 +1,106 @@
+//! A [`Brancher`] which makes the decisions that are suggested by the propagators (see
+//! [`Propagator::next_decision`]) and otherwise delegates to another [`Brancher`].
+
+use crate::basic_types::SolutionReference;
+use crate::branching::Brancher;
+use crate::branching::SelectionContext;
+#[cfg(doc)]
+use crate::engine::cp::propagation::Propagator;
+use crate::engine::cp::propagation::PropagatorId;
+use crate::engine::predicates::predicate::Predicate;
+use crate::engine::variables::DomainId;
+use crate::engine::variables::Literal;
+use crate::statistics::SearchStatistics;
+#[cfg(doc)]
+use crate::Solver;
+
+/// A handle to the propagators of a [`Solver`] which is used by a [`PropagatorGuidedBrancher`] to
+/// poll them for decisions; it can be obtained using [`Solver::propagator_registry`].
+///
+/// The registry contains the propagators which were added to the [`Solver`] before it was
+/// created, ordered by their priority (see [`Propagator::priority`]).
+#[derive(Debug, Clone)]
+pub struct PropagatorRegistry {
+    propagators: Vec<PropagatorId>,
+}
+
+impl PropagatorRegistry {
+    pub(crate) fn new(propagators: Vec<PropagatorId>) -> Self {
+        PropagatorRegistry { propagators }
+    }
+
+    /// Returns the number of propagators in the registry.
+    pub fn len(&self) -> usize {
+        self.propagators.len()
+    }
+
+    /// Returns whether the registry does not contain any propagators.
+    pub fn is_empty(&self) -> bool {
+        self.propagators.is_empty()
+    }
+}
+
+/// An implementation of a [`Brancher`] which polls the propagators in its [`PropagatorRegistry`]
+/// in priority order and uses the first suggested decision which is neither true nor false (see
+/// [`Propagator::next_decision`]). If none of the propagators suggests such a decision, then the
+/// decision is made by the provided [`Brancher`].
+///
+/// The other callbacks are forwarded to the provided [`Brancher`].
+#[derive(Debug)]
+pub struct PropagatorGuidedBrancher<B> {
+    registry: PropagatorRegistry,
+    brancher: B,
+}
+
+impl<B: Brancher> PropagatorGuidedBrancher<B> {
+    pub fn new(registry: PropagatorRegistry, brancher: B) -> Self {
+        PropagatorGuidedBrancher { registry, brancher }
+    }
+}
+
+impl<B: Brancher> Brancher for PropagatorGuidedBrancher<B> {
+    fn next_decision(&mut self, context: &mut SelectionContext) -> Option<Predicate> {
+        self.registry
+            .propagators
+            .iter()
+            .filter_map(|&propagator| context.get_propagator_decision(propagator))
+            .find(|&predicate| context.evaluate_predicate(predicate).is_none())
+            .or_else(|| self.brancher.next_decision(context))
+    }
+
+    fn on_conflict(&mut self) {
+        self.brancher.on_conflict();
+    }
+
+    fn on_unassign_literal(&mut self, literal: Literal) {
+        self.brancher.on_unassign_literal(literal);
+    }
+
+    fn on_unassign_integer(&mut self, variable: DomainId, value: i32) {
+        self.brancher.on_unassign_integer(variable, value);
+    }
+
+    fn on_branch_flipped(&mut self, literal: Literal) {
+        self.brancher.on_branch_flipped(literal);
+    }
+
+    fn refuses_branch(&mut self) -> bool {
+        self.brancher.refuses_branch()
+    }
+
+    fn on_appearance_in_conflict_literal(&mut self, literal: Literal) {
+        self.brancher.on_appearance_in_conflict_literal(literal);
+    }
+
+    fn on_appearance_in_conflict_integer(&mut self, variable: DomainId) {
+        self.brancher.on_appearance_in_conflict_integer(variable);
+    }
+
+    fn on_solution(&mut self, solution: SolutionReference) {
+        self.brancher.on_solution(solution);
+    }
+
+    fn on_restart(&mut self, statistics: &SearchStatistics) {
+        self.brancher.on_restart(statistics);
+    }
+}
@@ -0,0 +1,213 @@
+//! A [`Brancher`] which makes random decisions; it is mainly useful as a baseline for comparing
+//! search strategies.
+
+use rand::rngs::SmallRng;
+use rand::RngCore;
+use rand::SeedableRng;
+
+use crate::basic_types::Random;
+use crate::branching::Brancher;
+use crate::branching::SelectionContext;
+use crate::engine::predicates::predicate::Predicate;
+use crate::engine::variables::DomainId;
+use crate::predicate;
+
+/// A [`Brancher`] which selects a random unfixed variable and assigns it to a random value in its
+/// domain (taking holes into account).
+///
+/// The randomness only depends on the provided seed and the decision level at which a decision is
+/// made: the first time a decision level is reached, a seed is drawn for it, and every decision
+/// made at that level uses a random generator which is created from that seed. Consequently, the
+/// random choices do not depend on how many decisions were made in subtrees which have been
+/// abandoned; when the solver backtracks to a decision level, the same draws are used to select
+/// among the (now different) remaining variables and values. Note that this also holds across
+/// restarts.
+///
+/// The random generator of the [`SelectionContext`] is not used, so the decisions are independent
+/// of [`SolverOptions::random_seed`](crate::options::SolverOptions::random_seed).
+#[derive(Debug)]
+pub struct RandomBrancher {
+    variables: Vec<DomainId>,
+    /// The generator from which the seeds of the decision levels are drawn.
+    seed_generator: SmallRng,
+    /// The seed of every decision level which has been reached so far.
+    level_seeds: Vec<u64>,
+}
+
+impl RandomBrancher {
+    pub fn new(variables: Vec<DomainId>, seed: u64) -> Self {
+        RandomBrancher {
+            variables,
+            seed_generator: SmallRng::seed_from_u64(seed),
+            level_seeds: vec![],
+        }
+    }
+
+    /// Returns the random generator which should be used for a decision at `decision_level`.
+    fn generator_for_level(&mut self, decision_level: usize) -> SmallRng {
+        while self.level_seeds.len() <= decision_level {
+            self.level_seeds.push(self.seed_generator.next_u64());
+        }
+
+        SmallRng::seed_from_u64(self.level_seeds[decision_level])
+    }
+}
+
+impl Brancher for RandomBrancher {
+    fn next_decision(&mut self, context: &mut SelectionContext) -> Option<Predicate> {
+        let unfixed = self
+            .variables
+            .iter()
+            .copied()
+            .filter(|variable| !context.is_integer_fixed(variable))
+            .collect::<Vec<_>>();
+        if unfixed.is_empty() {
+            return None;
+        }
+
+        let mut random = self.generator_for_level(context.get_decision_level());
+
+        let variable = unfixed[random.generate_usize_in_range(0..unfixed.len())];
+        let values = (context.lower_bound(&variable)..=context.upper_bound(&variable))
+            .filter(|&value| context.contains(&variable, value))
+            .collect::<Vec<_>>();
+        let value = values[random.generate_usize_in_range(0..values.len())];
+
+        Some(predicate!(variable == value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZero;
+
+    use super::*;
+    use crate::basic_types::tests::TestRandom;
+    use crate::branching::TestSelectionContextBuilder;
+    use crate::constraints;
+    use crate::predicates::IntegerPredicate;
+    use crate::results::solution_iterator::IteratedSolution;
+    use crate::results::ProblemSolution;
+    use crate::termination::Indefinite;
+    use crate::Solver;
+
+    /// Returns the variable and value of an equality decision.
+    fn as_assignment(decision: Predicate) -> (DomainId, i32) {
+        match decision {
+            Predicate::IntegerPredicate(IntegerPredicate::Equal {
+                domain_id,
+                equality_constant,
+            }) => (domain_id, equality_constant),
+            _ => panic!("expected an equality decision, got {decision}"),
+        }
+    }
+
+    #[test]
+    fn decisions_are_reproducible_with_fixed_seed() {
+        let (assignments_integer, assignments_propositional) =
+            SelectionContext::create_for_testing(5, 0, Some(vec![(0, 20); 5]));
+        let mut test_rng = TestRandom::default();
+        let mut context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut test_rng,
+        );
+        let variables = context.get_domains().collect::<Vec<_>>();
+
+        let mut first = RandomBrancher::new(variables.clone(), 3);
+        let mut second = RandomBrancher::new(variables, 3);
+
+        let decision = first.next_decision(&mut context).unwrap();
+        assert_eq!(second.next_decision(&mut context), Some(decision));
+
+        // Every decision at the same decision level uses the same draws
+        for _ in 0..5 {
+            assert_eq!(first.next_decision(&mut context), Some(decision));
+        }
+    }
+
+    #[test]
+    fn fixed_variables_and_holes_are_skipped() {
+        let (assignments_integer, assignments_propositional) =
+            TestSelectionContextBuilder::default()
+                .with_assigned_integer(0, 5, 2)
+                .with_integer_with_holes(0, 10, &[1, 2, 3, 4, 6, 7, 8, 9])
+                .with_assigned_integer(0, 5, 4)
+                .build();
+        let mut test_rng = TestRandom::default();
+        let mut context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut test_rng,
+        );
+        let variables = context.get_domains().collect::<Vec<_>>();
+
+        for seed in 0..20 {
+            let mut brancher = RandomBrancher::new(variables.clone(), seed);
+            let (variable, value) = as_assignment(brancher.next_decision(&mut context).unwrap());
+
+            assert_eq!(variable, variables[1]);
+            assert!([0, 5, 10].contains(&value));
+        }
+    }
+
+    #[test]
+    fn no_decision_when_all_variables_are_fixed() {
+        let (assignments_integer, assignments_propositional) =
+            TestSelectionContextBuilder::default()
+                .with_assigned_integer(0, 5, 2)
+                .with_assigned_integer(0, 5, 4)
+                .build();
+        let mut test_rng = TestRandom::default();
+        let mut context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut test_rng,
+        );
+        let variables = context.get_domains().collect::<Vec<_>>();
+
+        let mut brancher = RandomBrancher::new(variables, 42);
+        assert_eq!(brancher.next_decision(&mut context), None);
+    }
+
+    #[test]
+    fn solution_iteration_finds_all_solutions() {
+        let mut solver = Solver::default();
+        let variables = vec![
+            solver.new_bounded_integer(0, 3),
+            solver.new_sparse_integer(vec![0, 2, 3]),
+            solver.new_bounded_integer(1, 3),
+        ];
+        let _ = solver
+            .add_constraint(constraints::less_than_or_equals(variables.clone(), 5))
+            .post(NonZero::new(1).unwrap());
+
+        let mut brancher = RandomBrancher::new(variables.clone(), 11);
+        let mut termination = Indefinite;
+        let mut solution_iterator = solver.get_solution_iterator(&mut brancher, &mut termination);
+
+        let mut solutions = vec![];
+        while let IteratedSolution::Solution(solution) = solution_iterator.next_solution() {
+            solutions.push(
+                variables
+                    .iter()
+                    .map(|&variable| solution.get_integer_value(variable))
+                    .collect::<Vec<_>>(),
+            );
+        }
+        solutions.sort();
+
+        let mut expected = vec![];
+        for x in 0..=3 {
+            for y in [0, 2, 3] {
+                for z in 1..=3 {
+                    if x + y + z <= 5 {
+                        expected.push(vec![x, y, z]);
+                    }
+                }
+            }
+        }
+
+        assert_eq!(solutions, expected);
+    }
+}
@@ -82,6 +82,8 @@ pub mod variable_selection;
 
 pub use brancher::Brancher;
 pub use selection_context::SelectionContext;
+#[cfg(test)]
+pub(crate) use selection_context::TestSelectionContextBuilder;
 pub use value_selection::*;
 pub use variable_selection::*;
 
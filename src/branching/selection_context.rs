@@ -1,11 +1,14 @@
 use std::fmt::Debug;
 
+use crate::basic_types::KeyedVec;
 use crate::basic_types::Random;
 #[cfg(doc)]
 use crate::branching::Brancher;
-#[cfg(doc)]
 use crate::engine::cp::propagation::PropagationContext;
+use crate::engine::cp::propagation::Propagator;
+use crate::engine::cp::propagation::PropagatorId;
 use crate::engine::cp::AssignmentsInteger;
+use crate::engine::predicates::predicate::Predicate;
 use crate::engine::sat::AssignmentsPropositional;
 use crate::engine::variables::DomainGeneratorIterator;
 #[cfg(doc)]
@@ -17,11 +20,29 @@ use crate::engine::variables::PropositionalVariableGeneratorIterator;
 /// The context provided to the [`Brancher`],
 /// it allows the retrieval of domain values of variables and access to methods from a [`Random`]
 /// generator.
-#[derive(Debug)]
 pub struct SelectionContext<'a> {
     assignments_integer: &'a AssignmentsInteger,
     assignments_propositional: &'a AssignmentsPropositional,
     random_generator: &'a mut dyn Random,
+    /// Read-only access to the propagators of the solver, which is used to retrieve the decisions
+    /// which they suggest.
+    propagators: Option<&'a KeyedVec<PropagatorId, Box<dyn Propagator>>>,
+    use_non_generic_conflict_explanation: bool,
+    use_non_generic_propagation_explanation: bool,
+}
+
+impl Debug for SelectionContext<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SelectionContext")
+            .field("assignments_integer", &self.assignments_integer)
+            .field("assignments_propositional", &self.assignments_propositional)
+            .field("random_generator", &self.random_generator)
+            .field(
+                "num_propagators",
+                &self.propagators.map(|propagators| propagators.len()),
+            )
+            .finish()
+    }
 }
 
 impl<'a> SelectionContext<'a> {
@@ -34,9 +55,44 @@ impl<'a> SelectionContext<'a> {
             assignments_integer,
             assignments_propositional,
             random_generator: rng,
+            propagators: None,
+            use_non_generic_conflict_explanation: false,
+            use_non_generic_propagation_explanation: false,
         }
     }
 
+    /// Provides the context with read-only access to the propagators of the solver, such that
+    /// [`SelectionContext::get_propagator_decision`] can be used.
+    pub(crate) fn with_propagators(
+        mut self,
+        propagators: &'a KeyedVec<PropagatorId, Box<dyn Propagator>>,
+        use_non_generic_conflict_explanation: bool,
+        use_non_generic_propagation_explanation: bool,
+    ) -> Self {
+        self.propagators = Some(propagators);
+        self.use_non_generic_conflict_explanation = use_non_generic_conflict_explanation;
+        self.use_non_generic_propagation_explanation = use_non_generic_propagation_explanation;
+        self
+    }
+
+    /// Returns the decision which is suggested by the provided propagator (see
+    /// [`Propagator::next_decision`]); returns [`None`] if the propagator does not have a
+    /// suggestion or if the context does not provide access to the propagators.
+    pub fn get_propagator_decision(&self, propagator: PropagatorId) -> Option<Predicate> {
+        self.propagators?[propagator].next_decision(PropagationContext::new(
+            self.assignments_integer,
+            self.assignments_propositional,
+            self.use_non_generic_conflict_explanation,
+            self.use_non_generic_propagation_explanation,
+        ))
+    }
+
+    /// Evaluates the provided [`Predicate`] in the current state; returns [`None`] if the predicate
+    /// is neither true nor false.
+    pub fn evaluate_predicate(&self, predicate: Predicate) -> Option<bool> {
+        predicate.evaluate(self.assignments_integer, self.assignments_propositional)
+    }
+
     /// Returns a random generator which can be used to generate random values (see [`Random`] for
     /// more information).
     pub fn random(&mut self) -> &mut dyn Random {
@@ -50,6 +106,29 @@ impl<'a> SelectionContext<'a> {
         var.upper_bound(self.assignments_integer) - var.lower_bound(self.assignments_integer)
     }
 
+    /// Returns the number of values which are in the domain of the provided [`IntegerVariable`];
+    /// contrary to [`SelectionContext::get_size_of_domain`], this takes into account the holes in
+    /// the domain.
+    pub fn get_number_of_values_in_domain<Var: IntegerVariable>(&self, var: &Var) -> usize {
+        self.values_in_domain(var).count()
+    }
+
+    /// Returns the values which are in the domain of the provided [`IntegerVariable`] in
+    /// increasing order, skipping the holes in the domain.
+    pub fn values_in_domain<'this, Var: IntegerVariable + 'this>(
+        &'this self,
+        var: &'this Var,
+    ) -> impl DoubleEndedIterator<Item = i32> + 'this {
+        let assignments_integer: &'this AssignmentsInteger = self.assignments_integer;
+        (self.lower_bound(var)..=self.upper_bound(var))
+            .filter(move |&value| var.contains(assignments_integer, value))
+    }
+
+    /// Returns the current decision level, i.e. the number of decisions on the current search path.
+    pub fn get_decision_level(&self) -> usize {
+        self.assignments_propositional.get_decision_level()
+    }
+
     /// Returns the lower bound of the provided [`IntegerVariable`]
     pub fn lower_bound<Var: IntegerVariable>(&self, var: &Var) -> i32 {
         var.lower_bound(self.assignments_integer)
@@ -82,6 +161,14 @@ impl<'a> SelectionContext<'a> {
             .is_variable_assigned_true(var)
     }
 
+    /// Returns a value in `[-1, 1]` indicating the polarity to which the provided
+    /// [`PropositionalVariable`] has recently been assigned; it is positive if it has more often
+    /// been assigned to true, negative if it has more often been assigned to false, and `0` if
+    /// neither polarity is preferred. Recent assignments have more influence than older ones.
+    pub fn polarity_bias(&self, var: PropositionalVariable) -> f64 {
+        self.assignments_propositional.polarity_counts(var).bias()
+    }
+
     /// Returns all currently defined [`DomainId`]s.
     pub fn get_domains(&self) -> DomainGeneratorIterator {
         self.assignments_integer.get_domains()
@@ -96,17 +183,14 @@ impl<'a> SelectionContext<'a> {
     /// A method for creating and returning `num_integer_variables` [`DomainId`]s and
     /// `num_prop_variables` [`PropositionalVariable`]s in addition to initialising (and
     /// returning) the corresponding [`AssignmentsInteger`] and [`AssignmentsPropositional`].
+    ///
+    /// See [`TestSelectionContextBuilder`] for creating domains with holes or pre-assigned
+    /// variables.
     pub fn create_for_testing(
         num_integer_variables: usize,
         num_propositional_variables: usize,
         domains: Option<Vec<(i32, i32)>>,
     ) -> (AssignmentsInteger, AssignmentsPropositional) {
-        use crate::engine::cp::VariableLiteralMappings;
-        use crate::engine::cp::WatchListCP;
-        use crate::engine::cp::WatchListPropositional;
-        use crate::engine::sat::ClausalPropagator;
-        use crate::engine::sat::ClauseAllocator;
-        use crate::engine::variables::Literal;
         use crate::munchkin_assert_simple;
 
         munchkin_assert_simple!({
@@ -117,6 +201,117 @@ impl<'a> SelectionContext<'a> {
             }
         });
 
+        let mut builder = TestSelectionContextBuilder::default();
+        match domains {
+            Some(domains) => {
+                for (lower_bound, upper_bound) in domains {
+                    builder = builder.with_integer(lower_bound, upper_bound);
+                }
+            }
+            None => {
+                for _ in 0..num_integer_variables {
+                    builder = builder.with_integer(0, 10);
+                }
+            }
+        }
+        for _ in 0..num_propositional_variables {
+            builder = builder.with_propositional();
+        }
+
+        builder.build()
+    }
+}
+
+/// The domain of an integer variable created by a [`TestSelectionContextBuilder`].
+#[cfg(test)]
+#[derive(Debug)]
+enum TestDomain {
+    Interval {
+        lower_bound: i32,
+        upper_bound: i32,
+        assigned_value: Option<i32>,
+    },
+    Sparse(Vec<i32>),
+}
+
+/// A builder for the [`AssignmentsInteger`] and [`AssignmentsPropositional`] which are used to
+/// create a [`SelectionContext`] in tests.
+///
+/// Contrary to [`SelectionContext::create_for_testing`], it allows integer domains with holes and
+/// variables which are already assigned. The variables are created in the order in which they are
+/// added to the builder (first all integer variables and then all propositional variables), i.e.
+/// the `i`-th integer variable which is added has [`DomainId`] `i`.
+#[cfg(test)]
+#[derive(Debug, Default)]
+pub(crate) struct TestSelectionContextBuilder {
+    integer_domains: Vec<TestDomain>,
+    propositional_assignments: Vec<Option<bool>>,
+}
+
+#[cfg(test)]
+impl TestSelectionContextBuilder {
+    /// Adds an integer variable with the domain `[lower_bound, upper_bound]`.
+    pub(crate) fn with_integer(mut self, lower_bound: i32, upper_bound: i32) -> Self {
+        self.integer_domains.push(TestDomain::Interval {
+            lower_bound,
+            upper_bound,
+            assigned_value: None,
+        });
+        self
+    }
+
+    /// Adds an integer variable with the domain `[lower_bound, upper_bound]` from which the values
+    /// in `holes` have been removed.
+    pub(crate) fn with_integer_with_holes(
+        mut self,
+        lower_bound: i32,
+        upper_bound: i32,
+        holes: &[i32],
+    ) -> Self {
+        let values = (lower_bound..=upper_bound)
+            .filter(|value| !holes.contains(value))
+            .collect();
+        self.integer_domains.push(TestDomain::Sparse(values));
+        self
+    }
+
+    /// Adds an integer variable with the domain `[lower_bound, upper_bound]` which is assigned to
+    /// `value`.
+    pub(crate) fn with_assigned_integer(
+        mut self,
+        lower_bound: i32,
+        upper_bound: i32,
+        value: i32,
+    ) -> Self {
+        self.integer_domains.push(TestDomain::Interval {
+            lower_bound,
+            upper_bound,
+            assigned_value: Some(value),
+        });
+        self
+    }
+
+    /// Adds an unassigned propositional variable.
+    pub(crate) fn with_propositional(mut self) -> Self {
+        self.propositional_assignments.push(None);
+        self
+    }
+
+    /// Adds a propositional variable which is assigned to `value`.
+    pub(crate) fn with_assigned_propositional(mut self, value: bool) -> Self {
+        self.propositional_assignments.push(Some(value));
+        self
+    }
+
+    /// Creates the assignments containing the variables which have been added to the builder.
+    pub(crate) fn build(self) -> (AssignmentsInteger, AssignmentsPropositional) {
+        use crate::engine::cp::VariableLiteralMappings;
+        use crate::engine::cp::WatchListCP;
+        use crate::engine::cp::WatchListPropositional;
+        use crate::engine::sat::ClausalPropagator;
+        use crate::engine::sat::ClauseAllocator;
+        use crate::engine::variables::Literal;
+
         let mut mediator = VariableLiteralMappings::default();
         let mut clausal_propagator = ClausalPropagator::default();
         let mut assignments_propositional = AssignmentsPropositional::default();
@@ -138,42 +333,54 @@ impl<'a> SelectionContext<'a> {
 
         assignments_propositional.enqueue_decision_literal(true_literal);
 
-        if let Some(domains) = domains.as_ref() {
-            for (_, (lower_bound, upper_bound)) in (0..num_integer_variables).zip(domains) {
-                let _ = mediator.create_new_domain(
-                    *lower_bound,
-                    *upper_bound,
-                    &mut assignments_integer,
-                    &mut watch_list_cp,
-                    &mut watch_list_propositional,
-                    &mut clausal_propagator,
-                    &mut assignments_propositional,
-                    &mut clause_allocator,
-                );
-            }
-        } else {
-            for _ in 0..num_integer_variables {
-                let _ = mediator.create_new_domain(
-                    0,
-                    10,
-                    &mut assignments_integer,
-                    &mut watch_list_cp,
-                    &mut watch_list_propositional,
-                    &mut clausal_propagator,
-                    &mut assignments_propositional,
-                    &mut clause_allocator,
-                );
+        for domain in self.integer_domains {
+            match domain {
+                TestDomain::Interval {
+                    lower_bound,
+                    upper_bound,
+                    assigned_value,
+                } => {
+                    let domain_id = mediator.create_new_domain(
+                        lower_bound,
+                        upper_bound,
+                        &mut assignments_integer,
+                        &mut watch_list_cp,
+                        &mut watch_list_propositional,
+                        &mut clausal_propagator,
+                        &mut assignments_propositional,
+                        &mut clause_allocator,
+                    );
+
+                    if let Some(value) = assigned_value {
+                        assignments_integer
+                            .make_assignment(domain_id, value, None)
+                            .expect("the assigned value should be in the domain");
+                    }
+                }
+                TestDomain::Sparse(values) => {
+                    let _ = mediator.create_new_sparse_domain(
+                        values,
+                        &mut assignments_integer,
+                        &mut watch_list_cp,
+                        &mut watch_list_propositional,
+                        &mut clausal_propagator,
+                        &mut assignments_propositional,
+                        &mut clause_allocator,
+                    );
+                }
             }
         }
 
-        for _ in 0..num_propositional_variables {
-            // We create an additional variable to ensure that the generator returns the correct
-            // variables
-            let _ = mediator.create_new_propositional_variable(
+        for assignment in self.propositional_assignments {
+            let variable = mediator.create_new_propositional_variable(
                 &mut watch_list_propositional,
                 &mut clausal_propagator,
                 &mut assignments_propositional,
             );
+
+            if let Some(value) = assignment {
+                assignments_propositional.enqueue_decision_literal(Literal::new(variable, value));
+            }
         }
 
         (assignments_integer, assignments_propositional)
@@ -0,0 +1,103 @@
+use super::ValueSelector;
+use crate::branching::SelectionContext;
+use crate::engine::predicates::predicate::Predicate;
+use crate::engine::variables::IntegerVariable;
+use crate::engine::variables::Literal;
+use crate::engine::variables::PropositionalVariable;
+use crate::predicate;
+
+/// [`ValueSelector`] which assigns the provided variable to the value in its domain which is
+/// closest to the provided target, i.e. it returns `[x == v]` where `v` minimises `|v - target|`;
+/// if two values are equally close, then the smaller one is selected.
+///
+/// A [`PropositionalVariable`] is considered to have the values 0 (false) and 1 (true).
+#[derive(Debug, Copy, Clone)]
+pub struct InDomainClosestTo(pub i32);
+
+impl<Var: IntegerVariable> ValueSelector<Var> for InDomainClosestTo {
+    fn select_value(
+        &mut self,
+        context: &mut SelectionContext,
+        decision_variable: Var,
+    ) -> Predicate {
+        let target = self.0;
+        // The values are iterated in increasing order and `min_by_key` returns the first minimum,
+        // so ties are broken towards the smaller value
+        let value = context
+            .values_in_domain(&decision_variable)
+            .min_by_key(|value| value.abs_diff(target))
+            .expect("the domain of a decision variable is not empty");
+        predicate!(decision_variable == value)
+    }
+}
+
+impl ValueSelector<PropositionalVariable> for InDomainClosestTo {
+    fn select_value(
+        &mut self,
+        _context: &mut SelectionContext,
+        decision_variable: PropositionalVariable,
+    ) -> Predicate {
+        Literal::new(decision_variable, self.0 >= 1).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::basic_types::tests::TestRandom;
+    use crate::branching::selection_context::TestSelectionContextBuilder;
+    use crate::branching::InDomainClosestTo;
+    use crate::branching::SelectionContext;
+    use crate::branching::ValueSelector;
+    use crate::engine::variables::Literal;
+    use crate::predicate;
+
+    #[test]
+    fn selects_the_closest_value_in_a_sparse_domain() {
+        let (assignments_integer, assignments_propositional) =
+            TestSelectionContextBuilder::default()
+                .with_integer_with_holes(-10, 10, &[-1, 0, 1, 2, 3, 7])
+                .build();
+        let mut test_rng = TestRandom::default();
+        let mut context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut test_rng,
+        );
+        let domain_id = context.get_domains().next().unwrap();
+
+        for (target, expected) in [
+            // -2 and 4 are equally close, so the smaller value is selected
+            (1, -2),
+            (0, -2),
+            (3, 4),
+            // 6 and 8 are equally close
+            (7, 6),
+            (-100, -10),
+            (100, 10),
+        ] {
+            let selected_predicate =
+                InDomainClosestTo(target).select_value(&mut context, domain_id);
+            assert_eq!(selected_predicate, predicate!(domain_id == expected));
+            assert!(context.contains(&domain_id, expected));
+        }
+    }
+
+    #[test]
+    fn propositional_variable_is_assigned_to_the_closest_truth_value() {
+        let (assignments_integer, assignments_propositional) =
+            SelectionContext::create_for_testing(0, 1, None);
+        let mut test_rng = TestRandom::default();
+        let mut context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut test_rng,
+        );
+        let variable = context.get_propositional_variables().next().unwrap();
+
+        let selected_predicate = InDomainClosestTo(0).select_value(&mut context, variable);
+        assert_eq!(selected_predicate, Literal::new(variable, false).into());
+
+        let selected_predicate = InDomainClosestTo(5).select_value(&mut context, variable);
+        assert_eq!(selected_predicate, Literal::new(variable, true).into());
+    }
+}
@@ -0,0 +1,144 @@
+use super::ValueSelector;
+use crate::branching::SelectionContext;
+use crate::engine::predicates::predicate::Predicate;
+use crate::engine::variables::IntegerVariable;
+use crate::engine::variables::Literal;
+use crate::engine::variables::PropositionalVariable;
+use crate::predicate;
+
+/// [`ValueSelector`] which bisects the domain of the provided variable by excluding its upper
+/// half, i.e. it returns `[x <= mid]` where `mid = lb + (ub - lb) / 2`.
+///
+/// Note that such a decision does not assign the variable; it may thus be selected again at a
+/// deeper level, until it is fixed.
+#[derive(Debug, Copy, Clone)]
+pub struct InDomainSplit;
+
+impl<Var: IntegerVariable> ValueSelector<Var> for InDomainSplit {
+    fn select_value(
+        &mut self,
+        context: &mut SelectionContext,
+        decision_variable: Var,
+    ) -> Predicate {
+        let mid = split_point(context, &decision_variable);
+        predicate!(decision_variable <= mid)
+    }
+}
+
+impl ValueSelector<PropositionalVariable> for InDomainSplit {
+    fn select_value(
+        &mut self,
+        _context: &mut SelectionContext,
+        decision_variable: PropositionalVariable,
+    ) -> Predicate {
+        Literal::new(decision_variable, true).into()
+    }
+}
+
+/// [`ValueSelector`] which bisects the domain of the provided variable by excluding its lower
+/// half, i.e. it returns `[x >= mid + 1]` where `mid = lb + (ub - lb) / 2`.
+///
+/// Note that such a decision does not assign the variable; it may thus be selected again at a
+/// deeper level, until it is fixed.
+#[derive(Debug, Copy, Clone)]
+pub struct InDomainSplitReversed;
+
+impl<Var: IntegerVariable> ValueSelector<Var> for InDomainSplitReversed {
+    fn select_value(
+        &mut self,
+        context: &mut SelectionContext,
+        decision_variable: Var,
+    ) -> Predicate {
+        let mid = split_point(context, &decision_variable);
+        predicate!(decision_variable >= mid + 1)
+    }
+}
+
+impl ValueSelector<PropositionalVariable> for InDomainSplitReversed {
+    fn select_value(
+        &mut self,
+        _context: &mut SelectionContext,
+        decision_variable: PropositionalVariable,
+    ) -> Predicate {
+        Literal::new(decision_variable, true).into()
+    }
+}
+
+fn split_point<Var: IntegerVariable>(context: &SelectionContext, variable: &Var) -> i32 {
+    let lower_bound = context.lower_bound(variable);
+    let upper_bound = context.upper_bound(variable);
+
+    lower_bound + (upper_bound - lower_bound) / 2
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::basic_types::tests::TestRandom;
+    use crate::branching::InDomainSplit;
+    use crate::branching::InDomainSplitReversed;
+    use crate::branching::SelectionContext;
+    use crate::branching::ValueSelector;
+    use crate::engine::variables::Literal;
+    use crate::predicate;
+
+    #[test]
+    fn test_returns_lower_half() {
+        let (assignments_integer, assignments_propositional) =
+            SelectionContext::create_for_testing(2, 0, Some(vec![(0, 10), (-3, 4)]));
+        let mut test_rng = TestRandom::default();
+        let mut context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut test_rng,
+        );
+        let domain_ids = context.get_domains().collect::<Vec<_>>();
+
+        let mut selector = InDomainSplit;
+
+        let selected_predicate = selector.select_value(&mut context, domain_ids[0]);
+        assert_eq!(selected_predicate, predicate!(domain_ids[0] <= 5));
+
+        let selected_predicate = selector.select_value(&mut context, domain_ids[1]);
+        assert_eq!(selected_predicate, predicate!(domain_ids[1] <= 0));
+    }
+
+    #[test]
+    fn test_reversed_returns_upper_half() {
+        let (assignments_integer, assignments_propositional) =
+            SelectionContext::create_for_testing(2, 0, Some(vec![(0, 10), (-3, 4)]));
+        let mut test_rng = TestRandom::default();
+        let mut context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut test_rng,
+        );
+        let domain_ids = context.get_domains().collect::<Vec<_>>();
+
+        let mut selector = InDomainSplitReversed;
+
+        let selected_predicate = selector.select_value(&mut context, domain_ids[0]);
+        assert_eq!(selected_predicate, predicate!(domain_ids[0] >= 6));
+
+        let selected_predicate = selector.select_value(&mut context, domain_ids[1]);
+        assert_eq!(selected_predicate, predicate!(domain_ids[1] >= 1));
+    }
+
+    #[test]
+    fn test_propositional_variable_is_assigned_true() {
+        let (assignments_integer, assignments_propositional) =
+            SelectionContext::create_for_testing(0, 1, None);
+        let mut test_rng = TestRandom::default();
+        let mut context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut test_rng,
+        );
+        let variable = context.get_propositional_variables().next().unwrap();
+
+        let selected_predicate = InDomainSplit.select_value(&mut context, variable);
+        assert_eq!(selected_predicate, Literal::new(variable, true).into());
+
+        let selected_predicate = InDomainSplitReversed.select_value(&mut context, variable);
+        assert_eq!(selected_predicate, Literal::new(variable, true).into());
+    }
+}
@@ -0,0 +1,125 @@
+use super::ValueSelector;
+use crate::branching::SelectionContext;
+use crate::engine::predicates::predicate::Predicate;
+use crate::engine::variables::IntegerVariable;
+use crate::engine::variables::Literal;
+use crate::engine::variables::PropositionalVariable;
+use crate::predicate;
+
+/// [`ValueSelector`] which assigns the provided variable to the smallest value in its domain, i.e.
+/// it returns `[x == lb]`.
+///
+/// Contrary to [`InDomainMin`](super::InDomainMin), the decision assigns the variable; the other
+/// branch removes only the lower bound from the domain.
+#[derive(Debug, Copy, Clone)]
+pub struct InDomainMinValue;
+
+impl<Var: IntegerVariable> ValueSelector<Var> for InDomainMinValue {
+    fn select_value(
+        &mut self,
+        context: &mut SelectionContext,
+        decision_variable: Var,
+    ) -> Predicate {
+        let value = context
+            .values_in_domain(&decision_variable)
+            .next()
+            .expect("the domain of a decision variable is not empty");
+        predicate!(decision_variable == value)
+    }
+}
+
+impl ValueSelector<PropositionalVariable> for InDomainMinValue {
+    fn select_value(
+        &mut self,
+        _context: &mut SelectionContext,
+        decision_variable: PropositionalVariable,
+    ) -> Predicate {
+        Literal::new(decision_variable, false).into()
+    }
+}
+
+/// [`ValueSelector`] which assigns the provided variable to the largest value in its domain, i.e.
+/// it returns `[x == ub]`.
+#[derive(Debug, Copy, Clone)]
+pub struct InDomainMaxValue;
+
+impl<Var: IntegerVariable> ValueSelector<Var> for InDomainMaxValue {
+    fn select_value(
+        &mut self,
+        context: &mut SelectionContext,
+        decision_variable: Var,
+    ) -> Predicate {
+        let value = context
+            .values_in_domain(&decision_variable)
+            .next_back()
+            .expect("the domain of a decision variable is not empty");
+        predicate!(decision_variable == value)
+    }
+}
+
+impl ValueSelector<PropositionalVariable> for InDomainMaxValue {
+    fn select_value(
+        &mut self,
+        _context: &mut SelectionContext,
+        decision_variable: PropositionalVariable,
+    ) -> Predicate {
+        Literal::new(decision_variable, true).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::basic_types::tests::TestRandom;
+    use crate::branching::selection_context::TestSelectionContextBuilder;
+    use crate::branching::InDomainMaxValue;
+    use crate::branching::InDomainMinValue;
+    use crate::branching::SelectionContext;
+    use crate::branching::ValueSelector;
+    use crate::engine::variables::Literal;
+    use crate::predicate;
+
+    #[test]
+    fn selects_the_extreme_values_of_sparse_domains() {
+        let (assignments_integer, assignments_propositional) =
+            TestSelectionContextBuilder::default()
+                .with_integer_with_holes(0, 10, &[0, 1, 9, 10])
+                .with_integer_with_holes(-5, 5, &[-4, 4])
+                .build();
+        let mut test_rng = TestRandom::default();
+        let mut context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut test_rng,
+        );
+        let domain_ids = context.get_domains().collect::<Vec<_>>();
+
+        for (domain_id, min_value, max_value) in [(domain_ids[0], 2, 8), (domain_ids[1], -5, 5)] {
+            let selected_predicate = InDomainMinValue.select_value(&mut context, domain_id);
+            assert_eq!(selected_predicate, predicate!(domain_id == min_value));
+            assert!(context.contains(&domain_id, min_value));
+
+            let selected_predicate = InDomainMaxValue.select_value(&mut context, domain_id);
+            assert_eq!(selected_predicate, predicate!(domain_id == max_value));
+            assert!(context.contains(&domain_id, max_value));
+        }
+    }
+
+    #[test]
+    fn propositional_variables_are_assigned_to_the_extreme_values() {
+        let (assignments_integer, assignments_propositional) =
+            SelectionContext::create_for_testing(0, 1, None);
+        let mut test_rng = TestRandom::default();
+        let mut context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut test_rng,
+        );
+        let variable = context.get_propositional_variables().next().unwrap();
+
+        let selected_predicate = InDomainMinValue.select_value(&mut context, variable);
+        assert_eq!(selected_predicate, Literal::new(variable, false).into());
+
+        let selected_predicate = InDomainMaxValue.select_value(&mut context, variable);
+        assert_eq!(selected_predicate, Literal::new(variable, true).into());
+    }
+}
@@ -3,11 +3,19 @@
 //! [`ValueSelector::select_value`].
 //!
 //! Furthermore, it defines several implementations of the [`ValueSelector`] trait such as
-//! [`InDomainMin`], [`PhaseSaving`] and [`SolutionGuidedValueSelector`]. Any [`ValueSelector`]
+//! [`InDomainMin`], [`InDomainSplit`], [`PhaseSaving`] and [`SolutionGuidedValueSelector`]. Any [`ValueSelector`]
 //! should only select values which are in the domain of the provided variable.
 
+mod in_domain_closest_to;
 mod in_domain_min;
+mod in_domain_split;
+mod in_domain_value;
+mod phase_saving;
 mod value_selector;
 
+pub use in_domain_closest_to::*;
 pub use in_domain_min::*;
+pub use in_domain_split::*;
+pub use in_domain_value::*;
+pub use phase_saving::*;
 pub use value_selector::ValueSelector;
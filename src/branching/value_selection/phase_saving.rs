@@ -0,0 +1,208 @@
+use super::ValueSelector;
+use crate::branching::SelectionContext;
+use crate::engine::predicates::predicate::Predicate;
+use crate::engine::variables::Literal;
+use crate::engine::variables::PropositionalVariable;
+
+/// Determines the polarity which is selected by [`PhaseSaving`] if a variable has recently been
+/// assigned to true and to false equally often.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PhaseTieBreak {
+    /// Assign the variable to true.
+    True,
+    /// Assign the variable to false.
+    #[default]
+    False,
+    /// Assign the variable to true or false with equal probability.
+    Random,
+}
+
+/// [`ValueSelector`] which assigns the provided variable to the polarity to which it has most often
+/// been assigned recently (see [`SelectionContext::polarity_bias`]); ties are broken according to
+/// the provided [`PhaseTieBreak`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PhaseSaving {
+    tie_break: PhaseTieBreak,
+}
+
+impl PhaseSaving {
+    pub fn new(tie_break: PhaseTieBreak) -> Self {
+        PhaseSaving { tie_break }
+    }
+}
+
+impl ValueSelector<PropositionalVariable> for PhaseSaving {
+    fn select_value(
+        &mut self,
+        context: &mut SelectionContext,
+        decision_variable: PropositionalVariable,
+    ) -> Predicate {
+        let bias = context.polarity_bias(decision_variable);
+
+        let polarity = if bias > 0.0 {
+            true
+        } else if bias < 0.0 {
+            false
+        } else {
+            match self.tie_break {
+                PhaseTieBreak::True => true,
+                PhaseTieBreak::False => false,
+                PhaseTieBreak::Random => context.random().generate_bool(0.5),
+            }
+        };
+
+        Literal::new(decision_variable, polarity).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basic_types::tests::TestRandom;
+    use crate::branching::selection_context::TestSelectionContextBuilder;
+    use crate::engine::sat::AssignmentsPropositional;
+
+    /// Assigns `variable` to `value` at a new decision level and immediately backtracks, such that
+    /// the variable is unassigned again but the assignment is recorded in the polarity counts.
+    fn assign_and_unassign(
+        assignments_propositional: &mut AssignmentsPropositional,
+        variable: PropositionalVariable,
+        value: bool,
+    ) {
+        let decision_level = assignments_propositional.get_decision_level();
+        assignments_propositional.increase_decision_level();
+        assignments_propositional.enqueue_decision_literal(Literal::new(variable, value));
+        let _ = assignments_propositional
+            .synchronise(decision_level)
+            .collect::<Vec<_>>();
+    }
+
+    fn bias(
+        assignments_propositional: &AssignmentsPropositional,
+        variable: PropositionalVariable,
+    ) -> f64 {
+        assignments_propositional.polarity_counts(variable).bias()
+    }
+
+    fn variables(
+        assignments_propositional: &AssignmentsPropositional,
+    ) -> (PropositionalVariable, PropositionalVariable) {
+        let variables = assignments_propositional
+            .get_propositional_variables()
+            .collect::<Vec<_>>();
+        (
+            variables[variables.len() - 2],
+            variables[variables.len() - 1],
+        )
+    }
+
+    #[test]
+    fn bias_moves_towards_assigned_polarity() {
+        let (_, mut assignments_propositional) = TestSelectionContextBuilder::default()
+            .with_propositional()
+            .with_propositional()
+            .build();
+        let (x, _) = variables(&assignments_propositional);
+
+        assert_eq!(bias(&assignments_propositional, x), 0.0);
+
+        assign_and_unassign(&mut assignments_propositional, x, true);
+        assert_eq!(bias(&assignments_propositional, x), 1.0);
+
+        assign_and_unassign(&mut assignments_propositional, x, false);
+        assert_eq!(bias(&assignments_propositional, x), 0.0);
+
+        assign_and_unassign(&mut assignments_propositional, x, false);
+        let bias_after_two_false = bias(&assignments_propositional, x);
+        assert!(bias_after_two_false < 0.0);
+
+        assign_and_unassign(&mut assignments_propositional, x, false);
+        assert!(bias(&assignments_propositional, x) < bias_after_two_false);
+    }
+
+    #[test]
+    fn decay_reduces_influence_of_old_assignments() {
+        let (_, mut assignments_propositional) = TestSelectionContextBuilder::default()
+            .with_propositional()
+            .with_propositional()
+            .build();
+        assignments_propositional.set_polarity_decay_interval(4);
+        let (x, y) = variables(&assignments_propositional);
+
+        for _ in 0..8 {
+            assign_and_unassign(&mut assignments_propositional, x, true);
+        }
+        let counts_before_decay = assignments_propositional.polarity_counts(x);
+        assert!(counts_before_decay.num_true > 0);
+
+        // Assignments of other variables advance the epochs, which halves the counts of `x`
+        // without scanning it.
+        for _ in 0..8 {
+            assign_and_unassign(&mut assignments_propositional, y, true);
+        }
+        let counts_after_decay = assignments_propositional.polarity_counts(x);
+        assert!(counts_after_decay.num_true < counts_before_decay.num_true);
+
+        // Only a few recent assignments to false now outweigh the many older assignments to true.
+        for _ in 0..3 {
+            assign_and_unassign(&mut assignments_propositional, x, false);
+        }
+        assert!(bias(&assignments_propositional, x) < 0.0);
+    }
+
+    #[test]
+    fn counts_saturate_without_losing_the_majority() {
+        let (_, mut assignments_propositional) = TestSelectionContextBuilder::default()
+            .with_propositional()
+            .with_propositional()
+            .build();
+        assignments_propositional.set_polarity_decay_interval(u32::MAX);
+        let (x, _) = variables(&assignments_propositional);
+
+        assign_and_unassign(&mut assignments_propositional, x, false);
+        for _ in 0..70_000 {
+            assign_and_unassign(&mut assignments_propositional, x, true);
+        }
+
+        let counts = assignments_propositional.polarity_counts(x);
+        assert!(counts.num_true <= u16::MAX as u32);
+        assert!(counts.num_true > counts.num_false);
+    }
+
+    #[test]
+    fn phase_saving_selects_majority_polarity() {
+        let (assignments_integer, mut assignments_propositional) =
+            TestSelectionContextBuilder::default()
+                .with_propositional()
+                .with_propositional()
+                .build();
+        let (x, y) = variables(&assignments_propositional);
+
+        assign_and_unassign(&mut assignments_propositional, x, true);
+        assign_and_unassign(&mut assignments_propositional, x, true);
+        assign_and_unassign(&mut assignments_propositional, x, false);
+
+        let mut test_rng = TestRandom::default();
+        let mut context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut test_rng,
+        );
+
+        let mut selector = PhaseSaving::new(PhaseTieBreak::False);
+        assert_eq!(
+            selector.select_value(&mut context, x),
+            Literal::new(x, true).into()
+        );
+        assert_eq!(
+            selector.select_value(&mut context, y),
+            Literal::new(y, false).into()
+        );
+
+        let mut selector = PhaseSaving::new(PhaseTieBreak::True);
+        assert_eq!(
+            selector.select_value(&mut context, y),
+            Literal::new(y, true).into()
+        );
+    }
+}
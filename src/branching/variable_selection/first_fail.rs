@@ -0,0 +1,129 @@
+use log::warn;
+
+use crate::branching::SelectionContext;
+use crate::branching::VariableSelector;
+use crate::variables::IntegerVariable;
+
+/// A [`VariableSelector`] which selects the variable with the fewest values in its domain (taking
+/// into account the holes in the domain); ties are broken by selecting the variable which occurs
+/// first in the provided list.
+#[derive(Debug)]
+pub struct FirstFail<Var> {
+    variables: Vec<Var>,
+}
+
+impl<Var> FirstFail<Var> {
+    pub fn new(variables: Vec<Var>) -> Self {
+        if variables.is_empty() {
+            warn!("The FirstFail variable selector was not provided with any variables");
+        }
+
+        FirstFail { variables }
+    }
+}
+
+impl<Var: IntegerVariable> VariableSelector<Var> for FirstFail<Var> {
+    fn select_variable(&mut self, context: &SelectionContext) -> Option<Var> {
+        self.variables
+            .iter()
+            .filter(|&variable| !context.is_integer_fixed(variable))
+            .min_by_key(|&variable| context.get_number_of_values_in_domain(variable))
+            .cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::basic_types::tests::TestRandom;
+    use crate::branching::assert_selects;
+    use crate::branching::FirstFail;
+    use crate::branching::SelectionContext;
+    use crate::branching::TestSelectionContextBuilder;
+
+    #[test]
+    fn smallest_domain_is_selected() {
+        let (assignments_integer, assignments_propositional) =
+            TestSelectionContextBuilder::default()
+                .with_integer(0, 10)
+                .with_integer(0, 3)
+                .with_integer(0, 5)
+                .build();
+        let mut test_rng = TestRandom::default();
+        let context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut test_rng,
+        );
+        let integer_variables = context.get_domains().collect::<Vec<_>>();
+
+        let mut strategy = FirstFail::new(integer_variables.clone());
+        assert_selects(&mut strategy, &context, Some(integer_variables[1]));
+    }
+
+    #[test]
+    fn holes_are_taken_into_account() {
+        // Both variables have the same bounds but the first one has only 3 values left
+        let (assignments_integer, assignments_propositional) =
+            TestSelectionContextBuilder::default()
+                .with_integer(0, 5)
+                .with_integer_with_holes(0, 5, &[1, 2, 4])
+                .build();
+        let mut test_rng = TestRandom::default();
+        let context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut test_rng,
+        );
+        let integer_variables = context.get_domains().collect::<Vec<_>>();
+
+        let mut strategy = FirstFail::new(integer_variables.clone());
+        assert_selects(&mut strategy, &context, Some(integer_variables[1]));
+    }
+
+    #[test]
+    fn ties_are_broken_by_input_order() {
+        let (assignments_integer, assignments_propositional) =
+            TestSelectionContextBuilder::default()
+                .with_integer(0, 10)
+                .with_integer_with_holes(0, 4, &[1, 3])
+                .with_integer_with_holes(2, 6, &[3, 5])
+                .build();
+        let mut test_rng = TestRandom::default();
+        let context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut test_rng,
+        );
+        let integer_variables = context.get_domains().collect::<Vec<_>>();
+
+        let mut strategy = FirstFail::new(vec![
+            integer_variables[2],
+            integer_variables[1],
+            integer_variables[0],
+        ]);
+        assert_selects(&mut strategy, &context, Some(integer_variables[2]));
+    }
+
+    #[test]
+    fn assigned_variables_are_not_selected() {
+        let (assignments_integer, assignments_propositional) =
+            TestSelectionContextBuilder::default()
+                .with_assigned_integer(0, 10, 4)
+                .with_integer_with_holes(0, 10, &[2, 3, 4, 5, 6, 7])
+                .with_assigned_integer(0, 10, 8)
+                .build();
+        let mut test_rng = TestRandom::default();
+        let context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut test_rng,
+        );
+        let integer_variables = context.get_domains().collect::<Vec<_>>();
+
+        let mut strategy = FirstFail::new(integer_variables.clone());
+        assert_selects(&mut strategy, &context, Some(integer_variables[1]));
+
+        let mut strategy = FirstFail::new(vec![integer_variables[0], integer_variables[2]]);
+        assert_selects(&mut strategy, &context, None);
+    }
+}
@@ -43,14 +43,18 @@ impl VariableSelector<PropositionalVariable> for InputOrder<PropositionalVariabl
 #[cfg(test)]
 mod tests {
     use crate::basic_types::tests::TestRandom;
+    use crate::branching::assert_selects;
     use crate::branching::InputOrder;
     use crate::branching::SelectionContext;
-    use crate::branching::VariableSelector;
+    use crate::branching::TestSelectionContextBuilder;
 
     #[test]
     fn test_correctly_selected() {
         let (mut assignments_integer, assignments_propositional) =
-            SelectionContext::create_for_testing(2, 0, Some(vec![(0, 10), (5, 20)]));
+            TestSelectionContextBuilder::default()
+                .with_integer(0, 10)
+                .with_integer(5, 20)
+                .build();
         let mut test_rng = TestRandom::default();
         let integer_variables = assignments_integer.get_domains().collect::<Vec<_>>();
         let mut strategy = InputOrder::new(integer_variables.clone());
@@ -61,10 +65,7 @@ mod tests {
                 &assignments_propositional,
                 &mut test_rng,
             );
-
-            let selected = strategy.select_variable(&context);
-            assert!(selected.is_some());
-            assert_eq!(selected.unwrap(), integer_variables[0]);
+            assert_selects(&mut strategy, &context, Some(integer_variables[0]));
         }
 
         let _ = assignments_integer.make_assignment(integer_variables[0], 0, None);
@@ -74,16 +75,16 @@ mod tests {
             &assignments_propositional,
             &mut test_rng,
         );
-
-        let selected = strategy.select_variable(&context);
-        assert!(selected.is_some());
-        assert_eq!(selected.unwrap(), integer_variables[1]);
+        assert_selects(&mut strategy, &context, Some(integer_variables[1]));
     }
 
     #[test]
     fn fixed_variables_are_not_selected() {
         let (assignments_integer, assignments_propositional) =
-            SelectionContext::create_for_testing(2, 0, Some(vec![(10, 10), (20, 20)]));
+            TestSelectionContextBuilder::default()
+                .with_assigned_integer(0, 10, 10)
+                .with_assigned_integer(5, 20, 20)
+                .build();
         let mut test_rng = TestRandom::default();
         let context = SelectionContext::new(
             &assignments_integer,
@@ -93,7 +94,48 @@ mod tests {
         let integer_variables = context.get_domains().collect::<Vec<_>>();
 
         let mut strategy = InputOrder::new(integer_variables);
-        let selected = strategy.select_variable(&context);
-        assert!(selected.is_none());
+        assert_selects(&mut strategy, &context, None);
+    }
+
+    #[test]
+    fn domains_with_holes_are_not_fixed() {
+        let (assignments_integer, assignments_propositional) =
+            TestSelectionContextBuilder::default()
+                .with_assigned_integer(0, 10, 3)
+                .with_integer_with_holes(0, 10, &[1, 2, 3, 4, 5, 6, 7, 8, 9])
+                .build();
+        let mut test_rng = TestRandom::default();
+        let context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut test_rng,
+        );
+        let integer_variables = context.get_domains().collect::<Vec<_>>();
+
+        let mut strategy = InputOrder::new(integer_variables.clone());
+        assert_selects(&mut strategy, &context, Some(integer_variables[1]));
+    }
+
+    #[test]
+    fn assigned_propositional_variables_are_not_selected() {
+        let (assignments_integer, assignments_propositional) =
+            TestSelectionContextBuilder::default()
+                .with_assigned_propositional(true)
+                .with_assigned_propositional(false)
+                .with_propositional()
+                .build();
+        let mut test_rng = TestRandom::default();
+        let context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut test_rng,
+        );
+        let propositional_variables = context.get_propositional_variables().collect::<Vec<_>>();
+
+        let mut strategy = InputOrder::new(propositional_variables.clone());
+        assert_selects(&mut strategy, &context, Some(propositional_variables[2]));
+
+        let mut strategy = InputOrder::new(propositional_variables[..2].to_vec());
+        assert_selects(&mut strategy, &context, None);
     }
 }
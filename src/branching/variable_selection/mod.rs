@@ -6,8 +6,12 @@
 //! [`Vsids`]. Any [`VariableSelector`] should only select variables which have a domain of size 2
 //! or larger.
 
+mod first_fail;
 mod input_order;
 mod variable_selector;
 
+pub use first_fail::*;
 pub use input_order::*;
+#[cfg(test)]
+pub(crate) use variable_selector::assert_selects;
 pub use variable_selector::VariableSelector;
@@ -1,3 +1,6 @@
+#[cfg(test)]
+use std::fmt::Debug;
+
 use crate::branching::SelectionContext;
 use crate::engine::variables::DomainId;
 use crate::engine::variables::Literal;
@@ -37,3 +40,17 @@ pub trait VariableSelector<Var> {
     /// A function which is called when a variable appears in a conflict during conflict analysis.
     fn on_appearance_in_conflict_integer(&mut self, _variable: DomainId) {}
 }
+
+/// Asserts that `strategy` selects `expected_variable` given the provided [`SelectionContext`].
+#[cfg(test)]
+pub(crate) fn assert_selects<Var: Debug + PartialEq>(
+    strategy: &mut impl VariableSelector<Var>,
+    context: &SelectionContext,
+    expected_variable: Option<Var>,
+) {
+    let selected = strategy.select_variable(context);
+    assert_eq!(
+        selected, expected_variable,
+        "expected {expected_variable:?} to be selected but {selected:?} was selected"
+    );
+}
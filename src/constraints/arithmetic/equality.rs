@@ -11,6 +11,9 @@ use crate::Solver;
 
 /// Creates the [`NegatableConstraint`] `\sum terms_i = rhs`.
 ///
+/// If there are no terms, then the constraint is decided when it is posted: nothing is posted if
+/// `rhs = 0`, and otherwise the solver becomes infeasible.
+///
 /// Its negation is [`not_equals`].
 pub fn equals<Var: IntegerVariable + Clone + 'static>(
     terms: impl Into<Box<[Var]>>,
@@ -34,6 +37,9 @@ pub fn binary_equals<Var: IntegerVariable + 'static>(
 
 /// Create the [`NegatableConstraint`] `\sum terms_i != rhs`.
 ///
+/// If there are no terms, then the constraint is decided when it is posted: nothing is posted if
+/// `0 != rhs`, and otherwise the solver becomes infeasible.
+///
 /// Its negation is [`equals`].
 pub fn not_equals<Var: IntegerVariable + Clone + 'static>(
     terms: impl Into<Box<[Var]>>,
@@ -121,6 +127,14 @@ where
     Var: IntegerVariable + Clone + 'static,
 {
     fn post(self, solver: &mut Solver, tag: NonZero<u32>) -> Result<(), ConstraintOperationError> {
+        if self.terms.is_empty() {
+            return if self.rhs != 0 {
+                Ok(())
+            } else {
+                solver.add_clause([])
+            };
+        }
+
         LinearNotEqualPropagator::new(self.terms, self.rhs).post(solver, tag)
     }
 
@@ -130,6 +144,14 @@ where
         reification_literal: Literal,
         tag: NonZero<u32>,
     ) -> Result<(), ConstraintOperationError> {
+        if self.terms.is_empty() {
+            return if self.rhs != 0 {
+                Ok(())
+            } else {
+                solver.add_clause([!reification_literal])
+            };
+        }
+
         LinearNotEqualPropagator::new(self.terms, self.rhs).implied_by(
             solver,
             reification_literal,
@@ -1,8 +1,15 @@
 use std::num::NonZero;
 
+#[cfg(doc)]
+use crate::constraints::weighted_sum_less_than_or_equals;
 use crate::constraints::Constraint;
 use crate::constraints::NegatableConstraint;
+use crate::propagators::arithmetic::binary_less_or_equal::BinaryLessOrEqualPropagator;
 use crate::propagators::arithmetic::linear_less_or_equal::LinearLessOrEqualPropagator;
+#[cfg(doc)]
+use crate::variables::AffineView;
+#[cfg(doc)]
+use crate::variables::DomainId;
 use crate::variables::IntegerVariable;
 use crate::variables::Literal;
 use crate::ConstraintOperationError;
@@ -10,7 +17,57 @@ use crate::Solver;
 
 /// Create the [`NegatableConstraint`] `\sum terms_i <= rhs`.
 ///
+/// If there are no terms, then the constraint is decided when it is posted: nothing is posted if
+/// `0 <= rhs`, and otherwise the solver becomes infeasible.
+///
 /// Its negation is `\sum terms_i > rhs`
+///
+/// The terms should all have the same type. A [`DomainId`] can be converted into the
+/// [`AffineView`] `1 * variable`, so plain variables and scaled variables can be mixed by
+/// converting the plain ones; alternatively, the coefficients can be given explicitly using
+/// [`weighted_sum_less_than_or_equals`].
+///
+/// # Example
+/// ```
+/// # use munchkin::constraints;
+/// # use munchkin::variables::AffineView;
+/// # use munchkin::variables::DomainId;
+/// # use munchkin::variables::TransformableVariable;
+/// # use munchkin::Solver;
+/// # use std::num::NonZero;
+/// let mut solver = Solver::default();
+/// let x = solver.new_bounded_integer(0, 3);
+/// let y = solver.new_bounded_integer(0, 3);
+/// let z = solver.new_bounded_integer(0, 3);
+///
+/// // x + y + z <= 5
+/// solver
+///     .add_constraint(constraints::less_than_or_equals([x, y, z], 5))
+///     .post(NonZero::new(1).unwrap())
+///     .expect("the constraint is consistent");
+///
+/// // x - 2y <= 1
+/// solver
+///     .add_constraint(constraints::less_than_or_equals([x.into(), y.scaled(-2)], 1))
+///     .post(NonZero::new(2).unwrap())
+///     .expect("the constraint is consistent");
+///
+/// // 2x - y + z <= 4; if every term is converted, then their type has to be specified
+/// let terms: Vec<AffineView<DomainId>> = vec![x.scaled(2), y.scaled(-1), z.into()];
+/// solver
+///     .add_constraint(constraints::less_than_or_equals(terms, 4))
+///     .post(NonZero::new(3).unwrap())
+///     .expect("the constraint is consistent");
+///
+/// // 2x - y <= 3
+/// solver
+///     .add_constraint(constraints::weighted_sum_less_than_or_equals(
+///         &[(2, x), (-1, y)],
+///         3,
+///     ))
+///     .post(NonZero::new(4).unwrap())
+///     .expect("the constraint is consistent");
+/// ```
 pub fn less_than_or_equals<Var: IntegerVariable + 'static>(
     terms: impl Into<Box<[Var]>>,
     rhs: i32,
@@ -21,24 +78,29 @@ pub fn less_than_or_equals<Var: IntegerVariable + 'static>(
     }
 }
 
-/// Creates the [`NegatableConstraint`] `lhs <= rhs`.
+/// Creates the [`NegatableConstraint`] `lhs + offset <= rhs`.
+///
+/// Unlike [`less_than_or_equals`], this is propagated by a dedicated binary propagator which
+/// explains every propagation with a single predicate.
 ///
-/// Its negation is `lhs > rhs`.
+/// Its negation is `lhs + offset > rhs`.
 pub fn binary_less_than_or_equals<Var: IntegerVariable + 'static>(
     lhs: Var,
     rhs: Var,
+    offset: i32,
 ) -> impl NegatableConstraint {
-    less_than_or_equals([lhs.scaled(1), rhs.scaled(-1)], 0)
+    BinaryInequality { lhs, rhs, offset }
 }
 
-/// Creates the [`NegatableConstraint`] `lhs < rhs`.
+/// Creates the [`NegatableConstraint`] `lhs + offset < rhs`.
 ///
-/// Its negation is `lhs >= rhs`.
+/// Its negation is `lhs + offset >= rhs`.
 pub fn binary_less_than<Var: IntegerVariable + 'static>(
     lhs: Var,
     rhs: Var,
+    offset: i32,
 ) -> impl NegatableConstraint {
-    binary_less_than_or_equals(lhs.scaled(1), rhs.offset(-1))
+    binary_less_than_or_equals(lhs, rhs, offset + 1)
 }
 
 struct Inequality<Var> {
@@ -48,6 +110,14 @@ struct Inequality<Var> {
 
 impl<Var: IntegerVariable + 'static> Constraint for Inequality<Var> {
     fn post(self, solver: &mut Solver, tag: NonZero<u32>) -> Result<(), ConstraintOperationError> {
+        if self.terms.is_empty() {
+            return if 0 <= self.rhs {
+                Ok(())
+            } else {
+                solver.add_clause([])
+            };
+        }
+
         LinearLessOrEqualPropagator::new(self.terms, self.rhs).post(solver, tag)
     }
 
@@ -57,6 +127,14 @@ impl<Var: IntegerVariable + 'static> Constraint for Inequality<Var> {
         reification_literal: Literal,
         tag: NonZero<u32>,
     ) -> Result<(), ConstraintOperationError> {
+        if self.terms.is_empty() {
+            return if 0 <= self.rhs {
+                Ok(())
+            } else {
+                solver.add_clause([!reification_literal])
+            };
+        }
+
         LinearLessOrEqualPropagator::new(self.terms, self.rhs).implied_by(
             solver,
             reification_literal,
@@ -75,3 +153,41 @@ impl<Var: IntegerVariable + 'static> NegatableConstraint for Inequality<Var> {
         }
     }
 }
+
+struct BinaryInequality<Var> {
+    lhs: Var,
+    rhs: Var,
+    offset: i32,
+}
+
+impl<Var: IntegerVariable + 'static> Constraint for BinaryInequality<Var> {
+    fn post(self, solver: &mut Solver, tag: NonZero<u32>) -> Result<(), ConstraintOperationError> {
+        BinaryLessOrEqualPropagator::new(self.lhs, self.rhs, self.offset).post(solver, tag)
+    }
+
+    fn implied_by(
+        self,
+        solver: &mut Solver,
+        reification_literal: Literal,
+        tag: NonZero<u32>,
+    ) -> Result<(), ConstraintOperationError> {
+        BinaryLessOrEqualPropagator::new(self.lhs, self.rhs, self.offset).implied_by(
+            solver,
+            reification_literal,
+            tag,
+        )
+    }
+}
+
+impl<Var: IntegerVariable + 'static> NegatableConstraint for BinaryInequality<Var> {
+    type NegatedConstraint = Self;
+
+    fn negation(&self) -> Self::NegatedConstraint {
+        // `lhs + offset > rhs` is equivalent to `rhs + (1 - offset) <= lhs`
+        BinaryInequality {
+            lhs: self.rhs.clone(),
+            rhs: self.lhs.clone(),
+            offset: 1 - self.offset,
+        }
+    }
+}
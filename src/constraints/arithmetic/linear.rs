@@ -0,0 +1,292 @@
+use std::num::NonZero;
+
+use super::equals;
+use super::less_than_or_equals;
+use super::not_equals;
+use crate::constraints::Constraint;
+use crate::constraints::NegatableConstraint;
+use crate::variables::IntegerVariable;
+use crate::variables::Literal;
+use crate::ConstraintOperationError;
+use crate::Solver;
+
+/// Creates the term `coefficient * variable`, which can be used in linear constraints such as
+/// [`equals`] and [`less_than_or_equals`].
+pub fn linear_term<Var: IntegerVariable>(coefficient: i32, variable: Var) -> Var::AffineView {
+    variable.scaled(coefficient)
+}
+
+/// Starts a [`LinearExpressionBuilder`] for the sum of the provided `variables`.
+///
+/// # Example
+/// ```
+/// # use munchkin::constraints;
+/// # use munchkin::Solver;
+/// # use std::num::NonZero;
+/// let mut solver = Solver::default();
+///
+/// let xs = [
+///     solver.new_bounded_integer(0, 3),
+///     solver.new_bounded_integer(0, 3),
+/// ];
+/// let objective = solver.new_bounded_integer(0, 10);
+///
+/// // x_0 + x_1 - objective = 0
+/// solver
+///     .add_constraint(constraints::sum(xs).minus(objective).equals(0))
+///     .post(NonZero::new(1).unwrap())
+///     .expect("the constraint is consistent");
+///
+/// // 2 * x_0 - x_1 + 1 <= 4
+/// solver
+///     .add_constraint(
+///         constraints::sum([constraints::linear_term(2, xs[0])])
+///             .minus(xs[1])
+///             .plus_constant(1)
+///             .less_than_or_equals(4),
+///     )
+///     .post(NonZero::new(2).unwrap())
+///     .expect("the constraint is consistent");
+/// ```
+pub fn sum<Var: IntegerVariable>(
+    variables: impl IntoIterator<Item = Var>,
+) -> LinearExpressionBuilder<Var::AffineView> {
+    LinearExpressionBuilder {
+        terms: variables
+            .into_iter()
+            .map(|variable| variable.scaled(1))
+            .collect(),
+        constant: 0,
+    }
+}
+
+/// Creates the [`NegatableConstraint`] `\sum coefficient_i * variable_i = rhs`.
+///
+/// Its negation is `\sum coefficient_i * variable_i != rhs`.
+pub fn weighted_sum<Var: IntegerVariable + 'static>(
+    pairs: &[(i32, Var)],
+    rhs: i32,
+) -> impl NegatableConstraint
+where
+    Var::AffineView: IntegerVariable<AffineView = Var::AffineView> + 'static,
+{
+    weighted_sum_builder(pairs).equals(rhs)
+}
+
+/// Creates the [`NegatableConstraint`] `\sum coefficient_i * variable_i <= rhs`.
+///
+/// Its negation is `\sum coefficient_i * variable_i > rhs`.
+pub fn weighted_sum_less_than_or_equals<Var: IntegerVariable + 'static>(
+    pairs: &[(i32, Var)],
+    rhs: i32,
+) -> impl NegatableConstraint
+where
+    Var::AffineView: IntegerVariable<AffineView = Var::AffineView> + 'static,
+{
+    weighted_sum_builder(pairs).less_than_or_equals(rhs)
+}
+
+fn weighted_sum_builder<Var: IntegerVariable>(
+    pairs: &[(i32, Var)],
+) -> LinearExpressionBuilder<Var::AffineView> {
+    LinearExpressionBuilder {
+        terms: pairs
+            .iter()
+            .map(|(coefficient, variable)| variable.scaled(*coefficient))
+            .collect(),
+        constant: 0,
+    }
+}
+
+/// A builder for a linear expression `\sum terms_i + constant`, which is turned into a constraint
+/// by comparing it to a constant (e.g. [`LinearExpressionBuilder::equals`]).
+///
+/// The resulting constraints are posted as [`equals`], [`not_equals`] or [`less_than_or_equals`]
+/// after the constant of the expression has been moved to the right-hand side. If the expression
+/// has no terms, the comparison is decided when the constraint is posted: a constraint which
+/// trivially holds is not posted at all, and one which trivially does not hold makes the solver
+/// infeasible.
+///
+/// See [`sum`] for an example.
+#[derive(Clone, Debug)]
+pub struct LinearExpressionBuilder<View> {
+    terms: Vec<View>,
+    /// The constant is kept as an `i64` such that folding several constants cannot overflow.
+    constant: i64,
+}
+
+impl<View> LinearExpressionBuilder<View>
+where
+    View: IntegerVariable<AffineView = View> + 'static,
+{
+    /// Adds `variable` to the expression.
+    pub fn plus(self, variable: impl IntegerVariable<AffineView = View>) -> Self {
+        self.plus_term(1, variable)
+    }
+
+    /// Subtracts `variable` from the expression.
+    pub fn minus(self, variable: impl IntegerVariable<AffineView = View>) -> Self {
+        self.plus_term(-1, variable)
+    }
+
+    /// Adds `coefficient * variable` to the expression.
+    pub fn plus_term(
+        mut self,
+        coefficient: i32,
+        variable: impl IntegerVariable<AffineView = View>,
+    ) -> Self {
+        self.terms.push(variable.scaled(coefficient));
+        self
+    }
+
+    /// Adds the constant `value` to the expression.
+    pub fn plus_constant(mut self, value: i32) -> Self {
+        self.constant += value as i64;
+        self
+    }
+
+    /// Creates the [`NegatableConstraint`] `expression = rhs`.
+    pub fn equals(self, rhs: i32) -> impl NegatableConstraint {
+        self.compare(Comparison::Equals, rhs)
+    }
+
+    /// Creates the [`NegatableConstraint`] `expression != rhs`.
+    pub fn not_equals(self, rhs: i32) -> impl NegatableConstraint {
+        self.compare(Comparison::NotEquals, rhs)
+    }
+
+    /// Creates the [`NegatableConstraint`] `expression <= rhs`.
+    pub fn less_than_or_equals(self, rhs: i32) -> impl NegatableConstraint {
+        self.compare(Comparison::LessThanOrEquals, rhs)
+    }
+
+    /// Creates the [`NegatableConstraint`] `expression >= rhs`.
+    pub fn greater_than_or_equals(self, rhs: i32) -> impl NegatableConstraint {
+        // `expression >= rhs` is equivalent to `-expression <= -rhs`
+        LinearConstraint {
+            terms: self.terms.iter().map(|term| term.scaled(-1)).collect(),
+            comparison: Comparison::LessThanOrEquals,
+            rhs: self.constant - rhs as i64,
+        }
+    }
+
+    fn compare(self, comparison: Comparison, rhs: i32) -> LinearConstraint<View> {
+        LinearConstraint {
+            terms: self.terms.into(),
+            comparison,
+            rhs: rhs as i64 - self.constant,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Comparison {
+    Equals,
+    NotEquals,
+    LessThanOrEquals,
+}
+
+/// The constraint `\sum terms_i <comparison> rhs`, in which the constants have been folded into
+/// `rhs`.
+struct LinearConstraint<View> {
+    terms: Box<[View]>,
+    comparison: Comparison,
+    rhs: i64,
+}
+
+impl<View> LinearConstraint<View>
+where
+    View: IntegerVariable<AffineView = View> + 'static,
+{
+    /// Whether the constraint holds if there are no terms, i.e. whether `0 <comparison> rhs`.
+    fn holds_for_empty_sum(&self) -> bool {
+        match self.comparison {
+            Comparison::Equals => self.rhs == 0,
+            Comparison::NotEquals => self.rhs != 0,
+            Comparison::LessThanOrEquals => self.rhs >= 0,
+        }
+    }
+
+    fn folded_rhs(&self) -> i32 {
+        i32::try_from(self.rhs)
+            .expect("the right-hand side of a linear constraint should fit in an i32")
+    }
+}
+
+impl<View> Constraint for LinearConstraint<View>
+where
+    View: IntegerVariable<AffineView = View> + 'static,
+{
+    fn post(self, solver: &mut Solver, tag: NonZero<u32>) -> Result<(), ConstraintOperationError> {
+        if self.terms.is_empty() {
+            return if self.holds_for_empty_sum() {
+                Ok(())
+            } else {
+                solver.add_clause([])
+            };
+        }
+
+        let rhs = self.folded_rhs();
+        match self.comparison {
+            Comparison::Equals => equals(self.terms, rhs).post(solver, tag),
+            Comparison::NotEquals => not_equals(self.terms, rhs).post(solver, tag),
+            Comparison::LessThanOrEquals => less_than_or_equals(self.terms, rhs).post(solver, tag),
+        }
+    }
+
+    fn implied_by(
+        self,
+        solver: &mut Solver,
+        reification_literal: Literal,
+        tag: NonZero<u32>,
+    ) -> Result<(), ConstraintOperationError> {
+        if self.terms.is_empty() {
+            return if self.holds_for_empty_sum() {
+                Ok(())
+            } else {
+                solver.add_clause([!reification_literal])
+            };
+        }
+
+        let rhs = self.folded_rhs();
+        match self.comparison {
+            Comparison::Equals => {
+                equals(self.terms, rhs).implied_by(solver, reification_literal, tag)
+            }
+            Comparison::NotEquals => {
+                not_equals(self.terms, rhs).implied_by(solver, reification_literal, tag)
+            }
+            Comparison::LessThanOrEquals => {
+                less_than_or_equals(self.terms, rhs).implied_by(solver, reification_literal, tag)
+            }
+        }
+    }
+}
+
+impl<View> NegatableConstraint for LinearConstraint<View>
+where
+    View: IntegerVariable<AffineView = View> + 'static,
+{
+    type NegatedConstraint = Self;
+
+    fn negation(&self) -> Self::NegatedConstraint {
+        match self.comparison {
+            Comparison::Equals => LinearConstraint {
+                terms: self.terms.clone(),
+                comparison: Comparison::NotEquals,
+                rhs: self.rhs,
+            },
+            Comparison::NotEquals => LinearConstraint {
+                terms: self.terms.clone(),
+                comparison: Comparison::Equals,
+                rhs: self.rhs,
+            },
+            // `\sum terms_i > rhs` is equivalent to `\sum -terms_i <= -rhs - 1`
+            Comparison::LessThanOrEquals => LinearConstraint {
+                terms: self.terms.iter().map(|term| term.scaled(-1)).collect(),
+                comparison: Comparison::LessThanOrEquals,
+                rhs: -self.rhs - 1,
+            },
+        }
+    }
+}
@@ -1,12 +1,15 @@
 mod equality;
 mod inequality;
+mod linear;
 
 use std::num::NonZero;
 
 pub use equality::*;
 pub use inequality::*;
+pub use linear::*;
 
 use super::Constraint;
+use crate::propagators::arithmetic::integer_multiplication::IntegerMultiplicationPropagator;
 use crate::propagators::arithmetic::maximum::MaximumPropagator;
 use crate::variables::IntegerVariable;
 use crate::variables::Literal;
@@ -18,15 +21,33 @@ pub fn plus<Var: IntegerVariable + 'static>(a: Var, b: Var, c: Var) -> impl Cons
     equals([a.scaled(1), b.scaled(1), c.scaled(-1)], 0)
 }
 
+/// Creates the [`Constraint`] `a * b = c`.
+pub fn times(
+    a: impl IntegerVariable + 'static,
+    b: impl IntegerVariable + 'static,
+    c: impl IntegerVariable + 'static,
+) -> impl Constraint {
+    IntegerMultiplicationPropagator::new(a, b, c)
+}
+
 /// Creates the [`Constraint`] `max(array) = m`.
+///
+/// The maximum of an empty array is undefined, so posting the constraint over an empty `array`
+/// fails with [`ConstraintOperationError::EmptyMaximum`].
 pub fn maximum<Var: IntegerVariable + 'static>(
     array: impl Into<Box<[Var]>>,
     rhs: impl IntegerVariable + 'static,
 ) -> impl Constraint {
-    MaximumPropagator::new(array.into(), rhs)
+    Maximum {
+        array: array.into(),
+        rhs,
+    }
 }
 
 /// Creates the [`Constraint`] `max(array) = m`.
+///
+/// The maximum of an empty array is undefined, so posting the constraint over an empty `array`
+/// fails with [`ConstraintOperationError::EmptyMaximum`].
 pub fn maximum_decomposition<Var: IntegerVariable + 'static>(
     array: impl Into<Box<[Var]>>,
     rhs: Var,
@@ -37,6 +58,38 @@ pub fn maximum_decomposition<Var: IntegerVariable + 'static>(
     }
 }
 
+struct Maximum<Var, Rhs> {
+    array: Box<[Var]>,
+    rhs: Rhs,
+}
+
+impl<Var, Rhs> Constraint for Maximum<Var, Rhs>
+where
+    Var: IntegerVariable + 'static,
+    Rhs: IntegerVariable + 'static,
+{
+    fn post(self, solver: &mut Solver, tag: NonZero<u32>) -> Result<(), ConstraintOperationError> {
+        if self.array.is_empty() {
+            return Err(ConstraintOperationError::EmptyMaximum);
+        }
+
+        MaximumPropagator::new(self.array, self.rhs).post(solver, tag)
+    }
+
+    fn implied_by(
+        self,
+        solver: &mut Solver,
+        reification_literal: Literal,
+        tag: NonZero<u32>,
+    ) -> Result<(), ConstraintOperationError> {
+        if self.array.is_empty() {
+            return Err(ConstraintOperationError::EmptyMaximum);
+        }
+
+        MaximumPropagator::new(self.array, self.rhs).implied_by(solver, reification_literal, tag)
+    }
+}
+
 struct MaximumDecomposition<Var> {
     array: Box<[Var]>,
     rhs: Var,
@@ -47,13 +100,34 @@ where
     Var: IntegerVariable + 'static,
 {
     fn post(self, solver: &mut Solver, tag: NonZero<u32>) -> Result<(), ConstraintOperationError> {
-        for element in self.array {
+        if self.array.is_empty() {
+            return Err(ConstraintOperationError::EmptyMaximum);
+        }
+
+        // At least one of the elements should be at least `rhs`
+        let mut at_least_rhs = Vec::with_capacity(self.array.len());
+
+        for element in self.array.iter() {
             solver
-                .add_constraint(binary_less_than_or_equals(element, self.rhs.clone()))
+                .add_constraint(binary_less_than_or_equals(
+                    element.clone(),
+                    self.rhs.clone(),
+                    0,
+                ))
                 .post(tag)?;
+
+            let literal = solver.new_literal();
+            solver
+                .add_constraint(binary_less_than_or_equals(
+                    self.rhs.clone(),
+                    element.clone(),
+                    0,
+                ))
+                .implied_by(literal, tag)?;
+            at_least_rhs.push(literal);
         }
 
-        Ok(())
+        solver.add_clause(at_least_rhs)
     }
 
     fn implied_by(
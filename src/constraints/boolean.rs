@@ -1,7 +1,7 @@
 use std::num::NonZero;
 
-use super::equals;
 use super::less_than_or_equals;
+use super::sum;
 use super::Constraint;
 use crate::predicate;
 use crate::variables::AffineView;
@@ -97,7 +97,7 @@ impl Constraint for BooleanEqual {
     fn post(self, solver: &mut Solver, tag: NonZero<u32>) -> Result<(), ConstraintOperationError> {
         let domains = self.create_domains(solver);
 
-        equals(domains, 0).post(solver, tag)
+        sum(domains).minus(self.rhs).equals(0).post(solver, tag)
     }
 
     fn implied_by(
@@ -108,7 +108,10 @@ impl Constraint for BooleanEqual {
     ) -> Result<(), ConstraintOperationError> {
         let domains = self.create_domains(solver);
 
-        equals(domains, 0).implied_by(solver, reification_literal, tag)
+        sum(domains)
+            .minus(self.rhs)
+            .equals(0)
+            .implied_by(solver, reification_literal, tag)
     }
 }
 
@@ -131,7 +134,6 @@ impl BooleanEqual {
                 ]);
                 corresponding_domain_id.scaled(self.weights[index])
             })
-            .chain(std::iter::once(self.rhs.scaled(-1)))
             .collect()
     }
 }
@@ -16,6 +16,9 @@ use crate::Solver;
 /// global propagator.
 ///
 /// Note that the decomposition is exponential in the number of variables.
+///
+/// The nodes are numbered from 1. A circuit over a single node is the self-loop, i.e. its
+/// successor is fixed to 1, and a circuit over no nodes is trivially satisfied.
 pub fn circuit(
     successor: impl Into<Box<[AffineView<DomainId>]>>,
     sub_circuit_elimination: SubCircuitElimination,
@@ -53,6 +56,16 @@ impl Constraint for DecomposedCircuit {
             use_element_decomposition,
         } = self;
 
+        match &successors[..] {
+            [] => return Ok(()),
+            [successor] => {
+                return solver
+                    .add_constraint(constraints::equals([successor.clone()], 1))
+                    .post(tag)
+            }
+            _ => {}
+        }
+
         match sub_circuit_elimination {
             SubCircuitElimination::Decomposition => post_sub_circuit_elimination_decomposition(
                 solver,
@@ -114,16 +127,22 @@ fn post_sub_circuit_elimination_decomposition(
         .unwrap();
     assert_eq!(i32::try_from(successors.len()).unwrap(), max);
 
+    // The variables are named so that they can be referred to in the proof
     let order: Box<[_]> = (0..max)
         .map(|i| {
             let ub = if i == 0 { 1 } else { max };
 
-            AffineView::from(solver.new_bounded_integer(1, ub))
+            AffineView::from(solver.new_named_bounded_integer(
+                1,
+                ub,
+                format!("circuitOrder{tag}[{i}]"),
+            ))
         })
         .collect();
 
     for (i, successor) in successors.iter().enumerate() {
-        let succ_order = solver.new_bounded_integer(1, max);
+        let succ_order =
+            solver.new_named_bounded_integer(1, max, format!("circuitSuccOrder{tag}[{i}]"));
 
         if use_element_decomposition {
             solver
@@ -31,7 +31,9 @@ impl<ConstraintImpl: Constraint> ConstraintPoster<'_, ConstraintImpl> {
     /// This method returns a [`ConstraintOperationError`] if the addition of the [`Constraint`] led
     /// to a root-level conflict.
     pub fn post(mut self, tag: NonZero<u32>) -> Result<(), ConstraintOperationError> {
-        self.constraint.take().unwrap().post(self.solver, tag)
+        let constraint = self.constraint.take().unwrap();
+        self.solver
+            .post_with_tag(tag, |solver| constraint.post(solver, tag))
     }
 
     /// Add the half-reified version of the [`Constraint`] to the [`Solver`]; i.e. post the
@@ -44,10 +46,10 @@ impl<ConstraintImpl: Constraint> ConstraintPoster<'_, ConstraintImpl> {
         reification_literal: Literal,
         tag: NonZero<u32>,
     ) -> Result<(), ConstraintOperationError> {
-        self.constraint
-            .take()
-            .unwrap()
-            .implied_by(self.solver, reification_literal, tag)
+        let constraint = self.constraint.take().unwrap();
+        self.solver.post_with_tag(tag, |solver| {
+            constraint.implied_by(solver, reification_literal, tag)
+        })
     }
 }
 
@@ -62,10 +64,10 @@ impl<ConstraintImpl: NegatableConstraint> ConstraintPoster<'_, ConstraintImpl> {
         reification_literal: Literal,
         tag: NonZero<u32>,
     ) -> Result<(), ConstraintOperationError> {
-        self.constraint
-            .take()
-            .unwrap()
-            .reify(self.solver, reification_literal, tag)
+        let constraint = self.constraint.take().unwrap();
+        self.solver.post_with_tag(tag, |solver| {
+            constraint.reify(solver, reification_literal, tag)
+        })
     }
 }
 
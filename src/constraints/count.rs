@@ -0,0 +1,162 @@
+use std::num::NonZero;
+
+use super::sum;
+use super::Constraint;
+use crate::predicate;
+use crate::propagators::count::CountPropagator;
+use crate::variables::AffineView;
+use crate::variables::DomainId;
+use crate::variables::IntegerVariable;
+use crate::variables::Literal;
+use crate::variables::TransformableVariable;
+use crate::ConstraintOperationError;
+use crate::Solver;
+
+/// Creates the [count](https://sofdem.github.io/gccat/gccat/Ccount.html) [`Constraint`] which
+/// states that `rhs` is equal to the number of variables in `array` which take the value `value`.
+pub fn count<Var: IntegerVariable + 'static>(
+    array: impl Into<Box<[Var]>>,
+    value: i32,
+    rhs: impl IntegerVariable + 'static,
+) -> impl Constraint {
+    CountPropagator::new(array.into(), value, rhs)
+}
+
+/// Creates the [among](https://sofdem.github.io/gccat/gccat/Camong.html) [`Constraint`] which
+/// states that the number of variables in `array` which take one of the values in `values` is at
+/// least `lower` and at most `upper`.
+pub fn among<Var: IntegerVariable + 'static>(
+    array: impl Into<Box<[Var]>>,
+    values: impl Into<Box<[i32]>>,
+    lower: i32,
+    upper: i32,
+) -> impl Constraint {
+    Among {
+        array: array.into(),
+        values: values.into(),
+        lower,
+        upper,
+    }
+}
+
+struct Among<Var> {
+    array: Box<[Var]>,
+    values: Box<[i32]>,
+    lower: i32,
+    upper: i32,
+}
+
+impl<Var: IntegerVariable + 'static> Among<Var> {
+    /// Creates the variable which counts the variables taking one of the values, and posts the
+    /// propagator which defines it; returns the literals which state that the counter lies within
+    /// the bounds of the constraint.
+    fn post_counter(
+        self,
+        solver: &mut Solver,
+        tag: NonZero<u32>,
+    ) -> Result<[Literal; 2], ConstraintOperationError> {
+        let counter = solver.new_bounded_integer(0, self.array.len() as i32);
+        solver.add_propagator(
+            CountPropagator::with_values(self.array, self.values, counter),
+            tag,
+        )?;
+
+        let lower = self.lower;
+        let upper = self.upper;
+        Ok([
+            solver.get_literal(predicate![counter >= lower]),
+            solver.get_literal(predicate![counter <= upper]),
+        ])
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Constraint for Among<Var> {
+    fn post(self, solver: &mut Solver, tag: NonZero<u32>) -> Result<(), ConstraintOperationError> {
+        for bound in self.post_counter(solver, tag)? {
+            solver.add_clause([bound])?;
+        }
+
+        Ok(())
+    }
+
+    fn implied_by(
+        self,
+        solver: &mut Solver,
+        reification_literal: Literal,
+        tag: NonZero<u32>,
+    ) -> Result<(), ConstraintOperationError> {
+        // The counter is a new variable, so it can be defined regardless of the reification
+        for bound in self.post_counter(solver, tag)? {
+            solver.add_clause([!reification_literal, bound])?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Creates a decomposition of [`count`] which introduces a 0-1 variable `b_i <-> [array[i] ==
+/// value]` for every variable in `array` and posts `\sum b_i = rhs`.
+pub fn count_decomposition<Var: IntegerVariable + 'static>(
+    array: impl Into<Box<[Var]>>,
+    value: i32,
+    rhs: AffineView<DomainId>,
+) -> impl Constraint {
+    CountDecomposition {
+        array: array.into(),
+        value,
+        rhs,
+    }
+}
+
+struct CountDecomposition<Var> {
+    array: Box<[Var]>,
+    value: i32,
+    rhs: AffineView<DomainId>,
+}
+
+impl<Var: IntegerVariable + 'static> CountDecomposition<Var> {
+    /// Creates the 0-1 variables which indicate whether the elements of the array take `value`
+    /// and returns them as the terms of the count.
+    fn create_terms(
+        &self,
+        solver: &mut Solver,
+        tag: NonZero<u32>,
+    ) -> Result<Vec<AffineView<DomainId>>, ConstraintOperationError> {
+        let mut terms = Vec::with_capacity(self.array.len());
+
+        for (i, element) in self.array.iter().enumerate() {
+            // The variables are named so that they can be referred to in the proof
+            let is_value = solver.new_named_bounded_integer(0, 1, format!("count{tag}[{i}]"));
+
+            let element_is_value = solver.get_literal(predicate![element == self.value]);
+            let is_value_literal = solver.get_literal(predicate![is_value >= 1]);
+            solver.add_clause([!element_is_value, is_value_literal])?;
+            solver.add_clause([element_is_value, !is_value_literal])?;
+
+            terms.push(is_value.scaled(1));
+        }
+
+        Ok(terms)
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Constraint for CountDecomposition<Var> {
+    fn post(self, solver: &mut Solver, tag: NonZero<u32>) -> Result<(), ConstraintOperationError> {
+        let terms = self.create_terms(solver, tag)?;
+        solver
+            .add_constraint(sum(terms).minus(self.rhs).equals(0))
+            .post(tag)
+    }
+
+    fn implied_by(
+        self,
+        solver: &mut Solver,
+        reification_literal: Literal,
+        tag: NonZero<u32>,
+    ) -> Result<(), ConstraintOperationError> {
+        let terms = self.create_terms(solver, tag)?;
+        solver
+            .add_constraint(sum(terms).minus(self.rhs).equals(0))
+            .implied_by(reification_literal, tag)
+    }
+}
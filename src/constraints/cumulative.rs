@@ -4,6 +4,7 @@ use std::num::NonZero;
 use super::Constraint;
 use crate::constraints;
 use crate::propagators::cumulative::EnergeticReasoningPropagator;
+use crate::propagators::cumulative::OverloadCheckingPropagator;
 use crate::propagators::cumulative::TimeTablePropagator;
 use crate::variables::IntegerVariable;
 use crate::variables::Literal;
@@ -17,18 +18,43 @@ pub enum CumulativeImpl {
     Decomposition,
 }
 
+/// The propagation passes which are used by the [`CumulativeImpl::TimeTable`] implementation of
+/// the [`cumulative`] constraint.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CumulativeOptions {
+    /// Whether the time-table propagator is used.
+    pub time_table: bool,
+    /// Whether the resource is checked for overloads using energetic reasoning over task
+    /// intervals; this check is only performed once the other propagators (including the
+    /// time-table propagator) have reached a fixed point.
+    pub overload_checking: bool,
+}
+
+impl Default for CumulativeOptions {
+    fn default() -> Self {
+        CumulativeOptions {
+            time_table: true,
+            overload_checking: false,
+        }
+    }
+}
+
 /// Creates the [Cumulative](https://sofdem.github.io/gccat/gccat/Ccumulative.html) [`Constraint`].
 /// This constraint ensures that at no point in time, the cumulative resource usage of the tasks
 /// exceeds `bound`.
+///
+/// The propagation which is used by the [`CumulativeImpl::TimeTable`] implementation can be
+/// configured using [`CumulativeConstraint::with_options`].
 pub fn cumulative<Var: IntegerVariable + 'static + Debug>(
     impl_strategy: CumulativeImpl,
     start_times: impl Into<Box<[Var]>>,
     durations: impl Into<Box<[u32]>>,
     resource_requirements: impl Into<Box<[u32]>>,
     resource_capacity: u32,
-) -> impl Constraint {
+) -> CumulativeConstraint<Var> {
     CumulativeConstraint {
         impl_strategy,
+        options: CumulativeOptions::default(),
         start_times: start_times.into(),
         durations: durations.into(),
         resource_requirements: resource_requirements.into(),
@@ -36,18 +62,31 @@ pub fn cumulative<Var: IntegerVariable + 'static + Debug>(
     }
 }
 
-struct CumulativeConstraint<Var> {
+/// The [`Constraint`] created by [`cumulative`].
+#[derive(Debug)]
+pub struct CumulativeConstraint<Var> {
     impl_strategy: CumulativeImpl,
+    options: CumulativeOptions,
     start_times: Box<[Var]>,
     durations: Box<[u32]>,
     resource_requirements: Box<[u32]>,
     resource_capacity: u32,
 }
 
+impl<Var> CumulativeConstraint<Var> {
+    /// Sets the [`CumulativeOptions`] which are used by the [`CumulativeImpl::TimeTable`]
+    /// implementation; they are ignored by the other implementations.
+    pub fn with_options(mut self, options: CumulativeOptions) -> Self {
+        self.options = options;
+        self
+    }
+}
+
 impl<Var: IntegerVariable + 'static> Constraint for CumulativeConstraint<Var> {
     fn post(self, solver: &mut Solver, tag: NonZero<u32>) -> Result<(), ConstraintOperationError> {
         let CumulativeConstraint {
             impl_strategy,
+            options,
             start_times,
             durations,
             resource_requirements,
@@ -55,15 +94,33 @@ impl<Var: IntegerVariable + 'static> Constraint for CumulativeConstraint<Var> {
         } = self;
 
         match impl_strategy {
-            CumulativeImpl::TimeTable => solver.add_propagator(
-                TimeTablePropagator::new(
-                    start_times,
-                    durations,
-                    resource_requirements,
-                    resource_capacity,
-                ),
-                tag,
-            ),
+            CumulativeImpl::TimeTable => {
+                if options.time_table {
+                    solver.add_propagator(
+                        TimeTablePropagator::new(
+                            start_times.clone(),
+                            durations.clone(),
+                            resource_requirements.clone(),
+                            resource_capacity,
+                        ),
+                        tag,
+                    )?;
+                }
+
+                if options.overload_checking {
+                    solver.add_propagator(
+                        OverloadCheckingPropagator::new(
+                            start_times,
+                            durations,
+                            resource_requirements,
+                            resource_capacity,
+                        ),
+                        tag,
+                    )?;
+                }
+
+                Ok(())
+            }
             CumulativeImpl::EnergeticReasoning => solver.add_propagator(
                 EnergeticReasoningPropagator::new(
                     start_times,
@@ -0,0 +1,104 @@
+use std::num::NonZero;
+
+use super::less_than_or_equals;
+use super::Constraint;
+use crate::propagators::disjunctive::DisjunctivePropagator;
+use crate::variables::IntegerVariable;
+use crate::variables::Literal;
+use crate::ConstraintOperationError;
+use crate::Solver;
+
+/// Creates the [disjunctive](https://sofdem.github.io/gccat/gccat/Cdisjunctive.html)
+/// [`Constraint`] which states that no two tasks overlap on a unary resource, where task `i`
+/// starts at `start_times[i]` and takes `durations[i]` time units.
+///
+/// Tasks with a duration of 0 do not occupy the resource.
+pub fn disjunctive<Var: IntegerVariable + 'static>(
+    start_times: impl Into<Box<[Var]>>,
+    durations: impl Into<Box<[i32]>>,
+) -> impl Constraint {
+    DisjunctivePropagator::new(start_times.into(), durations.into())
+}
+
+/// Creates a decomposition of [`disjunctive`] which introduces the literals `p_ij` and `p_ji` for
+/// every pair of tasks `i < j`, posts `p_ij -> s_i + d_i <= s_j` and `p_ji -> s_j + d_j <= s_i`,
+/// and requires that at least one of `p_ij` and `p_ji` holds.
+pub fn disjunctive_decomposition<Var: IntegerVariable + 'static>(
+    start_times: impl Into<Box<[Var]>>,
+    durations: impl Into<Box<[i32]>>,
+) -> impl Constraint {
+    DisjunctiveDecomposition {
+        start_times: start_times.into(),
+        durations: durations.into(),
+    }
+}
+
+struct DisjunctiveDecomposition<Var> {
+    start_times: Box<[Var]>,
+    durations: Box<[i32]>,
+}
+
+impl<Var: IntegerVariable + 'static> DisjunctiveDecomposition<Var> {
+    /// Posts the precedences between every pair of tasks; if a `reification_literal` is provided,
+    /// then one of the precedences only has to hold when it is true.
+    fn post_precedences(
+        self,
+        solver: &mut Solver,
+        reification_literal: Option<Literal>,
+        tag: NonZero<u32>,
+    ) -> Result<(), ConstraintOperationError> {
+        let tasks = self
+            .start_times
+            .iter()
+            .zip(self.durations.iter().copied())
+            .filter(|&(_, duration)| duration > 0)
+            .collect::<Vec<_>>();
+
+        for (index, &(start_i, duration_i)) in tasks.iter().enumerate() {
+            for &(start_j, duration_j) in tasks[index + 1..].iter() {
+                let i_before_j = solver.new_literal();
+                let j_before_i = solver.new_literal();
+
+                // s_i + d_i <= s_j
+                solver
+                    .add_constraint(less_than_or_equals(
+                        [start_i.scaled(1), start_j.scaled(-1)],
+                        -duration_i,
+                    ))
+                    .implied_by(i_before_j, tag)?;
+
+                // s_j + d_j <= s_i
+                solver
+                    .add_constraint(less_than_or_equals(
+                        [start_j.scaled(1), start_i.scaled(-1)],
+                        -duration_j,
+                    ))
+                    .implied_by(j_before_i, tag)?;
+
+                solver.add_clause(
+                    reification_literal
+                        .map(|literal| !literal)
+                        .into_iter()
+                        .chain([i_before_j, j_before_i]),
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Constraint for DisjunctiveDecomposition<Var> {
+    fn post(self, solver: &mut Solver, tag: NonZero<u32>) -> Result<(), ConstraintOperationError> {
+        self.post_precedences(solver, None, tag)
+    }
+
+    fn implied_by(
+        self,
+        solver: &mut Solver,
+        reification_literal: Literal,
+        tag: NonZero<u32>,
+    ) -> Result<(), ConstraintOperationError> {
+        self.post_precedences(solver, Some(reification_literal), tag)
+    }
+}
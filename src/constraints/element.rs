@@ -42,6 +42,16 @@ where
     ArrayVar: IntegerVariable + 'static,
 {
     fn post(self, solver: &mut Solver, tag: NonZero<u32>) -> Result<(), ConstraintOperationError> {
+        // With a single element, the index is fixed and the element is equal to `rhs`.
+        if let [array_element] = &self.array[..] {
+            let index_is_one = solver.get_literal(predicate![self.index == 1]);
+            solver.add_clause([index_is_one])?;
+
+            return solver
+                .add_constraint(constraints::binary_equals(array_element.clone(), self.rhs))
+                .post(tag);
+        }
+
         // Index is 1-indexed, but the implementation is 0-indexed.
         let index = self.index.offset(-1);
 
@@ -0,0 +1,145 @@
+use std::num::NonZero;
+
+use thiserror::Error;
+
+use super::Constraint;
+use crate::predicate;
+use crate::propagators::inverse::InversePropagator;
+use crate::variables::IntegerVariable;
+use crate::variables::Literal;
+use crate::ConstraintOperationError;
+use crate::Solver;
+
+/// Creates the [inverse](https://sofdem.github.io/gccat/gccat/Cinverse.html) [`Constraint`] which
+/// states that `f[i] = j` if and only if `g[j] = i`; e.g. `f` are the successors and `g` the
+/// predecessors of the nodes in a route.
+///
+/// The values are 0-based: both arrays should have the same length `n` and every variable should
+/// have a domain within `[0, n)`. Posting the constraint fails with
+/// [`ConstraintOperationError::InvalidInverse`] otherwise.
+pub fn inverse<Var: IntegerVariable + 'static>(
+    f: impl Into<Box<[Var]>>,
+    g: impl Into<Box<[Var]>>,
+) -> impl Constraint {
+    Inverse {
+        f: f.into(),
+        g: g.into(),
+        use_decomposition: false,
+    }
+}
+
+/// Creates a decomposition of [`inverse`] which ties the equality literals `[f[i] == j]` and
+/// `[g[j] == i]` together with clauses.
+pub fn inverse_decomposition<Var: IntegerVariable + 'static>(
+    f: impl Into<Box<[Var]>>,
+    g: impl Into<Box<[Var]>>,
+) -> impl Constraint {
+    Inverse {
+        f: f.into(),
+        g: g.into(),
+        use_decomposition: true,
+    }
+}
+
+/// The reasons for which the arrays of an [`inverse`] constraint are rejected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Error)]
+pub enum InvalidInverse {
+    #[error("The arrays have different lengths {f_length} and {g_length}")]
+    MismatchedLengths { f_length: usize, g_length: usize },
+    #[error("A variable has domain [{lower_bound}, {upper_bound}], which is not within [0, {n})")]
+    ValueOutOfRange {
+        lower_bound: i32,
+        upper_bound: i32,
+        n: usize,
+    },
+}
+
+struct Inverse<Var> {
+    f: Box<[Var]>,
+    g: Box<[Var]>,
+    use_decomposition: bool,
+}
+
+impl<Var: IntegerVariable + 'static> Inverse<Var> {
+    fn validate(&self, solver: &Solver) -> Result<(), InvalidInverse> {
+        if self.f.len() != self.g.len() {
+            return Err(InvalidInverse::MismatchedLengths {
+                f_length: self.f.len(),
+                g_length: self.g.len(),
+            });
+        }
+
+        let n = self.f.len();
+        for variable in self.f.iter().chain(self.g.iter()) {
+            let lower_bound = solver.lower_bound(variable);
+            let upper_bound = solver.upper_bound(variable);
+
+            if lower_bound < 0 || upper_bound >= n as i32 {
+                return Err(InvalidInverse::ValueOutOfRange {
+                    lower_bound,
+                    upper_bound,
+                    n,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Posts the clauses `[f[i] == j] <-> [g[j] == i]`, which are only enforced if the
+    /// `reification_literal` (if any) is true.
+    fn post_decomposition(
+        self,
+        solver: &mut Solver,
+        reification_literal: Option<Literal>,
+    ) -> Result<(), ConstraintOperationError> {
+        let condition = reification_literal.map(|literal| !literal);
+
+        for (i, source) in self.f.iter().enumerate() {
+            for (j, target) in self.g.iter().enumerate() {
+                let source_literal = solver.get_literal(predicate![source == j as i32]);
+                let target_literal = solver.get_literal(predicate![target == i as i32]);
+
+                solver.add_clause(
+                    condition
+                        .into_iter()
+                        .chain([!source_literal, target_literal]),
+                )?;
+                solver.add_clause(
+                    condition
+                        .into_iter()
+                        .chain([source_literal, !target_literal]),
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Constraint for Inverse<Var> {
+    fn post(self, solver: &mut Solver, tag: NonZero<u32>) -> Result<(), ConstraintOperationError> {
+        self.validate(solver)?;
+
+        if self.use_decomposition {
+            self.post_decomposition(solver, None)
+        } else {
+            InversePropagator::new(self.f, self.g).post(solver, tag)
+        }
+    }
+
+    fn implied_by(
+        self,
+        solver: &mut Solver,
+        reification_literal: Literal,
+        tag: NonZero<u32>,
+    ) -> Result<(), ConstraintOperationError> {
+        self.validate(solver)?;
+
+        if self.use_decomposition {
+            self.post_decomposition(solver, Some(reification_literal))
+        } else {
+            InversePropagator::new(self.f, self.g).implied_by(solver, reification_literal, tag)
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use super::Constraint;
+use crate::propagators::lex_leq::LexLeqPropagator;
+use crate::variables::IntegerVariable;
+
+/// Creates the [lex_lesseq](https://sofdem.github.io/gccat/gccat/Clex_lesseq.html) [`Constraint`]
+/// which states that `xs` is lexicographically smaller than or equal to `ys`.
+///
+/// This constraint is typically used to break symmetries between interchangeable rows of
+/// variables. The sequences `xs` and `ys` should have the same length.
+pub fn lex_leq<Var: IntegerVariable + 'static>(
+    xs: impl Into<Box<[Var]>>,
+    ys: impl Into<Box<[Var]>>,
+) -> impl Constraint {
+    LexLeqPropagator::new(xs.into(), ys.into())
+}
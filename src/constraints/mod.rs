@@ -28,8 +28,14 @@ mod boolean;
 mod circuit;
 mod clause;
 mod constraint_poster;
+mod count;
 mod cumulative;
+mod disjunctive;
 mod element;
+mod inverse;
+mod lex_leq;
+mod regular;
+mod value_precede;
 
 use std::num::NonZero;
 
@@ -39,8 +45,16 @@ pub use boolean::*;
 pub use circuit::*;
 pub use clause::*;
 pub use constraint_poster::*;
+pub use count::*;
 pub use cumulative::*;
+pub use disjunctive::*;
 pub use element::*;
+pub use inverse::*;
+pub use lex_leq::*;
+pub use regular::*;
+pub use value_precede::*;
+
+pub use crate::engine::constraint_satisfaction_solver::ConstraintHandle;
 
 use crate::engine::cp::propagation::Propagator;
 use crate::propagators::ReifiedPropagator;
@@ -0,0 +1,131 @@
+use std::num::NonZero;
+
+use thiserror::Error;
+
+use super::Constraint;
+use crate::propagators::regular::RegularPropagator;
+use crate::variables::IntegerVariable;
+use crate::variables::Literal;
+use crate::ConstraintOperationError;
+use crate::Solver;
+
+/// Creates the [regular](https://sofdem.github.io/gccat/gccat/Cregular.html) [`Constraint`] which
+/// states that the sequence `xs` spells a word which is accepted by the provided deterministic
+/// finite automaton.
+///
+/// The automaton has the states `0..num_states` and reads the symbols `0..alphabet_size`; every
+/// transition `(from, symbol, to)` states that reading `symbol` in state `from` leads to state
+/// `to`, and a symbol which has no transition from a state cannot be read in that state. The word
+/// is accepted if reading it from `initial_state` ends in one of the `accepting_states`.
+///
+/// This constraint is typically used in rostering, e.g. to state that an employee works at most
+/// two night shifts in a row. Posting the constraint fails with
+/// [`ConstraintOperationError::InvalidAutomaton`] if the automaton refers to states or symbols
+/// outside of its range or is not deterministic.
+pub fn regular<Var: IntegerVariable + 'static>(
+    xs: impl Into<Box<[Var]>>,
+    num_states: usize,
+    alphabet_size: usize,
+    transitions: Vec<(usize, i32, usize)>,
+    initial_state: usize,
+    accepting_states: Vec<usize>,
+) -> impl Constraint {
+    Regular {
+        xs: xs.into(),
+        num_states,
+        alphabet_size,
+        transitions,
+        initial_state,
+        accepting_states,
+    }
+}
+
+/// The reasons for which the automaton of a [`regular`] constraint is rejected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Error)]
+pub enum InvalidAutomaton {
+    #[error("The state {state} is not in the range [0, {num_states})")]
+    StateOutOfRange { state: usize, num_states: usize },
+    #[error("The symbol {symbol} is not in the range [0, {alphabet_size})")]
+    SymbolOutOfRange { symbol: i32, alphabet_size: usize },
+    #[error("There are multiple transitions from state {state} reading the symbol {symbol}")]
+    NonDeterministic { state: usize, symbol: i32 },
+}
+
+struct Regular<Var> {
+    xs: Box<[Var]>,
+    num_states: usize,
+    alphabet_size: usize,
+    transitions: Vec<(usize, i32, usize)>,
+    initial_state: usize,
+    accepting_states: Vec<usize>,
+}
+
+impl<Var: IntegerVariable + 'static> Regular<Var> {
+    fn validate(&self) -> Result<(), InvalidAutomaton> {
+        let num_states = self.num_states;
+        let check_state = |state: usize| {
+            if state < num_states {
+                Ok(())
+            } else {
+                Err(InvalidAutomaton::StateOutOfRange { state, num_states })
+            }
+        };
+
+        check_state(self.initial_state)?;
+        self.accepting_states
+            .iter()
+            .try_for_each(|&state| check_state(state))?;
+
+        let mut has_transition = vec![false; self.num_states * self.alphabet_size];
+        for &(from, symbol, to) in &self.transitions {
+            check_state(from)?;
+            check_state(to)?;
+            if symbol < 0 || symbol as usize >= self.alphabet_size {
+                return Err(InvalidAutomaton::SymbolOutOfRange {
+                    symbol,
+                    alphabet_size: self.alphabet_size,
+                });
+            }
+
+            let index = from * self.alphabet_size + symbol as usize;
+            if has_transition[index] {
+                return Err(InvalidAutomaton::NonDeterministic {
+                    state: from,
+                    symbol,
+                });
+            }
+            has_transition[index] = true;
+        }
+
+        Ok(())
+    }
+
+    fn into_propagator(self) -> Result<RegularPropagator<Var>, ConstraintOperationError> {
+        self.validate()?;
+
+        Ok(RegularPropagator::new(
+            self.xs,
+            self.num_states,
+            self.alphabet_size,
+            &self.transitions,
+            self.initial_state,
+            &self.accepting_states,
+        ))
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Constraint for Regular<Var> {
+    fn post(self, solver: &mut Solver, tag: NonZero<u32>) -> Result<(), ConstraintOperationError> {
+        self.into_propagator()?.post(solver, tag)
+    }
+
+    fn implied_by(
+        self,
+        solver: &mut Solver,
+        reification_literal: Literal,
+        tag: NonZero<u32>,
+    ) -> Result<(), ConstraintOperationError> {
+        self.into_propagator()?
+            .implied_by(solver, reification_literal, tag)
+    }
+}
@@ -0,0 +1,16 @@
+use super::Constraint;
+use crate::propagators::value_precede::ValuePrecedePropagator;
+use crate::variables::IntegerVariable;
+
+/// Creates the [int_value_precede](https://sofdem.github.io/gccat/gccat/Cint_value_precede.html)
+/// [`Constraint`] which states that the first occurrence of the value `s` in `xs` comes before the
+/// first occurrence of the value `t`; if `t` occurs in `xs`, then `s` has to occur before it.
+///
+/// This constraint is typically used to break symmetries between interchangeable values.
+pub fn value_precede<Var: IntegerVariable + 'static>(
+    s: i32,
+    t: i32,
+    xs: impl Into<Box<[Var]>>,
+) -> impl Constraint {
+    ValuePrecedePropagator::new(s, t, xs.into())
+}
@@ -5,7 +5,11 @@ use super::LearnedNogood;
 use crate::engine::ConstraintSatisfactionSolver;
 
 #[derive(Default, Debug)]
-pub(crate) struct AllDecisionLearning {
+#[allow(
+    missing_copy_implementations,
+    reason = "the state of the resolver is added in the assignments"
+)]
+pub struct AllDecisionLearning {
     // TODO
 }
 
@@ -4,12 +4,15 @@ use super::LearnedNogood;
 use crate::basic_types::ClauseReference;
 use crate::basic_types::Conjunction;
 use crate::basic_types::ConstraintReference;
+use crate::basic_types::KeyedVec;
 use crate::basic_types::StoredConflictInfo;
 use crate::branching::Brancher;
 use crate::engine::constraint_satisfaction_solver::CSPSolverState;
 use crate::engine::constraint_satisfaction_solver::Counters;
 use crate::engine::cp::propagation::propagation_context::HasAssignments;
 use crate::engine::cp::propagation::PropagationContext;
+use crate::engine::cp::propagation::Propagator;
+use crate::engine::cp::propagation::PropagatorId;
 use crate::engine::cp::reason::ReasonRef;
 use crate::engine::cp::reason::ReasonStore;
 use crate::engine::cp::AssignmentsInteger;
@@ -22,6 +25,7 @@ use crate::engine::sat::ClausalPropagator;
 use crate::engine::sat::ClauseAllocator;
 use crate::engine::sat::ExplanationClauseManager;
 use crate::engine::variables::Literal;
+use crate::engine::ConstraintSatisfactionSolver;
 use crate::engine::SatisfactionSolverOptions;
 use crate::munchkin_assert_moderate;
 use crate::munchkin_assert_simple;
@@ -29,7 +33,7 @@ use crate::munchkin_assert_simple;
 /// Used during conflict analysis to provide the necessary information.
 /// All fields are made public for the time being for simplicity. In the future that may change.
 #[allow(missing_debug_implementations, unused)]
-pub(crate) struct ConflictAnalysisContext<'a> {
+pub struct ConflictAnalysisContext<'a> {
     pub(crate) clausal_propagator: &'a mut ClausalPropagator,
     pub(crate) variable_literal_mappings: &'a VariableLiteralMappings,
     pub(crate) assignments_integer: &'a mut AssignmentsInteger,
@@ -47,6 +51,7 @@ pub(crate) struct ConflictAnalysisContext<'a> {
     pub(crate) propositional_trail_index: &'a mut usize,
     pub(crate) propagator_queue: &'a mut PropagatorQueue,
     pub(crate) watch_list_cp: &'a mut WatchListCP,
+    pub(crate) propagators: &'a mut KeyedVec<PropagatorId, Box<dyn Propagator>>,
     pub(crate) sat_trail_synced_position: &'a mut usize,
     pub(crate) cp_trail_synced_position: &'a mut usize,
 }
@@ -115,13 +120,18 @@ impl ConflictAnalysisContext<'_> {
             self.assignments_propositional.num_trail_entries(),
         );
         self.assignments_integer
-            .synchronise(backtrack_level)
-            .iter()
-            .for_each(|(domain_id, previous_value)| {
-                self.brancher
-                    .on_unassign_integer(*domain_id, *previous_value)
+            .synchronise(backtrack_level, |domain_id, previous_value| {
+                self.brancher.on_unassign_integer(domain_id, previous_value)
             });
 
+        ConstraintSatisfactionSolver::deliver_backtrack_events(
+            self.assignments_integer,
+            self.assignments_propositional,
+            self.watch_list_cp,
+            self.propagators,
+            self.internal_parameters,
+        );
+
         self.reason_store.synchronise(backtrack_level);
         self.propagator_queue.clear();
         //  note that variable_literal_mappings sync should be called after the sat/cp data
@@ -174,6 +184,15 @@ impl ConflictAnalysisContext<'_> {
     /// Returns the reason for the provided `literal` in the form `l_1 /\ ... /\ l_n -> literal`
     #[allow(unused, reason = "will be used in an assignment")]
     pub(crate) fn get_reason(&mut self, literal: Literal) -> Conjunction {
+        // binary clauses are not allocated, the reason consists of the other literal of the clause
+        let constraint_reference = self
+            .assignments_propositional
+            .get_literal_reason_constraint(literal);
+        if constraint_reference.is_virtual_binary_clause() {
+            let clause_reference = constraint_reference.as_clause_reference();
+            return vec![!clause_reference.get_virtual_binary_clause_literal()].into();
+        }
+
         let clause_reference = self.get_propagation_clause_reference(literal);
         // 0-th literal is the propagated literal so it is skipped
         self.clause_allocator[clause_reference].get_literal_slice()[1..]
@@ -187,6 +206,21 @@ impl ConflictAnalysisContext<'_> {
     /// Returns the reason for the current conflict in the form `l_1 /\ ... /\ l_n -> false`
     #[allow(unused, reason = "will be used in an assignment")]
     pub(crate) fn get_conflict_nogood(&mut self) -> Conjunction {
+        // binary clauses are not allocated, the conflicting clause consists of the propagated
+        // literal and the other literal of the clause
+        if let StoredConflictInfo::Propagation { literal, reference } =
+            self.solver_state.get_conflict_info()
+        {
+            if reference.is_virtual_binary_clause() {
+                let clause_reference = reference.as_clause_reference();
+                return vec![
+                    !*literal,
+                    !clause_reference.get_virtual_binary_clause_literal(),
+                ]
+                .into();
+            }
+        }
+
         let clause_reference = self.get_conflict_reason_clause_reference();
         self.clause_allocator[clause_reference]
             .get_literal_slice()
@@ -1,7 +1,11 @@
 use super::ConflictAnalysisContext;
 use crate::variables::Literal;
+#[cfg(doc)]
+use crate::Solver;
 
-pub(crate) trait ConflictResolver {
+/// A strategy for resolving conflicts which the [`Solver`] can be created with using
+/// [`Solver::with_options_and_conflict_resolver`].
+pub trait ConflictResolver {
     /// Resolves the current conflict.
     ///
     /// If the [`ConflictResolver`] learns a nogood then it should be returned (and [`None`]
@@ -11,6 +15,10 @@ pub(crate) trait ConflictResolver {
     /// After creating the learned nogood in [`ConflictResolver::resolve_conflict`], this method
     /// should put the solver in the "correct" state (e.g. by backtracking using
     /// [`ConflictAnalysisContext::backtrack`]).
+    #[allow(
+        clippy::result_unit_err,
+        reason = "an error only signals that the solver is inconsistent at the root"
+    )]
     fn process(
         &mut self,
         learned_nogood: Option<LearnedNogood>,
@@ -29,7 +37,7 @@ pub(crate) trait ConflictResolver {
 /// A [`LearnedNogood`] can be created using either [`LearnedNogood::new`] or, in the case of a
 /// unit learned nogood, using [`LearnedNogood::unit_learned_nogood`].
 #[derive(Clone, Debug, Default)]
-pub(crate) struct LearnedNogood {
+pub struct LearnedNogood {
     pub(crate) literals: Vec<Literal>,
     pub(crate) backjump_level: usize,
 }
@@ -6,8 +6,8 @@ mod conflict_resolver;
 mod no_learning;
 mod unique_implication_point;
 
-pub(crate) use all_decision_learning::*;
-pub(crate) use conflict_analysis_context::ConflictAnalysisContext;
-pub(crate) use conflict_resolver::*;
-pub(crate) use no_learning::*;
-pub(crate) use unique_implication_point::*;
+pub use all_decision_learning::*;
+pub use conflict_analysis_context::ConflictAnalysisContext;
+pub use conflict_resolver::*;
+pub use no_learning::*;
+pub use unique_implication_point::*;
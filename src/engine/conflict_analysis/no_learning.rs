@@ -3,7 +3,7 @@ use super::ConflictResolver;
 use super::LearnedNogood;
 
 #[derive(Debug, Copy, Clone)]
-pub(crate) struct NoLearning;
+pub struct NoLearning;
 
 impl ConflictResolver for NoLearning {
     fn resolve_conflict(
@@ -23,6 +23,7 @@ impl ConflictResolver for NoLearning {
 
         context.backtrack(context.get_decision_level() - 1);
         context.enqueue_propagated_literal(!last_decision);
+        context.brancher.on_branch_flipped(!last_decision);
         Ok(())
     }
 }
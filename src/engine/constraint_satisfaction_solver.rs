@@ -2,13 +2,20 @@
 //! using a Lazy Clause Generation approach.
 
 use std::cmp::min;
+use std::collections::VecDeque;
 use std::fmt::Debug;
 use std::fmt::Display;
 use std::fmt::Formatter;
 use std::num::NonZero;
+use std::sync::Arc;
+use std::time::Duration;
 use std::time::Instant;
 
 use clap::ValueEnum;
+use log::debug;
+use log::log_enabled;
+use log::warn;
+use log::Level;
 use rand::rngs::SmallRng;
 use rand::SeedableRng;
 
@@ -26,20 +33,32 @@ use super::sat::ClauseAllocator;
 use super::termination::TerminationCondition;
 use super::variables::IntegerVariable;
 use super::VariableNames;
+#[cfg(doc)]
+use crate::api::optimisation::lns::LnsPolisher;
+use crate::asserts;
+use crate::asserts::AssertLevel;
 use crate::basic_types::statistic_logging::statistic_logger::log_statistic;
 use crate::basic_types::CSPSolverExecutionFlag;
 use crate::basic_types::ClauseReference;
 use crate::basic_types::ConflictInfo;
 use crate::basic_types::ConstraintOperationError;
 use crate::basic_types::ConstraintReference;
+use crate::basic_types::HashMap;
+use crate::basic_types::HashSet;
 use crate::basic_types::Inconsistency;
 use crate::basic_types::KeyedVec;
+use crate::basic_types::ProblemSolution;
 use crate::basic_types::PropagationStatusOneStepCP;
+use crate::basic_types::Random;
 use crate::basic_types::SolutionReference;
+use crate::basic_types::StatisticAccumulator;
 use crate::basic_types::StoredConflictInfo;
+use crate::basic_types::ViolatedConstraint;
 use crate::branching::Brancher;
 use crate::branching::SelectionContext;
 use crate::engine::conflict_analysis::ConflictAnalysisContext;
+use crate::engine::cp::propagation::DomainChange;
+use crate::engine::cp::propagation::EnqueueDecision;
 use crate::engine::cp::propagation::PropagationContextMut;
 use crate::engine::cp::propagation::Propagator;
 use crate::engine::cp::propagation::PropagatorId;
@@ -55,6 +74,7 @@ use crate::engine::cp::WatchListCP;
 use crate::engine::cp::WatchListPropositional;
 use crate::engine::debug_helper::DebugDyn;
 use crate::engine::minimisation::Minimiser;
+use crate::engine::predicates::integer_predicate::IntegerPredicate;
 use crate::engine::predicates::predicate::Predicate;
 use crate::engine::sat::calculate_lbd;
 use crate::engine::sat::AssignmentsPropositional;
@@ -63,15 +83,34 @@ use crate::engine::sat::ExplanationClauseManager;
 use crate::engine::variables::DomainId;
 use crate::engine::variables::Literal;
 use crate::engine::variables::PropositionalVariable;
+use crate::engine::ConstraintTagTracker;
 use crate::engine::DebugHelper;
+use crate::engine::TagArtifacts;
+use crate::munchkin_assert_advanced;
 use crate::munchkin_assert_extreme;
 use crate::munchkin_assert_moderate;
 use crate::munchkin_assert_simple;
+use crate::predicate;
+use crate::proof::inference_labels;
 use crate::proof::Proof;
+use crate::proof::ProofStatistics;
+use crate::results::unsatisfiable::CoreExtractionOptions;
+#[cfg(doc)]
+use crate::results::unsatisfiable::UnsatisfiableUnderAssumptions;
+use crate::results::PropagationProbeResult;
+use crate::results::RootPropagationSummary;
+#[cfg(doc)]
+use crate::statistics::OptimisationIteration;
 use crate::termination::Indefinite;
+use crate::termination::SolverSizeSnapshot;
 #[cfg(doc)]
 use crate::Solver;
 
+/// The number of most recently invoked propagators and of the most recent integer trail entries
+/// which are reported when a potential propagation loop is detected; see
+/// [`SatisfactionSolverOptions::propagation_loop_threshold`].
+const PROPAGATION_LOOP_HISTORY: usize = 16;
+
 /// A solver which attempts to find a solution to a Constraint Satisfaction Problem (CSP) using
 /// a Lazy Clause Generation (LCG [\[1\]](https://people.eng.unimelb.edu.au/pstuckey/papers/cp09-lc.pdf))
 /// approach.
@@ -112,12 +151,30 @@ pub struct ConstraintSatisfactionSolver {
     /// Responsible for clausal propagation based on the two-watched scheme.
     /// Although technically just another propagator, we treat the clausal propagator in a special
     /// way due to efficiency and conflict analysis.
-    clausal_propagator: ClausalPropagator,
+    pub(crate) clausal_propagator: ClausalPropagator,
     /// The list of propagators. Propagators live here and are queried when events (domain changes)
     /// happen. The list is only traversed during synchronisation for now.
     pub(crate) cp_propagators: KeyedVec<PropagatorId, Box<dyn Propagator>>,
     /// Tags for propagators.
     pub(crate) propagator_tags: KeyedVec<PropagatorId, NonZero<u32>>,
+    /// Tracks which tagged constraints are responsible for the conflicts; see
+    /// [`ConstraintSatisfactionSolver::explain_unsat`].
+    tag_tracker: ConstraintTagTracker,
+    /// The effort spent by each of the propagators.
+    propagator_counters: KeyedVec<PropagatorId, PropagatorCounters>,
+    /// The artifacts created by the constraints of every tag, which are recorded while a proof is
+    /// logged; see [`ConstraintSatisfactionSolver::tag_artifacts`].
+    tag_artifacts: HashMap<NonZero<u32>, TagArtifacts>,
+    /// The tag of the constraint which is currently being posted, if any.
+    posting_tag: Option<NonZero<u32>>,
+    /// The propagators which were found to be entailed at the root by
+    /// [`ConstraintSatisfactionSolver::simplify_root`] in the order in which they were found,
+    /// together with the number of integer trail entries at that moment; these are marked as
+    /// inactive in the watch lists and in the propagator queue.
+    entailed_propagators: Vec<(PropagatorId, usize)>,
+    /// Whether the domains were compacted after the first root propagation of a solve call (see
+    /// [`ConstraintSatisfactionSolver::compact_domains`]).
+    has_compacted_domains: bool,
     /// Tracks information about all allocated clauses. All clause allocaton goes exclusively
     /// through the clause allocator. There are two notable exceptions:
     /// - Unit clauses are stored directly on the trail.
@@ -125,6 +182,12 @@ pub struct ConstraintSatisfactionSolver {
     pub(crate) clause_allocator: ClauseAllocator,
     /// Holds the assumptions when the solver is queried to solve under assumptions.
     assumptions: Vec<Literal>,
+    /// The activation literals of the removable constraints which are currently in the solver;
+    /// these are assumed (before any other assumptions) in every solve call.
+    activation_literals: Vec<Literal>,
+    /// The snapshots of the root state which can be rolled back to; a [`SnapshotId`] is an index
+    /// into this list.
+    snapshots: Vec<RootSnapshot>,
     /// Resolves and processes the conflict.
     conflict_resolver: Box<dyn ConflictResolver>,
     /// Tracks information related to the assignments of integer variables.
@@ -142,6 +205,10 @@ pub struct ConstraintSatisfactionSolver {
     propositional_trail_index: usize,
     /// Dictates the order in which propagators will be called to propagate.
     propagator_queue: PropagatorQueue,
+    /// The (at most [`PROPAGATION_LOOP_HISTORY`]) most recently invoked propagators during the
+    /// current call to [`ConstraintSatisfactionSolver::propagate_enqueued`]; these are reported
+    /// when a potential propagation loop is detected.
+    recently_invoked_propagators: VecDeque<PropagatorId>,
     /// Handles storing information about propagation reasons, which are used later to construct
     /// explanations during conflict analysis
     pub(crate) reason_store: ReasonStore,
@@ -165,10 +232,25 @@ pub struct ConstraintSatisfactionSolver {
     false_literal: Literal,
     /// A set of counters updated during the search.
     counters: Counters,
+    /// The number of conflicts after which the search is interrupted; this is used to limit the
+    /// effort spent on minimising a core (see [`CoreExtractionOptions`]) and on polishing a
+    /// solution (see [`LnsPolisher`]).
+    conflict_limit: Option<u64>,
+    /// The number of decisions after which the search is suspended; see
+    /// [`ConstraintSatisfactionSolver::solve_with_decision_budget`].
+    decision_limit: Option<u64>,
     /// Miscellaneous constant parameters used by the solver.
     internal_parameters: SatisfactionSolverOptions,
-    /// The names of the variables in the solver.
-    pub(crate) variable_names: VariableNames,
+    /// The random generator used when making decisions, seeded with
+    /// [`SatisfactionSolverOptions::random_seed`].
+    random_generator: SmallRng,
+    /// The names of the variables in the solver; these are shared with the [`Solution`]s of the
+    /// solver.
+    pub(crate) variable_names: Arc<VariableNames>,
+    /// The number of propositional variables which were created through
+    /// [`ConstraintSatisfactionSolver::create_new_propositional_variable`], as opposed to the
+    /// propositional variables which encode the domains of integer variables.
+    num_user_propositional_variables: usize,
 
     semantic_minimiser: SemanticMinimiser,
     recursive_minimiser: RecursiveMinimiser,
@@ -201,11 +283,19 @@ impl Default for ConstraintSatisfactionSolver {
 }
 
 /// Options for the [`Solver`] which determine how it behaves.
+///
+/// With the `serde` feature, the options can be (de)serialised to record the exact options of a
+/// run; the [`SatisfactionSolverOptions::proof`] is not serialised, and missing options are
+/// deserialised to their default value.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct SatisfactionSolverOptions {
-    /// A random generator which is used by the [`Solver`], passing it as an
-    /// argument allows seeding of the randomization.
-    pub random_generator: SmallRng,
+    /// The seed of the random generator which is used by the [`Solver`].
+    ///
+    /// This is the only source of randomness in the solver; two runs with the same seed and
+    /// options explore the same search tree.
+    pub random_seed: u64,
 
     /// The strategy to use when the solver reaches a conflicting state.
     pub conflict_resolver: ConflictResolutionStrategy,
@@ -220,11 +310,92 @@ pub struct SatisfactionSolverOptions {
     pub use_non_generic_propagation_explanation: bool,
 
     /// The proof log.
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub proof: Proof,
+
+    /// The number of bytes after which the solver stops logging steps to the [`Proof`]; the proof
+    /// is then marked as truncated and is not concluded, since it would be incomplete. Note that
+    /// the step which exceeds the limit is still logged. If it is [`None`], then the size of the
+    /// proof is not limited.
+    pub max_proof_bytes: Option<u64>,
+
+    /// Whether to measure the time spent in each propagator; since this requires querying the
+    /// clock for every call to a propagator, it is disabled by default.
+    pub time_propagators: bool,
+
+    /// The number of conflicts after which the solver restarts the search (i.e. it backtracks to
+    /// the root and notifies the [`Brancher`] using [`Brancher::on_restart`]); if it is [`None`],
+    /// then the solver never restarts.
+    ///
+    /// Note that restarts are not performed when using [`ConflictResolutionStrategy::NoLearning`],
+    /// since the search would then not be guaranteed to terminate.
+    pub restart_interval: Option<u64>,
+
+    /// Determines whether (and how much) probing is performed at the root before the search
+    /// starts; see [`ProbingOptions`].
+    pub root_probing: ProbingOptions,
+
+    /// Whether the optimisation procedures log the bounds on the objective (and some other
+    /// statistics) at the end of every iteration; see [`OptimisationIteration`].
+    pub verbose_optimisation: bool,
+
+    /// The level of the internal assertions which are checked while solving; see
+    /// [`AssertLevel`]. The expensive checks slow down the solver considerably, but can be used
+    /// to detect bugs (e.g. in newly implemented propagators) without recompiling the solver.
+    pub assert_level: AssertLevel,
+
+    /// The number of round trips between the clausal propagator and the CP propagators after
+    /// which propagation at a single decision level is reported as a potential propagation loop;
+    /// if it is [`None`], then propagation loops are not reported.
+    ///
+    /// A round trip consists of synchronising the propositional and integer trails, clausal
+    /// propagation and a single call to a CP propagator. When the threshold is exceeded, a warning
+    /// with the names of the most recently invoked propagators is logged (together with the last
+    /// entries on the integer trail at the debug level), and the loop is counted in
+    /// [`SearchStatistics::propagation_loops`] and [`PropagatorCounters::num_propagation_loops`].
+    /// Propagation itself is not interrupted.
+    pub propagation_loop_threshold: Option<u64>,
+
+    /// The number of propositional variables which the solver is expected to create (e.g. for
+    /// the literals of integer domains and for auxiliary literals of encodings); the space for
+    /// them is reserved when the solver is created, which avoids reallocating the per-variable
+    /// data structures while the model is built. This does not limit the number of variables.
+    pub expected_num_propositional_variables: usize,
+}
+
+/// The options for probing at the root, which is performed before the search starts.
+///
+/// For every integer variable `x` with bounds `[l, u]`, both `[x <= m]` and `[x >= m + 1]` are
+/// probed, where `m` is the median of the bounds. A probe temporarily assigns the literal and
+/// propagates; if this leads to a conflict, then the negation of the probed literal is asserted
+/// permanently at the root.
+///
+/// The budgets are counted over the lifetime of the solver, i.e. once a budget is exhausted, no
+/// probing is performed in subsequent calls to [`ConstraintSatisfactionSolver::solve`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProbingOptions {
+    /// Whether probing is performed.
+    pub enabled: bool,
+    /// The maximum number of probes which are performed.
+    pub max_probes: u64,
+    /// The maximum number of probes which are allowed to lead to a conflict.
+    pub max_conflicts: u64,
+}
+
+impl Default for ProbingOptions {
+    fn default() -> Self {
+        ProbingOptions {
+            enabled: false,
+            max_probes: 10_000,
+            max_conflicts: 1_000,
+        }
+    }
 }
 
 /// The strategy used for minimisation
 #[derive(Default, Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NogoodMinimisationStrategy {
     #[default]
     NoMinimisation,
@@ -252,6 +423,7 @@ impl Display for NogoodMinimisationStrategy {
 
 /// The strategy used for conflict resolution.
 #[derive(Default, Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ConflictResolutionStrategy {
     UniqueImplicationPoint,
     AllDecision,
@@ -286,12 +458,20 @@ impl ConflictResolutionStrategy {
 impl Default for SatisfactionSolverOptions {
     fn default() -> Self {
         SatisfactionSolverOptions {
-            random_generator: SmallRng::seed_from_u64(42),
+            random_seed: 42,
             conflict_resolver: ConflictResolutionStrategy::default(),
             minimisation_strategy: NogoodMinimisationStrategy::default(),
             use_non_generic_conflict_explanation: false,
             use_non_generic_propagation_explanation: false,
             proof: Proof::default(),
+            max_proof_bytes: None,
+            time_propagators: false,
+            restart_interval: None,
+            root_probing: ProbingOptions::default(),
+            verbose_optimisation: false,
+            assert_level: AssertLevel::default(),
+            propagation_loop_threshold: Some(100_000),
+            expected_num_propositional_variables: 0,
         }
     }
 }
@@ -315,8 +495,32 @@ impl ConstraintSatisfactionSolver {
 
             for (event, domain) in self.event_drain.drain(..) {
                 for propagator_var in self.watch_list_cp.get_affected_propagators(event, domain) {
-                    self.propagator_queue
-                        .enqueue_propagator(propagator_var.propagator, 0);
+                    let context = PropagationContext::new(
+                        &self.assignments_integer,
+                        &self.assignments_propositional,
+                        self.internal_parameters
+                            .use_non_generic_conflict_explanation,
+                        self.internal_parameters
+                            .use_non_generic_propagation_explanation,
+                    );
+                    let (old_bound, new_bound) =
+                        self.assignments_integer.get_bound_change(event, domain);
+                    let enqueue_decision = self.cp_propagators[propagator_var.propagator]
+                        .notify_change(
+                            context,
+                            DomainChange {
+                                local_id: propagator_var.variable,
+                                event,
+                                old_bound,
+                                new_bound,
+                            },
+                        );
+
+                    if enqueue_decision == EnqueueDecision::Enqueue {
+                        let priority = self.cp_propagators[propagator_var.propagator].priority();
+                        self.propagator_queue
+                            .enqueue_propagator(propagator_var.propagator, priority);
+                    }
                 }
             }
         }
@@ -332,8 +536,9 @@ impl ConstraintSatisfactionSolver {
                         .watch_list_propositional
                         .get_affected_propagators(event, affected_literal)
                     {
+                        let priority = self.cp_propagators[propagator_var.propagator].priority();
                         self.propagator_queue
-                            .enqueue_propagator(propagator_var.propagator, 0);
+                            .enqueue_propagator(propagator_var.propagator, priority);
                     }
                 }
             }
@@ -359,9 +564,23 @@ impl ConstraintSatisfactionSolver {
         }
     }
 
+    /// Returns the [`IntegerPredicate`]s which are attached to the provided `literal`.
+    pub fn get_predicates(&self, literal: Literal) -> impl Iterator<Item = IntegerPredicate> + '_ {
+        self.variable_literal_mappings
+            .get_predicates_for_literal(literal)
+    }
+
+    /// Returns the most specific [`IntegerPredicate`] attached to the provided `literal`; see
+    /// [`VariableLiteralMappings::get_canonical_predicate`].
+    pub fn get_canonical_predicate(&self, literal: Literal) -> Option<IntegerPredicate> {
+        self.variable_literal_mappings
+            .get_canonical_predicate(literal)
+    }
+
     /// This is a temporary accessor to help refactoring.
     pub fn get_solution_reference(&self) -> SolutionReference<'_> {
         SolutionReference::new(&self.assignments_propositional, &self.assignments_integer)
+            .with_variable_names(&self.variable_names)
     }
 
     #[allow(unused)]
@@ -378,17 +597,34 @@ impl ConstraintSatisfactionSolver {
 // methods that offer basic functionality
 impl ConstraintSatisfactionSolver {
     pub fn new(solver_options: SatisfactionSolverOptions) -> Self {
+        let conflict_resolver = solver_options.conflict_resolver.make();
+        Self::with_conflict_resolver(solver_options, conflict_resolver)
+    }
+
+    /// Creates a solver which resolves conflicts using the provided [`ConflictResolver`] rather
+    /// than the one corresponding to [`SatisfactionSolverOptions::conflict_resolver`].
+    pub(crate) fn with_conflict_resolver(
+        mut solver_options: SatisfactionSolverOptions,
+        conflict_resolver: Box<dyn ConflictResolver>,
+    ) -> Self {
+        solver_options
+            .proof
+            .set_max_bytes(solver_options.max_proof_bytes);
+        asserts::set_assert_level(solver_options.assert_level);
         let dummy_literal = Literal::new(PropositionalVariable::new(0), true);
 
         let mut csp_solver = ConstraintSatisfactionSolver {
             state: CSPSolverState::default(),
             assumptions: Vec::default(),
+            activation_literals: Vec::default(),
+            snapshots: Vec::default(),
             assignments_propositional: AssignmentsPropositional::default(),
             clause_allocator: ClauseAllocator::default(),
             assignments_integer: AssignmentsInteger::default(),
             watch_list_cp: WatchListCP::default(),
             watch_list_propositional: WatchListPropositional::default(),
             propagator_queue: PropagatorQueue::new(5),
+            recently_invoked_propagators: VecDeque::with_capacity(PROPAGATION_LOOP_HISTORY),
             reason_store: ReasonStore::default(),
             propositional_trail_index: 0,
             event_drain: vec![],
@@ -398,15 +634,25 @@ impl ConstraintSatisfactionSolver {
             explanation_clause_manager: ExplanationClauseManager::default(),
             true_literal: dummy_literal,
             false_literal: !dummy_literal,
-            conflict_resolver: solver_options.conflict_resolver.make(),
+            conflict_resolver,
             clausal_propagator: ClausalPropagator::default(),
             cp_propagators: KeyedVec::default(),
             counters: Counters::default(),
+            conflict_limit: None,
+            decision_limit: None,
+            random_generator: SmallRng::seed_from_u64(solver_options.random_seed),
             internal_parameters: solver_options,
-            variable_names: VariableNames::default(),
+            variable_names: Arc::default(),
+            num_user_propositional_variables: 0,
             semantic_minimiser: Default::default(),
             recursive_minimiser: Default::default(),
             propagator_tags: KeyedVec::default(),
+            tag_tracker: ConstraintTagTracker::default(),
+            propagator_counters: KeyedVec::default(),
+            tag_artifacts: HashMap::default(),
+            posting_tag: None,
+            entailed_propagators: vec![],
+            has_compacted_domains: false,
         };
 
         // we introduce a dummy variable set to true at the root level
@@ -414,6 +660,8 @@ impl ConstraintSatisfactionSolver {
         //  e.g., this makes writing propagator explanations easier for corner cases
         let root_variable = csp_solver.create_new_propositional_variable(Some("true".to_owned()));
         let true_literal = Literal::new(root_variable, true);
+        // The dummy variable is internal to the solver, so it is not counted as a user variable
+        csp_solver.num_user_propositional_variables = 0;
 
         csp_solver.assignments_propositional.true_literal = true_literal;
         csp_solver.assignments_propositional.false_literal = !true_literal;
@@ -421,6 +669,12 @@ impl ConstraintSatisfactionSolver {
         csp_solver.true_literal = true_literal;
         csp_solver.false_literal = !true_literal;
 
+        csp_solver.reserve_propositional_variables(
+            csp_solver
+                .internal_parameters
+                .expected_num_propositional_variables,
+        );
+
         let result = csp_solver.add_clause([true_literal]);
         munchkin_assert_simple!(result.is_ok());
 
@@ -446,9 +700,20 @@ impl ConstraintSatisfactionSolver {
             return CSPSolverExecutionFlag::Infeasible;
         }
 
+        // Starting a new search abandons a suspended search
+        self.restore_state_at_root(brancher);
+
         let start_time = Instant::now();
 
         self.initialise(assumptions);
+        if self.internal_parameters.root_probing.enabled {
+            self.probe_at_root(termination, brancher);
+        }
+        if !self.has_compacted_domains {
+            self.propagate_enqueued(termination);
+            self.compact_domains();
+            self.has_compacted_domains = true;
+        }
         let result = self.solve_internal(termination, brancher);
 
         self.counters.time_spent_in_solver += start_time.elapsed().as_millis() as u64;
@@ -456,8 +721,206 @@ impl ConstraintSatisfactionSolver {
         result
     }
 
+    /// Solves under the provided `assumptions`, but interrupts the search once `conflict_budget`
+    /// conflicts have been encountered; in that case, [`CSPSolverExecutionFlag::Timeout`] is
+    /// returned.
+    pub(crate) fn solve_under_assumptions_with_conflict_budget(
+        &mut self,
+        assumptions: &[Literal],
+        conflict_budget: u64,
+        termination: &mut impl TerminationCondition,
+        brancher: &mut impl Brancher,
+    ) -> CSPSolverExecutionFlag {
+        self.conflict_limit = Some(self.counters.num_conflicts + conflict_budget);
+        let result = self.solve_under_assumptions(assumptions, termination, brancher);
+        self.conflict_limit = None;
+
+        result
+    }
+
+    /// Solves the problem, but suspends the search once `decision_budget` decisions have been
+    /// made (or once the `termination` condition triggers); in that case,
+    /// [`CSPSolverExecutionFlag::Timeout`] is returned and the solver is *not* restored to the
+    /// root.
+    ///
+    /// If the solver is suspended when this method is called, then the suspended search is
+    /// continued from the exact state in which it was suspended (i.e. with the same trail,
+    /// learned clauses and restart schedule); otherwise, a new search is started. Constraints
+    /// cannot be added while the search is suspended; the suspension ends once a result is found
+    /// or the solver is restored to the root.
+    pub(crate) fn solve_with_decision_budget(
+        &mut self,
+        decision_budget: u64,
+        termination: &mut impl TerminationCondition,
+        brancher: &mut impl Brancher,
+    ) -> CSPSolverExecutionFlag {
+        self.decision_limit = Some(self.counters.num_decisions + decision_budget);
+        let result = if self.state.is_suspended() {
+            let start_time = Instant::now();
+            self.state.declare_solving();
+            let result = self.solve_internal(termination, brancher);
+            self.counters.time_spent_in_solver += start_time.elapsed().as_millis() as u64;
+            result
+        } else {
+            self.solve(termination, brancher)
+        };
+        self.decision_limit = None;
+
+        if result == CSPSolverExecutionFlag::Timeout {
+            self.state.declare_suspended();
+        }
+
+        result
+    }
+
+    /// Returns whether a search started by
+    /// [`ConstraintSatisfactionSolver::solve_with_decision_budget`] is suspended.
+    pub(crate) fn is_suspended(&self) -> bool {
+        self.state.is_suspended()
+    }
+
+    /// Returns the random generator of the solver, which is seeded with
+    /// [`SatisfactionSolverOptions::random_seed`].
+    pub(crate) fn random_generator(&mut self) -> &mut dyn Random {
+        &mut self.random_generator
+    }
+
     pub fn log_statistics(&self) {
-        self.counters.log_statistics()
+        self.counters.log_statistics();
+
+        let summary = self.model_summary();
+        log_statistic("numberOfIntegerVariables", summary.num_integer_variables);
+        log_statistic(
+            "numberOfUserPropositionalVariables",
+            summary.num_user_propositional_variables,
+        );
+        log_statistic(
+            "numberOfEncodingPropositionalVariables",
+            summary.num_encoding_propositional_variables,
+        );
+        log_statistic("numberOfPropagators", summary.num_propagators());
+        log_statistic("numberOfOriginalClauses", summary.num_original_clauses);
+        log_statistic("numberOfLearnedClauses", summary.num_learned_clauses);
+
+        if let Some(statistics) = self.proof_statistics() {
+            log_statistic(
+                "proofSteps",
+                statistics.inference_steps + statistics.nogood_steps,
+            );
+            log_statistic("proofBytes", statistics.bytes_written);
+            log_statistic("proofTruncated", statistics.is_truncated);
+        }
+
+        for (index, (name, counters)) in self.propagator_statistics().into_iter().enumerate() {
+            log_statistic(format!("propagator{index}Name"), name);
+            log_statistic(
+                format!("propagator{index}NumberOfInvocations"),
+                counters.num_invocations,
+            );
+            log_statistic(
+                format!("propagator{index}NumberOfDomainReductions"),
+                counters.num_domain_reductions,
+            );
+            log_statistic(
+                format!("propagator{index}NumberOfConflicts"),
+                counters.num_conflicts,
+            );
+            log_statistic(
+                format!("propagator{index}TimeSpentInMicroseconds"),
+                counters.time_spent.as_micros(),
+            );
+        }
+    }
+
+    /// Returns a snapshot of the search effort counters at the time of calling.
+    pub fn statistics(&self) -> SearchStatistics {
+        self.counters.snapshot()
+    }
+
+    /// Returns the options with which the solver was created.
+    pub(crate) fn options(&self) -> &SatisfactionSolverOptions {
+        &self.internal_parameters
+    }
+
+    /// Returns whether the optimisation procedures should log every iteration; see
+    /// [`SatisfactionSolverOptions::verbose_optimisation`].
+    pub(crate) fn is_verbose_optimisation(&self) -> bool {
+        self.internal_parameters.verbose_optimisation
+    }
+
+    /// Returns the [`ProofStatistics`] of the proof, or [`None`] if no proof is being logged.
+    pub fn proof_statistics(&self) -> Option<ProofStatistics> {
+        self.internal_parameters.proof.statistics()
+    }
+
+    /// Returns the name and [`PropagatorCounters`] of every propagator, sorted by the time spent
+    /// in the propagator in descending order.
+    pub fn propagator_statistics(&self) -> Vec<(&str, PropagatorCounters)> {
+        let mut statistics = self
+            .cp_propagators
+            .iter()
+            .zip(self.propagator_counters.iter())
+            .map(|(propagator, &counters)| (propagator.name(), counters))
+            .collect::<Vec<_>>();
+        statistics.sort_by_key(|(_, counters)| std::cmp::Reverse(counters.time_spent));
+        statistics
+    }
+
+    /// Returns a summary of the size of the problem which is currently in the solver.
+    pub fn model_summary(&self) -> ModelSummary {
+        let num_propositional_variables =
+            self.assignments_propositional.num_propositional_variables() as usize;
+
+        let mut propagators = Vec::<(String, usize)>::new();
+        for propagator in self.cp_propagators.iter() {
+            match propagators
+                .iter_mut()
+                .find(|(name, _)| name == propagator.name())
+            {
+                Some((_, count)) => *count += 1,
+                None => propagators.push((propagator.name().to_owned(), 1)),
+            }
+        }
+        propagators.sort();
+
+        // The binary clauses are stored as two implications each
+        let num_binary_clauses = self
+            .clausal_propagator
+            .binary_implications
+            .iter()
+            .map(Vec::len)
+            .sum::<usize>()
+            / 2;
+        let (num_learned_clauses, num_allocated_original_clauses) = self
+            .clausal_propagator
+            .permanent_clauses
+            .iter()
+            .map(|&reference| self.clause_allocator.get_clause(reference))
+            .filter(|clause| !clause.is_deleted())
+            .fold((0, 0), |(learned, original), clause| {
+                if clause.is_learned() {
+                    (learned + 1, original)
+                } else {
+                    (learned, original + 1)
+                }
+            });
+
+        ModelSummary {
+            num_integer_variables: self.assignments_integer.num_domains() as usize,
+            num_user_propositional_variables: self.num_user_propositional_variables,
+            // The dummy variable which is always true is part of neither group
+            num_encoding_propositional_variables: num_propositional_variables
+                - self.num_user_propositional_variables
+                - 1,
+            propagators,
+            num_original_clauses: num_binary_clauses + num_allocated_original_clauses,
+            num_learned_clauses,
+        }
+    }
+
+    /// Returns the statistic of the number of literals removed by core minimisation.
+    pub fn get_minimisation_statistics(&mut self) -> &mut StatisticAccumulator {
+        &mut self.counters.core_minimisation_removed
     }
 
     /// Create a new integer variable. Its domain will have the given lower and upper bounds.
@@ -467,6 +930,14 @@ impl ConstraintSatisfactionSolver {
         upper_bound: i32,
         name: Option<String>,
     ) -> DomainId {
+        assert!(
+            lower_bound <= upper_bound,
+            "cannot create a variable with an empty domain [{lower_bound}, {upper_bound}]"
+        );
+        assert!(
+            upper_bound.checked_sub(lower_bound).is_some(),
+            "the width of the domain [{lower_bound}, {upper_bound}] does not fit in an i32"
+        );
         assert!(
             !self.state.is_inconsistent(),
             "Variables cannot be created in an inconsistent state"
@@ -484,55 +955,76 @@ impl ConstraintSatisfactionSolver {
         );
 
         if let Some(name) = name {
-            self.variable_names.add_integer(domain, name);
+            Arc::make_mut(&mut self.variable_names).add_integer(domain, name);
         }
 
         domain
     }
 
-    /// Creates an integer variable with a domain containing only the values in `values`
+    /// Creates an integer variable with a domain containing only the values in `values`. Only the
+    /// values in the domain get a propositional representation, which means that the size of the
+    /// representation does not depend on the distance between the values.
     pub fn create_new_integer_variable_sparse(
         &mut self,
-        mut values: Vec<i32>,
+        values: Vec<i32>,
         name: Option<String>,
     ) -> DomainId {
         assert!(
             !values.is_empty(),
             "cannot create a variable with an empty domain"
         );
+        assert!(
+            !self.state.is_inconsistent(),
+            "Variables cannot be created in an inconsistent state"
+        );
 
-        values.sort();
-        values.dedup();
+        let domain = self.variable_literal_mappings.create_new_sparse_domain(
+            values,
+            &mut self.assignments_integer,
+            &mut self.watch_list_cp,
+            &mut self.watch_list_propositional,
+            &mut self.clausal_propagator,
+            &mut self.assignments_propositional,
+            &mut self.clause_allocator,
+        );
 
-        let lower_bound = values[0];
-        let upper_bound = values[values.len() - 1];
+        if let Some(name) = name {
+            Arc::make_mut(&mut self.variable_names).add_integer(domain, name);
+        }
 
-        let domain_id = self.create_new_integer_variable(lower_bound, upper_bound, name);
+        domain
+    }
 
-        let mut next_idx = 0;
-        for value in lower_bound..=upper_bound {
-            if value == values[next_idx] {
-                next_idx += 1;
-            } else {
-                self.assignments_integer
-                    .remove_initial_value_from_domain(domain_id, value, None)
-                    .expect("the domain should not be empty");
-                self.assignments_propositional.enqueue_decision_literal(
-                    self.variable_literal_mappings.get_inequality_literal(
-                        domain_id,
-                        value,
-                        &self.assignments_propositional,
-                        &self.assignments_integer,
-                    ),
-                )
-            }
-        }
+    /// Creates a 0-1 integer variable which is equivalent to the provided `literal`; i.e. the
+    /// variable is 1 if and only if `literal` is true. The variable is represented by `literal`
+    /// itself, so no new propositional variables or channelling clauses are created.
+    pub fn create_new_integer_variable_for_literal(&mut self, literal: Literal) -> DomainId {
+        assert!(
+            !self.state.is_inconsistent(),
+            "Variables cannot be created in an inconsistent state"
+        );
         munchkin_assert_simple!(
-            next_idx == values.len(),
-            "Expected all values to have been processed"
+            self.assignments_propositional.is_at_the_root_level(),
+            "Variables can only be created at the root"
+        );
+
+        let domain = self.variable_literal_mappings.create_new_literal_domain(
+            literal,
+            &mut self.assignments_integer,
+            &mut self.watch_list_cp,
+            &self.assignments_propositional,
         );
 
-        domain_id
+        if self.assignments_propositional.is_literal_assigned(literal) {
+            let value = self
+                .assignments_propositional
+                .is_literal_assigned_true(literal) as i32;
+            self.assignments_integer
+                .apply_integer_predicate(predicate![domain == value].try_into().unwrap(), None)
+                .expect("the domain of a new variable contains both 0 and 1");
+        }
+
+        domain
     }
 
     /// Returns an infinite iterator of positive literals of new variables. The new variables will
@@ -544,6 +1036,49 @@ impl ConstraintSatisfactionSolver {
             .map(|var| Literal::new(var, true))
     }
 
+    /// Returns the positive literals of `num_literals` new variables with consecutive indices. The
+    /// new variables will be unnamed.
+    ///
+    /// Unlike [`ConstraintSatisfactionSolver::new_literals`], the space for the variables is
+    /// reserved at once, rather than growing the data structures for every variable separately.
+    pub fn new_literal_batch(&mut self, num_literals: usize) -> Vec<Literal> {
+        let literals: Vec<Literal> = self
+            .variable_literal_mappings
+            .create_new_propositional_variables(
+                num_literals,
+                &mut self.watch_list_propositional,
+                &mut self.clausal_propagator,
+                &mut self.assignments_propositional,
+            )
+            .map(|variable| Literal::new(variable, true))
+            .collect();
+
+        self.num_user_propositional_variables += num_literals;
+
+        literals
+    }
+
+    /// Reserves space for at least `num_variables` more propositional variables in the data
+    /// structures which store information per variable; see
+    /// [`SatisfactionSolverOptions::expected_num_propositional_variables`].
+    fn reserve_propositional_variables(&mut self, num_variables: usize) {
+        self.assignments_propositional.reserve(num_variables);
+        self.clausal_propagator.reserve(num_variables);
+        self.watch_list_propositional.reserve(num_variables);
+        self.variable_literal_mappings.reserve(num_variables);
+    }
+
+    /// Returns the number of propositional variables which can be stored without reallocating any
+    /// of the per-variable data structures.
+    #[cfg(test)]
+    pub(crate) fn propositional_variable_capacity(&self) -> usize {
+        self.assignments_propositional
+            .capacity()
+            .min(self.clausal_propagator.capacity())
+            .min(self.watch_list_propositional.capacity())
+            .min(self.variable_literal_mappings.capacity())
+    }
+
     pub fn create_new_propositional_variable(
         &mut self,
         name: Option<String>,
@@ -557,9 +1092,11 @@ impl ConstraintSatisfactionSolver {
             );
 
         if let Some(name) = name {
-            self.variable_names.add_propositional(variable, name);
+            Arc::make_mut(&mut self.variable_names).add_propositional(variable, name);
         }
 
+        self.num_user_propositional_variables += 1;
+
         variable
     }
 
@@ -583,6 +1120,18 @@ impl ConstraintSatisfactionSolver {
         variable.upper_bound(&self.assignments_integer)
     }
 
+    /// Get the lower bound for the given variable at the root, regardless of the current decision
+    /// level.
+    pub fn get_root_lower_bound(&self, variable: &impl IntegerVariable) -> i32 {
+        variable.lower_bound_at_root(&self.assignments_integer)
+    }
+
+    /// Get the upper bound for the given variable at the root, regardless of the current decision
+    /// level.
+    pub fn get_root_upper_bound(&self, variable: &impl IntegerVariable) -> i32 {
+        variable.upper_bound_at_root(&self.assignments_integer)
+    }
+
     /// Determine whether `value` is in the domain of `variable`.
     pub fn integer_variable_contains(&self, variable: &impl IntegerVariable, value: i32) -> bool {
         variable.contains(&self.assignments_integer, value)
@@ -602,79 +1151,465 @@ impl ConstraintSatisfactionSolver {
 
     /// Get the value of the given literal, which could be unassigned.
     pub fn get_literal_value(&self, literal: Literal) -> Option<bool> {
-        if self.assignments_propositional.is_literal_assigned(literal) {
-            Some(
-                self.assignments_propositional
-                    .is_literal_assigned_true(literal),
-            )
-        } else {
-            None
-        }
+        Predicate::from(literal)
+            .evaluate(&self.assignments_integer, &self.assignments_propositional)
     }
 
     pub(crate) fn get_propositional_assignments(&self) -> &AssignmentsPropositional {
         &self.assignments_propositional
     }
 
+    pub(crate) fn get_integer_assignments(&self) -> &AssignmentsInteger {
+        &self.assignments_integer
+    }
+
     pub fn restore_state_at_root(&mut self, brancher: &mut impl Brancher) {
         if !self.assignments_propositional.is_at_the_root_level() {
             self.backtrack(0, brancher);
             self.state.declare_ready();
+        } else if self.state.is_suspended() {
+            self.state.declare_ready();
         }
     }
 
-    /// Conclude the proof with the given bound on the objective variable.
-    pub(crate) fn conclude_proof_optimal(&mut self, bound: Literal) {
-        self.internal_parameters.proof.conclude_proof_optimal(
-            bound,
-            &self.variable_names,
-            &self.variable_literal_mappings,
-        );
-    }
+    /// Extracts a core in terms of the assumptions after the solver has been found to be
+    /// infeasible under them; the core is a clause consisting of negated assumptions.
+    ///
+    /// See [`UnsatisfiableUnderAssumptions::extract_core_options`] for more information.
+    pub(crate) fn extract_clausal_core(
+        &mut self,
+        options: CoreExtractionOptions,
+        brancher: &mut impl Brancher,
+    ) -> Vec<Literal> {
+        let mut core = self.compute_clausal_core(brancher);
+        // The activation literals of removable constraints are not part of the core
+        core.retain(|literal| !self.activation_literals.contains(&!*literal));
+
+        if options.deduplicate {
+            let mut seen: HashSet<Literal> = HashSet::default();
+            core.retain(|&literal| seen.insert(literal));
+        }
 
-    /// Conclude the proof with the unsat conclusion.
-    pub(crate) fn conclude_proof_unsat(&mut self) {
-        self.internal_parameters
-            .proof
-            .conclude_proof_unsat(&self.variable_names, &self.variable_literal_mappings);
+        if options.minimise {
+            core = self.minimise_clausal_core(core, options.minimisation_conflict_budget, brancher);
+        }
+
+        core
     }
 
-    fn synchronise_propositional_trail_based_on_integer_trail(&mut self) -> Option<ConflictInfo> {
-        // for each entry on the integer trail, we now add the equivalent propositional
-        // representation on the propositional trail  note that only one literal per
-        // predicate will be stored      since the clausal propagator will propagate other
-        // literals to ensure that the meaning of the literal is respected          e.g.,
-        // placing [x >= 5] will prompt the clausal propagator to set [x >= 4] [x >= 3] ... [x >= 1]
-        // to true
-        for cp_trail_pos in
-            self.cp_trail_synced_position..self.assignments_integer.num_trail_entries()
+    /// Computes the core by going backwards over the trail from the violated assumption and
+    /// collecting the assumptions (i.e. the decisions) which are responsible for falsifying it.
+    fn compute_clausal_core(&mut self, brancher: &mut impl Brancher) -> Vec<Literal> {
+        let violated_assumption = self
+            .state
+            .get_violated_assumption()
+            .expect("a core can only be extracted when infeasible under assumptions");
+
+        // If the assumption is falsified at the root, then it is a core by itself
+        if self
+            .assignments_propositional
+            .is_literal_root_assignment(violated_assumption)
         {
-            let entry = self.assignments_integer.get_trail_entry(cp_trail_pos);
+            return vec![!violated_assumption];
+        }
 
-            // It could be the case that the reason is `None`
-            // due to a SAT propagation being put on the trail during
-            // `synchronise_integer_trail_based_on_propositional_trail` In that case we
-            // do not synchronise since we assume that the SAT trail is already aware of the
-            // information
-            if let Some(reason_ref) = entry.reason {
-                let literal = self.variable_literal_mappings.get_literal(
-                    entry.predicate,
-                    &self.assignments_propositional,
-                    &self.assignments_integer,
-                );
+        let mut context = ConflictAnalysisContext {
+            assumptions: &self.assumptions,
+            clausal_propagator: &mut self.clausal_propagator,
+            variable_literal_mappings: &self.variable_literal_mappings,
+            assignments_integer: &mut self.assignments_integer,
+            assignments_propositional: &mut self.assignments_propositional,
+            internal_parameters: &mut self.internal_parameters,
+            solver_state: &mut self.state,
+            brancher,
+            clause_allocator: &mut self.clause_allocator,
+            explanation_clause_manager: &mut self.explanation_clause_manager,
+            reason_store: &mut self.reason_store,
+            counters: &mut self.counters,
+            propositional_trail_index: &mut self.propositional_trail_index,
+            propagator_queue: &mut self.propagator_queue,
+            watch_list_cp: &mut self.watch_list_cp,
+            propagators: &mut self.cp_propagators,
+            sat_trail_synced_position: &mut self.sat_trail_synced_position,
+            cp_trail_synced_position: &mut self.cp_trail_synced_position,
+        };
 
-                let constraint_reference = ConstraintReference::create_reason_reference(reason_ref);
+        let mut core = vec![!violated_assumption];
+        let mut is_marked: HashSet<PropositionalVariable> = HashSet::default();
+        let _ = is_marked.insert(violated_assumption.get_propositional_variable());
 
-                let conflict_info = self
-                    .assignments_propositional
-                    .enqueue_propagated_literal(literal, constraint_reference);
+        for trail_index in (0..context.get_num_trail_entries()).rev() {
+            let literal = context.get_trail_entry(trail_index);
+            if !is_marked.contains(&literal.get_propositional_variable()) {
+                continue;
+            }
 
-                if conflict_info.is_some() {
-                    self.cp_trail_synced_position = cp_trail_pos + 1;
-                    return conflict_info;
+            // Before all assumptions have been set, the only decisions are the assumptions
+            if context.is_literal_decision(literal) {
+                core.push(!literal);
+                continue;
+            }
+
+            for reason_literal in context.get_reason(literal).literals {
+                if !context.is_root_level_assignment(reason_literal) {
+                    let _ = is_marked.insert(reason_literal.get_propositional_variable());
                 }
+            }
+        }
 
-                // It could occur that one of these propagations caused a conflict in which case the
+        core
+    }
+
+    /// Deletion-based minimisation of the core; a literal is removed from the core if the solver
+    /// proves that the negations of the remaining literals are still unsatisfiable. If this is the
+    /// case, then the core is further reduced to the core of that call.
+    fn minimise_clausal_core(
+        &mut self,
+        mut core: Vec<Literal>,
+        conflict_budget: Option<u64>,
+        brancher: &mut impl Brancher,
+    ) -> Vec<Literal> {
+        self.restore_state_at_root(brancher);
+        self.conflict_limit = conflict_budget.map(|budget| self.counters.num_conflicts + budget);
+        let num_literals_before = core.len();
+
+        let mut index = 0;
+        while index < core.len() {
+            let assumptions = core
+                .iter()
+                .enumerate()
+                .filter(|&(other_index, _)| other_index != index)
+                .map(|(_, &literal)| !literal)
+                .collect::<Vec<_>>();
+
+            match self.solve_under_assumptions(&assumptions, &mut Indefinite, brancher) {
+                CSPSolverExecutionFlag::Infeasible if self.state.is_infeasible() => {
+                    // The problem is infeasible without any of the assumptions
+                    core.clear();
+                    break;
+                }
+                CSPSolverExecutionFlag::Infeasible => {
+                    // Every literal before `index` is necessary, so they are part of any core
+                    // which is a subset of the current one
+                    let smaller_core = self.compute_clausal_core(brancher);
+                    core.retain(|literal| smaller_core.contains(literal));
+                    self.restore_state_at_root(brancher);
+                }
+                CSPSolverExecutionFlag::Feasible => {
+                    self.restore_state_at_root(brancher);
+                    index += 1;
+                }
+                CSPSolverExecutionFlag::Timeout => {
+                    self.restore_state_at_root(brancher);
+                    break;
+                }
+            }
+        }
+
+        self.conflict_limit = None;
+        self.counters
+            .core_minimisation_removed
+            .add_term((num_literals_before - core.len()) as u64);
+        core
+    }
+
+    /// Solves the problem while keeping track of which tagged constraints are responsible for the
+    /// conflicts; if the problem is infeasible, then the tags of the constraints which participate
+    /// in the final conflict are returned (sorted and without duplicates).
+    ///
+    /// See [`Solver::explain_unsat`] for more information.
+    pub(crate) fn explain_unsat(
+        &mut self,
+        termination: &mut impl TerminationCondition,
+        brancher: &mut impl Brancher,
+    ) -> Option<Vec<NonZero<u32>>> {
+        // If a constraint led to a conflict when it was added, then the root conflict has already
+        // been recorded
+        if !self.state.is_inconsistent() {
+            self.tag_tracker.set_enabled(true);
+            let result = self.solve(termination, brancher);
+
+            // The activation literals of removable constraints are assumed, so the conflict can
+            // also be in terms of one of them
+            if let Some(violated_assumption) = self.state.get_violated_assumption() {
+                let conflict_tags =
+                    self.compute_implication_tags(vec![!violated_assumption], HashSet::default());
+                self.tag_tracker.record_root_conflict(conflict_tags);
+            }
+
+            self.tag_tracker.set_enabled(false);
+            self.restore_state_at_root(brancher);
+
+            if result != CSPSolverExecutionFlag::Infeasible {
+                return None;
+            }
+        }
+
+        let mut tags = self
+            .tag_tracker
+            .get_root_conflict()
+            .map(|tags| tags.iter().copied().collect::<Vec<_>>())
+            .unwrap_or_default();
+        tags.sort();
+        Some(tags)
+    }
+
+    /// Returns the tags of the constraints which are responsible for the current conflict.
+    fn compute_conflict_tags(&mut self) -> HashSet<NonZero<u32>> {
+        let mut tags = HashSet::default();
+        let conflict_literals = self.get_conflict_literals(&mut tags);
+
+        self.compute_implication_tags(conflict_literals, tags)
+    }
+
+    /// Returns the (true) literals which together are inconsistent according to the current
+    /// conflict, and adds the tag of the constraint which reported the conflict to `tags`.
+    fn get_conflict_literals(&mut self, tags: &mut HashSet<NonZero<u32>>) -> Vec<Literal> {
+        match self.state.get_conflict_info().clone() {
+            StoredConflictInfo::VirtualBinaryClause { lit1, lit2 } => vec![!lit1, !lit2],
+            StoredConflictInfo::Propagation { literal, reference } => {
+                // The literal is false, while `reference` propagates it
+                let mut conflict_literals = self.get_antecedents(literal, reference, tags);
+                conflict_literals.push(!literal);
+                conflict_literals
+            }
+            StoredConflictInfo::Explanation {
+                conjunction,
+                propagator,
+            } => {
+                let _ = tags.insert(self.propagator_tags[propagator]);
+                conjunction
+                    .iter()
+                    .map(|&predicate| self.get_literal(predicate))
+                    .collect()
+            }
+        }
+    }
+
+    /// Adds the tags of the constraints which (transitively) imply the provided literals to
+    /// `tags` by going backwards over the trail, including the root-level assignments.
+    fn compute_implication_tags(
+        &mut self,
+        literals: Vec<Literal>,
+        mut tags: HashSet<NonZero<u32>>,
+    ) -> HashSet<NonZero<u32>> {
+        let mut is_marked: HashSet<PropositionalVariable> = literals
+            .iter()
+            .map(|literal| literal.get_propositional_variable())
+            .collect();
+
+        for trail_index in (0..self.assignments_propositional.num_trail_entries()).rev() {
+            let literal = self.assignments_propositional.get_trail_entry(trail_index);
+            if !is_marked.contains(&literal.get_propositional_variable()) {
+                continue;
+            }
+
+            let reference = self
+                .assignments_propositional
+                .get_literal_reason_constraint(literal);
+            for antecedent in self.get_antecedents(literal, reference, &mut tags) {
+                let _ = is_marked.insert(antecedent.get_propositional_variable());
+            }
+        }
+
+        tags
+    }
+
+    /// Returns the (true) literals which imply `literal` through the constraint identified by
+    /// `reference`, and adds the tag of that constraint to `tags` if it is known.
+    fn get_antecedents(
+        &mut self,
+        literal: Literal,
+        reference: ConstraintReference,
+        tags: &mut HashSet<NonZero<u32>>,
+    ) -> Vec<Literal> {
+        if let Some(derived_tags) = self.tag_tracker.get_derived_tags(literal, reference) {
+            tags.extend(derived_tags.iter().copied());
+        }
+
+        if reference.is_null() || reference.is_non_reason() {
+            // Decisions, unit clauses and literals derived without learning have no antecedents
+            vec![]
+        } else if reference.is_virtual_binary_clause() {
+            vec![!reference
+                .as_clause_reference()
+                .get_virtual_binary_clause_literal()]
+        } else if reference.is_clause() {
+            self.clause_allocator[reference.as_clause_reference()]
+                .get_literal_slice()
+                .iter()
+                .filter(|&&clause_literal| clause_literal != literal)
+                .map(|&clause_literal| !clause_literal)
+                .collect()
+        } else {
+            let reason_ref = reference.get_reason_ref();
+            let _ = tags.insert(self.propagator_tags[self.reason_store.get_propagator(reason_ref)]);
+
+            let propagation_context = PropagationContext::new(
+                &self.assignments_integer,
+                &self.assignments_propositional,
+                self.internal_parameters
+                    .use_non_generic_conflict_explanation,
+                self.internal_parameters
+                    .use_non_generic_propagation_explanation,
+            );
+            let reason = self
+                .reason_store
+                .get_or_compute(reason_ref, &propagation_context)
+                .expect("reason reference should not be stale")
+                .iter()
+                .copied()
+                .collect::<Vec<_>>();
+
+            reason
+                .into_iter()
+                .map(|predicate| self.get_literal(predicate))
+                .collect()
+        }
+    }
+
+    /// Checks whether the provided solution satisfies all of the propagators and the permanent
+    /// clauses of the solver; the first constraint which is violated is returned.
+    ///
+    /// See [`Solver::verify_solution`] for more information.
+    pub(crate) fn verify_solution(
+        &self,
+        solution: &impl ProblemSolution,
+    ) -> Result<(), ViolatedConstraint> {
+        // The propagators are checked on a copy of the assignments, such that any (erroneous)
+        // changes which they make do not affect the solution
+        let mut assignments_integer = solution.assignments_integer().clone();
+        let mut assignments_propositional = solution.assignments_propositional().clone();
+        let mut reason_store = ReasonStore::default();
+
+        for (index, propagator) in self.cp_propagators.iter().enumerate() {
+            let propagator_id = PropagatorId(index as u32);
+            let context = PropagationContextMut::new(
+                &mut assignments_integer,
+                &mut reason_store,
+                &mut assignments_propositional,
+                propagator_id,
+                self.internal_parameters
+                    .use_non_generic_conflict_explanation,
+                self.internal_parameters
+                    .use_non_generic_propagation_explanation,
+            );
+
+            if !propagator.check(context) {
+                return Err(ViolatedConstraint::Propagator {
+                    name: propagator.name().to_owned(),
+                    tag: self.propagator_tags[propagator_id],
+                });
+            }
+        }
+
+        // Auxiliary literals are not necessarily assigned in a solution, so a clause is only
+        // violated if all of its literals are assigned false
+        let assignments = solution.assignments_propositional();
+        let is_violated = |literals: &[Literal]| {
+            literals
+                .iter()
+                .all(|&literal| assignments.is_literal_assigned_false(literal))
+        };
+
+        // The learned clauses can depend on the root of a later optimisation iteration (e.g. on a
+        // strengthened bound on the objective), so only the original clauses are checked
+        for &clause_reference in self.clausal_propagator.permanent_clauses.iter() {
+            let clause = &self.clause_allocator[clause_reference];
+            if clause.is_learned() {
+                continue;
+            }
+
+            let literals = clause.get_literal_slice();
+            if is_violated(literals) {
+                return Err(ViolatedConstraint::Clause {
+                    literals: literals.to_vec(),
+                });
+            }
+        }
+
+        for (literal_code, implied_literals) in self
+            .clausal_propagator
+            .binary_implications
+            .iter()
+            .enumerate()
+        {
+            let lhs = Literal::u32_to_literal(literal_code as u32);
+            for &rhs in implied_literals.iter() {
+                if is_violated(&[!lhs, rhs]) {
+                    return Err(ViolatedConstraint::Clause {
+                        literals: vec![!lhs, rhs],
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Conclude the proof with the given bound on the objective variable.
+    pub(crate) fn conclude_proof_optimal(&mut self, bound: Literal) {
+        self.internal_parameters.proof.conclude_proof_optimal(
+            bound,
+            &self.variable_names,
+            &self.variable_literal_mappings,
+        );
+    }
+
+    /// Conclude the proof with the unsat conclusion.
+    pub(crate) fn conclude_proof_unsat(&mut self) {
+        self.internal_parameters
+            .proof
+            .conclude_proof_unsat(&self.variable_names, &self.variable_literal_mappings);
+    }
+
+    fn synchronise_propositional_trail_based_on_integer_trail(&mut self) -> Option<ConflictInfo> {
+        // for each entry on the integer trail, we now add the equivalent propositional
+        // representation on the propositional trail  note that only one literal per
+        // predicate will be stored      since the clausal propagator will propagate other
+        // literals to ensure that the meaning of the literal is respected          e.g.,
+        // placing [x >= 5] will prompt the clausal propagator to set [x >= 4] [x >= 3] ... [x >= 1]
+        // to true
+        for cp_trail_pos in
+            self.cp_trail_synced_position..self.assignments_integer.num_trail_entries()
+        {
+            let entry = self.assignments_integer.get_trail_entry(cp_trail_pos);
+
+            // It could be the case that the reason is `None`
+            // due to a SAT propagation being put on the trail during
+            // `synchronise_integer_trail_based_on_propositional_trail` In that case we
+            // do not synchronise since we assume that the SAT trail is already aware of the
+            // information
+            if let Some(reason_ref) = entry.reason {
+                let literal = self.variable_literal_mappings.get_literal(
+                    entry.predicate,
+                    &self.assignments_propositional,
+                    &self.assignments_integer,
+                );
+
+                // If the literal is already true (e.g. it was propagated by the clausal propagator
+                // based on a stronger bound), then the SAT-view is already aware of the
+                // information; enqueueing it again would only lead to another round of clausal
+                // propagation
+                if self
+                    .assignments_propositional
+                    .is_literal_assigned_true(literal)
+                {
+                    continue;
+                }
+
+                let constraint_reference = ConstraintReference::create_reason_reference(reason_ref);
+
+                let conflict_info = self
+                    .assignments_propositional
+                    .enqueue_propagated_literal(literal, constraint_reference);
+
+                if conflict_info.is_some() {
+                    self.cp_trail_synced_position = cp_trail_pos + 1;
+                    return conflict_info;
+                }
+
+                // It could occur that one of these propagations caused a conflict in which case the
                 // SAT-view and the CP-view are unsynchronised We need to ensure
                 // that the views are synchronised up to the CP trail entry which caused the
                 // conflict
@@ -711,6 +1646,8 @@ impl ConstraintSatisfactionSolver {
 
         if self.assignments_integer.num_domains() == 0 {
             self.sat_trail_synced_position = self.assignments_propositional.num_trail_entries();
+            // There are no domains to synchronise, but propagators can still watch literals
+            let _ = self.process_domain_events();
             return Ok(());
         }
 
@@ -767,8 +1704,11 @@ impl ConstraintSatisfactionSolver {
             "Solver is not expected to be in the infeasible under assumptions state when initialising.
              Missed extracting the core?"
         );
+        asserts::set_assert_level(self.internal_parameters.assert_level);
         self.state.declare_solving();
-        assumptions.clone_into(&mut self.assumptions);
+        self.assumptions.clear();
+        self.assumptions
+            .extend(self.activation_literals.iter().chain(assumptions).copied());
     }
 
     fn solve_internal(
@@ -779,12 +1719,30 @@ impl ConstraintSatisfactionSolver {
         loop {
             self.propagate_enqueued(termination);
 
-            if termination.should_stop() {
+            if termination.should_stop() || self.is_conflict_limit_reached() {
                 self.state.declare_timeout();
                 return CSPSolverExecutionFlag::Timeout;
             }
 
             if self.state.no_conflict() {
+                if brancher.refuses_branch() {
+                    if let Err(flag) = self.abandon_branch(brancher) {
+                        return flag;
+                    }
+                    continue;
+                }
+
+                if self.should_restart() {
+                    self.restart(brancher);
+                }
+
+                // The limit is checked right before the decision, such that a resumed search
+                // continues by making exactly the decision that it would otherwise have made
+                if self.is_decision_limit_reached() {
+                    self.state.declare_timeout();
+                    return CSPSolverExecutionFlag::Timeout;
+                }
+
                 self.declare_new_decision_level();
 
                 let branching_result = self.enqueue_next_decision(brancher);
@@ -796,6 +1754,8 @@ impl ConstraintSatisfactionSolver {
 
                 if self.assignments_propositional.is_at_the_root_level() {
                     // If it is at the root level then the problem is infeasible
+                    let conflict_tags = self.compute_conflict_tags();
+                    self.tag_tracker.record_root_conflict(conflict_tags);
                     self.state.declare_infeasible();
                     let _ = self.internal_parameters.proof.log_nogood([], []);
                     return CSPSolverExecutionFlag::Infeasible;
@@ -808,11 +1768,37 @@ impl ConstraintSatisfactionSolver {
                     return CSPSolverExecutionFlag::Infeasible;
                 }
 
-                brancher.on_conflict()
+                brancher.on_conflict();
+                termination.observe(&self.size_snapshot());
             }
         }
     }
 
+    /// Backtracks chronologically out of the current branch, which was refused by the brancher
+    /// (see [`Brancher::refuses_branch`]); if the branch is refused at the root, then there are no
+    /// branches left and the solver is declared infeasible.
+    fn abandon_branch(
+        &mut self,
+        brancher: &mut impl Brancher,
+    ) -> Result<(), CSPSolverExecutionFlag> {
+        munchkin_assert_simple!(
+            self.internal_parameters.conflict_resolver == ConflictResolutionStrategy::NoLearning,
+            "Branches can only be refused when backtracking chronologically"
+        );
+
+        if self.assignments_propositional.is_at_the_root_level() {
+            self.state.declare_infeasible();
+            return Err(CSPSolverExecutionFlag::Infeasible);
+        }
+
+        if self.process_learned_nogood(None, brancher).is_err() {
+            self.state.declare_infeasible();
+            return Err(CSPSolverExecutionFlag::Infeasible);
+        }
+
+        Ok(())
+    }
+
     fn enqueue_next_decision(
         &mut self,
         brancher: &mut impl Brancher,
@@ -824,11 +1810,20 @@ impl ConstraintSatisfactionSolver {
             }
             Ok(())
         } else {
-            let decided_predicate = brancher.next_decision(&mut SelectionContext::new(
-                &self.assignments_integer,
-                &self.assignments_propositional,
-                &mut self.internal_parameters.random_generator,
-            ));
+            let decided_predicate = brancher.next_decision(
+                &mut SelectionContext::new(
+                    &self.assignments_integer,
+                    &self.assignments_propositional,
+                    &mut self.random_generator,
+                )
+                .with_propagators(
+                    &self.cp_propagators,
+                    self.internal_parameters
+                        .use_non_generic_conflict_explanation,
+                    self.internal_parameters
+                        .use_non_generic_propagation_explanation,
+                ),
+            );
             if let Some(predicate) = decided_predicate {
                 self.counters.num_decisions += 1;
                 self.assignments_propositional
@@ -886,10 +1881,300 @@ impl ConstraintSatisfactionSolver {
         }
     }
 
+    /// Probes the bounds of the integer variables at the root (see [`ProbingOptions`]); the
+    /// negation of every probe which leads to a conflict is added as a unit clause.
+    ///
+    /// If a root-level conflict is derived, then the solver is left in the conflicting state,
+    /// which is subsequently detected by [`ConstraintSatisfactionSolver::solve_internal`].
+    fn probe_at_root(
+        &mut self,
+        termination: &mut impl TerminationCondition,
+        brancher: &mut impl Brancher,
+    ) {
+        if !self.assignments_propositional.is_at_the_root_level() {
+            return;
+        }
+
+        self.propagate_enqueued(termination);
+        if self.state.conflicting() {
+            return;
+        }
+
+        let ProbingOptions {
+            max_probes,
+            max_conflicts,
+            ..
+        } = self.internal_parameters.root_probing;
+
+        let domains = self.assignments_integer.get_domains().collect::<Vec<_>>();
+        for domain_id in domains {
+            let lower_bound = self.assignments_integer.get_lower_bound(domain_id);
+            let upper_bound = self.assignments_integer.get_upper_bound(domain_id);
+            if lower_bound == upper_bound {
+                continue;
+            }
+
+            let median = lower_bound + (upper_bound - lower_bound) / 2;
+            let probe = self.variable_literal_mappings.get_literal(
+                IntegerPredicate::UpperBound {
+                    domain_id,
+                    upper_bound: median,
+                },
+                &self.assignments_propositional,
+                &self.assignments_integer,
+            );
+
+            for literal in [probe, !probe] {
+                if self.counters.num_probes >= max_probes
+                    || self.counters.num_probing_conflicts >= max_conflicts
+                    || termination.should_stop()
+                {
+                    return;
+                }
+
+                if self.assignments_propositional.is_literal_assigned(literal) {
+                    continue;
+                }
+
+                self.counters.num_probes += 1;
+                self.declare_new_decision_level();
+                self.assignments_propositional
+                    .enqueue_decision_literal(literal);
+                self.propagate_enqueued(termination);
+
+                let is_failed_probe = self.state.conflicting();
+                self.backtrack(0, brancher);
+                if !is_failed_probe {
+                    continue;
+                }
+
+                self.counters.num_probing_conflicts += 1;
+                self.counters.num_root_assignments_derived += 1;
+                self.state.declare_solving();
+
+                let _ = self.internal_parameters.proof.log_nogood([literal], []);
+                if self.add_clause([!literal]).is_err() || self.state.conflicting() {
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Enqueues every assumption at a new decision level and propagates to a fixed point, without
+    /// learning or branching; afterwards, the state before the call is restored.
+    ///
+    /// See [`Solver::propagate_under_assumptions`] for more information.
+    pub(crate) fn propagate_under_assumptions(
+        &mut self,
+        assumptions: &[Literal],
+    ) -> PropagationProbeResult {
+        munchkin_assert_simple!(
+            self.assignments_propositional.is_at_the_root_level(),
+            "Propagating under assumptions can only be done at the root"
+        );
+
+        if self.state.is_infeasible() {
+            return PropagationProbeResult::Conflict(vec![]);
+        }
+
+        let previous_state = std::mem::take(&mut self.state);
+        let num_trail_entries = self.assignments_propositional.num_trail_entries();
+
+        let mut core = None;
+        for &assumption in assumptions {
+            if self
+                .assignments_propositional
+                .is_literal_assigned_true(assumption)
+            {
+                continue;
+            }
+
+            if self
+                .assignments_propositional
+                .is_literal_assigned_false(assumption)
+            {
+                let mut decisions = self.get_implying_decisions(vec![!assumption]);
+                decisions.push(assumption);
+                core = Some(decisions);
+                break;
+            }
+
+            self.declare_new_decision_level();
+            self.assignments_propositional
+                .enqueue_decision_literal(assumption);
+            self.propagate_enqueued(&mut Indefinite);
+
+            if self.state.conflicting() {
+                let conflict_literals = self.get_conflict_literals(&mut HashSet::default());
+                core = Some(self.get_implying_decisions(conflict_literals));
+                break;
+            }
+        }
+
+        let result = match core {
+            Some(core) => PropagationProbeResult::Conflict(core),
+            None => PropagationProbeResult::Consistent {
+                implied: (num_trail_entries..self.assignments_propositional.num_trail_entries())
+                    .map(|index| self.assignments_propositional.get_trail_entry(index))
+                    .filter(|&literal| !self.assignments_propositional.is_literal_decision(literal))
+                    .collect(),
+            },
+        };
+
+        if !self.assignments_propositional.is_at_the_root_level() {
+            self.backtrack(0, &mut NoOpBrancher);
+        }
+        self.state = previous_state;
+
+        result
+    }
+
+    /// Propagates the clauses and the propagators to a fixed point at the root and reports the
+    /// bounds and literals which are fixed at the root.
+    ///
+    /// See [`Solver::propagate`] for more information.
+    pub(crate) fn propagate_at_root(
+        &mut self,
+    ) -> Result<RootPropagationSummary, ConstraintOperationError> {
+        munchkin_assert_simple!(
+            self.assignments_propositional.is_at_the_root_level(),
+            "Propagating to a fixed point can only be done at the root"
+        );
+
+        if self.state.is_infeasible() {
+            return Err(ConstraintOperationError::InfeasibleState);
+        }
+        if self.state.is_suspended() {
+            return Err(ConstraintOperationError::SuspendedSearch);
+        }
+
+        let num_trail_entries = self.assignments_integer.num_trail_entries();
+        self.propagate_enqueued(&mut Indefinite);
+        self.log_root_propagations(num_trail_entries);
+
+        if !self.state.no_conflict() {
+            let conflict_tags = self.compute_conflict_tags();
+            self.tag_tracker.record_root_conflict(conflict_tags);
+
+            self.state.declare_infeasible();
+            return Err(ConstraintOperationError::InfeasibleRoot);
+        }
+
+        let tightened_bounds = self
+            .assignments_integer
+            .get_domains()
+            .flat_map(|domain| {
+                let lower_bound = self.assignments_integer.get_lower_bound(domain);
+                let upper_bound = self.assignments_integer.get_upper_bound(domain);
+
+                let tightened_lower_bound = (lower_bound
+                    > self.assignments_integer.get_initial_lower_bound(domain))
+                .then(|| predicate![domain >= lower_bound]);
+                let tightened_upper_bound = (upper_bound
+                    < self.assignments_integer.get_initial_upper_bound(domain))
+                .then(|| predicate![domain <= upper_bound]);
+
+                tightened_lower_bound
+                    .into_iter()
+                    .chain(tightened_upper_bound)
+            })
+            .collect();
+
+        let fixed_literals = (0..self.assignments_propositional.num_trail_entries())
+            .map(|index| self.assignments_propositional.get_trail_entry(index))
+            .filter(|&literal| {
+                literal != self.true_literal
+                    && self.variable_literal_mappings.literal_to_predicates[literal].is_empty()
+            })
+            .collect();
+
+        Ok(RootPropagationSummary {
+            tightened_bounds,
+            fixed_literals,
+        })
+    }
+
+    /// Returns the decisions which (transitively) imply the provided (true) literals by going
+    /// backwards over the trail, in the order in which they were made; the root-level assignments
+    /// are not considered.
+    fn get_implying_decisions(&mut self, literals: Vec<Literal>) -> Vec<Literal> {
+        let mut is_marked: HashSet<PropositionalVariable> = literals
+            .iter()
+            .map(|literal| literal.get_propositional_variable())
+            .collect();
+        let mut decisions = vec![];
+
+        for trail_index in (0..self.assignments_propositional.num_trail_entries()).rev() {
+            let literal = self.assignments_propositional.get_trail_entry(trail_index);
+            if !is_marked.contains(&literal.get_propositional_variable())
+                || self
+                    .assignments_propositional
+                    .is_literal_root_assignment(literal)
+            {
+                continue;
+            }
+
+            if self.assignments_propositional.is_literal_decision(literal) {
+                decisions.push(literal);
+                continue;
+            }
+
+            let reference = self
+                .assignments_propositional
+                .get_literal_reason_constraint(literal);
+            for antecedent in self.get_antecedents(literal, reference, &mut HashSet::default()) {
+                let _ = is_marked.insert(antecedent.get_propositional_variable());
+            }
+        }
+
+        decisions.reverse();
+        decisions
+    }
+
+    fn should_restart(&self) -> bool {
+        self.internal_parameters.conflict_resolver != ConflictResolutionStrategy::NoLearning
+            && self
+                .internal_parameters
+                .restart_interval
+                .is_some_and(|interval| {
+                    self.counters.num_conflicts - self.counters.num_conflicts_at_last_restart
+                        >= interval
+                })
+    }
+
+    /// Restarts the search by backtracking to the root, after which the [`Brancher`] is notified
+    /// with the current [`SearchStatistics`].
+    ///
+    /// Note that the assumptions are re-enqueued as decisions by the subsequent search.
+    fn restart(&mut self, brancher: &mut impl Brancher) {
+        if !self.assignments_propositional.is_at_the_root_level() {
+            self.backtrack(0, brancher);
+        }
+
+        self.counters.num_restarts += 1;
+        self.counters.num_conflicts_at_last_restart = self.counters.num_conflicts;
+        brancher.on_restart(&self.counters.snapshot());
+    }
+
+    fn is_conflict_limit_reached(&self) -> bool {
+        self.conflict_limit
+            .is_some_and(|conflict_limit| self.counters.num_conflicts >= conflict_limit)
+    }
+
+    fn is_decision_limit_reached(&self) -> bool {
+        self.decision_limit
+            .is_some_and(|decision_limit| self.counters.num_decisions >= decision_limit)
+    }
+
     pub(crate) fn declare_new_decision_level(&mut self) {
         self.assignments_propositional.increase_decision_level();
         self.assignments_integer.increase_decision_level();
         self.reason_store.increase_decision_level();
+
+        self.counters.peak_decision_level = self
+            .counters
+            .peak_decision_level
+            .max(self.assignments_propositional.get_decision_level() as u32);
     }
 
     fn minimise_learned_nogood(&mut self, learned_nogood: &mut LearnedNogood) {
@@ -1005,6 +2290,11 @@ impl ConstraintSatisfactionSolver {
     fn resolve_conflict(&mut self, brancher: &mut impl Brancher) {
         munchkin_assert_moderate!(self.state.conflicting());
 
+        let conflict_tags = self
+            .tag_tracker
+            .is_enabled()
+            .then(|| self.compute_conflict_tags());
+
         let mut learned_nogood = self.compute_learned_nogood(brancher);
         // We calculate some statistics and perform clause minimisation (before collecting the
         // statistics)
@@ -1046,6 +2336,22 @@ impl ConstraintSatisfactionSolver {
 
         let result = self.process_learned_nogood(learned_nogood, brancher);
 
+        if let Some(conflict_tags) = conflict_tags {
+            if result.is_err() {
+                self.tag_tracker.record_root_conflict(conflict_tags);
+            } else {
+                // The literal which is derived from the conflict is the last one on the trail
+                let derived_literal = self
+                    .assignments_propositional
+                    .get_trail_entry(self.assignments_propositional.num_trail_entries() - 1);
+                let reference = self
+                    .assignments_propositional
+                    .get_literal_reason_constraint(derived_literal);
+                self.tag_tracker
+                    .record_derived_literal(derived_literal, reference, conflict_tags);
+            }
+        }
+
         if result.is_err() {
             self.state.declare_infeasible();
         } else {
@@ -1070,6 +2376,7 @@ impl ConstraintSatisfactionSolver {
             propositional_trail_index: &mut self.propositional_trail_index,
             propagator_queue: &mut self.propagator_queue,
             watch_list_cp: &mut self.watch_list_cp,
+            propagators: &mut self.cp_propagators,
             sat_trail_synced_position: &mut self.sat_trail_synced_position,
             cp_trail_synced_position: &mut self.cp_trail_synced_position,
         };
@@ -1098,6 +2405,7 @@ impl ConstraintSatisfactionSolver {
             propositional_trail_index: &mut self.propositional_trail_index,
             propagator_queue: &mut self.propagator_queue,
             watch_list_cp: &mut self.watch_list_cp,
+            propagators: &mut self.cp_propagators,
             sat_trail_synced_position: &mut self.sat_trail_synced_position,
             cp_trail_synced_position: &mut self.cp_trail_synced_position,
         };
@@ -1129,17 +2437,67 @@ impl ConstraintSatisfactionSolver {
             self.assignments_propositional.num_trail_entries(),
         );
         self.assignments_integer
-            .synchronise(backtrack_level)
-            .iter()
-            .for_each(|(domain_id, previous_value)| {
-                brancher.on_unassign_integer(*domain_id, *previous_value)
+            .synchronise(backtrack_level, |domain_id, previous_value| {
+                brancher.on_unassign_integer(domain_id, previous_value)
             });
 
-        self.reason_store.synchronise(backtrack_level);
-        self.propagator_queue.clear();
-        //  note that variable_literal_mappings sync should be called after the sat/cp data
-        // structures backtrack
-        self.synchronise_assignments();
+        self.notify_backtrack_events();
+
+        self.reason_store.synchronise(backtrack_level);
+        self.propagator_queue.clear();
+        //  note that variable_literal_mappings sync should be called after the sat/cp data
+        // structures backtrack
+        self.synchronise_assignments();
+    }
+
+    /// Notifies the propagators of the domain changes which were undone while backtracking (or
+    /// while rolling back to a snapshot); see [`Propagator::notify_backtrack`].
+    fn notify_backtrack_events(&mut self) {
+        Self::deliver_backtrack_events(
+            &mut self.assignments_integer,
+            &self.assignments_propositional,
+            &self.watch_list_cp,
+            &mut self.cp_propagators,
+            &self.internal_parameters,
+        );
+    }
+
+    /// Delivers the undone domain changes in `assignments_integer` to the propagators which
+    /// subscribed to them; this is shared by [`ConstraintSatisfactionSolver::backtrack`] and
+    /// [`ConflictAnalysisContext::backtrack`].
+    ///
+    /// If no propagator subscribed to backtrack events, the undone changes are discarded without
+    /// being collected.
+    pub(crate) fn deliver_backtrack_events(
+        assignments_integer: &mut AssignmentsInteger,
+        assignments_propositional: &AssignmentsPropositional,
+        watch_list_cp: &WatchListCP,
+        propagators: &mut KeyedVec<PropagatorId, Box<dyn Propagator>>,
+        internal_parameters: &SatisfactionSolverOptions,
+    ) {
+        if !watch_list_cp.is_watching_any_backtrack_events() {
+            assignments_integer.clear_backtrack_domain_events();
+            return;
+        }
+
+        let backtrack_events = assignments_integer
+            .drain_backtrack_domain_events()
+            .collect::<Vec<_>>();
+        for (event, domain) in backtrack_events {
+            for propagator_var in watch_list_cp.get_backtrack_affected_propagators(event, domain) {
+                let context = PropagationContext::new(
+                    assignments_integer,
+                    assignments_propositional,
+                    internal_parameters.use_non_generic_conflict_explanation,
+                    internal_parameters.use_non_generic_propagation_explanation,
+                );
+                propagators[propagator_var.propagator].notify_backtrack(
+                    context,
+                    propagator_var.variable,
+                    event,
+                );
+            }
+        }
     }
 
     /// Main propagation loop.
@@ -1147,9 +2505,14 @@ impl ConstraintSatisfactionSolver {
         let num_trail_entries_before = self.assignments_integer.num_trail_entries();
 
         let mut empty_domain_reported = false;
+        let mut is_interrupted = false;
+
+        self.recently_invoked_propagators.clear();
+        let mut num_round_trips = 0;
 
         loop {
             if termination.should_stop() {
+                is_interrupted = true;
                 break;
             }
 
@@ -1192,6 +2555,12 @@ impl ConstraintSatisfactionSolver {
                     // complex ones  after a cp propagation was done one step,
                     // it is time to go to the clausal propagator
                     empty_domain_reported = reported_empty_domain;
+
+                    num_round_trips += 1;
+                    if self.internal_parameters.propagation_loop_threshold == Some(num_round_trips)
+                    {
+                        self.report_propagation_loop(num_round_trips);
+                    }
                 }
                 PropagationStatusOneStepCP::FixedPoint => {
                     break;
@@ -1222,14 +2591,21 @@ impl ConstraintSatisfactionSolver {
         self.counters.num_propagations +=
             self.assignments_integer.num_trail_entries() as u64 - num_trail_entries_before as u64;
 
-        // Only check fixed point propagation if there was no reported conflict.
+        // Only check the state of the clausal propagator and fixed point propagation if there was
+        // no reported conflict and the propagation was not interrupted.
+        munchkin_assert_advanced!(
+            self.state.conflicting()
+                || is_interrupted
+                || self
+                    .clausal_propagator
+                    .debug_check_state(&self.assignments_propositional, &self.clause_allocator)
+        );
         munchkin_assert_extreme!(
             self.state.conflicting()
+                || is_interrupted
                 || DebugHelper::debug_fixed_point_propagation(
-                    &self.clausal_propagator,
                     &self.assignments_integer,
                     &self.assignments_propositional,
-                    &self.clause_allocator,
                     &self.cp_propagators,
                     self.internal_parameters
                         .use_non_generic_conflict_explanation,
@@ -1239,6 +2615,47 @@ impl ConstraintSatisfactionSolver {
         );
     }
 
+    /// Reports that propagation did not reach a fixed point after `num_round_trips` round trips
+    /// between the clausal propagator and the CP propagators; see
+    /// [`SatisfactionSolverOptions::propagation_loop_threshold`].
+    fn report_propagation_loop(&mut self, num_round_trips: u64) {
+        self.counters.num_propagation_loops += 1;
+
+        let mut involved_propagators: Vec<PropagatorId> = vec![];
+        for &propagator_id in &self.recently_invoked_propagators {
+            if !involved_propagators.contains(&propagator_id) {
+                involved_propagators.push(propagator_id);
+            }
+        }
+
+        let mut names = Vec::with_capacity(involved_propagators.len());
+        for propagator_id in involved_propagators {
+            self.propagator_counters[propagator_id].num_propagation_loops += 1;
+            names.push(self.cp_propagators[propagator_id].name());
+        }
+
+        warn!(
+            "Propagation did not reach a fixed point after {num_round_trips} round trips at \
+             decision level {}, the most recently invoked propagators are: {}",
+            self.get_decision_level(),
+            names.join(", ")
+        );
+
+        if log_enabled!(Level::Debug) {
+            let num_trail_entries = self.assignments_integer.num_trail_entries();
+            for index in
+                num_trail_entries.saturating_sub(PROPAGATION_LOOP_HISTORY)..num_trail_entries
+            {
+                let entry = self.assignments_integer.get_trail_entry(index);
+                debug!(
+                    "Integer trail entry {index}: {} (propagated: {})",
+                    entry.predicate,
+                    entry.reason.is_some()
+                );
+            }
+        }
+    }
+
     /// Performs propagation using propagators, stops after a propagator propagates at least one
     /// domain change. The idea is to go to the clausal propagator first before proceeding with
     /// other propagators, in line with the idea of propagating simpler propagators before more
@@ -1251,10 +2668,14 @@ impl ConstraintSatisfactionSolver {
             return PropagationStatusOneStepCP::FixedPoint;
         }
 
-        #[cfg(feature = "explanation-checks")]
         let num_trail_entries_before = self.assignments_integer.num_trail_entries();
 
         let propagator_id = self.propagator_queue.pop();
+        if self.recently_invoked_propagators.len() == PROPAGATION_LOOP_HISTORY {
+            let _ = self.recently_invoked_propagators.pop_front();
+        }
+        self.recently_invoked_propagators.push_back(propagator_id);
+
         let propagator = &mut self.cp_propagators[propagator_id];
         let context = PropagationContextMut::new(
             &mut self.assignments_integer,
@@ -1267,7 +2688,21 @@ impl ConstraintSatisfactionSolver {
                 .use_non_generic_propagation_explanation,
         );
 
-        match propagator.propagate(context) {
+        let start_time = self.internal_parameters.time_propagators.then(Instant::now);
+        let propagation_status = propagator.propagate(context);
+
+        let counters = &mut self.propagator_counters[propagator_id];
+        counters.num_invocations += 1;
+        counters.num_domain_reductions +=
+            (self.assignments_integer.num_trail_entries() - num_trail_entries_before) as u64;
+        if propagation_status.is_err() {
+            counters.num_conflicts += 1;
+        }
+        if let Some(start_time) = start_time {
+            counters.time_spent += start_time.elapsed();
+        }
+
+        match propagation_status {
             // An empty domain conflict will be caught by the clausal propagator.
             Err(Inconsistency::EmptyDomain) => {
                 PropagationStatusOneStepCP::PropagationHappened(true)
@@ -1299,7 +2734,7 @@ impl ConstraintSatisfactionSolver {
             Ok(()) => {
                 let _ = self.process_domain_events();
                 #[cfg(feature = "explanation-checks")]
-                assert!(
+                munchkin_assert_advanced!(
                     DebugHelper::debug_check_propagations(
                         _termination,
                         num_trail_entries_before,
@@ -1388,9 +2823,21 @@ impl ConstraintSatisfactionSolver {
         if self.state.is_inconsistent() {
             return Err(ConstraintOperationError::InfeasiblePropagator);
         }
+        if self.state.is_suspended() {
+            return Err(ConstraintOperationError::SuspendedSearch);
+        }
+
+        let num_trail_entries = self.assignments_integer.num_trail_entries();
 
         self.propagator_tags.push(tag);
+        self.propagator_counters.push(PropagatorCounters::default());
         let new_propagator_id = PropagatorId(self.cp_propagators.len() as u32);
+        if self.internal_parameters.proof.is_logging() {
+            self.tag_artifacts
+                .entry(tag)
+                .or_default()
+                .add_propagator(new_propagator_id);
+        }
 
         self.cp_propagators.push(Box::new(propagator_to_add));
 
@@ -1400,8 +2847,9 @@ impl ConstraintSatisfactionSolver {
             &mut self.watch_list_cp,
             &mut self.watch_list_propositional,
             new_propagator_id,
-            &self.assignments_integer,
-            &self.assignments_propositional,
+            &mut self.assignments_integer,
+            &mut self.reason_store,
+            &mut self.assignments_propositional,
             self.internal_parameters
                 .use_non_generic_conflict_explanation,
             self.internal_parameters
@@ -1410,31 +2858,610 @@ impl ConstraintSatisfactionSolver {
 
         let initialisation_status = new_propagator.initialise_at_root(&mut initialisation_context);
 
-        if initialisation_status.is_err() {
+        if let Err(conjunction) = initialisation_status {
+            let conflict_literals = conjunction
+                .iter()
+                .map(|&predicate| self.get_literal(predicate))
+                .collect();
+            let mut conflict_tags = HashSet::default();
+            let _ = conflict_tags.insert(tag);
+            let conflict_tags = self.compute_implication_tags(conflict_literals, conflict_tags);
+            self.tag_tracker.record_root_conflict(conflict_tags);
+
             self.state.declare_infeasible();
             Err(ConstraintOperationError::InfeasiblePropagator)
         } else {
+            // Notify the propagators of the domain changes made during initialisation; these
+            // are synchronised with the propositional trail (which detects any root conflicts)
+            // when propagating.
+            let _ = self.process_domain_events();
+
+            let priority = self.cp_propagators[new_propagator_id].priority();
             self.propagator_queue
-                .enqueue_propagator(new_propagator_id, 0);
+                .enqueue_propagator(new_propagator_id, priority);
+
+            self.propagate_enqueued(&mut Indefinite);
+            self.log_root_propagations(num_trail_entries);
+
+            if self.state.no_conflict() {
+                Ok(())
+            } else {
+                let conflict_tags = self.compute_conflict_tags();
+                self.tag_tracker.record_root_conflict(conflict_tags);
+
+                Err(ConstraintOperationError::InfeasiblePropagator)
+            }
+        }
+    }
+
+    /// The number of propagators which have been added to the solver.
+    pub(crate) fn num_propagators(&self) -> usize {
+        self.cp_propagators.len()
+    }
+
+    /// The number of permanent clauses (including the learned clauses) which are in the solver.
+    pub(crate) fn num_permanent_clauses(&self) -> usize {
+        self.clausal_propagator.permanent_clauses.len()
+    }
+
+    /// Returns the name and the tag of every propagator, in the order in which the propagators
+    /// were added to the solver.
+    pub(crate) fn tagged_propagators(&self) -> impl Iterator<Item = (&str, NonZero<u32>)> + '_ {
+        self.cp_propagators
+            .iter()
+            .zip(self.propagator_tags.iter())
+            .map(|(propagator, &tag)| (propagator.name(), tag))
+    }
+
+    /// Returns how many original clauses there are of every length, sorted by the length.
+    pub(crate) fn original_clause_length_histogram(&self) -> Vec<(usize, usize)> {
+        let mut histogram = std::collections::BTreeMap::<usize, usize>::new();
+
+        // The binary clauses are stored as two implications each
+        let num_binary_clauses = self
+            .clausal_propagator
+            .binary_implications
+            .iter()
+            .map(Vec::len)
+            .sum::<usize>()
+            / 2;
+        if num_binary_clauses > 0 {
+            let _ = histogram.insert(2, num_binary_clauses);
+        }
+
+        self.clausal_propagator
+            .permanent_clauses
+            .iter()
+            .map(|&reference| self.clause_allocator.get_clause(reference))
+            .filter(|clause| !clause.is_deleted() && !clause.is_learned())
+            .for_each(|clause| *histogram.entry(clause.len() as usize).or_default() += 1);
+
+        histogram.into_iter().collect()
+    }
+
+    /// Registers the constraint `activation_literal -> constraint` as a removable constraint,
+    /// where the constraint was posted using the propagators and the clauses which were added
+    /// since the solver contained `num_propagators` propagators and `num_permanent_clauses`
+    /// clauses.
+    ///
+    /// From now on, `activation_literal` is assumed in every solve call until the constraint is
+    /// removed using [`ConstraintSatisfactionSolver::remove_constraint`].
+    pub(crate) fn add_removable_constraint(
+        &mut self,
+        activation_literal: Literal,
+        num_propagators: usize,
+        num_permanent_clauses: usize,
+    ) -> ConstraintHandle {
+        self.activation_literals.push(activation_literal);
+
+        ConstraintHandle {
+            activation_literal,
+            propagators: (num_propagators..self.cp_propagators.len())
+                .map(|index| PropagatorId(index as u32))
+                .collect(),
+            clauses: self.clausal_propagator.permanent_clauses[num_permanent_clauses..].to_vec(),
+        }
+    }
+
+    /// Removes a constraint which was added using [`Solver::add_removable_constraint`]; this
+    /// can only be done at the root.
+    ///
+    /// The propagators of the constraint are detached from the watch lists and its clauses are
+    /// deleted, as are the learned clauses which depend on the constraint (i.e. which contain the
+    /// negation of its activation literal). Since the constraint only propagates when its
+    /// activation literal is assumed, it has not made any root-level propagations which need to
+    /// be undone.
+    pub(crate) fn remove_constraint(
+        &mut self,
+        handle: ConstraintHandle,
+    ) -> Result<(), ConstraintOperationError> {
+        munchkin_assert_simple!(
+            self.assignments_propositional.is_at_the_root_level(),
+            "Constraints can only be removed at the root"
+        );
+
+        if let Some(index) = self
+            .activation_literals
+            .iter()
+            .position(|&literal| literal == handle.activation_literal)
+        {
+            let _ = self.activation_literals.remove(index);
+            for snapshot in self.snapshots.iter_mut() {
+                if index < snapshot.num_activation_literals {
+                    snapshot.num_activation_literals -= 1;
+                }
+            }
+        }
+
+        for &propagator in handle.propagators.iter() {
+            self.watch_list_cp.remove_propagator(propagator);
+            self.watch_list_propositional.remove_propagator(propagator);
+        }
+
+        let deactivated = !handle.activation_literal;
+        let clauses = handle.clauses.iter().copied().collect::<HashSet<_>>();
+        let mut index = 0;
+        let mut removed_indices = vec![];
+        self.clausal_propagator.remove_permanent_clauses(
+            &mut self.clause_allocator,
+            |clause_reference, literals| {
+                let should_remove =
+                    clauses.contains(&clause_reference) || literals.contains(&deactivated);
+                if should_remove {
+                    removed_indices.push(index);
+                }
+                index += 1;
+                should_remove
+            },
+        );
+
+        // The snapshots refer to the clauses by their position, which shifts when clauses which
+        // were added before the snapshot are removed
+        for snapshot in self.snapshots.iter_mut() {
+            snapshot.num_permanent_clauses -= removed_indices
+                .iter()
+                .filter(|&&index| index < snapshot.num_permanent_clauses)
+                .count();
+        }
+
+        // The activation literal is fixed such that the constraint can never become active again
+        self.add_clause([deactivated])
+    }
+
+    /// Records the current state of the root such that it can be restored using
+    /// [`ConstraintSatisfactionSolver::rollback_to`]; this can only be done at the root.
+    ///
+    /// See [`Solver::snapshot`] for more information.
+    pub(crate) fn snapshot(&mut self) -> SnapshotId {
+        munchkin_assert_simple!(
+            self.assignments_propositional.is_at_the_root_level(),
+            "Snapshots can only be taken at the root"
+        );
+
+        self.snapshots.push(RootSnapshot {
+            num_propagators: self.cp_propagators.len(),
+            num_permanent_clauses: self.clausal_propagator.permanent_clauses.len(),
+            num_binary_implications: self.clausal_propagator.num_binary_implications(),
+            num_domains: self.assignments_integer.num_domains(),
+            num_activation_literals: self.activation_literals.len(),
+            num_propositional_trail_entries: self.assignments_propositional.num_trail_entries(),
+            num_integer_trail_entries: self.assignments_integer.num_trail_entries(),
+            num_reasons: self.reason_store.len(),
+            num_entailed_propagators: self.entailed_propagators.len(),
+            is_infeasible: self.state.is_infeasible(),
+        });
+
+        SnapshotId(self.snapshots.len() - 1)
+    }
+
+    /// Restores the state of the root to the provided snapshot by undoing the root assignments
+    /// and removing the propagators and clauses (including the learned clauses) which were added
+    /// after it; the snapshots which were taken after it become invalid.
+    ///
+    /// See [`Solver::rollback_to`] for more information.
+    pub(crate) fn rollback_to(&mut self, snapshot_id: SnapshotId) {
+        munchkin_assert_simple!(
+            self.assignments_propositional.is_at_the_root_level(),
+            "Rolling back to a snapshot can only be done at the root"
+        );
+        munchkin_assert_simple!(
+            snapshot_id.0 < self.snapshots.len(),
+            "The snapshot was invalidated by rolling back to an earlier snapshot"
+        );
+
+        self.snapshots.truncate(snapshot_id.0 + 1);
+        let snapshot = self.snapshots[snapshot_id.0].clone();
+
+        // Undo the root assignments; this is similar to backtracking, except that the root is
+        // truncated rather than a decision level being removed
+        self.assignments_propositional
+            .undo_root_assignments(snapshot.num_propositional_trail_entries)
+            .for_each(|_| {});
+        self.clausal_propagator
+            .synchronise(self.assignments_propositional.num_trail_entries());
+        self.propositional_trail_index = min(
+            self.propositional_trail_index,
+            self.assignments_propositional.num_trail_entries(),
+        );
+        // The propagators which were found to be entailed after the snapshot are reactivated once
+        // the integer trail is undone to the point at which they were detached, such that they
+        // are notified of exactly those undone changes which they were notified of when they
+        // happened
+        let reactivated_propagators = self
+            .entailed_propagators
+            .split_off(snapshot.num_entailed_propagators);
+        for (propagator, num_integer_trail_entries) in reactivated_propagators.into_iter().rev() {
+            self.assignments_integer
+                .undo_root_trail_entries(num_integer_trail_entries, |_, _| {});
+            self.notify_backtrack_events();
+            self.watch_list_cp.mark_active(propagator);
+            self.propagator_queue.mark_active(propagator);
+        }
+        self.assignments_integer
+            .undo_root_trail_entries(snapshot.num_integer_trail_entries, |_, _| {});
+        self.notify_backtrack_events();
+        self.reason_store.truncate_root(snapshot.num_reasons);
+        self.propagator_queue.clear();
+        self.synchronise_assignments();
+
+        // The propagators which were added after the snapshot are the last ones, so they can be
+        // removed entirely rather than only being detached
+        for index in snapshot.num_propagators..self.cp_propagators.len() {
+            let propagator = PropagatorId(index as u32);
+            self.watch_list_propositional.remove_propagator(propagator);
+        }
+        self.watch_list_cp
+            .truncate_propagators(snapshot.num_propagators);
+        self.cp_propagators.truncate(snapshot.num_propagators);
+        self.propagator_tags.truncate(snapshot.num_propagators);
+        self.propagator_counters.truncate(snapshot.num_propagators);
+        self.activation_literals
+            .truncate(snapshot.num_activation_literals);
+
+        // All of the clauses which were added after the snapshot are removed, including the
+        // learned clauses, since these may be derived from the removed constraints. The clauses
+        // which were learned before the snapshot only depend on the constraints which remain.
+        let mut index = 0;
+        self.clausal_propagator
+            .remove_permanent_clauses(&mut self.clause_allocator, |_, _| {
+                let should_remove = index >= snapshot.num_permanent_clauses;
+                index += 1;
+                should_remove
+            });
+        self.clausal_propagator
+            .truncate_binary_implications(&snapshot.num_binary_implications);
+
+        // If the solver was already infeasible at the snapshot, then it remains infeasible
+        if snapshot.is_infeasible {
+            return;
+        }
+        self.clausal_propagator.reset_infeasible_state();
+        self.state.declare_ready();
+
+        // The variables which were created after the snapshot remain, so the clauses which encode
+        // their domains are restored
+        for domain_id in snapshot.num_domains..self.assignments_integer.num_domains() {
+            self.variable_literal_mappings.add_encoding_clauses(
+                DomainId::new(domain_id),
+                &mut self.clausal_propagator,
+                &mut self.assignments_propositional,
+                &mut self.clause_allocator,
+            );
+        }
+    }
+
+    /// Simplifies the problem using the assignments at the root, which is worthwhile after the
+    /// root has been strengthened (e.g. after the bound on the objective has been tightened):
+    /// - The learned clauses which are satisfied at the root are deleted, unless they are the
+    ///   reason for a root assignment.
+    /// - The literals which are falsified at the root are removed from the learned clauses which
+    ///   are not satisfied at the root.
+    /// - The propagators which are entailed at the root (see [`Propagator::is_entailed`]) are
+    ///   marked as inactive, so they are not notified of events nor enqueued anymore.
+    ///
+    /// The simplifications of the clauses are only valid as long as the root assignments are not
+    /// undone, so they are skipped while there are snapshots to which the solver can be rolled
+    /// back; the entailed propagators are reactivated when rolling back instead. Furthermore, the
+    /// clauses are only shrunk if neither the proof nor the constraint tags are recorded, since
+    /// these rely on the root assignments being explained by the original clauses.
+    pub(crate) fn simplify_root(&mut self) {
+        munchkin_assert_simple!(
+            self.assignments_propositional.is_at_the_root_level(),
+            "The problem can only be simplified at the root"
+        );
+
+        // A conflict at the root is left for the next call to solve
+        if self.state.is_inconsistent() {
+            return;
+        }
+        munchkin_assert_moderate!(self.is_propagation_complete());
+
+        if self.snapshots.is_empty() {
+            self.simplify_root_clauses();
+        }
+        self.detach_entailed_propagators();
+    }
+
+    fn simplify_root_clauses(&mut self) {
+        let satisfied_learned_clauses = self
+            .clausal_propagator
+            .permanent_clauses
+            .iter()
+            .copied()
+            .filter(|&reference| {
+                let clause = self.clause_allocator.get_clause(reference);
+                clause.is_learned() && self.is_satisfied_unlocked_clause(reference)
+            })
+            .collect::<HashSet<_>>();
+        self.clausal_propagator
+            .remove_permanent_clauses(&mut self.clause_allocator, |reference, _| {
+                satisfied_learned_clauses.contains(&reference)
+            });
+        for &reference in satisfied_learned_clauses.iter() {
+            self.tag_tracker.remove_learned_clause(reference);
+        }
+        self.counters.num_satisfied_clauses_removed += satisfied_learned_clauses.len() as u64;
+
+        if !self.internal_parameters.proof.is_logging() && !self.tag_tracker.is_enabled() {
+            let num_removed_literals = self.clausal_propagator.remove_root_falsified_literals(
+                &self.assignments_propositional,
+                &mut self.clause_allocator,
+            );
+            self.counters.num_falsified_literals_removed += num_removed_literals as u64;
+        }
+    }
+
+    /// Compacts the propositional representation of the domains whose bounds at the root are
+    /// strictly tighter than their initial bounds. The literals of the values outside of the root
+    /// bounds are fixed at the root (which is ensured by propagation), so the clauses and binary
+    /// implications of the representation which are satisfied at the root are removed from the
+    /// clausal propagator, unless they are the reason for a root assignment.
+    ///
+    /// The tightened bounds are logged to the proof as the facts which justify the fixing of the
+    /// literals. Similar to [`ConstraintSatisfactionSolver::simplify_root`], this is skipped while
+    /// there are snapshots to which the solver can be rolled back, since the removed clauses are
+    /// only redundant as long as the root assignments are not undone.
+    ///
+    /// This is done automatically after the first root propagation of a solve call.
+    pub(crate) fn compact_domains(&mut self) {
+        munchkin_assert_simple!(
+            self.assignments_propositional.is_at_the_root_level(),
+            "The domains can only be compacted at the root"
+        );
+
+        // A conflict at the root is left for the next call to solve
+        if self.state.is_inconsistent() || !self.snapshots.is_empty() {
+            return;
+        }
+        munchkin_assert_moderate!(self.is_propagation_complete());
+
+        let tightened_domains = self
+            .assignments_integer
+            .get_domains()
+            .filter(|&domain| {
+                self.assignments_integer.get_lower_bound(domain)
+                    > self.assignments_integer.get_initial_lower_bound(domain)
+                    || self.assignments_integer.get_upper_bound(domain)
+                        < self.assignments_integer.get_initial_upper_bound(domain)
+            })
+            .collect::<Vec<_>>();
+        if tightened_domains.is_empty() {
+            return;
+        }
+
+        for &domain in tightened_domains.iter() {
+            self.log_root_bounds(domain);
+        }
+
+        let compacted_variables = tightened_domains
+            .iter()
+            .flat_map(|&domain| self.variable_literal_mappings.get_domain_literals(domain))
+            .map(|literal| literal.get_propositional_variable())
+            .filter(|&variable| variable != self.true_literal.get_propositional_variable())
+            .collect::<HashSet<_>>();
+
+        let satisfied_clauses = self
+            .clausal_propagator
+            .permanent_clauses
+            .iter()
+            .copied()
+            .filter(|&reference| {
+                let clause = self.clause_allocator.get_clause(reference);
+                !clause.is_learned()
+                    && clause.get_literal_slice().iter().all(|literal| {
+                        compacted_variables.contains(&literal.get_propositional_variable())
+                    })
+                    && self.is_satisfied_unlocked_clause(reference)
+            })
+            .collect::<HashSet<_>>();
+        self.clausal_propagator
+            .remove_permanent_clauses(&mut self.clause_allocator, |reference, _| {
+                satisfied_clauses.contains(&reference)
+            });
+
+        let num_removed_implications = self.clausal_propagator.remove_root_satisfied_implications(
+            compacted_variables
+                .iter()
+                .map(|&variable| Literal::new(variable, true)),
+            &self.assignments_propositional,
+        );
+
+        self.counters.num_domains_compacted += tightened_domains.len() as u64;
+        self.counters.num_compaction_clauses_removed += satisfied_clauses.len() as u64;
+        self.counters.num_compaction_implications_removed += num_removed_implications as u64;
+    }
+
+    /// Logs the bounds of `domain` at the root which are tighter than its initial bounds to the
+    /// proof.
+    fn log_root_bounds(&mut self, domain: DomainId) {
+        let lower_bound = self.assignments_integer.get_lower_bound(domain);
+        let upper_bound = self.assignments_integer.get_upper_bound(domain);
+
+        let mut facts = vec![];
+        if lower_bound > self.assignments_integer.get_initial_lower_bound(domain) {
+            facts.push(predicate![domain >= lower_bound]);
+        }
+        if upper_bound < self.assignments_integer.get_initial_upper_bound(domain) {
+            facts.push(predicate![domain <= upper_bound]);
+        }
+
+        for fact in facts {
+            let literal = self.get_literal(fact);
+            let _ = self.internal_parameters.proof.log_nogood([!literal], []);
+        }
+    }
+
+    fn detach_entailed_propagators(&mut self) {
+        for index in 0..self.cp_propagators.len() {
+            let propagator_id = PropagatorId(index as u32);
+            // The propagator is either entailed already or part of a removed constraint
+            if !self.watch_list_cp.is_active(propagator_id) {
+                continue;
+            }
+
+            let context = PropagationContext::new(
+                &self.assignments_integer,
+                &self.assignments_propositional,
+                self.internal_parameters
+                    .use_non_generic_conflict_explanation,
+                self.internal_parameters
+                    .use_non_generic_propagation_explanation,
+            );
+            if !self.cp_propagators[propagator_id].is_entailed(context) {
+                continue;
+            }
+
+            self.watch_list_cp.mark_inactive(propagator_id);
+            self.propagator_queue.mark_inactive(propagator_id);
+            self.entailed_propagators
+                .push((propagator_id, self.assignments_integer.num_trail_entries()));
+            self.counters.num_propagators_entailed += 1;
+        }
+    }
+
+    /// Returns the identifiers of the propagators, ordered by their priority (see
+    /// [`Propagator::priority`]) and then by the order in which they were added.
+    pub(crate) fn get_propagators_by_priority(&self) -> Vec<PropagatorId> {
+        let mut propagators = (0..self.cp_propagators.len())
+            .map(|index| PropagatorId(index as u32))
+            .collect::<Vec<_>>();
+        propagators.sort_by_key(|&propagator| self.cp_propagators[propagator].priority());
+        propagators
+    }
+
+    /// Returns whether the propagator was found to be entailed at the root by
+    /// [`ConstraintSatisfactionSolver::simplify_root`].
+    #[cfg(test)]
+    pub(crate) fn is_propagator_entailed(&self, propagator_id: PropagatorId) -> bool {
+        self.entailed_propagators
+            .iter()
+            .any(|&(propagator, _)| propagator == propagator_id)
+    }
+
+    /// Returns whether the clause is satisfied at the root without being the reason for the
+    /// literal which satisfies it.
+    fn is_satisfied_unlocked_clause(&self, reference: ClauseReference) -> bool {
+        let mut true_literals = self
+            .clause_allocator
+            .get_clause(reference)
+            .get_literal_slice()
+            .iter()
+            .filter(|&&literal| {
+                self.assignments_propositional
+                    .is_literal_assigned_true(literal)
+            })
+            .peekable();
+
+        true_literals.peek().is_some()
+            && true_literals.all(|&literal| {
+                self.assignments_propositional
+                    .get_literal_reason_constraint(literal)
+                    != ConstraintReference::from(reference)
+            })
+    }
+
+    /// Creates a clause from `literals` and adds it to the current formula.
+    ///
+    /// If the formula becomes trivially unsatisfiable, a [`ConstraintOperationError`] will be
+    /// returned. Subsequent calls to this method will always return an error, and no
+    /// modification of the solver will take place.
+    pub fn add_clause(
+        &mut self,
+        literals: impl IntoIterator<Item = Literal>,
+    ) -> Result<(), ConstraintOperationError> {
+        munchkin_assert_moderate!(!self.state.is_infeasible_under_assumptions());
+        munchkin_assert_moderate!(self.is_propagation_complete());
+
+        if self.state.is_infeasible() {
+            return Err(ConstraintOperationError::InfeasibleState);
+        }
+        if self.state.is_suspended() {
+            return Err(ConstraintOperationError::SuspendedSearch);
+        }
+
+        let literals: Vec<Literal> = literals.into_iter().collect();
+        let num_trail_entries = self.assignments_integer.num_trail_entries();
+        let root_unit = self.get_root_unit(&literals);
+        let num_permanent_clauses = self.clausal_propagator.permanent_clauses.len();
+        let num_propositional_trail_entries = self.assignments_propositional.num_trail_entries();
+
+        let result = self.clausal_propagator.add_permanent_clause(
+            literals,
+            &mut self.assignments_propositional,
+            &mut self.clause_allocator,
+        );
+
+        if result.is_err() {
+            self.state.declare_infeasible();
+            return Err(ConstraintOperationError::InfeasibleClause);
+        }
+        self.record_clause_artifact(num_permanent_clauses, num_propositional_trail_entries);
+
+        if let Some((unit, falsified_literals)) = root_unit {
+            self.log_root_unit(unit, falsified_literals);
+        }
+
+        self.propagate_enqueued(&mut Indefinite);
+        self.log_root_propagations(num_trail_entries);
+
+        if self.state.is_infeasible() {
+            self.state.declare_infeasible();
+            return Err(ConstraintOperationError::InfeasibleClause);
+        }
+
+        Ok(())
+    }
 
-            self.propagate_enqueued(&mut Indefinite);
+    /// Posts the provided predicate as a unit clause at the root and propagates it. If proof
+    /// logging is enabled, then the predicate is logged as a fact which is given by the constraint
+    /// that is currently being posted (if any).
+    pub(crate) fn add_root_fact(
+        &mut self,
+        predicate: Predicate,
+    ) -> Result<(), ConstraintOperationError> {
+        if self.state.is_infeasible() {
+            return Err(ConstraintOperationError::InfeasibleState);
+        }
 
-            if self.state.no_conflict() {
-                Ok(())
-            } else {
-                Err(ConstraintOperationError::InfeasiblePropagator)
-            }
+        let literal = self.get_literal(predicate);
+
+        let proof = &mut self.internal_parameters.proof;
+        if let Ok(inference) = proof.log_inference(self.posting_tag, None, [], Some(literal)) {
+            let _ = proof.log_nogood([!literal], [inference]);
         }
+
+        self.add_clause([literal])
     }
 
-    /// Creates a clause from `literals` and adds it to the current formula.
+    /// Adds all of the provided clauses to the current formula.
     ///
-    /// If the formula becomes trivially unsatisfiable, a [`ConstraintOperationError`] will be
-    /// returned. Subsequent calls to this method will always return an error, and no
-    /// modification of the solver will take place.
-    pub fn add_clause(
+    /// As opposed to [`ConstraintSatisfactionSolver::add_clause`], propagation (other than
+    /// propagating unit clauses) only takes place once all of the clauses have been added. Once
+    /// the formula becomes unsatisfiable at the root, the remaining clauses are not added and a
+    /// [`ConstraintOperationError`] is returned.
+    pub fn add_clauses(
         &mut self,
-        literals: impl IntoIterator<Item = Literal>,
+        clauses: impl IntoIterator<Item = Vec<Literal>>,
     ) -> Result<(), ConstraintOperationError> {
         munchkin_assert_moderate!(!self.state.is_infeasible_under_assumptions());
         munchkin_assert_moderate!(self.is_propagation_complete());
@@ -1442,29 +3469,205 @@ impl ConstraintSatisfactionSolver {
         if self.state.is_infeasible() {
             return Err(ConstraintOperationError::InfeasibleState);
         }
+        if self.state.is_suspended() {
+            return Err(ConstraintOperationError::SuspendedSearch);
+        }
 
-        let literals: Vec<Literal> = literals.into_iter().collect();
+        let num_trail_entries = self.assignments_integer.num_trail_entries();
 
-        let result = self.clausal_propagator.add_permanent_clause(
-            literals,
-            &mut self.assignments_propositional,
-            &mut self.clause_allocator,
-        );
+        for clause in clauses {
+            let root_unit = self.get_root_unit(&clause);
+            let num_permanent_clauses = self.clausal_propagator.permanent_clauses.len();
+            let num_propositional_trail_entries =
+                self.assignments_propositional.num_trail_entries();
 
-        if result.is_err() {
-            self.state.declare_infeasible();
-            return Err(ConstraintOperationError::InfeasibleClause);
+            let result = self.clausal_propagator.add_permanent_clause(
+                clause,
+                &mut self.assignments_propositional,
+                &mut self.clause_allocator,
+            );
+
+            if result.is_err() {
+                self.state.declare_infeasible();
+                return Err(ConstraintOperationError::InfeasibleClause);
+            }
+            self.record_clause_artifact(num_permanent_clauses, num_propositional_trail_entries);
+
+            if let Some((unit, falsified_literals)) = root_unit {
+                self.log_root_unit(unit, falsified_literals);
+            }
         }
 
         self.propagate_enqueued(&mut Indefinite);
+        self.log_root_propagations(num_trail_entries);
 
-        if self.state.is_infeasible() {
+        if self.state.conflicting() {
             self.state.declare_infeasible();
             return Err(ConstraintOperationError::InfeasibleClause);
         }
 
         Ok(())
     }
+
+    /// Sets the tag of the constraint which is being posted, under which the clauses which are
+    /// added are recorded (see [`ConstraintSatisfactionSolver::tag_artifacts`]); the previous tag
+    /// is returned such that it can be restored once the constraint is posted.
+    pub(crate) fn set_posting_tag(&mut self, tag: Option<NonZero<u32>>) -> Option<NonZero<u32>> {
+        std::mem::replace(&mut self.posting_tag, tag)
+    }
+
+    /// Returns an estimate of the number of bytes used by the major data structures of the
+    /// solver; see [`ConstraintSatisfactionSolver::size_snapshot`].
+    pub fn approximate_memory_usage(&self) -> usize {
+        self.size_snapshot().total_bytes()
+    }
+
+    /// Returns an estimate of the memory used by the clauses, the assignments, the watch lists
+    /// and the reasons, which is passed to the [`TerminationCondition`] after every conflict.
+    pub fn size_snapshot(&self) -> SolverSizeSnapshot {
+        SolverSizeSnapshot {
+            clause_bytes: self.clause_allocator.approximate_memory_usage(),
+            assignment_bytes: self.assignments_propositional.approximate_memory_usage()
+                + self.assignments_integer.approximate_memory_usage(),
+            watch_list_bytes: self
+                .clausal_propagator
+                .approximate_memory_usage(self.clause_allocator.num_clause_slots())
+                + self.watch_list_cp.approximate_memory_usage()
+                + self.watch_list_propositional.approximate_memory_usage(),
+            reason_bytes: self.reason_store.approximate_memory_usage(),
+        }
+    }
+
+    /// Returns the artifacts which were created by posting the constraints with the provided
+    /// `tag`, or [`None`] if no proof is logged or the tag did not create any artifacts.
+    pub fn tag_artifacts(&self, tag: NonZero<u32>) -> Option<&TagArtifacts> {
+        self.tag_artifacts.get(&tag)
+    }
+
+    /// Records the clause which was just added under the tag of the constraint which is being
+    /// posted; if the clause was reduced to a single literal, then that literal is recorded
+    /// instead.
+    fn record_clause_artifact(
+        &mut self,
+        num_permanent_clauses: usize,
+        num_propositional_trail_entries: usize,
+    ) {
+        let Some(tag) = self.posting_tag else {
+            return;
+        };
+        if !self.internal_parameters.proof.is_logging() {
+            return;
+        }
+
+        let artifacts = self.tag_artifacts.entry(tag).or_default();
+        if self.clausal_propagator.permanent_clauses.len() > num_permanent_clauses {
+            artifacts.add_clause(self.clausal_propagator.permanent_clauses[num_permanent_clauses]);
+        } else if self.assignments_propositional.num_trail_entries()
+            > num_propositional_trail_entries
+        {
+            artifacts.add_unit_literal(
+                self.assignments_propositional
+                    .get_trail_entry(num_propositional_trail_entries),
+            );
+        }
+    }
+
+    /// If the clause is reduced to a single literal which is unassigned at the root, then this
+    /// literal is returned together with the other literals of the clause (which are all falsified
+    /// at the root). This is only determined when a proof is being logged, as the derived unit is
+    /// only needed to log it to the proof.
+    fn get_root_unit(&self, literals: &[Literal]) -> Option<(Literal, Vec<Literal>)> {
+        if !self.internal_parameters.proof.is_logging()
+            || literals.contains(&self.true_literal)
+            || self.get_decision_level() > 0
+        {
+            return None;
+        }
+
+        let (falsified_literals, remaining_literals): (Vec<_>, Vec<_>) =
+            literals.iter().partition(|&&literal| {
+                self.assignments_propositional
+                    .is_literal_assigned_false(literal)
+            });
+
+        // A clause without falsified literals is not reduced, it is part of the model itself
+        let unit = *remaining_literals.first()?;
+        if falsified_literals.is_empty()
+            || remaining_literals.iter().any(|&literal| literal != unit)
+            || self.assignments_propositional.is_literal_assigned(unit)
+        {
+            return None;
+        }
+
+        Some((unit, falsified_literals))
+    }
+
+    /// Logs the unit which was derived at the root from a clause to the proof. The derivation from
+    /// the clause is logged as an inference, which is the hint of the nogood that states the unit.
+    fn log_root_unit(&mut self, unit: Literal, falsified_literals: Vec<Literal>) {
+        let proof = &mut self.internal_parameters.proof;
+
+        let Ok(inference) = proof.log_inference(
+            None,
+            None,
+            falsified_literals.into_iter().map(|literal| !literal),
+            Some(unit),
+        ) else {
+            return;
+        };
+        let _ = proof.log_nogood([!unit], [inference]);
+    }
+
+    /// Logs the propagations made by propagators at the root since the integer trail contained
+    /// `num_trail_entries` entries. Every propagation is logged as an inference, explained by the
+    /// propagator, which is the hint of the nogood that states the propagated predicate.
+    ///
+    /// Without these steps, the proof would refer to facts which were derived while posting the
+    /// constraints, but which cannot be justified by the proof checker.
+    fn log_root_propagations(&mut self, num_trail_entries: usize) {
+        if !self.internal_parameters.proof.is_logging() || self.get_decision_level() > 0 {
+            return;
+        }
+
+        for index in num_trail_entries..self.assignments_integer.num_trail_entries() {
+            let entry = self.assignments_integer.get_trail_entry(index);
+            let Some(reason_ref) = entry.reason else {
+                // The entry was synchronised from the propositional trail
+                continue;
+            };
+
+            let propagator = self.reason_store.get_propagator(reason_ref);
+            let tag = self.propagator_tags[propagator];
+            let label = inference_labels::of_propagator(self.cp_propagators[propagator].name());
+
+            let propagation_context = PropagationContext::new(
+                &self.assignments_integer,
+                &self.assignments_propositional,
+                self.internal_parameters
+                    .use_non_generic_conflict_explanation,
+                self.internal_parameters
+                    .use_non_generic_propagation_explanation,
+            );
+            // The reason is cloned since computing it borrows the reason store mutably
+            let reason = self
+                .reason_store
+                .get_or_compute(reason_ref, &propagation_context)
+                .expect("reason reference should not be stale")
+                .clone();
+            let premises = reason
+                .iter()
+                .map(|&predicate| self.get_literal(predicate))
+                .collect::<Vec<_>>();
+            let propagated = self.get_literal(entry.predicate.into());
+
+            let proof = &mut self.internal_parameters.proof;
+            let Ok(inference) = proof.log_inference(Some(tag), label, premises, Some(propagated))
+            else {
+                return;
+            };
+            let _ = proof.log_nogood([!propagated], [inference]);
+        }
+    }
 }
 
 // methods for getting simple info out of the solver
@@ -1506,6 +3709,131 @@ impl CumulativeMovingAverage {
     }
 }
 
+/// A handle to a constraint which was added using [`Solver::add_removable_constraint`]; it can be
+/// used to remove the constraint again using [`Solver::remove_constraint`].
+#[derive(Debug)]
+pub struct ConstraintHandle {
+    /// The literal which activates the constraint; the constraint is posted as
+    /// `activation_literal -> constraint`.
+    pub(crate) activation_literal: Literal,
+    /// The propagators which were added when posting the constraint.
+    propagators: Vec<PropagatorId>,
+    /// The clauses which were added when posting the constraint.
+    clauses: Vec<ClauseReference>,
+}
+
+/// Identifies a snapshot of the root state of the solver which was taken using
+/// [`Solver::snapshot`]; the solver can be rolled back to it using [`Solver::rollback_to`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SnapshotId(usize);
+
+/// The sizes of the structures of the [`ConstraintSatisfactionSolver`] at the moment a snapshot
+/// was taken; everything beyond these is removed when rolling back to the snapshot.
+#[derive(Debug, Clone)]
+struct RootSnapshot {
+    num_propagators: usize,
+    num_permanent_clauses: usize,
+    /// The number of binary implications of every literal; see
+    /// [`ClausalPropagator::num_binary_implications`].
+    num_binary_implications: Vec<usize>,
+    num_domains: u32,
+    num_activation_literals: usize,
+    num_propositional_trail_entries: usize,
+    num_integer_trail_entries: usize,
+    num_reasons: usize,
+    num_entailed_propagators: usize,
+    /// Whether the solver was already infeasible at the root when the snapshot was taken.
+    is_infeasible: bool,
+}
+
+/// A read-only snapshot of the search effort of the [`ConstraintSatisfactionSolver`].
+///
+/// The snapshot is taken from the live counters of the solver, so it can be queried in between
+/// (or during) solve calls to guide adaptive procedures; see [`Solver::statistics`].
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SearchStatistics {
+    /// The number of conflicts encountered.
+    pub conflicts: u64,
+    /// The number of decisions made by the brancher.
+    pub decisions: u64,
+    /// The number of domain changes made through propagation.
+    pub propagations: u64,
+    /// The number of restarts performed.
+    pub restarts: u64,
+    /// The highest decision level reached during search.
+    pub peak_decision_level: u32,
+    /// The number of times that propagation did not reach a fixed point within
+    /// [`SatisfactionSolverOptions::propagation_loop_threshold`] round trips.
+    pub propagation_loops: u64,
+}
+
+/// A summary of the size of the problem in the solver; see [`Solver::model_summary`].
+///
+/// The [`Display`] implementation gives a short human-readable overview, which is suitable to be
+/// printed before solving.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct ModelSummary {
+    /// The number of integer variables.
+    pub num_integer_variables: usize,
+    /// The number of propositional variables created through [`Solver::new_literal`] (including
+    /// those created by constraints when they are posted).
+    pub num_user_propositional_variables: usize,
+    /// The number of propositional variables which encode the domains of the integer variables.
+    pub num_encoding_propositional_variables: usize,
+    /// The number of propagators with a particular name, sorted by name.
+    pub propagators: Vec<(String, usize)>,
+    /// The number of stored clauses which were added to the solver; unit clauses are not stored
+    /// but are applied as root assignments instead.
+    pub num_original_clauses: usize,
+    /// The number of stored clauses which were learned during search.
+    pub num_learned_clauses: usize,
+}
+
+impl ModelSummary {
+    /// The total number of propagators.
+    pub fn num_propagators(&self) -> usize {
+        self.propagators.iter().map(|(_, count)| count).sum()
+    }
+}
+
+impl Display for ModelSummary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "integer variables: {}", self.num_integer_variables)?;
+        writeln!(
+            f,
+            "propositional variables: {} user, {} encoding",
+            self.num_user_propositional_variables, self.num_encoding_propositional_variables
+        )?;
+        writeln!(f, "propagators: {}", self.num_propagators())?;
+        for (name, count) in &self.propagators {
+            writeln!(f, "  {name}: {count}")?;
+        }
+        write!(
+            f,
+            "clauses: {} original, {} learned",
+            self.num_original_clauses, self.num_learned_clauses
+        )
+    }
+}
+
+/// The effort spent by a single propagator; see [`Solver::propagator_statistics`].
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PropagatorCounters {
+    /// The number of times the propagator was called to propagate.
+    pub num_invocations: u64,
+    /// The number of domain changes made by the propagator.
+    pub num_domain_reductions: u64,
+    /// The number of conflicts reported by the propagator (including empty domains).
+    pub num_conflicts: u64,
+    /// The number of potential propagation loops in which the propagator was one of the most
+    /// recently invoked propagators; see
+    /// [`SatisfactionSolverOptions::propagation_loop_threshold`].
+    pub num_propagation_loops: u64,
+    /// The time spent propagating; this is only measured if
+    /// [`SatisfactionSolverOptions::time_propagators`] is set.
+    pub time_spent: Duration,
+}
+
 /// Structure responsible for storing several statistics of the solving process of the
 /// [`ConstraintSatisfactionSolver`].
 #[derive(Default, Debug, Copy, Clone)]
@@ -1513,6 +3841,19 @@ pub(crate) struct Counters {
     pub(crate) num_decisions: u64,
     pub(crate) num_conflicts: u64,
     num_propagations: u64,
+    num_restarts: u64,
+    num_conflicts_at_last_restart: u64,
+    num_probes: u64,
+    num_probing_conflicts: u64,
+    num_root_assignments_derived: u64,
+    num_satisfied_clauses_removed: u64,
+    num_falsified_literals_removed: u64,
+    num_propagators_entailed: u64,
+    num_domains_compacted: u64,
+    num_compaction_clauses_removed: u64,
+    num_compaction_implications_removed: u64,
+    num_propagation_loops: u64,
+    peak_decision_level: u32,
     time_spent_in_solver: u64,
 
     pub(crate) average_conflict_size: CumulativeMovingAverage,
@@ -1524,14 +3865,54 @@ pub(crate) struct Counters {
     average_number_of_literals_removed_semantic: CumulativeMovingAverage,
     average_number_of_literals_removed_recursive: CumulativeMovingAverage,
     average_number_of_literals_removed_minimisation: CumulativeMovingAverage,
+
+    pub(crate) core_minimisation_removed: StatisticAccumulator,
 }
 
 impl Counters {
+    fn snapshot(&self) -> SearchStatistics {
+        SearchStatistics {
+            conflicts: self.num_conflicts,
+            decisions: self.num_decisions,
+            propagations: self.num_propagations,
+            restarts: self.num_restarts,
+            peak_decision_level: self.peak_decision_level,
+            propagation_loops: self.num_propagation_loops,
+        }
+    }
+
     fn log_statistics(&self) {
-        log_statistic("numberOfDecisions", self.num_decisions);
-        log_statistic("numberOfConflicts", self.num_conflicts);
-        log_statistic("numberOfPropagations", self.num_propagations);
+        let statistics = self.snapshot();
+        log_statistic("numberOfDecisions", statistics.decisions);
+        log_statistic("numberOfConflicts", statistics.conflicts);
+        log_statistic("numberOfPropagations", statistics.propagations);
+        log_statistic("numberOfRestarts", statistics.restarts);
+        log_statistic("peakDecisionLevel", statistics.peak_decision_level);
+        log_statistic("numberOfPropagationLoops", statistics.propagation_loops);
         log_statistic("timeSpentInSolverInMilliseconds", self.time_spent_in_solver);
+        log_statistic("probesPerformed", self.num_probes);
+        log_statistic("rootAssignmentsDerived", self.num_root_assignments_derived);
+        log_statistic(
+            "rootSimplificationClausesRemoved",
+            self.num_satisfied_clauses_removed,
+        );
+        log_statistic(
+            "rootSimplificationLiteralsRemoved",
+            self.num_falsified_literals_removed,
+        );
+        log_statistic(
+            "rootSimplificationPropagatorsEntailed",
+            self.num_propagators_entailed,
+        );
+        log_statistic("domainCompactionDomains", self.num_domains_compacted);
+        log_statistic(
+            "domainCompactionClausesRemoved",
+            self.num_compaction_clauses_removed,
+        );
+        log_statistic(
+            "domainCompactionImplicationsRemoved",
+            self.num_compaction_implications_removed,
+        );
 
         log_statistic(
             "averageSizeOfConflictExplanation",
@@ -1563,6 +3944,20 @@ impl Counters {
             "averageNumberOfLiteralsRemovedNogoodMinimisation",
             self.average_number_of_literals_removed_minimisation.value(),
         );
+
+        self.core_minimisation_removed
+            .log_statistics("coreMinimisationRemoved");
+    }
+}
+
+/// A [`Brancher`] which is used to backtrack when no brancher is available; since it never makes
+/// a decision, it does not need to be notified of the unassigned variables.
+#[derive(Debug)]
+struct NoOpBrancher;
+
+impl Brancher for NoOpBrancher {
+    fn next_decision(&mut self, _context: &mut SelectionContext) -> Option<Predicate> {
+        None
     }
 }
 
@@ -1582,6 +3977,9 @@ enum CSPSolverStateInternal {
         violated_assumption: Literal,
     },
     Timeout,
+    /// The search was interrupted by
+    /// [`ConstraintSatisfactionSolver::solve_with_decision_budget`] and can be resumed.
+    Suspended,
 }
 
 #[derive(Default, Debug)]
@@ -1645,6 +4043,10 @@ impl CSPSolverState {
         matches!(self.internal_state, CSPSolverStateInternal::Timeout)
     }
 
+    pub(crate) fn is_suspended(&self) -> bool {
+        matches!(self.internal_state, CSPSolverStateInternal::Suspended)
+    }
+
     #[allow(unused)]
     pub(crate) fn has_solution(&self) -> bool {
         matches!(
@@ -1680,6 +4082,11 @@ impl CSPSolverState {
         self.internal_state = CSPSolverStateInternal::Timeout;
     }
 
+    fn declare_suspended(&mut self) {
+        munchkin_assert_simple!(!self.is_infeasible());
+        self.internal_state = CSPSolverStateInternal::Suspended;
+    }
+
     fn declare_infeasible_under_assumptions(&mut self, violated_assumption: Literal) {
         munchkin_assert_simple!(!self.is_infeasible());
         self.internal_state = CSPSolverStateInternal::InfeasibleUnderAssumptions {
@@ -1690,9 +4097,39 @@ impl CSPSolverState {
 
 #[cfg(test)]
 mod tests {
+    use std::cell::Cell;
+    use std::num::NonZero;
+    use std::rc::Rc;
+
     use super::ConstraintSatisfactionSolver;
+    use super::SatisfactionSolverOptions;
+    use crate::asserts::AssertLevel;
+    use crate::basic_types::CSPSolverExecutionFlag;
+    use crate::basic_types::PropagationStatusCP;
+    use crate::basic_types::StorageKey;
+    use crate::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
+    use crate::branching::InDomainMaxValue;
+    use crate::branching::InDomainMin;
+    use crate::branching::InputOrder;
+    use crate::engine::cp::domain_events::DomainEvents;
+    use crate::engine::cp::propagation::EnqueueDecision;
+    use crate::engine::cp::propagation::LocalId;
+    use crate::engine::cp::propagation::PropagationContext;
+    use crate::engine::cp::propagation::PropagationContextMut;
+    use crate::engine::cp::propagation::Propagator;
+    use crate::engine::cp::propagation::PropagatorId;
+    use crate::engine::cp::propagation::PropagatorInitialisationContext;
+    use crate::engine::cp::propagation::ReadDomains;
     use crate::engine::cp::reason::ReasonRef;
+    use crate::engine::cp::IntDomainEvent;
+    use crate::engine::variables::DomainId;
     use crate::predicate;
+    use crate::predicates::PropositionalConjunction;
+    use crate::propagators::arithmetic::linear_less_or_equal::LinearLessOrEqualPropagator;
+    use crate::results::solution_iterator::IteratedSolution;
+    use crate::results::solution_iterator::SolutionIterator;
+    use crate::results::ProblemSolution;
+    use crate::termination::Indefinite;
 
     #[test]
     fn negative_upper_bound() {
@@ -1831,4 +4268,398 @@ mod tests {
             }
         }
     }
+
+    /// Sleeps whenever it propagates, without changing any domain.
+    struct SlowPropagator {
+        watched: DomainId,
+    }
+
+    impl Propagator for SlowPropagator {
+        fn name(&self) -> &str {
+            "Slow"
+        }
+
+        fn propagate(&self, _context: PropagationContextMut) -> PropagationStatusCP {
+            std::thread::sleep(std::time::Duration::from_millis(5));
+            Ok(())
+        }
+
+        fn initialise_at_root(
+            &mut self,
+            context: &mut PropagatorInitialisationContext,
+        ) -> Result<(), PropositionalConjunction> {
+            context.register(self.watched, DomainEvents::LOWER_BOUND);
+            Ok(())
+        }
+    }
+
+    /// Sets the lower bound of a variable to 1.
+    struct FastPropagator {
+        variable: DomainId,
+    }
+
+    impl Propagator for FastPropagator {
+        fn name(&self) -> &str {
+            "Fast"
+        }
+
+        fn propagate(&self, mut context: PropagationContextMut) -> PropagationStatusCP {
+            context.set_lower_bound(&self.variable, 1, PropositionalConjunction::default())?;
+            Ok(())
+        }
+
+        fn initialise_at_root(
+            &mut self,
+            _context: &mut PropagatorInitialisationContext,
+        ) -> Result<(), PropositionalConjunction> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn propagator_statistics_are_sorted_by_time_spent() {
+        let mut solver = ConstraintSatisfactionSolver::new(SatisfactionSolverOptions {
+            time_propagators: true,
+            ..Default::default()
+        });
+        let x = solver.create_new_integer_variable(0, 10, None);
+
+        let result =
+            solver.add_propagator(FastPropagator { variable: x }, NonZero::new(1).unwrap());
+        assert!(result.is_ok());
+        // The slow propagator is invoked once when it is added and once more when the lower bound
+        // of `x` is changed
+        let result = solver.add_propagator(SlowPropagator { watched: x }, NonZero::new(2).unwrap());
+        assert!(result.is_ok());
+        solver.declare_new_decision_level();
+        let _ = solver.assignments_integer.tighten_lower_bound(x, 2, None);
+        let _ = solver.process_domain_events();
+        solver.propagate_enqueued(&mut Indefinite);
+
+        let statistics = solver.propagator_statistics();
+        assert_eq!(statistics.len(), 2);
+
+        let (slow_name, slow_counters) = statistics[0];
+        assert_eq!(slow_name, "Slow");
+        assert_eq!(slow_counters.num_invocations, 2);
+        assert_eq!(slow_counters.num_domain_reductions, 0);
+
+        let (fast_name, fast_counters) = statistics[1];
+        assert_eq!(fast_name, "Fast");
+        assert_eq!(fast_counters.num_invocations, 1);
+        assert_eq!(fast_counters.num_domain_reductions, 1);
+        assert_eq!(fast_counters.num_conflicts, 0);
+    }
+
+    /// Propagates `to >= from`, counting the number of times it is notified of a change to the
+    /// lower bound of `from`.
+    struct CopyLowerBound {
+        from: DomainId,
+        to: DomainId,
+        num_notifications: Rc<Cell<u64>>,
+    }
+
+    impl Propagator for CopyLowerBound {
+        fn name(&self) -> &str {
+            "CopyLowerBound"
+        }
+
+        fn notify(
+            &mut self,
+            _context: PropagationContext,
+            _local_id: LocalId,
+            _event: IntDomainEvent,
+        ) -> EnqueueDecision {
+            self.num_notifications.set(self.num_notifications.get() + 1);
+            EnqueueDecision::Enqueue
+        }
+
+        fn propagate(&self, mut context: PropagationContextMut) -> PropagationStatusCP {
+            let lower_bound = context.lower_bound(&self.from);
+            context.set_lower_bound(
+                &self.to,
+                lower_bound,
+                PropositionalConjunction::from(vec![predicate![self.from >= lower_bound]]),
+            )?;
+            Ok(())
+        }
+
+        fn initialise_at_root(
+            &mut self,
+            context: &mut PropagatorInitialisationContext,
+        ) -> Result<(), PropositionalConjunction> {
+            context.register(self.from, DomainEvents::LOWER_BOUND);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn bound_literals_propagated_by_clausal_propagator_do_not_renotify_propagators() {
+        let mut solver = ConstraintSatisfactionSolver::default();
+        let x = solver.create_new_integer_variable(0, 10, None);
+        let y = solver.create_new_integer_variable(0, 10, None);
+
+        let num_notifications = Rc::new(Cell::new(0));
+        let result = solver.add_propagator(
+            CopyLowerBound {
+                from: x,
+                to: y,
+                num_notifications: Rc::clone(&num_notifications),
+            },
+            NonZero::new(1).unwrap(),
+        );
+        assert!(result.is_ok());
+
+        solver.declare_new_decision_level();
+        let decision = solver.get_literal(predicate![x >= 5]);
+        solver
+            .assignments_propositional
+            .enqueue_decision_literal(decision);
+        solver.propagate_enqueued(&mut Indefinite);
+        assert!(!solver.state.conflicting());
+
+        // The clausal propagator sets the weaker bound literals of both variables, but these
+        // already hold in the integer domains and do not lead to further notifications
+        for bound in 1..=5 {
+            let x_literal = solver.get_literal(predicate![x >= bound]);
+            let y_literal = solver.get_literal(predicate![y >= bound]);
+            assert!(solver
+                .assignments_propositional
+                .is_literal_assigned_true(x_literal));
+            assert!(solver
+                .assignments_propositional
+                .is_literal_assigned_true(y_literal));
+        }
+        assert_eq!(num_notifications.get(), 1);
+        assert_eq!(solver.statistics().propagation_loops, 0);
+    }
+
+    #[test]
+    fn propagation_exceeding_the_loop_threshold_is_reported_once() {
+        let mut solver = ConstraintSatisfactionSolver::new(SatisfactionSolverOptions {
+            propagation_loop_threshold: Some(3),
+            ..Default::default()
+        });
+        let xs = (0..10)
+            .map(|_| solver.create_new_integer_variable(0, 10, None))
+            .collect::<Vec<_>>();
+
+        for pair in xs.windows(2) {
+            let result = solver.add_propagator(
+                CopyLowerBound {
+                    from: pair[0],
+                    to: pair[1],
+                    num_notifications: Rc::default(),
+                },
+                NonZero::new(1).unwrap(),
+            );
+            assert!(result.is_ok());
+        }
+        assert_eq!(solver.statistics().propagation_loops, 0);
+
+        solver.declare_new_decision_level();
+        let _ = solver
+            .assignments_integer
+            .tighten_lower_bound(xs[0], 1, None);
+        let _ = solver.process_domain_events();
+        solver.propagate_enqueued(&mut Indefinite);
+
+        assert_eq!(solver.assignments_integer.get_lower_bound(xs[9]), 1);
+        assert_eq!(solver.statistics().propagation_loops, 1);
+
+        // Only the propagators which were invoked before the threshold was reached are involved
+        let num_involved = solver
+            .propagator_statistics()
+            .into_iter()
+            .filter(|(_, counters)| counters.num_propagation_loops > 0)
+            .count();
+        assert_eq!(num_involved, 3);
+    }
+
+    #[test]
+    fn simplify_root_detaches_entailed_propagators() {
+        let mut solver = ConstraintSatisfactionSolver::default();
+        let x = solver.create_new_integer_variable(0, 10, None);
+        let y = solver.create_new_integer_variable(0, 10, None);
+
+        let result = solver.add_propagator(
+            LinearLessOrEqualPropagator::new([x, y].into(), 12),
+            NonZero::new(1).unwrap(),
+        );
+        assert!(result.is_ok());
+        let propagator = PropagatorId(0);
+
+        solver.simplify_root();
+        assert!(!solver.is_propagator_entailed(propagator));
+
+        let x_at_most_5 = solver.get_literal(predicate![x <= 5]);
+        let y_at_most_7 = solver.get_literal(predicate![y <= 7]);
+        assert!(solver
+            .add_clauses([vec![x_at_most_5], vec![y_at_most_7]])
+            .is_ok());
+
+        solver.simplify_root();
+        assert!(solver.is_propagator_entailed(propagator));
+
+        // The detached propagator is no longer enqueued when its variables change
+        let x_at_least_5 = solver.get_literal(predicate![x >= 5]);
+        assert!(solver.add_clause([x_at_least_5]).is_ok());
+        assert_eq!(solver.propagator_counters[propagator].num_invocations, 1);
+    }
+
+    /// Creates a solver with a variable in [0, 10^5] which is limited to [0, 50] at the root.
+    ///
+    /// The advanced checks are disabled, since checking that each of the removed clauses is
+    /// deleted only once takes quadratic time.
+    fn solver_with_tightened_domain() -> (ConstraintSatisfactionSolver, DomainId) {
+        let mut solver = ConstraintSatisfactionSolver::new(SatisfactionSolverOptions {
+            assert_level: AssertLevel::Moderate,
+            ..Default::default()
+        });
+        let x = solver.create_new_integer_variable(0, 100_000, None);
+        let result = solver.add_propagator(
+            LinearLessOrEqualPropagator::new([x].into(), 50),
+            NonZero::new(1).unwrap(),
+        );
+        assert!(result.is_ok());
+
+        (solver, x)
+    }
+
+    /// Returns the number of watchers of the clauses and the number of binary implications.
+    fn count_watchers(solver: &ConstraintSatisfactionSolver) -> usize {
+        let num_clause_watchers = solver
+            .clausal_propagator
+            .watch_lists
+            .iter()
+            .map(Vec::len)
+            .sum::<usize>();
+        let num_implications = solver
+            .clausal_propagator
+            .num_binary_implications()
+            .iter()
+            .sum::<usize>();
+
+        num_clause_watchers + num_implications
+    }
+
+    #[test]
+    fn compacting_domains_removes_the_clauses_of_values_outside_the_root_bounds() {
+        let (mut solver, x) = solver_with_tightened_domain();
+        let num_clauses = solver.clausal_propagator.permanent_clauses.len();
+        let num_watchers = count_watchers(&solver);
+
+        solver.compact_domains();
+
+        // The representation of the 51 values in the root domain remains, as well as the clause
+        // which states that at least one of the values is taken
+        let compacted_num_clauses = solver.clausal_propagator.permanent_clauses.len();
+        let compacted_num_watchers = count_watchers(&solver);
+        assert!(num_clauses > 99_000);
+        assert!(compacted_num_clauses <= 51);
+        assert!(num_watchers > 500_000);
+        assert!(compacted_num_watchers <= 500);
+        assert_eq!(solver.counters.num_domains_compacted, 1);
+        assert_eq!(
+            solver.counters.num_compaction_clauses_removed as usize,
+            num_clauses - compacted_num_clauses
+        );
+
+        let mut brancher =
+            IndependentVariableValueBrancher::new(InputOrder::new(vec![x]), InDomainMaxValue);
+        let flag = solver.solve(&mut Indefinite, &mut brancher);
+        assert!(matches!(flag, CSPSolverExecutionFlag::Feasible));
+        assert_eq!(solver.get_assigned_integer_value(&x), Some(50));
+    }
+
+    #[test]
+    fn compacted_domains_keep_their_values_within_the_root_bounds() {
+        let (mut solver, x) = solver_with_tightened_domain();
+        solver.compact_domains();
+
+        let mut brancher =
+            IndependentVariableValueBrancher::new(InputOrder::new(vec![x]), InDomainMin);
+        let mut termination = Indefinite;
+        let mut solution_iterator =
+            SolutionIterator::new(&mut solver, &mut brancher, &mut termination);
+        let mut values = vec![];
+        while let IteratedSolution::Solution(solution) = solution_iterator.next_solution() {
+            values.push(solution.get_integer_value(x));
+        }
+        drop(solution_iterator);
+
+        values.sort();
+        assert_eq!(values, (0..=50).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn domains_are_compacted_after_the_first_root_propagation_when_solving() {
+        let (mut solver, x) = solver_with_tightened_domain();
+
+        let mut brancher =
+            IndependentVariableValueBrancher::new(InputOrder::new(vec![x]), InDomainMin);
+        let flag = solver.solve(&mut Indefinite, &mut brancher);
+
+        assert!(matches!(flag, CSPSolverExecutionFlag::Feasible));
+        assert_eq!(solver.counters.num_domains_compacted, 1);
+        assert!(solver.clausal_propagator.permanent_clauses.len() <= 51);
+    }
+
+    #[test]
+    fn domains_are_not_compacted_while_snapshots_exist() {
+        let (mut solver, _) = solver_with_tightened_domain();
+        let num_clauses = solver.clausal_propagator.permanent_clauses.len();
+        let _ = solver.snapshot();
+
+        solver.compact_domains();
+
+        assert_eq!(
+            solver.clausal_propagator.permanent_clauses.len(),
+            num_clauses
+        );
+        assert_eq!(solver.counters.num_domains_compacted, 0);
+    }
+
+    #[test]
+    fn literal_batch_creates_consecutive_unassigned_variables_with_a_single_reservation() {
+        const NUM_LITERALS: usize = 100_000;
+
+        let mut solver = ConstraintSatisfactionSolver::default();
+        let num_variables_before = solver
+            .assignments_propositional
+            .num_propositional_variables();
+        let capacity_before = solver.propositional_variable_capacity();
+        assert!(capacity_before < NUM_LITERALS);
+
+        let literals = solver.new_literal_batch(NUM_LITERALS);
+
+        assert_eq!(literals.len(), NUM_LITERALS);
+        for (offset, literal) in literals.iter().enumerate() {
+            assert!(literal.is_positive());
+            assert_eq!(
+                literal.get_propositional_variable().index(),
+                num_variables_before as usize + offset
+            );
+            assert!(!solver
+                .assignments_propositional
+                .is_literal_assigned(*literal));
+        }
+        assert_eq!(
+            solver.num_user_propositional_variables, NUM_LITERALS,
+            "the literals are created for the user"
+        );
+
+        // Growing one variable at a time doubles the capacity whenever it is exceeded, which
+        // would leave spare capacity; reserving once allocates exactly the required space
+        let num_variables = num_variables_before as usize + NUM_LITERALS;
+        assert_eq!(solver.propositional_variable_capacity(), num_variables);
+
+        // The lazy iterator still creates one variable at a time
+        let next = solver.new_literals().next().unwrap();
+        assert_eq!(
+            next.get_propositional_variable().index(),
+            num_variables,
+            "the iterator continues after the batch"
+        );
+    }
 }
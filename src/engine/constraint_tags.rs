@@ -0,0 +1,152 @@
+use std::fmt::Display;
+use std::num::NonZero;
+
+use crate::basic_types::ClauseReference;
+use crate::basic_types::ConstraintReference;
+use crate::basic_types::HashMap;
+use crate::basic_types::HashSet;
+use crate::engine::cp::propagation::PropagatorId;
+use crate::engine::variables::Literal;
+#[cfg(doc)]
+use crate::Solver;
+
+/// Keeps track of the tags of the original constraints which are responsible for the conflicts
+/// encountered by the solver, which allows the solver to explain why an instance is
+/// unsatisfiable in terms of the constraints which were posted.
+///
+/// The tags of the propagators are known directly, but the literals which are derived from a
+/// conflict (i.e. the literals propagated by learned clauses and the negated decisions which are
+/// enqueued when no learning is used) are only justified by the conflict which derived them. For
+/// these, the union of the tags of the constraints in the conflict is stored.
+///
+/// Note that clauses which are added directly to the solver are not tagged, so these do not
+/// contribute to the explanation.
+#[derive(Debug, Default)]
+pub(crate) struct ConstraintTagTracker {
+    /// Whether the tags of the derived literals are recorded; this requires the implication
+    /// graph of every conflict to be traversed.
+    is_enabled: bool,
+    /// The tags of the literals which were enqueued without a reason after a conflict.
+    asserted_literals: HashMap<Literal, HashSet<NonZero<u32>>>,
+    /// The tags of the learned clauses.
+    learned_clauses: HashMap<ClauseReference, HashSet<NonZero<u32>>>,
+    /// The tags of the constraints which are responsible for the conflict at the root, if one has
+    /// been encountered.
+    root_conflict: Option<HashSet<NonZero<u32>>>,
+}
+
+impl ConstraintTagTracker {
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.is_enabled
+    }
+
+    /// Starts (or stops) recording the tags of the literals which are derived from conflicts;
+    /// the previously recorded tags are discarded.
+    pub(crate) fn set_enabled(&mut self, is_enabled: bool) {
+        self.is_enabled = is_enabled;
+        self.asserted_literals.clear();
+        self.learned_clauses.clear();
+    }
+
+    /// Records the tags of the conflict which led to `literal` being enqueued with the reason
+    /// `reference` during conflict resolution.
+    pub(crate) fn record_derived_literal(
+        &mut self,
+        literal: Literal,
+        reference: ConstraintReference,
+        tags: HashSet<NonZero<u32>>,
+    ) {
+        if reference.is_non_reason() {
+            let _ = self.asserted_literals.insert(literal, tags);
+        } else if reference.is_clause() && !reference.is_virtual_binary_clause() {
+            let _ = self
+                .learned_clauses
+                .insert(reference.as_clause_reference(), tags);
+        }
+    }
+
+    /// Returns the tags of the conflict which derived `literal`, given that it was assigned with
+    /// the reason `reference`.
+    pub(crate) fn get_derived_tags(
+        &self,
+        literal: Literal,
+        reference: ConstraintReference,
+    ) -> Option<&HashSet<NonZero<u32>>> {
+        if reference.is_non_reason() {
+            self.asserted_literals.get(&literal)
+        } else if reference.is_clause() && !reference.is_virtual_binary_clause() {
+            self.learned_clauses.get(&reference.as_clause_reference())
+        } else {
+            None
+        }
+    }
+
+    /// Forgets the tags of a learned clause which is deleted, since its reference can be reused
+    /// by a new clause.
+    pub(crate) fn remove_learned_clause(&mut self, reference: ClauseReference) {
+        let _ = self.learned_clauses.remove(&reference);
+    }
+
+    pub(crate) fn record_root_conflict(&mut self, tags: HashSet<NonZero<u32>>) {
+        self.root_conflict = Some(tags);
+    }
+
+    pub(crate) fn get_root_conflict(&self) -> Option<&HashSet<NonZero<u32>>> {
+        self.root_conflict.as_ref()
+    }
+}
+
+/// The artifacts which were created by posting the constraints with a certain tag: the clauses,
+/// the propagators and the literals which were assigned at the root because a clause was reduced
+/// to a single literal; see [`Solver::tag_artifacts`].
+///
+/// This relates the constraint hints in a proof to the parts of the solver which were
+/// responsible for them, so the artifacts are only recorded while a proof is logged. Note that
+/// the artifacts are not updated when constraints are removed from the solver.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TagArtifacts {
+    clauses: Vec<ClauseReference>,
+    propagators: Vec<PropagatorId>,
+    unit_literals: Vec<Literal>,
+}
+
+impl TagArtifacts {
+    /// Returns the number of clauses which were added.
+    pub fn num_clauses(&self) -> usize {
+        self.clauses.len()
+    }
+
+    /// Returns the number of propagators which were added.
+    pub fn num_propagators(&self) -> usize {
+        self.propagators.len()
+    }
+
+    /// Returns the literals which were assigned at the root instead of adding a clause.
+    pub fn unit_literals(&self) -> &[Literal] {
+        &self.unit_literals
+    }
+
+    pub(crate) fn add_clause(&mut self, clause: ClauseReference) {
+        self.clauses.push(clause);
+    }
+
+    pub(crate) fn add_propagator(&mut self, propagator: PropagatorId) {
+        self.propagators.push(propagator);
+    }
+
+    pub(crate) fn add_unit_literal(&mut self, literal: Literal) {
+        self.unit_literals.push(literal);
+    }
+}
+
+impl Display for TagArtifacts {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} clause(s), {} propagator(s), {} unit literal(s)",
+            self.clauses.len(),
+            self.propagators.len(),
+            self.unit_literals.len()
+        )
+    }
+}
@@ -53,6 +53,12 @@ impl AssignmentsInteger {
         self.trail.len()
     }
 
+    /// Returns the approximate number of bytes used by the domains and the trail; the memory
+    /// owned by the individual domains (e.g. their removed values) is not included.
+    pub fn approximate_memory_usage(&self) -> usize {
+        self.domains.approximate_memory_usage() + self.trail.approximate_memory_usage()
+    }
+
     pub fn get_trail_entry(&self, index: usize) -> ConstraintProgrammingTrailEntry {
         self.trail[index]
     }
@@ -95,10 +101,56 @@ impl AssignmentsInteger {
         id
     }
 
+    /// Creates a new domain containing only the provided `values`, which should be sorted and free
+    /// of duplicates. The values in the gaps are removed without being recorded on the trail.
+    pub fn grow_sparse(&mut self, values: &[i32]) -> DomainId {
+        let id = DomainId {
+            id: self.num_domains(),
+        };
+
+        self.domains
+            .push(IntegerDomainExplicit::new_sparse(values, id));
+
+        self.events.grow();
+        self.backtrack_events.grow();
+
+        id
+    }
+
     pub fn drain_domain_events(&mut self) -> impl Iterator<Item = (IntDomainEvent, DomainId)> + '_ {
         self.events.drain()
     }
 
+    /// Returns the value of the bound which is affected by the provided event (which was drained
+    /// using [`AssignmentsInteger::drain_domain_events`]) before and after the changes that led to
+    /// it; this is the upper bound for [`IntDomainEvent::UpperBound`] and the lower bound for the
+    /// other events.
+    pub(crate) fn get_bound_change(
+        &self,
+        event: IntDomainEvent,
+        domain_id: DomainId,
+    ) -> (i32, i32) {
+        let (old_lower_bound, old_upper_bound) = self.events.get_bounds_before_change(domain_id);
+        match event {
+            IntDomainEvent::UpperBound => (old_upper_bound, self.get_upper_bound(domain_id)),
+            IntDomainEvent::LowerBound | IntDomainEvent::Assign | IntDomainEvent::Removal => {
+                (old_lower_bound, self.get_lower_bound(domain_id))
+            }
+        }
+    }
+
+    pub fn drain_backtrack_domain_events(
+        &mut self,
+    ) -> impl Iterator<Item = (IntDomainEvent, DomainId)> + '_ {
+        self.backtrack_events.drain()
+    }
+
+    /// Discards the domain changes which were undone while backtracking, for when no propagator
+    /// needs to be notified of them.
+    pub fn clear_backtrack_domain_events(&mut self) {
+        self.backtrack_events.clear();
+    }
+
     pub fn debug_create_empty_clone(&self) -> Self {
         let mut domains = self.domains.clone();
         let event_sink = EventSink::new(domains.len());
@@ -133,11 +185,15 @@ impl AssignmentsInteger {
         self.domains[domain_id].initial_upper_bound
     }
 
-    pub fn get_initial_holes(&self, domain_id: DomainId) -> impl Iterator<Item = i32> + '_ {
-        self.domains[domain_id]
-            .initial_removed_values
-            .iter()
-            .copied()
+    /// Returns whether the value is part of the domain with which the variable was created.
+    pub fn is_value_in_initial_domain(&self, domain_id: DomainId, value: i32) -> bool {
+        self.domains[domain_id].is_initial_value(value)
+    }
+
+    /// Returns the values strictly between the bounds which are not in the domain. For a sparse
+    /// domain, only its values are considered; the gaps between those are not holes.
+    pub fn get_holes(&self, domain_id: DomainId) -> impl Iterator<Item = i32> + '_ {
+        self.domains[domain_id].holes()
     }
 
     pub fn get_assigned_value(&self, domain_id: DomainId) -> i32 {
@@ -156,12 +212,9 @@ impl AssignmentsInteger {
         // if not fixed, start with the bounds...
         predicates.push(predicate![domain_id >= domain.lower_bound]);
         predicates.push(predicate![domain_id <= domain.upper_bound]);
-        // then the holes...
-        for i in (domain.lower_bound + 1)..domain.upper_bound {
-            if !domain.is_value_in_domain[domain.get_index(i)] {
-                predicates.push(predicate![domain_id != i]);
-            }
-        }
+        // then the holes; the gaps of a sparse domain are implied by the initial domain, so these
+        // are not described
+        predicates.extend(domain.holes().map(|value| predicate![domain_id != value]));
         predicates
     }
 
@@ -170,6 +223,44 @@ impl AssignmentsInteger {
         domain.contains(value)
     }
 
+    /// Returns the bounds of the domain at the root, i.e. before the changes which were made after
+    /// the first decision.
+    pub fn get_root_bounds(&self, domain_id: DomainId) -> (i32, i32) {
+        self.trail[self.trail.num_root_entries()..]
+            .iter()
+            .find(|entry| entry.predicate.get_domain() == domain_id)
+            .map(|entry| (entry.old_lower_bound, entry.old_upper_bound))
+            .unwrap_or((
+                self.get_lower_bound(domain_id),
+                self.get_upper_bound(domain_id),
+            ))
+    }
+
+    /// Returns whether the value is in the domain at the root, i.e. before the changes which were
+    /// made after the first decision.
+    pub fn is_value_in_root_domain(&self, domain_id: DomainId, value: i32) -> bool {
+        let (lower_bound, upper_bound) = self.get_root_bounds(domain_id);
+        if value < lower_bound || value > upper_bound {
+            return false;
+        }
+
+        let domain = &self.domains[domain_id];
+        // The bit of a value is only cleared when the value itself is removed; if this happened
+        // after the root, then the value was still in the domain at the root
+        domain
+            .get_index(value)
+            .is_some_and(|idx| domain.is_value_in_domain[idx])
+            || self.trail[self.trail.num_root_entries()..]
+                .iter()
+                .any(|entry| {
+                    entry.predicate
+                        == IntegerPredicate::NotEqual {
+                            domain_id,
+                            not_equal_constant: value,
+                        }
+                })
+    }
+
     pub fn is_domain_assigned(&self, domain_id: DomainId) -> bool {
         self.get_lower_bound(domain_id) == self.get_upper_bound(domain_id)
     }
@@ -205,6 +296,8 @@ impl AssignmentsInteger {
             old_upper_bound,
             reason,
         });
+        self.events
+            .record_bounds_before_change(domain_id, old_lower_bound, old_upper_bound);
 
         let domain = &mut self.domains[domain_id];
         domain.set_lower_bound(new_lower_bound, &mut self.events);
@@ -236,6 +329,8 @@ impl AssignmentsInteger {
             old_upper_bound,
             reason,
         });
+        self.events
+            .record_bounds_before_change(domain_id, old_lower_bound, old_upper_bound);
 
         let domain = &mut self.domains[domain_id];
         domain.set_upper_bound(new_upper_bound, &mut self.events);
@@ -288,6 +383,8 @@ impl AssignmentsInteger {
             old_upper_bound,
             reason,
         });
+        self.events
+            .record_bounds_before_change(domain_id, old_lower_bound, old_upper_bound);
 
         let domain = &mut self.domains[domain_id];
         domain.remove_initial_value(removed_value_from_domain, &mut self.events);
@@ -319,6 +416,8 @@ impl AssignmentsInteger {
             old_upper_bound,
             reason,
         });
+        self.events
+            .record_bounds_before_change(domain_id, old_lower_bound, old_upper_bound);
 
         let domain = &mut self.domains[domain_id];
         domain.remove_value(removed_value_from_domain, &mut self.events);
@@ -362,57 +461,100 @@ impl AssignmentsInteger {
     /// Determines whether the provided [`Predicate`] holds in the current state of the
     /// [`AssignmentsInteger`].
     pub fn does_integer_predicate_hold(&self, predicate: IntegerPredicate) -> bool {
-        match predicate {
-            IntegerPredicate::LowerBound {
-                domain_id,
-                lower_bound,
-            } => self.get_lower_bound(domain_id) >= lower_bound,
-            IntegerPredicate::UpperBound {
-                domain_id,
-                upper_bound,
-            } => self.get_upper_bound(domain_id) <= upper_bound,
-            IntegerPredicate::NotEqual {
-                domain_id,
-                not_equal_constant,
-            } => !self.is_value_in_domain(domain_id, not_equal_constant),
-            IntegerPredicate::Equal {
-                domain_id,
-                equality_constant,
-            } => self.is_domain_assigned_to_value(domain_id, equality_constant),
-        }
+        predicate.evaluate(self) == Some(true)
     }
 
     /// Synchronises the internal structures of [`AssignmentsInteger`] based on the fact that
-    /// backtracking to `new_decision_level` is taking place. This method returns the list of
-    /// [`DomainId`]s and their values which were fixed (i.e. domain of size one) before
-    /// backtracking and are unfixed (i.e. domain of two or more values) after synchronisation.
-    pub fn synchronise(&mut self, new_decision_level: usize) -> Vec<(DomainId, i32)> {
-        let mut unfixed_variables = Vec::new();
-        self.trail.synchronise(new_decision_level).for_each(|entry| {
-            munchkin_assert_moderate!(
-                !entry.predicate.is_equality_predicate(),
-                "For now we do not expect equality predicates on the trail, since currently equality predicates are split into lower and upper bound predicates."
-            );
-            let domain_id = entry.predicate.get_domain();
+    /// backtracking to `new_decision_level` is taking place. The provided `on_unfixed` is called
+    /// with every [`DomainId`] (and its value) which was fixed (i.e. domain of size one) before
+    /// backtracking and is unfixed (i.e. domain of two or more values) after synchronisation.
+    ///
+    /// The trail entries are undone in place, so no intermediate collection is allocated.
+    pub fn synchronise(
+        &mut self,
+        new_decision_level: usize,
+        mut on_unfixed: impl FnMut(DomainId, i32),
+    ) {
+        self.trail
+            .synchronise(new_decision_level)
+            .for_each(|entry| {
+                Self::undo_trail_entry(
+                    &mut self.domains,
+                    &mut self.events,
+                    &mut self.backtrack_events,
+                    entry,
+                    &mut on_unfixed,
+                )
+            });
+    }
+
+    /// Undoes the changes which were made at the root after the first `num_trail_entries` entries
+    /// of the trail; this is used to roll back to an earlier state of the root. As with
+    /// [`AssignmentsInteger::synchronise`], the undone changes are recorded as backtrack events.
+    pub fn undo_root_trail_entries(
+        &mut self,
+        num_trail_entries: usize,
+        mut on_unfixed: impl FnMut(DomainId, i32),
+    ) {
+        self.trail
+            .truncate_root(num_trail_entries)
+            .for_each(|entry| {
+                Self::undo_trail_entry(
+                    &mut self.domains,
+                    &mut self.events,
+                    &mut self.backtrack_events,
+                    entry,
+                    &mut on_unfixed,
+                )
+            });
+    }
+
+    fn undo_trail_entry(
+        domains: &mut KeyedVec<DomainId, IntegerDomainExplicit>,
+        events: &mut EventSink,
+        backtrack_events: &mut EventSink,
+        entry: ConstraintProgrammingTrailEntry,
+        on_unfixed: &mut impl FnMut(DomainId, i32),
+    ) {
+        munchkin_assert_moderate!(
+            !entry.predicate.is_equality_predicate(),
+            "For now we do not expect equality predicates on the trail, since currently equality predicates are split into lower and upper bound predicates."
+        );
+        let domain_id = entry.predicate.get_domain();
 
-            let lower_bound_before = self.domains[domain_id].lower_bound;
-            let upper_bound_before = self.domains[domain_id].upper_bound;
-            let fixed_before = upper_bound_before == lower_bound_before;
+        let lower_bound_before = domains[domain_id].lower_bound;
+        let upper_bound_before = domains[domain_id].upper_bound;
+        let fixed_before = upper_bound_before == lower_bound_before;
 
+        domains[domain_id].undo_trail_entry(&entry);
+        events.restore_bounds_before_change(
+            domain_id,
+            entry.old_lower_bound,
+            entry.old_upper_bound,
+        );
 
-            self.domains[domain_id].undo_trail_entry(&entry);
+        let lower_bound_after = domains[domain_id].lower_bound;
+        let upper_bound_after = domains[domain_id].upper_bound;
 
-            if fixed_before && self.domains[domain_id].lower_bound != self.domains[domain_id].upper_bound {
+        if fixed_before && lower_bound_after != upper_bound_after {
+            // Variable used to be fixed but is not after backtracking
+            on_unfixed(domain_id, lower_bound_before);
+            backtrack_events.event_occurred(IntDomainEvent::Assign, domain_id);
+        }
 
-                // Variable used to be fixed but is not after backtracking
-                unfixed_variables.push((domain_id, lower_bound_before));
-            }
-        });
-        unfixed_variables
+        if lower_bound_before != lower_bound_after {
+            backtrack_events.event_occurred(IntDomainEvent::LowerBound, domain_id);
+        }
+        if upper_bound_before != upper_bound_after {
+            backtrack_events.event_occurred(IntDomainEvent::UpperBound, domain_id);
+        }
+        if entry.predicate.is_not_equal_predicate() {
+            backtrack_events.event_occurred(IntDomainEvent::Removal, domain_id);
+        }
     }
 }
 
-#[cfg(test)]
+#[cfg(any(test, feature = "test-utilities"))]
 impl AssignmentsInteger {
     pub fn get_reason_for_predicate(&self, predicate: IntegerPredicate) -> ReasonRef {
         self.trail
@@ -446,6 +588,12 @@ pub struct ConstraintProgrammingTrailEntry {
 /// more memory than the size of the domain, an offset is determined which is used to index into
 /// the slice that keeps track of whether an individual value is in the domain.
 ///
+/// A sparse domain (see [`AssignmentsInteger::grow_sparse`]) instead stores its sorted values, and
+/// `is_value_in_domain` is indexed by the position of a value in those values. The values between
+/// the initial values (i.e. the gaps) are never part of the domain, so the memory and the time
+/// needed to update the bounds are proportional to the number of values rather than to the range
+/// of the domain.
+///
 /// When the domain is in an empty state, `lower_bound > upper_bound` and the state of the
 /// `is_value_in_domain` field is undefined.
 #[derive(Clone, Debug)]
@@ -456,9 +604,15 @@ struct IntegerDomainExplicit {
     upper_bound: i32,
     initial_lower_bound: i32,
     initial_upper_bound: i32,
+    /// The values which were removed through
+    /// [`AssignmentsInteger::remove_initial_value_from_domain`]; the gaps of a sparse domain are
+    /// not stored here.
     initial_removed_values: Vec<i32>,
 
     offset: i32,
+    /// The values of a sparse domain, or [`None`] if the domain contains every value between its
+    /// initial bounds.
+    sparse_values: Option<Box<[i32]>>,
 
     is_value_in_domain: Box<[bool]>,
 }
@@ -480,14 +634,62 @@ impl IntegerDomainExplicit {
             initial_lower_bound: lower_bound,
             initial_upper_bound: upper_bound,
             offset,
+            sparse_values: None,
             is_value_in_domain: is_value_in_domain.into(),
         }
     }
 
+    fn new_sparse(values: &[i32], id: DomainId) -> IntegerDomainExplicit {
+        munchkin_assert_simple!(!values.is_empty(), "Cannot create an empty domain.");
+        munchkin_assert_simple!(
+            values.windows(2).all(|pair| pair[0] < pair[1]),
+            "The values of a sparse domain should be sorted and unique."
+        );
+
+        let lower_bound = values[0];
+        let upper_bound = values[values.len() - 1];
+
+        IntegerDomainExplicit {
+            id,
+            lower_bound,
+            upper_bound,
+            initial_removed_values: vec![],
+            initial_lower_bound: lower_bound,
+            initial_upper_bound: upper_bound,
+            offset: 0,
+            sparse_values: Some(values.into()),
+            is_value_in_domain: vec![true; values.len()].into(),
+        }
+    }
+
     fn contains(&self, value: i32) -> bool {
-        let idx = self.get_index(value);
+        self.lower_bound <= value
+            && value <= self.upper_bound
+            && self
+                .get_index(value)
+                .is_some_and(|idx| self.is_value_in_domain[idx])
+    }
 
-        self.lower_bound <= value && value <= self.upper_bound && self.is_value_in_domain[idx]
+    /// Returns whether the value is part of the domain with which the domain was created.
+    fn is_initial_value(&self, value: i32) -> bool {
+        self.initial_lower_bound <= value
+            && value <= self.initial_upper_bound
+            && self.get_index(value).is_some()
+            && !self.initial_removed_values.contains(&value)
+    }
+
+    /// Returns the values strictly between the bounds which are not in the domain; the gaps of a
+    /// sparse domain are not considered.
+    fn holes(&self) -> impl Iterator<Item = i32> + '_ {
+        let indices = if self.lower_bound < self.upper_bound {
+            self.first_index_at_least(self.lower_bound)..self.first_index_above(self.upper_bound)
+        } else {
+            0..0
+        };
+
+        indices
+            .filter(|&idx| !self.is_value_in_domain[idx])
+            .map(|idx| self.get_value(idx))
     }
 
     fn remove_initial_value(&mut self, value: i32, events: &mut EventSink) {
@@ -500,7 +702,9 @@ impl IntegerDomainExplicit {
             return;
         }
 
-        let idx = self.get_index(value);
+        let Some(idx) = self.get_index(value) else {
+            return;
+        };
 
         if self.is_value_in_domain[idx] {
             events.event_occurred(IntDomainEvent::Removal, self.id);
@@ -546,26 +750,101 @@ impl IntegerDomainExplicit {
         }
     }
 
+    /// Moves the lower bound up to the smallest value in the domain which is at least the current
+    /// lower bound; if there is no such value, the domain is made empty (see
+    /// [`IntegerDomainExplicit::make_empty`]).
     fn update_lower_bound(&mut self, events: &mut EventSink) {
-        while self.get_index(self.lower_bound) < self.is_value_in_domain.len()
-            && !self.is_value_in_domain[self.get_index(self.lower_bound)]
-        {
+        let Some(new_lower_bound) = (self.first_index_at_least(self.lower_bound)
+            ..self.is_value_in_domain.len())
+            .find(|&idx| self.is_value_in_domain[idx])
+            .map(|idx| self.get_value(idx))
+        else {
+            self.make_empty(events);
+            return;
+        };
+
+        if new_lower_bound != self.lower_bound {
             events.event_occurred(IntDomainEvent::LowerBound, self.id);
-            self.lower_bound += 1;
+            self.lower_bound = new_lower_bound;
         }
     }
 
+    /// Moves the upper bound down to the largest value in the domain which is at most the current
+    /// upper bound; if there is no such value, the domain is made empty (see
+    /// [`IntegerDomainExplicit::make_empty`]).
     fn update_upper_bound(&mut self, events: &mut EventSink) {
-        while self.upper_bound + self.offset >= 0
-            && !self.is_value_in_domain[self.get_index(self.upper_bound)]
-        {
+        let Some(new_upper_bound) = (0..self.first_index_above(self.upper_bound))
+            .rev()
+            .find(|&idx| self.is_value_in_domain[idx])
+            .map(|idx| self.get_value(idx))
+        else {
+            self.make_empty(events);
+            return;
+        };
+
+        if new_upper_bound != self.upper_bound {
             events.event_occurred(IntDomainEvent::UpperBound, self.id);
-            self.upper_bound -= 1;
+            self.upper_bound = new_upper_bound;
         }
     }
 
-    fn get_index(&self, value: i32) -> usize {
-        (value + self.offset) as usize
+    /// Puts the domain in the empty state, i.e. `lower_bound > upper_bound`, when no value remains
+    /// between its bounds.
+    ///
+    /// The lower bound is moved directly past the upper bound; only if the upper bound is
+    /// `i32::MAX` is the upper bound moved directly below the lower bound instead, so neither
+    /// bound overflows.
+    fn make_empty(&mut self, events: &mut EventSink) {
+        if self.lower_bound > self.upper_bound {
+            return;
+        }
+
+        if self.upper_bound < i32::MAX {
+            events.event_occurred(IntDomainEvent::LowerBound, self.id);
+            self.lower_bound = self.upper_bound + 1;
+        } else {
+            events.event_occurred(IntDomainEvent::UpperBound, self.id);
+            self.upper_bound = self.lower_bound - 1;
+        }
+    }
+
+    /// Returns the index of the value in `is_value_in_domain`, or [`None`] if the value is not
+    /// represented (i.e. it is outside the initial bounds or in a gap of a sparse domain).
+    fn get_index(&self, value: i32) -> Option<usize> {
+        match &self.sparse_values {
+            Some(values) => values.binary_search(&value).ok(),
+            None => {
+                let idx = value as i64 + self.offset as i64;
+                (0 <= idx && idx < self.is_value_in_domain.len() as i64).then_some(idx as usize)
+            }
+        }
+    }
+
+    /// Returns the value at the given index of `is_value_in_domain`.
+    fn get_value(&self, idx: usize) -> i32 {
+        match &self.sparse_values {
+            Some(values) => values[idx],
+            None => idx as i32 - self.offset,
+        }
+    }
+
+    /// Returns the smallest index of `is_value_in_domain` of which the value is at least `value`.
+    fn first_index_at_least(&self, value: i32) -> usize {
+        match &self.sparse_values {
+            Some(values) => values.partition_point(|&other| other < value),
+            None => (value as i64 + self.offset as i64)
+                .clamp(0, self.is_value_in_domain.len() as i64) as usize,
+        }
+    }
+
+    /// Returns the smallest index of `is_value_in_domain` of which the value is larger than
+    /// `value`.
+    fn first_index_above(&self, value: i32) -> usize {
+        match &self.sparse_values {
+            Some(values) => values.partition_point(|&other| other <= value),
+            None => (value as i64 + self.offset as i64 + 1)
+                .clamp(0, self.is_value_in_domain.len() as i64) as usize,
+        }
     }
 
     fn debug_bounds_check(&self) -> bool {
@@ -573,13 +852,11 @@ impl IntegerDomainExplicit {
         if self.lower_bound > self.upper_bound {
             true
         } else {
-            let lb_idx = self.get_index(self.lower_bound);
-            let ub_idx = self.get_index(self.upper_bound);
-
-            lb_idx < self.is_value_in_domain.len()
-                && ub_idx < self.is_value_in_domain.len()
-                && self.is_value_in_domain[lb_idx]
-                && self.is_value_in_domain[ub_idx]
+            self.get_index(self.lower_bound)
+                .is_some_and(|idx| self.is_value_in_domain[idx])
+                && self
+                    .get_index(self.upper_bound)
+                    .is_some_and(|idx| self.is_value_in_domain[idx])
         }
     }
 
@@ -597,8 +874,9 @@ impl IntegerDomainExplicit {
             not_equal_constant,
         } = entry.predicate
         {
-            let value_idx = self.get_index(not_equal_constant);
-            self.is_value_in_domain[value_idx] = true;
+            if let Some(value_idx) = self.get_index(not_equal_constant) {
+                self.is_value_in_domain[value_idx] = true;
+            }
         }
 
         self.lower_bound = entry.old_lower_bound;
@@ -807,11 +1085,96 @@ mod tests {
             .remove_value_from_domain(d1, 5, None)
             .expect("non-empty domain");
 
-        let _ = assignment.synchronise(0);
+        assignment.synchronise(0, |_, _| {});
 
         assert_eq!(5, assignment.get_upper_bound(d1));
     }
 
+    #[test]
+    fn sparse_domain_with_extreme_range_only_stores_its_values() {
+        let mut assignment = AssignmentsInteger::default();
+        let d1 = assignment.grow_sparse(&[-1_000_000_000, 0, 1_000_000_000]);
+
+        assert_eq!(3, assignment.domains[d1].is_value_in_domain.len());
+        assert!(assignment.is_value_in_domain(d1, 0));
+        assert!(!assignment.is_value_in_domain(d1, 1));
+        assert!(!assignment.is_value_in_initial_domain(d1, 1));
+        assert_eq!(
+            vec![
+                predicate![d1 >= -1_000_000_000],
+                predicate![d1 <= 1_000_000_000]
+            ],
+            assignment.get_domain_description(d1)
+        );
+
+        assignment.increase_decision_level();
+        assignment
+            .tighten_lower_bound(d1, -5, None)
+            .expect("non-empty domain");
+        assert_eq!(0, assignment.get_lower_bound(d1));
+
+        assignment
+            .remove_value_from_domain(d1, 0, None)
+            .expect("non-empty domain");
+        assert!(assignment.is_domain_assigned_to_value(d1, 1_000_000_000));
+
+        assignment.synchronise(0, |_, _| {});
+
+        assert_eq!(-1_000_000_000, assignment.get_lower_bound(d1));
+        assert!(assignment.is_value_in_domain(d1, 0));
+    }
+
+    #[test]
+    fn holes_of_sparse_domain_only_contain_removed_values() {
+        let mut assignment = AssignmentsInteger::default();
+        let d1 = assignment.grow_sparse(&[-1_000_000_000, 0, 5, 1_000_000_000]);
+
+        assignment
+            .remove_value_from_domain(d1, 5, None)
+            .expect("non-empty domain");
+        assert_eq!(vec![5], assignment.get_holes(d1).collect::<Vec<_>>());
+        assert!(assignment.is_value_in_initial_domain(d1, 5));
+
+        assignment
+            .tighten_upper_bound(d1, 999_999_999, None)
+            .expect("non-empty domain");
+        assert_eq!(0, assignment.get_upper_bound(d1));
+
+        assert!(assignment.tighten_lower_bound(d1, 1, None).is_err());
+    }
+
+    #[test]
+    fn removing_the_last_value_of_domain_at_the_edge_of_the_range_empties_it() {
+        let mut assignment = AssignmentsInteger::default();
+        let d1 = assignment.grow(i32::MAX - 2, i32::MAX);
+        let d2 = assignment.grow_sparse(&[i32::MIN, 0, i32::MAX]);
+
+        assignment
+            .tighten_lower_bound(d1, i32::MAX, None)
+            .expect("non-empty domain");
+        assert!(assignment
+            .remove_value_from_domain(d1, i32::MAX, None)
+            .is_err());
+
+        assignment
+            .remove_value_from_domain(d2, 0, None)
+            .expect("non-empty domain");
+        assignment
+            .tighten_upper_bound(d2, i32::MIN, None)
+            .expect("non-empty domain");
+        assert!(assignment
+            .remove_value_from_domain(d2, i32::MIN, None)
+            .is_err());
+
+        let d3 = assignment.grow_sparse(&[i32::MIN, i32::MAX]);
+        assignment
+            .tighten_lower_bound(d3, 0, None)
+            .expect("non-empty domain");
+        assert!(assignment
+            .remove_value_from_domain(d3, i32::MAX, None)
+            .is_err());
+    }
+
     fn assert_contains_events<DomainEvent: PartialEq + Copy>(
         slice: &[(DomainEvent, DomainId)],
         domain: DomainId,
@@ -15,10 +15,15 @@ use crate::propagators;
 /// Triggering any [`DomainEvents`] will also trigger the event [`DomainEvents::ANY_INT`].
 ///
 /// The event sink will ensure duplicate events are ignored.
+///
+/// For every domain, the sink also keeps the bounds before the first change which led to the
+/// events that are currently in the sink (see [`EventSink::record_bounds_before_change`]); these
+/// remain available after draining until the domain changes again.
 #[derive(Clone, Debug, Default)]
 pub(crate) struct EventSink {
     present: KeyedVec<DomainId, EnumSet<IntDomainEvent>>,
     events: Vec<(IntDomainEvent, DomainId)>,
+    bounds_before: KeyedVec<DomainId, (i32, i32)>,
 }
 
 impl EventSink {
@@ -31,6 +36,39 @@ impl EventSink {
     }
     pub(crate) fn grow(&mut self) {
         self.present.push(EnumSet::new());
+        self.bounds_before.push((0, 0));
+    }
+
+    /// Records the bounds of the domain before it is changed; only the bounds before the first
+    /// change since the events of the domain were drained are kept.
+    pub(crate) fn record_bounds_before_change(
+        &mut self,
+        domain: DomainId,
+        lower_bound: i32,
+        upper_bound: i32,
+    ) {
+        if self.present[domain].is_empty() {
+            self.bounds_before[domain] = (lower_bound, upper_bound);
+        }
+    }
+
+    /// Restores the recorded bounds of the domain when a change is undone while its events are
+    /// still in the sink, such that the recorded bounds do not refer to an undone state.
+    pub(crate) fn restore_bounds_before_change(
+        &mut self,
+        domain: DomainId,
+        lower_bound: i32,
+        upper_bound: i32,
+    ) {
+        if !self.present[domain].is_empty() {
+            self.bounds_before[domain] = (lower_bound, upper_bound);
+        }
+    }
+
+    /// Returns the lower and upper bound of the domain before the changes which led to its
+    /// (possibly already drained) events.
+    pub(crate) fn get_bounds_before_change(&self, domain: DomainId) -> (i32, i32) {
+        self.bounds_before[domain]
     }
 
     pub(crate) fn event_occurred(&mut self, event: IntDomainEvent, domain: DomainId) {
@@ -46,6 +84,13 @@ impl EventSink {
             let _ = self.present[domain].remove(event);
         })
     }
+
+    /// Removes all events from the sink without reporting them.
+    pub(crate) fn clear(&mut self) {
+        for (event, domain) in self.events.drain(..) {
+            let _ = self.present[domain].remove(event);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -92,6 +137,20 @@ mod tests {
         assert!(events.is_empty());
     }
 
+    #[test]
+    fn after_clearing_the_event_sink_is_empty_and_records_events_again() {
+        let mut sink = EventSink::default();
+        sink.grow();
+
+        sink.event_occurred(IntDomainEvent::LowerBound, DomainId::new(0));
+        sink.clear();
+        assert!(sink.drain().next().is_none());
+
+        sink.event_occurred(IntDomainEvent::LowerBound, DomainId::new(0));
+        let events = sink.drain().collect::<Vec<_>>();
+        assert_eq!(events, vec![(IntDomainEvent::LowerBound, DomainId::new(0))]);
+    }
+
     #[test]
     fn duplicate_events_are_ignored() {
         let mut sink = EventSink::default();
@@ -104,4 +163,24 @@ mod tests {
 
         assert_eq!(events.len(), 1);
     }
+
+    #[test]
+    fn bounds_before_the_first_change_are_kept_until_drained() {
+        let mut sink = EventSink::default();
+        sink.grow();
+        let domain = DomainId::new(0);
+
+        sink.record_bounds_before_change(domain, 0, 10);
+        sink.event_occurred(IntDomainEvent::LowerBound, domain);
+        sink.record_bounds_before_change(domain, 3, 10);
+        sink.event_occurred(IntDomainEvent::LowerBound, domain);
+        assert_eq!(sink.get_bounds_before_change(domain), (0, 10));
+
+        let _ = sink.drain().collect::<Vec<_>>();
+        assert_eq!(sink.get_bounds_before_change(domain), (0, 10));
+
+        sink.record_bounds_before_change(domain, 5, 10);
+        sink.event_occurred(IntDomainEvent::LowerBound, domain);
+        assert_eq!(sink.get_bounds_before_change(domain), (5, 10));
+    }
 }
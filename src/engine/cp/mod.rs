@@ -5,7 +5,7 @@ pub(crate) mod propagation;
 mod propagator_queue;
 pub(crate) mod reason;
 mod variable_literal_mappings;
-mod watch_list_cp;
+pub(crate) mod watch_list_cp;
 mod watch_list_propositional;
 
 pub(crate) use assignments_integer::AssignmentsInteger;
@@ -0,0 +1,23 @@
+use crate::engine::cp::propagation::LocalId;
+#[cfg(doc)]
+use crate::engine::cp::propagation::Propagator;
+use crate::engine::cp::IntDomainEvent;
+
+/// Describes a change to the domain of a variable which a [`Propagator`] is subscribed to; this is
+/// provided to [`Propagator::notify_change`].
+///
+/// The bounds are those of the underlying domain (i.e. not transformed by a view) before the first
+/// change since the propagator was last notified and after the last change. For
+/// [`IntDomainEvent::UpperBound`] these are upper bounds, while for the other events these are
+/// lower bounds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DomainChange {
+    /// The [`LocalId`] with which the variable was registered.
+    pub local_id: LocalId,
+    /// The event which occurred on the variable.
+    pub event: IntDomainEvent,
+    /// The bound before the change.
+    pub old_bound: i32,
+    /// The bound after the change.
+    pub new_bound: i32,
+}
@@ -0,0 +1,13 @@
+#[cfg(doc)]
+use crate::engine::cp::propagation::Propagator;
+
+/// Determines whether a [`Propagator`] should be enqueued for propagation after it has been
+/// notified of a domain event (see [`Propagator::notify`]).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EnqueueDecision {
+    /// The propagator should be enqueued.
+    Enqueue,
+    /// The propagator cannot propagate anything due to the event and should not be enqueued.
+    #[allow(unused, reason = "can be used in an assignment")]
+    Skip,
+}
@@ -75,6 +75,8 @@
 //! International Workshop on Constraint Solving and Constraint Logic Programming, 2005, pp.
 //! 118–132.
 
+pub(crate) mod domain_change;
+pub(crate) mod enqueue_decision;
 pub(crate) mod local_id;
 pub(crate) mod propagation_context;
 pub(crate) mod propagator;
@@ -82,6 +84,8 @@ pub(crate) mod propagator_id;
 pub(crate) mod propagator_initialisation_context;
 pub(crate) mod propagator_var_id;
 
+pub(crate) use domain_change::DomainChange;
+pub(crate) use enqueue_decision::EnqueueDecision;
 pub(crate) use local_id::LocalId;
 pub(crate) use propagation_context::PropagationContext;
 pub(crate) use propagation_context::PropagationContextMut;
@@ -1,6 +1,7 @@
 use super::PropagatorId;
 use crate::basic_types::ConstraintReference;
 use crate::basic_types::Inconsistency;
+use crate::basic_types::PropositionalConjunction;
 use crate::engine::cp::reason::Reason;
 use crate::engine::cp::reason::ReasonStore;
 use crate::engine::cp::AssignmentsInteger;
@@ -113,6 +114,13 @@ impl<'a> PropagationContextMut<'a> {
         }
     }
 
+    /// Returns an empty conjunction which can be filled with the reason for a propagation; when it
+    /// is passed to the context, it is moved into the reason store, which recycles its memory
+    /// once the solver backtracks past the propagation.
+    pub fn reason_builder(&mut self) -> PropositionalConjunction {
+        self.reason_store.new_reason()
+    }
+
     pub(crate) fn as_readonly(&self) -> PropagationContext<'_> {
         PropagationContext {
             assignments_integer: self.assignments_integer,
@@ -158,7 +166,11 @@ mod private {
 }
 
 #[allow(unused, reason = "could be used in an assignment")]
-pub(crate) trait ReadDomains: HasAssignments {
+#[cfg_attr(
+    not(feature = "test-utilities"),
+    allow(unreachable_pub, reason = "exported by the test utilities")
+)]
+pub trait ReadDomains: HasAssignments {
     fn is_literal_fixed(&self, var: Literal) -> bool {
         self.assignments_propositional().is_literal_assigned(var)
     }
@@ -1,18 +1,23 @@
 use super::PropagatorInitialisationContext;
 #[cfg(doc)]
-use crate::asserts::munchkin_assert_ADVANCED;
-#[cfg(doc)]
-use crate::asserts::munchkin_assert_EXTREME;
-#[cfg(doc)]
 use crate::basic_types::Inconsistency;
 use crate::basic_types::PropagationStatusCP;
+#[cfg(doc)]
+use crate::branching::branchers::propagator_guided_brancher::PropagatorGuidedBrancher;
 use crate::engine::cp::propagation::propagation_context::PropagationContext;
 use crate::engine::cp::propagation::propagation_context::PropagationContextMut;
+use crate::engine::cp::propagation::DomainChange;
+use crate::engine::cp::propagation::EnqueueDecision;
+use crate::engine::cp::propagation::LocalId;
+use crate::engine::cp::IntDomainEvent;
+use crate::engine::predicates::predicate::Predicate;
 #[cfg(doc)]
 use crate::engine::sat::ClausalPropagator;
 #[cfg(doc)]
 use crate::engine::ConstraintSatisfactionSolver;
 use crate::predicates::PropositionalConjunction;
+#[cfg(doc)]
+use crate::Solver;
 
 /// All propagators implement the [`Propagator`] trait, with the exception of the
 /// clausal propagator. Structs implementing the trait defines the main propagator logic with
@@ -62,6 +67,65 @@ pub trait Propagator {
         _: &mut PropagatorInitialisationContext,
     ) -> Result<(), PropositionalConjunction>;
 
+    /// Called by the solver when the given [`IntDomainEvent`] occurs on the variable with
+    /// [`LocalId`], provided that the propagator subscribed to it using
+    /// [`PropagatorInitialisationContext::register`].
+    ///
+    /// The propagator can use this to update its internal data structures, and indicate whether
+    /// it should be enqueued for propagation through the returned [`EnqueueDecision`].
+    ///
+    /// By default, the propagator is always enqueued.
+    fn notify(
+        &mut self,
+        _context: PropagationContext,
+        _local_id: LocalId,
+        _event: IntDomainEvent,
+    ) -> EnqueueDecision {
+        EnqueueDecision::Enqueue
+    }
+
+    /// Called by the solver when a change occurs on a variable which the propagator subscribed to
+    /// using [`PropagatorInitialisationContext::register`]. In contrast to
+    /// [`Propagator::notify`], the [`DomainChange`] also describes the bound before and after the
+    /// change, such that incremental propagators do not need to compare the domains to a cached
+    /// copy.
+    ///
+    /// By default, this calls [`Propagator::notify`].
+    fn notify_change(
+        &mut self,
+        context: PropagationContext,
+        change: DomainChange,
+    ) -> EnqueueDecision {
+        self.notify(context, change.local_id, change.event)
+    }
+
+    /// Called by the solver when the given [`IntDomainEvent`] is undone on the variable with
+    /// [`LocalId`] while backtracking, provided that the propagator subscribed to it using
+    /// [`PropagatorInitialisationContext::register_for_backtrack_events`].
+    ///
+    /// The provided [`PropagationContext`] reflects the state after backtracking, i.e. the
+    /// restored domains can be read from it. This can be used by incremental propagators to
+    /// restore the internal data structures (e.g. supports) which depend on the domains.
+    ///
+    /// By default, this function does nothing.
+    fn notify_backtrack(
+        &mut self,
+        _context: PropagationContext,
+        _local_id: LocalId,
+        _event: IntDomainEvent,
+    ) {
+    }
+
+    /// Returns the priority of the propagator, where a lower value indicates a higher priority.
+    /// The solver only propagates an enqueued propagator once all enqueued propagators with a
+    /// higher priority have reached a fixed point; this allows expensive propagators to be delayed
+    /// until the cheaper ones have finished.
+    ///
+    /// The priority should be smaller than 5. By default, this function returns 0.
+    fn priority(&self) -> u32 {
+        0
+    }
+
     /// A check whether this propagator can detect an inconsistency.
     ///
     /// By implementing this function, if the propagator is reified, it can propagate the
@@ -74,4 +138,37 @@ pub trait Propagator {
     ) -> Option<PropositionalConjunction> {
         None
     }
+
+    /// Returns whether the constraint of the propagator is satisfied by every assignment which
+    /// extends the current one, in which case the propagator will never propagate again.
+    ///
+    /// This is called by [`ConstraintSatisfactionSolver::simplify_root`], which deactivates the
+    /// propagators which are entailed at the root. The method does not have to detect every case
+    /// of entailment; by default, this function returns false.
+    fn is_entailed(&self, _context: PropagationContext) -> bool {
+        false
+    }
+
+    /// Returns a decision which the propagator suggests to the search, or [`None`] if it has no
+    /// suggestion. Some propagators can cheaply derive good decisions from their internal state,
+    /// e.g. a circuit propagator can suggest to extend the longest fixed path.
+    ///
+    /// The suggestions are only used when searching with a [`PropagatorGuidedBrancher`], which
+    /// ignores suggestions that are already true or false. By default, this function returns
+    /// [`None`].
+    fn next_decision(&self, _context: PropagationContext) -> Option<Predicate> {
+        None
+    }
+
+    /// Checks whether a solution satisfies the constraint of the propagator; the provided
+    /// `context` is a copy of the solution in which every variable is fixed, so changes made to it
+    /// are discarded. This is used by [`Solver::verify_solution`] to verify the solutions which are
+    /// found independently of the search.
+    ///
+    /// By default, this function calls [`Propagator::propagate`] and reports whether it found the
+    /// solution to be inconsistent. Propagators which do not detect every violation when all of
+    /// their variables are fixed should override this.
+    fn check(&self, context: PropagationContextMut) -> bool {
+        self.propagate(context).is_ok()
+    }
 }
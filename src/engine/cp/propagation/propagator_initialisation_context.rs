@@ -1,11 +1,15 @@
-use super::PropagationContext;
+use super::PropagationContextMut;
+use crate::basic_types::Inconsistency;
 use crate::engine::cp::domain_events::DomainEvents;
 use crate::engine::cp::propagation::LocalId;
 #[cfg(doc)]
 use crate::engine::cp::propagation::Propagator;
 use crate::engine::cp::propagation::PropagatorId;
 use crate::engine::cp::propagation::PropagatorVarId;
+use crate::engine::cp::reason::Reason;
+use crate::engine::cp::reason::ReasonStore;
 use crate::engine::cp::AssignmentsInteger;
+use crate::engine::cp::EmptyDomain;
 use crate::engine::cp::WatchListCP;
 use crate::engine::cp::WatchListPropositional;
 use crate::engine::cp::Watchers;
@@ -18,27 +22,33 @@ use crate::engine::variables::Literal;
 ///
 /// It represents a communication point between the [`Solver`] and the [`Propagator`].
 /// Propagators use the [`PropagatorInitialisationContext`] to register to domain changes
-/// of variables and to retrieve the current bounds of variables.
+/// of variables, to retrieve the current bounds of variables, and to perform root-level
+/// propagations (e.g. removing values which can never be part of a solution).
 #[derive(Debug)]
 pub struct PropagatorInitialisationContext<'a> {
     watch_list: &'a mut WatchListCP,
     watch_list_propositional: &'a mut WatchListPropositional,
     propagator_id: PropagatorId,
     next_local_id: LocalId,
+    /// Whether the domain changes made through this context are applied; see
+    /// [`PropagatorInitialisationContext::allows_root_propagation`].
+    allows_root_propagation: bool,
 
-    context: PropagationContext<'a>,
+    context: PropagationContextMut<'a>,
 
     pub use_non_generic_conflict_explanation: bool,
     pub use_non_generic_propagation_explanation: bool,
 }
 
 impl PropagatorInitialisationContext<'_> {
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn new<'a>(
         watch_list: &'a mut WatchListCP,
         watch_list_propositional: &'a mut WatchListPropositional,
         propagator_id: PropagatorId,
-        assignments_integer: &'a AssignmentsInteger,
-        assignments_propositional: &'a AssignmentsPropositional,
+        assignments_integer: &'a mut AssignmentsInteger,
+        reason_store: &'a mut ReasonStore,
+        assignments_propositional: &'a mut AssignmentsPropositional,
         use_non_generic_conflict_explanation: bool,
         use_non_generic_propagation_explanation: bool,
     ) -> PropagatorInitialisationContext<'a> {
@@ -47,10 +57,13 @@ impl PropagatorInitialisationContext<'_> {
             watch_list_propositional,
             propagator_id,
             next_local_id: LocalId::from(0),
+            allows_root_propagation: true,
 
-            context: PropagationContext::new(
+            context: PropagationContextMut::new(
                 assignments_integer,
+                reason_store,
                 assignments_propositional,
+                propagator_id,
                 use_non_generic_conflict_explanation,
                 use_non_generic_propagation_explanation,
             ),
@@ -82,6 +95,37 @@ impl PropagatorInitialisationContext<'_> {
         var.watch_all(&mut watchers, domain_events.get_int_events());
     }
 
+    /// Subscribes the propagator to the given [`DomainEvents`] being undone when the solver
+    /// backtracks.
+    ///
+    /// The domain events determine when [`Propagator::notify_backtrack()`] will be called on the
+    /// propagator. In contrast to [`PropagatorInitialisationContext::register`], the [`LocalId`]
+    /// is provided explicitly so that it can coincide with the [`LocalId`] with which the variable
+    /// was registered for the forward events.
+    pub fn register_for_backtrack_events<Var: IntegerVariable>(
+        &mut self,
+        var: Var,
+        domain_events: DomainEvents,
+        local_id: LocalId,
+    ) {
+        let propagator_var = PropagatorVarId {
+            propagator: self.propagator_id,
+            variable: local_id,
+        };
+
+        self.next_local_id = self.next_local_id.max(LocalId::from(local_id.unpack() + 1));
+
+        let mut watchers = Watchers::new(propagator_var, self.watch_list);
+        var.watch_all_backtrack(&mut watchers, domain_events.get_int_events());
+    }
+
+    /// Subscribes the propagator to the given [`DomainEvents`] of the provided [`Literal`].
+    ///
+    /// The events act as a polarity filter: a propagator registered with
+    /// [`DomainEvents::ASSIGNED_TRUE`] (resp. [`DomainEvents::ASSIGNED_FALSE`]) is only enqueued
+    /// when the literal is assigned true (resp. false), while [`DomainEvents::ANY_BOOL`] enqueues
+    /// it for both assignments. Unlike for integer variables, [`Propagator::notify()`] is not
+    /// called for these events.
     pub fn register_literal(
         &mut self,
         var: Literal,
@@ -105,6 +149,81 @@ impl PropagatorInitialisationContext<'_> {
     pub fn get_next_local_id(&self) -> LocalId {
         self.next_local_id
     }
+
+    /// Returns whether the domain changes made through this context are applied.
+    ///
+    /// If this is not the case (e.g. when the propagator is reified, in which case the
+    /// propagations should only happen when the reification literal is true), the domain
+    /// changes are ignored. A propagator should thus not rely on root-level propagations made
+    /// during initialisation, and should also perform them in [`Propagator::propagate`].
+    pub fn allows_root_propagation(&self) -> bool {
+        self.allows_root_propagation
+    }
+
+    /// Sets whether the domain changes made through this context are applied; see
+    /// [`PropagatorInitialisationContext::allows_root_propagation`].
+    pub(crate) fn set_allows_root_propagation(&mut self, allows_root_propagation: bool) {
+        self.allows_root_propagation = allows_root_propagation;
+    }
+
+    /// Removes `value` from the domain of `var` at the root; see
+    /// [`PropagationContextMut::remove`].
+    pub fn remove<Var: IntegerVariable, R: Into<Reason>>(
+        &mut self,
+        var: &Var,
+        value: i32,
+        reason: R,
+    ) -> Result<(), EmptyDomain> {
+        if !self.allows_root_propagation {
+            return Ok(());
+        }
+
+        self.context.remove(var, value, reason)
+    }
+
+    /// Sets the upper bound of `var` to `bound` at the root; see
+    /// [`PropagationContextMut::set_upper_bound`].
+    pub fn set_upper_bound<Var: IntegerVariable, R: Into<Reason>>(
+        &mut self,
+        var: &Var,
+        bound: i32,
+        reason: R,
+    ) -> Result<(), EmptyDomain> {
+        if !self.allows_root_propagation {
+            return Ok(());
+        }
+
+        self.context.set_upper_bound(var, bound, reason)
+    }
+
+    /// Sets the lower bound of `var` to `bound` at the root; see
+    /// [`PropagationContextMut::set_lower_bound`].
+    pub fn set_lower_bound<Var: IntegerVariable, R: Into<Reason>>(
+        &mut self,
+        var: &Var,
+        bound: i32,
+        reason: R,
+    ) -> Result<(), EmptyDomain> {
+        if !self.allows_root_propagation {
+            return Ok(());
+        }
+
+        self.context.set_lower_bound(var, bound, reason)
+    }
+
+    /// Assigns `var` to `bound` at the root; see [`PropagationContextMut::assign_literal`].
+    pub fn assign_literal<R: Into<Reason>>(
+        &mut self,
+        var: Literal,
+        bound: bool,
+        reason: R,
+    ) -> Result<(), Inconsistency> {
+        if !self.allows_root_propagation {
+            return Ok(());
+        }
+
+        self.context.assign_literal(var, bound, reason)
+    }
 }
 
 mod private {
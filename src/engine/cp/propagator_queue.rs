@@ -11,6 +11,8 @@ pub(crate) struct PropagatorQueue {
     queues: Vec<VecDeque<PropagatorId>>,
     present_propagators: HashSet<PropagatorId>,
     present_priorities: BinaryHeap<Reverse<u32>>,
+    /// The propagators which are not enqueued when they are notified of an event.
+    inactive_propagators: HashSet<PropagatorId>,
 }
 
 impl PropagatorQueue {
@@ -19,6 +21,7 @@ impl PropagatorQueue {
             queues: vec![VecDeque::new(); num_priority_levels as usize],
             present_propagators: HashSet::default(),
             present_priorities: BinaryHeap::new(),
+            inactive_propagators: HashSet::default(),
         }
     }
 
@@ -29,6 +32,10 @@ impl PropagatorQueue {
     pub(crate) fn enqueue_propagator(&mut self, propagator_id: PropagatorId, priority: u32) {
         munchkin_assert_moderate!((priority as usize) < self.queues.len());
 
+        if self.inactive_propagators.contains(&propagator_id) {
+            return;
+        }
+
         if !self.is_propagator_enqueued(propagator_id) {
             if self.queues[priority as usize].is_empty() {
                 self.present_priorities.push(Reverse(priority));
@@ -65,7 +72,51 @@ impl PropagatorQueue {
         self.present_priorities.clear();
     }
 
+    /// Marks the provided propagator as inactive, after which it is no longer enqueued until it is
+    /// reactivated using [`PropagatorQueue::mark_active`].
+    ///
+    /// The propagator should not be enqueued when it is marked as inactive.
+    pub(crate) fn mark_inactive(&mut self, propagator_id: PropagatorId) {
+        munchkin_assert_moderate!(!self.is_propagator_enqueued(propagator_id));
+        let _ = self.inactive_propagators.insert(propagator_id);
+    }
+
+    /// Reactivates a propagator which was marked as inactive.
+    pub(crate) fn mark_active(&mut self, propagator_id: PropagatorId) {
+        let _ = self.inactive_propagators.remove(&propagator_id);
+    }
+
     fn is_propagator_enqueued(&self, propagator_id: PropagatorId) -> bool {
         self.present_propagators.contains(&propagator_id)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::PropagatorQueue;
+    use crate::engine::cp::propagation::PropagatorId;
+
+    #[test]
+    fn inactive_propagators_are_not_enqueued() {
+        let mut queue = PropagatorQueue::new(2);
+        queue.mark_inactive(PropagatorId(0));
+
+        queue.enqueue_propagator(PropagatorId(0), 0);
+        queue.enqueue_propagator(PropagatorId(1), 1);
+
+        assert_eq!(queue.pop(), PropagatorId(1));
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn reactivated_propagators_are_enqueued() {
+        let mut queue = PropagatorQueue::new(1);
+        queue.mark_inactive(PropagatorId(0));
+        queue.mark_active(PropagatorId(0));
+
+        queue.enqueue_propagator(PropagatorId(0), 0);
+
+        assert_eq!(queue.pop(), PropagatorId(0));
+        assert!(queue.is_empty());
+    }
+}
@@ -4,18 +4,28 @@ use std::fmt::Formatter;
 use super::propagation::PropagatorId;
 use crate::basic_types::PropositionalConjunction;
 use crate::basic_types::Trail;
-#[cfg(doc)]
-use crate::engine::conflict_analysis::PropagationContext;
 use crate::engine::cp::propagation::PropagationContext;
 use crate::engine::debug_helper::DebugDyn;
+use crate::engine::predicates::predicate::Predicate;
 use crate::munchkin_assert_simple;
 
 /// The reason store holds a reason for each change made by a CP propagator on a trail.
 ///   This trail makes is easy to garbage collect reasons by simply synchronising whenever
 ///   the `AssignmentsInteger` and `AssignmentsPropositional` are synchronised.
+///
+/// Lazy reasons are only computed when they are requested through
+/// [`ReasonStore::get_or_compute`]; if the solver backtracks before that happens, then the closure
+/// is dropped without ever being called.
+///
+/// Most reasons are discarded on backtrack without ever being inspected, so the allocated buffers
+/// of the reasons which are removed during [`ReasonStore::synchronise`] are kept in a free list
+/// and handed out again by [`ReasonStore::new_reason`].
 #[derive(Default, Debug)]
 pub struct ReasonStore {
     trail: Trail<(PropagatorId, Reason)>,
+    num_lazy_reasons_computed: u64,
+    free_buffers: Vec<Vec<Predicate>>,
+    num_reused_buffers: u64,
 }
 
 impl ReasonStore {
@@ -35,9 +45,16 @@ impl ReasonStore {
         reference: ReasonRef,
         context: &PropagationContext,
     ) -> Option<&'this PropositionalConjunction> {
-        self.trail
-            .get_mut(reference.0 as usize)
-            .map(|reason| reason.1.compute(context))
+        let (_, reason) = self.trail.get_mut(reference.0 as usize)?;
+        if matches!(reason, Reason::Lazy(_)) {
+            self.num_lazy_reasons_computed += 1;
+        }
+        Some(reason.compute(context))
+    }
+
+    /// Returns the number of lazy reasons which have been computed so far.
+    pub fn num_lazy_reasons_computed(&self) -> u64 {
+        self.num_lazy_reasons_computed
     }
 
     pub fn increase_decision_level(&mut self) {
@@ -45,15 +62,52 @@ impl ReasonStore {
     }
 
     pub fn synchronise(&mut self, level: usize) {
-        let _ = self.trail.synchronise(level);
+        for (_, reason) in self.trail.synchronise(level) {
+            if let Reason::Eager(conjunction) = reason {
+                self.free_buffers.extend(conjunction.into_buffer());
+            }
+        }
+    }
+
+    /// Removes the reasons beyond the first `num_reasons` reasons, which can only be done at the
+    /// root; this is used to roll back to an earlier state of the root.
+    pub fn truncate_root(&mut self, num_reasons: usize) {
+        for (_, reason) in self.trail.truncate_root(num_reasons) {
+            if let Reason::Eager(conjunction) = reason {
+                self.free_buffers.extend(conjunction.into_buffer());
+            }
+        }
     }
 
+    /// Returns an empty conjunction which (if possible) reuses the buffer of a reason which was
+    /// removed during synchronisation, so that it can be filled and pushed without allocating.
+    pub fn new_reason(&mut self) -> PropositionalConjunction {
+        match self.free_buffers.pop() {
+            Some(buffer) => {
+                self.num_reused_buffers += 1;
+                PropositionalConjunction::with_buffer(buffer)
+            }
+            None => PropositionalConjunction::default(),
+        }
+    }
+
+    /// Returns the number of times that the buffer of a removed reason was reused.
     #[cfg(test)]
+    pub fn num_reused_buffers(&self) -> u64 {
+        self.num_reused_buffers
+    }
+
     #[allow(clippy::len_without_is_empty)]
     pub fn len(&self) -> usize {
         self.trail.len()
     }
 
+    /// Returns the approximate number of bytes used by the trail of reasons; the predicates of
+    /// the eager reasons are not included.
+    pub fn approximate_memory_usage(&self) -> usize {
+        self.trail.approximate_memory_usage()
+    }
+
     /// Get the propagator which generated the given reason.
     pub fn get_propagator(&self, reason_ref: ReasonRef) -> PropagatorId {
         self.trail.get(reason_ref.0 as usize).unwrap().0
@@ -146,6 +200,7 @@ mod tests {
     use crate::engine::cp::AssignmentsInteger;
     use crate::engine::sat::AssignmentsPropositional;
     use crate::engine::variables::DomainId;
+    use crate::predicate;
 
     #[test]
     fn computing_an_eager_reason_returns_a_reference_to_the_conjunction() {
@@ -198,4 +253,79 @@ mod tests {
             reason_store.get_or_compute(reason_ref, &context)
         );
     }
+
+    #[test]
+    fn lazy_reasons_are_only_counted_once_computed() {
+        let mut reason_store = ReasonStore::default();
+        let integers = AssignmentsInteger::default();
+        let booleans = AssignmentsPropositional::default();
+        let context = PropagationContext::new(&integers, &booleans, false, false);
+
+        let x = DomainId::new(0);
+
+        let conjunction = conjunction!([x == 1]);
+        let conjunction_to_return = conjunction.clone();
+        let reason_ref = reason_store.push(
+            PropagatorId(0),
+            Reason::from(move |_: &PropagationContext| conjunction_to_return),
+        );
+        assert_eq!(0, reason_store.num_lazy_reasons_computed());
+
+        assert_eq!(
+            Some(&conjunction),
+            reason_store.get_or_compute(reason_ref, &context)
+        );
+        assert_eq!(1, reason_store.num_lazy_reasons_computed());
+
+        // The reason has been replaced by its eager version
+        let _ = reason_store.get_or_compute(reason_ref, &context);
+        assert_eq!(1, reason_store.num_lazy_reasons_computed());
+    }
+
+    #[test]
+    fn lazy_reasons_are_dropped_on_synchronise() {
+        let mut reason_store = ReasonStore::default();
+
+        reason_store.increase_decision_level();
+        let _ = reason_store.push(
+            PropagatorId(0),
+            Reason::from(|_: &PropagationContext| PropositionalConjunction::default()),
+        );
+        assert_eq!(1, reason_store.len());
+
+        reason_store.synchronise(0);
+        assert_eq!(0, reason_store.len());
+        assert_eq!(0, reason_store.num_lazy_reasons_computed());
+    }
+
+    #[test]
+    fn buffers_of_removed_reasons_are_reused() {
+        let mut reason_store = ReasonStore::default();
+        let x = DomainId::new(0);
+
+        for cycle in 0..100 {
+            reason_store.increase_decision_level();
+
+            // The reason does not fit in the inline storage of a conjunction
+            let mut reason = reason_store.new_reason();
+            reason.extend((0..6).map(|value| predicate![x != value]));
+            let _ = reason_store.push(PropagatorId(0), Reason::Eager(reason));
+
+            reason_store.synchronise(0);
+            assert_eq!(1, reason_store.free_buffers.len());
+            assert_eq!(cycle, reason_store.num_reused_buffers());
+        }
+    }
+
+    #[test]
+    fn small_reasons_do_not_allocate_buffers() {
+        let mut reason_store = ReasonStore::default();
+        let x = DomainId::new(0);
+
+        reason_store.increase_decision_level();
+        let _ = reason_store.push(PropagatorId(0), Reason::Eager(conjunction!([x == 1])));
+        reason_store.synchronise(0);
+
+        assert!(reason_store.free_buffers.is_empty());
+    }
 }
@@ -24,11 +24,13 @@ use crate::predicate;
 pub(crate) struct VariableLiteralMappings {
     /// `domain_to_equality_literals[DomainId x][i]` is the [`Literal`]
     /// that represents `[x == i + initial_lb(x)]`, where `initial_lb(x)` is
-    /// the lower bound of [`DomainId`] `x` at the time of its creation.
+    /// the lower bound of [`DomainId`] `x` at the time of its creation. For sparse domains, it
+    /// represents `[x == v]` where `v` is the `i`-th value of the initial domain.
     pub(crate) domain_to_equality_literals: KeyedVec<DomainId, Box<[Literal]>>,
     /// `domain_to_lower_bound_literals[DomainId x][i]` is the [`Literal`]
     /// that represents `[x >= i + initial_lb(x)]`, where `initial_lb(x)` is
-    /// the lower bound of [`DomainId`] `x` at the time of its creation.
+    /// the lower bound of [`DomainId`] `x` at the time of its creation. For sparse domains, it
+    /// represents `[x >= v]` where `v` is the `i`-th value of the initial domain.
     /// Note that the [`Literal`]s representing `[x <= k]` are obtained by negating `[x >= k+1]`.
     pub(crate) domain_to_lower_bound_literals: KeyedVec<DomainId, Box<[Literal]>>,
     /// `literal_to_predicates[literal]` is the vector of [`IntegerPredicate`]s associated with
@@ -36,6 +38,11 @@ pub(crate) struct VariableLiteralMappings {
     /// [`Literal`], but due to preprocessing (not currently implemented), it could be that one
     /// [`Literal`] is associated with three or more [`IntegerPredicate`]s.
     pub(crate) literal_to_predicates: KeyedVec<Literal, Vec<IntegerPredicate>>,
+    /// For domains created with [`VariableLiteralMappings::create_new_sparse_domain`], this
+    /// contains the sorted values of the initial domain; the literals of such a domain are indexed
+    /// by the position of the value rather than by the offset from the initial lower bound. For
+    /// other domains this is [`None`].
+    sparse_domain_values: KeyedVec<DomainId, Option<Box<[i32]>>>,
 }
 
 // methods for creating new variables
@@ -88,6 +95,47 @@ impl VariableLiteralMappings {
         PropositionalVariable::new(new_variable_index)
     }
 
+    /// Creates `num_variables` new propositional variables with consecutive indices; the space
+    /// for them is reserved at once rather than for every variable separately.
+    ///
+    /// Note that the variables are not registered with any predicate.
+    pub(crate) fn create_new_propositional_variables(
+        &mut self,
+        num_variables: usize,
+        watch_list_propositional: &mut WatchListPropositional,
+        clausal_propagator: &mut ClausalPropagator,
+        assignments_propositional: &mut AssignmentsPropositional,
+    ) -> impl Iterator<Item = PropositionalVariable> {
+        let first_variable_index = assignments_propositional.num_propositional_variables();
+
+        clausal_propagator.grow_by(num_variables);
+
+        watch_list_propositional.grow_by(num_variables);
+
+        assignments_propositional.grow_by(num_variables);
+
+        self.reserve(num_variables);
+        for _ in 0..num_variables {
+            self.literal_to_predicates.push(vec![]);
+            self.literal_to_predicates.push(vec![]);
+        }
+
+        (first_variable_index..first_variable_index + num_variables as u32)
+            .map(PropositionalVariable::new)
+    }
+
+    /// Reserves space for the predicates of at least `num_variables` more propositional
+    /// variables.
+    pub(crate) fn reserve(&mut self, num_variables: usize) {
+        self.literal_to_predicates.reserve(2 * num_variables);
+    }
+
+    /// Returns the number of propositional variables which can be stored without reallocating.
+    #[cfg(test)]
+    pub(crate) fn capacity(&self) -> usize {
+        self.literal_to_predicates.capacity() / 2
+    }
+
     /// Create a new integer variable and tie it to a fresh propositional representation. The given
     /// clausal propagator will be responsible for keeping the propositional representation
     /// consistent.
@@ -113,22 +161,111 @@ impl VariableLiteralMappings {
         // 2. Create the propositional representation.
         self.create_propositional_representation(
             domain_id,
-            assignments_integer,
+            lower_bound..=upper_bound,
+            watch_list_propositional,
+            clausal_propagator,
+            assignments_propositional,
+            clause_allocator,
+        );
+        self.sparse_domain_values.push(None);
+
+        domain_id
+    }
+
+    /// Create a new integer variable which can only take the provided `values`. Contrary to
+    /// [`VariableLiteralMappings::create_new_domain`], the propositional representation only
+    /// contains literals for the values in the domain; the values in the gaps between them do not
+    /// get literals of their own.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn create_new_sparse_domain(
+        &mut self,
+        mut values: Vec<i32>,
+        assignments_integer: &mut AssignmentsInteger,
+        watch_list_cp: &mut WatchListCP,
+        watch_list_propositional: &mut WatchListPropositional,
+        clausal_propagator: &mut ClausalPropagator,
+        assignments_propositional: &mut AssignmentsPropositional,
+        clause_allocator: &mut ClauseAllocator,
+    ) -> DomainId {
+        munchkin_assert_simple!(!values.is_empty(), "Cannot create an empty domain.");
+
+        values.sort();
+        values.dedup();
+
+        // 1. Create the integer/domain representation.
+        let domain_id = assignments_integer.grow_sparse(&values);
+        watch_list_cp.grow();
+
+        // 2. Create the propositional representation.
+        self.create_propositional_representation(
+            domain_id,
+            values.iter().copied(),
             watch_list_propositional,
             clausal_propagator,
             assignments_propositional,
             clause_allocator,
         );
+        self.sparse_domain_values.push(Some(values.into()));
 
         domain_id
     }
 
-    /// Eagerly create the propositional representation of the integer variable. This is done using
-    /// a unary representation.
+    /// Create a new 0-1 integer variable whose propositional representation is the provided
+    /// `literal`, i.e. `[x >= 1]` and `[x == 1]` are represented by `literal` and `[x == 0]` by its
+    /// negation. No new propositional variables or clauses are created.
+    ///
+    /// Note that this function does not take into account whether `literal` is already assigned;
+    /// the caller is responsible for synchronising the domain with the assignment.
+    pub(crate) fn create_new_literal_domain(
+        &mut self,
+        literal: Literal,
+        assignments_integer: &mut AssignmentsInteger,
+        watch_list_cp: &mut WatchListCP,
+        assignments_propositional: &AssignmentsPropositional,
+    ) -> DomainId {
+        // 1. Create the integer/domain representation.
+        let domain_id = assignments_integer.grow(0, 1);
+        watch_list_cp.grow();
+
+        // 2. Link the literal to the predicates over the domain.
+        let lower_bound_literals = [
+            assignments_propositional.true_literal,
+            literal,
+            assignments_propositional.false_literal,
+        ];
+        for (value, &lower_bound_literal) in lower_bound_literals.iter().enumerate() {
+            self.add_predicate_information_to_propositional_variable(
+                lower_bound_literal,
+                predicate![domain_id >= value as i32].try_into().unwrap(),
+            );
+        }
+
+        let equality_literals = [!literal, literal];
+        for (value, &equality_literal) in equality_literals.iter().enumerate() {
+            self.add_predicate_information_to_propositional_variable(
+                equality_literal,
+                predicate![domain_id == value as i32].try_into().unwrap(),
+            );
+        }
+
+        self.domain_to_lower_bound_literals
+            .push(lower_bound_literals.into());
+        self.domain_to_equality_literals
+            .push(equality_literals.into());
+        self.sparse_domain_values.push(None);
+
+        domain_id
+    }
+
+    /// Eagerly create the propositional representation of the integer variable over the (sorted)
+    /// `values` in its initial domain. This is done using a unary representation.
+    ///
+    /// The values are provided as an iterator so that a dense domain does not need to collect the
+    /// values between its bounds.
     fn create_propositional_representation(
         &mut self,
         domain_id: DomainId,
-        assignments_integer: &AssignmentsInteger,
+        values: impl Iterator<Item = i32> + Clone,
         watch_list_propositional: &mut WatchListPropositional,
         clausal_propagator: &mut ClausalPropagator,
         assignments_propositional: &mut AssignmentsPropositional,
@@ -136,33 +273,92 @@ impl VariableLiteralMappings {
     ) {
         let lower_bound_literals = self.create_lower_bound_literals(
             domain_id,
-            assignments_integer,
+            values.clone(),
             watch_list_propositional,
             clausal_propagator,
             assignments_propositional,
-            clause_allocator,
         );
 
         let equality_literals = self.create_equality_literals(
             domain_id,
+            values,
             &lower_bound_literals,
-            assignments_integer,
             watch_list_propositional,
             clausal_propagator,
             assignments_propositional,
-            clause_allocator,
         );
 
         self.domain_to_lower_bound_literals
             .push(lower_bound_literals);
 
-        self.domain_to_equality_literals
-            .push(equality_literals.clone());
+        self.domain_to_equality_literals.push(equality_literals);
+
+        self.add_encoding_clauses(
+            domain_id,
+            clausal_propagator,
+            assignments_propositional,
+            clause_allocator,
+        );
+    }
+
+    /// Adds the clauses which keep the propositional representation of `domain_id` consistent.
+    ///
+    /// Besides being used when creating the domain, this is used to restore the representation
+    /// of the domains which were created after a snapshot when rolling back to it, since the
+    /// clauses of those domains are removed along with the other clauses which were added after
+    /// the snapshot.
+    pub(crate) fn add_encoding_clauses(
+        &self,
+        domain_id: DomainId,
+        clausal_propagator: &mut ClausalPropagator,
+        assignments_propositional: &mut AssignmentsPropositional,
+        clause_allocator: &mut ClauseAllocator,
+    ) {
+        let lower_bound_literals = &self.domain_to_lower_bound_literals[domain_id];
+        let equality_literals = &self.domain_to_equality_literals[domain_id];
+        let num_values = equality_literals.len();
+
+        // Enforce consistency over the lower bound literals by adding the following clause:
+        // [x >= values[i + 1]] -> [x >= values[i]].
+        //
+        // Special case (skipped in the loop): [x >= values[1]] -> [x >= lb(x)], but
+        // [x >= lb(x)] is trivially true.
+        for idx in 2..num_values {
+            clausal_propagator.add_permanent_implication_unchecked(
+                lower_bound_literals[idx],
+                lower_bound_literals[idx - 1],
+            );
+        }
+
+        // Enforce consistency of the equality literals through the following clauses:
+        // [x == values[i]] <-> [x >= values[i]] AND ~[x >= values[i + 1]]
+        //
+        // The equality literals for the bounds are skipped, as they are already defined above.
+        for idx in 1..num_values.saturating_sub(1) {
+            // One side of the implication <-
+            clausal_propagator.add_permanent_ternary_clause_unchecked(
+                !lower_bound_literals[idx],
+                lower_bound_literals[idx + 1],
+                equality_literals[idx],
+                clause_allocator,
+            );
+
+            // The other side of the implication ->
+            clausal_propagator.add_permanent_implication_unchecked(
+                equality_literals[idx],
+                lower_bound_literals[idx],
+            );
+
+            clausal_propagator.add_permanent_implication_unchecked(
+                equality_literals[idx],
+                !lower_bound_literals[idx + 1],
+            );
+        }
 
         // Add clause to select at least one equality.
         clausal_propagator
             .add_permanent_clause(
-                equality_literals.into(),
+                equality_literals.to_vec(),
                 assignments_propositional,
                 clause_allocator,
             )
@@ -170,29 +366,28 @@ impl VariableLiteralMappings {
     }
 
     /// Create the literals representing [x == v] for all values v in the initial domain.
-    #[allow(clippy::too_many_arguments)]
     fn create_equality_literals(
         &mut self,
         domain_id: DomainId,
+        values: impl Iterator<Item = i32> + Clone,
         lower_bound_literals: &[Literal],
-        assignments_integer: &AssignmentsInteger,
         watch_list_propositional: &mut WatchListPropositional,
         clausal_propagator: &mut ClausalPropagator,
         assignments_propositional: &mut AssignmentsPropositional,
-        clause_allocator: &mut ClauseAllocator,
     ) -> Box<[Literal]> {
         assert!(
             lower_bound_literals.len() >= 2,
             "the lower bound literals should contain at least two literals"
         );
 
-        let lower_bound = assignments_integer.get_lower_bound(domain_id);
-        let upper_bound = assignments_integer.get_upper_bound(domain_id);
+        let num_values = lower_bound_literals.len() - 1;
+        let lower_bound = values.clone().next().expect("the domain is not empty");
+        let upper_bound = values.clone().last().expect("the domain is not empty");
 
-        // The literal at index i is [x == lb(x) + i].
+        // The literal at index i is [x == values[i]].
         let mut equality_literals: Vec<Literal> = Vec::new();
 
-        // Edge case where i = 0: [x == lb(x)] <-> ~[x >= lb(x) + 1]
+        // Edge case where i = 0: [x == lb(x)] <-> ~[x >= values[1]]
         equality_literals.push(!lower_bound_literals[1]);
 
         // Add the predicate information to the [x == lower_bound] literal.
@@ -204,7 +399,7 @@ impl VariableLiteralMappings {
             predicate![domain_id == lower_bound].try_into().unwrap(),
         );
 
-        for value in (lower_bound + 1)..upper_bound {
+        for value in values.take(num_values - 1).skip(1) {
             let propositional_variable = self.create_new_propositional_variable_with_predicate(
                 watch_list_propositional,
                 predicate![domain_id == value].try_into().unwrap(),
@@ -229,58 +424,28 @@ impl VariableLiteralMappings {
             );
         }
 
-        munchkin_assert_eq_simple!(
-            equality_literals.len(),
-            (upper_bound - lower_bound + 1) as usize
-        );
-
-        // Enforce consistency of the equality literals through the following clauses:
-        // [x == value] <-> [x >= value] AND ~[x >= value + 1]
-        //
-        // The equality literals for the bounds are skipped, as they are already defined above.
-        for value in (lower_bound + 1)..upper_bound {
-            let idx = value.abs_diff(lower_bound) as usize;
-
-            // One side of the implication <-
-            clausal_propagator.add_permanent_ternary_clause_unchecked(
-                !lower_bound_literals[idx],
-                lower_bound_literals[idx + 1],
-                equality_literals[idx],
-                clause_allocator,
-            );
-
-            // The other side of the implication ->
-            clausal_propagator.add_permanent_implication_unchecked(
-                equality_literals[idx],
-                lower_bound_literals[idx],
-                clause_allocator,
-            );
-
-            clausal_propagator.add_permanent_implication_unchecked(
-                equality_literals[idx],
-                !lower_bound_literals[idx + 1],
-                clause_allocator,
-            );
-        }
+        munchkin_assert_eq_simple!(equality_literals.len(), num_values);
 
         equality_literals.into()
     }
 
-    /// Eagerly create the literals that encode the bounds of the integer variable.
-    #[allow(clippy::too_many_arguments)]
+    /// Eagerly create the literals that encode the bounds of the integer variable. Only the
+    /// literals `[x >= v]` for the values `v` in the initial domain are created, since for a value
+    /// `w` in a gap, `[x >= w]` is equivalent to `[x >= v]` where `v` is the next value in the
+    /// domain.
     fn create_lower_bound_literals(
         &mut self,
         domain_id: DomainId,
-        assignments_integer: &AssignmentsInteger,
+        values: impl Iterator<Item = i32> + Clone,
         watch_list_propositional: &mut WatchListPropositional,
         clausal_propagator: &mut ClausalPropagator,
         assignments_propositional: &mut AssignmentsPropositional,
-        clause_allocator: &mut ClauseAllocator,
     ) -> Box<[Literal]> {
-        let lower_bound = assignments_integer.get_lower_bound(domain_id);
-        let upper_bound = assignments_integer.get_upper_bound(domain_id);
+        let num_values = values.clone().count();
+        let lower_bound = values.clone().next().expect("the domain is not empty");
+        let upper_bound = values.clone().last().expect("the domain is not empty");
 
-        // The literal at index i is [x >= lb(x) + i].
+        // The literal at index i is [x >= values[i]].
         let mut lower_bound_literals = Vec::new();
 
         // The integer variable will always be at least the lower bound of the initial domain.
@@ -290,7 +455,7 @@ impl VariableLiteralMappings {
             predicate![domain_id >= lower_bound].try_into().unwrap(),
         );
 
-        for value in (lower_bound + 1)..=upper_bound {
+        for value in values.skip(1) {
             let propositional_variable = self.create_new_propositional_variable_with_predicate(
                 watch_list_propositional,
                 predicate![domain_id >= value].try_into().unwrap(),
@@ -308,25 +473,7 @@ impl VariableLiteralMappings {
             predicate![domain_id >= upper_bound + 1].try_into().unwrap(),
         );
 
-        munchkin_assert_eq_simple!(
-            lower_bound_literals.len(),
-            (upper_bound - lower_bound + 2) as usize
-        );
-
-        // Enforce consistency over the lower bound literals by adding the following clause:
-        // [x >= v + 1] -> [x >= v].
-        //
-        // Special case (skipped in the loop): [x >= lb(x) + 1] -> [x >= lb(x)], but
-        // [x >= lb(x)] is trivially true.
-        for v in (lower_bound + 2)..=upper_bound {
-            let idx = v.abs_diff(lower_bound) as usize;
-
-            clausal_propagator.add_permanent_implication_unchecked(
-                lower_bound_literals[idx],
-                lower_bound_literals[idx - 1],
-                clause_allocator,
-            );
-        }
+        munchkin_assert_eq_simple!(lower_bound_literals.len(), num_values + 1);
 
         lower_bound_literals.into()
     }
@@ -361,6 +508,19 @@ impl VariableLiteralMappings {
             .map(|predicate| predicate.get_domain())
     }
 
+    /// Returns the literals of the propositional representation of `domain_id`, i.e. its lower
+    /// bound literals and its equality literals; these may contain duplicates and include the
+    /// trivially true and false literals.
+    pub(crate) fn get_domain_literals(
+        &self,
+        domain_id: DomainId,
+    ) -> impl Iterator<Item = Literal> + '_ {
+        self.domain_to_lower_bound_literals[domain_id]
+            .iter()
+            .chain(self.domain_to_equality_literals[domain_id].iter())
+            .copied()
+    }
+
     ///  Returns a literal which corresponds to the provided [`IntegerPredicate`].
     pub(crate) fn get_literal(
         &self,
@@ -426,7 +586,11 @@ impl VariableLiteralMappings {
             return assignments_propositional.false_literal;
         }
 
-        let literal_idx = lower_bound.abs_diff(initial_lower_bound) as usize;
+        let literal_idx = match &self.sparse_domain_values[domain] {
+            // A value in a gap has the same literal as the next value in the domain
+            Some(values) => values.partition_point(|&value| value < lower_bound),
+            None => lower_bound.abs_diff(initial_lower_bound) as usize,
+        };
         self.domain_to_lower_bound_literals[domain][literal_idx]
     }
 
@@ -459,7 +623,14 @@ impl VariableLiteralMappings {
             return assignments_propositional.false_literal;
         }
 
-        let literal_idx = equality_constant.abs_diff(initial_lower_bound) as usize;
+        let literal_idx = match &self.sparse_domain_values[domain] {
+            Some(values) => match values.binary_search(&equality_constant) {
+                Ok(idx) => idx,
+                // The value is not in the initial domain
+                Err(_) => return assignments_propositional.false_literal,
+            },
+            None => equality_constant.abs_diff(initial_lower_bound) as usize,
+        };
         self.domain_to_equality_literals[domain][literal_idx]
     }
 
@@ -478,13 +649,32 @@ impl VariableLiteralMappings {
         )
     }
 
-    #[allow(unused, reason = "will be used in the assignments")]
+    /// Returns the [`IntegerPredicate`]s which are attached to the provided `literal`; note that
+    /// the polarity of the literal is respected, i.e. the predicates of `!literal` are the
+    /// negations of the predicates of `literal`.
     pub(crate) fn get_predicates_for_literal(
         &self,
         literal: Literal,
     ) -> impl Iterator<Item = IntegerPredicate> + '_ {
         self.literal_to_predicates[literal].iter().copied()
     }
+
+    /// Returns the most specific [`IntegerPredicate`] attached to the provided `literal` or
+    /// [`None`] if no predicate is attached to it.
+    ///
+    /// A literal can be linked to multiple (equivalent) predicates; e.g. the literal `[x >= 1]`
+    /// over a domain `[0, 5]` also represents `[x != 0]`. The predicates are ranked as follows:
+    /// [`IntegerPredicate::Equal`] first, then the bound predicates, and finally
+    /// [`IntegerPredicate::NotEqual`]. Ties are broken by the order in which the predicates were
+    /// attached to the literal.
+    pub(crate) fn get_canonical_predicate(&self, literal: Literal) -> Option<IntegerPredicate> {
+        self.get_predicates_for_literal(literal)
+            .min_by_key(|predicate| match predicate {
+                IntegerPredicate::Equal { .. } => 0,
+                IntegerPredicate::LowerBound { .. } | IntegerPredicate::UpperBound { .. } => 1,
+                IntegerPredicate::NotEqual { .. } => 2,
+            })
+    }
 }
 
 #[cfg(test)]
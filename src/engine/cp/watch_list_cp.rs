@@ -2,15 +2,56 @@ use enumset::EnumSet;
 use enumset::EnumSetType;
 
 use crate::basic_types::KeyedVec;
+use crate::basic_types::StorageKey;
+use crate::engine::cp::propagation::PropagatorId;
 use crate::engine::cp::propagation::PropagatorVarId;
 use crate::engine::variables::DomainId;
+use crate::munchkin_assert_simple;
 
+/// The watch lists of the integer domains.
+///
+/// Propagators are detached lazily: a propagator which is marked as inactive (see
+/// [`WatchListCP::mark_inactive`]) keeps its entries, but these are skipped when the affected
+/// propagators of an event are retrieved. Once more than half of the entries belong to inactive
+/// propagators, the entries are removed by [`WatchListCP::compact`]. The entries of a propagator
+/// which may be reactivated (see [`WatchListCP::mark_active`]) are set aside when they are
+/// removed, and are restored when the propagator is reactivated.
 #[derive(Default, Debug)]
 pub(crate) struct WatchListCP {
     watchers: KeyedVec<DomainId, WatcherCP>, /* contains propagator ids of propagators that
                                               * watch domain changes of the i-th integer
                                               * variable */
     is_watching_anything: bool,
+    is_watching_any_backtrack_events: bool,
+    /// The status of every propagator which has subscribed to an event.
+    statuses: KeyedVec<PropagatorId, PropagatorStatus>,
+    /// The number of entries which every propagator has in the watch lists.
+    num_entries_of_propagator: KeyedVec<PropagatorId, usize>,
+    /// The total number of entries in the watch lists.
+    num_entries: usize,
+    /// The number of entries in the watch lists which belong to inactive propagators.
+    num_inactive_entries: usize,
+    /// The entries of the inactive propagators which were removed by [`WatchListCP::compact`].
+    detached_entries: KeyedVec<PropagatorId, Vec<DetachedEntry>>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum PropagatorStatus {
+    #[default]
+    Active,
+    /// The propagator is not notified of events, but it can be reactivated.
+    Inactive,
+    /// The propagator is not notified of events and cannot be reactivated.
+    Removed,
+}
+
+/// An entry of the watch lists which was removed by [`WatchListCP::compact`].
+#[derive(Clone, Copy, Debug)]
+struct DetachedEntry {
+    domain: DomainId,
+    event: IntDomainEvent,
+    is_backtrack_event: bool,
+    propagator_var: PropagatorVarId,
 }
 
 #[derive(Debug)]
@@ -44,18 +85,235 @@ impl WatchListCP {
         self.is_watching_anything
     }
 
+    /// Returns the approximate number of bytes used by the watch lists, counting the watchers of
+    /// every domain but not the individual entries.
+    pub(crate) fn approximate_memory_usage(&self) -> usize {
+        self.watchers.approximate_memory_usage()
+    }
+
+    pub(crate) fn is_watching_any_backtrack_events(&self) -> bool {
+        self.is_watching_any_backtrack_events
+    }
+
+    /// Returns the active propagators which are subscribed to the given event on the provided
+    /// domain.
     pub(crate) fn get_affected_propagators(
         &self,
         event: IntDomainEvent,
         domain: DomainId,
-    ) -> &[PropagatorVarId] {
-        let watcher = &self.watchers[domain];
+    ) -> impl Iterator<Item = PropagatorVarId> + '_ {
+        self.watchers[domain]
+            .forward_watcher
+            .get(event)
+            .iter()
+            .copied()
+            .filter(|propagator_var| self.is_active(propagator_var.propagator))
+    }
 
-        match event {
-            IntDomainEvent::Assign => &watcher.forward_watcher.assign_watchers,
-            IntDomainEvent::LowerBound => &watcher.forward_watcher.lower_bound_watchers,
-            IntDomainEvent::UpperBound => &watcher.forward_watcher.upper_bound_watchers,
-            IntDomainEvent::Removal => &watcher.forward_watcher.removal_watchers,
+    /// Returns the active propagators which are subscribed to the given event being undone on the
+    /// provided domain while backtracking.
+    pub(crate) fn get_backtrack_affected_propagators(
+        &self,
+        event: IntDomainEvent,
+        domain: DomainId,
+    ) -> impl Iterator<Item = PropagatorVarId> + '_ {
+        self.watchers[domain]
+            .backtrack_watcher
+            .get(event)
+            .iter()
+            .copied()
+            .filter(|propagator_var| self.is_active(propagator_var.propagator))
+    }
+
+    /// Returns whether the provided propagator is notified of the events to which it subscribed.
+    pub(crate) fn is_active(&self, propagator: PropagatorId) -> bool {
+        self.status(propagator) == PropagatorStatus::Active
+    }
+
+    /// Marks the provided propagator as inactive, after which it is no longer notified of any
+    /// events until it is reactivated using [`WatchListCP::mark_active`].
+    pub(crate) fn mark_inactive(&mut self, propagator: PropagatorId) {
+        if self.status(propagator) != PropagatorStatus::Active {
+            return;
+        }
+
+        self.set_status(propagator, PropagatorStatus::Inactive);
+        self.num_inactive_entries += self.num_entries_of(propagator);
+        self.compact_if_mostly_inactive();
+    }
+
+    /// Reactivates a propagator which was marked as inactive, after which it is notified of the
+    /// events to which it subscribed again.
+    pub(crate) fn mark_active(&mut self, propagator: PropagatorId) {
+        munchkin_assert_simple!(
+            self.status(propagator) != PropagatorStatus::Removed,
+            "A removed propagator cannot be reactivated"
+        );
+        if self.status(propagator) != PropagatorStatus::Inactive {
+            return;
+        }
+
+        self.set_status(propagator, PropagatorStatus::Active);
+        self.num_inactive_entries -= self.num_entries_of(propagator);
+
+        let detached_entries = std::mem::take(&mut self.detached_entries[propagator]);
+        for entry in detached_entries {
+            let watcher = &mut self.watchers[entry.domain];
+            let watcher = if entry.is_backtrack_event {
+                &mut watcher.backtrack_watcher
+            } else {
+                &mut watcher.forward_watcher
+            };
+            watcher.get_mut(entry.event).push(entry.propagator_var);
+            self.num_entries_of_propagator[propagator] += 1;
+            self.num_entries += 1;
+        }
+    }
+
+    /// Removes every subscription of the provided propagator, after which it is no longer notified
+    /// of any events; unlike [`WatchListCP::mark_inactive`], the propagator cannot be reactivated.
+    pub(crate) fn remove_propagator(&mut self, propagator: PropagatorId) {
+        match self.status(propagator) {
+            PropagatorStatus::Active => {
+                self.num_inactive_entries += self.num_entries_of(propagator);
+            }
+            PropagatorStatus::Inactive => {
+                self.detached_entries[propagator].clear();
+            }
+            PropagatorStatus::Removed => return,
+        }
+
+        self.set_status(propagator, PropagatorStatus::Removed);
+        self.compact_if_mostly_inactive();
+    }
+
+    /// Removes the propagators with an index of at least `num_propagators`, such that their
+    /// identifiers can be reused by new propagators.
+    pub(crate) fn truncate_propagators(&mut self, num_propagators: usize) {
+        for index in num_propagators..self.statuses.len() {
+            self.remove_propagator(PropagatorId(index as u32));
+        }
+        self.compact();
+
+        self.statuses.truncate(num_propagators);
+        self.num_entries_of_propagator.truncate(num_propagators);
+        self.detached_entries.truncate(num_propagators);
+    }
+
+    /// Removes the entries of the inactive propagators from the watch lists; the entries of the
+    /// propagators which can be reactivated are set aside until they are.
+    pub(crate) fn compact(&mut self) {
+        if self.num_inactive_entries == 0 {
+            return;
+        }
+
+        for (domain, watcher) in self.watchers.iter_mut().enumerate() {
+            let domain = DomainId::new(domain as u32);
+            for (is_backtrack_event, watcher) in [
+                (false, &mut watcher.forward_watcher),
+                (true, &mut watcher.backtrack_watcher),
+            ] {
+                for event in EnumSet::<IntDomainEvent>::all() {
+                    watcher.get_mut(event).retain(|&propagator_var| {
+                        let propagator = propagator_var.propagator;
+                        match self.statuses[propagator] {
+                            PropagatorStatus::Active => true,
+                            PropagatorStatus::Inactive => {
+                                self.detached_entries[propagator].push(DetachedEntry {
+                                    domain,
+                                    event,
+                                    is_backtrack_event,
+                                    propagator_var,
+                                });
+                                false
+                            }
+                            PropagatorStatus::Removed => false,
+                        }
+                    });
+                }
+            }
+        }
+
+        for (status, num_entries) in self
+            .statuses
+            .iter()
+            .zip(self.num_entries_of_propagator.iter_mut())
+        {
+            if *status != PropagatorStatus::Active {
+                *num_entries = 0;
+            }
+        }
+        self.num_entries -= self.num_inactive_entries;
+        self.num_inactive_entries = 0;
+    }
+
+    /// Returns the total number of entries in the watch lists, including the entries of the
+    /// inactive propagators which have not been compacted yet.
+    #[cfg(test)]
+    pub(crate) fn num_entries(&self) -> usize {
+        self.num_entries
+    }
+}
+
+// private functions
+impl WatchListCP {
+    fn status(&self, propagator: PropagatorId) -> PropagatorStatus {
+        if propagator.index() < self.statuses.len() {
+            self.statuses[propagator]
+        } else {
+            PropagatorStatus::Active
+        }
+    }
+
+    fn set_status(&mut self, propagator: PropagatorId, status: PropagatorStatus) {
+        self.accomodate(propagator);
+        self.statuses[propagator] = status;
+    }
+
+    fn num_entries_of(&self, propagator: PropagatorId) -> usize {
+        if propagator.index() < self.num_entries_of_propagator.len() {
+            self.num_entries_of_propagator[propagator]
+        } else {
+            0
+        }
+    }
+
+    fn accomodate(&mut self, propagator: PropagatorId) {
+        self.statuses
+            .accomodate(propagator, PropagatorStatus::Active);
+        self.num_entries_of_propagator.accomodate(propagator, 0);
+        self.detached_entries.accomodate(propagator, vec![]);
+    }
+
+    fn compact_if_mostly_inactive(&mut self) {
+        if 2 * self.num_inactive_entries > self.num_entries {
+            self.compact();
+        }
+    }
+
+    fn add_entry(
+        &mut self,
+        domain: DomainId,
+        events: EnumSet<IntDomainEvent>,
+        is_backtrack_event: bool,
+        propagator_var: PropagatorVarId,
+    ) {
+        self.accomodate(propagator_var.propagator);
+
+        let watcher = &mut self.watchers[domain];
+        let watcher = if is_backtrack_event {
+            &mut watcher.backtrack_watcher
+        } else {
+            &mut watcher.forward_watcher
+        };
+
+        for event in events {
+            let event_watcher = watcher.get_mut(event);
+            if !event_watcher.contains(&propagator_var) {
+                event_watcher.push(propagator_var);
+                self.num_entries_of_propagator[propagator_var.propagator] += 1;
+                self.num_entries += 1;
+            }
         }
     }
 }
@@ -70,26 +328,26 @@ impl<'a> Watchers<'a> {
 
     pub(crate) fn watch_all(&mut self, domain: DomainId, events: EnumSet<IntDomainEvent>) {
         self.watch_list.is_watching_anything = true;
-        let watcher = &mut self.watch_list.watchers[domain];
-
-        for event in events {
-            let event_watcher = match event {
-                IntDomainEvent::LowerBound => &mut watcher.forward_watcher.lower_bound_watchers,
-                IntDomainEvent::UpperBound => &mut watcher.forward_watcher.upper_bound_watchers,
-                IntDomainEvent::Assign => &mut watcher.forward_watcher.assign_watchers,
-                IntDomainEvent::Removal => &mut watcher.forward_watcher.removal_watchers,
-            };
+        self.watch_list
+            .add_entry(domain, events, false, self.propagator_var);
+    }
 
-            if !event_watcher.contains(&self.propagator_var) {
-                event_watcher.push(self.propagator_var);
-            }
-        }
+    /// Subscribes to the given events being undone on the domain when the solver backtracks.
+    pub(crate) fn watch_all_backtrack(
+        &mut self,
+        domain: DomainId,
+        events: EnumSet<IntDomainEvent>,
+    ) {
+        self.watch_list.is_watching_any_backtrack_events = true;
+        self.watch_list
+            .add_entry(domain, events, true, self.propagator_var);
     }
 }
 
 #[derive(Default, Debug)]
 struct WatcherCP {
     forward_watcher: Watcher,
+    backtrack_watcher: Watcher,
 }
 
 #[derive(Debug, Default)]
@@ -99,3 +357,131 @@ struct Watcher {
     assign_watchers: Vec<PropagatorVarId>,
     removal_watchers: Vec<PropagatorVarId>,
 }
+
+impl Watcher {
+    fn get(&self, event: IntDomainEvent) -> &Vec<PropagatorVarId> {
+        match event {
+            IntDomainEvent::Assign => &self.assign_watchers,
+            IntDomainEvent::LowerBound => &self.lower_bound_watchers,
+            IntDomainEvent::UpperBound => &self.upper_bound_watchers,
+            IntDomainEvent::Removal => &self.removal_watchers,
+        }
+    }
+
+    fn get_mut(&mut self, event: IntDomainEvent) -> &mut Vec<PropagatorVarId> {
+        match event {
+            IntDomainEvent::Assign => &mut self.assign_watchers,
+            IntDomainEvent::LowerBound => &mut self.lower_bound_watchers,
+            IntDomainEvent::UpperBound => &mut self.upper_bound_watchers,
+            IntDomainEvent::Removal => &mut self.removal_watchers,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use enumset::enum_set;
+
+    use super::IntDomainEvent;
+    use super::WatchListCP;
+    use super::Watchers;
+    use crate::engine::cp::propagation::LocalId;
+    use crate::engine::cp::propagation::PropagatorId;
+    use crate::engine::cp::propagation::PropagatorVarId;
+    use crate::engine::variables::DomainId;
+
+    /// Creates a watch list over a single domain to which the propagators subscribe to the lower
+    /// bound and upper bound events, and returns it together with the domain.
+    fn watch_list_with(num_propagators: u32) -> (WatchListCP, DomainId) {
+        let mut watch_list = WatchListCP::default();
+        watch_list.grow();
+        let domain = DomainId::new(0);
+
+        for propagator in 0..num_propagators {
+            let propagator_var = PropagatorVarId {
+                propagator: PropagatorId(propagator),
+                variable: LocalId::from(0),
+            };
+            let mut watchers = Watchers::new(propagator_var, &mut watch_list);
+            watchers.watch_all(
+                domain,
+                enum_set!(IntDomainEvent::LowerBound | IntDomainEvent::UpperBound),
+            );
+        }
+
+        (watch_list, domain)
+    }
+
+    fn affected_propagators(watch_list: &WatchListCP, domain: DomainId) -> Vec<PropagatorId> {
+        watch_list
+            .get_affected_propagators(IntDomainEvent::LowerBound, domain)
+            .map(|propagator_var| propagator_var.propagator)
+            .collect()
+    }
+
+    #[test]
+    fn inactive_propagators_are_not_notified() {
+        let (mut watch_list, domain) = watch_list_with(3);
+
+        watch_list.mark_inactive(PropagatorId(1));
+
+        assert_eq!(
+            affected_propagators(&watch_list, domain),
+            vec![PropagatorId(0), PropagatorId(2)]
+        );
+        // The entries are only removed once most of them are inactive
+        assert_eq!(watch_list.num_entries(), 6);
+    }
+
+    #[test]
+    fn watch_lists_are_compacted_once_most_entries_are_inactive() {
+        let (mut watch_list, domain) = watch_list_with(3);
+
+        watch_list.mark_inactive(PropagatorId(0));
+        watch_list.remove_propagator(PropagatorId(1));
+
+        assert_eq!(watch_list.num_entries(), 2);
+        assert_eq!(
+            affected_propagators(&watch_list, domain),
+            vec![PropagatorId(2)]
+        );
+    }
+
+    #[test]
+    fn reactivated_propagators_are_notified_again() {
+        let (mut watch_list, domain) = watch_list_with(3);
+
+        watch_list.mark_inactive(PropagatorId(0));
+        watch_list.mark_inactive(PropagatorId(1));
+        assert_eq!(watch_list.num_entries(), 2);
+
+        watch_list.mark_active(PropagatorId(0));
+
+        assert_eq!(watch_list.num_entries(), 4);
+        let mut affected = affected_propagators(&watch_list, domain);
+        affected.sort_by_key(|propagator| propagator.0);
+        assert_eq!(affected, vec![PropagatorId(0), PropagatorId(2)]);
+        assert_eq!(
+            watch_list
+                .get_affected_propagators(IntDomainEvent::UpperBound, domain)
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn truncated_propagators_are_removed() {
+        let (mut watch_list, domain) = watch_list_with(3);
+        watch_list.mark_inactive(PropagatorId(2));
+
+        watch_list.truncate_propagators(1);
+
+        assert_eq!(watch_list.num_entries(), 2);
+        assert_eq!(
+            affected_propagators(&watch_list, domain),
+            vec![PropagatorId(0)]
+        );
+        // The identifiers of the truncated propagators can be reused
+        assert!(watch_list.is_active(PropagatorId(2)));
+    }
+}
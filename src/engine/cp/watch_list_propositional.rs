@@ -2,6 +2,7 @@ use enumset::EnumSet;
 use enumset::EnumSetType;
 
 use crate::basic_types::KeyedVec;
+use crate::engine::cp::propagation::PropagatorId;
 use crate::engine::cp::propagation::PropagatorVarId;
 use crate::engine::variables::Literal;
 
@@ -53,10 +54,48 @@ impl WatchListPropositional {
         self.watchers.push(WatcherPropositional::default());
     }
 
+    /// Adds the watchers of `num_variables` new variables, reserving the space for them at once.
+    pub(crate) fn grow_by(&mut self, num_variables: usize) {
+        self.reserve(num_variables);
+        for _ in 0..num_variables {
+            self.grow();
+        }
+    }
+
+    /// Reserves space for the watchers of at least `num_variables` more variables.
+    pub(crate) fn reserve(&mut self, num_variables: usize) {
+        self.watchers.reserve(2 * num_variables);
+    }
+
+    /// Returns the number of variables which can be stored without reallocating.
+    #[cfg(test)]
+    pub(crate) fn capacity(&self) -> usize {
+        self.watchers.capacity() / 2
+    }
+
     pub(crate) fn is_watching_anything(&self) -> bool {
         self.is_watching_anything
     }
 
+    /// Returns the approximate number of bytes used by the watch lists, counting the watchers of
+    /// every literal but not the individual entries.
+    pub(crate) fn approximate_memory_usage(&self) -> usize {
+        self.watchers.approximate_memory_usage()
+    }
+
+    /// Removes every subscription of the provided propagator, after which it is no longer notified
+    /// of any events.
+    pub(crate) fn remove_propagator(&mut self, propagator: PropagatorId) {
+        for watcher in self.watchers.iter_mut() {
+            watcher
+                .assigned_true_watchers
+                .retain(|propagator_var| propagator_var.propagator != propagator);
+            watcher
+                .assigned_false_watchers
+                .retain(|propagator_var| propagator_var.propagator != propagator);
+        }
+    }
+
     pub(crate) fn get_affected_propagators(
         &self,
         event: BooleanDomainEvent,
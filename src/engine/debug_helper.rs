@@ -1,38 +1,35 @@
 use std::fmt::Debug;
 use std::fmt::Formatter;
 
-#[cfg(any(feature = "explanation-checks", test))]
+#[cfg(any(feature = "explanation-checks", test, doc))]
 use log::debug;
 use log::warn;
 
-#[cfg(any(feature = "explanation-checks", test))]
+#[cfg(any(feature = "explanation-checks", test, doc))]
 use super::cp::propagation::PropagationContext;
-#[cfg(any(feature = "explanation-checks", test))]
+#[cfg(any(feature = "explanation-checks", test, doc))]
 use super::cp::reason::ReasonStore;
-#[cfg(any(feature = "explanation-checks", test))]
+#[cfg(any(feature = "explanation-checks", test, doc))]
 use super::predicates::integer_predicate::IntegerPredicate;
-#[cfg(any(feature = "explanation-checks", test))]
+#[cfg(any(feature = "explanation-checks", test, doc))]
 use super::predicates::integer_predicate::IntegerPredicateConversionError;
-use super::sat::ClauseAllocator;
-#[cfg(any(feature = "explanation-checks", test))]
+#[cfg(any(feature = "explanation-checks", test, doc))]
 use super::termination::TerminationCondition;
-#[cfg(any(feature = "explanation-checks", test))]
+#[cfg(any(feature = "explanation-checks", test, doc))]
 use crate::basic_types::HashSet;
 use crate::basic_types::KeyedVec;
-#[cfg(any(feature = "explanation-checks", test))]
+#[cfg(any(feature = "explanation-checks", test, doc))]
 use crate::basic_types::PropositionalConjunction;
 use crate::engine::cp::propagation::PropagationContextMut;
 use crate::engine::cp::propagation::Propagator;
 use crate::engine::cp::propagation::PropagatorId;
 use crate::engine::cp::AssignmentsInteger;
-#[cfg(any(feature = "explanation-checks", test))]
+#[cfg(any(feature = "explanation-checks", test, doc))]
 use crate::engine::cp::VariableLiteralMappings;
-#[cfg(any(feature = "explanation-checks", test))]
+#[cfg(any(feature = "explanation-checks", test, doc))]
 use crate::engine::predicates::predicate::Predicate;
 use crate::engine::sat::AssignmentsPropositional;
-use crate::engine::sat::ClausalPropagator;
-use crate::munchkin_assert_simple;
-#[cfg(any(feature = "explanation-checks", test))]
+#[cfg(any(feature = "explanation-checks", test, doc))]
 use crate::predicates::PredicateConstructor;
 
 #[derive(Copy, Clone)]
@@ -55,16 +52,24 @@ impl Debug for DebugDyn<'_> {
 #[derive(Debug, Copy, Clone)]
 pub(crate) struct DebugHelper {}
 
+/// A predicate which can be removed from the explanation of a propagation while the propagation
+/// still follows; see [`DebugHelper::debug_check_explanation_minimality`].
+#[cfg(any(feature = "explanation-checks", test, doc))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct RemovablePredicate {
+    pub(crate) propagator_id: PropagatorId,
+    pub(crate) propagated_predicate: IntegerPredicate,
+    pub(crate) removable_predicate: Predicate,
+}
+
 impl DebugHelper {
     // this method is only to be called after the solver completed propagation until a fixed point
     // and no conflict were detected  the point is to check whether there is a propagation that
     // missed a propagation or failure  additionally checks whether the internal data structures
     // of the clausal propagator are okay and consistent with the assignments_propositional
     pub(crate) fn debug_fixed_point_propagation(
-        clausal_propagator: &ClausalPropagator,
         assignments_integer: &AssignmentsInteger,
         assignments_propositional: &AssignmentsPropositional,
-        clause_allocator: &ClauseAllocator,
         propagators_cp: &KeyedVec<PropagatorId, Box<dyn Propagator>>,
         use_non_generic_conflict_explanation: bool,
         use_non_generic_propagation_explanation: bool,
@@ -133,10 +138,6 @@ impl DebugHelper {
                 panic!("Missed propositional propagations");
             }
         }
-        // then check the clausal propagator
-        munchkin_assert_simple!(
-            clausal_propagator.debug_check_state(assignments_propositional, clause_allocator)
-        );
         true
     }
 
@@ -144,7 +145,7 @@ impl DebugHelper {
         clippy::too_many_arguments,
         reason = "Should be refactored in the future"
     )]
-    #[cfg(feature = "explanation-checks")]
+    #[cfg(any(feature = "explanation-checks", test, doc))]
     pub(crate) fn debug_reported_failure(
         assignments_integer: &AssignmentsInteger,
         assignments_propositional: &AssignmentsPropositional,
@@ -177,7 +178,7 @@ impl DebugHelper {
         clippy::too_many_arguments,
         reason = "Should be refactored in the future"
     )]
-    #[cfg(feature = "explanation-checks")]
+    #[cfg(any(feature = "explanation-checks", test, doc))]
     fn debug_reported_propagations_reproduce_failure(
         assignments_integer: &AssignmentsInteger,
         assignments_propositional: &AssignmentsPropositional,
@@ -265,7 +266,7 @@ impl DebugHelper {
         clippy::too_many_arguments,
         reason = "Should be refactored in the future"
     )]
-    #[cfg(any(feature = "explanation-checks", test))]
+    #[cfg(any(feature = "explanation-checks", test, doc))]
     pub(crate) fn debug_check_propagations(
         termination: &mut impl TerminationCondition,
         num_trail_entries_before: usize,
@@ -328,7 +329,7 @@ impl DebugHelper {
         clippy::too_many_arguments,
         reason = "Should be refactored in the future"
     )]
-    #[cfg(any(feature = "explanation-checks", test))]
+    #[cfg(any(feature = "explanation-checks", test, doc))]
     fn debug_propagator_reason(
         propagated_predicate: IntegerPredicate,
         reason: &PropositionalConjunction,
@@ -451,7 +452,128 @@ impl DebugHelper {
         true
     }
 
-    #[cfg(any(feature = "explanation-checks", test))]
+    /// Checks whether the explanations of the propagations performed by the propagator with
+    /// `propagator_id` (i.e. the trail entries starting from `num_trail_entries_before`) contain
+    /// predicates which are not required for the propagation.
+    ///
+    /// For every explanation, the predicates are greedily removed one at a time; a predicate is
+    /// removable if re-running the propagator from scratch on the remaining predicates still leads
+    /// to the propagation (or to a conflict). Every removable predicate is logged as a warning and
+    /// returned.
+    ///
+    /// Note that this check re-runs the propagator once for every predicate of every explanation,
+    /// so it should only be used on small instances in the test helper.
+    #[cfg(any(feature = "explanation-checks", test, doc))]
+    pub(crate) fn debug_check_explanation_minimality(
+        num_trail_entries_before: usize,
+        propagator_id: PropagatorId,
+        assignments: &AssignmentsInteger,
+        assignments_propositional: &AssignmentsPropositional,
+        variable_literal_mappings: &VariableLiteralMappings,
+        reason_store: &mut ReasonStore,
+        propagators_cp: &KeyedVec<PropagatorId, Box<dyn Propagator>>,
+    ) -> Vec<RemovablePredicate> {
+        let propagator = propagators_cp[propagator_id].as_ref();
+        let mut removable_predicates = vec![];
+
+        for trail_index in num_trail_entries_before..assignments.num_trail_entries() {
+            let trail_entry = assignments.get_trail_entry(trail_index);
+
+            let reason: Vec<Predicate> = reason_store
+                .get_or_compute(
+                    trail_entry
+                        .reason
+                        .expect("Expected checked propagation to have a reason"),
+                    &PropagationContext::new(assignments, assignments_propositional, true, true),
+                )
+                .expect("Expected reason to exist for integer trail entry")
+                .iter()
+                .copied()
+                .collect();
+
+            let mut remaining_reason = reason.clone();
+            for predicate in reason {
+                let candidate_reason = remaining_reason
+                    .iter()
+                    .copied()
+                    .filter(|&other| other != predicate)
+                    .collect::<Vec<_>>();
+
+                if Self::debug_propagation_follows_from(
+                    trail_entry.predicate,
+                    &candidate_reason,
+                    assignments,
+                    assignments_propositional,
+                    variable_literal_mappings,
+                    propagator,
+                    propagator_id,
+                ) {
+                    warn!(
+                        "Found a predicate which is not required in an explanation.\n
+                         Propagator: '{}'\n
+                         Propagator id: {propagator_id}\n
+                         Reported propagation: {}\n
+                         Removable predicate: {predicate}",
+                        propagator.name(),
+                        trail_entry.predicate,
+                    );
+                    removable_predicates.push(RemovablePredicate {
+                        propagator_id,
+                        propagated_predicate: trail_entry.predicate,
+                        removable_predicate: predicate,
+                    });
+                    remaining_reason = candidate_reason;
+                }
+            }
+        }
+
+        removable_predicates
+    }
+
+    /// Returns whether running `propagator` from scratch on the initial domains extended with
+    /// `reason` leads to `propagated_predicate` or to a conflict.
+    #[cfg(any(feature = "explanation-checks", test, doc))]
+    fn debug_propagation_follows_from(
+        propagated_predicate: IntegerPredicate,
+        reason: &[Predicate],
+        assignments_integer: &AssignmentsInteger,
+        assignments_propositional: &AssignmentsPropositional,
+        variable_literal_mappings: &VariableLiteralMappings,
+        propagator: &dyn Propagator,
+        propagator_id: PropagatorId,
+    ) -> bool {
+        let mut assignments_clone = assignments_integer.debug_create_empty_clone();
+        let mut assignments_propositional_clone =
+            assignments_propositional.debug_create_empty_clone();
+
+        if !DebugHelper::debug_add_predicates_to_assignment_integers(&mut assignments_clone, reason)
+            || !DebugHelper::debug_add_predicates_to_assignment_propositional(
+                &assignments_clone,
+                &mut assignments_propositional_clone,
+                variable_literal_mappings,
+                reason,
+            )
+        {
+            return false;
+        }
+
+        let mut reason_store = Default::default();
+        let context = PropagationContextMut::new(
+            &mut assignments_clone,
+            &mut reason_store,
+            &mut assignments_propositional_clone,
+            propagator_id,
+            true,
+            true,
+        );
+
+        match propagator.propagate(context) {
+            Ok(()) => assignments_clone.does_integer_predicate_hold(propagated_predicate),
+            Err(_) => true,
+        }
+    }
+
+    #[cfg(any(feature = "explanation-checks", test, doc))]
     fn is_circuit_explanation_with_only_inequalities(
         propagator: &dyn Propagator,
         original_reason: &[Predicate],
@@ -468,7 +590,7 @@ impl DebugHelper {
             })
     }
 
-    #[cfg(any(feature = "explanation-checks", test))]
+    #[cfg(any(feature = "explanation-checks", test, doc))]
     fn transform_circuit_reason(
         original_reason: &[Predicate],
         assignments_integer: &AssignmentsInteger,
@@ -486,7 +608,11 @@ impl DebugHelper {
             .collect::<Vec<_>>()
     }
 
-    #[cfg(feature = "explanation-checks")]
+    #[allow(
+        clippy::too_many_arguments,
+        reason = "Should be refactored in the future"
+    )]
+    #[cfg(any(feature = "explanation-checks", test, doc))]
     fn debug_circuit_reason_conflict(
         original_reason: &[Predicate],
         assignments_integer: &AssignmentsInteger,
@@ -548,7 +674,7 @@ impl DebugHelper {
         }
     }
 
-    #[cfg(any(feature = "explanation-checks", test))]
+    #[cfg(any(feature = "explanation-checks", test, doc))]
     #[allow(
         clippy::too_many_arguments,
         reason = "Should be refactored in the future"
@@ -633,7 +759,7 @@ impl DebugHelper {
 
 // methods that serve as small utility functions
 impl DebugHelper {
-    #[cfg(any(feature = "explanation-checks", test))]
+    #[cfg(any(feature = "explanation-checks", test, doc))]
     fn debug_add_predicates_to_assignment_integers(
         assignments_integer: &mut AssignmentsInteger,
         predicates: &[Predicate],
@@ -663,7 +789,7 @@ impl DebugHelper {
         true
     }
 
-    #[cfg(any(feature = "explanation-checks", test))]
+    #[cfg(any(feature = "explanation-checks", test, doc))]
     fn debug_add_predicates_to_assignment_propositional(
         assignments_integer: &AssignmentsInteger,
         assignments_propositional: &mut AssignmentsPropositional,
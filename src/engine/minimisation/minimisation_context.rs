@@ -110,6 +110,15 @@ impl<'a> MinimisationContext<'a> {
     /// Returns the reason for the provided `literal` in the form `l_1 /\ ... /\ l_n -> literal`
     #[allow(unused, reason = "will be used in an assignment")]
     pub(crate) fn get_reason(&mut self, literal: Literal) -> Conjunction {
+        // binary clauses are not allocated, the reason consists of the other literal of the clause
+        let constraint_reference = self
+            .assignments_propositional
+            .get_literal_reason_constraint(literal);
+        if constraint_reference.is_virtual_binary_clause() {
+            let clause_reference = constraint_reference.as_clause_reference();
+            return vec![!clause_reference.get_virtual_binary_clause_literal()].into();
+        }
+
         let clause_reference = self.get_propagation_clause_reference(literal);
         // 0-th literal is the propagated literal so it is skipped
         self.clause_allocator[clause_reference].get_literal_slice()[1..]
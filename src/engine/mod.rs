@@ -8,12 +8,20 @@ pub(crate) mod termination;
 pub(crate) mod test_helper;
 pub(crate) mod variables;
 
+mod constraint_tags;
 mod debug_helper;
 mod preprocessor;
+mod solver_options_builder;
 mod variable_names;
 
 pub(crate) use constraint_satisfaction_solver::ConstraintSatisfactionSolver;
+pub use constraint_satisfaction_solver::ProbingOptions;
 pub use constraint_satisfaction_solver::SatisfactionSolverOptions;
+pub use constraint_satisfaction_solver::SnapshotId;
+pub(crate) use constraint_tags::ConstraintTagTracker;
+pub use constraint_tags::TagArtifacts;
 pub(crate) use debug_helper::DebugHelper;
 pub(crate) use preprocessor::Preprocessor;
+pub use solver_options_builder::InvalidOptions;
+pub use solver_options_builder::SolverOptionsBuilder;
 pub(crate) use variable_names::VariableNames;
@@ -0,0 +1,27 @@
+//! Contains [`Bound`], which describes a bound which can be imposed on an integer variable using
+//! [`Solver::add_bound`].
+
+use crate::engine::predicates::predicate::Predicate;
+use crate::engine::variables::DomainId;
+use crate::predicate;
+#[cfg(doc)]
+use crate::Solver;
+
+/// A lower or an upper bound on the value of an integer variable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Bound {
+    /// The variable takes a value which is at least the provided value.
+    Lower(i32),
+    /// The variable takes a value which is at most the provided value.
+    Upper(i32),
+}
+
+impl Bound {
+    /// Returns the predicate which states that the provided variable respects this bound.
+    pub(crate) fn predicate_over(self, domain_id: DomainId) -> Predicate {
+        match self {
+            Bound::Lower(value) => predicate![domain_id >= value],
+            Bound::Upper(value) => predicate![domain_id <= value],
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use thiserror::Error;
 
 use super::predicate::Predicate;
+use crate::engine::cp::AssignmentsInteger;
 use crate::engine::variables::DomainId;
 
 /// Representation of a domain operation, it can either be in the form of atomic constraints over
@@ -78,6 +79,72 @@ impl IntegerPredicate {
             } => domain_id,
         }
     }
+
+    /// Evaluates the predicate in the current state of the `assignments`; returns [`None`] if the
+    /// predicate is neither true nor false.
+    pub fn evaluate(&self, assignments: &AssignmentsInteger) -> Option<bool> {
+        let (is_true, is_false) = match *self {
+            IntegerPredicate::LowerBound {
+                domain_id,
+                lower_bound,
+            } => (
+                assignments.get_lower_bound(domain_id) >= lower_bound,
+                assignments.get_upper_bound(domain_id) < lower_bound,
+            ),
+            IntegerPredicate::UpperBound {
+                domain_id,
+                upper_bound,
+            } => (
+                assignments.get_upper_bound(domain_id) <= upper_bound,
+                assignments.get_lower_bound(domain_id) > upper_bound,
+            ),
+            IntegerPredicate::NotEqual {
+                domain_id,
+                not_equal_constant,
+            } => (
+                !assignments.is_value_in_domain(domain_id, not_equal_constant),
+                assignments.is_domain_assigned_to_value(domain_id, not_equal_constant),
+            ),
+            IntegerPredicate::Equal {
+                domain_id,
+                equality_constant,
+            } => (
+                assignments.is_domain_assigned_to_value(domain_id, equality_constant),
+                !assignments.is_value_in_domain(domain_id, equality_constant),
+            ),
+        };
+
+        if is_true {
+            Some(true)
+        } else if is_false {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    /// Returns whether the predicate holds at the root of the `assignments`, in which case it
+    /// holds regardless of the decisions which have been made.
+    pub fn is_satisfied_at_root(&self, assignments: &AssignmentsInteger) -> bool {
+        match *self {
+            IntegerPredicate::LowerBound {
+                domain_id,
+                lower_bound,
+            } => assignments.get_root_bounds(domain_id).0 >= lower_bound,
+            IntegerPredicate::UpperBound {
+                domain_id,
+                upper_bound,
+            } => assignments.get_root_bounds(domain_id).1 <= upper_bound,
+            IntegerPredicate::NotEqual {
+                domain_id,
+                not_equal_constant,
+            } => !assignments.is_value_in_root_domain(domain_id, not_equal_constant),
+            IntegerPredicate::Equal {
+                domain_id,
+                equality_constant,
+            } => assignments.get_root_bounds(domain_id) == (equality_constant, equality_constant),
+        }
+    }
 }
 
 impl std::ops::Not for IntegerPredicate {
@@ -132,31 +199,211 @@ impl TryFrom<Predicate> for IntegerPredicate {
     }
 }
 
-impl std::fmt::Display for IntegerPredicate {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl IntegerPredicate {
+    /// Formats the predicate in the bracket syntax (e.g. `[x >= 5]`), where `name` is used to
+    /// refer to the domain of the predicate.
+    pub(crate) fn fmt_with_name(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        name: impl std::fmt::Display,
+    ) -> std::fmt::Result {
         match self {
-            IntegerPredicate::LowerBound {
-                domain_id,
-                lower_bound,
-            } => write!(f, "[{} >= {}]", domain_id, lower_bound),
-            IntegerPredicate::UpperBound {
-                domain_id,
-                upper_bound,
-            } => write!(f, "[{} <= {}]", domain_id, upper_bound),
+            IntegerPredicate::LowerBound { lower_bound, .. } => {
+                write!(f, "[{} >= {}]", name, lower_bound)
+            }
+            IntegerPredicate::UpperBound { upper_bound, .. } => {
+                write!(f, "[{} <= {}]", name, upper_bound)
+            }
             IntegerPredicate::NotEqual {
-                domain_id,
-                not_equal_constant,
-            } => write!(f, "[{} != {}]", domain_id, not_equal_constant),
+                not_equal_constant, ..
+            } => write!(f, "[{} != {}]", name, not_equal_constant),
             IntegerPredicate::Equal {
-                domain_id,
-                equality_constant,
-            } => write!(f, "[{} == {}]", domain_id, equality_constant),
+                equality_constant, ..
+            } => write!(f, "[{} == {}]", name, equality_constant),
         }
     }
 }
 
+impl std::fmt::Display for IntegerPredicate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_with_name(f, self.get_domain())
+    }
+}
+
 impl std::fmt::Debug for IntegerPredicate {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::sat::AssignmentsPropositional;
+    use crate::engine::variables::Literal;
+    use crate::engine::variables::PropositionalVariable;
+    use crate::predicate;
+
+    fn evaluate(predicate: Predicate, assignments: &AssignmentsInteger) -> Option<bool> {
+        IntegerPredicate::try_from(predicate)
+            .expect("integer predicate")
+            .evaluate(assignments)
+    }
+
+    #[test]
+    fn bound_predicates_are_decided_at_the_bounds() {
+        let mut assignments = AssignmentsInteger::default();
+        let x = assignments.grow(2, 6);
+
+        assert_eq!(evaluate(predicate![x >= 2], &assignments), Some(true));
+        assert_eq!(evaluate(predicate![x >= 3], &assignments), None);
+        assert_eq!(evaluate(predicate![x >= 6], &assignments), None);
+        assert_eq!(evaluate(predicate![x >= 7], &assignments), Some(false));
+
+        assert_eq!(evaluate(predicate![x <= 6], &assignments), Some(true));
+        assert_eq!(evaluate(predicate![x <= 5], &assignments), None);
+        assert_eq!(evaluate(predicate![x <= 2], &assignments), None);
+        assert_eq!(evaluate(predicate![x <= 1], &assignments), Some(false));
+    }
+
+    #[test]
+    fn predicates_over_values_outside_the_initial_bounds_are_decided() {
+        let mut assignments = AssignmentsInteger::default();
+        let x = assignments.grow(2, 6);
+
+        assert_eq!(evaluate(predicate![x >= -10], &assignments), Some(true));
+        assert_eq!(evaluate(predicate![x <= 10], &assignments), Some(true));
+        assert_eq!(evaluate(predicate![x != 10], &assignments), Some(true));
+        assert_eq!(evaluate(predicate![x == 10], &assignments), Some(false));
+        assert_eq!(evaluate(predicate![x != -1], &assignments), Some(true));
+        assert_eq!(evaluate(predicate![x == -1], &assignments), Some(false));
+    }
+
+    #[test]
+    fn equality_predicates_are_decided_by_holes_and_assignments() {
+        let mut assignments = AssignmentsInteger::default();
+        let x = assignments.grow_sparse(&[1, 3, 5]);
+
+        assert_eq!(evaluate(predicate![x == 2], &assignments), Some(false));
+        assert_eq!(evaluate(predicate![x != 2], &assignments), Some(true));
+        assert_eq!(evaluate(predicate![x == 3], &assignments), None);
+        assert_eq!(evaluate(predicate![x != 3], &assignments), None);
+
+        assignments
+            .make_assignment(x, 3, None)
+            .expect("non-empty domain");
+
+        assert_eq!(evaluate(predicate![x == 3], &assignments), Some(true));
+        assert_eq!(evaluate(predicate![x != 3], &assignments), Some(false));
+        assert_eq!(evaluate(predicate![x == 5], &assignments), Some(false));
+        assert_eq!(evaluate(predicate![x != 5], &assignments), Some(true));
+    }
+
+    #[test]
+    fn evaluation_agrees_with_does_integer_predicate_hold() {
+        let mut assignments = AssignmentsInteger::default();
+        let x = assignments.grow(0, 4);
+        assignments
+            .remove_value_from_domain(x, 2, None)
+            .expect("non-empty domain");
+        assignments
+            .tighten_upper_bound(x, 3, None)
+            .expect("non-empty domain");
+
+        for value in -1..=5 {
+            for predicate in [
+                predicate![x >= value],
+                predicate![x <= value],
+                predicate![x == value],
+                predicate![x != value],
+            ] {
+                let integer_predicate = IntegerPredicate::try_from(predicate).unwrap();
+                assert_eq!(
+                    integer_predicate.evaluate(&assignments) == Some(true),
+                    assignments.does_integer_predicate_hold(integer_predicate)
+                );
+                assert_eq!(
+                    integer_predicate.evaluate(&assignments) == Some(false),
+                    assignments.does_integer_predicate_hold(!integer_predicate)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn only_root_changes_satisfy_predicates_at_the_root() {
+        let mut assignments = AssignmentsInteger::default();
+        let x = assignments.grow(0, 10);
+        let y = assignments.grow(0, 10);
+        assignments
+            .tighten_lower_bound(x, 2, None)
+            .expect("non-empty domain");
+        assignments
+            .remove_value_from_domain(x, 5, None)
+            .expect("non-empty domain");
+
+        assignments.increase_decision_level();
+        assignments
+            .tighten_upper_bound(x, 8, None)
+            .expect("non-empty domain");
+        assignments
+            .remove_value_from_domain(x, 6, None)
+            .expect("non-empty domain");
+        assignments
+            .make_assignment(y, 4, None)
+            .expect("non-empty domain");
+
+        let at_root = |predicate: Predicate| {
+            IntegerPredicate::try_from(predicate)
+                .unwrap()
+                .is_satisfied_at_root(&assignments)
+        };
+
+        assert!(at_root(predicate![x >= 2]));
+        assert!(!at_root(predicate![x >= 3]));
+        assert!(at_root(predicate![x <= 10]));
+        assert!(!at_root(predicate![x <= 8]));
+        assert!(at_root(predicate![x != 5]));
+        assert!(at_root(predicate![x != 1]));
+        assert!(at_root(predicate![x != 11]));
+        assert!(!at_root(predicate![x != 6]));
+        assert!(!at_root(predicate![x != 9]));
+        assert!(!at_root(predicate![y == 4]));
+        assert!(!at_root(predicate![y != 5]));
+
+        assert_eq!(evaluate(predicate![x != 9], &assignments), Some(true));
+        assert_eq!(evaluate(predicate![y == 4], &assignments), Some(true));
+    }
+
+    #[test]
+    fn predicate_evaluation_covers_literals_and_constants() {
+        let assignments_integer = AssignmentsInteger::default();
+        let mut assignments_propositional = AssignmentsPropositional::default();
+        assignments_propositional.grow();
+        let literal = Literal::new(PropositionalVariable::new(0), true);
+
+        assert_eq!(
+            Predicate::True.evaluate(&assignments_integer, &assignments_propositional),
+            Some(true)
+        );
+        assert_eq!(
+            Predicate::False.evaluate(&assignments_integer, &assignments_propositional),
+            Some(false)
+        );
+        assert_eq!(
+            Predicate::from(literal).evaluate(&assignments_integer, &assignments_propositional),
+            None
+        );
+
+        assignments_propositional.enqueue_decision_literal(!literal);
+
+        assert_eq!(
+            Predicate::from(literal).evaluate(&assignments_integer, &assignments_propositional),
+            Some(false)
+        );
+        assert_eq!(
+            Predicate::from(!literal).evaluate(&assignments_integer, &assignments_propositional),
+            Some(true)
+        );
+    }
+}
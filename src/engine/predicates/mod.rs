@@ -9,7 +9,9 @@
 //!   predicates which are always true/false.
 //!
 //! In general, these [`Predicate`]s are used to represent propagations, explanations or decisions.
+pub(crate) mod bound;
 pub(crate) mod integer_predicate;
+pub(crate) mod named_display;
 pub(crate) mod predicate;
 pub(crate) mod predicate_constructor;
 #[cfg(doc)]
@@ -0,0 +1,200 @@
+//! Contains [`NamedDisplay`], which displays predicates using the names of the variables rather
+//! than their internal identifiers (e.g. `[Succ[3] >= 2]` instead of `[x7 >= 2]`).
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::predicates::integer_predicate::IntegerPredicate;
+use crate::engine::predicates::predicate::Predicate;
+use crate::engine::variables::DomainId;
+use crate::engine::variables::Literal;
+
+/// Provides the names of the variables which are used by a [`NamedDisplay`].
+pub trait VariableNameLookup {
+    /// Returns the name of the provided integer variable or [`None`] if it has no name; in the
+    /// latter case the internal identifier is displayed instead.
+    fn integer_name(&self, domain_id: DomainId) -> Option<String>;
+
+    /// Returns the [`IntegerPredicate`] which is attached to the provided [`Literal`] (respecting
+    /// its polarity) or [`None`] if the literal is not attached to a predicate.
+    fn literal_predicate(&self, _literal: Literal) -> Option<IntegerPredicate> {
+        None
+    }
+}
+
+impl<F> VariableNameLookup for F
+where
+    F: Fn(DomainId) -> Option<String>,
+{
+    fn integer_name(&self, domain_id: DomainId) -> Option<String> {
+        self(domain_id)
+    }
+}
+
+/// A wrapper which implements [`Display`] for predicates, [`PropositionalConjunction`]s and
+/// [`Literal`]s using the names provided by a [`VariableNameLookup`].
+///
+/// The predicates are displayed using the bracket syntax of the proofs, e.g. `[Succ[3] >= 2] /\
+/// [Objective <= 40]`. A [`Literal`] is displayed together with the [`IntegerPredicate`] it is
+/// attached to, e.g. `~p5 ([x <= 1])`.
+pub struct NamedDisplay<'a, T: ?Sized> {
+    value: &'a T,
+    names: &'a dyn VariableNameLookup,
+}
+
+impl<'a, T: ?Sized> NamedDisplay<'a, T> {
+    pub fn new(value: &'a T, names: &'a dyn VariableNameLookup) -> Self {
+        NamedDisplay { value, names }
+    }
+
+    fn with<U: ?Sized>(&self, value: &'a U) -> NamedDisplay<'a, U> {
+        NamedDisplay {
+            value,
+            names: self.names,
+        }
+    }
+}
+
+impl<T: std::fmt::Debug + ?Sized> std::fmt::Debug for NamedDisplay<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NamedDisplay")
+            .field("value", &self.value)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Display for NamedDisplay<'_, IntegerPredicate> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let domain_id = self.value.get_domain();
+        match self.names.integer_name(domain_id) {
+            Some(name) => self.value.fmt_with_name(f, name),
+            None => self.value.fmt_with_name(f, domain_id),
+        }
+    }
+}
+
+impl Display for NamedDisplay<'_, Literal> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.names.literal_predicate(*self.value) {
+            Some(predicate) => write!(f, "{} ({})", self.value, self.with(&predicate)),
+            None => write!(f, "{}", self.value),
+        }
+    }
+}
+
+impl Display for NamedDisplay<'_, Predicate> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.value {
+            Predicate::IntegerPredicate(integer_predicate) => {
+                write!(f, "{}", self.with(integer_predicate))
+            }
+            Predicate::Literal(literal) => write!(f, "{}", self.with(literal)),
+            Predicate::False | Predicate::True => write!(f, "{}", self.value),
+        }
+    }
+}
+
+impl Display for NamedDisplay<'_, PropositionalConjunction> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.value.num_predicates() == 0 {
+            return write!(f, "{}", self.value);
+        }
+
+        for (index, predicate) in self.value.iter().enumerate() {
+            if index > 0 {
+                write!(f, " /\\ ")?;
+            }
+            write!(f, "{}", self.with(predicate))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::options::SolverOptions;
+    use crate::predicate;
+    use crate::Solver;
+
+    fn names(domain_id: DomainId) -> Option<String> {
+        (domain_id.id == 0).then(|| "Succ[3]".to_owned())
+    }
+
+    #[test]
+    fn integer_predicates_use_bracket_syntax() {
+        let x = DomainId::new(3);
+
+        assert_eq!(predicate!(x >= 5).to_string(), "[x3 >= 5]");
+        assert_eq!(predicate!(x <= -4).to_string(), "[x3 <= -4]");
+        assert_eq!(predicate!(x != -1).to_string(), "[x3 != -1]");
+        assert_eq!(predicate!(x == 0).to_string(), "[x3 == 0]");
+    }
+
+    #[test]
+    fn constant_predicates_are_displayed_as_booleans() {
+        assert_eq!(Predicate::True.to_string(), "true");
+        assert_eq!(Predicate::False.to_string(), "false");
+        assert_eq!(
+            NamedDisplay::new(&Predicate::True, &names).to_string(),
+            "true"
+        );
+        assert_eq!(
+            NamedDisplay::new(&Predicate::False, &names).to_string(),
+            "false"
+        );
+    }
+
+    #[test]
+    fn named_predicates_fall_back_to_identifiers() {
+        let succ = DomainId::new(0);
+        let objective = DomainId::new(1);
+
+        assert_eq!(
+            NamedDisplay::new(&predicate!(succ >= -2), &names).to_string(),
+            "[Succ[3] >= -2]"
+        );
+        assert_eq!(
+            NamedDisplay::new(&predicate!(objective <= 40), &names).to_string(),
+            "[x1 <= 40]"
+        );
+    }
+
+    #[test]
+    fn conjunctions_are_joined_with_conjunction_symbol() {
+        let succ = DomainId::new(0);
+        let objective = DomainId::new(1);
+        let reason = PropositionalConjunction::from(vec![
+            predicate!(succ >= 2),
+            predicate!(objective <= 40),
+        ]);
+
+        assert_eq!(reason.to_string(), "[x0 >= 2] /\\ [x1 <= 40]");
+        assert_eq!(
+            NamedDisplay::new(&reason, &names).to_string(),
+            "[Succ[3] >= 2] /\\ [x1 <= 40]"
+        );
+        assert_eq!(
+            NamedDisplay::new(&PropositionalConjunction::default(), &names).to_string(),
+            "true"
+        );
+    }
+
+    #[test]
+    fn literals_are_displayed_with_their_canonical_predicate() {
+        let mut solver = Solver::with_options(SolverOptions::default());
+        let x = solver.new_named_bounded_integer(0, 5, "x");
+        let literal = solver.get_literal(predicate!(x >= 2));
+
+        assert_eq!(
+            NamedDisplay::new(&literal, &solver).to_string(),
+            format!("{literal} ([x >= 2])")
+        );
+        assert_eq!(
+            NamedDisplay::new(&!literal, &solver).to_string(),
+            format!("~{literal} ([x <= 1])")
+        );
+    }
+}
@@ -1,6 +1,8 @@
 use super::integer_predicate::IntegerPredicate;
+use crate::engine::cp::AssignmentsInteger;
 #[cfg(doc)]
 use crate::engine::cp::VariableLiteralMappings;
+use crate::engine::sat::AssignmentsPropositional;
 use crate::engine::variables::DomainId;
 #[cfg(doc)]
 use crate::engine::variables::IntegerVariable;
@@ -49,6 +51,25 @@ impl Predicate {
     pub fn is_integer_predicate(&self) -> bool {
         matches!(self, Predicate::IntegerPredicate(_))
     }
+
+    /// Evaluates the predicate in the current state of the assignments; returns [`None`] if the
+    /// predicate is neither true nor false. See [`IntegerPredicate::evaluate`].
+    pub fn evaluate(
+        &self,
+        assignments_integer: &AssignmentsInteger,
+        assignments_propositional: &AssignmentsPropositional,
+    ) -> Option<bool> {
+        match self {
+            Predicate::IntegerPredicate(integer_predicate) => {
+                integer_predicate.evaluate(assignments_integer)
+            }
+            Predicate::Literal(literal) => assignments_propositional
+                .is_literal_assigned(*literal)
+                .then(|| assignments_propositional.is_literal_assigned_true(*literal)),
+            Predicate::False => Some(false),
+            Predicate::True => Some(true),
+        }
+    }
 }
 
 impl std::ops::Not for Predicate {
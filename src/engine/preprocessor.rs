@@ -8,6 +8,7 @@ impl Preprocessor {
     // does simple preprocessing, modifying the input vector of literals
     // 	removes duplicate literals
     // 	removes falsified literals at the root
+    // 	only root assignments are taken into account, so the method can also be used during search
     // 	if the same variable appears with both polarities or there is a literal that is true at the
     // root, removes all literals from the clause and adds a literal that is true at the root 	if
     // the clause is violated at the root, it will become empty  if the clause is satisfied at
@@ -25,12 +26,13 @@ impl Preprocessor {
         let mut satisfied_at_root = false;
         let mut next_location = 0;
         for i in 0..literals.len() {
-            if assignments.is_literal_assigned_true(literals[i]) {
+            let is_root_assignment = assignments.is_literal_root_assignment(literals[i]);
+            if is_root_assignment && assignments.is_literal_assigned_true(literals[i]) {
                 satisfied_at_root = true;
                 break;
             }
-            // skip falsified literals, only keep unassigned literals
-            else if assignments.is_literal_unassigned(literals[i]) {
+            // skip literals falsified at the root, keep all other literals
+            else if !is_root_assignment {
                 literals[next_location] = literals[i];
                 next_location += 1;
             }
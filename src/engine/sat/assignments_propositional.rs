@@ -2,8 +2,10 @@ use crate::basic_types::ConflictInfo;
 use crate::basic_types::ConstraintReference;
 use crate::basic_types::KeyedVec;
 use crate::basic_types::Trail;
-#[cfg(test)]
+#[cfg(any(test, feature = "test-utilities"))]
 use crate::engine::cp::reason::ReasonRef;
+use crate::engine::sat::polarity_statistics::PolarityCounts;
+use crate::engine::sat::polarity_statistics::PolarityStatistics;
 use crate::engine::variables::Literal;
 use crate::engine::variables::PropositionalVariable;
 use crate::engine::variables::PropositionalVariableGeneratorIterator;
@@ -14,6 +16,7 @@ use crate::munchkin_assert_simple;
 pub struct AssignmentsPropositional {
     assignment_info: KeyedVec<PropositionalVariable, PropositionalAssignmentInfo>,
     trail: Trail<Literal>,
+    polarity_statistics: PolarityStatistics,
     pub true_literal: Literal,
     pub false_literal: Literal,
 }
@@ -24,6 +27,7 @@ impl Default for AssignmentsPropositional {
         AssignmentsPropositional {
             assignment_info: Default::default(),
             trail: Default::default(),
+            polarity_statistics: Default::default(),
             true_literal: dummy_literal,
             false_literal: !dummy_literal,
         }
@@ -31,6 +35,14 @@ impl Default for AssignmentsPropositional {
 }
 
 impl AssignmentsPropositional {
+    /// Returns the approximate number of bytes used by the assignments, the trail and the polarity
+    /// statistics.
+    pub fn approximate_memory_usage(&self) -> usize {
+        self.assignment_info.approximate_memory_usage()
+            + self.trail.approximate_memory_usage()
+            + self.polarity_statistics.approximate_memory_usage()
+    }
+
     pub fn increase_decision_level(&mut self) {
         self.trail.increase_decision_level()
     }
@@ -57,6 +69,46 @@ impl AssignmentsPropositional {
     pub fn grow(&mut self) {
         self.assignment_info
             .push(PropositionalAssignmentInfo::Unassigned);
+        self.polarity_statistics.grow();
+    }
+
+    /// Adds `num_variables` unassigned variables; unlike calling [`AssignmentsPropositional::grow`]
+    /// repeatedly, the space for the new variables is reserved at once.
+    pub fn grow_by(&mut self, num_variables: usize) {
+        self.reserve(num_variables);
+        for _ in 0..num_variables {
+            self.assignment_info
+                .push(PropositionalAssignmentInfo::Unassigned);
+        }
+        self.polarity_statistics.grow_by(num_variables);
+    }
+
+    /// Reserves space for at least `num_variables` more variables, such that they can be added
+    /// without reallocating.
+    pub fn reserve(&mut self, num_variables: usize) {
+        self.assignment_info.reserve(num_variables);
+        self.polarity_statistics.reserve(num_variables);
+    }
+
+    /// Returns the number of variables which can be stored without reallocating.
+    #[cfg(test)]
+    pub(crate) fn capacity(&self) -> usize {
+        self.assignment_info.capacity()
+    }
+
+    /// Returns how often the provided variable has recently been assigned to true and to false;
+    /// older assignments have less influence on the counts since they are halved every time the
+    /// polarity decay interval (see [`AssignmentsPropositional::set_polarity_decay_interval`])
+    /// has passed.
+    pub(crate) fn polarity_counts(&self, variable: PropositionalVariable) -> PolarityCounts {
+        self.polarity_statistics.counts(variable)
+    }
+
+    #[cfg(test)]
+    /// Sets the number of assignments after which the polarity counts of all variables are
+    /// halved.
+    pub(crate) fn set_polarity_decay_interval(&mut self, decay_interval: u32) {
+        self.polarity_statistics.set_decay_interval(decay_interval)
     }
 
     pub fn num_propositional_variables(&self) -> u32 {
@@ -216,6 +268,10 @@ impl AssignmentsPropositional {
                 decision_level: self.get_decision_level(),
                 constraint_reference,
             };
+        self.polarity_statistics.record_assignment(
+            true_literal.get_propositional_variable(),
+            true_literal.is_positive(),
+        );
 
         self.trail.push(true_literal);
 
@@ -252,6 +308,24 @@ impl AssignmentsPropositional {
         })
     }
 
+    /// Unassigns the literals which were assigned at the root after the first
+    /// `num_trail_entries` entries of the trail; this is used to roll back to an earlier state of
+    /// the root.
+    ///
+    /// This iterator returns the literals on the trail in _reverse_ order (LIFO)
+    pub fn undo_root_assignments(
+        &mut self,
+        num_trail_entries: usize,
+    ) -> impl Iterator<Item = Literal> + '_ {
+        self.trail
+            .truncate_root(num_trail_entries)
+            .inspect(|entry| {
+                let variable = entry.get_propositional_variable();
+
+                self.assignment_info[variable] = PropositionalAssignmentInfo::Unassigned;
+            })
+    }
+
     pub fn is_at_the_root_level(&self) -> bool {
         self.get_decision_level() == 0
     }
@@ -260,13 +334,14 @@ impl AssignmentsPropositional {
         AssignmentsPropositional {
             assignment_info: KeyedVec::new(vec![Default::default(); self.assignment_info.len()]),
             trail: Default::default(),
+            polarity_statistics: self.polarity_statistics.clone(),
             true_literal: self.true_literal,
             false_literal: self.false_literal,
         }
     }
 }
 
-#[cfg(test)]
+#[cfg(any(test, feature = "test-utilities"))]
 impl AssignmentsPropositional {
     pub fn get_reason_for_assignment(&self, literal: Literal, assignment: bool) -> ReasonRef {
         if assignment {
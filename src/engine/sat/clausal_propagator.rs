@@ -12,12 +12,27 @@ use crate::engine::Preprocessor;
 use crate::munchkin_assert_moderate;
 use crate::munchkin_assert_simple;
 
+#[cfg(test)]
+thread_local! {
+    /// The number of times [`ClausalPropagator::debug_check_state`] was called on this thread.
+    pub(crate) static NUM_STATE_CHECKS: std::cell::Cell<u64> = const { std::cell::Cell::new(0) };
+}
+
 #[derive(Default, Debug)]
 pub(crate) struct ClausalPropagator {
     pub(crate) watch_lists: KeyedVec<Literal, Vec<ClauseWatcher>>,
+    /// The binary clauses `(~a \/ b)` are not allocated, but are stored as the implications
+    /// `a -> b` and `~b -> ~a`; for every literal, this contains the literals which are implied by
+    /// it.
+    ///
+    /// A literal propagated by an implication `a -> b` has the virtual binary clause reference of
+    /// `~a` as its reason (see [`ClauseReference::is_virtual_binary_clause`]).
+    pub(crate) binary_implications: KeyedVec<Literal, Vec<Literal>>,
     pub(crate) next_position_on_trail_to_propagate: usize,
     pub(crate) permanent_clauses: Vec<ClauseReference>,
     is_in_infeasible_state: bool,
+    /// The total number of entries in [`ClausalPropagator::binary_implications`].
+    num_implication_entries: usize,
 }
 
 impl ClausalPropagator {
@@ -25,6 +40,33 @@ impl ClausalPropagator {
         // increase the watch list, once for each polarity
         self.watch_lists.push(vec![]);
         self.watch_lists.push(vec![]);
+
+        self.binary_implications.push(vec![]);
+        self.binary_implications.push(vec![]);
+    }
+
+    /// Adds the (empty) watch lists and implications of `num_variables` new variables, reserving
+    /// the space for them at once.
+    pub(crate) fn grow_by(&mut self, num_variables: usize) {
+        self.reserve(num_variables);
+        for _ in 0..num_variables {
+            self.grow();
+        }
+    }
+
+    /// Reserves space for at least `num_variables` more variables.
+    pub(crate) fn reserve(&mut self, num_variables: usize) {
+        self.watch_lists.reserve(2 * num_variables);
+        self.binary_implications.reserve(2 * num_variables);
+    }
+
+    /// Returns the number of variables which can be stored without reallocating.
+    #[cfg(test)]
+    pub(crate) fn capacity(&self) -> usize {
+        self.watch_lists
+            .capacity()
+            .min(self.binary_implications.capacity())
+            / 2
     }
 
     pub(crate) fn get_literal_propagation_clause_reference(
@@ -94,6 +136,110 @@ impl ClausalPropagator {
         Ok(())
     }
 
+    /// Adds a clause while the solver is not necessarily at the root level (e.g. an explanation
+    /// clause or a clause derived during search).
+    ///
+    /// The two literals which are watched are chosen such that the watch invariant holds with
+    /// respect to the current assignment: non-falsified literals are preferred, followed by the
+    /// falsified literals which were assigned at the highest decision levels.
+    ///
+    /// Unlike [`ClausalPropagator::add_permanent_clause`], no assignments are made; the returned
+    /// [`AddedClauseInfo`] tells the caller whether the clause propagates or is conflicting, and
+    /// at which decision level this is the case.
+    #[allow(unused, reason = "can be used in assignment")]
+    pub(crate) fn add_clause_during_search(
+        &mut self,
+        literals: Vec<Literal>,
+        assignments: &AssignmentsPropositional,
+        clause_allocator: &mut ClauseAllocator,
+    ) -> Result<AddedClauseInfo, ConstraintOperationError> {
+        if self.is_in_infeasible_state {
+            return Err(ConstraintOperationError::InfeasibleState);
+        }
+
+        let mut literals = Preprocessor::preprocess_clause(literals, assignments);
+
+        if literals.is_empty() {
+            self.is_in_infeasible_state = true;
+            return Err(ConstraintOperationError::InfeasibleClause);
+        }
+
+        // clauses with a single literal are not stored, the literal should be propagated at the
+        // root
+        if literals.len() == 1 {
+            return Ok(if assignments.is_literal_root_assignment(literals[0]) {
+                AddedClauseInfo::Satisfied {
+                    clause_reference: None,
+                }
+            } else {
+                AddedClauseInfo::Unit {
+                    clause_reference: None,
+                    propagated_literal: literals[0],
+                    propagation_level: 0,
+                }
+            });
+        }
+
+        // non-falsified literals are placed first, followed by falsified literals in decreasing
+        // order of their assignment level
+        let watch_priority = |literal: &Literal| {
+            if assignments.is_literal_assigned_false(*literal) {
+                assignments.get_literal_assignment_level(*literal)
+            } else {
+                usize::MAX
+            }
+        };
+        literals.sort_by_key(|literal| std::cmp::Reverse(watch_priority(literal)));
+
+        let num_non_falsified_literals = literals
+            .iter()
+            .take_while(|literal| !assignments.is_literal_assigned_false(**literal))
+            .count();
+
+        let clause_reference = self
+            .add_clause_unchecked(literals, false, clause_allocator)
+            .expect("Add clause failed for some reason");
+        let clause = clause_allocator.get_clause(clause_reference);
+
+        let added_clause_info = match num_non_falsified_literals {
+            0 => AddedClauseInfo::Conflicting {
+                clause_reference,
+                conflict_level: assignments.get_literal_assignment_level(clause[0]),
+            },
+            1 => {
+                let propagated_literal = clause[0];
+                let propagation_level = assignments.get_literal_assignment_level(clause[1]);
+
+                if assignments.is_literal_assigned_true(propagated_literal)
+                    && assignments.get_literal_assignment_level(propagated_literal)
+                        <= propagation_level
+                {
+                    AddedClauseInfo::Satisfied {
+                        clause_reference: Some(clause_reference),
+                    }
+                } else {
+                    AddedClauseInfo::Unit {
+                        clause_reference: Some(clause_reference),
+                        propagated_literal,
+                        propagation_level,
+                    }
+                }
+            }
+            _ => AddedClauseInfo::Satisfied {
+                clause_reference: Some(clause_reference),
+            },
+        };
+
+        Ok(added_clause_info)
+    }
+
+    /// Adds the learned clause and propagates its asserting literal, which is the first literal of
+    /// the clause.
+    ///
+    /// The duplicate literals of the clause are removed. A learned clause which is tautological
+    /// (or which has fewer than two distinct literals) indicates a bug in the conflict analysis;
+    /// this panics in debug builds, whereas in release builds the clause is not learned and
+    /// [`None`] is returned.
     #[allow(unused, reason = "can be used in assignment")]
     pub(crate) fn add_asserting_learned_clause(
         &mut self,
@@ -101,11 +247,23 @@ impl ClausalPropagator {
         assignments: &mut AssignmentsPropositional,
         clause_allocator: &mut ClauseAllocator,
     ) -> Option<ClauseReference> {
+        munchkin_assert_simple!(!self.is_in_infeasible_state);
         let asserting_literal = literals[0];
 
-        let clause_reference = self
-            .add_clause_unchecked(literals, true, clause_allocator)
-            .expect("Add clause failed for some reason");
+        let clause_reference = match clause_allocator.create_clause_checked(literals, true) {
+            Ok(clause_reference) => clause_reference,
+            Err(error) => {
+                if cfg!(debug_assertions) {
+                    panic!("Degenerate learned clause: {error}");
+                }
+                return None;
+            }
+        };
+        self.permanent_clauses.push(clause_reference);
+        self.start_watching_clause_unchecked(
+            clause_allocator[clause_reference].get_literal_slice(),
+            clause_reference,
+        );
 
         let _ = assignments.enqueue_propagated_literal(asserting_literal, clause_reference.into());
 
@@ -130,13 +288,14 @@ impl ClausalPropagator {
         Some(clause_reference)
     }
 
-    pub(crate) fn add_permanent_implication_unchecked(
-        &mut self,
-        lhs: Literal,
-        rhs: Literal,
-        clause_allocator: &mut ClauseAllocator,
-    ) {
-        let _ = self.add_clause_unchecked(vec![!lhs, rhs], false, clause_allocator);
+    /// Adds the implication `lhs -> rhs` as a binary clause which is not allocated in the
+    /// [`ClauseAllocator`]; both `lhs` and `rhs` are assumed to be unassigned.
+    pub(crate) fn add_permanent_implication_unchecked(&mut self, lhs: Literal, rhs: Literal) {
+        munchkin_assert_simple!(!self.is_in_infeasible_state);
+
+        self.binary_implications[lhs].push(rhs);
+        self.binary_implications[!rhs].push(!lhs);
+        self.num_implication_entries += 2;
     }
 
     pub(crate) fn add_permanent_ternary_clause_unchecked(
@@ -165,6 +324,22 @@ impl ClausalPropagator {
                 assignments.get_trail_entry(self.next_position_on_trail_to_propagate);
             munchkin_assert_simple!(assignments.is_literal_assigned_true(true_literal));
 
+            // the binary implications are propagated before the allocated clauses since they do
+            // not require inspecting the clause
+            for index in 0..self.binary_implications[true_literal].len() {
+                let implied_literal = self.binary_implications[true_literal][index];
+                if assignments.is_literal_assigned_true(implied_literal) {
+                    continue;
+                }
+
+                let reason = ClauseReference::create_virtual_binary_clause_reference(!true_literal);
+                let conflict_info =
+                    assignments.enqueue_propagated_literal(implied_literal, reason.into());
+                if let Some(conflict_info) = conflict_info {
+                    return Err(conflict_info);
+                }
+            }
+
             // effectively remove all watches from this true_literal
             // then go through the previous watches one by one and insert them as indicated (some
             // might be placed back in the watch list of this true_literal)
@@ -296,6 +471,79 @@ impl ClausalPropagator {
         self.next_position_on_trail_to_propagate == trail_size
     }
 
+    /// Returns the number of implications of every literal, which can be passed to
+    /// [`ClausalPropagator::truncate_binary_implications`] to remove the implications which are
+    /// added afterwards.
+    pub(crate) fn num_binary_implications(&self) -> Vec<usize> {
+        self.binary_implications.iter().map(Vec::len).collect()
+    }
+
+    /// Removes the binary clauses which were added after `num_binary_implications` was obtained
+    /// using [`ClausalPropagator::num_binary_implications`]; the literals which did not exist at
+    /// that point lose all of their implications.
+    pub(crate) fn truncate_binary_implications(&mut self, num_binary_implications: &[usize]) {
+        for (index, implied_literals) in self.binary_implications.iter_mut().enumerate() {
+            implied_literals.truncate(num_binary_implications.get(index).copied().unwrap_or(0));
+        }
+        self.num_implication_entries = self.binary_implications.iter().map(Vec::len).sum();
+    }
+
+    /// Removes the binary clauses which are satisfied at the root from the implications of the
+    /// provided `literals` and their negations, and returns the number of removed entries.
+    ///
+    /// The implications of a literal which is assigned at the root are all removed: if the literal
+    /// is true, then the implied literals are already true, and if it is false, then they are
+    /// never triggered. The implications of an unassigned literal only keep the implied literals
+    /// which are unassigned, since propagation is complete and the other ones are therefore true.
+    ///
+    /// Note that this is only valid as long as the root assignments are not undone.
+    pub(crate) fn remove_root_satisfied_implications(
+        &mut self,
+        literals: impl IntoIterator<Item = Literal>,
+        assignments: &AssignmentsPropositional,
+    ) -> usize {
+        munchkin_assert_simple!(assignments.is_at_the_root_level());
+        munchkin_assert_simple!(self.is_propagation_complete(assignments.num_trail_entries()));
+
+        let mut num_removed_entries = 0;
+        for literal in literals.into_iter().flat_map(|literal| [literal, !literal]) {
+            let implied_literals = &mut self.binary_implications[literal];
+            let num_entries = implied_literals.len();
+
+            if assignments.is_literal_assigned(literal) {
+                *implied_literals = vec![];
+            } else {
+                implied_literals.retain(|&implied| {
+                    munchkin_assert_moderate!(!assignments.is_literal_assigned_false(implied));
+                    !assignments.is_literal_assigned(implied)
+                });
+                implied_literals.shrink_to_fit();
+            }
+
+            num_removed_entries += num_entries - implied_literals.len();
+        }
+
+        self.num_implication_entries -= num_removed_entries;
+        num_removed_entries
+    }
+
+    /// Returns the approximate number of bytes used by the watch lists and the binary
+    /// implications, assuming that each of the `num_clause_slots` allocated clauses is watched
+    /// twice.
+    pub(crate) fn approximate_memory_usage(&self, num_clause_slots: usize) -> usize {
+        self.watch_lists.approximate_memory_usage()
+            + 2 * num_clause_slots * size_of::<ClauseWatcher>()
+            + self.binary_implications.approximate_memory_usage()
+            + self.num_implication_entries * size_of::<Literal>()
+    }
+
+    /// Leaves the infeasible state which is entered when a clause is found to be conflicting at
+    /// the root; this is only valid if the root assignments which led to the conflict have been
+    /// undone.
+    pub(crate) fn reset_infeasible_state(&mut self) {
+        self.is_in_infeasible_state = false;
+    }
+
     pub(crate) fn remove_clause_from_consideration(
         &mut self,
         clause: &[Literal],
@@ -319,11 +567,90 @@ impl ClausalPropagator {
         remove_clause_from_watchers(&mut self.watch_lists[watched_literal2], clause_reference);
     }
 
+    /// Removes the permanent clauses for which `should_remove` returns true from consideration and
+    /// deletes them from the [`ClauseAllocator`].
+    pub(crate) fn remove_permanent_clauses(
+        &mut self,
+        clause_allocator: &mut ClauseAllocator,
+        mut should_remove: impl FnMut(ClauseReference, &[Literal]) -> bool,
+    ) {
+        let mut permanent_clauses = std::mem::take(&mut self.permanent_clauses);
+        permanent_clauses.retain(|&clause_reference| {
+            let clause = clause_allocator
+                .get_clause(clause_reference)
+                .get_literal_slice();
+            if !should_remove(clause_reference, clause) {
+                return true;
+            }
+
+            self.remove_clause_from_consideration(clause, clause_reference);
+            clause_allocator.delete_clause(clause_reference);
+            false
+        });
+        self.permanent_clauses = permanent_clauses;
+    }
+
+    /// Removes the literals which are falsified at the root from the learned clauses which are
+    /// not satisfied at the root, and returns the number of removed literals.
+    ///
+    /// The original clauses are not shrunk, since these define the problem; a solution which was
+    /// found before the root was strengthened (e.g. by a bound on the objective) should still
+    /// satisfy them.
+    ///
+    /// Since propagation is complete, every such clause has at least two unassigned literals,
+    /// which become its watched literals.
+    pub(crate) fn remove_root_falsified_literals(
+        &mut self,
+        assignments: &AssignmentsPropositional,
+        clause_allocator: &mut ClauseAllocator,
+    ) -> usize {
+        munchkin_assert_simple!(assignments.is_at_the_root_level());
+
+        let mut num_removed_literals = 0;
+        for index in 0..self.permanent_clauses.len() {
+            let clause_reference = self.permanent_clauses[index];
+            let clause = clause_allocator.get_clause(clause_reference);
+            if !clause.is_learned() {
+                continue;
+            }
+
+            let literals = clause.get_literal_slice();
+            if literals
+                .iter()
+                .any(|&literal| assignments.is_literal_assigned_true(literal))
+            {
+                continue;
+            }
+            let num_falsified = literals
+                .iter()
+                .filter(|&&literal| assignments.is_literal_assigned_false(literal))
+                .count();
+            if num_falsified == 0 {
+                continue;
+            }
+
+            self.remove_clause_from_consideration(literals, clause_reference);
+            clause_allocator[clause_reference]
+                .remove_literals(|&literal| assignments.is_literal_assigned_false(literal));
+            self.start_watching_clause_unchecked(
+                clause_allocator[clause_reference].get_literal_slice(),
+                clause_reference,
+            );
+
+            num_removed_literals += num_falsified;
+        }
+
+        num_removed_literals
+    }
+
     pub(crate) fn debug_check_state(
         &self,
         assignments: &AssignmentsPropositional,
         clause_allocator: &ClauseAllocator,
     ) -> bool {
+        #[cfg(test)]
+        NUM_STATE_CHECKS.with(|num_checks| num_checks.set(num_checks.get() + 1));
+
         assert!(
             self.watch_lists.len() as u32 == 2 * assignments.num_propositional_variables(),
             "Watch list length is not as expected given the number of propositional variables."
@@ -337,18 +664,27 @@ impl ClausalPropagator {
         //  first compute the histogram for each clause present
         let mut clause_ids: HashMap<ClauseReference, usize> = HashMap::default();
 
-        // counting the number of binary clause watchers is a proxy
-        //  in case the number is uneven we have a problem
+        // every binary implication should be stored together with its contrapositive
         assert!(
-            self.watch_lists
-                .iter()
-                .flatten()
-                .filter(|x| x.clause_reference.is_virtual_binary_clause())
-                .count()
-                % 2
-                == 0,
+            self.binary_implications.len() == self.watch_lists.len(),
+            "The binary implications should be stored for every literal."
         );
+        for literal_code in 0..self.binary_implications.len() {
+            let lhs = Literal::u32_to_literal(literal_code as u32);
+            for &rhs in self.binary_implications[lhs].iter() {
+                assert!(
+                    self.binary_implications[!rhs].contains(&!lhs),
+                    "The contrapositive of a binary implication is missing."
+                );
+                assert!(
+                    !assignments.is_literal_assigned_true(lhs)
+                        || assignments.is_literal_assigned_true(rhs),
+                    "Debugging revealed that the clausal propagator missed a binary propagation."
+                );
+            }
+        }
 
+        // binary clauses are not allocated, so they should not appear in the watch lists
         assert!(self
             .watch_lists
             .iter()
@@ -374,10 +710,7 @@ impl ClausalPropagator {
         assert!(
             self.watch_lists.iter().flatten().all(|x| {
                 let clause = clause_allocator.get_clause(x.clause_reference);
-                clause
-                    .get_literal_slice()
-                    .iter()
-                    .any(|lit| *lit == x.cached_literal)
+                clause.get_literal_slice().contains(&x.cached_literal)
             }),
             "There is a watcher with a cached literal that is not present in the clause."
         );
@@ -403,7 +736,23 @@ impl ClausalPropagator {
             {
                 let constraint_reference = assignments.get_literal_reason_constraint(literal);
 
-                if constraint_reference.is_clause() {
+                if constraint_reference.is_virtual_binary_clause() {
+                    let clause_reference: ClauseReference = constraint_reference.into();
+                    let reason_literal = clause_reference.get_virtual_binary_clause_literal();
+                    assert!(
+                        self.binary_implications[!reason_literal].contains(&literal),
+                        "The binary clause responsible for propagation is not stored."
+                    );
+                    assert!(
+                        assignments.is_literal_assigned_false(reason_literal),
+                        "A binary clause is recorded as the reason for propagation, but the other literal is not false."
+                    );
+                    assert!(
+                        assignments.get_literal_assignment_level(reason_literal)
+                            == assignments.get_literal_assignment_level(literal),
+                        "Literal propagation level does not match the other literal."
+                    );
+                } else if constraint_reference.is_clause() {
                     let clause_reference = constraint_reference.into();
                     assert!(
                         clause_ids.contains_key(&clause_reference),
@@ -486,8 +835,294 @@ impl ClausalPropagator {
     }
 }
 
+/// The information returned by [`ClausalPropagator::add_clause_during_search`] describing the
+/// state of the added clause with respect to the current assignment.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum AddedClauseInfo {
+    /// The clause does not propagate anything; either it contains at least two non-falsified
+    /// literals, or it is satisfied by a literal which was assigned at a level no higher than any
+    /// falsified literal.
+    ///
+    /// The clause reference is [`None`] if the clause was satisfied at the root, in which case it
+    /// is not stored.
+    Satisfied {
+        clause_reference: Option<ClauseReference>,
+    },
+    /// All literals except `propagated_literal` are falsified, and the clause propagates
+    /// `propagated_literal` at `propagation_level`. The caller is responsible for backtracking to
+    /// that level if needed and enqueueing the propagation with the clause as its reason.
+    ///
+    /// The clause reference is [`None`] if the clause reduced to a single literal, in which case
+    /// the literal should be assigned at the root.
+    Unit {
+        clause_reference: Option<ClauseReference>,
+        propagated_literal: Literal,
+        propagation_level: usize,
+    },
+    /// All literals in the clause are falsified, the highest assignment level of its literals is
+    /// `conflict_level`. The caller is responsible for triggering conflict analysis.
+    Conflicting {
+        clause_reference: ClauseReference,
+        conflict_level: usize,
+    },
+}
+
 #[derive(Clone, Copy, Debug)]
 pub(crate) struct ClauseWatcher {
     cached_literal: Literal,
     clause_reference: ClauseReference,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::AddedClauseInfo;
+    use crate::basic_types::ClauseReference;
+    use crate::engine::test_helper::TestSolver;
+    use crate::engine::variables::Literal;
+
+    fn add_clause_during_search(
+        solver: &mut TestSolver,
+        literals: Vec<Literal>,
+    ) -> AddedClauseInfo {
+        solver
+            .clausal_propagator
+            .add_clause_during_search(
+                literals,
+                &solver.assignments_propositional,
+                &mut solver.clause_allocator,
+            )
+            .expect("the solver is not in an infeasible state")
+    }
+
+    fn check_state(solver: &mut TestSolver) {
+        assert!(solver.propagate_clausal_propagator().is_ok());
+        assert!(solver
+            .clausal_propagator
+            .debug_check_state(&solver.assignments_propositional, &solver.clause_allocator));
+    }
+
+    #[test]
+    fn duplicate_literals_of_learned_clause_are_not_both_watched() {
+        let mut solver = TestSolver::default();
+        let a = solver.new_literal();
+        let b = solver.new_literal();
+
+        solver.increase_decision_level();
+        solver.set_decision(!b);
+        check_state(&mut solver);
+
+        let clause_reference = solver
+            .clausal_propagator
+            .add_asserting_learned_clause(
+                vec![a, a, b, b],
+                &mut solver.assignments_propositional,
+                &mut solver.clause_allocator,
+            )
+            .expect("the learned clause is not degenerate");
+
+        assert_eq!(
+            solver.clause_allocator[clause_reference].get_literal_slice(),
+            &[a, b]
+        );
+        assert!(solver.assignments_propositional.is_literal_assigned_true(a));
+        check_state(&mut solver);
+    }
+
+    #[test]
+    #[should_panic(expected = "Degenerate learned clause")]
+    #[cfg(debug_assertions)]
+    fn tautological_learned_clause_panics_in_debug_builds() {
+        let mut solver = TestSolver::default();
+        let a = solver.new_literal();
+        let b = solver.new_literal();
+
+        solver.increase_decision_level();
+        solver.set_decision(!b);
+
+        let _ = solver.clausal_propagator.add_asserting_learned_clause(
+            vec![a, b, !a],
+            &mut solver.assignments_propositional,
+            &mut solver.clause_allocator,
+        );
+    }
+
+    #[test]
+    fn satisfied_clause_added_during_search_does_not_propagate() {
+        let mut solver = TestSolver::default();
+        let a = solver.new_literal();
+        let b = solver.new_literal();
+        let c = solver.new_literal();
+
+        solver.increase_decision_level();
+        solver.set_decision(a);
+        check_state(&mut solver);
+
+        let info = add_clause_during_search(&mut solver, vec![b, a, c]);
+
+        assert!(matches!(
+            info,
+            AddedClauseInfo::Satisfied {
+                clause_reference: Some(_)
+            }
+        ));
+        check_state(&mut solver);
+    }
+
+    #[test]
+    fn unit_clause_added_during_search_reports_propagation() {
+        let mut solver = TestSolver::default();
+        let a = solver.new_literal();
+        let b = solver.new_literal();
+        let c = solver.new_literal();
+
+        solver.increase_decision_level();
+        solver.set_decision(!a);
+        solver.increase_decision_level();
+        solver.set_decision(!b);
+        check_state(&mut solver);
+
+        let info = add_clause_during_search(&mut solver, vec![a, b, c]);
+
+        let AddedClauseInfo::Unit {
+            clause_reference: Some(clause_reference),
+            propagated_literal,
+            propagation_level,
+        } = info
+        else {
+            panic!("expected the clause to be unit, got {info:?}");
+        };
+        assert_eq!(propagated_literal, c);
+        assert_eq!(propagation_level, 2);
+        // the falsified literal with the highest assignment level is the second watch
+        assert_eq!(solver.clause_allocator.get_clause(clause_reference)[1], b);
+
+        let _ = solver
+            .assignments_propositional
+            .enqueue_propagated_literal(propagated_literal, clause_reference.into());
+        check_state(&mut solver);
+        assert!(solver.assignments_propositional.is_literal_assigned_true(c));
+    }
+
+    #[test]
+    fn conflicting_clause_added_during_search_reports_conflict_level() {
+        let mut solver = TestSolver::default();
+        let a = solver.new_literal();
+        let b = solver.new_literal();
+
+        solver.increase_decision_level();
+        solver.set_decision(!a);
+        solver.increase_decision_level();
+        solver.set_decision(!b);
+        check_state(&mut solver);
+
+        let info = add_clause_during_search(&mut solver, vec![a, b]);
+
+        let AddedClauseInfo::Conflicting {
+            clause_reference,
+            conflict_level,
+        } = info
+        else {
+            panic!("expected the clause to be conflicting, got {info:?}");
+        };
+        assert_eq!(conflict_level, 2);
+
+        // after backtracking below the conflict level, the clause propagates its first literal
+        let _ = solver
+            .assignments_propositional
+            .synchronise(conflict_level - 1)
+            .count();
+        solver
+            .clausal_propagator
+            .synchronise(solver.assignments_propositional.num_trail_entries());
+        let propagated_literal = solver.clause_allocator.get_clause(clause_reference)[0];
+        assert_eq!(propagated_literal, b);
+
+        let _ = solver
+            .assignments_propositional
+            .enqueue_propagated_literal(propagated_literal, clause_reference.into());
+        check_state(&mut solver);
+    }
+
+    fn binary_clause_literal_of_reason(solver: &TestSolver, literal: Literal) -> Literal {
+        let reference = solver
+            .assignments_propositional
+            .get_literal_reason_constraint(literal);
+        assert!(reference.is_virtual_binary_clause());
+        ClauseReference::from(reference).get_virtual_binary_clause_literal()
+    }
+
+    #[test]
+    fn binary_implications_propagate_in_chains() {
+        let mut solver = TestSolver::default();
+        let a = solver.new_literal();
+        let b = solver.new_literal();
+        let c = solver.new_literal();
+
+        solver
+            .clausal_propagator
+            .add_permanent_implication_unchecked(a, b);
+        solver
+            .clausal_propagator
+            .add_permanent_implication_unchecked(b, c);
+
+        solver.increase_decision_level();
+        solver.set_decision(a);
+        check_state(&mut solver);
+
+        assert!(solver.assignments_propositional.is_literal_assigned_true(b));
+        assert!(solver.assignments_propositional.is_literal_assigned_true(c));
+        assert_eq!(binary_clause_literal_of_reason(&solver, b), !a);
+        assert_eq!(binary_clause_literal_of_reason(&solver, c), !b);
+    }
+
+    #[test]
+    fn binary_implications_propagate_their_contrapositive() {
+        let mut solver = TestSolver::default();
+        let a = solver.new_literal();
+        let b = solver.new_literal();
+        let c = solver.new_literal();
+
+        solver
+            .clausal_propagator
+            .add_permanent_implication_unchecked(a, b);
+        solver
+            .clausal_propagator
+            .add_permanent_implication_unchecked(b, c);
+
+        solver.increase_decision_level();
+        solver.set_decision(!c);
+        check_state(&mut solver);
+
+        assert!(solver
+            .assignments_propositional
+            .is_literal_assigned_false(b));
+        assert!(solver
+            .assignments_propositional
+            .is_literal_assigned_false(a));
+        assert_eq!(binary_clause_literal_of_reason(&solver, !b), c);
+        assert_eq!(binary_clause_literal_of_reason(&solver, !a), b);
+    }
+
+    #[test]
+    fn binary_clauses_of_the_encoding_are_not_allocated() {
+        let mut solver = TestSolver::default();
+        let num_values = 10_000;
+        let _ = solver.new_variable(0, num_values - 1);
+
+        // Only the ternary clauses defining the equality literals and the clause stating that at
+        // least one of the equality literals holds are allocated
+        assert_eq!(
+            solver.clause_allocator.num_allocated_clauses(),
+            num_values as usize - 1
+        );
+
+        let num_implications = solver
+            .clausal_propagator
+            .binary_implications
+            .iter()
+            .map(|implications| implications.len())
+            .sum::<usize>();
+        // Every implication is stored twice, once for each direction
+        assert_eq!(num_implications, 2 * (3 * num_values as usize - 6));
+    }
+}
@@ -39,10 +39,21 @@ impl Clause {
         self.is_deleted
     }
 
+    pub(crate) fn is_learned(&self) -> bool {
+        self.is_learned
+    }
+
     pub(crate) fn get_literal_slice(&self) -> &[Literal] {
         &self.literals
     }
 
+    /// Removes the literals for which `should_remove` returns true; the clause should keep at
+    /// least two literals.
+    pub(crate) fn remove_literals(&mut self, mut should_remove: impl FnMut(&Literal) -> bool) {
+        self.literals.retain(|literal| !should_remove(literal));
+        munchkin_assert_simple!(self.literals.len() >= 2);
+    }
+
     // note that this does _not_ delete the clause, it simply marks it as if it was deleted
     //  to delete a clause, use the ClauseManager
     //  could restrict access of this method in the future
@@ -1,27 +1,82 @@
+use thiserror::Error;
+
 use super::Clause;
 use crate::basic_types::ClauseReference;
+use crate::basic_types::HashSet;
 use crate::engine::variables::Literal;
 use crate::munchkin_assert_advanced;
 use crate::munchkin_assert_moderate;
 use crate::munchkin_assert_simple;
 
+/// The reasons for which [`ClauseAllocator::create_clause_checked`] rejects a clause.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DegenerateClause {
+    /// The clause contains both `literal` and its negation, so it is always satisfied.
+    #[error("The clause contains both {literal} and its negation")]
+    Tautology { literal: Literal },
+    /// After removing the duplicate literals, the clause contains fewer than two literals.
+    #[error("The clause contains {num_literals} distinct literals, while at least 2 are expected")]
+    TooShort { num_literals: usize },
+}
+
 #[derive(Default, Debug)]
 pub(crate) struct ClauseAllocator {
     allocated_clauses: Vec<Clause>,
     deleted_clause_references: Vec<ClauseReference>,
+    /// The number of literals in the allocated clauses at the moment they were created, including
+    /// the deleted clauses whose slots have not been reused yet; this is an upper bound, since
+    /// literals may be removed from a clause afterwards.
+    num_literals: usize,
 }
 
 impl ClauseAllocator {
+    /// Creates a clause after removing its duplicate literals; the order of the remaining
+    /// literals is kept (e.g. the propagated literal of an explanation clause stays in front).
+    ///
+    /// Two watchers on the same literal would break the invariants of the
+    /// [`ClausalPropagator`](super::ClausalPropagator), so clauses which are not known to be free
+    /// of duplicates (such as clauses which are created from predicates, of which several can
+    /// map to the same literal) should be created using this method. A clause which contains
+    /// complementary literals or fewer than two distinct literals is rejected.
+    pub(crate) fn create_clause_checked(
+        &mut self,
+        mut literals: Vec<Literal>,
+        is_learned: bool,
+    ) -> Result<ClauseReference, DegenerateClause> {
+        let mut seen: HashSet<Literal> = HashSet::default();
+        for &literal in literals.iter() {
+            if seen.contains(&!literal) {
+                return Err(DegenerateClause::Tautology { literal });
+            }
+            let _ = seen.insert(literal);
+        }
+
+        if seen.len() < literals.len() {
+            seen.clear();
+            literals.retain(|&literal| seen.insert(literal));
+        }
+
+        if literals.len() < 2 {
+            return Err(DegenerateClause::TooShort {
+                num_literals: literals.len(),
+            });
+        }
+
+        Ok(self.create_clause(literals, is_learned))
+    }
+
+    /// Creates a clause without checking its literals; the clause is assumed to consist of at
+    /// least two distinct, non-complementary literals (see
+    /// [`ClauseAllocator::create_clause_checked`]).
     pub(crate) fn create_clause(
         &mut self,
         literals: Vec<Literal>,
         is_learned: bool,
     ) -> ClauseReference {
-        // todo - add assert to ensure that the clause is as we expect, e.g., no duplicate literals.
-        // Normally preprocess_clause would get rid of this. Perhaps could move the responsibility
-        // to the clause manager, and have an unchecked version for learned clauses
         munchkin_assert_simple!(literals.len() >= 2);
 
+        self.num_literals += literals.len();
+
         if self.deleted_clause_references.is_empty() {
             // create a new clause reference, unseen before
             let clause_reference = ClauseReference::create_allocated_clause_reference(
@@ -36,6 +91,7 @@ impl ClauseAllocator {
         } else {
             // reuse a clause reference from the deleted clause pool
             let clause_reference = self.deleted_clause_references.pop().unwrap();
+            self.num_literals -= self.get_clause(clause_reference).len() as usize;
             self.allocated_clauses[clause_reference.get_code() as usize - 1] =
                 Clause::new(literals, is_learned);
 
@@ -43,6 +99,25 @@ impl ClauseAllocator {
         }
     }
 
+    #[cfg(test)]
+    /// The number of clauses which are currently allocated, i.e. which have not been deleted.
+    pub(crate) fn num_allocated_clauses(&self) -> usize {
+        self.allocated_clauses.len() - self.deleted_clause_references.len()
+    }
+
+    /// Returns the approximate number of bytes used by the allocated clauses: the clause slots
+    /// (based on their capacity) and the literals of the clauses.
+    pub(crate) fn approximate_memory_usage(&self) -> usize {
+        self.allocated_clauses.capacity() * size_of::<Clause>()
+            + self.num_literals * size_of::<Literal>()
+    }
+
+    /// The number of clause slots which are allocated, including the slots of the deleted
+    /// clauses.
+    pub(crate) fn num_clause_slots(&self) -> usize {
+        self.allocated_clauses.len()
+    }
+
     pub(crate) fn get_mutable_clause(&mut self, clause_reference: ClauseReference) -> &mut Clause {
         &mut self.allocated_clauses[clause_reference.get_code() as usize - 1]
         //-1 since clause ids go from one, and not zero
@@ -100,3 +175,57 @@ impl std::fmt::Display for ClauseAllocator {
         write!(f, "Num clauses: {num_clauses}\n{clauses_string}")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ClauseAllocator;
+    use super::DegenerateClause;
+    use crate::engine::variables::Literal;
+    use crate::engine::variables::PropositionalVariable;
+
+    fn literals() -> (Literal, Literal, Literal) {
+        (
+            Literal::new(PropositionalVariable::new(1), true),
+            Literal::new(PropositionalVariable::new(2), true),
+            Literal::new(PropositionalVariable::new(3), true),
+        )
+    }
+
+    #[test]
+    fn duplicate_literals_are_removed_in_order() {
+        let mut clause_allocator = ClauseAllocator::default();
+        let (a, b, c) = literals();
+
+        let clause_reference = clause_allocator
+            .create_clause_checked(vec![a, b, a, c, b], false)
+            .expect("the clause is not degenerate");
+
+        assert_eq!(
+            clause_allocator[clause_reference].get_literal_slice(),
+            &[a, b, c]
+        );
+    }
+
+    #[test]
+    fn tautology_is_rejected() {
+        let mut clause_allocator = ClauseAllocator::default();
+        let (a, b, _) = literals();
+
+        assert_eq!(
+            clause_allocator.create_clause_checked(vec![a, b, !a], true),
+            Err(DegenerateClause::Tautology { literal: !a })
+        );
+        assert_eq!(clause_allocator.num_allocated_clauses(), 0);
+    }
+
+    #[test]
+    fn clause_with_a_single_distinct_literal_is_rejected() {
+        let mut clause_allocator = ClauseAllocator::default();
+        let (a, _, _) = literals();
+
+        assert_eq!(
+            clause_allocator.create_clause_checked(vec![a, a], false),
+            Err(DegenerateClause::TooShort { num_literals: 1 })
+        );
+    }
+}
@@ -14,6 +14,11 @@ impl ExplanationClauseManager {
         self.explanation_clauses.is_empty()
     }
 
+    /// Allocates the explanation clause after removing its duplicate literals, which occur when
+    /// several predicates of the explanation map to the same literal.
+    ///
+    /// An explanation with complementary literals (or with fewer than two distinct literals)
+    /// indicates a bug in the explanation, in which case this method panics.
     pub(crate) fn add_explanation_clause_unchecked(
         &mut self,
         explanation_literals: Vec<Literal>,
@@ -21,7 +26,9 @@ impl ExplanationClauseManager {
     ) -> ClauseReference {
         munchkin_assert_moderate!(explanation_literals.len() >= 2);
 
-        let clause_reference = clause_allocator.create_clause(explanation_literals, false);
+        let clause_reference = clause_allocator
+            .create_clause_checked(explanation_literals, false)
+            .unwrap_or_else(|error| panic!("Degenerate explanation clause: {error}"));
         self.explanation_clauses.push(clause_reference);
 
         clause_reference
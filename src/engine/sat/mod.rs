@@ -4,9 +4,14 @@ mod clause;
 mod clause_allocator;
 mod explanation_clause_manager;
 mod lbd_calculator;
+mod polarity_statistics;
 
 pub(crate) use assignments_propositional::AssignmentsPropositional;
+#[allow(unused_imports, reason = "can be used in assignment")]
+pub(crate) use clausal_propagator::AddedClauseInfo;
 pub(crate) use clausal_propagator::ClausalPropagator;
+#[cfg(test)]
+pub(crate) use clausal_propagator::NUM_STATE_CHECKS;
 pub(crate) use clause::Clause;
 pub(crate) use clause_allocator::ClauseAllocator;
 pub(crate) use explanation_clause_manager::ExplanationClauseManager;
@@ -0,0 +1,131 @@
+use crate::basic_types::KeyedVec;
+use crate::engine::variables::PropositionalVariable;
+
+/// The default number of assignments after which the polarity counts are halved.
+const DEFAULT_POLARITY_DECAY_INTERVAL: u32 = 1024;
+
+/// The number of times that a [`PropositionalVariable`] has recently been assigned to true and to
+/// false; see [`AssignmentsPropositional::polarity_counts`].
+///
+/// [`AssignmentsPropositional::polarity_counts`]:
+/// crate::engine::sat::AssignmentsPropositional::polarity_counts
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct PolarityCounts {
+    pub(crate) num_true: u32,
+    pub(crate) num_false: u32,
+}
+
+impl PolarityCounts {
+    /// Returns a value in `[-1, 1]` which is positive if the variable has more recently been
+    /// assigned to true than to false, negative if the opposite holds, and `0` if the variable has
+    /// been assigned to both polarities equally often (or has not recently been assigned at all).
+    pub(crate) fn bias(&self) -> f64 {
+        let total = self.num_true + self.num_false;
+        if total == 0 {
+            return 0.0;
+        }
+
+        (self.num_true as f64 - self.num_false as f64) / total as f64
+    }
+}
+
+/// Keeps track of how often every [`PropositionalVariable`] has been assigned to true and to false.
+///
+/// The counts decay exponentially: every `decay_interval` assignments (of any variable) an epoch
+/// passes, and every passed epoch halves the counts. This decay is applied lazily; every variable
+/// stores the epoch in which its counts were last updated, and the elapsed epochs are only taken
+/// into account when its counts are read or updated.
+#[derive(Clone, Debug)]
+pub(crate) struct PolarityStatistics {
+    /// Per variable, the number of assignments to true in the upper 16 bits and the number of
+    /// assignments to false in the lower 16 bits, together with the epoch in which these counts
+    /// were last updated.
+    counts: KeyedVec<PropositionalVariable, (u32, u32)>,
+    decay_interval: u32,
+    num_assignments_in_epoch: u32,
+    epoch: u32,
+}
+
+impl Default for PolarityStatistics {
+    fn default() -> Self {
+        PolarityStatistics {
+            counts: Default::default(),
+            decay_interval: DEFAULT_POLARITY_DECAY_INTERVAL,
+            num_assignments_in_epoch: 0,
+            epoch: 0,
+        }
+    }
+}
+
+impl PolarityStatistics {
+    pub(crate) fn approximate_memory_usage(&self) -> usize {
+        self.counts.approximate_memory_usage()
+    }
+
+    pub(crate) fn grow(&mut self) {
+        self.counts.push((0, self.epoch));
+    }
+
+    /// Adds the counts of `num_variables` new variables, reserving the space for them at once.
+    pub(crate) fn grow_by(&mut self, num_variables: usize) {
+        self.counts.reserve(num_variables);
+        for _ in 0..num_variables {
+            self.grow();
+        }
+    }
+
+    /// Reserves space for the counts of at least `num_variables` more variables.
+    pub(crate) fn reserve(&mut self, num_variables: usize) {
+        self.counts.reserve(num_variables);
+    }
+
+    #[cfg(test)]
+    pub(crate) fn set_decay_interval(&mut self, decay_interval: u32) {
+        self.decay_interval = decay_interval.max(1);
+    }
+
+    /// Returns the counts of the provided variable, taking into account the decay of the epochs
+    /// which have passed since they were last updated.
+    pub(crate) fn counts(&self, variable: PropositionalVariable) -> PolarityCounts {
+        let (packed_counts, epoch) = self.counts[variable];
+        let packed_counts = self.decay(packed_counts, epoch);
+
+        PolarityCounts {
+            num_true: packed_counts >> 16,
+            num_false: packed_counts & 0xFFFF,
+        }
+    }
+
+    /// Records that the provided variable has been assigned to `truth_value`.
+    pub(crate) fn record_assignment(&mut self, variable: PropositionalVariable, truth_value: bool) {
+        let (packed_counts, epoch) = self.counts[variable];
+        let mut packed_counts = self.decay(packed_counts, epoch);
+
+        let shift = if truth_value { 16 } else { 0 };
+        if (packed_counts >> shift) & 0xFFFF == 0xFFFF {
+            // Halving both counts keeps their ratio (approximately) intact.
+            packed_counts = (packed_counts >> 1) & 0x7FFF_7FFF;
+        }
+        packed_counts += 1 << shift;
+
+        self.counts[variable] = (packed_counts, self.epoch);
+
+        self.num_assignments_in_epoch += 1;
+        if self.num_assignments_in_epoch >= self.decay_interval {
+            self.num_assignments_in_epoch = 0;
+            self.epoch = self.epoch.wrapping_add(1);
+        }
+    }
+
+    fn decay(&self, packed_counts: u32, epoch: u32) -> u32 {
+        let num_elapsed_epochs = self.epoch.wrapping_sub(epoch);
+        if num_elapsed_epochs >= 16 {
+            return 0;
+        }
+
+        // Shifting both halves at once, the bits shifted out of the upper half should not end up
+        // in the lower half.
+        let mask = (0xFFFF >> num_elapsed_epochs) * 0x0001_0001;
+        (packed_counts >> num_elapsed_epochs) & mask
+    }
+}
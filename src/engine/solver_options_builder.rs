@@ -0,0 +1,301 @@
+use thiserror::Error;
+
+use super::constraint_satisfaction_solver::ConflictResolutionStrategy;
+use super::constraint_satisfaction_solver::NogoodMinimisationStrategy;
+use super::ProbingOptions;
+use super::SatisfactionSolverOptions;
+use crate::asserts::AssertLevel;
+use crate::proof::Proof;
+
+/// A builder for [`SatisfactionSolverOptions`] which checks that the options are consistent with
+/// each other when they are built; see [`SolverOptionsBuilder::build`].
+///
+/// # Example
+/// ```rust
+/// # use munchkin::options::SolverOptions;
+/// # use munchkin::options::ConflictResolutionStrategy;
+/// # use munchkin::Solver;
+/// let options = SolverOptions::builder()
+///     .with_conflict_resolver(ConflictResolutionStrategy::UniqueImplicationPoint)
+///     .with_restart_interval(Some(100))
+///     .build()
+///     .expect("the options are valid");
+///
+/// let solver = Solver::with_options(options);
+/// assert_eq!(solver.options().restart_interval, Some(100));
+/// ```
+#[derive(Debug, Default)]
+pub struct SolverOptionsBuilder {
+    options: SatisfactionSolverOptions,
+}
+
+/// The combinations of options which are rejected by [`SolverOptionsBuilder::build`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Error)]
+pub enum InvalidOptions {
+    #[error(
+        "A restart interval is set, but restarts are not performed with the conflict resolver \
+         `{0}`; use a learning conflict resolver or remove the restart interval"
+    )]
+    RestartsWithoutLearning(ConflictResolutionStrategy),
+    #[error("The restart interval should be at least 1 conflict")]
+    ZeroRestartInterval,
+    #[error(
+        "The nogood minimisation strategy `{minimisation_strategy}` is set, but no nogoods are \
+         learned with the conflict resolver `{conflict_resolver}`"
+    )]
+    MinimisationWithoutLearning {
+        minimisation_strategy: NogoodMinimisationStrategy,
+        conflict_resolver: ConflictResolutionStrategy,
+    },
+    #[error("A maximum proof size is set, but no proof is logged")]
+    ProofSizeLimitWithoutProof,
+    #[error("Root probing is enabled, but its budget does not allow any probes")]
+    ProbingWithoutBudget,
+    #[error("The propagation loop threshold should be at least 1 round trip")]
+    ZeroPropagationLoopThreshold,
+}
+
+impl SatisfactionSolverOptions {
+    /// Returns a [`SolverOptionsBuilder`] which starts from the default options.
+    pub fn builder() -> SolverOptionsBuilder {
+        SolverOptionsBuilder::default()
+    }
+}
+
+impl SolverOptionsBuilder {
+    /// Sets [`SatisfactionSolverOptions::random_seed`].
+    pub fn with_random_seed(mut self, random_seed: u64) -> Self {
+        self.options.random_seed = random_seed;
+        self
+    }
+
+    /// Sets [`SatisfactionSolverOptions::conflict_resolver`].
+    pub fn with_conflict_resolver(mut self, conflict_resolver: ConflictResolutionStrategy) -> Self {
+        self.options.conflict_resolver = conflict_resolver;
+        self
+    }
+
+    /// Sets [`SatisfactionSolverOptions::minimisation_strategy`].
+    pub fn with_minimisation_strategy(
+        mut self,
+        minimisation_strategy: NogoodMinimisationStrategy,
+    ) -> Self {
+        self.options.minimisation_strategy = minimisation_strategy;
+        self
+    }
+
+    /// Sets [`SatisfactionSolverOptions::use_non_generic_conflict_explanation`].
+    pub fn with_non_generic_conflict_explanation(mut self, is_enabled: bool) -> Self {
+        self.options.use_non_generic_conflict_explanation = is_enabled;
+        self
+    }
+
+    /// Sets [`SatisfactionSolverOptions::use_non_generic_propagation_explanation`].
+    pub fn with_non_generic_propagation_explanation(mut self, is_enabled: bool) -> Self {
+        self.options.use_non_generic_propagation_explanation = is_enabled;
+        self
+    }
+
+    /// Sets [`SatisfactionSolverOptions::proof`].
+    pub fn with_proof(mut self, proof: Proof) -> Self {
+        self.options.proof = proof;
+        self
+    }
+
+    /// Sets [`SatisfactionSolverOptions::max_proof_bytes`].
+    pub fn with_max_proof_bytes(mut self, max_proof_bytes: Option<u64>) -> Self {
+        self.options.max_proof_bytes = max_proof_bytes;
+        self
+    }
+
+    /// Sets [`SatisfactionSolverOptions::time_propagators`].
+    pub fn with_time_propagators(mut self, time_propagators: bool) -> Self {
+        self.options.time_propagators = time_propagators;
+        self
+    }
+
+    /// Sets [`SatisfactionSolverOptions::restart_interval`].
+    pub fn with_restart_interval(mut self, restart_interval: Option<u64>) -> Self {
+        self.options.restart_interval = restart_interval;
+        self
+    }
+
+    /// Sets [`SatisfactionSolverOptions::root_probing`].
+    pub fn with_root_probing(mut self, root_probing: ProbingOptions) -> Self {
+        self.options.root_probing = root_probing;
+        self
+    }
+
+    /// Sets [`SatisfactionSolverOptions::verbose_optimisation`].
+    pub fn with_verbose_optimisation(mut self, verbose_optimisation: bool) -> Self {
+        self.options.verbose_optimisation = verbose_optimisation;
+        self
+    }
+
+    /// Sets [`SatisfactionSolverOptions::assert_level`].
+    pub fn with_assert_level(mut self, assert_level: AssertLevel) -> Self {
+        self.options.assert_level = assert_level;
+        self
+    }
+
+    /// Sets [`SatisfactionSolverOptions::propagation_loop_threshold`].
+    pub fn with_propagation_loop_threshold(
+        mut self,
+        propagation_loop_threshold: Option<u64>,
+    ) -> Self {
+        self.options.propagation_loop_threshold = propagation_loop_threshold;
+        self
+    }
+
+    /// Sets [`SatisfactionSolverOptions::expected_num_propositional_variables`].
+    pub fn with_expected_num_propositional_variables(
+        mut self,
+        expected_num_propositional_variables: usize,
+    ) -> Self {
+        self.options.expected_num_propositional_variables = expected_num_propositional_variables;
+        self
+    }
+
+    /// Returns the [`SatisfactionSolverOptions`] which have been set, or the first
+    /// [`InvalidOptions`] if they are not consistent with each other (e.g. a restart interval
+    /// combined with a conflict resolver which does not learn, in which case the restart interval
+    /// would be silently ignored).
+    pub fn build(self) -> Result<SatisfactionSolverOptions, InvalidOptions> {
+        let options = &self.options;
+        let is_learning = options.conflict_resolver != ConflictResolutionStrategy::NoLearning;
+
+        match options.restart_interval {
+            Some(0) => return Err(InvalidOptions::ZeroRestartInterval),
+            Some(_) if !is_learning => {
+                return Err(InvalidOptions::RestartsWithoutLearning(
+                    options.conflict_resolver,
+                ))
+            }
+            _ => {}
+        }
+
+        if options.minimisation_strategy != NogoodMinimisationStrategy::NoMinimisation
+            && !is_learning
+        {
+            return Err(InvalidOptions::MinimisationWithoutLearning {
+                minimisation_strategy: options.minimisation_strategy,
+                conflict_resolver: options.conflict_resolver,
+            });
+        }
+
+        if options.max_proof_bytes.is_some() && !options.proof.is_logging() {
+            return Err(InvalidOptions::ProofSizeLimitWithoutProof);
+        }
+
+        if options.root_probing.enabled
+            && (options.root_probing.max_probes == 0 || options.root_probing.max_conflicts == 0)
+        {
+            return Err(InvalidOptions::ProbingWithoutBudget);
+        }
+
+        if options.propagation_loop_threshold == Some(0) {
+            return Err(InvalidOptions::ZeroPropagationLoopThreshold);
+        }
+
+        Ok(self.options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Solver;
+
+    #[test]
+    fn restarts_without_learning_are_rejected() {
+        let result = SatisfactionSolverOptions::builder()
+            .with_restart_interval(Some(50))
+            .build();
+
+        assert_eq!(
+            result.err(),
+            Some(InvalidOptions::RestartsWithoutLearning(
+                ConflictResolutionStrategy::NoLearning
+            ))
+        );
+    }
+
+    #[test]
+    fn minimisation_without_learning_is_rejected() {
+        let result = SatisfactionSolverOptions::builder()
+            .with_minimisation_strategy(NogoodMinimisationStrategy::Recursive)
+            .build();
+
+        assert_eq!(
+            result.err(),
+            Some(InvalidOptions::MinimisationWithoutLearning {
+                minimisation_strategy: NogoodMinimisationStrategy::Recursive,
+                conflict_resolver: ConflictResolutionStrategy::NoLearning,
+            })
+        );
+    }
+
+    #[test]
+    fn proof_size_limit_without_proof_is_rejected() {
+        let result = SatisfactionSolverOptions::builder()
+            .with_max_proof_bytes(Some(1024))
+            .build();
+
+        assert_eq!(
+            result.err(),
+            Some(InvalidOptions::ProofSizeLimitWithoutProof)
+        );
+    }
+
+    #[test]
+    fn probing_without_budget_is_rejected() {
+        let result = SatisfactionSolverOptions::builder()
+            .with_root_probing(ProbingOptions {
+                enabled: true,
+                max_probes: 0,
+                ..Default::default()
+            })
+            .build();
+
+        assert_eq!(result.err(), Some(InvalidOptions::ProbingWithoutBudget));
+    }
+
+    #[test]
+    fn zero_propagation_loop_threshold_is_rejected() {
+        let result = SatisfactionSolverOptions::builder()
+            .with_propagation_loop_threshold(Some(0))
+            .build();
+
+        assert_eq!(
+            result.err(),
+            Some(InvalidOptions::ZeroPropagationLoopThreshold)
+        );
+    }
+
+    #[test]
+    fn valid_options_are_available_from_the_solver() {
+        let options = SatisfactionSolverOptions::builder()
+            .with_random_seed(7)
+            .with_conflict_resolver(ConflictResolutionStrategy::UniqueImplicationPoint)
+            .with_minimisation_strategy(NogoodMinimisationStrategy::RecursiveSemantic)
+            .with_restart_interval(Some(100))
+            .with_verbose_optimisation(true)
+            .build()
+            .expect("the options are valid");
+
+        let solver = Solver::with_options(options);
+        let options = solver.options();
+
+        assert_eq!(options.random_seed, 7);
+        assert_eq!(
+            options.conflict_resolver,
+            ConflictResolutionStrategy::UniqueImplicationPoint
+        );
+        assert_eq!(
+            options.minimisation_strategy,
+            NogoodMinimisationStrategy::RecursiveSemantic
+        );
+        assert_eq!(options.restart_interval, Some(100));
+        assert!(options.verbose_optimisation);
+    }
+}
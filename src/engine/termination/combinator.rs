@@ -1,24 +1,166 @@
+use super::SolverSizeSnapshot;
 use super::TerminationCondition;
+use super::TerminationReason;
 
 /// A [`TerminationCondition`] which triggers when one of two given [`TerminationCondition`]s
 /// triggers.
+///
+/// The second condition is only polled if the first one did not trigger.
 #[derive(Clone, Copy, Debug)]
-pub struct Combinator<T1, T2> {
+pub struct AnyOf<T1, T2> {
     t1: T1,
     t2: T2,
 }
 
-impl<T1, T2> Combinator<T1, T2> {
+/// The previous name of [`AnyOf`].
+pub type Combinator<T1, T2> = AnyOf<T1, T2>;
+
+impl<T1, T2> AnyOf<T1, T2> {
     /// Combine two [`TerminationCondition`]s into one.
     pub fn new(t1: T1, t2: T2) -> Self {
-        Combinator { t1, t2 }
+        AnyOf { t1, t2 }
     }
 }
 
-impl<T1: TerminationCondition, T2: TerminationCondition> TerminationCondition
-    for Combinator<T1, T2>
-{
+impl<T1: TerminationCondition, T2: TerminationCondition> TerminationCondition for AnyOf<T1, T2> {
     fn should_stop(&mut self) -> bool {
         self.t1.should_stop() || self.t2.should_stop()
     }
+
+    fn describe_trigger(&self) -> Option<String> {
+        self.t1
+            .describe_trigger()
+            .or_else(|| self.t2.describe_trigger())
+    }
+
+    fn reason(&self) -> Option<TerminationReason> {
+        self.t1.reason().or_else(|| self.t2.reason())
+    }
+
+    fn observe(&mut self, snapshot: &SolverSizeSnapshot) {
+        self.t1.observe(snapshot);
+        self.t2.observe(snapshot);
+    }
+}
+
+/// A [`TerminationCondition`] which triggers only when both of the given
+/// [`TerminationCondition`]s trigger; e.g. to stop only once a minimum amount of time has elapsed
+/// _and_ another budget has been exhausted.
+///
+/// Both conditions are polled every time, regardless of whether the first one triggered.
+#[derive(Clone, Copy, Debug)]
+pub struct AllOf<T1, T2> {
+    t1: T1,
+    t2: T2,
+}
+
+impl<T1, T2> AllOf<T1, T2> {
+    /// Combine two [`TerminationCondition`]s into one.
+    pub fn new(t1: T1, t2: T2) -> Self {
+        AllOf { t1, t2 }
+    }
+}
+
+impl<T1: TerminationCondition, T2: TerminationCondition> TerminationCondition for AllOf<T1, T2> {
+    fn should_stop(&mut self) -> bool {
+        let t1_stops = self.t1.should_stop();
+        let t2_stops = self.t2.should_stop();
+
+        t1_stops && t2_stops
+    }
+
+    fn describe_trigger(&self) -> Option<String> {
+        let t1_description = self.t1.describe_trigger()?;
+        let t2_description = self.t2.describe_trigger()?;
+
+        Some(format!("{t1_description} and {t2_description}"))
+    }
+
+    /// Since both conditions need to trigger, the reason of the first condition is reported.
+    fn reason(&self) -> Option<TerminationReason> {
+        let t1_reason = self.t1.reason()?;
+        let _ = self.t2.reason()?;
+
+        Some(t1_reason)
+    }
+
+    fn observe(&mut self, snapshot: &SolverSizeSnapshot) {
+        self.t1.observe(snapshot);
+        self.t2.observe(snapshot);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A [`TerminationCondition`] which triggers after it has been polled a given number of times.
+    #[derive(Debug)]
+    struct AfterPolls {
+        name: &'static str,
+        remaining_polls: u32,
+    }
+
+    impl AfterPolls {
+        fn new(name: &'static str, num_polls: u32) -> Self {
+            AfterPolls {
+                name,
+                remaining_polls: num_polls,
+            }
+        }
+    }
+
+    impl TerminationCondition for AfterPolls {
+        fn should_stop(&mut self) -> bool {
+            self.remaining_polls = self.remaining_polls.saturating_sub(1);
+            self.remaining_polls == 0
+        }
+
+        fn describe_trigger(&self) -> Option<String> {
+            (self.remaining_polls == 0).then(|| self.name.to_owned())
+        }
+    }
+
+    #[test]
+    fn any_of_stops_when_either_condition_triggers() {
+        let mut termination = AnyOf::new(AfterPolls::new("a", 3), AfterPolls::new("b", 2));
+
+        assert!(!termination.should_stop());
+        assert_eq!(termination.describe_trigger(), None);
+        assert!(termination.should_stop());
+        assert_eq!(termination.describe_trigger(), Some("b".to_owned()));
+    }
+
+    #[test]
+    fn all_of_stops_only_when_both_conditions_trigger() {
+        let mut termination = AllOf::new(AfterPolls::new("a", 1), AfterPolls::new("b", 3));
+
+        assert!(!termination.should_stop());
+        assert_eq!(termination.describe_trigger(), None);
+        assert!(!termination.should_stop());
+        assert_eq!(termination.describe_trigger(), None);
+        assert!(termination.should_stop());
+        assert_eq!(termination.describe_trigger(), Some("a and b".to_owned()));
+    }
+
+    #[test]
+    fn trigger_description_propagates_through_nesting() {
+        let mut termination = AnyOf::new(
+            AllOf::new(AfterPolls::new("a", 1), AfterPolls::new("b", 2)),
+            AnyOf::new(AfterPolls::new("c", 5), Some(AfterPolls::new("d", 4))),
+        );
+
+        assert!(!termination.should_stop());
+        assert!(termination.should_stop());
+        assert_eq!(termination.describe_trigger(), Some("a and b".to_owned()));
+
+        let mut termination = AllOf::new(
+            AnyOf::new(AfterPolls::new("a", 3), Some(AfterPolls::new("b", 1))),
+            AnyOf::new(AfterPolls::new("c", 2), AfterPolls::new("d", 5)),
+        );
+
+        assert!(!termination.should_stop());
+        assert!(termination.should_stop());
+        assert_eq!(termination.describe_trigger(), Some("b and c".to_owned()));
+    }
 }
@@ -0,0 +1,71 @@
+use super::SolverSizeSnapshot;
+use super::TerminationCondition;
+use super::TerminationReason;
+
+/// A [`TerminationCondition`] which triggers when the solver has encountered the specified number
+/// of conflicts.
+///
+/// The conflicts are counted by observing the solver after every conflict; conflicts at the root
+/// (which prove the problem to be unsatisfiable) are not counted.
+#[derive(Clone, Copy, Debug)]
+pub struct ConflictBudget {
+    /// The number of conflicts after which [`ConflictBudget::should_stop()`] becomes true.
+    budget: u64,
+    /// The number of conflicts which have been observed so far.
+    num_conflicts: u64,
+}
+
+impl ConflictBudget {
+    /// Give the solver a budget of `budget` conflicts.
+    pub fn with_limit(budget: u64) -> ConflictBudget {
+        ConflictBudget {
+            budget,
+            num_conflicts: 0,
+        }
+    }
+}
+
+impl TerminationCondition for ConflictBudget {
+    fn should_stop(&mut self) -> bool {
+        self.num_conflicts >= self.budget
+    }
+
+    fn describe_trigger(&self) -> Option<String> {
+        (self.num_conflicts >= self.budget)
+            .then(|| format!("conflict budget ({} conflicts)", self.budget))
+    }
+
+    fn reason(&self) -> Option<TerminationReason> {
+        (self.num_conflicts >= self.budget).then_some(TerminationReason::ConflictBudget)
+    }
+
+    fn observe(&mut self, _snapshot: &SolverSizeSnapshot) {
+        self.num_conflicts += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn triggers_once_the_budget_is_exhausted() {
+        let mut termination = ConflictBudget::with_limit(2);
+        assert!(!termination.should_stop());
+
+        termination.observe(&SolverSizeSnapshot::default());
+        assert!(!termination.should_stop());
+        assert_eq!(termination.reason(), None);
+
+        termination.observe(&SolverSizeSnapshot::default());
+        assert!(termination.should_stop());
+        assert_eq!(
+            termination.reason(),
+            Some(TerminationReason::ConflictBudget)
+        );
+        assert_eq!(
+            termination.describe_trigger(),
+            Some("conflict budget (2 conflicts)".to_owned())
+        );
+    }
+}
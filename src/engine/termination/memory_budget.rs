@@ -0,0 +1,78 @@
+use super::SolverSizeSnapshot;
+use super::TerminationCondition;
+use super::TerminationReason;
+
+/// A [`TerminationCondition`] which triggers when the estimated memory usage of the solver exceeds
+/// the specified budget.
+///
+/// The memory usage is not measured, but estimated based on the sizes of the major data
+/// structures of the solver (see [`SolverSizeSnapshot`]); these are observed after every conflict.
+/// The budget should thus leave some room for the memory which is not part of the estimate.
+#[derive(Clone, Copy, Debug)]
+pub struct MemoryBudget {
+    /// The number of bytes which the estimate may not exceed.
+    limit_bytes: usize,
+    /// The estimate of the most recently observed snapshot.
+    observed_bytes: usize,
+}
+
+impl MemoryBudget {
+    /// Give the solver a budget of `limit_bytes` bytes.
+    pub fn with_limit_bytes(limit_bytes: usize) -> MemoryBudget {
+        MemoryBudget {
+            limit_bytes,
+            observed_bytes: 0,
+        }
+    }
+}
+
+impl TerminationCondition for MemoryBudget {
+    fn should_stop(&mut self) -> bool {
+        self.observed_bytes > self.limit_bytes
+    }
+
+    fn describe_trigger(&self) -> Option<String> {
+        (self.observed_bytes > self.limit_bytes)
+            .then(|| format!("memory budget ({} bytes)", self.limit_bytes))
+    }
+
+    fn reason(&self) -> Option<TerminationReason> {
+        (self.observed_bytes > self.limit_bytes).then_some(TerminationReason::MemoryBudget)
+    }
+
+    fn observe(&mut self, snapshot: &SolverSizeSnapshot) {
+        self.observed_bytes = snapshot.total_bytes();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn triggers_once_an_observed_snapshot_exceeds_the_budget() {
+        let mut termination = MemoryBudget::with_limit_bytes(100);
+        assert!(!termination.should_stop());
+
+        termination.observe(&SolverSizeSnapshot {
+            clause_bytes: 40,
+            assignment_bytes: 30,
+            watch_list_bytes: 20,
+            reason_bytes: 10,
+        });
+        assert!(!termination.should_stop());
+        assert_eq!(termination.describe_trigger(), None);
+
+        termination.observe(&SolverSizeSnapshot {
+            clause_bytes: 41,
+            assignment_bytes: 30,
+            watch_list_bytes: 20,
+            reason_bytes: 10,
+        });
+        assert!(termination.should_stop());
+        assert_eq!(
+            termination.describe_trigger(),
+            Some("memory budget (100 bytes)".to_owned())
+        );
+    }
+}
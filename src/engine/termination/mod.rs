@@ -3,8 +3,13 @@
 //! made. The most common example would be [`time_budget::TimeBudget`], which gives the solver a
 //! certain time budget to complete its search.
 
+use std::ops::Deref;
+use std::ops::DerefMut;
+
 pub(crate) mod combinator;
+pub(crate) mod conflict_budget;
 pub(crate) mod indefinite;
+pub(crate) mod memory_budget;
 pub(crate) mod os_signal;
 pub(crate) mod time_budget;
 
@@ -13,6 +18,28 @@ pub(crate) mod time_budget;
 pub trait TerminationCondition {
     /// Returns `true` when the solver should stop, `false` otherwise.
     fn should_stop(&mut self) -> bool;
+
+    /// Returns a description of the budget which caused the condition to trigger (e.g. `"time
+    /// budget (5s)"`), or [`None`] if the condition has not triggered or cannot describe why it
+    /// triggered.
+    ///
+    /// This is meant to be called after [`TerminationCondition::should_stop`] returned `true`.
+    fn describe_trigger(&self) -> Option<String> {
+        None
+    }
+
+    /// Returns the [`TerminationReason`] describing the kind of budget which caused the condition
+    /// to trigger, or [`None`] if the condition has not triggered or cannot describe why it
+    /// triggered.
+    ///
+    /// This is meant to be called after [`TerminationCondition::should_stop`] returned `true`.
+    fn reason(&self) -> Option<TerminationReason> {
+        None
+    }
+
+    /// Observes the estimated size of the solver, which is provided by the solver after every
+    /// conflict; see [`SolverSizeSnapshot`].
+    fn observe(&mut self, _snapshot: &SolverSizeSnapshot) {}
 }
 
 impl<T: TerminationCondition> TerminationCondition for Option<T> {
@@ -22,4 +49,138 @@ impl<T: TerminationCondition> TerminationCondition for Option<T> {
             None => false,
         }
     }
+
+    fn describe_trigger(&self) -> Option<String> {
+        self.as_ref().and_then(|t| t.describe_trigger())
+    }
+
+    fn reason(&self) -> Option<TerminationReason> {
+        self.as_ref().and_then(|t| t.reason())
+    }
+
+    fn observe(&mut self, snapshot: &SolverSizeSnapshot) {
+        if let Some(t) = self {
+            t.observe(snapshot);
+        }
+    }
+}
+
+/// Allows termination conditions which are chosen at runtime (e.g. based on command line flags) to
+/// be used as a single type.
+impl TerminationCondition for Box<dyn TerminationCondition> {
+    fn should_stop(&mut self) -> bool {
+        self.deref_mut().should_stop()
+    }
+
+    fn describe_trigger(&self) -> Option<String> {
+        self.deref().describe_trigger()
+    }
+
+    fn reason(&self) -> Option<TerminationReason> {
+        self.deref().reason()
+    }
+
+    fn observe(&mut self, snapshot: &SolverSizeSnapshot) {
+        self.deref_mut().observe(snapshot);
+    }
+}
+
+/// The kind of budget which caused a [`TerminationCondition`] to trigger; this is reported by the
+/// `Unknown` variants of the results of the solver.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TerminationReason {
+    /// The time budget was exceeded; see [`time_budget::TimeBudget`].
+    TimeBudget,
+    /// The conflict budget was exhausted; see [`conflict_budget::ConflictBudget`].
+    ConflictBudget,
+    /// The estimated memory usage exceeded the budget; see [`memory_budget::MemoryBudget`].
+    MemoryBudget,
+    /// The solver was interrupted by a signal; see [`os_signal::OsSignal`].
+    Signal,
+    /// The condition which triggered did not report a reason (e.g. a user-defined condition), or
+    /// the search was stopped by a limit of the search procedure itself (e.g. the maximum number
+    /// of discrepancies of limited discrepancy search).
+    #[default]
+    Unspecified,
+}
+
+/// An estimate of the memory used by the major data structures of the solver, in bytes.
+///
+/// The estimate is based on the capacities of the underlying storage rather than on the memory
+/// which is actually reserved by the process; memory owned by individual entries (e.g. the
+/// removed values of an integer domain or the state of a propagator) is not included.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SolverSizeSnapshot {
+    pub(crate) clause_bytes: usize,
+    pub(crate) assignment_bytes: usize,
+    pub(crate) watch_list_bytes: usize,
+    pub(crate) reason_bytes: usize,
+}
+
+impl SolverSizeSnapshot {
+    /// The bytes used by the allocated clauses and their literals.
+    pub fn clause_bytes(&self) -> usize {
+        self.clause_bytes
+    }
+
+    /// The bytes used by the propositional and integer assignments, including their trails.
+    pub fn assignment_bytes(&self) -> usize {
+        self.assignment_bytes
+    }
+
+    /// The bytes used by the watch lists of the clauses and the propagators, and by the binary
+    /// clauses.
+    pub fn watch_list_bytes(&self) -> usize {
+        self.watch_list_bytes
+    }
+
+    /// The bytes used by the reasons of the propagations.
+    pub fn reason_bytes(&self) -> usize {
+        self.reason_bytes
+    }
+
+    /// The total number of bytes of the estimate.
+    pub fn total_bytes(&self) -> usize {
+        self.clause_bytes + self.assignment_bytes + self.watch_list_bytes + self.reason_bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::conflict_budget::ConflictBudget;
+    use super::indefinite::Indefinite;
+    use super::*;
+
+    #[test]
+    fn boxed_condition_forwards_to_the_boxed_condition() {
+        let mut termination: Box<dyn TerminationCondition> =
+            Box::new(ConflictBudget::with_limit(1));
+        assert!(!termination.should_stop());
+        assert_eq!(termination.reason(), None);
+
+        termination.observe(&SolverSizeSnapshot::default());
+        assert!(termination.should_stop());
+        assert_eq!(
+            termination.reason(),
+            Some(TerminationReason::ConflictBudget)
+        );
+        assert_eq!(
+            termination.describe_trigger(),
+            Some("conflict budget (1 conflicts)".to_owned())
+        );
+    }
+
+    #[test]
+    fn boxed_conditions_of_different_types_can_be_stored_together() {
+        let mut terminations: Vec<Box<dyn TerminationCondition>> = vec![
+            Box::new(Indefinite),
+            Box::new(ConflictBudget::with_limit(0)),
+        ];
+
+        let should_stop = terminations
+            .iter_mut()
+            .map(|termination| termination.should_stop())
+            .collect::<Vec<_>>();
+        assert_eq!(should_stop, vec![false, true]);
+    }
 }
@@ -3,6 +3,7 @@ use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 use super::TerminationCondition;
+use super::TerminationReason;
 
 /// A [`TerminationCondition`] which triggers due to a SIGINT signal.
 #[derive(Clone, Debug)]
@@ -31,4 +32,16 @@ impl TerminationCondition for OsSignal {
     fn should_stop(&mut self) -> bool {
         self.signal_received.load(Ordering::Relaxed)
     }
+
+    fn describe_trigger(&self) -> Option<String> {
+        self.signal_received
+            .load(Ordering::Relaxed)
+            .then(|| "interrupted by SIGINT".to_owned())
+    }
+
+    fn reason(&self) -> Option<TerminationReason> {
+        self.signal_received
+            .load(Ordering::Relaxed)
+            .then_some(TerminationReason::Signal)
+    }
 }
@@ -2,6 +2,7 @@ use std::time::Duration;
 use std::time::Instant;
 
 use super::TerminationCondition;
+use super::TerminationReason;
 
 /// A [`TerminationCondition`] which triggers when the specified time budget has been exceeded.
 #[derive(Clone, Copy, Debug)]
@@ -25,4 +26,13 @@ impl TerminationCondition for TimeBudget {
     fn should_stop(&mut self) -> bool {
         self.started_at.elapsed() >= self.budget
     }
+
+    fn describe_trigger(&self) -> Option<String> {
+        (self.started_at.elapsed() >= self.budget)
+            .then(|| format!("time budget ({:?})", self.budget))
+    }
+
+    fn reason(&self) -> Option<TerminationReason> {
+        (self.started_at.elapsed() >= self.budget).then_some(TerminationReason::TimeBudget)
+    }
 }
@@ -1,4 +1,4 @@
-#![cfg(any(test, doc))]
+#![cfg(any(test, doc, feature = "test-utilities"))]
 //! This module exposes helpers that aid testing of CP propagators. The [`TestSolver`] allows
 //! setting up specific scenarios under which to test the various operations of a propagator.
 use std::fmt::Debug;
@@ -6,14 +6,17 @@ use std::fmt::Formatter;
 
 use super::cp::VariableLiteralMappings;
 use super::cp::WatchListPropositional;
+use super::debug_helper::RemovablePredicate;
 use super::sat::ClausalPropagator;
 use super::sat::ClauseAllocator;
 use super::DebugHelper;
+use crate::asserts;
 use crate::basic_types::ConflictInfo;
 use crate::basic_types::Inconsistency;
 use crate::basic_types::KeyedVec;
 use crate::basic_types::PropagationStatusCP;
 use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::propagation::DomainChange;
 use crate::engine::cp::propagation::PropagationContext;
 use crate::engine::cp::propagation::PropagationContextMut;
 use crate::engine::cp::propagation::Propagator;
@@ -28,7 +31,6 @@ use crate::engine::sat::AssignmentsPropositional;
 use crate::engine::variables::DomainId;
 use crate::engine::variables::IntegerVariable;
 use crate::engine::variables::Literal;
-use crate::munchkin_assert_simple;
 use crate::termination::Indefinite;
 use crate::ConstraintOperationError;
 
@@ -45,7 +47,13 @@ pub(crate) struct TestSolver {
     pub(crate) clause_allocator: ClauseAllocator,
     next_id: u32,
 
-    propagators: KeyedVec<PropagatorId, Box<dyn Propagator>>,
+    pub(crate) propagators: KeyedVec<PropagatorId, Box<dyn Propagator>>,
+
+    /// Whether the explanations of the propagations are checked for predicates which are not
+    /// required; see [`TestSolver::check_explanation_minimality`].
+    is_checking_explanation_minimality: bool,
+    /// The predicates which were found to be removable from an explanation.
+    pub(crate) removable_predicates: Vec<RemovablePredicate>,
 }
 
 impl Default for TestSolver {
@@ -61,6 +69,8 @@ impl Default for TestSolver {
             clause_allocator: Default::default(),
             next_id: Default::default(),
             propagators: Default::default(),
+            is_checking_explanation_minimality: false,
+            removable_predicates: Default::default(),
         };
         let true_literal = Literal::new(
             result
@@ -138,40 +148,16 @@ impl TestSolver {
             !values.is_empty(),
             "cannot create a variable with an empty domain"
         );
-        let mut values = values.to_vec();
-
-        values.sort();
-        values.dedup();
-
-        let lower_bound = values[0];
-        let upper_bound = values[values.len() - 1];
-
-        let domain_id = self.new_variable(lower_bound, upper_bound);
 
-        let mut next_idx = 0;
-        for value in lower_bound..=upper_bound {
-            if value == values[next_idx] {
-                next_idx += 1;
-            } else {
-                self.assignments_integer
-                    .remove_initial_value_from_domain(domain_id, value, None)
-                    .expect("the domain should not be empty");
-                self.assignments_propositional.enqueue_decision_literal(
-                    self.variable_literal_mappings.get_inequality_literal(
-                        domain_id,
-                        value,
-                        &self.assignments_propositional,
-                        &self.assignments_integer,
-                    ),
-                )
-            }
-        }
-        munchkin_assert_simple!(
-            next_idx == values.len(),
-            "Expected all values to have been processed"
-        );
-
-        domain_id
+        self.variable_literal_mappings.create_new_sparse_domain(
+            values.to_vec(),
+            &mut self.assignments_integer,
+            &mut self.watch_list,
+            &mut self.watch_list_propositional,
+            &mut self.clausal_propagator,
+            &mut self.assignments_propositional,
+            &mut self.clause_allocator,
+        )
     }
 
     pub(crate) fn new_literal(&mut self) -> Literal {
@@ -186,6 +172,15 @@ impl TestSolver {
         Literal::new(variable, true)
     }
 
+    pub(crate) fn new_literal_variable(&mut self, literal: Literal) -> DomainId {
+        self.variable_literal_mappings.create_new_literal_domain(
+            literal,
+            &mut self.assignments_integer,
+            &mut self.watch_list,
+            &self.assignments_propositional,
+        )
+    }
+
     pub(crate) fn new_propagator(
         &mut self,
         propagator: impl Propagator + 'static,
@@ -200,18 +195,18 @@ impl TestSolver {
                 &mut self.watch_list,
                 &mut self.watch_list_propositional,
                 id,
-                &self.assignments_integer,
-                &self.assignments_propositional,
+                &mut self.assignments_integer,
+                &mut self.reason_store,
+                &mut self.assignments_propositional,
                 true,
                 true,
             ))
-            .expect("do not detect conflicts in initialise at root");
-
-        let num_trail_entries_before = self.assignments_integer.num_trail_entries();
+            .map_err(|conjunction| Inconsistency::Other(ConflictInfo::Explanation(conjunction)))?;
 
         self.propagators.push(propagator);
 
-        self.propagate(id)?;
+        // The domain changes made during initialisation are delivered before propagating
+        self.notify_and_propagate(id)?;
 
         Ok(id)
     }
@@ -254,6 +249,13 @@ impl TestSolver {
         assert!(result.is_ok(), "The provided value to `increase_lower_bound` caused an empty domain, generally the propagator should not be notified of this change!");
     }
 
+    pub(crate) fn decrease_upper_bound(&mut self, var: DomainId, value: i32) {
+        let result = self
+            .assignments_integer
+            .tighten_upper_bound(var, value, None);
+        assert!(result.is_ok(), "The provided value to `decrease_upper_bound` caused an empty domain, generally the propagator should not be notified of this change!");
+    }
+
     pub(crate) fn set_literal(&mut self, var: Literal, val: bool) {
         self.assignments_propositional
             .enqueue_decision_literal(if val { var } else { !var });
@@ -273,13 +275,21 @@ impl TestSolver {
             .remove_value_from_domain(var, value, None)
     }
 
+    /// Enables checking whether the explanations of subsequent propagations contain predicates
+    /// which are not required for the propagation (when the assert level is
+    /// [`asserts::ASSERT_EXTREME`]); the removable predicates are logged and collected in
+    /// [`TestSolver::removable_predicates`].
+    pub(crate) fn check_explanation_minimality(&mut self) {
+        self.is_checking_explanation_minimality = true;
+    }
+
     pub(crate) fn propagate(&mut self, propagator: PropagatorId) -> PropagationStatusCP {
         let num_trail_entries_before = self.assignments_integer.num_trail_entries();
         let context = PropagationContextMut::new(
             &mut self.assignments_integer,
             &mut self.reason_store,
             &mut self.assignments_propositional,
-            PropagatorId(0),
+            propagator,
             true,
             true,
         );
@@ -300,9 +310,84 @@ impl TestSolver {
             ),
             "Inconsistency in explanation detected in test case"
         );
+        if self.is_checking_explanation_minimality
+            && asserts::assert_level() >= asserts::ASSERT_EXTREME
+        {
+            let removable_predicates = DebugHelper::debug_check_explanation_minimality(
+                num_trail_entries_before,
+                propagator,
+                &self.assignments_integer,
+                &self.assignments_propositional,
+                &self.variable_literal_mappings,
+                &mut self.reason_store,
+                &self.propagators,
+            );
+            self.removable_predicates.extend(removable_predicates);
+        }
+        if let Err(Inconsistency::Other(ConflictInfo::Explanation(ref reason))) = propagate {
+            DebugHelper::debug_reported_failure(
+                &self.assignments_integer,
+                &self.assignments_propositional,
+                &self.variable_literal_mappings,
+                reason,
+                self.propagators[propagator].as_ref(),
+                propagator,
+                true,
+                true,
+            );
+        }
         propagate
     }
 
+    /// Delivers the pending domain events to the propagators which are subscribed to them, and
+    /// then propagates the given propagator; this mimics how the solver notifies propagators
+    /// before propagating.
+    pub(crate) fn notify_and_propagate(&mut self, propagator: PropagatorId) -> PropagationStatusCP {
+        let events = self
+            .assignments_integer
+            .drain_domain_events()
+            .collect::<Vec<_>>();
+
+        for (event, domain) in events {
+            for propagator_var in self.watch_list.get_affected_propagators(event, domain) {
+                let context = PropagationContext::new(
+                    &self.assignments_integer,
+                    &self.assignments_propositional,
+                    true,
+                    true,
+                );
+                let (old_bound, new_bound) =
+                    self.assignments_integer.get_bound_change(event, domain);
+                let _ = self.propagators[propagator_var.propagator].notify_change(
+                    context,
+                    DomainChange {
+                        local_id: propagator_var.variable,
+                        event,
+                        old_bound,
+                        new_bound,
+                    },
+                );
+            }
+        }
+
+        self.propagate(propagator)
+    }
+
+    /// Propagates all registered propagators until none of them changes a domain anymore.
+    pub(crate) fn propagate_until_fixed_point(&mut self) -> PropagationStatusCP {
+        loop {
+            let num_trail_entries_before = self.assignments_integer.num_trail_entries();
+
+            for propagator in 0..self.propagators.len() {
+                self.notify_and_propagate(PropagatorId(propagator as u32))?;
+            }
+
+            if self.assignments_integer.num_trail_entries() == num_trail_entries_before {
+                return Ok(());
+            }
+        }
+    }
+
     pub(crate) fn get_reason_int(
         &mut self,
         predicate: IntegerPredicate,
@@ -348,3 +433,230 @@ impl TestSolver {
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::TestSolver;
+    use crate::basic_types::PropagationStatusCP;
+    use crate::basic_types::PropositionalConjunction;
+    use crate::conjunction;
+    use crate::engine::cp::domain_events::DomainEvents;
+    use crate::engine::cp::propagation::EnqueueDecision;
+    use crate::engine::cp::propagation::LocalId;
+    use crate::engine::cp::propagation::PropagationContext;
+    use crate::engine::cp::propagation::PropagationContextMut;
+    use crate::engine::cp::propagation::Propagator;
+    use crate::engine::cp::propagation::PropagatorInitialisationContext;
+    use crate::engine::cp::propagation::ReadDomains;
+    use crate::engine::cp::IntDomainEvent;
+    use crate::engine::variables::DomainId;
+    use crate::engine::variables::TransformableVariable;
+    use crate::predicate;
+    use crate::propagators::arithmetic::linear_less_or_equal::LinearLessOrEqualPropagator;
+
+    #[test]
+    fn reasons_are_attributed_to_the_propagating_propagator() {
+        let mut solver = TestSolver::default();
+        let a = solver.new_variable(3, 10);
+        let b = solver.new_variable(0, 10);
+        let c = solver.new_variable(1, 10);
+        let d = solver.new_variable(0, 10);
+
+        let first = solver
+            .new_propagator(LinearLessOrEqualPropagator::new([a, b].into(), 5))
+            .expect("no conflict");
+        let second = solver
+            .new_propagator(LinearLessOrEqualPropagator::new([c, d].into(), 4))
+            .expect("no conflict");
+
+        for (predicate, propagator) in [(predicate![b <= 2], first), (predicate![d <= 3], second)] {
+            let predicate = predicate.try_into().unwrap();
+            let reason_ref = solver
+                .assignments_integer
+                .get_reason_for_predicate(predicate);
+            assert_eq!(solver.reason_store.get_propagator(reason_ref), propagator);
+        }
+
+        assert_eq!(
+            solver.get_reason_int(predicate![b <= 2].try_into().unwrap()),
+            &PropositionalConjunction::from(predicate![a >= 3])
+        );
+        assert_eq!(
+            solver.get_reason_int(predicate![d <= 3].try_into().unwrap()),
+            &PropositionalConjunction::from(predicate![c >= 1])
+        );
+    }
+
+    /// Propagates `x <= y` only after it has been notified of a lower-bound change of `x`.
+    struct NotifiedLessOrEqual {
+        x: DomainId,
+        y: DomainId,
+        notified: bool,
+    }
+
+    impl Propagator for NotifiedLessOrEqual {
+        fn name(&self) -> &str {
+            "NotifiedLessOrEqual"
+        }
+
+        fn propagate(&self, mut context: PropagationContextMut) -> PropagationStatusCP {
+            if self.notified {
+                let lower_bound = context.lower_bound(&self.x);
+                let x = self.x;
+                context.set_lower_bound(&self.y, lower_bound, conjunction!([x >= lower_bound]))?;
+            }
+
+            Ok(())
+        }
+
+        fn initialise_at_root(
+            &mut self,
+            context: &mut PropagatorInitialisationContext,
+        ) -> Result<(), PropositionalConjunction> {
+            context.register(self.x, DomainEvents::LOWER_BOUND);
+            Ok(())
+        }
+
+        fn notify(
+            &mut self,
+            _context: PropagationContext,
+            _local_id: LocalId,
+            _event: IntDomainEvent,
+        ) -> EnqueueDecision {
+            self.notified = true;
+            EnqueueDecision::Enqueue
+        }
+    }
+
+    #[test]
+    fn notification_is_delivered_before_propagating() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(0, 10);
+        let y = solver.new_variable(0, 10);
+
+        let propagator = solver
+            .new_propagator(NotifiedLessOrEqual {
+                x,
+                y,
+                notified: false,
+            })
+            .expect("no conflict");
+
+        solver.increase_lower_bound(x, 4);
+
+        solver.propagate(propagator).expect("no conflict");
+        solver.assert_bounds(y, 0, 10);
+
+        solver
+            .notify_and_propagate(propagator)
+            .expect("no conflict");
+        solver.assert_bounds(y, 4, 10);
+    }
+
+    #[test]
+    fn all_propagators_are_propagated_until_fixed_point() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(0, 10);
+        let y = solver.new_variable(0, 10);
+        let z = solver.new_variable(0, 10);
+
+        // registered in reverse order, so a single pass over the propagators does not suffice
+        let _ = solver
+            .new_propagator(LinearLessOrEqualPropagator::new(
+                [y.scaled(1), z.scaled(-1)].into(),
+                0,
+            ))
+            .expect("no conflict");
+        let _ = solver
+            .new_propagator(LinearLessOrEqualPropagator::new(
+                [x.scaled(1), y.scaled(-1)].into(),
+                0,
+            ))
+            .expect("no conflict");
+
+        solver.increase_lower_bound(x, 3);
+        solver.propagate_until_fixed_point().expect("no conflict");
+
+        solver.assert_bounds(y, 3, 10);
+        solver.assert_bounds(z, 3, 10);
+    }
+
+    /// Propagates `x <= y`, but includes the irrelevant lower bound of `z` in its explanations.
+    struct OverWideLessOrEqual {
+        x: DomainId,
+        y: DomainId,
+        z: DomainId,
+    }
+
+    impl Propagator for OverWideLessOrEqual {
+        fn name(&self) -> &str {
+            "OverWideLessOrEqual"
+        }
+
+        fn propagate(&self, mut context: PropagationContextMut) -> PropagationStatusCP {
+            let x = self.x;
+            let z = self.z;
+            let x_lower_bound = context.lower_bound(&x);
+            let z_lower_bound = context.lower_bound(&z);
+            context.set_lower_bound(
+                &self.y,
+                x_lower_bound,
+                conjunction!([x >= x_lower_bound] & [z >= z_lower_bound]),
+            )?;
+
+            Ok(())
+        }
+
+        fn initialise_at_root(
+            &mut self,
+            _: &mut PropagatorInitialisationContext,
+        ) -> Result<(), PropositionalConjunction> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn removable_predicates_in_explanations_are_detected() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(0, 10);
+        let y = solver.new_variable(0, 10);
+        let z = solver.new_variable(0, 10);
+
+        solver.increase_lower_bound(x, 4);
+        solver.increase_lower_bound(z, 3);
+
+        solver.check_explanation_minimality();
+        let _ = solver
+            .new_propagator(OverWideLessOrEqual { x, y, z })
+            .expect("no conflict");
+        solver.assert_bounds(y, 4, 10);
+
+        assert_eq!(solver.removable_predicates.len(), 1);
+        let removable_predicate = solver.removable_predicates[0];
+        assert_eq!(
+            removable_predicate.propagated_predicate,
+            predicate![y >= 4].try_into().unwrap()
+        );
+        assert_eq!(removable_predicate.removable_predicate, predicate![z >= 3]);
+    }
+
+    #[test]
+    fn minimal_explanations_are_not_reported() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(0, 10);
+        let y = solver.new_variable(0, 10);
+
+        solver.increase_lower_bound(x, 4);
+
+        solver.check_explanation_minimality();
+        let _ = solver
+            .new_propagator(LinearLessOrEqualPropagator::new(
+                [x.scaled(1), y.scaled(-1)].into(),
+                0,
+            ))
+            .expect("no conflict");
+        solver.assert_bounds(y, 4, 10);
+
+        assert!(solver.removable_predicates.is_empty());
+    }
+}
@@ -33,17 +33,31 @@ impl<Inner> AffineView<Inner> {
 
     /// Apply the inverse transformation of this view on a value, to go from the value in the domain
     /// of `self` to a value in the domain of `self.inner`.
+    ///
+    /// The computation is performed in 64-bit arithmetic; if the inverted value does not fit in an
+    /// `i32`, it saturates at the bounds of the `i32` range.
     fn invert(&self, value: i32, rounding: Rounding) -> i32 {
-        let inverted_translation = value - self.offset;
+        let inverted_translation = value as i64 - self.offset as i64;
+        let scale = self.scale as i64;
 
-        match rounding {
-            Rounding::Up => <i32 as NumExt>::div_ceil(inverted_translation, self.scale),
-            Rounding::Down => <i32 as NumExt>::div_floor(inverted_translation, self.scale),
-        }
+        clamp_to_i32(match rounding {
+            Rounding::Up => <i64 as NumExt>::div_ceil(inverted_translation, scale),
+            Rounding::Down => <i64 as NumExt>::div_floor(inverted_translation, scale),
+        })
     }
 
+    /// Apply the transformation of this view on a value of `self.inner`.
+    ///
+    /// The computation is performed in 64-bit arithmetic; if the mapped value does not fit in an
+    /// `i32`, it saturates at the bounds of the `i32` range.
     fn map(&self, value: i32) -> i32 {
-        self.scale * value + self.offset
+        clamp_to_i32(self.scale as i64 * value as i64 + self.offset as i64)
+    }
+
+    /// Whether `value` is in the image of this view, i.e. whether there is an integer `x` such
+    /// that `scale * x + offset = value`.
+    fn is_in_image(&self, value: i32) -> bool {
+        (value as i64 - self.offset as i64) % self.scale as i64 == 0
     }
 }
 
@@ -69,8 +83,24 @@ where
         }
     }
 
+    fn lower_bound_at_root(&self, assignment: &AssignmentsInteger) -> i32 {
+        if self.scale < 0 {
+            self.map(self.inner.upper_bound_at_root(assignment))
+        } else {
+            self.map(self.inner.lower_bound_at_root(assignment))
+        }
+    }
+
+    fn upper_bound_at_root(&self, assignment: &AssignmentsInteger) -> i32 {
+        if self.scale < 0 {
+            self.map(self.inner.lower_bound_at_root(assignment))
+        } else {
+            self.map(self.inner.upper_bound_at_root(assignment))
+        }
+    }
+
     fn contains(&self, assignment: &AssignmentsInteger, value: i32) -> bool {
-        if (value - self.offset) % self.scale == 0 {
+        if self.is_in_image(value) {
             let inverted = self.invert(value, Rounding::Up);
             self.inner.contains(assignment, inverted)
         } else {
@@ -90,7 +120,7 @@ where
         value: i32,
         reason: Option<ReasonRef>,
     ) -> Result<(), EmptyDomain> {
-        if (value - self.offset) % self.scale == 0 {
+        if self.is_in_image(value) {
             let inverted = self.invert(value, Rounding::Up);
             self.inner.remove(assignment, inverted, reason)
         } else {
@@ -136,6 +166,19 @@ where
         }
         self.inner.watch_all(watchers, events);
     }
+
+    fn watch_all_backtrack(
+        &self,
+        watchers: &mut Watchers<'_>,
+        mut events: EnumSet<IntDomainEvent>,
+    ) {
+        let bound = IntDomainEvent::LowerBound | IntDomainEvent::UpperBound;
+        let intersection = events.intersection(bound);
+        if intersection.len() == 1 && self.scale.is_negative() {
+            events = events.symmetrical_difference(bound);
+        }
+        self.inner.watch_all_backtrack(watchers, events);
+    }
 }
 
 impl<View> TransformableVariable<AffineView<View>> for AffineView<View>
@@ -144,14 +187,23 @@ where
 {
     fn scaled(&self, scale: i32) -> AffineView<View> {
         let mut result = self.clone();
-        result.scale *= scale;
-        result.offset *= scale;
+        result.scale = result
+            .scale
+            .checked_mul(scale)
+            .expect("the scale of the view does not fit in an i32");
+        result.offset = result
+            .offset
+            .checked_mul(scale)
+            .expect("the offset of the view does not fit in an i32");
         result
     }
 
     fn offset(&self, offset: i32) -> AffineView<View> {
         let mut result = self.clone();
-        result.offset += offset;
+        result.offset = result
+            .offset
+            .checked_add(offset)
+            .expect("the offset of the view does not fit in an i32");
         result
     }
 }
@@ -200,7 +252,7 @@ impl<Var: PredicateConstructor<Value = i32>> PredicateConstructor for AffineView
     }
 
     fn equality_predicate(&self, bound: Self::Value) -> Predicate {
-        if (bound - self.offset) % self.scale == 0 {
+        if self.is_in_image(bound) {
             let inverted_bound = self.invert(bound, Rounding::Up);
             self.inner.equality_predicate(inverted_bound)
         } else {
@@ -209,7 +261,7 @@ impl<Var: PredicateConstructor<Value = i32>> PredicateConstructor for AffineView
     }
 
     fn disequality_predicate(&self, bound: Self::Value) -> Predicate {
-        if (bound - self.offset) % self.scale == 0 {
+        if self.is_in_image(bound) {
             let inverted_bound = self.invert(bound, Rounding::Up);
             self.inner.disequality_predicate(inverted_bound)
         } else {
@@ -239,7 +291,7 @@ pub(crate) trait NumExt {
     fn div_floor(self, other: Self) -> Self;
 }
 
-impl NumExt for i32 {
+impl NumExt for i64 {
     fn div_ceil(self, other: Self) -> Self {
         // TODO: The source is taken from the standard library nightly implementation of this
         // function and div_floor. Once they are stabilized, these definitions can be removed.
@@ -265,6 +317,12 @@ impl NumExt for i32 {
     }
 }
 
+/// Converts a value which was computed in 64-bit arithmetic back to an `i32`, saturating at the
+/// bounds of the `i32` range.
+fn clamp_to_i32(value: i64) -> i32 {
+    value.clamp(i32::MIN as i64, i32::MAX as i64) as i32
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -301,6 +359,44 @@ mod tests {
         assert_eq!(predicate!(domain <= -3), predicate!(view <= -5));
     }
 
+    #[test]
+    fn bounds_outside_of_i32_range_saturate() {
+        let mut assignment = AssignmentsInteger::default();
+        let domain = assignment.grow(999_999_990, 1_000_000_000);
+
+        let view = AffineView::new(domain, 3, 0);
+        assert_eq!(view.lower_bound(&assignment), i32::MAX);
+        assert_eq!(view.upper_bound(&assignment), i32::MAX);
+
+        let view = AffineView::new(domain, -3, 0);
+        assert_eq!(view.lower_bound(&assignment), i32::MIN);
+        assert_eq!(view.upper_bound(&assignment), i32::MIN);
+    }
+
+    #[test]
+    fn inverting_does_not_overflow_for_large_offsets() {
+        let domain = DomainId::new(0);
+        let view = AffineView::new(domain, 3, -2_000_000_000);
+
+        // (i32::MAX + 2_000_000_000) / 3 = 1_382_494_549
+        assert_eq!(
+            predicate!(view == i32::MAX),
+            predicate!(domain == 1_382_494_549)
+        );
+        assert_eq!(
+            predicate!(view >= i32::MAX),
+            predicate!(domain >= 1_382_494_549)
+        );
+        assert_eq!(predicate!(view != i32::MAX - 1), Predicate::True);
+
+        // The inverted bound (i32::MIN + 2) - 3 lies below the i32 range and saturates
+        let view = AffineView::new(domain, 1, 3);
+        assert_eq!(
+            predicate!(view <= i32::MIN + 2),
+            predicate!(domain <= i32::MIN)
+        );
+    }
+
     #[test]
     fn test_negated_variable_has_bounds_rounded_correctly() {
         let domain = DomainId::new(0);
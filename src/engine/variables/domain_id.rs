@@ -35,6 +35,14 @@ impl IntegerVariable for DomainId {
         assignment.get_upper_bound(*self)
     }
 
+    fn lower_bound_at_root(&self, assignment: &AssignmentsInteger) -> i32 {
+        assignment.get_root_bounds(*self).0
+    }
+
+    fn upper_bound_at_root(&self, assignment: &AssignmentsInteger) -> i32 {
+        assignment.get_root_bounds(*self).1
+    }
+
     fn contains(&self, assignment: &AssignmentsInteger, value: i32) -> bool {
         assignment.is_value_in_domain(*self, value)
     }
@@ -73,6 +81,10 @@ impl IntegerVariable for DomainId {
     fn watch_all(&self, watchers: &mut Watchers<'_>, events: EnumSet<IntDomainEvent>) {
         watchers.watch_all(*self, events);
     }
+
+    fn watch_all_backtrack(&self, watchers: &mut Watchers<'_>, events: EnumSet<IntDomainEvent>) {
+        watchers.watch_all_backtrack(*self, events);
+    }
 }
 
 impl TransformableVariable<AffineView<DomainId>> for DomainId {
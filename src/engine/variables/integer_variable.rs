@@ -23,6 +23,14 @@ pub trait IntegerVariable:
     /// Get the upper bound of the variable.
     fn upper_bound(&self, assignment: &AssignmentsInteger) -> i32;
 
+    /// Get the lower bound of the variable at the root, i.e. before the changes which were made
+    /// after the first decision.
+    fn lower_bound_at_root(&self, assignment: &AssignmentsInteger) -> i32;
+
+    /// Get the upper bound of the variable at the root, i.e. before the changes which were made
+    /// after the first decision.
+    fn upper_bound_at_root(&self, assignment: &AssignmentsInteger) -> i32;
+
     /// Determine whether the value is in the domain of this variable.
     fn contains(&self, assignment: &AssignmentsInteger, value: i32) -> bool;
 
@@ -65,4 +73,8 @@ pub trait IntegerVariable:
 
     /// Register a watch for this variable on the given domain events.
     fn watch_all(&self, watchers: &mut Watchers<'_>, events: EnumSet<IntDomainEvent>);
+
+    /// Register a watch for this variable on the given domain events being undone when the solver
+    /// backtracks.
+    fn watch_all_backtrack(&self, watchers: &mut Watchers<'_>, events: EnumSet<IntDomainEvent>);
 }
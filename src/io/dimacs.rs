@@ -0,0 +1,311 @@
+//! Reading of propositional formulas in the [DIMACS CNF](https://jix.github.io/varisat/manual/0.2.0/formats/dimacs.html)
+//! format.
+//!
+//! A DIMACS file consists of a header of the form `p cnf <num_variables> <num_clauses>`,
+//! followed by the clauses. Every clause is a list of non-zero integers terminated by a `0`, where
+//! a positive integer `i` denotes the `i`-th variable and a negative integer `-i` denotes its
+//! negation. Lines starting with `c` are comments.
+//!
+//! ```rust
+//! # use munchkin::io::dimacs::load_cnf;
+//! # use munchkin::Solver;
+//! let cnf = "c a small example
+//! p cnf 2 2
+//! 1 2 0
+//! -1 0
+//! ";
+//!
+//! let mut solver = Solver::default();
+//! let literals = load_cnf(cnf.as_bytes(), &mut solver).expect("valid cnf");
+//!
+//! // The literal of variable `1` is at index 0.
+//! assert_eq!(literals.len(), 2);
+//! assert_eq!(solver.get_literal_value(literals[0]), Some(false));
+//! assert_eq!(solver.get_literal_value(literals[1]), Some(true));
+//! ```
+use std::io::BufRead;
+
+use thiserror::Error;
+
+use crate::variables::Literal;
+use crate::Solver;
+
+/// Errors which can occur when reading a DIMACS CNF file using [`load_cnf`].
+#[derive(Debug, Error)]
+pub enum DimacsError {
+    #[error("Failed to read: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// A header is present but malformed, or there is more than one header.
+    #[error("Line {line}: expected a header of the form 'p cnf <variables> <clauses>'")]
+    InvalidHeader { line: usize },
+
+    /// There is no header before the first clause, or the input has no header at all; in the
+    /// latter case, `line` is the last line of the input.
+    #[error("Line {line}: missing the 'p cnf' header")]
+    MissingHeader { line: usize },
+
+    #[error("Line {line}: '{token}' is not a valid literal")]
+    InvalidLiteral { line: usize, token: String },
+
+    #[error("Line {line}: variable {variable} exceeds the declared number of variables ({num_variables})")]
+    VariableOutOfRange {
+        line: usize,
+        variable: u64,
+        num_variables: u32,
+    },
+
+    #[error("Line {line}: the last clause is not terminated by a 0")]
+    MissingTerminator { line: usize },
+}
+
+/// Reads a DIMACS CNF formula from `reader` and adds it to the `solver`.
+///
+/// A fresh propositional variable is created for every variable declared in the header. The
+/// returned vector maps the DIMACS variables to the positive literals of the created variables,
+/// i.e. the literal of variable `i` is at index `i - 1`.
+///
+/// All clauses are added through [`Solver::add_clauses`]. Note that if the formula is
+/// unsatisfiable at the root, this is not reported as an error; instead, the solver will report
+/// unsatisfiability when solving.
+pub fn load_cnf(reader: impl BufRead, solver: &mut Solver) -> Result<Vec<Literal>, DimacsError> {
+    let mut literals: Option<Vec<Literal>> = None;
+    let mut clauses = Vec::new();
+    let mut current_clause = Vec::new();
+    let mut last_line = 0;
+
+    for (index, line) in reader.lines().enumerate() {
+        let line_number = index + 1;
+        last_line = line_number;
+
+        let line = line?;
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('c') {
+            continue;
+        }
+
+        if line.starts_with('p') {
+            if literals.is_some() {
+                return Err(DimacsError::InvalidHeader { line: line_number });
+            }
+
+            let num_variables =
+                parse_header(line).ok_or(DimacsError::InvalidHeader { line: line_number })?;
+            literals = Some(solver.new_literals().take(num_variables as usize).collect());
+            continue;
+        }
+
+        let Some(variable_literals) = literals.as_ref() else {
+            return Err(DimacsError::MissingHeader { line: line_number });
+        };
+
+        for token in line.split_whitespace() {
+            let code = token
+                .parse::<i64>()
+                .map_err(|_| DimacsError::InvalidLiteral {
+                    line: line_number,
+                    token: token.to_owned(),
+                })?;
+
+            if code == 0 {
+                clauses.push(std::mem::take(&mut current_clause));
+                continue;
+            }
+
+            let variable = code.unsigned_abs();
+            if variable > variable_literals.len() as u64 {
+                return Err(DimacsError::VariableOutOfRange {
+                    line: line_number,
+                    variable,
+                    num_variables: variable_literals.len() as u32,
+                });
+            }
+
+            let literal = variable_literals[variable as usize - 1];
+            current_clause.push(if code > 0 { literal } else { !literal });
+        }
+    }
+
+    if !current_clause.is_empty() {
+        return Err(DimacsError::MissingTerminator { line: last_line });
+    }
+
+    let Some(literals) = literals else {
+        return Err(DimacsError::MissingHeader { line: last_line });
+    };
+
+    // An unsatisfiable formula is not a malformed input; the solver keeps track of the
+    // infeasibility and reports it when solving.
+    let _ = solver.add_clauses(clauses);
+
+    Ok(literals)
+}
+
+/// Parses the header line `p cnf <num_variables> <num_clauses>` and returns the number of
+/// variables.
+fn parse_header(line: &str) -> Option<u32> {
+    let mut tokens = line.split_whitespace();
+
+    if tokens.next() != Some("p") || tokens.next() != Some("cnf") {
+        return None;
+    }
+
+    let num_variables = tokens.next()?.parse::<u32>().ok()?;
+    let _num_clauses = tokens.next()?.parse::<u32>().ok()?;
+
+    if tokens.next().is_some() {
+        return None;
+    }
+
+    Some(num_variables)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::load_cnf;
+    use super::DimacsError;
+    use crate::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
+    use crate::branching::InDomainMin;
+    use crate::branching::InputOrder;
+    use crate::results::ProblemSolution;
+    use crate::results::SatisfactionResult;
+    use crate::termination::Indefinite;
+    use crate::variables::Literal;
+    use crate::Solver;
+
+    fn solve(solver: &mut Solver, literals: &[Literal]) -> SatisfactionResult {
+        let mut brancher = IndependentVariableValueBrancher::new(
+            InputOrder::new(
+                literals
+                    .iter()
+                    .map(|literal| literal.get_propositional_variable())
+                    .collect(),
+            ),
+            InDomainMin,
+        );
+        solver.satisfy(&mut brancher, &mut Indefinite)
+    }
+
+    #[test]
+    fn satisfiable_cnf_is_loaded_and_solved() {
+        let cnf = "c satisfiable
+p cnf 3 4
+1 2 0
+-1 3 0
+-2 -3
+0
+-3 1 0
+";
+        let mut solver = Solver::default();
+        let literals = load_cnf(cnf.as_bytes(), &mut solver).expect("valid cnf");
+        assert_eq!(literals.len(), 3);
+
+        match solve(&mut solver, &literals) {
+            SatisfactionResult::Satisfiable(solution) => {
+                let value = |code: i32| {
+                    let literal = literals[code.unsigned_abs() as usize - 1];
+                    solution.get_literal_value(if code > 0 { literal } else { !literal })
+                };
+                assert!(value(1) || value(2));
+                assert!(value(-1) || value(3));
+                assert!(value(-2) || value(-3));
+                assert!(value(-3) || value(1));
+            }
+            _ => panic!("the formula should be satisfiable"),
+        }
+    }
+
+    #[test]
+    fn unsatisfiable_cnf_is_loaded_and_solved() {
+        let cnf = "p cnf 2 4
+1 2 0
+1 -2 0
+-1 2 0
+-1 -2 0
+";
+        let mut solver = Solver::default();
+        let literals = load_cnf(cnf.as_bytes(), &mut solver).expect("valid cnf");
+
+        assert!(matches!(
+            solve(&mut solver, &literals),
+            SatisfactionResult::Unsatisfiable
+        ));
+    }
+
+    #[test]
+    fn variable_beyond_declared_count_is_reported() {
+        let cnf = "p cnf 2 1\n1 3 0\n";
+        let mut solver = Solver::default();
+
+        let result = load_cnf(cnf.as_bytes(), &mut solver);
+        assert!(matches!(
+            result,
+            Err(DimacsError::VariableOutOfRange {
+                line: 2,
+                variable: 3,
+                num_variables: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn missing_terminating_zero_is_reported() {
+        let cnf = "p cnf 2 2\n1 2 0\n-1 -2\n";
+        let mut solver = Solver::default();
+
+        let result = load_cnf(cnf.as_bytes(), &mut solver);
+        assert!(matches!(
+            result,
+            Err(DimacsError::MissingTerminator { line: 3 })
+        ));
+    }
+
+    #[test]
+    fn clause_before_header_is_reported() {
+        let cnf = "1 2 0\np cnf 2 1\n";
+        let mut solver = Solver::default();
+
+        let result = load_cnf(cnf.as_bytes(), &mut solver);
+        assert!(matches!(
+            result,
+            Err(DimacsError::MissingHeader { line: 1 })
+        ));
+    }
+
+    #[test]
+    fn input_without_header_is_reported() {
+        let cnf = "c only comments\n\nc and no header\n";
+        let mut solver = Solver::default();
+
+        let result = load_cnf(cnf.as_bytes(), &mut solver);
+        assert!(matches!(
+            result,
+            Err(DimacsError::MissingHeader { line: 3 })
+        ));
+    }
+
+    #[test]
+    fn malformed_header_is_reported() {
+        let cnf = "c comment\np cnf two 1\n1 2 0\n";
+        let mut solver = Solver::default();
+
+        let result = load_cnf(cnf.as_bytes(), &mut solver);
+        assert!(matches!(
+            result,
+            Err(DimacsError::InvalidHeader { line: 2 })
+        ));
+    }
+
+    #[test]
+    fn invalid_literal_is_reported() {
+        let cnf = "p cnf 2 1\n1 x 0\n";
+        let mut solver = Solver::default();
+
+        let result = load_cnf(cnf.as_bytes(), &mut solver);
+        assert!(matches!(
+            result,
+            Err(DimacsError::InvalidLiteral { line: 2, .. })
+        ));
+    }
+}
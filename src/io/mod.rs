@@ -0,0 +1,5 @@
+//! Contains functionality for reading problem instances from standard file formats.
+//!
+//! Currently, the following formats are supported:
+//! - [DIMACS CNF](dimacs) for purely propositional (SAT) instances.
+pub mod dimacs;
@@ -24,11 +24,7 @@
 //! Pumpkin can be used to solve a variety of problems. The first step to solving a problem is
 //! **adding variables**:
 //! ```rust
-//! # use munchkin::Solver;
-//! # use munchkin::results::OptimisationResult;
-//! # use munchkin::termination::Indefinite;
-//! # use munchkin::results::ProblemSolution;
-//! # use munchkin::constraints::Constraint;
+//! # use munchkin::prelude::*;
 //! # use std::cmp::max;
 //! // We create the solver with default options
 //! let mut solver = Solver::default();
@@ -41,12 +37,7 @@
 //!
 //! Then we can **add constraints** supported by the [`Solver`]:
 //! ```rust
-//! # use munchkin::Solver;
-//! # use munchkin::results::OptimisationResult;
-//! # use munchkin::termination::Indefinite;
-//! # use munchkin::results::ProblemSolution;
-//! # use munchkin::constraints;
-//! # use munchkin::constraints::Constraint;
+//! # use munchkin::prelude::*;
 //! # use std::cmp::max;
 //! # let mut solver = Solver::default();
 //! # let x = solver.new_bounded_integer(5, 10);
@@ -63,8 +54,7 @@
 //! determine when the solver should stop searching and the variable/value selection strategy which
 //! should be used:
 //! ```rust
-//! # use munchkin::Solver;
-//! # use munchkin::termination::Indefinite;
+//! # use munchkin::prelude::*;
 //! # let mut solver = Solver::default();
 //! // We create a termination condition which allows the solver to run indefinitely
 //! let mut termination = Indefinite;
@@ -75,12 +65,7 @@
 //!
 //! **Finding a solution** to this problem can be done by using [`Solver::satisfy`]:
 //! ```rust
-//! # use munchkin::Solver;
-//! # use munchkin::results::SatisfactionResult;
-//! # use munchkin::termination::Indefinite;
-//! # use munchkin::results::ProblemSolution;
-//! # use munchkin::constraints;
-//! # use munchkin::constraints::Constraint;
+//! # use munchkin::prelude::*;
 //! # use std::cmp::max;
 //! # let mut solver = Solver::default();
 //! # let x = solver.new_bounded_integer(5, 10);
@@ -92,24 +77,19 @@
 //! // Then we find a solution to the problem
 //! let result = solver.satisfy(&mut brancher, &mut termination);
 //!
-//! if let SatisfactionResult::Satisfiable(solution) = result {
-//!     let value_x = solution.get_integer_value(x);
-//!     let value_y = solution.get_integer_value(y);
-//!     let value_z = solution.get_integer_value(z);
+//! let solution = result.solution().expect("This problem should have a solution");
+//! let value_x = solution.get_integer_value(x);
+//! let value_y = solution.get_integer_value(y);
+//! let value_z = solution.get_integer_value(z);
 //!
-//!     // The constraint should hold for this solution
-//!     assert!(value_x + value_y + value_z == 17);
-//! } else {
-//!     panic!("This problem should have a solution")
-//! }
+//! // The constraint should hold for this solution
+//! assert!(value_x + value_y + value_z == 17);
 //! ```
 //!
 //! **Optimizing an objective** can be done in a similar way using [`Solver::maximise`] or
 //! [`Solver::minimise`]; first the objective variable and a constraint over this value are added:
 //! ```rust
-//! # use munchkin::Solver;
-//! # use munchkin::constraints;
-//! # use munchkin::constraints::Constraint;
+//! # use munchkin::prelude::*;
 //! # let mut solver = Solver::default();
 //! # let x = solver.new_bounded_integer(5, 10);
 //! # let y = solver.new_bounded_integer(-3, 15);
@@ -125,12 +105,7 @@
 //!
 //! Then we can find the optimal solution using [`Solver::minimise`] or [`Solver::maximise`]:
 //! ```rust
-//! # use munchkin::Solver;
-//! # use munchkin::results::OptimisationResult;
-//! # use munchkin::termination::Indefinite;
-//! # use munchkin::results::ProblemSolution;
-//! # use munchkin::constraints;
-//! # use munchkin::constraints::Constraint;
+//! # use munchkin::prelude::*;
 //! # use std::cmp::max;
 //! # let mut solver = Solver::default();
 //! # let x = solver.new_bounded_integer(5, 10);
@@ -144,24 +119,22 @@
 //! // Then we solve to optimality
 //! let result = solver.minimise(&mut brancher, &mut termination, objective);
 //!
-//! if let OptimisationResult::Optimal(optimal_solution) = result {
-//!     let value_x = optimal_solution.get_integer_value(x);
-//!     let value_y = optimal_solution.get_integer_value(y);
-//!     let value_z = optimal_solution.get_integer_value(z);
-//!     // The maximum objective values is 7;
-//!     // with one possible solution being: {x = 5, y = 5, z = 7, objective = 7}.
-//!
-//!     // We check whether the constraint holds again
-//!     assert!(value_x + value_y + value_z == 17);
-//!     // We check whether the newly added constraint for the objective value holds
-//!     assert!(
-//!         max(value_x, max(value_y, value_z)) == optimal_solution.get_integer_value(objective)
-//!     );
-//!     // We check whether this is actually an optimal solution
-//!     assert_eq!(optimal_solution.get_integer_value(objective), 7);
-//! } else {
-//!     panic!("This problem should have an optimal solution")
-//! }
+//! assert!(result.is_optimal());
+//! // The maximum objective values is 7;
+//! // with one possible solution being: {x = 5, y = 5, z = 7, objective = 7}.
+//! assert_eq!(result.objective_value(&objective), Some(7));
+//!
+//! let optimal_solution = result.solution().unwrap();
+//! let value_x = optimal_solution.get_integer_value(x);
+//! let value_y = optimal_solution.get_integer_value(y);
+//! let value_z = optimal_solution.get_integer_value(z);
+//!
+//! // We check whether the constraint holds again
+//! assert!(value_x + value_y + value_z == 17);
+//! // We check whether the newly added constraint for the objective value holds
+//! assert!(
+//!     max(value_x, max(value_y, value_z)) == optimal_solution.get_integer_value(objective)
+//! );
 //! ```
 //!
 //! # Obtaining multiple solutions
@@ -170,13 +143,8 @@
 //! clauses to the solver which means that after iterating over solutions, these solutions will
 //! remain blocked if the solver is used again.
 //! ```rust
-//! # use munchkin::Solver;
-//! # use munchkin::results::SatisfactionResult;
-//! # use munchkin::termination::Indefinite;
-//! # use munchkin::results::ProblemSolution;
+//! # use munchkin::prelude::*;
 //! # use munchkin::results::solution_iterator::IteratedSolution;
-//! # use munchkin::constraints;
-//! # use munchkin::constraints::Constraint;
 //! // We create the solver with default options
 //! let mut solver = Solver::default();
 //!
@@ -219,7 +187,7 @@
 //!             // No more solutions exist
 //!             break;
 //!         }
-//!         IteratedSolution::Unknown => {
+//!         IteratedSolution::Unknown(_) => {
 //!             // Our termination condition has caused the solver to terminate
 //!             break;
 //!         }
@@ -236,12 +204,8 @@
 //! Pumpkin allows the user to specify assumptions which can then be used to extract an
 //! unsatisfiable core (see [`UnsatisfiableUnderAssumptions::extract_core`]).
 //! ```rust
-//! # use munchkin::Solver;
+//! # use munchkin::prelude::*;
 //! # use munchkin::results::SatisfactionResultUnderAssumptions;
-//! # use munchkin::termination::Indefinite;
-//! # use munchkin::predicate;
-//! # use munchkin::constraints;
-//! # use munchkin::constraints::Constraint;
 //! // We create the solver with default options
 //! let mut solver = Solver::default();
 //!
@@ -287,7 +251,6 @@ use crate::results::unsatisfiable::UnsatisfiableUnderAssumptions;
 pub(crate) mod asserts;
 pub(crate) mod basic_types;
 pub(crate) mod engine;
-pub(crate) mod proof;
 pub(crate) mod propagators;
 #[cfg(doc)]
 use crate::branching::Brancher;
@@ -297,8 +260,12 @@ use crate::termination::TerminationCondition;
 pub mod branching;
 pub mod constraints;
 pub mod encodings;
+pub mod io;
 pub mod model;
+pub mod proof;
 pub mod runner;
+#[cfg(feature = "test-utilities")]
+pub mod test_utilities;
 
 // We declare a private module with public use, so that all exports from API are exports directly
 // from the crate.
@@ -314,4 +281,6 @@ pub use api::*;
 pub use crate::api::solver::Solver;
 pub use crate::basic_types::ConstraintOperationError;
 pub use crate::basic_types::Random;
+pub use crate::basic_types::ViolatedConstraint;
+pub use crate::engine::SnapshotId;
 pub(crate) mod tests;
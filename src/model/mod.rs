@@ -1,8 +1,11 @@
 use std::fmt::Display;
+use std::io;
+use std::io::Write;
 use std::num::NonZero;
 use std::ops::Range;
 
 use clap::ValueEnum;
+use thiserror::Error;
 
 use crate::constraints;
 use crate::constraints::CumulativeImpl;
@@ -10,6 +13,8 @@ use crate::constraints::SubCircuitElimination;
 use crate::encodings;
 use crate::engine::cp::AssignmentsInteger;
 use crate::options::SolverOptions;
+use crate::predicate;
+use crate::predicates::VariableNameLookup;
 use crate::termination::TerminationCondition;
 use crate::variables::AffineView;
 use crate::variables::DomainId;
@@ -18,7 +23,11 @@ use crate::ConstraintOperationError;
 use crate::Solver;
 
 /// Builds up the model, from which a solver can be constructed.
+///
+/// With the `serde` feature, the model can be (de)serialised, e.g. to construct it on one machine
+/// and to solve it on another.
 #[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Model {
     /// Every element denotes the bounds of the variable.
     variables: Vec<(String, i32, i32)>,
@@ -101,7 +110,204 @@ impl Model {
         (assignment, solver_variables)
     }
 
+    /// Checks the model for mistakes which would otherwise lead to panics or incorrect behaviour
+    /// when creating a solver from it, and returns all of the mistakes which were found.
+    ///
+    /// Note that indices of element constraints and the successors in circuit constraints are
+    /// 1-based.
+    pub fn validate(&self) -> Result<(), Vec<ModelError>> {
+        let mut errors = vec![];
+
+        for (name, lower_bound, upper_bound) in self.variables.iter() {
+            if lower_bound > upper_bound {
+                errors.push(ModelError::EmptyDomain {
+                    name: name.clone(),
+                    lower_bound: *lower_bound,
+                    upper_bound: *upper_bound,
+                });
+            }
+        }
+
+        for constraint in self.constraints.iter() {
+            let unknown_variables = constraint
+                .variables()
+                .into_iter()
+                .filter(|variable| variable.id >= self.variables.len())
+                .map(|variable| ModelError::UnknownVariable {
+                    constraint: constraint.name().to_owned(),
+                    id: variable.id,
+                })
+                .collect::<Vec<_>>();
+
+            // The other checks require the variables to be part of this model
+            if !unknown_variables.is_empty() {
+                errors.extend(unknown_variables);
+                continue;
+            }
+
+            self.validate_constraint(constraint, &mut errors);
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Checks a single constraint whose variables are all part of this model; see
+    /// [`Model::validate`].
+    fn validate_constraint(&self, constraint: &Constraint, errors: &mut Vec<ModelError>) {
+        match constraint {
+            Constraint::Element { array, index, .. } => {
+                let (lower_bound, upper_bound) = self.get_bounds(*index);
+                if lower_bound < 1 || upper_bound > array.len() as i32 {
+                    errors.push(ModelError::ElementIndexOutOfBounds {
+                        name: self.get_name(*index).to_owned(),
+                        lower_bound,
+                        upper_bound,
+                        array_length: array.len(),
+                    });
+                }
+            }
+            Constraint::Circuit(successors) => {
+                if successors.is_empty() {
+                    errors.push(ModelError::EmptyCircuit);
+                }
+
+                for &successor in successors.iter() {
+                    let (lower_bound, upper_bound) = self.get_bounds(successor);
+                    if lower_bound < 1 || upper_bound > successors.len() as i32 {
+                        errors.push(ModelError::CircuitSuccessorOutOfBounds {
+                            name: self.get_name(successor).to_owned(),
+                            lower_bound,
+                            upper_bound,
+                            num_nodes: successors.len(),
+                        });
+                    }
+                }
+            }
+            Constraint::Inverse { f, g } => {
+                if f.len() != g.len() {
+                    errors.push(ModelError::MismatchedInverseLengths {
+                        f_length: f.len(),
+                        g_length: g.len(),
+                    });
+                }
+
+                for &variable in f.iter().chain(g.iter()) {
+                    let (lower_bound, upper_bound) = self.get_bounds(variable);
+                    if lower_bound < 1 || upper_bound > f.len() as i32 {
+                        errors.push(ModelError::InverseValueOutOfBounds {
+                            name: self.get_name(variable).to_owned(),
+                            lower_bound,
+                            upper_bound,
+                            num_values: f.len(),
+                        });
+                    }
+                }
+            }
+            Constraint::Maximum { terms, .. } if terms.is_empty() => {
+                errors.push(ModelError::EmptyMaximum);
+            }
+            Constraint::Maximum { terms, rhs } => {
+                // The maximum lies between the largest lower bound and the largest upper bound
+                let (lower_bounds, upper_bounds): (Vec<_>, Vec<_>) =
+                    terms.iter().map(|&term| self.get_bounds(term)).unzip();
+                let lowest_maximum = lower_bounds.into_iter().max().unwrap();
+                let highest_maximum = upper_bounds.into_iter().max().unwrap();
+
+                self.check_rhs_bounds(*rhs, "maximum", lowest_maximum, highest_maximum)
+                    .unwrap_or_else(|error| errors.push(error));
+            }
+            Constraint::Count { array, rhs, .. } => {
+                self.check_rhs_bounds(*rhs, "count", 0, array.len() as i32)
+                    .unwrap_or_else(|error| errors.push(error));
+            }
+            Constraint::Disjunctive {
+                start_times,
+                durations,
+            } => {
+                if start_times.len() != durations.len() {
+                    errors.push(ModelError::MismatchedTaskData {
+                        constraint: constraint.name().to_owned(),
+                        num_start_times: start_times.len(),
+                        num_durations: durations.len(),
+                    });
+                }
+
+                errors.extend(
+                    start_times
+                        .iter()
+                        .zip(durations)
+                        .filter(|(_, &duration)| duration < 0)
+                        .map(|(&start_time, &duration)| ModelError::NegativeDuration {
+                            name: self.get_name(start_time).to_owned(),
+                            duration,
+                        }),
+                );
+            }
+            Constraint::Implication {
+                condition,
+                consequence,
+            } => {
+                let (lower_bound, upper_bound) = self.get_bounds(*condition);
+                if lower_bound < 0 || upper_bound > 1 {
+                    errors.push(ModelError::NonBooleanCondition {
+                        name: self.get_name(*condition).to_owned(),
+                        lower_bound,
+                        upper_bound,
+                    });
+                }
+
+                if consequence.can_be_implied() {
+                    self.validate_constraint(consequence, errors);
+                } else {
+                    errors.push(ModelError::UnsupportedImplication {
+                        constraint: consequence.name().to_owned(),
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Checks whether the domain of `rhs` intersects with the values `[lower_bound, upper_bound]`
+    /// which the aggregate constraint can take.
+    fn check_rhs_bounds(
+        &self,
+        rhs: IntVariable,
+        constraint: &str,
+        lower_bound: i32,
+        upper_bound: i32,
+    ) -> Result<(), ModelError> {
+        let (rhs_lower_bound, rhs_upper_bound) = self.get_bounds(rhs);
+        if rhs_upper_bound < lower_bound || rhs_lower_bound > upper_bound {
+            return Err(ModelError::IncompatibleRhsBounds {
+                constraint: constraint.to_owned(),
+                name: self.get_name(rhs).to_owned(),
+                lower_bound: rhs_lower_bound,
+                upper_bound: rhs_upper_bound,
+                feasible_lower_bound: lower_bound,
+                feasible_upper_bound: upper_bound,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Get the bounds of the given variable at the time of its creation.
+    fn get_bounds(&self, variable: IntVariable) -> (i32, i32) {
+        let (_, lower_bound, upper_bound) = &self.variables[variable.id];
+        let lower_bound = variable.scale * lower_bound + variable.offset;
+        let upper_bound = variable.scale * upper_bound + variable.offset;
+
+        (lower_bound.min(upper_bound), lower_bound.max(upper_bound))
+    }
+
     /// Create a solver instance from this model.
+    ///
+    /// In debug builds, this panics if [`Model::validate`] reports any mistakes in the model.
     pub fn into_solver(
         self,
         solver_options: SolverOptions,
@@ -109,6 +315,12 @@ impl Model {
         linear_encoding: Option<LinearEncoding>,
         termination: &mut impl TerminationCondition,
     ) -> (Solver, VariableMap) {
+        if cfg!(debug_assertions) {
+            if let Err(errors) = self.validate() {
+                panic!("the model is invalid: {errors:?}");
+            }
+        }
+
         let mut solver = Solver::with_options(solver_options);
 
         let (variables, names): (Vec<_>, Vec<_>) = self
@@ -154,6 +366,168 @@ impl Model {
     pub(crate) fn get_name(&self, variable: IntVariable) -> &str {
         &self.variables[variable.id].0
     }
+
+    /// Writes a human-readable listing of the model in a MiniZinc-like syntax: every variable
+    /// with its domain and every constraint, in the order in which they were added, followed by
+    /// `solve satisfy;`. Every constraint is annotated with the tag it is posted with by
+    /// [`Model::into_solver`], such that the listing can be compared to
+    /// [`Solver::dump_posted`].
+    ///
+    /// As the model does not store an objective, use [`Model::dump_with_objective`] to list the
+    /// objective of an optimisation problem.
+    pub fn dump(&self, writer: impl Write) -> io::Result<()> {
+        self.write_dump(None, writer)
+    }
+
+    /// Writes the listing of [`Model::dump`], but ends with minimising `objective` rather than
+    /// with `solve satisfy;`.
+    pub fn dump_with_objective(
+        &self,
+        objective: IntVariable,
+        writer: impl Write,
+    ) -> io::Result<()> {
+        self.write_dump(Some(objective), writer)
+    }
+
+    fn write_dump(&self, objective: Option<IntVariable>, mut writer: impl Write) -> io::Result<()> {
+        for (name, lower_bound, upper_bound) in self.variables.iter() {
+            writeln!(writer, "var {lower_bound}..{upper_bound}: {name};")?;
+        }
+
+        for (index, constraint) in self.constraints.iter().enumerate() {
+            writeln!(
+                writer,
+                "constraint {}; % tag {}",
+                self.display_constraint(constraint),
+                index + 1
+            )?;
+        }
+
+        match objective {
+            Some(objective) => writeln!(writer, "solve minimize {};", self.display(objective)),
+            None => writeln!(writer, "solve satisfy;"),
+        }
+    }
+
+    /// Formats the constraint as a MiniZinc-like call.
+    fn display_constraint(&self, constraint: &Constraint) -> String {
+        match constraint {
+            Constraint::Circuit(successors) => {
+                format!("circuit({})", self.display_array(successors))
+            }
+            Constraint::Element { array, index, rhs } => format!(
+                "element({}, {}, {})",
+                self.display(*index),
+                self.display_array(array),
+                self.display(*rhs)
+            ),
+            Constraint::LinearEqual { terms, rhs } => {
+                format!("linear_eq({})", self.display_linear(terms, *rhs))
+            }
+            Constraint::LinearLessEqual { terms, rhs } => {
+                format!("linear_le({})", self.display_linear(terms, *rhs))
+            }
+            Constraint::Cumulative {
+                start_times,
+                durations,
+                resource_requirements,
+                resource_capacity,
+            } => format!(
+                "cumulative({}, {}, {}, {resource_capacity})",
+                self.display_array(start_times),
+                display_values(durations),
+                display_values(resource_requirements)
+            ),
+            Constraint::Disjunctive {
+                start_times,
+                durations,
+            } => format!(
+                "disjunctive({}, {})",
+                self.display_array(start_times),
+                display_values(durations)
+            ),
+            Constraint::Maximum { terms, rhs } => format!(
+                "maximum({}, {})",
+                self.display_array(terms),
+                self.display(*rhs)
+            ),
+            Constraint::Count { array, value, rhs } => format!(
+                "count({}, {value}, {})",
+                self.display_array(array),
+                self.display(*rhs)
+            ),
+            Constraint::Inverse { f, g } => format!(
+                "inverse({}, {})",
+                self.display_array(f),
+                self.display_array(g)
+            ),
+            Constraint::Channel {
+                int_var,
+                values,
+                bools,
+            } => format!(
+                "channel({}, {}, {})",
+                self.display(*int_var),
+                display_values(values),
+                self.display_array(bools)
+            ),
+            Constraint::Implication {
+                condition,
+                consequence,
+            } => format!(
+                "{} = 1 -> {}",
+                self.display(*condition),
+                self.display_constraint(consequence)
+            ),
+        }
+    }
+
+    /// Formats the linear constraint over `terms` as the coefficients, the variables, and the
+    /// right-hand side; the offsets of the terms are moved to the right-hand side.
+    fn display_linear(&self, terms: &[IntVariable], rhs: i32) -> String {
+        let coefficients: Vec<_> = terms.iter().map(|term| term.scale).collect();
+        let names: Vec<_> = terms.iter().map(|&term| self.get_name(term)).collect();
+        let rhs = rhs - terms.iter().map(|term| term.offset).sum::<i32>();
+
+        format!(
+            "{}, [{}], {rhs}",
+            display_values(&coefficients),
+            names.join(", ")
+        )
+    }
+
+    fn display_array(&self, variables: &[IntVariable]) -> String {
+        let names: Vec<_> = variables
+            .iter()
+            .map(|&variable| self.display(variable))
+            .collect();
+
+        format!("[{}]", names.join(", "))
+    }
+
+    /// Formats the variable as an affine expression over its name.
+    fn display(&self, variable: IntVariable) -> String {
+        let mut expression = self.get_name(variable).to_owned();
+
+        if variable.scale != 1 {
+            expression = format!("{} * {}", variable.scale, expression);
+        }
+
+        if variable.offset < 0 {
+            expression = format!("{} - {}", expression, -variable.offset);
+        }
+
+        if variable.offset > 0 {
+            expression = format!("{} + {}", expression, variable.offset);
+        }
+
+        expression
+    }
+}
+
+fn display_values(values: &[impl Display]) -> String {
+    let values: Vec<_> = values.iter().map(ToString::to_string).collect();
+    format!("[{}]", values.join(","))
 }
 
 fn add_constraints(
@@ -172,6 +546,7 @@ fn add_constraints(
         }
 
         let tag = NonZero::new(idx as u32 + 1).unwrap();
+        solver.name_constraint(tag, constraint.name());
 
         match constraint {
             Constraint::Circuit(variables) => {
@@ -226,6 +601,24 @@ fn add_constraints(
                 }
             }
             Constraint::LinearLessEqual { terms, rhs } => {
+                // `x - y <= rhs` is posted as `x + (-rhs) <= y` with a dedicated propagator
+                let binary_terms = match &terms[..] {
+                    [x, y] if x.scale == 1 && y.scale == -1 => Some((*x, *y)),
+                    [y, x] if x.scale == 1 && y.scale == -1 => Some((*x, *y)),
+                    _ => None,
+                };
+
+                if let (None, Some((x, y))) = (&linear_encoding, binary_terms) {
+                    solver
+                        .add_constraint(constraints::binary_less_than_or_equals(
+                            to_solver_variable(x),
+                            to_solver_variable(y).scaled(-1),
+                            -rhs,
+                        ))
+                        .post(tag)?;
+                    continue;
+                }
+
                 let terms: Vec<_> = terms.into_iter().map(to_solver_variable).collect();
 
                 match linear_encoding {
@@ -282,14 +675,336 @@ fn add_constraints(
                         .post(tag);
                 }
             }
+            Constraint::Disjunctive {
+                start_times,
+                durations,
+            } => {
+                let start_times: Vec<_> = start_times.into_iter().map(to_solver_variable).collect();
+
+                if use_global_propagator(Globals::Disjunctive) {
+                    solver
+                        .add_constraint(constraints::disjunctive(start_times, durations))
+                        .post(tag)?;
+                } else {
+                    solver
+                        .add_constraint(constraints::disjunctive_decomposition(
+                            start_times,
+                            durations,
+                        ))
+                        .post(tag)?;
+                }
+            }
+            Constraint::Count { array, value, rhs } => {
+                let array: Vec<_> = array.into_iter().map(to_solver_variable).collect();
+                let rhs = to_solver_variable(rhs);
+
+                if use_global_propagator(Globals::Count) {
+                    solver
+                        .add_constraint(constraints::count(array, value, rhs))
+                        .post(tag)?;
+                } else {
+                    solver
+                        .add_constraint(constraints::count_decomposition(array, value, rhs))
+                        .post(tag)?;
+                }
+            }
+            Constraint::Inverse { f, g } => {
+                // The solver constraint is over the values `[0, n)`
+                let f: Vec<_> = f
+                    .into_iter()
+                    .map(|variable| to_solver_variable(variable).offset(-1))
+                    .collect();
+                let g: Vec<_> = g
+                    .into_iter()
+                    .map(|variable| to_solver_variable(variable).offset(-1))
+                    .collect();
+
+                if use_global_propagator(Globals::Inverse) {
+                    solver
+                        .add_constraint(constraints::inverse(f, g))
+                        .post(tag)?;
+                } else {
+                    solver
+                        .add_constraint(constraints::inverse_decomposition(f, g))
+                        .post(tag)?;
+                }
+            }
+            Constraint::Channel {
+                int_var,
+                values,
+                bools,
+            } => {
+                assert_eq!(
+                    values.len(),
+                    bools.len(),
+                    "every value should have exactly one boolean"
+                );
+                let int_var = to_solver_variable(int_var);
+
+                // The equality literals are already part of the representation of `int_var`, so
+                // we only need to tie the booleans to them.
+                for (value, bool) in values.into_iter().zip(bools) {
+                    let bool = to_solver_variable(bool);
+                    let equality_literal = solver.get_literal(predicate![int_var == value]);
+                    let bool_literal = solver.get_literal(predicate![bool >= 1]);
+
+                    solver.add_clause([!equality_literal, bool_literal])?;
+                    solver.add_clause([equality_literal, !bool_literal])?;
+                }
+            }
+            Constraint::Implication {
+                condition,
+                consequence,
+            } => add_implied_constraint(
+                *consequence,
+                to_solver_variable(condition),
+                solver_variables,
+                &use_global_propagator,
+                linear_encoding,
+                solver,
+                tag,
+            )?,
         }
     }
 
     Ok(())
 }
 
+/// Posts `[condition >= 1] -> constraint`, where `condition` is a 0-1 variable.
+///
+/// The constraint is half-reified using [`constraints::Constraint::implied_by`]. The
+/// decompositions of the element and maximum constraints do not support this, so their propagators
+/// are always used in an implication. Similarly, the encodings of linear constraints cannot be half-reified; when
+/// a `linear_encoding` is used, the implication is encoded as a big-M linear constraint instead
+/// (see [`post_big_m_less_than_or_equals`]).
+fn add_implied_constraint(
+    constraint: Constraint,
+    condition: AffineView<DomainId>,
+    solver_variables: &VariableMap,
+    use_global_propagator: &impl Fn(Globals) -> bool,
+    linear_encoding: Option<LinearEncoding>,
+    solver: &mut Solver,
+    tag: NonZero<u32>,
+) -> Result<(), ConstraintOperationError> {
+    let to_solver_variable = |int_var: IntVariable| solver_variables.to_solver_variable(int_var);
+    let condition_literal = solver.get_literal(predicate![condition >= 1]);
+
+    match constraint {
+        Constraint::LinearLessEqual { terms, rhs } => {
+            let terms: Vec<_> = terms.into_iter().map(to_solver_variable).collect();
+
+            match linear_encoding {
+                Some(encoding) => {
+                    post_big_m_less_than_or_equals(terms, rhs, condition, encoding, solver, tag)
+                }
+                None => solver
+                    .add_constraint(constraints::less_than_or_equals(terms, rhs))
+                    .implied_by(condition_literal, tag),
+            }
+        }
+        Constraint::LinearEqual { terms, rhs } => {
+            let terms: Vec<_> = terms.into_iter().map(to_solver_variable).collect();
+
+            match linear_encoding {
+                Some(encoding) => {
+                    let negated_terms = terms.iter().map(|term| term.scaled(-1)).collect();
+                    post_big_m_less_than_or_equals(
+                        terms,
+                        rhs,
+                        condition.clone(),
+                        encoding,
+                        solver,
+                        tag,
+                    )?;
+                    post_big_m_less_than_or_equals(
+                        negated_terms,
+                        -rhs,
+                        condition,
+                        encoding,
+                        solver,
+                        tag,
+                    )
+                }
+                None => solver
+                    .add_constraint(constraints::equals(terms, rhs))
+                    .implied_by(condition_literal, tag),
+            }
+        }
+        Constraint::Element { array, index, rhs } => {
+            let array: Vec<_> = array.into_iter().map(to_solver_variable).collect();
+
+            solver
+                .add_constraint(constraints::element(
+                    to_solver_variable(index),
+                    array,
+                    to_solver_variable(rhs),
+                ))
+                .implied_by(condition_literal, tag)
+        }
+        Constraint::Maximum { terms, rhs } => {
+            let terms: Vec<_> = terms.into_iter().map(to_solver_variable).collect();
+
+            solver
+                .add_constraint(constraints::maximum(terms, to_solver_variable(rhs)))
+                .implied_by(condition_literal, tag)
+        }
+        Constraint::Disjunctive {
+            start_times,
+            durations,
+        } => {
+            let start_times: Vec<_> = start_times.into_iter().map(to_solver_variable).collect();
+
+            if use_global_propagator(Globals::Disjunctive) {
+                solver
+                    .add_constraint(constraints::disjunctive(start_times, durations))
+                    .implied_by(condition_literal, tag)
+            } else {
+                solver
+                    .add_constraint(constraints::disjunctive_decomposition(
+                        start_times,
+                        durations,
+                    ))
+                    .implied_by(condition_literal, tag)
+            }
+        }
+        Constraint::Count { array, value, rhs } => {
+            let array: Vec<_> = array.into_iter().map(to_solver_variable).collect();
+            let rhs = to_solver_variable(rhs);
+
+            if use_global_propagator(Globals::Count) {
+                solver
+                    .add_constraint(constraints::count(array, value, rhs))
+                    .implied_by(condition_literal, tag)
+            } else {
+                solver
+                    .add_constraint(constraints::count_decomposition(array, value, rhs))
+                    .implied_by(condition_literal, tag)
+            }
+        }
+        Constraint::Inverse { f, g } => {
+            let f: Vec<_> = f
+                .into_iter()
+                .map(|variable| to_solver_variable(variable).offset(-1))
+                .collect();
+            let g: Vec<_> = g
+                .into_iter()
+                .map(|variable| to_solver_variable(variable).offset(-1))
+                .collect();
+
+            if use_global_propagator(Globals::Inverse) {
+                solver
+                    .add_constraint(constraints::inverse(f, g))
+                    .implied_by(condition_literal, tag)
+            } else {
+                solver
+                    .add_constraint(constraints::inverse_decomposition(f, g))
+                    .implied_by(condition_literal, tag)
+            }
+        }
+        Constraint::Channel {
+            int_var,
+            values,
+            bools,
+        } => {
+            assert_eq!(
+                values.len(),
+                bools.len(),
+                "every value should have exactly one boolean"
+            );
+            let int_var = to_solver_variable(int_var);
+
+            for (value, bool) in values.into_iter().zip(bools) {
+                let bool = to_solver_variable(bool);
+                let equality_literal = solver.get_literal(predicate![int_var == value]);
+                let bool_literal = solver.get_literal(predicate![bool >= 1]);
+
+                solver.add_clause([!condition_literal, !equality_literal, bool_literal])?;
+                solver.add_clause([!condition_literal, equality_literal, !bool_literal])?;
+            }
+
+            Ok(())
+        }
+        Constraint::Implication {
+            condition: inner_condition,
+            consequence,
+        } => {
+            // `a -> (b -> c)` is posted as `(a /\ b) -> c`
+            let inner_condition = to_solver_variable(inner_condition);
+            let inner_literal = solver.get_literal(predicate![inner_condition >= 1]);
+
+            let both = AffineView::from(solver.new_bounded_integer(0, 1));
+            let both_literal = solver.get_literal(predicate![both >= 1]);
+            solver.add_clause([!both_literal, condition_literal])?;
+            solver.add_clause([!both_literal, inner_literal])?;
+            solver.add_clause([!condition_literal, !inner_literal, both_literal])?;
+
+            add_implied_constraint(
+                *consequence,
+                both,
+                solver_variables,
+                use_global_propagator,
+                linear_encoding,
+                solver,
+                tag,
+            )
+        }
+        Constraint::Circuit(_) | Constraint::Cumulative { .. } => panic!(
+            "a {} constraint cannot be the consequence of an implication",
+            constraint.name()
+        ),
+    }
+}
+
+/// Posts `[condition >= 1] -> sum(terms) <= rhs` as a big-M linear constraint (see
+/// [`big_m_less_than_or_equals`]) using the provided `encoding`.
+fn post_big_m_less_than_or_equals(
+    terms: Vec<AffineView<DomainId>>,
+    rhs: i32,
+    condition: AffineView<DomainId>,
+    encoding: LinearEncoding,
+    solver: &mut Solver,
+    tag: NonZero<u32>,
+) -> Result<(), ConstraintOperationError> {
+    match big_m_less_than_or_equals(terms, rhs, condition, solver) {
+        Some((terms, rhs)) => solver
+            .add_constraint(encodings::less_than_or_equals(terms, rhs, encoding))
+            .post(tag),
+        None => Ok(()),
+    }
+}
+
+/// Returns the terms and right-hand side of the linear constraint
+/// `sum(terms) + M * condition <= rhs + M`, which states `[condition >= 1] -> sum(terms) <= rhs`.
+/// Here, `M` is the largest amount by which `sum(terms)` can exceed `rhs` given the current bounds
+/// of the terms in the `solver`.
+///
+/// If `sum(terms)` cannot exceed `rhs`, then the implication always holds and [`None`] is
+/// returned.
+fn big_m_less_than_or_equals(
+    mut terms: Vec<AffineView<DomainId>>,
+    rhs: i32,
+    condition: AffineView<DomainId>,
+    solver: &Solver,
+) -> Option<(Vec<AffineView<DomainId>>, i32)> {
+    let largest_sum = terms
+        .iter()
+        .map(|term| solver.upper_bound(term) as i64)
+        .sum::<i64>();
+    let big_m = largest_sum - rhs as i64;
+
+    if big_m <= 0 {
+        return None;
+    }
+
+    let big_m = i32::try_from(big_m).expect("the big-M value should fit in an i32");
+    terms.push(condition.scaled(big_m));
+
+    Some((terms, rhs + big_m))
+}
+
 /// The constraints which can be used in [`Model`].
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Constraint {
     Circuit(Vec<IntVariable>),
     Element {
@@ -311,10 +1026,44 @@ pub enum Constraint {
         resource_requirements: Vec<u32>,
         resource_capacity: u32,
     },
+    /// States that no two tasks overlap, where task `i` starts at `start_times[i]` and takes
+    /// `durations[i]` time units.
+    Disjunctive {
+        start_times: Vec<IntVariable>,
+        durations: Vec<i32>,
+    },
     Maximum {
         terms: Vec<IntVariable>,
         rhs: IntVariable,
     },
+    /// States that `rhs` is equal to the number of variables in `array` which are equal to
+    /// `value`.
+    Count {
+        array: Vec<IntVariable>,
+        value: i32,
+        rhs: IntVariable,
+    },
+    /// States that `f[i] = j` if and only if `g[j] = i`, where the values are numbered from 1; e.g.
+    /// `f` are the successors and `g` the predecessors of the nodes in a circuit.
+    Inverse {
+        f: Vec<IntVariable>,
+        g: Vec<IntVariable>,
+    },
+    /// Channels `int_var` to the 0-1 variables in `bools`: `bools[i]` is 1 if and only if
+    /// `int_var` is equal to `values[i]`.
+    Channel {
+        int_var: IntVariable,
+        values: Vec<i32>,
+        bools: Vec<IntVariable>,
+    },
+    /// States that `consequence` holds if the 0-1 variable `condition` is 1; if `condition` is 0,
+    /// then `consequence` is not enforced.
+    ///
+    /// Circuit and cumulative constraints cannot be the consequence of an implication.
+    Implication {
+        condition: IntVariable,
+        consequence: Box<Constraint>,
+    },
 }
 
 impl Constraint {
@@ -325,12 +1074,141 @@ impl Constraint {
             Constraint::LinearEqual { .. } => "linear_equal",
             Constraint::LinearLessEqual { .. } => "linear_less_equal",
             Constraint::Cumulative { .. } => "cumulative",
+            Constraint::Disjunctive { .. } => "disjunctive",
             Constraint::Maximum { .. } => "maximum",
+            Constraint::Count { .. } => "count",
+            Constraint::Inverse { .. } => "inverse",
+            Constraint::Channel { .. } => "channel",
+            Constraint::Implication { .. } => "implication",
+        }
+    }
+
+    /// Returns whether the constraint can be the consequence of a [`Constraint::Implication`].
+    fn can_be_implied(&self) -> bool {
+        match self {
+            Constraint::Circuit(_) | Constraint::Cumulative { .. } => false,
+            Constraint::Implication { consequence, .. } => consequence.can_be_implied(),
+            _ => true,
+        }
+    }
+
+    /// Returns all of the variables which occur in the constraint.
+    fn variables(&self) -> Vec<IntVariable> {
+        match self {
+            Constraint::Circuit(successors) => successors.clone(),
+            Constraint::Element { array, index, rhs } => {
+                array.iter().copied().chain([*index, *rhs]).collect()
+            }
+            Constraint::LinearEqual { terms, .. } | Constraint::LinearLessEqual { terms, .. } => {
+                terms.clone()
+            }
+            Constraint::Cumulative { start_times, .. }
+            | Constraint::Disjunctive { start_times, .. } => start_times.clone(),
+            Constraint::Maximum { terms, rhs }
+            | Constraint::Count {
+                array: terms, rhs, ..
+            } => terms.iter().copied().chain([*rhs]).collect(),
+            Constraint::Inverse { f, g } => f.iter().chain(g).copied().collect(),
+            Constraint::Channel { int_var, bools, .. } => {
+                bools.iter().copied().chain([*int_var]).collect()
+            }
+            Constraint::Implication {
+                condition,
+                consequence,
+            } => consequence
+                .variables()
+                .into_iter()
+                .chain([*condition])
+                .collect(),
         }
     }
 }
 
+/// The mistakes in a [`Model`] which are detected by [`Model::validate`].
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum ModelError {
+    #[error("Variable '{name}' has an empty domain [{lower_bound}, {upper_bound}]")]
+    EmptyDomain {
+        name: String,
+        lower_bound: i32,
+        upper_bound: i32,
+    },
+    #[error("A {constraint} constraint contains variable {id} which is not part of the model")]
+    UnknownVariable { constraint: String, id: usize },
+    #[error(
+        "The index '{name}' of an element constraint has domain [{lower_bound}, {upper_bound}], \
+         but the array has indices [1, {array_length}]"
+    )]
+    ElementIndexOutOfBounds {
+        name: String,
+        lower_bound: i32,
+        upper_bound: i32,
+        array_length: usize,
+    },
+    #[error("A circuit constraint should contain at least one successor")]
+    EmptyCircuit,
+    #[error("A maximum constraint should contain at least one term")]
+    EmptyMaximum,
+    #[error(
+        "The successor '{name}' of a circuit constraint has domain [{lower_bound}, {upper_bound}], \
+         but the nodes are [1, {num_nodes}]"
+    )]
+    CircuitSuccessorOutOfBounds {
+        name: String,
+        lower_bound: i32,
+        upper_bound: i32,
+        num_nodes: usize,
+    },
+    #[error("An inverse constraint is over arrays of different lengths {f_length} and {g_length}")]
+    MismatchedInverseLengths { f_length: usize, g_length: usize },
+    #[error(
+        "The variable '{name}' of an inverse constraint has domain [{lower_bound}, \
+         {upper_bound}], but the values are [1, {num_values}]"
+    )]
+    InverseValueOutOfBounds {
+        name: String,
+        lower_bound: i32,
+        upper_bound: i32,
+        num_values: usize,
+    },
+    #[error(
+        "A {constraint} constraint has {num_start_times} start times but {num_durations} durations"
+    )]
+    MismatchedTaskData {
+        constraint: String,
+        num_start_times: usize,
+        num_durations: usize,
+    },
+    #[error("The task starting at '{name}' has a negative duration {duration}")]
+    NegativeDuration { name: String, duration: i32 },
+    #[error(
+        "The right-hand side '{name}' of a {constraint} constraint has domain [{lower_bound}, \
+         {upper_bound}], but the constraint can only take values in [{feasible_lower_bound}, \
+         {feasible_upper_bound}]"
+    )]
+    IncompatibleRhsBounds {
+        constraint: String,
+        name: String,
+        lower_bound: i32,
+        upper_bound: i32,
+        feasible_lower_bound: i32,
+        feasible_upper_bound: i32,
+    },
+    #[error(
+        "The condition '{name}' of an implication has domain [{lower_bound}, {upper_bound}], but \
+         it should be a 0-1 variable"
+    )]
+    NonBooleanCondition {
+        name: String,
+        lower_bound: i32,
+        upper_bound: i32,
+    },
+    #[error("A {constraint} constraint cannot be the consequence of an implication")]
+    UnsupportedImplication { constraint: String },
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IntVariable {
     /// The scale w.r.t. the underlying domain.
     scale: i32,
@@ -359,6 +1237,7 @@ impl IntVariable {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IntVariableArray(usize);
 
 impl IntVariableArray {
@@ -446,6 +1325,16 @@ impl VariableMap {
     }
 }
 
+impl VariableNameLookup for VariableMap {
+    fn integer_name(&self, domain_id: DomainId) -> Option<String> {
+        let view = AffineView::from(domain_id);
+        self.variables
+            .iter()
+            .position(|variable| *variable == view)
+            .map(|index| self.names[index].clone())
+    }
+}
+
 #[derive(Clone, Copy, Debug, ValueEnum, PartialEq, Eq)]
 pub enum Globals {
     DfsCircuit,
@@ -453,6 +1342,9 @@ pub enum Globals {
     AllDifferent,
     Cumulative,
     Maximum,
+    Count,
+    Disjunctive,
+    Inverse,
     ForwardCheckingCircuit,
     TimeTableCumulative,
     EnergeticReasoningCumulative,
@@ -463,3 +1355,654 @@ pub enum LinearEncoding {
     Totalizer,
     SequentialSums,
 }
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZero;
+
+    use super::big_m_less_than_or_equals;
+    use super::Constraint;
+    use super::Globals;
+    use super::Model;
+    use super::ModelError;
+    use crate::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
+    use crate::branching::InDomainMin;
+    use crate::branching::InputOrder;
+    use crate::constraints;
+    use crate::options::SolverOptions;
+    use crate::predicate;
+    use crate::results::OptimisationResult;
+    use crate::results::ProblemSolution;
+    use crate::termination::Indefinite;
+    use crate::tests::harness::enumerate_solutions;
+    use crate::variables::AffineView;
+    use crate::variables::TransformableVariable;
+    use crate::Solver;
+
+    #[test]
+    fn channel_ties_booleans_to_the_values_of_the_variable() {
+        let mut model = Model::default();
+        let x = model.new_interval_variable("x", 0, 3);
+        let b1 = model.new_interval_variable("b1", 0, 1);
+        let b2 = model.new_interval_variable("b2", 0, 1);
+
+        model.add_constraint(Constraint::Channel {
+            int_var: x,
+            values: vec![1, 2],
+            bools: vec![b1, b2],
+        });
+
+        let (mut solver, variables) =
+            model.into_solver(SolverOptions::default(), |_| true, None, &mut Indefinite);
+        let x = variables.get_named_variable("x").unwrap();
+        let b1 = variables.get_named_variable("b1").unwrap();
+
+        let _ = solver.add_clause([solver.get_literal(predicate![x != 1])]);
+        assert_eq!(solver.upper_bound(&b1), 0);
+
+        let b2 = variables.get_named_variable("b2").unwrap();
+        let _ = solver.add_clause([solver.get_literal(predicate![b2 >= 1])]);
+        assert_eq!(solver.lower_bound(&x), 2);
+        assert_eq!(solver.upper_bound(&x), 2);
+    }
+
+    #[test]
+    fn binary_linear_less_equal_uses_binary_propagator() {
+        let mut model = Model::default();
+        let x = model.new_interval_variable("x", 0, 10);
+        let y = model.new_interval_variable("y", 0, 10);
+        let z = model.new_interval_variable("z", 0, 10);
+
+        // -y + x <= -3, i.e. x + 3 <= y
+        model.add_constraint(Constraint::LinearLessEqual {
+            terms: vec![y.scaled(-1), x],
+            rhs: -3,
+        });
+        model.add_constraint(Constraint::LinearLessEqual {
+            terms: vec![x, y, z],
+            rhs: 20,
+        });
+
+        let (solver, variables) =
+            model.into_solver(SolverOptions::default(), |_| true, None, &mut Indefinite);
+        let x = variables.get_named_variable("x").unwrap();
+        let y = variables.get_named_variable("y").unwrap();
+
+        let names = solver
+            .propagator_statistics()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>();
+        assert_eq!(names.iter().filter(|&&name| name == "BinaryLeq").count(), 1);
+        assert_eq!(names.iter().filter(|&&name| name == "LinearLeq").count(), 1);
+
+        assert_eq!(solver.upper_bound(&x), 7);
+        assert_eq!(solver.lower_bound(&y), 3);
+    }
+
+    #[test]
+    fn valid_model_passes_validation() {
+        let mut model = Model::default();
+        let successors = model.new_interval_variable_array("s", 1, 3, 3);
+        let successors = successors.as_array(&model).collect::<Vec<_>>();
+        let rhs = model.new_interval_variable("rhs", 0, 10);
+
+        model.add_constraint(Constraint::Circuit(successors.clone()));
+        model.add_constraint(Constraint::Element {
+            array: successors.clone(),
+            index: successors[0],
+            rhs,
+        });
+
+        assert_eq!(model.validate(), Ok(()));
+    }
+
+    #[test]
+    fn empty_domain_is_reported() {
+        let mut model = Model::default();
+        let _ = model.new_interval_variable("x", 5, 2);
+
+        let errors = model.validate().unwrap_err();
+        assert_eq!(
+            errors,
+            vec![ModelError::EmptyDomain {
+                name: "x".to_owned(),
+                lower_bound: 5,
+                upper_bound: 2,
+            }]
+        );
+        assert!(errors[0].to_string().contains("'x'"));
+    }
+
+    #[test]
+    fn variable_from_other_model_is_reported() {
+        let mut other_model = Model::default();
+        let _ = other_model.new_interval_variable("y", 0, 1);
+        let foreign = other_model.new_interval_variable("z", 0, 1);
+
+        let mut model = Model::default();
+        let x = model.new_interval_variable("x", 0, 1);
+        model.add_constraint(Constraint::LinearLessEqual {
+            terms: vec![x, foreign],
+            rhs: 1,
+        });
+
+        let errors = model.validate().unwrap_err();
+        assert_eq!(
+            errors,
+            vec![ModelError::UnknownVariable {
+                constraint: "linear_less_equal".to_owned(),
+                id: 1,
+            }]
+        );
+    }
+
+    #[test]
+    fn element_index_outside_array_is_reported() {
+        let mut model = Model::default();
+        let array = vec![
+            model.new_interval_variable("a", 0, 1),
+            model.new_interval_variable("b", 0, 1),
+        ];
+        let index = model.new_interval_variable("index", 0, 2);
+        let rhs = model.new_interval_variable("rhs", 0, 1);
+
+        model.add_constraint(Constraint::Element { array, index, rhs });
+
+        let errors = model.validate().unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            errors[0],
+            ModelError::ElementIndexOutOfBounds {
+                lower_bound: 0,
+                upper_bound: 2,
+                array_length: 2,
+                ..
+            }
+        ));
+        assert!(errors[0].to_string().contains("'index'"));
+    }
+
+    #[test]
+    fn element_index_bounds_take_the_view_into_account() {
+        let mut model = Model::default();
+        let array = vec![
+            model.new_interval_variable("a", 0, 1),
+            model.new_interval_variable("b", 0, 1),
+        ];
+        let index = model.new_interval_variable("index", 0, 1);
+        let rhs = model.new_interval_variable("rhs", 0, 1);
+
+        model.add_constraint(Constraint::Element {
+            array,
+            index: index.offset(1),
+            rhs,
+        });
+
+        assert_eq!(model.validate(), Ok(()));
+    }
+
+    #[test]
+    fn empty_maximum_is_reported() {
+        let mut model = Model::default();
+        let rhs = model.new_interval_variable("rhs", 0, 10);
+
+        model.add_constraint(Constraint::Maximum { terms: vec![], rhs });
+
+        assert_eq!(model.validate(), Err(vec![ModelError::EmptyMaximum]));
+    }
+
+    #[test]
+    fn circuit_problems_are_reported() {
+        let mut model = Model::default();
+        let successors = model.new_interval_variable_array("s", 1, 4, 3);
+        let successors = successors.as_array(&model).collect::<Vec<_>>();
+
+        model.add_constraint(Constraint::Circuit(vec![]));
+        model.add_constraint(Constraint::Circuit(successors));
+
+        let errors = model.validate().unwrap_err();
+        assert_eq!(errors.len(), 4);
+        assert_eq!(errors[0], ModelError::EmptyCircuit);
+        assert!(errors[1..].iter().all(|error| matches!(
+            error,
+            ModelError::CircuitSuccessorOutOfBounds { num_nodes: 3, .. }
+        )));
+        assert!(errors[1].to_string().contains("'s[0]'"));
+    }
+
+    #[test]
+    fn inverse_problems_are_reported() {
+        let mut model = Model::default();
+        let f = model.new_interval_variable_array("f", 1, 2, 2);
+        let f = f.as_array(&model).collect::<Vec<_>>();
+        let g = model.new_interval_variable_array("g", 0, 2, 3);
+        let g = g.as_array(&model).collect::<Vec<_>>();
+
+        model.add_constraint(Constraint::Inverse { f, g });
+
+        let errors = model.validate().unwrap_err();
+        assert_eq!(
+            errors[0],
+            ModelError::MismatchedInverseLengths {
+                f_length: 2,
+                g_length: 3
+            }
+        );
+        assert_eq!(errors.len(), 4);
+        assert!(errors[1..].iter().all(|error| matches!(
+            error,
+            ModelError::InverseValueOutOfBounds { num_values: 2, .. }
+        )));
+        assert!(errors[1].to_string().contains("'g[0]'"));
+    }
+
+    #[test]
+    fn dump_lists_variables_constraints_and_the_objective_in_order() {
+        let mut model = Model::default();
+        let x = model.new_interval_variable("x", 0, 5);
+        let y = model.new_interval_variable("y", -2, 2);
+        let b = model.new_interval_variable("b", 0, 1);
+
+        model.add_constraint(Constraint::LinearLessEqual {
+            terms: vec![x.scaled(2), y.scaled(-1).offset(1)],
+            rhs: 4,
+        });
+        model.add_constraint(Constraint::Implication {
+            condition: b,
+            consequence: Box::new(Constraint::Maximum {
+                terms: vec![x, y.offset(1)],
+                rhs: x,
+            }),
+        });
+
+        let mut dump = vec![];
+        model
+            .dump_with_objective(x.scaled(-1), &mut dump)
+            .expect("writing to a vector does not fail");
+
+        assert_eq!(
+            String::from_utf8(dump).unwrap(),
+            "var 0..5: x;\n\
+             var -2..2: y;\n\
+             var 0..1: b;\n\
+             constraint linear_le([2,-1], [x, y], 3); % tag 1\n\
+             constraint b = 1 -> maximum([x, y + 1], x); % tag 2\n\
+             solve minimize -1 * x;\n"
+        );
+    }
+
+    /// Creates a model over `x[0..3]` in `[0, 4]` which sums to 7, where `most = max(x)` and
+    /// `twos` is the number of variables in `x` which take the value 2.
+    fn aggregate_model() -> Model {
+        let mut model = Model::default();
+        let x = model.new_interval_variable_array("x", 0, 4, 3);
+        let x = x.as_array(&model).collect::<Vec<_>>();
+        let most = model.new_interval_variable("most", 0, 4);
+        let twos = model.new_interval_variable("twos", 1, 3);
+
+        model.add_constraint(Constraint::LinearEqual {
+            terms: x.clone(),
+            rhs: 7,
+        });
+        model.add_constraint(Constraint::Maximum {
+            terms: x.clone(),
+            rhs: most,
+        });
+        model.add_constraint(Constraint::Count {
+            array: x,
+            value: 2,
+            rhs: twos,
+        });
+
+        model
+    }
+
+    /// Returns the distinct assignments to `[x[0], x[1], x[2], most, twos]` and the minimum value
+    /// of `most` when lowering [`aggregate_model`] with the provided globals.
+    fn solve_aggregate_model(
+        use_global_propagator: impl Fn(Globals) -> bool,
+    ) -> (Vec<Vec<i32>>, i32) {
+        let names = ["x[0]", "x[1]", "x[2]", "most", "twos"];
+
+        let (mut solver, variables) = aggregate_model().into_solver(
+            SolverOptions::default(),
+            &use_global_propagator,
+            None,
+            &mut Indefinite,
+        );
+        let variables = names
+            .iter()
+            .map(|name| variables.get_named_variable(name).unwrap())
+            .collect::<Vec<_>>();
+
+        let solutions = enumerate_solutions(&mut solver, &variables);
+
+        let (mut solver, _) = aggregate_model().into_solver(
+            SolverOptions::default(),
+            &use_global_propagator,
+            None,
+            &mut Indefinite,
+        );
+        let mut brancher =
+            IndependentVariableValueBrancher::new(InputOrder::new(variables.clone()), InDomainMin);
+        let OptimisationResult::Optimal(optimal_solution) =
+            solver.minimise(&mut brancher, &mut Indefinite, variables[3].clone())
+        else {
+            panic!("expected the problem to be solved to optimality");
+        };
+
+        (
+            solutions,
+            optimal_solution.get_integer_value(variables[3].clone()),
+        )
+    }
+
+    #[test]
+    fn aggregate_lowerings_agree() {
+        // The maximum propagator is not available, so both lowerings use its decomposition
+        let (global_solutions, global_optimum) =
+            solve_aggregate_model(|globals| globals == Globals::Count);
+        let (decomposed_solutions, decomposed_optimum) = solve_aggregate_model(|_| false);
+
+        let expected_solutions = (0..125)
+            .map(|code| vec![code / 25, code / 5 % 5, code % 5])
+            .filter(|x| x.iter().sum::<i32>() == 7 && x.contains(&2))
+            .map(|x| {
+                let most = *x.iter().max().unwrap();
+                let twos = x.iter().filter(|&&value| value == 2).count() as i32;
+                x.into_iter().chain([most, twos]).collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(global_solutions, expected_solutions);
+        assert_eq!(decomposed_solutions, expected_solutions);
+        assert_eq!(global_optimum, 3);
+        assert_eq!(decomposed_optimum, 3);
+    }
+
+    #[test]
+    fn incompatible_aggregate_rhs_is_reported() {
+        let mut model = Model::default();
+        let x = model.new_interval_variable_array("x", 0, 2, 2);
+        let x = x.as_array(&model).collect::<Vec<_>>();
+        let most = model.new_interval_variable("most", 3, 5);
+        let occurrences = model.new_interval_variable("occurrences", -3, -1);
+
+        model.add_constraint(Constraint::Maximum {
+            terms: x.clone(),
+            rhs: most,
+        });
+        model.add_constraint(Constraint::Count {
+            array: x.clone(),
+            value: 1,
+            rhs: occurrences,
+        });
+        model.add_constraint(Constraint::Count {
+            array: x,
+            value: 1,
+            rhs: occurrences.offset(3),
+        });
+
+        let errors = model.validate().unwrap_err();
+        assert_eq!(
+            errors,
+            vec![
+                ModelError::IncompatibleRhsBounds {
+                    constraint: "maximum".to_owned(),
+                    name: "most".to_owned(),
+                    lower_bound: 3,
+                    upper_bound: 5,
+                    feasible_lower_bound: 0,
+                    feasible_upper_bound: 2,
+                },
+                ModelError::IncompatibleRhsBounds {
+                    constraint: "count".to_owned(),
+                    name: "occurrences".to_owned(),
+                    lower_bound: -3,
+                    upper_bound: -1,
+                    feasible_lower_bound: 0,
+                    feasible_upper_bound: 2,
+                }
+            ]
+        );
+        assert!(errors[0].to_string().contains("'most'"));
+    }
+
+    #[test]
+    fn invalid_disjunctive_durations_are_reported() {
+        let mut model = Model::default();
+        let starts = model.new_interval_variable_array("s", 0, 10, 2);
+        let starts = starts.as_array(&model).collect::<Vec<_>>();
+
+        model.add_constraint(Constraint::Disjunctive {
+            start_times: starts.clone(),
+            durations: vec![3],
+        });
+        model.add_constraint(Constraint::Disjunctive {
+            start_times: starts,
+            durations: vec![2, -1],
+        });
+
+        let errors = model.validate().unwrap_err();
+        assert_eq!(
+            errors,
+            vec![
+                ModelError::MismatchedTaskData {
+                    constraint: "disjunctive".to_owned(),
+                    num_start_times: 2,
+                    num_durations: 1,
+                },
+                ModelError::NegativeDuration {
+                    name: "s[1]".to_owned(),
+                    duration: -1,
+                },
+            ]
+        );
+    }
+
+    /// Creates a model over `x` and `y` in `[0, 3]` and the 0-1 variable `b`, where
+    /// `b -> x + y <= 2` and `b -> x - y = 1`.
+    fn implication_model() -> Model {
+        let mut model = Model::default();
+        let x = model.new_interval_variable("x", 0, 3);
+        let y = model.new_interval_variable("y", 0, 3);
+        let b = model.new_interval_variable("b", 0, 1);
+
+        model.add_constraint(Constraint::Implication {
+            condition: b,
+            consequence: Box::new(Constraint::LinearLessEqual {
+                terms: vec![x, y],
+                rhs: 2,
+            }),
+        });
+        model.add_constraint(Constraint::Implication {
+            condition: b,
+            consequence: Box::new(Constraint::LinearEqual {
+                terms: vec![x, y.scaled(-1)],
+                rhs: 1,
+            }),
+        });
+
+        model
+    }
+
+    /// Lowers [`implication_model`], fixes `b` to `condition` if it is provided, and returns the
+    /// solutions as assignments to `[x, y, b]`.
+    fn solve_implication_model(condition: Option<bool>) -> Vec<Vec<i32>> {
+        let (mut solver, variables) = implication_model().into_solver(
+            SolverOptions::default(),
+            |_| true,
+            None,
+            &mut Indefinite,
+        );
+        let variables = ["x", "y", "b"]
+            .iter()
+            .map(|name| variables.get_named_variable(name).unwrap())
+            .collect::<Vec<_>>();
+
+        if let Some(condition) = condition {
+            let b = variables[2].clone();
+            let _ = solver.add_clause([solver.get_literal(predicate![b == condition as i32])]);
+        }
+
+        enumerate_solutions(&mut solver, &variables)
+    }
+
+    #[test]
+    fn false_implication_condition_leaves_consequence_unenforced() {
+        let solutions = solve_implication_model(Some(false));
+
+        let expected_solutions = (0..16)
+            .map(|code| vec![code / 4, code % 4, 0])
+            .collect::<Vec<_>>();
+        assert_eq!(solutions, expected_solutions);
+    }
+
+    #[test]
+    fn true_implication_condition_enforces_consequence() {
+        let solutions = solve_implication_model(Some(true));
+
+        let expected_solutions = vec![vec![1, 0, 1]];
+        assert_eq!(solutions, expected_solutions);
+    }
+
+    #[test]
+    fn implication_agrees_with_hand_built_reified_model() {
+        let mut solver = Solver::default();
+        let x = AffineView::from(solver.new_bounded_integer(0, 3));
+        let y = AffineView::from(solver.new_bounded_integer(0, 3));
+        let b = AffineView::from(solver.new_bounded_integer(0, 1));
+        let b_literal = solver.get_literal(predicate![b >= 1]);
+        let tag = NonZero::new(1).unwrap();
+
+        solver
+            .add_constraint(constraints::less_than_or_equals(
+                vec![x.clone(), y.clone()],
+                2,
+            ))
+            .implied_by(b_literal, tag)
+            .expect("no conflict");
+        solver
+            .add_constraint(constraints::equals(vec![x.clone(), y.scaled(-1)], 1))
+            .implied_by(b_literal, tag)
+            .expect("no conflict");
+
+        let expected_solutions = enumerate_solutions(&mut solver, &[x, y, b]);
+        assert_eq!(expected_solutions.len(), 17);
+
+        assert_eq!(solve_implication_model(None), expected_solutions);
+    }
+
+    #[test]
+    fn big_m_implication_agrees_with_half_reification() {
+        let mut solver = Solver::default();
+        let x = AffineView::from(solver.new_bounded_integer(-2, 3));
+        let y = AffineView::from(solver.new_bounded_integer(0, 3));
+        let b = AffineView::from(solver.new_bounded_integer(0, 1));
+        let tag = NonZero::new(1).unwrap();
+
+        // b -> x + y <= 2 and b -> x - y = 1, where the equality is split into two inequalities
+        for (terms, rhs) in [
+            (vec![x.clone(), y.clone()], 2),
+            (vec![x.clone(), y.scaled(-1)], 1),
+            (vec![x.scaled(-1), y.clone()], -1),
+        ] {
+            let (terms, rhs) = big_m_less_than_or_equals(terms, rhs, b.clone(), &solver)
+                .expect("the constraint can be violated");
+            solver
+                .add_constraint(constraints::less_than_or_equals(terms, rhs))
+                .post(tag)
+                .expect("no conflict");
+        }
+
+        // The constraint is implied by the bounds, so no big-M constraint is needed
+        assert!(big_m_less_than_or_equals(vec![y.clone()], 3, b.clone(), &solver).is_none());
+
+        let solutions = enumerate_solutions(&mut solver, &[x, y, b]);
+        let mut expected_solutions = (0..24)
+            .map(|code| vec![code / 4 - 2, code % 4, 0])
+            .chain([vec![1, 0, 1]])
+            .collect::<Vec<_>>();
+        expected_solutions.sort();
+        assert_eq!(solutions, expected_solutions);
+    }
+
+    #[test]
+    fn nested_implication_requires_both_conditions() {
+        let mut model = Model::default();
+        let x = model.new_interval_variable("x", 0, 3);
+        let a = model.new_interval_variable("a", 0, 1);
+        let b = model.new_interval_variable("b", 0, 1);
+
+        model.add_constraint(Constraint::Implication {
+            condition: a,
+            consequence: Box::new(Constraint::Implication {
+                condition: b,
+                consequence: Box::new(Constraint::LinearLessEqual {
+                    terms: vec![x],
+                    rhs: 0,
+                }),
+            }),
+        });
+
+        let (mut solver, variables) =
+            model.into_solver(SolverOptions::default(), |_| true, None, &mut Indefinite);
+        let variables = ["x", "a", "b"]
+            .iter()
+            .map(|name| variables.get_named_variable(name).unwrap())
+            .collect::<Vec<_>>();
+
+        let solutions = enumerate_solutions(&mut solver, &variables);
+        let expected_solutions = (0..16)
+            .map(|code| vec![code / 4, code / 2 % 2, code % 2])
+            .filter(|solution| solution[1] == 0 || solution[2] == 0 || solution[0] == 0)
+            .collect::<Vec<_>>();
+        assert_eq!(solutions, expected_solutions);
+    }
+
+    #[test]
+    fn invalid_implications_are_reported() {
+        let mut model = Model::default();
+        let x = model.new_interval_variable("x", 0, 3);
+        let successors = model.new_interval_variable_array("s", 1, 2, 2);
+        let successors = successors.as_array(&model).collect::<Vec<_>>();
+        let b = model.new_interval_variable("b", 0, 1);
+
+        model.add_constraint(Constraint::Implication {
+            condition: x,
+            consequence: Box::new(Constraint::LinearLessEqual {
+                terms: vec![x],
+                rhs: 1,
+            }),
+        });
+        model.add_constraint(Constraint::Implication {
+            condition: b,
+            consequence: Box::new(Constraint::Circuit(successors)),
+        });
+        // The condition is 1 - b, which is also a 0-1 variable
+        model.add_constraint(Constraint::Implication {
+            condition: b.scaled(-1).offset(1),
+            consequence: Box::new(Constraint::LinearLessEqual {
+                terms: vec![x],
+                rhs: 1,
+            }),
+        });
+
+        let errors = model.validate().unwrap_err();
+        assert_eq!(
+            errors,
+            vec![
+                ModelError::NonBooleanCondition {
+                    name: "x".to_owned(),
+                    lower_bound: 0,
+                    upper_bound: 3,
+                },
+                ModelError::UnsupportedImplication {
+                    constraint: "circuit".to_owned(),
+                },
+            ]
+        );
+        assert!(errors[0].to_string().contains("'x'"));
+    }
+}
@@ -14,7 +14,7 @@ pub(crate) fn verify_unsat(state: CheckingState) -> anyhow::Result<()> {
 
 /// Verify the conclusion that the given bound is the optimal value. If we cannot conclude this
 /// bound is optimal yet, an error is returned.
-#[allow(unused_variables, reason = "to be implemented by students")]
+#[allow(unused, reason = "to be implemented by students")]
 pub(crate) fn verify_optimal(state: CheckingState, bound: Atomic) -> anyhow::Result<()> {
     // The state will have accumulated nogoods from all the combine steps encountered in the proof.
     // Those nogoods should collectively imply that the given bound is true. This can be tested by
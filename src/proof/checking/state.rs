@@ -299,7 +299,7 @@ fn to_integer_predicate(
 
 impl Drop for CheckingContext<'_> {
     fn drop(&mut self) {
-        let _ = self.assignment.synchronise(0);
+        self.assignment.synchronise(0, |_, _| {});
     }
 }
 
@@ -11,12 +11,15 @@ use drcp_format::AtomicConstraint;
 use drcp_format::Comparison;
 use drcp_format::IntAtomicConstraint;
 use drcp_format::LiteralDefinitions;
+use log::warn;
 
 use crate::basic_types::KeyedVec;
 use crate::engine::cp::AssignmentsInteger;
 use crate::engine::cp::VariableLiteralMappings;
 use crate::engine::sat::AssignmentsPropositional;
 use crate::engine::VariableNames;
+#[cfg(doc)]
+use crate::options::SolverOptions;
 use crate::predicates::IntegerPredicate;
 use crate::variables::Literal;
 use crate::variables::PropositionalVariable;
@@ -26,6 +29,22 @@ use crate::variables::PropositionalVariable;
 pub struct Proof {
     /// The proof, if one is being logged.
     proof_impl: Option<ProofImpl>,
+    /// The statistics of the proof at the moment it was concluded.
+    concluded_statistics: Option<ProofStatistics>,
+}
+
+/// Statistics on the size of the proof which is being logged.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ProofStatistics {
+    /// The number of inference steps which have been written to the proof.
+    pub inference_steps: u64,
+    /// The number of nogood steps which have been written to the proof.
+    pub nogood_steps: u64,
+    /// The number of bytes of the proof which have been written, excluding the conclusion.
+    pub bytes_written: u64,
+    /// Whether the proof exceeded [`SolverOptions::max_proof_bytes`]; if this is the case, then
+    /// no further steps are logged and the proof is not concluded.
+    pub is_truncated: bool,
 }
 
 /// A dummy step ID that is returned if no proof is being logged.
@@ -42,10 +61,29 @@ impl Proof {
                 ),
                 lits,
                 full_proof: false,
+                max_bytes: None,
+                is_truncated: false,
             }),
+            concluded_statistics: None,
+        }
+    }
+
+    /// Sets the number of bytes after which no more steps are logged; see
+    /// [`SolverOptions::max_proof_bytes`].
+    pub(crate) fn set_max_bytes(&mut self, max_bytes: Option<u64>) {
+        if let Some(proof) = self.proof_impl.as_mut() {
+            proof.max_bytes = max_bytes;
         }
     }
 
+    /// Returns the [`ProofStatistics`] of the proof, or [`None`] if no proof is being logged.
+    pub(crate) fn statistics(&self) -> Option<ProofStatistics> {
+        self.proof_impl
+            .as_ref()
+            .map(ProofImpl::statistics)
+            .or(self.concluded_statistics)
+    }
+
     /// Conclude the proof with the given bound on the objective variable.
     pub(crate) fn conclude_proof_optimal(
         &mut self,
@@ -54,6 +92,7 @@ impl Proof {
         variable_literal_mapping: &VariableLiteralMappings,
     ) {
         if let Some(proof) = self.proof_impl.take() {
+            self.concluded_statistics = Some(proof.statistics());
             let _ = proof.optimal(bound, variable_names, variable_literal_mapping);
         }
     }
@@ -65,10 +104,19 @@ impl Proof {
         variable_literal_mapping: &VariableLiteralMappings,
     ) {
         if let Some(proof) = self.proof_impl.take() {
+            self.concluded_statistics = Some(proof.statistics());
             let _ = proof.unsat(variable_names, variable_literal_mapping);
         }
     }
 
+    /// Returns whether steps which are logged are written to the proof; this can be used to avoid
+    /// computing steps which are discarded anyway.
+    pub(crate) fn is_logging(&self) -> bool {
+        self.proof_impl
+            .as_ref()
+            .is_some_and(|proof| !proof.is_truncated)
+    }
+
     /// Log a nogood to the proof. `literals` should be treated as the conjunction
     /// `/\literals -> false`.
     pub(crate) fn log_nogood(
@@ -82,6 +130,27 @@ impl Proof {
             Ok(DUMMY_STEP_ID)
         }
     }
+
+    /// Log an inference to the proof, stating that `/\premises -> propagated` is implied by the
+    /// constraint with the given tag; if `propagated` is [`None`], then the premises are
+    /// conflicting.
+    ///
+    /// The `label` identifies the inference rule (see [`inference_labels`]).
+    ///
+    /// [`inference_labels`]: crate::proof::inference_labels
+    pub(crate) fn log_inference(
+        &mut self,
+        constraint_tag: Option<NonZero<u32>>,
+        label: Option<&str>,
+        premises: impl IntoIterator<Item = Literal>,
+        propagated: Option<Literal>,
+    ) -> std::io::Result<StepId> {
+        if let Some(proof) = self.proof_impl.as_mut() {
+            proof.log_inference(constraint_tag, label, premises, propagated)
+        } else {
+            Ok(DUMMY_STEP_ID)
+        }
+    }
 }
 
 /// The actual implementation of the proof log.
@@ -92,19 +161,75 @@ struct ProofImpl {
     lits: PathBuf,
     /// True when we are logging the full proof, with inferences and hints.
     full_proof: bool,
+    /// The number of bytes after which no more steps are logged.
+    max_bytes: Option<u64>,
+    /// True when the proof exceeded `max_bytes`.
+    is_truncated: bool,
 }
 
 impl ProofImpl {
     /// Log a nogood to the proof. `literals` should be treated as the conjunction
     /// `/\literals -> false`.
+    ///
+    /// Once the proof has exceeded `max_bytes`, the nogood is not logged and a dummy step ID is
+    /// returned.
     pub(crate) fn log_nogood(
         &mut self,
         literals: impl IntoIterator<Item = Literal>,
         hints: impl IntoIterator<Item = StepId>,
     ) -> std::io::Result<StepId> {
+        if self.is_truncated {
+            return Ok(DUMMY_STEP_ID);
+        }
+
         let hints = if self.full_proof { Some(hints) } else { None };
+        let step_id = self.writer.log_nogood(literals, hints)?;
+        self.check_size();
+
+        Ok(step_id)
+    }
+
+    /// Log an inference to the proof; see [`Proof::log_inference`].
+    ///
+    /// Once the proof has exceeded `max_bytes`, the inference is not logged and a dummy step ID is
+    /// returned.
+    pub(crate) fn log_inference(
+        &mut self,
+        constraint_tag: Option<NonZero<u32>>,
+        label: Option<&str>,
+        premises: impl IntoIterator<Item = Literal>,
+        propagated: Option<Literal>,
+    ) -> std::io::Result<StepId> {
+        if self.is_truncated {
+            return Ok(DUMMY_STEP_ID);
+        }
+
+        let step_id = self
+            .writer
+            .log_inference(constraint_tag, label, premises, propagated)?;
+        self.check_size();
 
-        self.writer.log_nogood(literals, hints)
+        Ok(step_id)
+    }
+
+    /// Marks the proof as truncated if it exceeds `max_bytes`.
+    fn check_size(&mut self) {
+        if self
+            .max_bytes
+            .is_some_and(|max_bytes| self.writer.get_number_of_bytes_written() > max_bytes)
+        {
+            warn!("The proof exceeded its maximum size, no more steps are logged.");
+            self.is_truncated = true;
+        }
+    }
+
+    fn statistics(&self) -> ProofStatistics {
+        ProofStatistics {
+            inference_steps: self.writer.get_number_of_inferences() as u64,
+            nogood_steps: self.writer.get_number_of_nogoods() as u64,
+            bytes_written: self.writer.get_number_of_bytes_written(),
+            is_truncated: self.is_truncated,
+        }
     }
 
     pub(crate) fn unsat(
@@ -112,6 +237,11 @@ impl ProofImpl {
         variable_names: &VariableNames,
         variable_literal_mapping: &VariableLiteralMappings,
     ) -> std::io::Result<()> {
+        if self.is_truncated {
+            warn!("The proof is incomplete, it is not concluded.");
+            return Ok(());
+        }
+
         let literals = self.writer.unsat()?;
         let file = File::create(self.lits)?;
         literals.write(file, variable_names, variable_literal_mapping)
@@ -123,6 +253,11 @@ impl ProofImpl {
         variable_names: &VariableNames,
         variable_literal_mapping: &VariableLiteralMappings,
     ) -> std::io::Result<()> {
+        if self.is_truncated {
+            warn!("The proof is incomplete, it is not concluded.");
+            return Ok(());
+        }
+
         let literals = self.writer.optimal(objective_bound)?;
         let file = File::create(self.lits)?;
         literals.write(file, variable_names, variable_literal_mapping)
@@ -203,6 +338,17 @@ impl ProofLiterals {
         variable_names: &VariableNames,
         variable_literal_mapping: &VariableLiteralMappings,
     ) -> std::io::Result<()> {
+        self.into_definitions(variable_names, variable_literal_mapping)
+            .write(sink)
+    }
+
+    /// Converts the literals which have been given a code into the [`LiteralDefinitions`] which
+    /// can be written to a literal definition file.
+    pub(crate) fn into_definitions(
+        self,
+        variable_names: &VariableNames,
+        variable_literal_mapping: &VariableLiteralMappings,
+    ) -> LiteralDefinitions<String> {
         let entries = self
             .variables
             .into_entries()
@@ -222,7 +368,7 @@ impl ProofLiterals {
             }
         }
 
-        definitions.write(sink)
+        definitions
     }
 
     fn get_next_code(&mut self) -> NonZero<u32> {
@@ -270,7 +416,7 @@ fn atomic_to_integer_predicate(
 fn integer_predicate_to_atomic(
     predicate: IntegerPredicate,
     variable_names: &VariableNames,
-) -> AtomicConstraint<&str> {
+) -> AtomicConstraint<String> {
     match predicate {
         IntegerPredicate::LowerBound {
             domain_id,
@@ -278,7 +424,8 @@ fn integer_predicate_to_atomic(
         } => AtomicConstraint::Int(IntAtomicConstraint {
             name: variable_names
                 .get_int_name(domain_id)
-                .expect("integer domain is unnamed"),
+                .expect("integer domain is unnamed")
+                .to_owned(),
             comparison: Comparison::GreaterThanEqual,
             value: lower_bound.into(),
         }),
@@ -288,7 +435,8 @@ fn integer_predicate_to_atomic(
         } => AtomicConstraint::Int(IntAtomicConstraint {
             name: variable_names
                 .get_int_name(domain_id)
-                .expect("integer domain is unnamed"),
+                .expect("integer domain is unnamed")
+                .to_owned(),
             comparison: Comparison::LessThanEqual,
             value: upper_bound.into(),
         }),
@@ -298,7 +446,8 @@ fn integer_predicate_to_atomic(
         } => AtomicConstraint::Int(IntAtomicConstraint {
             name: variable_names
                 .get_int_name(domain_id)
-                .expect("integer domain is unnamed"),
+                .expect("integer domain is unnamed")
+                .to_owned(),
             comparison: Comparison::NotEqual,
             value: not_equal_constant.into(),
         }),
@@ -308,7 +457,8 @@ fn integer_predicate_to_atomic(
         } => AtomicConstraint::Int(IntAtomicConstraint {
             name: variable_names
                 .get_int_name(domain_id)
-                .expect("integer domain is unnamed"),
+                .expect("integer domain is unnamed")
+                .to_owned(),
             comparison: Comparison::Equal,
             value: equality_constant.into(),
         }),
@@ -2,7 +2,13 @@ pub(crate) mod checking;
 pub(crate) mod processing;
 
 mod logging;
+pub use logging::ProofStatistics;
 pub(crate) use logging::*;
+pub use processing::process_scaffold;
+pub use processing::ProcessingError;
+pub use processing::ProcessingReport;
+
+pub use crate::engine::TagArtifacts;
 
 /// The string labels for the different inference rules implemented by the various propagators.
 pub(crate) mod inference_labels {
@@ -12,4 +18,18 @@ pub(crate) mod inference_labels {
     pub(crate) const ALL_DIFFERENT: &str = "all_different";
     pub(crate) const TIME_TABLE: &str = "time_table";
     pub(crate) const PREVENT_AND_CHECK: &str = "prevent_and_check";
+
+    /// Returns the label of the inferences made by the propagator with the given name, or
+    /// [`None`] if the inferences of the propagator are not supported in proofs.
+    pub(crate) fn of_propagator(name: &str) -> Option<&'static str> {
+        match name {
+            "LinearLeq" | "BinaryLeq" => Some(LINEAR),
+            "Element" => Some(ELEMENT),
+            "Maximum" => Some(MAXIMUM),
+            "AllDifferent" => Some(ALL_DIFFERENT),
+            "ForwardCheckingCircuit" => Some(PREVENT_AND_CHECK),
+            "TimeTable" => Some(TIME_TABLE),
+            _ => None,
+        }
+    }
 }
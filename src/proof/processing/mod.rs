@@ -1,60 +1,135 @@
-use std::fs::File;
 use std::io::Read;
 use std::io::Write;
-use std::path::PathBuf;
+use std::num::NonZero;
 
 mod processor;
 mod rp_engine;
 
-use anyhow::Context;
+use drcp_format::reader::DrcpError;
+use drcp_format::reader::LiteralAtomicMap;
 use drcp_format::reader::ProofReader;
 use drcp_format::steps::Conclusion;
+use drcp_format::steps::Step;
 use drcp_format::writer::ProofWriter;
 use drcp_format::Format;
+use drcp_format::LiteralDefinitions;
 pub(crate) use processor::Processor;
+use thiserror::Error;
 
+use crate::model::Model;
 use crate::proof::ProofLiterals;
 use crate::variables::Literal;
 
-/// Processes a proof. This means the nogoods are trimmed, and the inferences are introduced to
-/// make a complete proof.
-pub(crate) fn process_proof<R: Read>(
-    mut processor: Processor,
-    reader: ProofReader<R, ProofLiterals>,
-    output: PathBuf,
-) -> anyhow::Result<()> {
-    // To process the proof, we do the following:
-    // 1. Trim the redundant nogoods.
-    // 2. Introduce the inferences for the remaining nogoods.
-    // 3. Finalize the proof with the correct conclusion.
+/// The statistics of a proof which was processed by [`process_scaffold`], together with the
+/// literal definitions of the processed proof.
+#[derive(Clone, Debug)]
+pub struct ProcessingReport {
+    /// The number of steps in the scaffold, excluding the conclusion.
+    pub input_steps: usize,
+    /// The number of nogoods which remain after trimming the scaffold.
+    pub trimmed_steps: usize,
+    /// The number of inferences which were introduced into the processed proof.
+    pub introduced_inferences: usize,
+    /// The definitions of the literals used in the processed proof; these should be written to
+    /// the `.lits` file accompanying the proof.
+    pub literal_definitions: LiteralDefinitions<String>,
+}
 
-    // 1. Trim the nogoods.
-    let (nogoods, conclusion) = trim(&mut processor, reader)?;
+/// The errors which can be reported by [`process_scaffold`] when the scaffold cannot be
+/// processed.
+#[derive(Debug, Error)]
+pub enum ProcessingError {
+    #[error("Failed to parse the scaffold: {0}")]
+    UnparseableScaffold(#[from] DrcpError),
+    #[error("The scaffold does not contain a conclusion")]
+    MissingConclusion,
+    #[error(
+        "The literal definitions refer to '{0}' which is not an integer variable of the model"
+    )]
+    UnknownVariable(String),
+    #[error("The objective bound {0} in the conclusion does not have a literal definition")]
+    ConclusionMismatch(NonZero<i32>),
+}
 
-    println!("%% nogoodsAfterTrimming={}", nogoods.len());
+/// Processes a proof scaffold into a full proof.
+///
+/// The pipeline consists of the following steps:
+/// 1. A processor is created from the `model`, and the objective bound of the conclusion (if any)
+///    is negated in it.
+/// 2. The redundant nogoods of the scaffold are trimmed.
+/// 3. The inferences for the remaining nogoods are introduced, and the proof is written to
+///    `output` in the given `format`.
+///
+/// The literal definitions of the processed proof are part of the returned [`ProcessingReport`].
+/// If the scaffold does not fit the model, then the returned error contains a
+/// [`ProcessingError`].
+pub fn process_scaffold(
+    model: &Model,
+    mut scaffold: impl Read,
+    definitions: LiteralDefinitions<String>,
+    output: impl Write,
+    format: Format,
+) -> anyhow::Result<ProcessingReport> {
+    // The scaffold is read twice; the conclusion has to be known before trimming.
+    let mut buffer = Vec::new();
+    let _ = scaffold
+        .read_to_end(&mut buffer)
+        .map_err(|error| ProcessingError::UnparseableScaffold(error.into()))?;
+    let (input_steps, scaffold_conclusion) = read_conclusion(&buffer)?;
 
-    // 2. Introduce inferences.
-    let file = File::create(&output)
-        .with_context(|| format!("Failed to create proof file {}.", output.display()))?;
-    let mut writer = ProofWriter::new(Format::Text, file, ProofLiterals::default());
-    introduce_inferences(&mut processor, nogoods, &mut writer)?;
+    // 1. Create the processor.
+    let mut processor = Processor::from(model.clone());
+    if let Some(name) = processor.find_unknown_variable(&definitions) {
+        return Err(ProcessingError::UnknownVariable(name).into());
+    }
+    if let Conclusion::Optimal(code) = scaffold_conclusion {
+        if definitions.get(code.unsigned_abs()).is_none() {
+            return Err(ProcessingError::ConclusionMismatch(code).into());
+        }
+    }
+
+    let literals = processor.initialise_proof_literals(definitions);
+    if let Conclusion::Optimal(code) = scaffold_conclusion {
+        processor.set_objective_bound(literals.to_atomic(code));
+    }
 
-    println!(
-        "%% numberOfInferences={}",
-        writer.get_number_of_inferences()
-    );
+    // 2. Trim the nogoods.
+    let reader = ProofReader::new(buffer.as_slice(), literals);
+    let (nogoods, conclusion) = trim(&mut processor, reader)?;
+    let trimmed_steps = nogoods.len();
+
+    // 3. Introduce inferences and finalize the proof.
+    let mut writer = ProofWriter::new(format, output, ProofLiterals::default());
+    introduce_inferences(&mut processor, nogoods, &mut writer)?;
+    let introduced_inferences = writer.get_number_of_inferences();
 
-    // 3. Finalize the proof
     let literals = match conclusion {
         Conclusion::Unsatisfiable => writer.unsat()?,
         Conclusion::Optimal(bound) => writer.optimal(bound)?,
     };
-    let literals_file_path = output.with_extension("lits");
-    let literals_file = File::create(&literals_file_path)
-        .with_context(|| format!("Failed to create file {}", literals_file_path.display()))?;
-    processor.write_proof_literals(literals, literals_file)?;
 
-    Ok(())
+    Ok(ProcessingReport {
+        input_steps,
+        trimmed_steps,
+        introduced_inferences,
+        literal_definitions: processor.proof_literal_definitions(literals),
+    })
+}
+
+/// Returns the number of steps before the conclusion of the scaffold, together with the
+/// conclusion in terms of the literal codes.
+fn read_conclusion(scaffold: &[u8]) -> Result<(usize, Conclusion<NonZero<i32>>), ProcessingError> {
+    let mut reader = ProofReader::new(scaffold, |literal: NonZero<i32>| literal);
+    let mut num_steps = 0;
+
+    while let Some(step) = reader.next_step()? {
+        match step {
+            Step::Conclusion(conclusion) => return Ok((num_steps, conclusion)),
+            _ => num_steps += 1,
+        }
+    }
+
+    Err(ProcessingError::MissingConclusion)
 }
 
 /// Reads the nogoods from the scaffold, and returns a list of nogoods that does not contain
@@ -1,8 +1,9 @@
-use std::fs::File;
 use std::num::NonZero;
 
 use drcp_format::steps::Nogood;
 use drcp_format::steps::StepId;
+use drcp_format::AtomicConstraint;
+use drcp_format::LiteralDefinitions;
 
 use super::rp_engine::ConflictReason;
 use super::rp_engine::RpClauseHandle;
@@ -26,11 +27,14 @@ impl From<Model> for Processor {
             SolverOptions::default(),
             |globals| match globals {
                 crate::model::Globals::DfsCircuit
-                | crate::model::Globals::EnergeticReasoningCumulative => false,
+                | crate::model::Globals::EnergeticReasoningCumulative
+                | crate::model::Globals::Inverse => false,
                 crate::model::Globals::Element
                 | crate::model::Globals::AllDifferent
                 | crate::model::Globals::Cumulative
                 | crate::model::Globals::Maximum
+                | crate::model::Globals::Count
+                | crate::model::Globals::Disjunctive
                 | crate::model::Globals::ForwardCheckingCircuit
                 | crate::model::Globals::TimeTableCumulative => true,
             },
@@ -100,7 +104,7 @@ impl Processor {
     /// Creates a new instance of [`ProofLiterals`] linked to the state in the processor.
     pub(crate) fn initialise_proof_literals(
         &self,
-        definitions: drcp_format::LiteralDefinitions<String>,
+        definitions: LiteralDefinitions<String>,
     ) -> ProofLiterals {
         ProofLiterals::new(
             definitions,
@@ -111,18 +115,37 @@ impl Processor {
         )
     }
 
-    /// Writes the literal mapping to the given file.
-    pub(crate) fn write_proof_literals(
+    /// Returns the literal definitions of the given [`ProofLiterals`], which should be written
+    /// next to the processed proof.
+    pub(crate) fn proof_literal_definitions(
         &self,
         literals: ProofLiterals,
-        file: File,
-    ) -> anyhow::Result<()> {
-        literals.write(
-            file,
+    ) -> LiteralDefinitions<String> {
+        literals.into_definitions(
             &self.engine.solver.variable_names,
             &self.engine.solver.variable_literal_mappings,
-        )?;
-        Ok(())
+        )
+    }
+
+    /// Returns the name of a variable in the given definitions which is not an integer variable
+    /// of the model, or [`None`] if all the definitions refer to the model.
+    pub(crate) fn find_unknown_variable(
+        &self,
+        definitions: &LiteralDefinitions<String>,
+    ) -> Option<String> {
+        definitions
+            .iter()
+            .flat_map(|(_, atomics)| atomics)
+            .find_map(|atomic| match atomic {
+                AtomicConstraint::Int(atomic) => self
+                    .engine
+                    .solver
+                    .variable_names
+                    .get_domain_by_name(&atomic.name)
+                    .is_none()
+                    .then(|| atomic.name.clone()),
+                AtomicConstraint::Bool(atomic) => Some(atomic.name.clone()),
+            })
     }
 
     fn map_reasons(&self, reasons: Vec<ConflictReason>) -> ProcessorConflict {
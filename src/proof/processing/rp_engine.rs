@@ -13,7 +13,6 @@
 //! and given that Pumpkin implements such an engine, the [`RpEngine`] exposes an API to verify the
 //! RP property of clauses.
 
-use std::collections::VecDeque;
 use std::num::NonZero;
 
 use log::warn;
@@ -286,6 +285,9 @@ impl RpEngine {
 
             if reference.is_null() {
                 continue;
+            } else if reference.is_virtual_binary_clause() {
+                let clause = reference.as_clause_reference();
+                let _ = should_explain.insert(!clause.get_virtual_binary_clause_literal());
             } else if reference.is_clause() {
                 let clause = reference.as_clause_reference();
                 if let Some(handle) = self.rp_allocated_clauses.get(&clause) {
@@ -365,7 +367,10 @@ impl RpEngine {
         let to_explain_vec = match conflict_info {
             StoredConflictInfo::VirtualBinaryClause { .. } => unreachable!(),
             StoredConflictInfo::Propagation { reference, literal } => {
-                if reference.is_clause() {
+                if reference.is_virtual_binary_clause() {
+                    let clause = reference.as_clause_reference();
+                    vec![!*literal, !clause.get_virtual_binary_clause_literal()]
+                } else if reference.is_clause() {
                     let clause = reference.as_clause_reference();
                     if let Some(handle) = self.rp_allocated_clauses.get(&clause) {
                         reasons.push(ConflictReason::Clause(*handle));
@@ -486,16 +491,8 @@ impl Brancher for DummyBrancher {
 }
 
 fn name_to_inference_label(name: &str) -> &'static str {
-    match name {
-        "LinearLeq" => inference_labels::LINEAR,
-        "Element" => inference_labels::ELEMENT,
-        "Maximum" => inference_labels::MAXIMUM,
-        "AllDifferent" => inference_labels::ALL_DIFFERENT,
-        "ForwardCheckingCircuit" => inference_labels::PREVENT_AND_CHECK,
-        "TimeTable" => inference_labels::TIME_TABLE,
-
-        unknown => panic!("propagator {unknown} not supported in the proof processor"),
-    }
+    inference_labels::of_propagator(name)
+        .unwrap_or_else(|| panic!("propagator {name} not supported in the proof processor"))
 }
 
 #[cfg(test)]
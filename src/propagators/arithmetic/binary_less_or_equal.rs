@@ -0,0 +1,101 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::conjunction;
+use crate::engine::cp::domain_events::DomainEvents;
+use crate::engine::cp::propagation::PropagationContext;
+use crate::engine::cp::propagation::PropagationContextMut;
+use crate::engine::cp::propagation::Propagator;
+use crate::engine::cp::propagation::PropagatorInitialisationContext;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::predicate;
+use crate::variables::IntegerVariable;
+
+/// Propagator for the constraint `lhs + offset <= rhs`.
+///
+/// This is a special case of [`LinearLessOrEqualPropagator`] which occurs very frequently (e.g.
+/// as precedence constraints in scheduling problems); every propagation only requires two bound
+/// updates, each of which is explained by a single predicate.
+///
+/// [`LinearLessOrEqualPropagator`]: super::linear_less_or_equal::LinearLessOrEqualPropagator
+#[derive(Debug)]
+pub(crate) struct BinaryLessOrEqualPropagator<Var> {
+    lhs: Var,
+    rhs: Var,
+    offset: i32,
+}
+
+impl<Var> BinaryLessOrEqualPropagator<Var> {
+    pub(crate) fn new(lhs: Var, rhs: Var, offset: i32) -> Self {
+        Self { lhs, rhs, offset }
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Propagator for BinaryLessOrEqualPropagator<Var> {
+    fn name(&self) -> &str {
+        "BinaryLeq"
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        // Only the lower bound of `lhs` and the upper bound of `rhs` can lead to propagations
+        context.register(self.lhs.clone(), DomainEvents::LOWER_BOUND);
+        context.register(self.rhs.clone(), DomainEvents::UPPER_BOUND);
+
+        Ok(())
+    }
+
+    fn is_entailed(&self, context: PropagationContext) -> bool {
+        context.upper_bound(&self.lhs) as i64 + self.offset as i64
+            <= context.lower_bound(&self.rhs) as i64
+    }
+
+    fn detect_inconsistency(
+        &self,
+        context: PropagationContext,
+    ) -> Option<PropositionalConjunction> {
+        let lhs_lower_bound = context.lower_bound(&self.lhs);
+        let rhs_upper_bound = context.upper_bound(&self.rhs);
+
+        if lhs_lower_bound as i64 + self.offset as i64 > rhs_upper_bound as i64 {
+            Some(conjunction!(
+                [self.lhs >= lhs_lower_bound] & [self.rhs <= rhs_upper_bound]
+            ))
+        } else {
+            None
+        }
+    }
+
+    fn propagate(&self, mut context: PropagationContextMut) -> PropagationStatusCP {
+        // If the constraint is violated, then the derived bounds could lie outside of the `i32`
+        // range; hence, the conflict is reported directly.
+        if let Some(conflict) = self.detect_inconsistency(context.as_readonly()) {
+            return Err(conflict.into());
+        }
+
+        // The new bounds are computed in 64-bit arithmetic; since the constraint is not violated,
+        // a bound outside of the `i32` range is weaker than the current bound of the variable
+        let lhs_lower_bound = context.lower_bound(&self.lhs);
+        let rhs_lower_bound = lhs_lower_bound as i64 + self.offset as i64;
+        if rhs_lower_bound > context.lower_bound(&self.rhs) as i64 {
+            context.set_lower_bound(
+                &self.rhs,
+                rhs_lower_bound as i32,
+                PropositionalConjunction::from(predicate![self.lhs >= lhs_lower_bound]),
+            )?;
+        }
+
+        let rhs_upper_bound = context.upper_bound(&self.rhs);
+        let lhs_upper_bound = rhs_upper_bound as i64 - self.offset as i64;
+        if lhs_upper_bound < context.upper_bound(&self.lhs) as i64 {
+            context.set_upper_bound(
+                &self.lhs,
+                lhs_upper_bound as i32,
+                PropositionalConjunction::from(predicate![self.rhs <= rhs_upper_bound]),
+            )?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,159 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::conjunction;
+use crate::engine::cp::domain_events::DomainEvents;
+use crate::engine::cp::propagation::PropagationContextMut;
+use crate::engine::cp::propagation::Propagator;
+use crate::engine::cp::propagation::PropagatorInitialisationContext;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::variables::IntegerVariable;
+
+/// Bounds-consistent propagator for the constraint `a * b = c`.
+///
+/// The bounds of `c` are the minimum and maximum of the products of the bounds of `a` and `b`. The
+/// bounds of `a` are obtained by dividing the bounds of `c` by the bounds of `b` (and vice versa),
+/// which is only done when the domain of the divisor does not contain 0; otherwise any value of
+/// `a` is supported when `c` can be 0.
+#[derive(Debug)]
+pub(crate) struct IntegerMultiplicationPropagator<VA, VB, VC> {
+    a: VA,
+    b: VB,
+    c: VC,
+}
+
+impl<VA, VB, VC> IntegerMultiplicationPropagator<VA, VB, VC> {
+    pub(crate) fn new(a: VA, b: VB, c: VC) -> Self {
+        IntegerMultiplicationPropagator { a, b, c }
+    }
+}
+
+impl<VA, VB, VC> Propagator for IntegerMultiplicationPropagator<VA, VB, VC>
+where
+    VA: IntegerVariable + 'static,
+    VB: IntegerVariable + 'static,
+    VC: IntegerVariable + 'static,
+{
+    fn name(&self) -> &str {
+        "IntTimes"
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        context.register(self.a.clone(), DomainEvents::BOUNDS);
+        context.register(self.b.clone(), DomainEvents::BOUNDS);
+        context.register(self.c.clone(), DomainEvents::BOUNDS);
+
+        Ok(())
+    }
+
+    fn propagate(&self, mut context: PropagationContextMut) -> PropagationStatusCP {
+        let a = &self.a;
+        let b = &self.b;
+        let c = &self.c;
+
+        // c = a * b
+        let (a_min, a_max) = (context.lower_bound(a), context.upper_bound(a));
+        let (b_min, b_max) = (context.lower_bound(b), context.upper_bound(b));
+        let (c_min, c_max) = bounds_of_product(a_min, a_max, b_min, b_max);
+
+        let reason = conjunction!([a >= a_min] & [a <= a_max] & [b >= b_min] & [b <= b_max]);
+        context.set_lower_bound(c, c_min, reason.clone())?;
+        context.set_upper_bound(c, c_max, reason)?;
+
+        // a = c / b
+        let (b_min, b_max) = (context.lower_bound(b), context.upper_bound(b));
+        let (c_min, c_max) = (context.lower_bound(c), context.upper_bound(c));
+        if let Some((new_a_min, new_a_max)) = bounds_of_quotient(c_min, c_max, b_min, b_max) {
+            let reason = conjunction!([c >= c_min] & [c <= c_max] & [b >= b_min] & [b <= b_max]);
+            context.set_lower_bound(a, new_a_min, reason.clone())?;
+            context.set_upper_bound(a, new_a_max, reason)?;
+        }
+
+        // b = c / a
+        let (a_min, a_max) = (context.lower_bound(a), context.upper_bound(a));
+        let (c_min, c_max) = (context.lower_bound(c), context.upper_bound(c));
+        if let Some((new_b_min, new_b_max)) = bounds_of_quotient(c_min, c_max, a_min, a_max) {
+            let reason = conjunction!([c >= c_min] & [c <= c_max] & [a >= a_min] & [a <= a_max]);
+            context.set_lower_bound(b, new_b_min, reason.clone())?;
+            context.set_upper_bound(b, new_b_max, reason)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the bounds of `x * y` for `x` in `[x_min, x_max]` and `y` in `[y_min, y_max]`.
+fn bounds_of_product(x_min: i32, x_max: i32, y_min: i32, y_max: i32) -> (i32, i32) {
+    let products = [
+        x_min as i64 * y_min as i64,
+        x_min as i64 * y_max as i64,
+        x_max as i64 * y_min as i64,
+        x_max as i64 * y_max as i64,
+    ];
+
+    (
+        clamp_to_i32(*products.iter().min().unwrap()),
+        clamp_to_i32(*products.iter().max().unwrap()),
+    )
+}
+
+/// Returns the bounds of the integer values `x` such that `x * divisor` is in
+/// `[dividend_min, dividend_max]` for some `divisor` in `[divisor_min, divisor_max]`.
+///
+/// If the divisor can be 0, then no bounds are derived and [`None`] is returned.
+fn bounds_of_quotient(
+    dividend_min: i32,
+    dividend_max: i32,
+    divisor_min: i32,
+    divisor_max: i32,
+) -> Option<(i32, i32)> {
+    if divisor_min <= 0 && divisor_max >= 0 {
+        return None;
+    }
+
+    // The divisor has a single sign, so the quotient is monotone in both arguments and its
+    // extreme values are attained at the corners of the domains
+    let corners = [
+        (dividend_min, divisor_min),
+        (dividend_min, divisor_max),
+        (dividend_max, divisor_min),
+        (dividend_max, divisor_max),
+    ];
+
+    let lower_bound = corners
+        .iter()
+        .map(|&(dividend, divisor)| div_ceil(dividend as i64, divisor as i64))
+        .min()
+        .unwrap();
+    let upper_bound = corners
+        .iter()
+        .map(|&(dividend, divisor)| div_floor(dividend as i64, divisor as i64))
+        .max()
+        .unwrap();
+
+    Some((clamp_to_i32(lower_bound), clamp_to_i32(upper_bound)))
+}
+
+fn div_floor(dividend: i64, divisor: i64) -> i64 {
+    let quotient = dividend / divisor;
+    if dividend % divisor != 0 && (dividend < 0) != (divisor < 0) {
+        quotient - 1
+    } else {
+        quotient
+    }
+}
+
+fn div_ceil(dividend: i64, divisor: i64) -> i64 {
+    let quotient = dividend / divisor;
+    if dividend % divisor != 0 && (dividend < 0) == (divisor < 0) {
+        quotient + 1
+    } else {
+        quotient
+    }
+}
+
+fn clamp_to_i32(value: i64) -> i32 {
+    value.clamp(i32::MIN as i64, i32::MAX as i64) as i32
+}
@@ -1,3 +1,5 @@
+use std::rc::Rc;
+
 use crate::basic_types::PropagationStatusCP;
 use crate::basic_types::PropositionalConjunction;
 use crate::engine::cp::domain_events::DomainEvents;
@@ -12,22 +14,28 @@ use crate::variables::IntegerVariable;
 /// Propagator for the constraint `reif => \sum x_i <= c`.
 #[derive(Debug)]
 pub(crate) struct LinearLessOrEqualPropagator<Var> {
-    terms: Box<[Var]>,
+    /// The terms are shared with the lazy explanations of the propagations.
+    terms: Rc<[Var]>,
     rhs: i32,
     // TODO: you can add more fields here!
 }
 
 impl<Var> LinearLessOrEqualPropagator<Var> {
     pub(crate) fn new(terms: Box<[Var]>, rhs: i32) -> Self {
-        Self { terms, rhs }
+        Self {
+            terms: terms.into(),
+            rhs,
+        }
     }
 }
 
 impl<Var: IntegerVariable> LinearLessOrEqualPropagator<Var> {
-    fn get_optimistic_lhs(&self, context: PropagationContext<'_>) -> i32 {
+    /// The sum of the lower bounds of the terms; this is computed in 64-bit arithmetic since it
+    /// can exceed the `i32` range even though every individual bound fits.
+    fn get_optimistic_lhs(&self, context: PropagationContext<'_>) -> i64 {
         self.terms
             .iter()
-            .map(|term| context.lower_bound(term))
+            .map(|term| context.lower_bound(term) as i64)
             .sum()
     }
 }
@@ -48,13 +56,23 @@ impl<Var: IntegerVariable + 'static> Propagator for LinearLessOrEqualPropagator<
         Ok(())
     }
 
+    fn is_entailed(&self, context: PropagationContext) -> bool {
+        let pessimistic_lhs = self
+            .terms
+            .iter()
+            .map(|term| context.upper_bound(term) as i64)
+            .sum::<i64>();
+
+        pessimistic_lhs <= self.rhs as i64
+    }
+
     fn detect_inconsistency(
         &self,
         context: PropagationContext,
     ) -> Option<PropositionalConjunction> {
         let optimistic_lhs = self.get_optimistic_lhs(context);
 
-        if optimistic_lhs > self.rhs {
+        if optimistic_lhs > self.rhs as i64 {
             let conflict = self
                 .terms
                 .iter()
@@ -71,24 +89,41 @@ impl<Var: IntegerVariable + 'static> Propagator for LinearLessOrEqualPropagator<
     }
 
     fn propagate(&self, mut context: PropagationContextMut) -> PropagationStatusCP {
+        // If the constraint is violated, then the bound derived below for a term could lie below
+        // the `i32` range; hence, the conflict is reported directly.
+        if let Some(conflict) = self.detect_inconsistency(context.as_readonly()) {
+            return Err(conflict.into());
+        }
+
         let optimistic_lhs = self.get_optimistic_lhs(context.as_readonly());
 
+        // The explanations are only computed when they are needed during conflict analysis, at
+        // which point the lower bounds could have changed; hence, we store the lower bounds at
+        // the time of propagation.
+        let lower_bounds: Rc<[i32]> = self
+            .terms
+            .iter()
+            .map(|term| context.lower_bound(term))
+            .collect();
+
         for (i, term) in self.terms.iter().enumerate() {
-            let bound = self.rhs - (optimistic_lhs - context.lower_bound(term));
-
-            if context.upper_bound(term) >= bound {
-                let reason: PropositionalConjunction = self
-                    .terms
-                    .iter()
-                    .enumerate()
-                    .filter_map(|(j, x_j)| {
-                        if j != i {
-                            Some(predicate![x_j >= context.lower_bound(x_j)])
-                        } else {
-                            None
-                        }
-                    })
-                    .collect();
+            // The bound is at least the lower bound of the term, as the constraint is not
+            // violated; if it exceeds the `i32` range, then it cannot tighten the upper bound.
+            let bound = self.rhs as i64 - (optimistic_lhs - lower_bounds[i] as i64);
+
+            if context.upper_bound(term) as i64 >= bound {
+                let bound = bound as i32;
+                let terms = Rc::clone(&self.terms);
+                let lower_bounds = Rc::clone(&lower_bounds);
+                let reason = move |_: &PropagationContext| -> PropositionalConjunction {
+                    terms
+                        .iter()
+                        .zip(lower_bounds.iter())
+                        .enumerate()
+                        .filter(|&(j, _)| j != i)
+                        .map(|(_, (x_j, &lower_bound))| predicate![x_j >= lower_bound])
+                        .collect()
+                };
 
                 context.set_upper_bound(term, bound, reason)?;
             }
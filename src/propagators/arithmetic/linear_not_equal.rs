@@ -63,16 +63,23 @@ impl<Var: IntegerVariable + 'static> Propagator for LinearNotEqualPropagator<Var
     fn propagate(&self, mut context: PropagationContextMut) -> PropagationStatusCP {
         let fixed_count = self.get_fixed_term_count(context.as_readonly());
 
-        let fixed_lhs: i32 = self
+        // The sum is computed in 64-bit arithmetic as it can exceed the `i32` range
+        let fixed_lhs: i64 = self
             .get_fixed_terms(context.as_readonly())
-            .map(|term| context.lower_bound(term))
+            .map(|term| context.lower_bound(term) as i64)
             .sum();
 
-        if fixed_count == self.terms.len() && fixed_lhs == self.rhs {
+        if fixed_count == self.terms.len() && fixed_lhs == self.rhs as i64 {
             return Err(self.get_conflict(context.as_readonly()).into());
         }
 
         if fixed_count + 1 == self.terms.len() {
+            // If the value to remove lies outside of the `i32` range, then it is not in the domain
+            // of the unfixed term in the first place
+            let Ok(value_to_remove) = i32::try_from(self.rhs as i64 - fixed_lhs) else {
+                return Ok(());
+            };
+
             let unfixed_term = self
                 .terms
                 .iter()
@@ -87,7 +94,7 @@ impl<Var: IntegerVariable + 'static> Propagator for LinearNotEqualPropagator<Var
                 })
                 .collect();
 
-            context.remove(unfixed_term, self.rhs - fixed_lhs, reason)?;
+            context.remove(unfixed_term, value_to_remove, reason)?;
         }
 
         Ok(())
@@ -106,13 +113,13 @@ impl<Var: IntegerVariable + 'static> Propagator for LinearNotEqualPropagator<Var
 
         // All terms are assigned at this point. So the lower bound equals the upper bound of every
         // term.
-        let lhs: i32 = self
+        let lhs: i64 = self
             .terms
             .iter()
-            .map(|term| context.lower_bound(term))
+            .map(|term| context.lower_bound(term) as i64)
             .sum();
 
-        if lhs == self.rhs {
+        if lhs == self.rhs as i64 {
             Some(self.get_conflict(context))
         } else {
             None
@@ -1,3 +1,5 @@
+pub(crate) mod binary_less_or_equal;
+pub(crate) mod integer_multiplication;
 pub(crate) mod linear_less_or_equal;
 pub(crate) mod linear_not_equal;
 pub(crate) mod maximum;
@@ -0,0 +1,397 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::domain_events::DomainEvents;
+use crate::engine::cp::propagation::EnqueueDecision;
+use crate::engine::cp::propagation::LocalId;
+use crate::engine::cp::propagation::PropagationContext;
+use crate::engine::cp::propagation::PropagationContextMut;
+use crate::engine::cp::propagation::Propagator;
+use crate::engine::cp::propagation::PropagatorInitialisationContext;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::cp::IntDomainEvent;
+use crate::predicate;
+use crate::predicates::Predicate;
+use crate::variables::IntegerVariable;
+
+/// Propagator for the constraint `|{i | array[i] in values}| = rhs`.
+///
+/// Every variable in the array is either counted (it is fixed to one of the values), not counted
+/// (it cannot take any of the values) or undecided. The number of counted variables is a lower
+/// bound on `rhs`, and the number of variables which are not "not counted" is an upper bound on
+/// `rhs`. Once either of the bounds of `rhs` is reached, the undecided variables are forced to not
+/// take any of the values (in case of the upper bound) or to take one of the values (in case of
+/// the lower bound); as the latter only tightens the bounds of the undecided variables, the
+/// propagator is bounds-consistent when there are multiple values.
+///
+/// The numbers of counted and not counted variables are kept up-to-date incrementally through
+/// [`Propagator::notify`] and [`Propagator::notify_backtrack`]; these are used to only enqueue the
+/// propagator when one of the bounds of `rhs` is reached or exceeded. [`Propagator::propagate`]
+/// determines the statuses from the provided domains, so that it can be re-run on arbitrary
+/// domains (e.g. when checking explanations).
+#[derive(Debug)]
+pub(crate) struct CountPropagator<ArrayVar, RhsVar> {
+    array: Box<[ArrayVar]>,
+    /// The values which are counted; these are sorted and contain no duplicates.
+    values: Box<[i32]>,
+    rhs: RhsVar,
+    statuses: Box<[Status]>,
+    num_counted: usize,
+    num_not_counted: usize,
+}
+
+/// The status of a variable in the array with respect to the counted values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Status {
+    Counted,
+    NotCounted,
+    Undecided,
+}
+
+impl<ArrayVar, RhsVar> CountPropagator<ArrayVar, RhsVar> {
+    pub(crate) fn new(array: Box<[ArrayVar]>, value: i32, rhs: RhsVar) -> Self {
+        Self::with_values(array, [value].into(), rhs)
+    }
+
+    pub(crate) fn with_values(array: Box<[ArrayVar]>, values: Box<[i32]>, rhs: RhsVar) -> Self {
+        let mut values = values.into_vec();
+        values.sort_unstable();
+        values.dedup();
+
+        // Until the propagator is initialised, every variable is considered to be undecided
+        let statuses = vec![Status::Undecided; array.len()].into();
+
+        CountPropagator {
+            array,
+            values: values.into(),
+            rhs,
+            statuses,
+            num_counted: 0,
+            num_not_counted: 0,
+        }
+    }
+}
+
+impl<ArrayVar: IntegerVariable, RhsVar: IntegerVariable> CountPropagator<ArrayVar, RhsVar> {
+    fn compute_status(&self, context: &impl ReadDomains, variable: &ArrayVar) -> Status {
+        if context.is_fixed(variable)
+            && self
+                .values
+                .binary_search(&context.lower_bound(variable))
+                .is_ok()
+        {
+            Status::Counted
+        } else if self
+            .values
+            .iter()
+            .all(|&value| !context.contains(variable, value))
+        {
+            Status::NotCounted
+        } else {
+            Status::Undecided
+        }
+    }
+
+    fn update_status(&mut self, context: &impl ReadDomains, index: usize) {
+        let status = self.compute_status(context, &self.array[index]);
+        let previous_status = std::mem::replace(&mut self.statuses[index], status);
+
+        match previous_status {
+            Status::Counted => self.num_counted -= 1,
+            Status::NotCounted => self.num_not_counted -= 1,
+            Status::Undecided => {}
+        }
+        match status {
+            Status::Counted => self.num_counted += 1,
+            Status::NotCounted => self.num_not_counted += 1,
+            Status::Undecided => {}
+        }
+    }
+
+    /// The number of variables which can still take one of the values.
+    fn num_possible(&self) -> usize {
+        self.array.len() - self.num_not_counted
+    }
+
+    /// Returns whether [`Propagator::propagate`] can either propagate or detect a conflict given
+    /// the incrementally maintained numbers of counted and not counted variables.
+    fn can_propagate(&self, context: &PropagationContext) -> bool {
+        let num_counted = self.num_counted as i32;
+        let num_possible = self.num_possible() as i32;
+        let lower_bound = context.lower_bound(&self.rhs);
+        let upper_bound = context.upper_bound(&self.rhs);
+
+        num_counted > lower_bound
+            || num_possible < upper_bound
+            || num_counted == upper_bound
+            || num_possible == lower_bound
+    }
+
+    /// Determines the status of every variable in the array from the given domains.
+    fn compute_statuses(&self, context: &impl ReadDomains) -> Statuses {
+        let statuses: Box<[Status]> = self
+            .array
+            .iter()
+            .map(|variable| self.compute_status(context, variable))
+            .collect();
+        let num_counted = statuses
+            .iter()
+            .filter(|&&status| status == Status::Counted)
+            .count();
+        let num_not_counted = statuses
+            .iter()
+            .filter(|&&status| status == Status::NotCounted)
+            .count();
+
+        Statuses {
+            statuses,
+            num_counted: num_counted as i32,
+            num_possible: (self.array.len() - num_not_counted) as i32,
+        }
+    }
+
+    /// The predicates which explain that the variables with the given status are counted or not
+    /// counted; these are the only variables which establish the bounds of the count.
+    fn explain_status(
+        &self,
+        context: PropagationContext,
+        statuses: &[Status],
+        status: Status,
+    ) -> Vec<Predicate> {
+        let mut predicates = vec![];
+
+        for (variable, _) in self
+            .array
+            .iter()
+            .zip(statuses.iter())
+            .filter(|&(_, &other)| other == status)
+        {
+            match status {
+                Status::Counted => {
+                    let value = context.lower_bound(variable);
+                    predicates.push(predicate![variable == value]);
+                }
+                Status::NotCounted => predicates.extend(
+                    self.values
+                        .iter()
+                        .map(|&value| predicate![variable != value]),
+                ),
+                Status::Undecided => unreachable!("undecided variables do not explain the count"),
+            }
+        }
+
+        predicates
+    }
+}
+
+/// The statuses of the variables in the array for a particular assignment.
+struct Statuses {
+    statuses: Box<[Status]>,
+    num_counted: i32,
+    /// The number of variables which can still take one of the values.
+    num_possible: i32,
+}
+
+impl<ArrayVar, RhsVar> Propagator for CountPropagator<ArrayVar, RhsVar>
+where
+    ArrayVar: IntegerVariable + 'static,
+    RhsVar: IntegerVariable + 'static,
+{
+    fn name(&self) -> &str {
+        "Count"
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        for (index, variable) in self.array.iter().enumerate() {
+            context.register(variable.clone(), DomainEvents::ANY_INT);
+            context.register_for_backtrack_events(
+                variable.clone(),
+                DomainEvents::ANY_INT,
+                LocalId::from(index as u32),
+            );
+        }
+        context.register(self.rhs.clone(), DomainEvents::BOUNDS);
+
+        for index in 0..self.array.len() {
+            self.update_status(context, index);
+        }
+
+        Ok(())
+    }
+
+    fn notify(
+        &mut self,
+        context: PropagationContext,
+        local_id: LocalId,
+        _event: IntDomainEvent,
+    ) -> EnqueueDecision {
+        let index = local_id.unpack() as usize;
+        if index < self.array.len() {
+            self.update_status(&context, index);
+        }
+
+        if self.can_propagate(&context) {
+            EnqueueDecision::Enqueue
+        } else {
+            EnqueueDecision::Skip
+        }
+    }
+
+    fn notify_backtrack(
+        &mut self,
+        context: PropagationContext,
+        local_id: LocalId,
+        _event: IntDomainEvent,
+    ) {
+        self.update_status(&context, local_id.unpack() as usize);
+    }
+
+    fn detect_inconsistency(
+        &self,
+        context: PropagationContext,
+    ) -> Option<PropositionalConjunction> {
+        let Statuses {
+            statuses,
+            num_counted,
+            num_possible,
+        } = self.compute_statuses(&context);
+
+        let upper_bound = context.upper_bound(&self.rhs);
+        if num_counted > upper_bound {
+            let mut reason = self.explain_status(context, &statuses, Status::Counted);
+            reason.push(predicate![self.rhs <= upper_bound]);
+            return Some(reason.into());
+        }
+
+        let lower_bound = context.lower_bound(&self.rhs);
+        if num_possible < lower_bound {
+            let mut reason = self.explain_status(context, &statuses, Status::NotCounted);
+            reason.push(predicate![self.rhs >= lower_bound]);
+            return Some(reason.into());
+        }
+
+        None
+    }
+
+    fn propagate(&self, mut context: PropagationContextMut) -> PropagationStatusCP {
+        let Statuses {
+            statuses,
+            num_counted,
+            num_possible,
+        } = self.compute_statuses(&context.as_readonly());
+
+        if num_counted > context.lower_bound(&self.rhs) {
+            let reason = self.explain_status(context.as_readonly(), &statuses, Status::Counted);
+            context.set_lower_bound(
+                &self.rhs,
+                num_counted,
+                PropositionalConjunction::from(reason),
+            )?;
+        }
+        if num_possible < context.upper_bound(&self.rhs) {
+            let reason = self.explain_status(context.as_readonly(), &statuses, Status::NotCounted);
+            context.set_upper_bound(
+                &self.rhs,
+                num_possible,
+                PropositionalConjunction::from(reason),
+            )?;
+        }
+
+        if num_counted == num_possible {
+            return Ok(());
+        }
+
+        let undecided = self
+            .array
+            .iter()
+            .zip(statuses.iter())
+            .filter(|&(_, &status)| status == Status::Undecided)
+            .map(|(variable, _)| variable);
+
+        let upper_bound = context.upper_bound(&self.rhs);
+        let lower_bound = context.lower_bound(&self.rhs);
+        if upper_bound == num_counted {
+            let counted = self.explain_status(context.as_readonly(), &statuses, Status::Counted);
+            let rhs_predicate = predicate![self.rhs <= upper_bound];
+            for variable in undecided {
+                for &value in self.values.iter() {
+                    let reason = with_predicates(&mut context, &counted, [rhs_predicate]);
+                    context.remove(variable, value, reason)?;
+                }
+            }
+        } else if lower_bound == num_possible {
+            let not_counted =
+                self.explain_status(context.as_readonly(), &statuses, Status::NotCounted);
+            let rhs_predicate = predicate![self.rhs >= lower_bound];
+            for variable in undecided {
+                // An undecided variable contains at least one of the values; the values which are
+                // skipped to get to the smallest (largest) of those are part of the reason, since
+                // the bound does not follow from the count alone
+                let num_skipped_from_start = self
+                    .values
+                    .iter()
+                    .position(|&value| context.contains(variable, value))
+                    .expect("an undecided variable contains one of the values");
+                let num_skipped_from_end = self
+                    .values
+                    .iter()
+                    .rev()
+                    .position(|&value| context.contains(variable, value))
+                    .expect("an undecided variable contains one of the values");
+
+                let skipped_from_start = &self.values[..num_skipped_from_start];
+                let reason = with_predicates(
+                    &mut context,
+                    &not_counted,
+                    skipped_from_start
+                        .iter()
+                        .map(|&value| predicate![variable != value])
+                        .chain([rhs_predicate]),
+                );
+                context.set_lower_bound(variable, self.values[num_skipped_from_start], reason)?;
+
+                let largest_index = self.values.len() - 1 - num_skipped_from_end;
+                let skipped_from_end = &self.values[largest_index + 1..];
+                let reason = with_predicates(
+                    &mut context,
+                    &not_counted,
+                    skipped_from_end
+                        .iter()
+                        .map(|&value| predicate![variable != value])
+                        .chain([rhs_predicate]),
+                );
+                context.set_upper_bound(variable, self.values[largest_index], reason)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check(&self, context: PropagationContextMut) -> bool {
+        let count = self
+            .array
+            .iter()
+            .filter(|&variable| {
+                self.values
+                    .binary_search(&context.lower_bound(variable))
+                    .is_ok()
+            })
+            .count();
+
+        context.lower_bound(&self.rhs) == count as i32
+    }
+}
+
+/// Builds the reason consisting of `predicates` and `additional` in a buffer of the context.
+fn with_predicates(
+    context: &mut PropagationContextMut,
+    predicates: &[Predicate],
+    additional: impl IntoIterator<Item = Predicate>,
+) -> PropositionalConjunction {
+    let mut reason = context.reason_builder();
+    reason.extend(predicates.iter().copied());
+    reason.extend(additional);
+    reason
+}
@@ -1,5 +1,7 @@
 mod energetic_reasoning;
+mod overload_checking;
 mod time_table;
 
 pub(crate) use energetic_reasoning::*;
+pub(crate) use overload_checking::*;
 pub(crate) use time_table::*;
@@ -0,0 +1,140 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::domain_events::DomainEvents;
+use crate::engine::cp::propagation::PropagationContext;
+use crate::engine::cp::propagation::PropagationContextMut;
+use crate::engine::cp::propagation::Propagator;
+use crate::engine::cp::propagation::PropagatorInitialisationContext;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::predicate;
+use crate::variables::IntegerVariable;
+
+/// Propagator which detects overloads of a cumulative resource using energetic reasoning over task
+/// intervals.
+///
+/// For every window `[a, b)` where `a` is the earliest start time of a task and `b` is the latest
+/// completion time of a task, the energy (i.e. `duration * resource_requirement`) of the tasks
+/// which have to execute entirely within the window is compared to the available energy
+/// `resource_capacity * (b - a)`. If the required energy exceeds the available energy, then the
+/// tasks cannot be scheduled and the conflict is explained by `[s_i >= a] /\ [s_i <= b - d_i]` for
+/// every task `i` which contributes to the window.
+///
+/// The propagator does not tighten any bounds, it only detects conflicts. Since it performs
+/// `O(n^2)` work per call, it has a lower [`Propagator::priority`] than the other propagators; it
+/// is thus only called once the time-table propagator (if posted) has reached a fixed point.
+#[derive(Debug)]
+pub(crate) struct OverloadCheckingPropagator<Var> {
+    start_times: Box<[Var]>,
+    durations: Box<[u32]>,
+    resource_requirements: Box<[u32]>,
+    resource_capacity: u32,
+}
+
+impl<Var> OverloadCheckingPropagator<Var> {
+    pub(crate) fn new(
+        start_times: Box<[Var]>,
+        durations: Box<[u32]>,
+        resource_requirements: Box<[u32]>,
+        resource_capacity: u32,
+    ) -> Self {
+        OverloadCheckingPropagator {
+            start_times,
+            durations,
+            resource_requirements,
+            resource_capacity,
+        }
+    }
+}
+
+impl<Var: IntegerVariable> OverloadCheckingPropagator<Var> {
+    /// Returns the explanation of an overloaded task interval if there is one.
+    fn find_overload(&self, context: PropagationContext) -> Option<PropositionalConjunction> {
+        let earliest_start = |task: usize| context.lower_bound(&self.start_times[task]);
+        let latest_completion = |task: usize| {
+            context.upper_bound(&self.start_times[task]) + self.durations[task] as i32
+        };
+        let energy =
+            |task: usize| self.durations[task] as i64 * self.resource_requirements[task] as i64;
+
+        let mut tasks_by_latest_completion = (0..self.start_times.len())
+            .filter(|&task| energy(task) > 0)
+            .collect::<Vec<_>>();
+        tasks_by_latest_completion.sort_by_key(|&task| latest_completion(task));
+
+        let mut window_starts = tasks_by_latest_completion
+            .iter()
+            .map(|&task| earliest_start(task))
+            .collect::<Vec<_>>();
+        window_starts.sort();
+        window_starts.dedup();
+
+        for window_start in window_starts {
+            let mut required_energy = 0;
+
+            for (index, &task) in tasks_by_latest_completion.iter().enumerate() {
+                if earliest_start(task) < window_start {
+                    continue;
+                }
+                required_energy += energy(task);
+
+                let window_end = latest_completion(task);
+                let available_energy =
+                    self.resource_capacity as i64 * (window_end - window_start) as i64;
+
+                if required_energy > available_energy {
+                    return Some(
+                        tasks_by_latest_completion[..=index]
+                            .iter()
+                            .filter(|&&task| earliest_start(task) >= window_start)
+                            .flat_map(|&task| {
+                                let start_time = &self.start_times[task];
+                                let latest_start = window_end - self.durations[task] as i32;
+                                [
+                                    predicate![start_time >= window_start],
+                                    predicate![start_time <= latest_start],
+                                ]
+                            })
+                            .collect(),
+                    );
+                }
+            }
+        }
+
+        None
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Propagator for OverloadCheckingPropagator<Var> {
+    fn name(&self) -> &str {
+        "OverloadChecking"
+    }
+
+    fn priority(&self) -> u32 {
+        1
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        for start_time in self.start_times.iter() {
+            context.register(start_time.clone(), DomainEvents::BOUNDS);
+        }
+
+        Ok(())
+    }
+
+    fn propagate(&self, context: PropagationContextMut) -> PropagationStatusCP {
+        match self.find_overload(context.as_readonly()) {
+            Some(explanation) => Err(explanation.into()),
+            None => Ok(()),
+        }
+    }
+
+    fn detect_inconsistency(
+        &self,
+        context: PropagationContext,
+    ) -> Option<PropositionalConjunction> {
+        self.find_overload(context)
+    }
+}
@@ -0,0 +1,289 @@
+mod theta_tree;
+
+use theta_tree::ThetaTree;
+
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::domain_events::DomainEvents;
+use crate::engine::cp::propagation::PropagationContext;
+use crate::engine::cp::propagation::PropagationContextMut;
+use crate::engine::cp::propagation::Propagator;
+use crate::engine::cp::propagation::PropagatorInitialisationContext;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::predicate;
+use crate::predicates::Predicate;
+use crate::variables::IntegerVariable;
+
+/// Propagator for the disjunctive (unary resource) constraint, which states that no two tasks
+/// overlap; i.e. for every pair of tasks `i` and `j`, either `s_i + d_i <= s_j` or `s_j + d_j <=
+/// s_i`. Tasks with a duration of 0 do not occupy the resource and are ignored.
+///
+/// Two rules are applied using a [`ThetaTree`], both based on [\[1\]](https://doi.org/10.3233/AIC-2008-0426):
+/// - Overload checking: if the tasks in a set `Omega` have to execute in the window `[est_Omega,
+///   lct_Omega)`, but their total duration exceeds the length of the window, then the constraint
+///   is violated. The conflict is explained by `[s_k >= est_Omega] /\ [s_k <= lct_Omega - d_k]`
+///   for every task `k` in `Omega`.
+/// - Detectable precedences: if `est_i + d_i > lst_j`, then task `j` has to precede task `i`. The
+///   earliest start of task `i` is then at least the earliest completion time of the tasks which
+///   precede it; the update is explained by `[s_i >= est_i]` together with `[s_k >= est_Omega]
+///   /\ [s_k <= lst_k]` for every task `k` in the responsible set `Omega`.
+///
+/// Only the earliest start times are updated.
+///
+/// \[1\] P. Vilím, ‘Filtering algorithms for the unary resource constraint’, Archives of Control
+/// Sciences, vol. 18, no. 2, pp. 159–202, 2008.
+#[derive(Debug)]
+pub(crate) struct DisjunctivePropagator<Var> {
+    start_times: Box<[Var]>,
+    durations: Box<[i32]>,
+}
+
+impl<Var> DisjunctivePropagator<Var> {
+    pub(crate) fn new(start_times: Box<[Var]>, durations: Box<[i32]>) -> Self {
+        let (start_times, durations) = start_times
+            .into_vec()
+            .into_iter()
+            .zip(durations.into_vec())
+            .filter(|&(_, duration)| duration > 0)
+            .unzip::<_, _, Vec<_>, Vec<_>>();
+
+        DisjunctivePropagator {
+            start_times: start_times.into(),
+            durations: durations.into(),
+        }
+    }
+}
+
+/// The bounds of the tasks at the time that the propagator is called.
+struct TaskBounds {
+    earliest_start_times: Vec<i32>,
+    latest_start_times: Vec<i32>,
+    durations: Vec<i32>,
+    /// The position of every task in the order of earliest start times.
+    positions: Vec<usize>,
+}
+
+impl TaskBounds {
+    fn new<Var: IntegerVariable>(
+        context: PropagationContext,
+        start_times: &[Var],
+        durations: &[i32],
+    ) -> Self {
+        let earliest_start_times = start_times
+            .iter()
+            .map(|start_time| context.lower_bound(start_time))
+            .collect::<Vec<_>>();
+        let latest_start_times = start_times
+            .iter()
+            .map(|start_time| context.upper_bound(start_time))
+            .collect();
+
+        let mut by_earliest_start_time = (0..start_times.len()).collect::<Vec<_>>();
+        by_earliest_start_time.sort_by_key(|&task| earliest_start_times[task]);
+
+        let mut positions = vec![0; start_times.len()];
+        for (position, &task) in by_earliest_start_time.iter().enumerate() {
+            positions[task] = position;
+        }
+
+        TaskBounds {
+            earliest_start_times,
+            latest_start_times,
+            durations: durations.to_vec(),
+            positions,
+        }
+    }
+
+    fn num_tasks(&self) -> usize {
+        self.durations.len()
+    }
+
+    fn earliest_completion_time(&self, task: usize) -> i32 {
+        self.earliest_start_times[task] + self.durations[task]
+    }
+
+    fn latest_completion_time(&self, task: usize) -> i32 {
+        self.latest_start_times[task] + self.durations[task]
+    }
+
+    fn insert(&self, tree: &mut ThetaTree, task: usize) {
+        tree.insert(
+            self.positions[task],
+            self.earliest_start_times[task],
+            self.durations[task],
+        );
+    }
+
+    /// Given the earliest completion time of the set of `tasks`, returns the earliest start time
+    /// `est_Omega` of the responsible set `Omega` together with the tasks in `Omega`; i.e. the
+    /// smallest set of tasks `{k in tasks | est_k >= est_Omega}` for which `est_Omega + \sum d_k`
+    /// is equal to the earliest completion time.
+    fn responsible_set(&self, tasks: &[usize], earliest_completion_time: i32) -> (i32, Vec<usize>) {
+        let mut tasks = tasks.to_vec();
+        tasks.sort_by_key(|&task| std::cmp::Reverse(self.earliest_start_times[task]));
+
+        let mut sum_of_durations = 0;
+        for (index, &task) in tasks.iter().enumerate() {
+            sum_of_durations += self.durations[task];
+
+            let earliest_start_time = self.earliest_start_times[task];
+            let is_last_with_this_start = tasks
+                .get(index + 1)
+                .is_none_or(|&next| self.earliest_start_times[next] != earliest_start_time);
+
+            if is_last_with_this_start
+                && earliest_start_time + sum_of_durations == earliest_completion_time
+            {
+                tasks.truncate(index + 1);
+                return (earliest_start_time, tasks);
+            }
+        }
+
+        unreachable!("the earliest completion time is attained by one of the tasks")
+    }
+}
+
+impl<Var: IntegerVariable + 'static> DisjunctivePropagator<Var> {
+    /// Returns the explanation of an overloaded set of tasks if there is one.
+    fn find_overload(&self, bounds: &TaskBounds) -> Option<PropositionalConjunction> {
+        let mut by_latest_completion_time = (0..bounds.num_tasks()).collect::<Vec<_>>();
+        by_latest_completion_time.sort_by_key(|&task| bounds.latest_completion_time(task));
+
+        let mut theta_tree = ThetaTree::new(bounds.num_tasks());
+        for (index, &task) in by_latest_completion_time.iter().enumerate() {
+            bounds.insert(&mut theta_tree, task);
+
+            let latest_completion_time = bounds.latest_completion_time(task);
+            let earliest_completion_time = theta_tree.earliest_completion_time();
+            if earliest_completion_time > latest_completion_time {
+                let (window_start, omega) = bounds.responsible_set(
+                    &by_latest_completion_time[..=index],
+                    earliest_completion_time,
+                );
+
+                return Some(
+                    omega
+                        .into_iter()
+                        .flat_map(|task| {
+                            let start_time = &self.start_times[task];
+                            let latest_start = latest_completion_time - bounds.durations[task];
+                            [
+                                predicate![start_time >= window_start],
+                                predicate![start_time <= latest_start],
+                            ]
+                        })
+                        .collect(),
+                );
+            }
+        }
+
+        None
+    }
+
+    /// Returns the updated earliest start times which are found using detectable precedences,
+    /// together with their explanations.
+    fn detectable_precedences(&self, bounds: &TaskBounds) -> Vec<(usize, i32, Vec<Predicate>)> {
+        let mut by_earliest_completion_time = (0..bounds.num_tasks()).collect::<Vec<_>>();
+        by_earliest_completion_time.sort_by_key(|&task| bounds.earliest_completion_time(task));
+
+        let mut by_latest_start_time = (0..bounds.num_tasks()).collect::<Vec<_>>();
+        by_latest_start_time.sort_by_key(|&task| bounds.latest_start_times[task]);
+
+        let mut theta_tree = ThetaTree::new(bounds.num_tasks());
+        let mut theta = vec![];
+        let mut num_inserted = 0;
+        let mut updates = vec![];
+
+        for &task in by_earliest_completion_time.iter() {
+            let earliest_completion_time = bounds.earliest_completion_time(task);
+
+            // Every task in theta has to precede `task` (apart from `task` itself)
+            while num_inserted < by_latest_start_time.len()
+                && bounds.latest_start_times[by_latest_start_time[num_inserted]]
+                    < earliest_completion_time
+            {
+                let preceding = by_latest_start_time[num_inserted];
+                bounds.insert(&mut theta_tree, preceding);
+                theta.push(preceding);
+                num_inserted += 1;
+            }
+
+            let is_in_theta = theta.contains(&task);
+            if is_in_theta {
+                theta_tree.remove(bounds.positions[task]);
+            }
+
+            let preceding_completion_time = theta_tree.earliest_completion_time();
+            if preceding_completion_time > bounds.earliest_start_times[task] {
+                let preceding = theta
+                    .iter()
+                    .copied()
+                    .filter(|&other| other != task)
+                    .collect::<Vec<_>>();
+                let (window_start, omega) =
+                    bounds.responsible_set(&preceding, preceding_completion_time);
+
+                let start_time = &self.start_times[task];
+                let earliest_start_time = bounds.earliest_start_times[task];
+                let mut reason = vec![predicate![start_time >= earliest_start_time]];
+                for other in omega {
+                    let other_start_time = &self.start_times[other];
+                    let latest_start_time = bounds.latest_start_times[other];
+                    reason.push(predicate![other_start_time >= window_start]);
+                    reason.push(predicate![other_start_time <= latest_start_time]);
+                }
+
+                updates.push((task, preceding_completion_time, reason));
+            }
+
+            if is_in_theta {
+                bounds.insert(&mut theta_tree, task);
+            }
+        }
+
+        updates
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Propagator for DisjunctivePropagator<Var> {
+    fn name(&self) -> &str {
+        "Disjunctive"
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        for start_time in self.start_times.iter() {
+            context.register(start_time.clone(), DomainEvents::BOUNDS);
+        }
+
+        Ok(())
+    }
+
+    fn propagate(&self, mut context: PropagationContextMut) -> PropagationStatusCP {
+        let bounds = TaskBounds::new(context.as_readonly(), &self.start_times, &self.durations);
+
+        if let Some(explanation) = self.find_overload(&bounds) {
+            return Err(explanation.into());
+        }
+
+        for (task, earliest_start_time, reason) in self.detectable_precedences(&bounds) {
+            context.set_lower_bound(
+                &self.start_times[task],
+                earliest_start_time,
+                PropositionalConjunction::from(reason),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn detect_inconsistency(
+        &self,
+        context: PropagationContext,
+    ) -> Option<PropositionalConjunction> {
+        let bounds = TaskBounds::new(context, &self.start_times, &self.durations);
+        self.find_overload(&bounds)
+    }
+}
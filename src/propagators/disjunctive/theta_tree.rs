@@ -0,0 +1,107 @@
+/// A Theta-tree [\[1\]](https://doi.org/10.3233/AIC-2008-0426) over a fixed set of tasks which
+/// allows the earliest completion time of a subset of the tasks (the set `Theta`) to be
+/// maintained while tasks are inserted into and removed from the subset in `O(log n)` time.
+///
+/// The tasks are identified by their position in the order of their earliest start times, which
+/// is provided when the tree is created.
+///
+/// \[1\] P. Vilím, ‘Filtering algorithms for the unary resource constraint’, Archives of Control
+/// Sciences, vol. 18, no. 2, pp. 159–202, 2008.
+#[derive(Clone, Debug)]
+pub(super) struct ThetaTree {
+    /// The nodes of a complete binary tree, where node `i` has children `2i + 1` and `2i + 2`; the
+    /// leaves are stored from index `num_leaves - 1` onwards.
+    nodes: Vec<ThetaNode>,
+    num_leaves: usize,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct ThetaNode {
+    /// The sum of the durations of the tasks in `Theta` in the subtree.
+    sum_of_durations: i32,
+    /// The earliest completion time of the tasks in `Theta` in the subtree.
+    earliest_completion_time: i32,
+}
+
+const EMPTY: ThetaNode = ThetaNode {
+    sum_of_durations: 0,
+    earliest_completion_time: i32::MIN,
+};
+
+impl ThetaTree {
+    /// Creates an empty tree for `num_tasks` tasks.
+    pub(super) fn new(num_tasks: usize) -> Self {
+        let num_leaves = num_tasks.next_power_of_two();
+
+        ThetaTree {
+            nodes: vec![EMPTY; 2 * num_leaves - 1],
+            num_leaves,
+        }
+    }
+
+    /// Inserts the task at position `index` (in the order of earliest start times) into `Theta`.
+    pub(super) fn insert(&mut self, index: usize, earliest_start_time: i32, duration: i32) {
+        self.set_leaf(
+            index,
+            ThetaNode {
+                sum_of_durations: duration,
+                earliest_completion_time: earliest_start_time + duration,
+            },
+        );
+    }
+
+    /// Removes the task at position `index` (in the order of earliest start times) from `Theta`.
+    pub(super) fn remove(&mut self, index: usize) {
+        self.set_leaf(index, EMPTY);
+    }
+
+    /// Returns the earliest completion time of the tasks in `Theta`, or [`i32::MIN`] if `Theta`
+    /// is empty.
+    pub(super) fn earliest_completion_time(&self) -> i32 {
+        self.nodes[0].earliest_completion_time
+    }
+
+    fn set_leaf(&mut self, index: usize, node: ThetaNode) {
+        let mut position = self.num_leaves - 1 + index;
+        self.nodes[position] = node;
+
+        while position > 0 {
+            position = (position - 1) / 2;
+
+            let left = self.nodes[2 * position + 1];
+            let right = self.nodes[2 * position + 2];
+            self.nodes[position] = ThetaNode {
+                sum_of_durations: left.sum_of_durations + right.sum_of_durations,
+                earliest_completion_time: right.earliest_completion_time.max(
+                    left.earliest_completion_time
+                        .saturating_add(right.sum_of_durations),
+                ),
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ThetaTree;
+
+    #[test]
+    fn earliest_completion_time_accounts_for_all_tasks_in_theta() {
+        // Tasks sorted by earliest start time: (est, duration)
+        let tasks = [(0, 3), (2, 2), (4, 5)];
+        let mut tree = ThetaTree::new(tasks.len());
+        assert_eq!(tree.earliest_completion_time(), i32::MIN);
+
+        tree.insert(0, tasks[0].0, tasks[0].1);
+        assert_eq!(tree.earliest_completion_time(), 3);
+
+        tree.insert(2, tasks[2].0, tasks[2].1);
+        assert_eq!(tree.earliest_completion_time(), 9);
+
+        tree.insert(1, tasks[1].0, tasks[1].1);
+        assert_eq!(tree.earliest_completion_time(), 10);
+
+        tree.remove(0);
+        assert_eq!(tree.earliest_completion_time(), 9);
+    }
+}
@@ -0,0 +1,101 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::domain_events::DomainEvents;
+use crate::engine::cp::propagation::PropagationContextMut;
+use crate::engine::cp::propagation::Propagator;
+use crate::engine::cp::propagation::PropagatorInitialisationContext;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::predicate;
+use crate::variables::IntegerVariable;
+
+/// Propagator for the constraint `f[i] = j <-> g[j] = i`, i.e. `f` and `g` are inverse functions
+/// of each other over the values `[0, n)`.
+///
+/// The domains are channelled in both directions: if `f[i]` is fixed to `j`, then `g[j]` is fixed
+/// to `i`, and if `j` is not in the domain of `f[i]`, then `i` is removed from the domain of
+/// `g[j]` (and vice versa). Every propagation is explained by a single predicate.
+///
+/// The propagator assumes that both arrays have the same length `n` and that all domains lie
+/// within `[0, n)`; this is checked when posting the constraint (see
+/// [`crate::constraints::inverse`]).
+#[derive(Debug)]
+pub(crate) struct InversePropagator<Var> {
+    f: Box<[Var]>,
+    g: Box<[Var]>,
+}
+
+impl<Var> InversePropagator<Var> {
+    pub(crate) fn new(f: Box<[Var]>, g: Box<[Var]>) -> Self {
+        assert_eq!(
+            f.len(),
+            g.len(),
+            "inverse functions should be defined over the same number of values"
+        );
+
+        InversePropagator { f, g }
+    }
+}
+
+/// Channels the domains of `from` to the domains of `to`; see [`InversePropagator`].
+fn channel<Var: IntegerVariable>(
+    context: &mut PropagationContextMut,
+    from: &[Var],
+    to: &[Var],
+) -> PropagationStatusCP {
+    for (i, source) in from.iter().enumerate() {
+        let i = i as i32;
+
+        if context.is_fixed(source) {
+            let j = context.lower_bound(source);
+            let target = &to[j as usize];
+
+            if !context.is_fixed(target) {
+                let reason = PropositionalConjunction::from(predicate![source == j]);
+                context.set_lower_bound(target, i, reason)?;
+                let reason = PropositionalConjunction::from(predicate![source == j]);
+                context.set_upper_bound(target, i, reason)?;
+            }
+        }
+
+        for (j, target) in to.iter().enumerate() {
+            let j = j as i32;
+
+            if !context.contains(source, j) && context.contains(target, i) {
+                let reason = PropositionalConjunction::from(predicate![source != j]);
+                context.remove(target, i, reason)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+impl<Var: IntegerVariable + 'static> Propagator for InversePropagator<Var> {
+    fn name(&self) -> &str {
+        "Inverse"
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        // Removals are required to channel the holes in the domains
+        for variable in self.f.iter().chain(self.g.iter()) {
+            context.register(variable.clone(), DomainEvents::ANY_INT);
+        }
+
+        Ok(())
+    }
+
+    fn propagate(&self, mut context: PropagationContextMut) -> PropagationStatusCP {
+        channel(&mut context, &self.f, &self.g)?;
+        channel(&mut context, &self.g, &self.f)
+    }
+
+    fn check(&self, context: PropagationContextMut) -> bool {
+        self.f.iter().enumerate().all(|(i, source)| {
+            let j = context.lower_bound(source);
+            context.lower_bound(&self.g[j as usize]) == i as i32
+        })
+    }
+}
@@ -0,0 +1,267 @@
+use std::cell::Cell;
+use std::cmp::Ordering;
+
+use enumset::enum_set;
+
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::domain_events::DomainEvents;
+use crate::engine::cp::propagation::EnqueueDecision;
+use crate::engine::cp::propagation::LocalId;
+use crate::engine::cp::propagation::PropagationContext;
+use crate::engine::cp::propagation::PropagationContextMut;
+use crate::engine::cp::propagation::Propagator;
+use crate::engine::cp::propagation::PropagatorInitialisationContext;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::cp::IntDomainEvent;
+use crate::predicate;
+use crate::predicates::Predicate;
+use crate::variables::IntegerVariable;
+
+/// Propagator for the constraint `xs <=_lex ys`, i.e. the sequence `xs` is lexicographically
+/// smaller than or equal to the sequence `ys`.
+///
+/// The propagator maintains the pointer `alpha`, which is the first index at which `xs` and `ys`
+/// are not (yet) fixed to the same value; since the prefix before `alpha` is equal, the constraint
+/// requires `xs[alpha] <= ys[alpha]`. The pointer is advanced during propagation and moved back
+/// when the solver backtracks (see [`Propagator::notify_backtrack`]).
+///
+/// Starting from `alpha`, the propagator looks for the pointer `beta`; this is the index at which
+/// the suffix of `xs` is forced to be lexicographically larger than the suffix of `ys`. If such an
+/// index exists directly after positions which can at best be equal, then the constraint requires
+/// the strict inequality `xs[alpha] < ys[alpha]`.
+///
+/// The pointer `beta` is maintained incrementally as well. The propagator keeps a frontier up to
+/// which the positions after `alpha` are known to have `xs[i] >= ys[i]` forced by the bounds; the
+/// frontier is advanced during propagation, and moved back when the lower bound of `xs[i]` or the
+/// upper bound of `ys[i]` is relaxed while backtracking. A position before the frontier which
+/// becomes forced to be larger is picked up in [`Propagator::notify`].
+#[derive(Debug)]
+pub(crate) struct LexLeqPropagator<Var> {
+    xs: Box<[Var]>,
+    ys: Box<[Var]>,
+    /// All positions before `alpha` are fixed to equal values in `xs` and `ys`.
+    alpha: Cell<usize>,
+    /// All positions strictly between `alpha` and `frontier` have a lower bound in `xs` which is
+    /// at least the upper bound in `ys`.
+    frontier: Cell<usize>,
+    /// The first position strictly between `alpha` and `frontier` at which the lower bound in
+    /// `xs` exceeds the upper bound in `ys`, if there is one.
+    beta: Cell<Option<usize>>,
+}
+
+impl<Var> LexLeqPropagator<Var> {
+    pub(crate) fn new(xs: Box<[Var]>, ys: Box<[Var]>) -> Self {
+        assert_eq!(
+            xs.len(),
+            ys.len(),
+            "lexicographic ordering is only defined for sequences of the same length"
+        );
+
+        LexLeqPropagator {
+            xs,
+            ys,
+            alpha: Cell::new(0),
+            frontier: Cell::new(0),
+            beta: Cell::new(None),
+        }
+    }
+}
+
+impl<Var: IntegerVariable> LexLeqPropagator<Var> {
+    fn is_fixed_equal(&self, context: PropagationContext, index: usize) -> bool {
+        let x = &self.xs[index];
+        let y = &self.ys[index];
+
+        context.is_fixed(x)
+            && context.is_fixed(y)
+            && context.lower_bound(x) == context.lower_bound(y)
+    }
+
+    /// The predicates explaining that the positions before `alpha` are equal.
+    fn prefix_explanation(&self, context: PropagationContext, alpha: usize) -> Vec<Predicate> {
+        self.xs[..alpha]
+            .iter()
+            .zip(self.ys[..alpha].iter())
+            .flat_map(|(x, y)| {
+                let value = context.lower_bound(x);
+                [predicate![x == value], predicate![y == value]]
+            })
+            .collect()
+    }
+
+    /// Compares the lower bound of `xs[index]` to the upper bound of `ys[index]`.
+    fn compare_bounds(&self, context: PropagationContext, index: usize) -> Ordering {
+        context
+            .lower_bound(&self.xs[index])
+            .cmp(&context.upper_bound(&self.ys[index]))
+    }
+
+    /// Returns the index `beta` if the suffix of `xs` after `alpha` is forced to be
+    /// lexicographically larger than the suffix of `ys`.
+    ///
+    /// The positions before the frontier were checked by earlier calls, so the scan continues
+    /// from the frontier.
+    fn find_beta(&self, context: PropagationContext, alpha: usize) -> Option<usize> {
+        if self.beta.get().is_some_and(|beta| beta <= alpha) {
+            // The prefix now includes `beta`, so the positions after it have to be checked again
+            self.beta.set(None);
+            self.frontier.set(alpha + 1);
+        }
+
+        let mut frontier = self.frontier.get().max(alpha + 1);
+        while self.beta.get().is_none() && frontier < self.xs.len() {
+            match self.compare_bounds(context, frontier) {
+                Ordering::Less => break,
+                Ordering::Equal => {}
+                Ordering::Greater => self.beta.set(Some(frontier)),
+            }
+            frontier += 1;
+        }
+        self.frontier.set(frontier);
+
+        self.beta.get()
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Propagator for LexLeqPropagator<Var> {
+    fn name(&self) -> &str {
+        "LexLeq"
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        let num_variables = self.xs.len();
+
+        for (index, x) in self.xs.iter().enumerate() {
+            context.register(x.clone(), DomainEvents::ANY_INT);
+            context.register_for_backtrack_events(
+                x.clone(),
+                DomainEvents::create_with_int_events(enum_set!(
+                    IntDomainEvent::Assign | IntDomainEvent::LowerBound
+                )),
+                LocalId::from(index as u32),
+            );
+        }
+
+        for (index, y) in self.ys.iter().enumerate() {
+            context.register(y.clone(), DomainEvents::ANY_INT);
+            context.register_for_backtrack_events(
+                y.clone(),
+                DomainEvents::create_with_int_events(enum_set!(
+                    IntDomainEvent::Assign | IntDomainEvent::UpperBound
+                )),
+                LocalId::from((num_variables + index) as u32),
+            );
+        }
+
+        Ok(())
+    }
+
+    fn notify(
+        &mut self,
+        context: PropagationContext,
+        local_id: LocalId,
+        _event: IntDomainEvent,
+    ) -> EnqueueDecision {
+        // A checked position may have become forced to be larger, in which case it is the new
+        // `beta` if it comes before the current one
+        let index = local_id.unpack() as usize % self.xs.len();
+        if self.alpha.get() < index
+            && index < self.frontier.get()
+            && self.beta.get().is_none_or(|beta| index < beta)
+            && self.compare_bounds(context, index) == Ordering::Greater
+        {
+            self.beta.set(Some(index));
+        }
+
+        EnqueueDecision::Enqueue
+    }
+
+    fn notify_backtrack(
+        &mut self,
+        _context: PropagationContext,
+        local_id: LocalId,
+        event: IntDomainEvent,
+    ) {
+        let index = local_id.unpack() as usize % self.xs.len();
+        match event {
+            IntDomainEvent::Assign => {
+                // A variable in the prefix became unfixed, so the prefix is only guaranteed to be
+                // equal up to that variable; the position at the old `alpha` was never checked
+                // against the frontier
+                let alpha = self.alpha.get();
+                if index < alpha {
+                    self.alpha.set(index);
+                    self.frontier.set(self.frontier.get().min(alpha));
+                }
+            }
+            _ => {
+                // The relaxed bound has to be checked again
+                self.frontier.set(self.frontier.get().min(index));
+            }
+        }
+
+        if self
+            .beta
+            .get()
+            .is_some_and(|beta| beta >= self.frontier.get())
+        {
+            self.beta.set(None);
+        }
+    }
+
+    fn propagate(&self, mut context: PropagationContextMut) -> PropagationStatusCP {
+        let mut alpha = self.alpha.get();
+        while alpha < self.xs.len() && self.is_fixed_equal(context.as_readonly(), alpha) {
+            alpha += 1;
+        }
+        self.alpha.set(alpha);
+
+        // The sequences are equal
+        if alpha == self.xs.len() {
+            return Ok(());
+        }
+
+        let x = &self.xs[alpha];
+        let y = &self.ys[alpha];
+
+        let mut explanation = self.prefix_explanation(context.as_readonly(), alpha);
+        let mut strictness = 0;
+
+        if let Some(beta) = self.find_beta(context.as_readonly(), alpha) {
+            strictness = 1;
+            explanation.extend(self.xs[alpha + 1..=beta].iter().map(|x_i| {
+                let lower_bound = context.lower_bound(x_i);
+                predicate![x_i >= lower_bound]
+            }));
+            explanation.extend(self.ys[alpha + 1..=beta].iter().map(|y_i| {
+                let upper_bound = context.upper_bound(y_i);
+                predicate![y_i <= upper_bound]
+            }));
+        }
+
+        let lower_bound_x = context.lower_bound(x);
+        let upper_bound_y = context.upper_bound(y);
+
+        let mut reason_x = explanation.clone();
+        reason_x.push(predicate![y <= upper_bound_y]);
+        context.set_upper_bound(
+            x,
+            upper_bound_y - strictness,
+            PropositionalConjunction::from(reason_x),
+        )?;
+
+        let mut reason_y = explanation;
+        reason_y.push(predicate![x >= lower_bound_x]);
+        context.set_lower_bound(
+            y,
+            lower_bound_x + strictness,
+            PropositionalConjunction::from(reason_y),
+        )?;
+
+        Ok(())
+    }
+}
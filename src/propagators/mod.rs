@@ -5,8 +5,14 @@
 pub(crate) mod all_different;
 pub(crate) mod arithmetic;
 pub(crate) mod circuit;
+pub(crate) mod count;
 pub(crate) mod cumulative;
+pub(crate) mod disjunctive;
 pub(crate) mod element;
+pub(crate) mod inverse;
+pub(crate) mod lex_leq;
+pub(crate) mod regular;
 mod reified_propagator;
+pub(crate) mod value_precede;
 
 pub(crate) use reified_propagator::*;
@@ -0,0 +1,340 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::domain_events::DomainEvents;
+use crate::engine::cp::propagation::EnqueueDecision;
+use crate::engine::cp::propagation::LocalId;
+use crate::engine::cp::propagation::PropagationContext;
+use crate::engine::cp::propagation::PropagationContextMut;
+use crate::engine::cp::propagation::Propagator;
+use crate::engine::cp::propagation::PropagatorInitialisationContext;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::cp::IntDomainEvent;
+use crate::predicate;
+use crate::predicates::Predicate;
+use crate::variables::IntegerVariable;
+
+/// Propagator for the constraint that the sequence `xs` spells a word which is accepted by a
+/// deterministic finite automaton.
+///
+/// The automaton is unfolded into a layered graph with a layer of states for every position in
+/// the sequence (and one for the end of the sequence); there is an edge labelled `a` from state
+/// `q` in layer `i` to state `transition(q, a)` in layer `i + 1` if `a` is in the domain of
+/// `xs[i]`. The propagator keeps track of the states which are reachable from the initial state
+/// (the forward states) and the states from which an accepting state in the last layer can be
+/// reached (the backward states); a value `a` is removed from `xs[i]` if there is no edge labelled
+/// `a` from a forward state in layer `i` to a backward state in layer `i + 1`.
+///
+/// The forward and backward states are kept up-to-date incrementally through
+/// [`Propagator::notify`] and [`Propagator::notify_backtrack`]; a domain change of `xs[i]` only
+/// updates the layers after (resp. before) position `i` until a layer is unchanged.
+#[derive(Debug)]
+pub(crate) struct RegularPropagator<Var> {
+    xs: Box<[Var]>,
+    num_states: usize,
+    alphabet_size: usize,
+    /// The state which is reached from a state by reading a symbol, indexed by
+    /// `state * alphabet_size + symbol`; [`None`] if the symbol cannot be read in the state.
+    transitions: Box<[Option<usize>]>,
+    initial_state: usize,
+    accepting_states: Box<[bool]>,
+    /// Whether a state is reachable from the initial state, indexed by
+    /// `layer * num_states + state`.
+    forward: Box<[bool]>,
+    /// Whether an accepting state in the last layer is reachable from a state, indexed by
+    /// `layer * num_states + state`.
+    backward: Box<[bool]>,
+}
+
+impl<Var> RegularPropagator<Var> {
+    /// Creates the propagator; the provided automaton is assumed to be valid, i.e. all states are
+    /// smaller than `num_states`, all symbols are in `[0, alphabet_size)` and there is at most one
+    /// transition for every pair of a state and a symbol.
+    pub(crate) fn new(
+        xs: Box<[Var]>,
+        num_states: usize,
+        alphabet_size: usize,
+        transitions: &[(usize, i32, usize)],
+        initial_state: usize,
+        accepting_states: &[usize],
+    ) -> Self {
+        let mut transition_table = vec![None; num_states * alphabet_size];
+        for &(from, symbol, to) in transitions {
+            transition_table[from * alphabet_size + symbol as usize] = Some(to);
+        }
+
+        let mut is_accepting = vec![false; num_states];
+        for &state in accepting_states {
+            is_accepting[state] = true;
+        }
+
+        // Until the propagator is initialised, every state is considered to be reachable
+        let num_layer_states = (xs.len() + 1) * num_states;
+
+        RegularPropagator {
+            xs,
+            num_states,
+            alphabet_size,
+            transitions: transition_table.into(),
+            initial_state,
+            accepting_states: is_accepting.into(),
+            forward: vec![true; num_layer_states].into(),
+            backward: vec![true; num_layer_states].into(),
+        }
+    }
+}
+
+impl<Var: IntegerVariable> RegularPropagator<Var> {
+    fn transition(&self, state: usize, symbol: usize) -> Option<usize> {
+        self.transitions[state * self.alphabet_size + symbol]
+    }
+
+    fn is_forward(&self, layer: usize, state: usize) -> bool {
+        self.forward[layer * self.num_states + state]
+    }
+
+    fn is_backward(&self, layer: usize, state: usize) -> bool {
+        self.backward[layer * self.num_states + state]
+    }
+
+    fn contains_symbol(&self, context: &impl ReadDomains, position: usize, symbol: usize) -> bool {
+        context.contains(&self.xs[position], symbol as i32)
+    }
+
+    /// Recomputes the forward states of the layers after `position`, stopping at the first layer
+    /// whose forward states do not change.
+    fn update_forward(&mut self, context: &impl ReadDomains, position: usize) {
+        for layer in position..self.xs.len() {
+            let mut next = vec![false; self.num_states];
+            for state in (0..self.num_states).filter(|&state| self.is_forward(layer, state)) {
+                for symbol in 0..self.alphabet_size {
+                    if let Some(to) = self.transition(state, symbol) {
+                        next[to] |= self.contains_symbol(context, layer, symbol);
+                    }
+                }
+            }
+
+            let next_layer =
+                &mut self.forward[(layer + 1) * self.num_states..(layer + 2) * self.num_states];
+            if next_layer == next.as_slice() {
+                return;
+            }
+            next_layer.copy_from_slice(&next);
+        }
+    }
+
+    /// Recomputes the backward states of the layers up to and including `position`, stopping at
+    /// the first layer whose backward states do not change.
+    fn update_backward(&mut self, context: &impl ReadDomains, position: usize) {
+        for layer in (0..=position).rev() {
+            let previous = (0..self.num_states)
+                .map(|state| {
+                    (0..self.alphabet_size).any(|symbol| {
+                        self.transition(state, symbol).is_some_and(|to| {
+                            self.is_backward(layer + 1, to)
+                                && self.contains_symbol(context, layer, symbol)
+                        })
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            let layer_states =
+                &mut self.backward[layer * self.num_states..(layer + 1) * self.num_states];
+            if layer_states == previous.as_slice() {
+                return;
+            }
+            layer_states.copy_from_slice(&previous);
+        }
+    }
+
+    /// Returns whether there is an edge labelled `symbol` from a forward state at `position` to a
+    /// backward state at the next position.
+    fn is_supported(&self, position: usize, symbol: usize) -> bool {
+        (0..self.num_states).any(|state| {
+            self.is_forward(position, state)
+                && self
+                    .transition(state, symbol)
+                    .is_some_and(|to| self.is_backward(position + 1, to))
+        })
+    }
+
+    /// Explains that no accepting path uses an edge at `position` which is not supported.
+    ///
+    /// The explanation consists of the removed values which keep the forward states closed before
+    /// `position` and the non-backward states closed after `position`; under these removals, every
+    /// path reaching layer `position` ends in a forward state and every path from a non-backward
+    /// state in layer `position + 1` ends in a non-accepting state.
+    fn explain(&self, position: usize) -> Vec<Predicate> {
+        let mut reason = vec![];
+        self.explain_forward(position, &mut reason);
+        self.explain_backward(position + 1, &mut reason);
+        reason
+    }
+
+    /// Explains that no accepting state is reachable in the last layer, i.e. that all paths end
+    /// in a (non-accepting) forward state of the last layer.
+    fn explain_conflict(&self) -> PropositionalConjunction {
+        let mut reason = vec![];
+        self.explain_forward(self.xs.len(), &mut reason);
+        reason.into()
+    }
+
+    /// Adds the removed values of the positions before `end` which would otherwise lead from a
+    /// forward state to a non-forward state.
+    fn explain_forward(&self, end: usize, reason: &mut Vec<Predicate>) {
+        for layer in 0..end {
+            let x = &self.xs[layer];
+            reason.extend(
+                (0..self.alphabet_size)
+                    .filter(|&symbol| {
+                        (0..self.num_states).any(|state| {
+                            self.is_forward(layer, state)
+                                && self
+                                    .transition(state, symbol)
+                                    .is_some_and(|to| !self.is_forward(layer + 1, to))
+                        })
+                    })
+                    .map(|symbol| predicate![x != symbol as i32]),
+            );
+        }
+    }
+
+    /// Adds the removed values of the positions from `start` onwards which would otherwise lead
+    /// from a non-backward state to a backward state.
+    fn explain_backward(&self, start: usize, reason: &mut Vec<Predicate>) {
+        for layer in start..self.xs.len() {
+            let x = &self.xs[layer];
+            reason.extend(
+                (0..self.alphabet_size)
+                    .filter(|&symbol| {
+                        (0..self.num_states).any(|state| {
+                            !self.is_backward(layer, state)
+                                && self
+                                    .transition(state, symbol)
+                                    .is_some_and(|to| self.is_backward(layer + 1, to))
+                        })
+                    })
+                    .map(|symbol| predicate![x != symbol as i32]),
+            );
+        }
+    }
+
+    /// Returns whether the automaton accepts the word spelled by the fixed variables.
+    fn accepts(&self, context: &impl ReadDomains) -> bool {
+        let mut state = Some(self.initial_state);
+        for x in self.xs.iter() {
+            let value = context.lower_bound(x);
+            state = state.and_then(|state| {
+                (0..self.alphabet_size as i32)
+                    .contains(&value)
+                    .then(|| self.transition(state, value as usize))
+                    .flatten()
+            });
+        }
+
+        state.is_some_and(|state| self.accepting_states[state])
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Propagator for RegularPropagator<Var> {
+    fn name(&self) -> &str {
+        "Regular"
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        for (index, x) in self.xs.iter().enumerate() {
+            context.register(x.clone(), DomainEvents::ANY_INT);
+            context.register_for_backtrack_events(
+                x.clone(),
+                DomainEvents::ANY_INT,
+                LocalId::from(index as u32),
+            );
+        }
+
+        let num_layers = self.xs.len() + 1;
+        self.forward = vec![false; num_layers * self.num_states].into();
+        self.forward[self.initial_state] = true;
+        self.backward = vec![false; num_layers * self.num_states].into();
+        self.backward[(num_layers - 1) * self.num_states..].copy_from_slice(&self.accepting_states);
+
+        if !self.xs.is_empty() {
+            self.update_forward(context, 0);
+            self.update_backward(context, self.xs.len() - 1);
+        }
+
+        Ok(())
+    }
+
+    fn notify(
+        &mut self,
+        context: PropagationContext,
+        local_id: LocalId,
+        _event: IntDomainEvent,
+    ) -> EnqueueDecision {
+        let position = local_id.unpack() as usize;
+        self.update_forward(&context, position);
+        self.update_backward(&context, position);
+
+        EnqueueDecision::Enqueue
+    }
+
+    fn notify_backtrack(
+        &mut self,
+        context: PropagationContext,
+        local_id: LocalId,
+        _event: IntDomainEvent,
+    ) {
+        let position = local_id.unpack() as usize;
+        self.update_forward(&context, position);
+        self.update_backward(&context, position);
+    }
+
+    fn detect_inconsistency(
+        &self,
+        _context: PropagationContext,
+    ) -> Option<PropositionalConjunction> {
+        if self.xs.is_empty() {
+            return (!self.accepting_states[self.initial_state])
+                .then(PropositionalConjunction::default);
+        }
+
+        let last_layer = self.xs.len();
+        let accepts = (0..self.num_states)
+            .any(|state| self.is_forward(last_layer, state) && self.accepting_states[state]);
+
+        (!accepts).then(|| self.explain_conflict())
+    }
+
+    fn propagate(&self, mut context: PropagationContextMut) -> PropagationStatusCP {
+        if let Some(conflict) = self.detect_inconsistency(context.as_readonly()) {
+            return Err(conflict.into());
+        }
+
+        let largest_symbol = self.alphabet_size as i32 - 1;
+        for (position, x) in self.xs.iter().enumerate() {
+            // Values outside of the alphabet cannot be read by the automaton
+            context.set_lower_bound(x, 0, PropositionalConjunction::default())?;
+            context.set_upper_bound(x, largest_symbol, PropositionalConjunction::default())?;
+
+            let mut reason = None;
+            for symbol in context.lower_bound(x)..=context.upper_bound(x) {
+                if !context.contains(x, symbol) || self.is_supported(position, symbol as usize) {
+                    continue;
+                }
+
+                let reason = reason.get_or_insert_with(|| self.explain(position));
+                context.remove(x, symbol, PropositionalConjunction::from(reason.clone()))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check(&self, context: PropagationContextMut) -> bool {
+        // The forward and backward states are not maintained for the copy of the solution, so the
+        // word is read from scratch
+        self.accepts(&context)
+    }
+}
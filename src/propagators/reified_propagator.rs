@@ -2,12 +2,16 @@ use crate::basic_types::ConflictInfo;
 use crate::basic_types::Inconsistency;
 use crate::basic_types::PropagationStatusCP;
 use crate::engine::cp::domain_events::DomainEvents;
+use crate::engine::cp::propagation::DomainChange;
+use crate::engine::cp::propagation::EnqueueDecision;
 use crate::engine::cp::propagation::LocalId;
+use crate::engine::cp::propagation::PropagationContext;
 use crate::engine::cp::propagation::PropagationContextMut;
 use crate::engine::cp::propagation::Propagator;
 use crate::engine::cp::propagation::PropagatorInitialisationContext;
 use crate::engine::cp::propagation::ReadDomains;
 use crate::engine::cp::BooleanDomainEvent;
+use crate::engine::cp::IntDomainEvent;
 use crate::predicates::PropositionalConjunction;
 use crate::variables::Literal;
 
@@ -49,12 +53,16 @@ impl<WrappedPropagator: Propagator> Propagator for ReifiedPropagator<WrappedProp
         &mut self,
         context: &mut PropagatorInitialisationContext,
     ) -> Result<(), PropositionalConjunction> {
-        // Since we cannot propagate here, we store a conflict which the wrapped propagator
-        // identifies at the root, and propagate the reification literal to false in the
-        // `propagate` method.
+        // The wrapped propagator should only propagate when the reification literal is true, so
+        // its root-level propagations are not applied. Since we cannot propagate here, we store a
+        // conflict which the wrapped propagator identifies at the root, and propagate the
+        // reification literal to false in the `propagate` method.
+        let allows_root_propagation = context.allows_root_propagation();
+        context.set_allows_root_propagation(false);
         if let Err(conjunction) = self.propagator.initialise_at_root(context) {
             self.root_level_inconsistency = Some(conjunction);
         }
+        context.set_allows_root_propagation(allows_root_propagation);
 
         self.reification_literal_id = context.get_next_local_id();
 
@@ -87,6 +95,49 @@ impl<WrappedPropagator: Propagator> Propagator for ReifiedPropagator<WrappedProp
         Ok(())
     }
 
+    fn notify(
+        &mut self,
+        context: PropagationContext,
+        local_id: LocalId,
+        event: IntDomainEvent,
+    ) -> EnqueueDecision {
+        self.propagator.notify(context, local_id, event)
+    }
+
+    fn notify_change(
+        &mut self,
+        context: PropagationContext,
+        change: DomainChange,
+    ) -> EnqueueDecision {
+        self.propagator.notify_change(context, change)
+    }
+
+    fn is_entailed(&self, context: PropagationContext) -> bool {
+        // The wrapped constraint no longer has to hold once the reification literal is false
+        let is_reification_literal_false = context.is_literal_fixed(self.reification_literal)
+            && !context.is_literal_true(self.reification_literal);
+
+        is_reification_literal_false || self.propagator.is_entailed(context)
+    }
+
+    fn check(&self, context: PropagationContextMut) -> bool {
+        // The wrapped constraint only has to hold when the reification literal is true
+        !context.is_literal_true(self.reification_literal) || self.propagator.check(context)
+    }
+
+    fn notify_backtrack(
+        &mut self,
+        context: PropagationContext,
+        local_id: LocalId,
+        event: IntDomainEvent,
+    ) {
+        self.propagator.notify_backtrack(context, local_id, event);
+    }
+
+    fn priority(&self) -> u32 {
+        self.propagator.priority()
+    }
+
     fn name(&self) -> &str {
         &self.name
     }
@@ -125,6 +176,7 @@ mod tests {
     use crate::predicate;
     use crate::predicates::Predicate;
     use crate::predicates::PropositionalConjunction;
+    use crate::propagators::arithmetic::linear_less_or_equal::LinearLessOrEqualPropagator;
 
     #[test]
     fn a_detected_inconsistency_is_given_as_reason_for_propagating_reification_literal_to_false() {
@@ -245,6 +297,77 @@ mod tests {
         assert!(solver.is_literal_false(reification_literal));
     }
 
+    #[test]
+    fn half_reified_linear_does_not_propagate_when_literal_is_unassigned() {
+        let mut solver = TestSolver::default();
+
+        let reification_literal = solver.new_literal();
+        let x = solver.new_variable(3, 10);
+        let y = solver.new_variable(0, 10);
+
+        let _ = solver
+            .new_propagator(ReifiedPropagator::new(
+                LinearLessOrEqualPropagator::new([x, y].into(), 5),
+                reification_literal,
+            ))
+            .expect("no conflict");
+
+        solver.assert_bounds(x, 3, 10);
+        solver.assert_bounds(y, 0, 10);
+        assert!(!solver.is_literal_false(reification_literal));
+    }
+
+    #[test]
+    fn half_reified_linear_propagates_when_literal_is_true() {
+        let mut solver = TestSolver::default();
+
+        let reification_literal = solver.new_literal();
+        let x = solver.new_variable(3, 10);
+        let y = solver.new_variable(0, 10);
+
+        let propagator = solver
+            .new_propagator(ReifiedPropagator::new(
+                LinearLessOrEqualPropagator::new([x, y].into(), 5),
+                reification_literal,
+            ))
+            .expect("no conflict");
+
+        solver.set_literal(reification_literal, true);
+        solver.propagate(propagator).expect("no conflict");
+
+        solver.assert_bounds(x, 3, 5);
+        solver.assert_bounds(y, 0, 2);
+
+        let reason = solver.get_reason_int(predicate![y <= 2].try_into().unwrap());
+        assert_eq!(
+            reason,
+            &PropositionalConjunction::from(vec![predicate![x >= 3], reification_literal.into()])
+        );
+    }
+
+    #[test]
+    fn half_reified_linear_is_inactive_when_literal_is_false() {
+        let mut solver = TestSolver::default();
+
+        let reification_literal = solver.new_literal();
+        solver.set_literal(reification_literal, false);
+
+        let x = solver.new_variable(6, 10);
+        let y = solver.new_variable(0, 10);
+
+        let propagator = solver
+            .new_propagator(ReifiedPropagator::new(
+                LinearLessOrEqualPropagator::new([x, y].into(), 5),
+                reification_literal,
+            ))
+            .expect("no conflict");
+
+        solver.propagate(propagator).expect("no conflict");
+
+        solver.assert_bounds(x, 6, 10);
+        solver.assert_bounds(y, 0, 10);
+    }
+
     struct GenericPropagator<Propagation, ConsistencyCheck, Init> {
         propagation: Propagation,
         consistency_check: ConsistencyCheck,
@@ -0,0 +1,107 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::domain_events::DomainEvents;
+use crate::engine::cp::propagation::PropagationContextMut;
+use crate::engine::cp::propagation::Propagator;
+use crate::engine::cp::propagation::PropagatorInitialisationContext;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::predicate;
+use crate::predicates::Predicate;
+use crate::variables::IntegerVariable;
+
+/// Propagator for the constraint that the value `s` precedes the value `t` in `xs`; i.e. if some
+/// `xs[j] = t`, then there is an `i < j` such that `xs[i] = s`.
+///
+/// Let `alpha` be the first index at which `s` is in the domain. No variable up to and including
+/// `xs[alpha]` can take the value `t`, since there cannot be an occurrence of `s` before it.
+/// Furthermore, if the first variable which is fixed to `t` occurs before any other variable which
+/// can take the value `s`, then `xs[alpha]` is the only support left and it is fixed to `s`.
+#[derive(Debug)]
+pub(crate) struct ValuePrecedePropagator<Var> {
+    s: i32,
+    t: i32,
+    xs: Box<[Var]>,
+}
+
+impl<Var> ValuePrecedePropagator<Var> {
+    pub(crate) fn new(s: i32, t: i32, xs: Box<[Var]>) -> Self {
+        assert_ne!(s, t, "a value cannot precede itself");
+
+        ValuePrecedePropagator { s, t, xs }
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Propagator for ValuePrecedePropagator<Var> {
+    fn name(&self) -> &str {
+        "ValuePrecede"
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        for x in self.xs.iter() {
+            context.register(x.clone(), DomainEvents::ANY_INT);
+        }
+
+        Ok(())
+    }
+
+    fn propagate(&self, mut context: PropagationContextMut) -> PropagationStatusCP {
+        let s = self.s;
+        let t = self.t;
+
+        // The predicates `[xs[k] != s]` for all `k` before the current index
+        let mut prefix_without_s: Vec<Predicate> = vec![];
+        let mut alpha = None;
+
+        for (index, x) in self.xs.iter().enumerate() {
+            if context.contains(x, t) {
+                context.remove(
+                    x,
+                    t,
+                    PropositionalConjunction::from(prefix_without_s.clone()),
+                )?;
+            }
+
+            if context.contains(x, s) {
+                alpha = Some(index);
+                break;
+            }
+
+            prefix_without_s.push(predicate![x != s]);
+        }
+
+        let Some(alpha) = alpha else {
+            return Ok(());
+        };
+
+        let Some(beta) = self.xs[alpha + 1..]
+            .iter()
+            .position(|x| context.is_fixed(x) && context.lower_bound(x) == t)
+            .map(|offset| alpha + 1 + offset)
+        else {
+            return Ok(());
+        };
+
+        if self.xs[alpha + 1..beta]
+            .iter()
+            .any(|x| context.contains(x, s))
+        {
+            return Ok(());
+        }
+
+        // `xs[alpha]` is the only variable before `xs[beta] = t` which can take the value `s`
+        let x_beta = &self.xs[beta];
+        let mut reason = prefix_without_s;
+        reason.extend(self.xs[alpha + 1..beta].iter().map(|x| predicate![x != s]));
+        reason.push(predicate![x_beta == t]);
+        let reason = PropositionalConjunction::from(reason);
+
+        let x_alpha = &self.xs[alpha];
+        context.set_lower_bound(x_alpha, s, reason.clone())?;
+        context.set_upper_bound(x_alpha, s, reason)?;
+
+        Ok(())
+    }
+}
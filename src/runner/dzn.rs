@@ -0,0 +1,240 @@
+//! Typed accessors for the parameters in a DZN data file.
+//!
+//! The accessors of [`DataFile`] return [`None`] when a parameter is missing, or when it has a
+//! different type or shape than requested. The [`DznContext`] wraps the data file together with
+//! the path it was read from, such that every accessor can report which parameter could not be
+//! extracted, what was expected, and from which file.
+
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::path::Path;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use dzn_rs::DataFile;
+use dzn_rs::ShapedArray;
+
+/// A parsed DZN data file, together with the path from which it was read.
+#[derive(Clone, Debug)]
+pub struct DznContext {
+    data: DataFile<i32>,
+    path: PathBuf,
+}
+
+impl DznContext {
+    /// Reads and parses the DZN file at `path`.
+    pub fn read(path: impl Into<PathBuf>) -> anyhow::Result<DznContext> {
+        let path = path.into();
+        let source = std::fs::read_to_string(&path)
+            .with_context(|| format!("Error reading {}", path.display()))?;
+
+        DznContext::parse(&source, path)
+    }
+
+    /// Parses `source` as DZN; `path` is only used to report errors.
+    pub fn parse(source: &str, path: impl Into<PathBuf>) -> anyhow::Result<DznContext> {
+        let path = path.into();
+        let data = dzn_rs::parse::<i32>(source.as_bytes())
+            .with_context(|| format!("Failed to parse DZN from {}", path.display()))?;
+
+        Ok(DznContext { data, path })
+    }
+
+    /// The path from which the data file was read.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The underlying data file.
+    pub fn data(&self) -> &DataFile<i32> {
+        &self.data
+    }
+
+    /// Returns the int parameter `name`.
+    pub fn int(&self, name: &str) -> anyhow::Result<i32> {
+        self.data
+            .get::<i32>(name)
+            .copied()
+            .ok_or_else(|| self.not_found(name, "an int"))
+    }
+
+    /// Returns the int parameter `name`, which should be non-negative.
+    pub fn usize(&self, name: &str) -> anyhow::Result<usize> {
+        let value = self.int(name)?;
+
+        usize::try_from(value).map_err(|_| {
+            anyhow::anyhow!(
+                "Expected parameter '{name}' in {} to be a non-negative int, but it is {value}",
+                self.path.display()
+            )
+        })
+    }
+
+    /// Returns the int array parameter `name`, which should have length `len`.
+    pub fn array_1d_i32(&self, name: &str, len: usize) -> anyhow::Result<Vec<i32>> {
+        let array = self
+            .data
+            .array_1d::<i32>(name, len)
+            .ok_or_else(|| self.not_found(name, format!("an array of int with length {len}")))?;
+
+        Ok(elements(array, len).copied().collect())
+    }
+
+    /// Returns the 2-dimensional int array parameter `name` as a list of rows; the array should
+    /// have `rows` rows and `columns` columns.
+    pub fn array_2d_i32(
+        &self,
+        name: &str,
+        [rows, columns]: [usize; 2],
+    ) -> anyhow::Result<Vec<Vec<i32>>> {
+        let array = self
+            .data
+            .array_2d::<i32>(name, [rows, columns])
+            .ok_or_else(|| {
+                self.not_found(
+                    name,
+                    format!("a 2d array of int with shape [{rows}, {columns}]"),
+                )
+            })?;
+
+        Ok((0..rows)
+            .map(|row| {
+                (0..columns)
+                    .map(|column| {
+                        *array
+                            .get([row, column])
+                            .expect("the array has the requested shape")
+                    })
+                    .collect()
+            })
+            .collect())
+    }
+
+    /// Returns the set of int array parameter `name`, which should have length `len`; the
+    /// elements of every set are sorted.
+    pub fn array_1d_set(&self, name: &str, len: usize) -> anyhow::Result<Vec<Vec<i32>>> {
+        let array = self
+            .data
+            .array_1d::<HashSet<i32>>(name, len)
+            .ok_or_else(|| {
+                self.not_found(name, format!("an array of set of int with length {len}"))
+            })?;
+
+        Ok(elements(array, len)
+            .map(|set| {
+                let mut elements = set.iter().copied().collect::<Vec<_>>();
+                elements.sort_unstable();
+                elements
+            })
+            .collect())
+    }
+
+    fn not_found(&self, name: &str, expected: impl Display) -> anyhow::Error {
+        anyhow::anyhow!(
+            "Expected parameter '{name}' in {} to be {expected}, but it is missing or has a \
+             different type or shape",
+            self.path.display()
+        )
+    }
+}
+
+fn elements<T>(array: &ShapedArray<T, 1>, len: usize) -> impl Iterator<Item = &T> {
+    (0..len).map(|index| {
+        array
+            .get([index])
+            .expect("the array has the requested length")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PATH: &str = "instances/test.dzn";
+
+    fn parse(source: &str) -> DznContext {
+        DznContext::parse(source, PATH).expect("valid dzn")
+    }
+
+    fn error_message<T: std::fmt::Debug>(result: anyhow::Result<T>) -> String {
+        format!(
+            "{:#}",
+            result.expect_err("the parameter should be rejected")
+        )
+    }
+
+    #[test]
+    fn well_formed_parameters_are_extracted() {
+        let data = parse(
+            "n = 2;\n\
+             d = [3, 4];\n\
+             rr = [| 1, 2 | 3, 4 |];\n\
+             suc = [{2, 1}, {}];\n",
+        );
+
+        assert_eq!(data.int("n").unwrap(), 2);
+        assert_eq!(data.usize("n").unwrap(), 2);
+        assert_eq!(data.array_1d_i32("d", 2).unwrap(), vec![3, 4]);
+        assert_eq!(
+            data.array_2d_i32("rr", [2, 2]).unwrap(),
+            vec![vec![1, 2], vec![3, 4]]
+        );
+        assert_eq!(
+            data.array_1d_set("suc", 2).unwrap(),
+            vec![vec![1, 2], vec![]]
+        );
+    }
+
+    #[test]
+    fn missing_int_is_reported_with_name_and_file() {
+        let data = parse("m = 2;\n");
+
+        let message = error_message(data.int("n"));
+        assert!(message.contains("'n'"));
+        assert!(message.contains("an int"));
+        assert!(message.contains(PATH));
+    }
+
+    #[test]
+    fn int_of_wrong_type_is_reported() {
+        let data = parse("n = true;\n");
+
+        let message = error_message(data.int("n"));
+        assert!(message.contains("'n'"));
+        assert!(message.contains("an int"));
+    }
+
+    #[test]
+    fn array_of_wrong_length_is_reported_with_expected_length() {
+        let data = parse("d = [1, 2];\n");
+
+        let message = error_message(data.array_1d_i32("d", 3));
+        assert!(message.contains("'d'"));
+        assert!(message.contains("length 3"));
+        assert!(message.contains(PATH));
+    }
+
+    #[test]
+    fn array_of_wrong_shape_is_reported_with_expected_shape() {
+        let data = parse("rr = [| 1, 2 | 3, 4 |];\n");
+
+        let message = error_message(data.array_2d_i32("rr", [3, 2]));
+        assert!(message.contains("'rr'"));
+        assert!(message.contains("[3, 2]"));
+    }
+
+    #[test]
+    fn int_array_is_not_a_set_array() {
+        let data = parse("suc = [1, 2];\n");
+
+        let message = error_message(data.array_1d_set("suc", 2));
+        assert!(message.contains("'suc'"));
+        assert!(message.contains("set of int"));
+    }
+
+    #[test]
+    fn malformed_dzn_is_reported_with_file() {
+        let message = error_message(DznContext::parse("n = ;\n", PATH));
+        assert!(message.contains(PATH));
+    }
+}
@@ -0,0 +1,1109 @@
+pub mod dzn;
+mod optimality;
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use anyhow::Context;
+use clap::ValueEnum;
+use drcp_format::reader::LiteralAtomicMap;
+use drcp_format::reader::ProofReader;
+use drcp_format::steps::Conclusion;
+use drcp_format::steps::Step;
+use drcp_format::LiteralDefinitions;
+
+use self::dzn::DznContext;
+use self::termination::TerminationCondition;
+use crate::branching::Brancher;
+use crate::engine::constraint_satisfaction_solver::ConflictResolutionStrategy;
+use crate::engine::constraint_satisfaction_solver::NogoodMinimisationStrategy;
+use crate::engine::termination;
+use crate::model::Globals;
+use crate::model::IntVariable;
+use crate::model::LinearEncoding;
+use crate::model::Model;
+use crate::model::Output;
+use crate::model::VariableMap;
+use crate::optimisation::OptimisationStrategy;
+use crate::options::SolverOptions;
+use crate::predicate;
+use crate::predicates::VariableNameLookup;
+use crate::proof::checking::state::CheckingState;
+use crate::proof::checking::verify_proof;
+use crate::proof::process_scaffold;
+use crate::proof::Proof;
+use crate::results::OptimisationResult;
+use crate::results::ProblemSolution;
+use crate::results::Solution;
+use crate::results::SolutionCallbackArguments;
+use crate::statistics::configure;
+use crate::termination::AnyOf;
+use crate::termination::ConflictBudget;
+use crate::termination::TimeBudget;
+use crate::Solver;
+
+pub use self::optimality::OptimalityError;
+
+pub trait OptionEnum: ValueEnum + Clone + Send + Sync + Any + Default {}
+impl<T> OptionEnum for T where T: ValueEnum + Clone + Send + Sync + Any + Default {}
+
+#[derive(Debug, clap::Parser)]
+pub struct Cli<SearchStrategies: OptionEnum> {
+    /// The data for the model.
+    pub instance: PathBuf,
+
+    #[command(subcommand)]
+    pub command: Action<SearchStrategies>,
+}
+
+#[derive(Clone, Debug, clap::Subcommand)]
+pub enum Action<SearchStrategies: OptionEnum> {
+    /// Solve the given instance.
+    Solve {
+        /// The constraints that should _not_ be decomposed.
+        ///
+        /// Multiple constraints can be provided by passing this option multiple times.
+        #[arg(short = 'G', long = "global")]
+        globals: Vec<Globals>,
+
+        /// The encoding to use for the linear constraint. If none is supplied, the propagator is
+        /// used.
+        #[arg(long)]
+        linear_encoding: Option<LinearEncoding>,
+
+        /// The file path to which the proof will be written.
+        ///
+        /// If no path is provided, a proof will not be produced.
+        #[arg(short = 'P')]
+        proof_path: Option<PathBuf>,
+
+        /// The search strategy to use.
+        #[arg(short = 'S', long = "search", value_enum, default_value_t)]
+        search_strategy: SearchStrategies,
+
+        #[arg(short = 'M', long = "minimisation", default_value_t)]
+        minimisation: NogoodMinimisationStrategy,
+
+        /// The procedure which is used to optimise the objective
+        #[arg(short = 'O', long = "optimisation", default_value_t)]
+        optimisation_strategy: OptimisationStrategy,
+
+        /// The conflict resolution strategy to use
+        #[arg(short = 'C', long = "resolution", default_value_t)]
+        conflict_resolution: ConflictResolutionStrategy,
+
+        /// Whether to use a non-trivial conflict explanation
+        #[arg(short = 'E', long = "non-trivial-conflict")]
+        use_non_trivial_conflict_explanation: bool,
+
+        /// Whether to use a non-trivial propagation explanation
+        #[arg(short = 'R', long = "non-trivial-propagation")]
+        use_non_trivial_propagation_explanation: bool,
+
+        /// Whether to print a summary of the model (the number of variables, propagators, and
+        /// clauses) before solving, and the variables which changed in every improving solution
+        #[arg(short = 'v', long = "verbose")]
+        verbose: bool,
+
+        /// The budget after which the search is terminated.
+        #[arg(short = 'T', long = "termination", default_value_t)]
+        termination: TerminationBudget,
+
+        /// The number of conflicts after which the search is terminated when the termination
+        /// budget includes conflicts.
+        #[arg(long = "conflicts", default_value_t = 1_000_000)]
+        conflict_budget: u64,
+
+        /// The number of seconds the solver is allowed to run.
+        time_out: u64,
+    },
+
+    Processing {
+        /// The path to the proof scaffold.
+        scaffold: PathBuf,
+
+        /// Output path of the full proof. The new literal mapping will be in the same location but
+        /// with the extension `.lits`.
+        output_path: PathBuf,
+    },
+
+    /// Check the proof of this instance.
+    Verify {
+        /// The file path to the proof.
+        proof_path: PathBuf,
+
+        /// The file path to a solution printed by the solver (`name = value;` lines). If the proof
+        /// concludes optimality, the solution should satisfy the model and its objective value
+        /// should be equal to the proven bound.
+        #[arg(long = "solution")]
+        solution_path: Option<PathBuf>,
+    },
+}
+
+/// The budget after which the search of the runner is terminated.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum TerminationBudget {
+    /// Terminates once the time-out has passed.
+    #[default]
+    Time,
+    /// Terminates once the conflict budget is exhausted.
+    Conflicts,
+    /// Terminates once either the time-out has passed or the conflict budget is exhausted.
+    Combined,
+}
+
+impl Display for TerminationBudget {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TerminationBudget::Time => write!(f, "time"),
+            TerminationBudget::Conflicts => write!(f, "conflicts"),
+            TerminationBudget::Combined => write!(f, "combined"),
+        }
+    }
+}
+
+impl TerminationBudget {
+    /// Creates the [`TerminationCondition`] for this budget, which starts counting the time
+    /// immediately.
+    pub fn create_termination(
+        self,
+        time_out: Duration,
+        conflict_budget: u64,
+    ) -> Box<dyn TerminationCondition> {
+        match self {
+            TerminationBudget::Time => Box::new(TimeBudget::starting_now(time_out)),
+            TerminationBudget::Conflicts => Box::new(ConflictBudget::with_limit(conflict_budget)),
+            TerminationBudget::Combined => Box::new(AnyOf::new(
+                TimeBudget::starting_now(time_out),
+                ConflictBudget::with_limit(conflict_budget),
+            )),
+        }
+    }
+}
+
+/// Definition of a problem instance to be solved with Munchkin.
+pub trait Problem<SearchStrategies>: Sized {
+    /// Constructor function which creates an instance of `Self`, as well as the [`Model`] for the
+    /// problem.
+    ///
+    /// New problems should implement [`Problem::create_from_dzn`] instead, which provides typed
+    /// accessors that report which parameter could not be extracted from which file.
+    fn create(_data: dzn_rs::DataFile<i32>) -> anyhow::Result<(Self, Model)> {
+        anyhow::bail!(
+            "Either `Problem::create` or `Problem::create_from_dzn` should be implemented"
+        )
+    }
+
+    /// Constructor function which creates an instance of `Self`, as well as the [`Model`] for the
+    /// problem, from the parsed data file.
+    ///
+    /// By default, this calls [`Problem::create`] with the underlying data file.
+    fn create_from_dzn(data: &DznContext) -> anyhow::Result<(Self, Model)> {
+        Self::create(data.data().clone())
+    }
+
+    /// The objective variable.
+    fn objective(&self) -> IntVariable;
+
+    fn get_search(
+        &self,
+        strategy: SearchStrategies,
+        solver: &Solver,
+        solver_variables: &VariableMap,
+    ) -> impl Brancher + 'static;
+
+    fn get_output_variables(&self) -> impl Iterator<Item = Output> + '_;
+}
+
+#[macro_export]
+macro_rules! entry_point {
+    (problem = $problem:ident, search_strategies = $search_strategies:ident) => {
+        fn main() -> anyhow::Result<()> {
+            $crate::runner::run::<$problem, $search_strategies>()
+        }
+    };
+}
+
+pub fn run<ProblemType, SearchStrategies>() -> anyhow::Result<()>
+where
+    ProblemType: Problem<SearchStrategies>,
+    SearchStrategies: OptionEnum,
+{
+    use clap::Parser;
+
+    let args = Cli::<SearchStrategies>::parse();
+
+    configure(true, "%% ", None);
+
+    let data = DznContext::read(&args.instance)?;
+    let (instance, model) = ProblemType::create_from_dzn(&data)?;
+    model.validate().map_err(|errors| {
+        let errors = errors
+            .iter()
+            .map(|error| format!("- {error}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        anyhow::anyhow!("The model is invalid:\n{errors}")
+    })?;
+
+    match args.command {
+        Action::Solve {
+            globals,
+            linear_encoding,
+            proof_path,
+            search_strategy,
+            conflict_resolution,
+            minimisation,
+            optimisation_strategy,
+            time_out,
+            use_non_trivial_conflict_explanation: use_non_generic_conflict_explanation,
+            use_non_trivial_propagation_explanation: use_non_generic_propagation_explanation,
+            verbose,
+            termination,
+            conflict_budget,
+        } => solve(
+            model,
+            instance,
+            search_strategy,
+            globals,
+            linear_encoding,
+            conflict_resolution,
+            minimisation,
+            optimisation_strategy,
+            use_non_generic_conflict_explanation,
+            use_non_generic_propagation_explanation,
+            proof_path,
+            termination.create_termination(Duration::from_secs(time_out), conflict_budget),
+            verbose,
+        ),
+        Action::Processing {
+            scaffold,
+            output_path,
+        } => process(model, scaffold, output_path),
+        Action::Verify {
+            proof_path,
+            solution_path,
+        } => verify(model, instance.objective(), proof_path, solution_path),
+    }
+}
+
+#[allow(clippy::too_many_arguments, reason = "All arguments need to be passed")]
+pub fn solve<SearchStrategies>(
+    model: Model,
+    instance: impl Problem<SearchStrategies>,
+    search_strategy: SearchStrategies,
+    globals: Vec<Globals>,
+    linear_encoding: Option<LinearEncoding>,
+    conflict_resolution: ConflictResolutionStrategy,
+    minimisation: NogoodMinimisationStrategy,
+    optimisation_strategy: OptimisationStrategy,
+    use_non_generic_conflict_explanation: bool,
+    use_non_generic_propagation_explanation: bool,
+    proof_path: Option<PathBuf>,
+    mut termination: Box<dyn TerminationCondition>,
+    verbose: bool,
+) -> anyhow::Result<()> {
+    let proof = proof_path
+        .map(|path| {
+            let proof_file = File::create(&path)
+                .with_context(|| format!("Failed to create proof file {}", path.display()))?;
+
+            Ok::<_, anyhow::Error>(Proof::new(proof_file, path.with_extension("lits")))
+        })
+        .transpose()?;
+
+    let options = SolverOptions::builder()
+        .with_conflict_resolver(conflict_resolution)
+        .with_minimisation_strategy(minimisation)
+        .with_non_generic_conflict_explanation(use_non_generic_conflict_explanation)
+        .with_non_generic_propagation_explanation(use_non_generic_propagation_explanation)
+        .with_proof(proof.unwrap_or_default())
+        .build()
+        .context("Invalid solver options")?;
+
+    let (mut solver, solver_variables) = model.into_solver(
+        options,
+        |global| globals.contains(&global),
+        linear_encoding,
+        &mut termination,
+    );
+
+    if termination.should_stop() {
+        solver.log_statistics();
+        print_termination_trigger(&termination);
+        println!("UNKNOWN");
+        return Ok(());
+    }
+
+    if verbose {
+        for line in solver.model_summary().to_string().lines() {
+            println!("%% {line}");
+        }
+    }
+
+    let output_variables: Vec<_> = instance.get_output_variables().collect();
+    let callback_solver_variables = solver_variables.clone();
+
+    // Only the final solution is printed in full; printing every improving solution dominates the
+    // runtime for instances with large outputs.
+    solver.with_final_solution_callback(move |arguments| {
+        for output in &output_variables {
+            print_output(output, &callback_solver_variables, arguments.solution);
+        }
+
+        println!("----------");
+    });
+
+    if verbose {
+        let callback_solver_variables = solver_variables.clone();
+        let previous_solution = RefCell::new(Solution::default());
+
+        solver.with_solution_callback(move |arguments| {
+            print_changed_variables(
+                arguments,
+                &previous_solution.borrow(),
+                &callback_solver_variables,
+            );
+            *previous_solution.borrow_mut() = arguments.solution.clone();
+        });
+    }
+
+    let mut brancher = instance.get_search(search_strategy, &solver, &solver_variables);
+    let objective_variable = solver_variables.to_solver_variable(instance.objective());
+
+    match solver.minimise_with_strategy(
+        &mut brancher,
+        &mut termination,
+        objective_variable.clone(),
+        optimisation_strategy,
+    ) {
+        // Printing of the solution is handled in the callback.
+        OptimisationResult::Optimal(solution) => {
+            let objective_bound = solution.get_integer_value(objective_variable.clone());
+            let literal = solver.get_literal(predicate![objective_variable >= objective_bound]);
+            solver.conclude_proof_optimal(literal);
+
+            println!("==========")
+        }
+        OptimisationResult::Satisfiable(_) => {}
+
+        OptimisationResult::Unsatisfiable => {
+            solver.log_statistics();
+            solver.conclude_proof_unsat();
+            println!("UNSATISFIABLE");
+        }
+        OptimisationResult::Unknown(_) => {
+            solver.log_statistics();
+            print_termination_trigger(&termination);
+            println!("UNKNOWN");
+        }
+    }
+
+    Ok(())
+}
+
+/// Prints the budget which caused the provided [`TerminationCondition`] to trigger (if any).
+fn print_termination_trigger(termination: &impl TerminationCondition) {
+    if let Some(description) = termination.describe_trigger() {
+        println!("%% termination: {description}");
+    }
+}
+
+/// Prints the named variables whose value changed since the `previous` solution as comments.
+fn print_changed_variables(
+    arguments: SolutionCallbackArguments,
+    previous: &Solution,
+    solver_variables: &VariableMap,
+) {
+    for domain in arguments.changed_since_last(previous) {
+        if let Some(name) = solver_variables.integer_name(domain) {
+            println!(
+                "%% {name} = {}",
+                arguments.solution.get_integer_value(domain)
+            );
+        }
+    }
+
+    println!("%% ----------");
+}
+
+fn print_output(output: &Output, solver_variables: &VariableMap, solution: &Solution) {
+    let name = solver_variables.get_name(output);
+
+    match output {
+        Output::Variable(variable) => {
+            let solver_variable = solver_variables.to_solver_variable(*variable);
+
+            println!(
+                "{name} = {};",
+                solution.get_integer_value(solver_variable.clone())
+            );
+        }
+
+        Output::Array(int_variable_array) => {
+            let solver_variables = solver_variables.get_array(*int_variable_array);
+            let num_variables = solver_variables.len();
+
+            print!("{name} = [");
+            for (idx, variable) in solver_variables.into_iter().enumerate() {
+                print!("{}", solution.get_integer_value(variable));
+
+                if idx < num_variables - 1 {
+                    print!(", ");
+                }
+            }
+            println!("];");
+        }
+    }
+}
+
+/// Verifies the proof at `proof_path` for the model. If the proof concludes optimality, the
+/// conclusion should be a bound on `objective`, and the solution at `solution_path` (if provided)
+/// is checked against the model and the proven bound; see [`OptimalityError`].
+pub fn verify(
+    model: Model,
+    objective: IntVariable,
+    proof_path: PathBuf,
+    solution_path: Option<PathBuf>,
+) -> anyhow::Result<()> {
+    // First, we read the contents of the `.drcp` and `.lits` files.
+    let proof = create_proof_reader_for_checker(&proof_path)?;
+    let conclusion = find_conclusion(proof)?;
+
+    let objective_bound = match &conclusion {
+        Conclusion::Optimal(bound) => Some(optimality::objective_bound(&model, objective, bound)?),
+        Conclusion::Unsatisfiable => None,
+    };
+
+    // Then, we can run the checker, giving it the proof reader and the model.
+    let proof = create_proof_reader_for_checker(&proof_path)?;
+    let mut state = CheckingState::from(model.clone());
+    if let Conclusion::Optimal(drcp_format::AtomicConstraint::Int(atomic)) = conclusion {
+        state.set_objective_bound(atomic).map_err(|_| {
+            anyhow::anyhow!("Negating the objective already leads to an empty domain.")
+        })?;
+    }
+    verify_proof(state, proof)?;
+
+    // Finally, the proven bound should be attained by the solution.
+    match (solution_path, objective_bound) {
+        (Some(solution_path), Some(bound)) => {
+            optimality::check_solution(&model, objective, bound, &solution_path)
+        }
+        (Some(_), None) => anyhow::bail!("The proof concludes that the model is unsatisfiable."),
+        (None, _) => Ok(()),
+    }
+}
+
+fn create_proof_reader_for_checker(
+    proof_path: &Path,
+) -> anyhow::Result<ProofReader<File, LiteralDefinitions<String>>> {
+    let lits_file = proof_path.with_extension("lits");
+    let lits = File::open(&lits_file)
+        .with_context(|| format!("Failed to open {}", lits_file.display()))?;
+    let proof_file = File::open(proof_path)
+        .with_context(|| format!("Failed to open {}", proof_path.display()))?;
+    let literals = LiteralDefinitions::<String>::parse(lits).with_context(|| {
+        format!(
+            "Failed to parse literal definition from {}",
+            lits_file.display()
+        )
+    })?;
+    let proof = ProofReader::new(proof_file, literals);
+    Ok(proof)
+}
+
+fn process(model: Model, scaffold: PathBuf, output: PathBuf) -> anyhow::Result<()> {
+    // First, we read the contents of the `.drcp` and `.lits` files.
+    let lits_file_path = scaffold.with_extension("lits");
+    let lits_file = File::open(&lits_file_path)
+        .with_context(|| format!("Failed to open {}", lits_file_path.display()))?;
+    let definitions = LiteralDefinitions::<String>::parse(lits_file).with_context(|| {
+        format!(
+            "Failed to parse literal definition from {}",
+            lits_file_path.display()
+        )
+    })?;
+    let scaffold_file =
+        File::open(&scaffold).with_context(|| format!("Failed to open {}", scaffold.display()))?;
+
+    // Then, we run the processing pipeline which writes the full proof to the output path.
+    let output_file = File::create(&output)
+        .with_context(|| format!("Failed to create proof file {}.", output.display()))?;
+    let mut report = process_scaffold(
+        &model,
+        scaffold_file,
+        definitions,
+        output_file,
+        drcp_format::Format::Text,
+    )?;
+
+    println!("%% scaffoldSteps={}", report.input_steps);
+    println!("%% nogoodsAfterTrimming={}", report.trimmed_steps);
+    println!("%% numberOfInferences={}", report.introduced_inferences);
+
+    // Finally, the new literal mapping is written next to the proof.
+    let literals_file_path = output.with_extension("lits");
+    let literals_file = File::create(&literals_file_path)
+        .with_context(|| format!("Failed to create file {}", literals_file_path.display()))?;
+    report.literal_definitions.write(literals_file)?;
+
+    Ok(())
+}
+
+fn find_conclusion<R: Read, Atomics: LiteralAtomicMap>(
+    mut proof: ProofReader<R, Atomics>,
+) -> anyhow::Result<Conclusion<Atomics::Atomic>> {
+    while let Some(step) = proof.next_step()? {
+        if let Step::Conclusion(conclusion) = step {
+            return Ok(conclusion);
+        }
+    }
+
+    anyhow::bail!("Cannot find conclusion in proof.")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use clap::ValueEnum;
+
+    use std::path::Path;
+    use std::path::PathBuf;
+
+    use super::create_proof_reader_for_checker;
+    use super::dzn::DznContext;
+    use super::find_conclusion;
+    use super::optimality::check_solution;
+    use super::optimality::objective_bound;
+    use super::solve;
+    use super::OptimalityError;
+    use super::Problem;
+    use super::TerminationBudget;
+    use drcp_format::steps::Conclusion;
+    use drcp_format::AtomicConstraint;
+    use drcp_format::IntAtomicConstraint;
+
+    use crate::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
+    use crate::branching::Brancher;
+    use crate::branching::InDomainMin;
+    use crate::branching::InputOrder;
+    use crate::engine::constraint_satisfaction_solver::ConflictResolutionStrategy;
+    use crate::engine::constraint_satisfaction_solver::NogoodMinimisationStrategy;
+    use crate::model::Constraint;
+    use crate::model::Globals;
+    use crate::model::IntVariable;
+    use crate::model::IntVariableArray;
+    use crate::model::Model;
+    use crate::model::Output;
+    use crate::model::VariableMap;
+    use crate::optimisation::OptimisationStrategy;
+    use crate::options::SolverOptions;
+    use crate::results::OptimisationResult;
+    use crate::results::ProblemSolution;
+    use crate::termination::Indefinite;
+    use crate::termination::SolverSizeSnapshot;
+    use crate::termination::TerminationReason;
+    use crate::Solver;
+
+    const ONE_NODE_TSP: &str = "data/tsp/TSP_N1.dzn";
+    const FIVE_NODE_TSP: &str = "data/tsp/TSP_N5_0.dzn";
+    const FIVE_NODE_TSP_OPTIMUM: i32 = 757;
+    /// The optimal solution of [`FIVE_NODE_TSP`], as printed by the runner.
+    const FIVE_NODE_TSP_SOLUTION: &str =
+        "Successor = [3, 5, 2, 1, 4];\nObjective = 757;\n----------\n==========\n";
+
+    #[derive(Clone, Default, ValueEnum)]
+    enum SearchStrategies {
+        #[default]
+        Default,
+    }
+
+    /// The travelling salesperson model of the `tsp` example.
+    struct TravellingSalesperson {
+        successors: IntVariableArray,
+        objective: IntVariable,
+    }
+
+    impl Problem<SearchStrategies> for TravellingSalesperson {
+        fn create_from_dzn(data: &DznContext) -> anyhow::Result<(Self, Model)> {
+            let mut model = Model::default();
+
+            let n = data.int("N")?;
+            let n_usize = data.usize("N")?;
+            let dist = data.array_2d_i32("Dist", [n_usize, n_usize])?;
+
+            let successors = model.new_interval_variable_array("Successor", 1, n, n_usize);
+            let successors_array: Vec<_> = successors.as_array(&model).collect();
+            model.add_constraint(Constraint::Circuit(successors_array.clone()));
+
+            let max_objective = dist.iter().flatten().sum();
+            let objective = model.new_interval_variable("Objective", 0, max_objective);
+
+            let outgoing_costs: Vec<_> = successors_array
+                .iter()
+                .enumerate()
+                .map(|(node, &successor)| {
+                    let max_distance = dist[node].iter().copied().max().unwrap();
+                    let outgoing_cost = model.new_interval_variable(
+                        format!("_OutgoingCost[{node}]"),
+                        0,
+                        max_distance,
+                    );
+                    let distances_from_node = dist[node]
+                        .iter()
+                        .enumerate()
+                        .map(|(idx, &dist)| {
+                            model.new_interval_variable(
+                                format!("_Dist[{node}][{}]", idx + 1),
+                                dist,
+                                dist,
+                            )
+                        })
+                        .collect();
+
+                    model.add_constraint(Constraint::Element {
+                        array: distances_from_node,
+                        index: successor,
+                        rhs: outgoing_cost,
+                    });
+
+                    outgoing_cost
+                })
+                .collect();
+
+            model.add_constraint(Constraint::LinearEqual {
+                terms: outgoing_costs
+                    .into_iter()
+                    .chain(std::iter::once(objective.scaled(-1)))
+                    .collect(),
+                rhs: 0,
+            });
+
+            Ok((
+                TravellingSalesperson {
+                    successors,
+                    objective,
+                },
+                model,
+            ))
+        }
+
+        fn objective(&self) -> IntVariable {
+            self.objective
+        }
+
+        fn get_search(
+            &self,
+            _: SearchStrategies,
+            _: &Solver,
+            variables: &VariableMap,
+        ) -> impl Brancher + 'static {
+            IndependentVariableValueBrancher::new(
+                InputOrder::new(variables.get_array(self.successors)),
+                InDomainMin,
+            )
+        }
+
+        fn get_output_variables(&self) -> impl Iterator<Item = Output> + '_ {
+            [
+                Output::Array(self.successors),
+                Output::Variable(self.objective),
+            ]
+            .into_iter()
+        }
+    }
+
+    fn one_node_tsp() -> (TravellingSalesperson, Model) {
+        let data = DznContext::read(ONE_NODE_TSP).expect("the instance exists");
+        TravellingSalesperson::create_from_dzn(&data).expect("the instance is well-formed")
+    }
+
+    #[test]
+    fn one_node_tsp_is_a_self_loop() {
+        let (instance, model) = one_node_tsp();
+        assert_eq!(model.validate(), Ok(()));
+
+        let (mut solver, variables) =
+            model.into_solver(SolverOptions::default(), |_| false, None, &mut Indefinite);
+        let mut brancher = instance.get_search(SearchStrategies::Default, &solver, &variables);
+        let objective = variables.to_solver_variable(instance.objective());
+
+        let OptimisationResult::Optimal(solution) =
+            solver.minimise(&mut brancher, &mut Indefinite, objective.clone())
+        else {
+            panic!("expected the problem to be solved to optimality");
+        };
+
+        let successor = variables.get_array(instance.successors)[0].clone();
+        assert_eq!(solution.get_integer_value(successor), 1);
+        assert_eq!(solution.get_integer_value(objective), 0);
+    }
+
+    /// Solves the model to optimality, returning the solver, the variables and the optimal
+    /// solution.
+    #[cfg(feature = "serde")]
+    fn solve_to_optimality(
+        instance: &TravellingSalesperson,
+        model: Model,
+    ) -> (Solver, VariableMap, crate::results::Solution) {
+        let (mut solver, variables) =
+            model.into_solver(SolverOptions::default(), |_| false, None, &mut Indefinite);
+        let mut brancher = instance.get_search(SearchStrategies::Default, &solver, &variables);
+        let objective = variables.to_solver_variable(instance.objective());
+
+        let OptimisationResult::Optimal(solution) =
+            solver.minimise(&mut brancher, &mut Indefinite, objective)
+        else {
+            panic!("expected the problem to be solved to optimality");
+        };
+
+        (solver, variables, solution)
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn deserialised_model_has_the_same_optimum() {
+        let data = DznContext::read(FIVE_NODE_TSP).expect("the instance exists");
+        let (instance, model) =
+            TravellingSalesperson::create_from_dzn(&data).expect("the instance is well-formed");
+
+        let json = serde_json::to_string(&model).expect("the model can be serialised");
+        let deserialised: Model = serde_json::from_str(&json).expect("the model is valid JSON");
+        assert_eq!(
+            serde_json::to_string(&deserialised).expect("the model can be serialised"),
+            json
+        );
+
+        let objective = instance.objective();
+        let (_, variables, solution) = solve_to_optimality(&instance, model);
+        let optimum = solution.get_integer_value(variables.to_solver_variable(objective));
+        let (_, variables, solution) = solve_to_optimality(&instance, deserialised);
+        let deserialised_optimum =
+            solution.get_integer_value(variables.to_solver_variable(objective));
+
+        assert_eq!(optimum, FIVE_NODE_TSP_OPTIMUM);
+        assert_eq!(deserialised_optimum, FIVE_NODE_TSP_OPTIMUM);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn deserialised_solution_is_accepted_as_initial_solution() {
+        let data = DznContext::read(FIVE_NODE_TSP).expect("the instance exists");
+        let (instance, model) =
+            TravellingSalesperson::create_from_dzn(&data).expect("the instance is well-formed");
+        let (_, _, solution) = solve_to_optimality(&instance, model.clone());
+
+        let json = serde_json::to_string(&solution).expect("the solution can be serialised");
+        assert!(json.contains(&format!("\"Objective\":{FIVE_NODE_TSP_OPTIMUM}")));
+        let deserialised: crate::results::Solution =
+            serde_json::from_str(&json).expect("the solution is valid JSON");
+
+        let (mut solver, variables) =
+            model.into_solver(SolverOptions::default(), |_| false, None, &mut Indefinite);
+        let num_solutions = std::rc::Rc::new(std::cell::Cell::new(0));
+        let counter = std::rc::Rc::clone(&num_solutions);
+        solver.with_solution_callback(move |_| counter.set(counter.get() + 1));
+
+        let mut brancher = instance.get_search(SearchStrategies::Default, &solver, &variables);
+        let objective = variables.to_solver_variable(instance.objective());
+        let result = solver.minimise_with_initial_solution(
+            &mut brancher,
+            &mut Indefinite,
+            objective.clone(),
+            &deserialised,
+        );
+
+        // The initial solution is optimal, so no improving solutions are found
+        assert_eq!(
+            result.objective_value(&objective),
+            Some(FIVE_NODE_TSP_OPTIMUM)
+        );
+        assert_eq!(num_solutions.get(), 0);
+    }
+
+    #[test]
+    fn termination_budgets_include_the_conflict_budget_when_requested() {
+        let conflict_triggers = |budget: TerminationBudget| {
+            let mut termination = budget.create_termination(Duration::from_secs(100), 1);
+            assert!(!termination.should_stop());
+            termination.observe(&SolverSizeSnapshot::default());
+            termination.should_stop()
+                && termination.reason() == Some(TerminationReason::ConflictBudget)
+        };
+
+        assert!(!conflict_triggers(TerminationBudget::Time));
+        assert!(conflict_triggers(TerminationBudget::Conflicts));
+        assert!(conflict_triggers(TerminationBudget::Combined));
+    }
+
+    #[test]
+    fn one_node_tsp_is_solved_by_the_runner() {
+        let (instance, model) = one_node_tsp();
+
+        let result = solve(
+            model,
+            instance,
+            SearchStrategies::Default,
+            vec![],
+            None,
+            ConflictResolutionStrategy::default(),
+            NogoodMinimisationStrategy::default(),
+            OptimisationStrategy::default(),
+            false,
+            false,
+            None,
+            TerminationBudget::Combined.create_termination(Duration::from_secs(10), 1000),
+            false,
+        );
+        assert!(result.is_ok());
+    }
+
+    /// Solves the five node instance with the runner while logging a proof, and returns the path
+    /// of the proof.
+    fn five_node_tsp_proof(name: &str) -> PathBuf {
+        let proof_path =
+            std::env::temp_dir().join(format!("munchkin-{name}-{}.drcp", std::process::id()));
+        let (instance, model) = five_node_tsp();
+
+        let result = solve(
+            model,
+            instance,
+            SearchStrategies::Default,
+            vec![],
+            None,
+            ConflictResolutionStrategy::default(),
+            NogoodMinimisationStrategy::default(),
+            OptimisationStrategy::default(),
+            false,
+            false,
+            Some(proof_path.clone()),
+            Box::new(Indefinite),
+            false,
+        );
+        assert!(result.is_ok());
+
+        proof_path
+    }
+
+    /// Writes a solution in the format of the runner to a temporary file, and returns its path.
+    fn write_solution(name: &str, solution: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("munchkin-{name}-{}.sol", std::process::id()));
+        std::fs::write(&path, solution).expect("the solution can be written");
+        path
+    }
+
+    fn remove_files(proof_path: &Path, solution_path: &Path) {
+        let _ = std::fs::remove_file(proof_path);
+        let _ = std::fs::remove_file(proof_path.with_extension("lits"));
+        let _ = std::fs::remove_file(solution_path);
+    }
+
+    fn five_node_tsp() -> (TravellingSalesperson, Model) {
+        let data = DznContext::read(FIVE_NODE_TSP).expect("the instance exists");
+        TravellingSalesperson::create_from_dzn(&data).expect("the instance is well-formed")
+    }
+
+    /// Adds the redundant predecessors of the `tsp` example, which are the inverse of the
+    /// successors.
+    fn add_predecessors(instance: &TravellingSalesperson, model: &mut Model) {
+        let successors: Vec<_> = instance.successors.as_array(model).collect();
+        let n = successors.len();
+
+        let predecessors = model.new_interval_variable_array("Predecessor", 1, n as i32, n);
+        let predecessors = predecessors.as_array(model).collect();
+        model.add_constraint(Constraint::Inverse {
+            f: successors,
+            g: predecessors,
+        });
+    }
+
+    /// Minimises the model with the inverse propagator, returning the optimum and the number of
+    /// conflicts which were needed to prove it.
+    fn minimise_with_inverse(instance: &TravellingSalesperson, model: Model) -> (i32, u64) {
+        let (mut solver, variables) = model.into_solver(
+            SolverOptions::default(),
+            |globals| globals == Globals::Inverse,
+            None,
+            &mut Indefinite,
+        );
+        let mut brancher = instance.get_search(SearchStrategies::Default, &solver, &variables);
+        let objective = variables.to_solver_variable(instance.objective());
+
+        let OptimisationResult::Optimal(solution) =
+            solver.minimise(&mut brancher, &mut Indefinite, objective.clone())
+        else {
+            panic!("expected the problem to be solved to optimality");
+        };
+
+        (
+            solution.get_integer_value(objective),
+            solver.statistics().conflicts,
+        )
+    }
+
+    #[test]
+    fn redundant_predecessors_find_the_optimum_with_fewer_conflicts() {
+        let (instance, model) = five_node_tsp();
+        let mut model_with_predecessors = model.clone();
+        add_predecessors(&instance, &mut model_with_predecessors);
+        assert_eq!(model_with_predecessors.validate(), Ok(()));
+
+        let (optimum, conflicts) = minimise_with_inverse(&instance, model);
+        let (optimum_with_predecessors, conflicts_with_predecessors) =
+            minimise_with_inverse(&instance, model_with_predecessors);
+
+        assert_eq!(optimum, FIVE_NODE_TSP_OPTIMUM);
+        assert_eq!(optimum_with_predecessors, FIVE_NODE_TSP_OPTIMUM);
+        assert!(
+            conflicts_with_predecessors < conflicts,
+            "{conflicts_with_predecessors} conflicts with predecessors, {conflicts} without"
+        );
+    }
+
+    fn optimality_error(result: anyhow::Result<()>) -> OptimalityError {
+        result
+            .expect_err("the check should fail")
+            .downcast::<OptimalityError>()
+            .expect("the check fails with an optimality error")
+    }
+
+    #[test]
+    fn proven_optimum_is_attained_by_the_solution() {
+        let proof_path = five_node_tsp_proof("valid-optimum");
+        let solution_path = write_solution("valid-optimum", FIVE_NODE_TSP_SOLUTION);
+        let (instance, model) = five_node_tsp();
+
+        let proof = create_proof_reader_for_checker(&proof_path).expect("the proof exists");
+        let Conclusion::Optimal(conclusion) =
+            find_conclusion(proof).expect("there is a conclusion")
+        else {
+            panic!("expected an optimality conclusion");
+        };
+        let bound = objective_bound(&model, instance.objective(), &conclusion);
+        let result = check_solution(
+            &model,
+            instance.objective(),
+            FIVE_NODE_TSP_OPTIMUM as i64,
+            &solution_path,
+        );
+        remove_files(&proof_path, &solution_path);
+
+        assert_eq!(bound, Ok(FIVE_NODE_TSP_OPTIMUM as i64));
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn tampered_bound_is_reported_as_a_bound_mismatch() {
+        let proof_path = five_node_tsp_proof("tampered-bound");
+        let solution_path = write_solution("tampered-bound", FIVE_NODE_TSP_SOLUTION);
+        let (instance, model) = five_node_tsp();
+
+        let proof = create_proof_reader_for_checker(&proof_path).expect("the proof exists");
+        let Conclusion::Optimal(AtomicConstraint::Int(conclusion)) =
+            find_conclusion(proof).expect("there is a conclusion")
+        else {
+            panic!("expected an optimality conclusion on an integer variable");
+        };
+
+        let tampered_bound = AtomicConstraint::Int(IntAtomicConstraint {
+            value: conclusion.value + 1,
+            ..conclusion.clone()
+        });
+        let bound = objective_bound(&model, instance.objective(), &tampered_bound)
+            .expect("the conclusion is a bound on the objective");
+        let result = check_solution(&model, instance.objective(), bound, &solution_path);
+
+        let other_variable = AtomicConstraint::Int(IntAtomicConstraint {
+            name: "Successor[0]".to_owned(),
+            ..conclusion
+        });
+        let unexpected_objective = objective_bound(&model, instance.objective(), &other_variable);
+        remove_files(&proof_path, &solution_path);
+
+        assert_eq!(
+            optimality_error(result),
+            OptimalityError::BoundMismatch {
+                bound: FIVE_NODE_TSP_OPTIMUM as i64 + 1,
+                value: FIVE_NODE_TSP_OPTIMUM as i64,
+            }
+        );
+        assert_eq!(
+            unexpected_objective,
+            Err(OptimalityError::UnexpectedObjective {
+                objective: "Objective".to_owned(),
+                found: "Successor[0]".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn tampered_solution_is_reported_as_a_constraint_violation() {
+        let (instance, model) = five_node_tsp();
+        let bound = FIVE_NODE_TSP_OPTIMUM as i64;
+
+        let wrong_objective = FIVE_NODE_TSP_SOLUTION.replace(
+            &format!("Objective = {FIVE_NODE_TSP_OPTIMUM};"),
+            &format!("Objective = {};", FIVE_NODE_TSP_OPTIMUM - 1),
+        );
+        let solution_path = write_solution("wrong-objective", &wrong_objective);
+        let wrong_objective_result =
+            check_solution(&model, instance.objective(), bound, &solution_path);
+        let _ = std::fs::remove_file(&solution_path);
+
+        let solution_path = write_solution("self-loops", "Successor = [1, 2, 3, 4, 5];\n");
+        let self_loops_result = check_solution(&model, instance.objective(), bound, &solution_path);
+        let _ = std::fs::remove_file(&solution_path);
+
+        assert!(matches!(
+            optimality_error(wrong_objective_result),
+            OptimalityError::ConstraintViolation { .. }
+        ));
+        assert!(matches!(
+            optimality_error(self_loops_result),
+            OptimalityError::ConstraintViolation { .. }
+        ));
+    }
+
+    /// The listings of the five node instance are checked in, such that changes to how the model
+    /// is lowered into the solver show up in review. Run the test with `MUNCHKIN_DUMP_SNAPSHOTS`
+    /// set to update them.
+    #[test]
+    fn dumps_of_the_five_node_tsp_match_the_snapshots() {
+        let (instance, model) = five_node_tsp();
+
+        let mut model_dump = vec![];
+        model
+            .dump_with_objective(instance.objective(), &mut model_dump)
+            .expect("writing to a vector does not fail");
+
+        let (solver, _) =
+            model.into_solver(SolverOptions::default(), |_| false, None, &mut Indefinite);
+        let mut posted_dump = vec![];
+        solver
+            .dump_posted(&mut posted_dump)
+            .expect("writing to a vector does not fail");
+
+        if std::env::var_os("MUNCHKIN_DUMP_SNAPSHOTS").is_some() {
+            std::fs::write("data/tsp/TSP_N5_0.model.dump", &model_dump).unwrap();
+            std::fs::write("data/tsp/TSP_N5_0.posted.dump", &posted_dump).unwrap();
+        }
+
+        assert_eq!(
+            String::from_utf8(model_dump).unwrap(),
+            include_str!("../../data/tsp/TSP_N5_0.model.dump")
+        );
+        assert_eq!(
+            String::from_utf8(posted_dump).unwrap(),
+            include_str!("../../data/tsp/TSP_N5_0.posted.dump")
+        );
+    }
+}
@@ -0,0 +1,221 @@
+//! Checks the optimality conclusion of a proof against the model of the [`Problem`].
+//!
+//! An optimality conclusion `[obj >= k]` (or `[obj <= k]` when maximising) should be a bound on
+//! the objective variable of the problem. Optionally, a solution which is printed by the runner
+//! (i.e. `name = value;` and `name = [value, ...];` lines) is checked against the constraints of
+//! the model, and its objective value should be equal to the bound `k`.
+//!
+//! [`Problem`]: super::Problem
+
+use std::path::Path;
+
+use anyhow::Context;
+use drcp_format::AtomicConstraint;
+use drcp_format::Comparison;
+use drcp_format::IntAtomicConstraint;
+use thiserror::Error;
+
+use crate::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
+use crate::branching::InDomainMin;
+use crate::branching::InputOrder;
+use crate::model::IntVariable;
+use crate::model::Model;
+use crate::options::SolverOptions;
+use crate::predicate;
+use crate::results::ProblemSolution;
+use crate::results::SatisfactionResult;
+use crate::termination::Indefinite;
+use crate::variables::DomainId;
+
+/// The reasons for which an optimality conclusion is rejected by the runner.
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum OptimalityError {
+    /// The conclusion is not a lower or upper bound on a variable.
+    #[error("The conclusion {conclusion} of the proof is not a bound on the objective")]
+    NotABound { conclusion: String },
+    /// The conclusion bounds a different variable than the objective of the problem.
+    #[error("The proof concludes a bound on '{found}', but the objective is '{objective}'")]
+    UnexpectedObjective { objective: String, found: String },
+    /// The proof is valid, but the objective value of the solution differs from the bound which
+    /// it concludes.
+    #[error(
+        "The proof concludes the optimal objective value {bound}, but the solution has objective \
+         value {value}"
+    )]
+    BoundMismatch { bound: i64, value: i64 },
+    /// The solution does not satisfy the constraints of the model.
+    #[error("The solution violates the constraints of the model: {reason}")]
+    ConstraintViolation { reason: String },
+}
+
+/// Returns the objective bound `k` of the conclusion `[obj >= k]` or `[obj <= k]`, after checking
+/// that `obj` is the objective variable of the model.
+pub(crate) fn objective_bound(
+    model: &Model,
+    objective: IntVariable,
+    conclusion: &AtomicConstraint<String>,
+) -> Result<i64, OptimalityError> {
+    let AtomicConstraint::Int(IntAtomicConstraint {
+        name,
+        comparison: Comparison::GreaterThanEqual | Comparison::LessThanEqual,
+        value,
+    }) = conclusion
+    else {
+        return Err(OptimalityError::NotABound {
+            conclusion: conclusion.to_string(),
+        });
+    };
+
+    let objective_name = model.get_name(objective);
+    if name != objective_name {
+        return Err(OptimalityError::UnexpectedObjective {
+            objective: objective_name.to_owned(),
+            found: name.clone(),
+        });
+    }
+
+    Ok(*value)
+}
+
+/// Checks the solution in the file at `solution_path` against the constraints of the model, and
+/// checks that its objective value is equal to `bound`.
+///
+/// The solution does not have to assign every variable of the model (e.g. auxiliary variables are
+/// not printed by the runner); it is accepted if the remaining variables can be assigned such that
+/// all constraints are satisfied.
+pub(crate) fn check_solution(
+    model: &Model,
+    objective: IntVariable,
+    bound: i64,
+    solution_path: &Path,
+) -> anyhow::Result<()> {
+    let source = std::fs::read_to_string(solution_path)
+        .with_context(|| format!("Failed to read {}", solution_path.display()))?;
+    let values = parse_solution(&source)
+        .with_context(|| format!("Failed to parse solution from {}", solution_path.display()))?;
+
+    let (mut solver, variables) =
+        model
+            .clone()
+            .into_solver(SolverOptions::default(), |_| false, None, &mut Indefinite);
+
+    for (name, value) in values {
+        let variable = variables
+            .get_named_variable(&name)
+            .ok_or_else(|| anyhow::anyhow!("The model has no variable named '{name}'"))?;
+
+        let literal = solver.get_literal(predicate![variable == value]);
+        if solver.add_clause([literal]).is_err() {
+            return Err(OptimalityError::ConstraintViolation {
+                reason: format!("'{name} = {value}' conflicts with the model"),
+            }
+            .into());
+        }
+    }
+
+    let mut brancher = IndependentVariableValueBrancher::new(
+        InputOrder::new(
+            (0..solver.num_integer_variables() as u32)
+                .map(DomainId::new)
+                .collect(),
+        ),
+        InDomainMin,
+    );
+
+    let solution = match solver.satisfy(&mut brancher, &mut Indefinite) {
+        SatisfactionResult::Satisfiable(solution) => solution,
+        SatisfactionResult::Unsatisfiable => {
+            return Err(OptimalityError::ConstraintViolation {
+                reason: "it cannot be extended to an assignment of all variables".to_owned(),
+            }
+            .into())
+        }
+        SatisfactionResult::Unknown(_) => {
+            unreachable!("the search is not terminated without a termination condition")
+        }
+    };
+
+    // The conclusion bounds the domain which underlies the objective variable.
+    let objective_domain = variables
+        .get_named_variable(model.get_name(objective))
+        .expect("the objective is a variable of the model");
+    let value = solution.get_integer_value(objective_domain) as i64;
+    if value != bound {
+        return Err(OptimalityError::BoundMismatch { bound, value }.into());
+    }
+
+    Ok(())
+}
+
+/// Parses the `name = value;` and `name = [value, ...];` lines of a solution. The elements of an
+/// array are returned as `name[0]`, `name[1]`, etc., which is how they are named in the model.
+///
+/// Empty lines, comments, and the separators printed by the runner are skipped.
+fn parse_solution(source: &str) -> anyhow::Result<Vec<(String, i32)>> {
+    let mut values = vec![];
+
+    for line in source.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('%') || line == "----------" || line == "=========="
+        {
+            continue;
+        }
+
+        let (name, value) = line
+            .strip_suffix(';')
+            .and_then(|assignment| assignment.split_once('='))
+            .ok_or_else(|| anyhow::anyhow!("Expected 'name = value;', but got '{line}'"))?;
+        let (name, value) = (name.trim(), value.trim());
+
+        let parse_value = |value: &str| {
+            value
+                .trim()
+                .parse::<i32>()
+                .with_context(|| format!("Invalid value '{value}' for '{name}'"))
+        };
+
+        match value
+            .strip_prefix('[')
+            .and_then(|elements| elements.strip_suffix(']'))
+        {
+            Some(elements) => {
+                for (idx, element) in elements
+                    .split(',')
+                    .filter(|element| !element.trim().is_empty())
+                    .enumerate()
+                {
+                    values.push((format!("{name}[{idx}]"), parse_value(element)?));
+                }
+            }
+            None => values.push((name.to_owned(), parse_value(value)?)),
+        }
+    }
+
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_solution;
+
+    #[test]
+    fn solutions_printed_by_the_runner_are_parsed() {
+        let source = "%% objective = 3\nx = [1, 2, 3];\ny = -4;\n----------\n==========\n";
+
+        let values = parse_solution(source).expect("the solution is well-formed");
+        assert_eq!(
+            values,
+            vec![
+                ("x[0]".to_owned(), 1),
+                ("x[1]".to_owned(), 2),
+                ("x[2]".to_owned(), 3),
+                ("y".to_owned(), -4),
+            ]
+        );
+    }
+
+    #[test]
+    fn malformed_lines_are_rejected() {
+        assert!(parse_solution("x = 1").is_err());
+        assert!(parse_solution("x = one;").is_err());
+    }
+}
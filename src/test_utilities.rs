@@ -0,0 +1,198 @@
+//! Utilities for testing propagators which are implemented outside of this crate; this module is
+//! only available when the `test-utilities` feature is enabled.
+//!
+//! The [`TestSolver`] allows setting up specific scenarios under which to test the various
+//! operations of a [`Propagator`]. Every propagation performed through it checks the explanations
+//! which the propagator provides, i.e. the feature also enables the `explanation-checks` feature.
+//!
+//! # Example
+//! ```
+//! # use munchkin::conjunction;
+//! # use munchkin::predicate;
+//! # use munchkin::predicates::PropositionalConjunction;
+//! # use munchkin::test_utilities::DomainEvents;
+//! # use munchkin::test_utilities::PropagationContextMut;
+//! # use munchkin::test_utilities::PropagationStatusCP;
+//! # use munchkin::test_utilities::Propagator;
+//! # use munchkin::test_utilities::PropagatorInitialisationContext;
+//! # use munchkin::test_utilities::ReadDomains;
+//! # use munchkin::test_utilities::TestSolver;
+//! # use munchkin::variables::DomainId;
+//! /// Propagates `x <= y`.
+//! struct LessOrEqual {
+//!     x: DomainId,
+//!     y: DomainId,
+//! }
+//!
+//! impl Propagator for LessOrEqual {
+//!     fn name(&self) -> &str {
+//!         "LessOrEqual"
+//!     }
+//!
+//!     fn propagate(&self, mut context: PropagationContextMut) -> PropagationStatusCP {
+//!         let (x, y) = (self.x, self.y);
+//!
+//!         let x_lower_bound = context.lower_bound(&x);
+//!         context.set_lower_bound(&y, x_lower_bound, conjunction!([x >= x_lower_bound]))?;
+//!
+//!         let y_upper_bound = context.upper_bound(&y);
+//!         context.set_upper_bound(&x, y_upper_bound, conjunction!([y <= y_upper_bound]))?;
+//!
+//!         Ok(())
+//!     }
+//!
+//!     fn initialise_at_root(
+//!         &mut self,
+//!         context: &mut PropagatorInitialisationContext,
+//!     ) -> Result<(), PropositionalConjunction> {
+//!         context.register(self.x, DomainEvents::LOWER_BOUND);
+//!         context.register(self.y, DomainEvents::UPPER_BOUND);
+//!         Ok(())
+//!     }
+//! }
+//!
+//! let mut solver = TestSolver::default();
+//! let x = solver.new_variable(3, 10);
+//! let y = solver.new_variable(0, 8);
+//!
+//! let _ = solver
+//!     .new_propagator(LessOrEqual { x, y })
+//!     .expect("the propagator is consistent");
+//!
+//! solver.assert_bounds(x, 3, 8);
+//! solver.assert_bounds(y, 3, 8);
+//! assert_eq!(
+//!     solver.get_reason_int(predicate![y >= 3]),
+//!     &PropositionalConjunction::from(predicate![x >= 3])
+//! );
+//! ```
+
+pub use crate::basic_types::conflict_info::ConflictInfo;
+pub use crate::basic_types::propagation_status_cp::Inconsistency;
+pub use crate::basic_types::propagation_status_cp::PropagationStatusCP;
+pub use crate::engine::cp::domain_events::DomainEvents;
+pub use crate::engine::cp::propagation::enqueue_decision::EnqueueDecision;
+pub use crate::engine::cp::propagation::local_id::LocalId;
+pub use crate::engine::cp::propagation::propagation_context::PropagationContext;
+pub use crate::engine::cp::propagation::propagation_context::PropagationContextMut;
+pub use crate::engine::cp::propagation::propagation_context::ReadDomains;
+pub use crate::engine::cp::propagation::propagator::Propagator;
+pub use crate::engine::cp::propagation::propagator_id::PropagatorId;
+pub use crate::engine::cp::propagation::propagator_initialisation_context::PropagatorInitialisationContext;
+pub use crate::engine::cp::watch_list_cp::IntDomainEvent;
+use crate::engine::test_helper;
+use crate::predicates::Predicate;
+use crate::predicates::PropositionalConjunction;
+use crate::variables::DomainId;
+use crate::variables::IntegerVariable;
+use crate::variables::Literal;
+
+/// A container for CP variables, which can be used to test propagators. See the
+/// [module-level documentation](self) for an example.
+#[derive(Debug, Default)]
+pub struct TestSolver {
+    solver: test_helper::TestSolver,
+}
+
+impl TestSolver {
+    /// Creates a new integer variable with the domain `[lower_bound, upper_bound]`.
+    pub fn new_variable(&mut self, lower_bound: i32, upper_bound: i32) -> DomainId {
+        self.solver.new_variable(lower_bound, upper_bound)
+    }
+
+    /// Creates a new integer variable whose domain consists of the provided `values`.
+    ///
+    /// # Panics
+    /// If `values` is empty.
+    pub fn new_sparse_variable(&mut self, values: &[i32]) -> DomainId {
+        self.solver.new_sparse_variable(values)
+    }
+
+    /// Creates a new propositional literal.
+    pub fn new_literal(&mut self) -> Literal {
+        self.solver.new_literal()
+    }
+
+    /// Adds the `propagator` to the solver, initialises it at the root and propagates it once.
+    /// Returns the [`Inconsistency`] if either of these steps detects a conflict.
+    pub fn new_propagator(
+        &mut self,
+        propagator: impl Propagator + 'static,
+    ) -> Result<PropagatorId, Inconsistency> {
+        self.solver.new_propagator(propagator)
+    }
+
+    /// Delivers the pending domain events to the subscribed propagators and then propagates the
+    /// given `propagator`, checking the explanations of the propagations which it performs.
+    ///
+    /// # Panics
+    /// If one of the explanations does not imply the propagation or the reported conflict.
+    pub fn propagate(&mut self, propagator: PropagatorId) -> PropagationStatusCP {
+        self.solver.notify_and_propagate(propagator)
+    }
+
+    /// Propagates all propagators until none of them changes a domain anymore.
+    pub fn propagate_until_fixed_point(&mut self) -> PropagationStatusCP {
+        self.solver.propagate_until_fixed_point()
+    }
+
+    /// Returns the reason for the propagation of the integer `predicate`.
+    ///
+    /// # Panics
+    /// If `predicate` is not an integer predicate, or if it was not propagated.
+    pub fn get_reason_int(&mut self, predicate: Predicate) -> &PropositionalConjunction {
+        let predicate = predicate
+            .try_into()
+            .expect("the reason should be requested for an integer predicate");
+        self.solver.get_reason_int(predicate)
+    }
+
+    /// Returns the lower bound of `var`.
+    pub fn lower_bound(&self, var: DomainId) -> i32 {
+        self.solver.lower_bound(var)
+    }
+
+    /// Returns the upper bound of `var`.
+    pub fn upper_bound(&self, var: DomainId) -> i32 {
+        self.solver.upper_bound(var)
+    }
+
+    /// Returns whether `value` is in the domain of `var`.
+    pub fn contains<Var: IntegerVariable>(&self, var: Var, value: i32) -> bool {
+        self.solver.contains(var, value)
+    }
+
+    /// Sets the lower bound of `var` to `value`, as if it was done by the search.
+    ///
+    /// # Panics
+    /// If the change results in an empty domain.
+    pub fn increase_lower_bound(&mut self, var: DomainId, value: i32) {
+        self.solver.increase_lower_bound(var, value)
+    }
+
+    /// Sets the upper bound of `var` to `value`, as if it was done by the search.
+    ///
+    /// # Panics
+    /// If the change results in an empty domain.
+    pub fn decrease_upper_bound(&mut self, var: DomainId, value: i32) {
+        self.solver.decrease_upper_bound(var, value)
+    }
+
+    /// Assigns `value` to `literal`, as if it was done by the search.
+    pub fn set_literal(&mut self, literal: Literal, value: bool) {
+        self.solver.set_literal(literal, value)
+    }
+
+    /// Asserts that the bounds of `var` are `[lower_bound, upper_bound]`.
+    ///
+    /// # Panics
+    /// If the bounds do not match.
+    pub fn assert_bounds<Var: IntegerVariable>(
+        &self,
+        var: Var,
+        lower_bound: i32,
+        upper_bound: i32,
+    ) {
+        self.solver.assert_bounds(var, lower_bound, upper_bound)
+    }
+}
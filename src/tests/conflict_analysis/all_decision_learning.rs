@@ -1,8 +1,5 @@
 #![cfg(test)]
 
-use rand::rngs::SmallRng;
-use rand::SeedableRng;
-
 use crate::branching::Brancher;
 use crate::branching::SelectionContext;
 use crate::engine::conflict_analysis::AllDecisionLearning;
@@ -12,9 +9,11 @@ use crate::engine::constraint_satisfaction_solver::CSPSolverState;
 use crate::engine::constraint_satisfaction_solver::ConflictResolutionStrategy;
 use crate::engine::constraint_satisfaction_solver::Counters;
 use crate::engine::constraint_satisfaction_solver::NogoodMinimisationStrategy;
+use crate::engine::constraint_satisfaction_solver::ProbingOptions;
 use crate::engine::cp::PropagatorQueue;
 use crate::engine::sat::ExplanationClauseManager;
 use crate::engine::test_helper::TestSolver;
+use crate::options::AssertLevel;
 use crate::options::SolverOptions;
 use crate::predicates::Predicate;
 use crate::proof::Proof;
@@ -72,12 +71,20 @@ fn test_all_decision() {
             assignments_integer: &mut solver.assignments_integer,
             assignments_propositional: &mut solver.assignments_propositional,
             internal_parameters: &mut SolverOptions {
-                random_generator: SmallRng::seed_from_u64(42),
+                random_seed: 42,
                 conflict_resolver: ConflictResolutionStrategy::AllDecision,
                 minimisation_strategy: NogoodMinimisationStrategy::default(),
                 use_non_generic_conflict_explanation: true,
                 use_non_generic_propagation_explanation: true,
                 proof: Proof::default(),
+                max_proof_bytes: None,
+                time_propagators: false,
+                restart_interval: None,
+                root_probing: ProbingOptions::default(),
+                verbose_optimisation: false,
+                assert_level: AssertLevel::default(),
+                propagation_loop_threshold: Some(100_000),
+                expected_num_propositional_variables: 0,
             },
             assumptions: &vec![],
             solver_state: &mut state,
@@ -89,6 +96,7 @@ fn test_all_decision() {
             propositional_trail_index: &mut 0,
             propagator_queue: &mut PropagatorQueue::new(0),
             watch_list_cp: &mut solver.watch_list,
+            propagators: &mut solver.propagators,
             sat_trail_synced_position: &mut 0,
             cp_trail_synced_position: &mut 0,
         })
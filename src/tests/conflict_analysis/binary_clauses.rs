@@ -0,0 +1,106 @@
+#![cfg(test)]
+
+use crate::branching::Brancher;
+use crate::branching::SelectionContext;
+use crate::engine::conflict_analysis::ConflictAnalysisContext;
+use crate::engine::constraint_satisfaction_solver::CSPSolverState;
+use crate::engine::constraint_satisfaction_solver::ConflictResolutionStrategy;
+use crate::engine::constraint_satisfaction_solver::Counters;
+use crate::engine::constraint_satisfaction_solver::NogoodMinimisationStrategy;
+use crate::engine::constraint_satisfaction_solver::ProbingOptions;
+use crate::engine::cp::PropagatorQueue;
+use crate::engine::sat::ExplanationClauseManager;
+use crate::engine::test_helper::TestSolver;
+use crate::options::AssertLevel;
+use crate::options::SolverOptions;
+use crate::predicates::Predicate;
+use crate::proof::Proof;
+
+struct DummyBrancher;
+impl Brancher for DummyBrancher {
+    fn next_decision(&mut self, _context: &mut SelectionContext) -> Option<Predicate> {
+        todo!()
+    }
+}
+
+/// The binary clauses are not allocated, so the conflict analysis should recover the literals of
+/// the clauses from the virtual binary clause references.
+#[test]
+fn conflict_over_binary_clauses_is_explained_by_their_literals() {
+    let mut solver = TestSolver::default();
+
+    let a = solver.new_literal();
+    let b = solver.new_literal();
+    let c = solver.new_literal();
+
+    solver
+        .clausal_propagator
+        .add_permanent_implication_unchecked(a, b);
+    solver
+        .clausal_propagator
+        .add_permanent_implication_unchecked(a, !c);
+    solver
+        .clausal_propagator
+        .add_permanent_implication_unchecked(b, c);
+
+    solver.increase_decision_level();
+    solver.set_decision(a);
+
+    let mut state = CSPSolverState::default();
+    let result = solver.propagate_clausal_propagator();
+    if let Err(conflict_info) = result {
+        state.declare_conflict(conflict_info.try_into().unwrap());
+    } else {
+        panic!("Should have been an error");
+    }
+
+    let num_allocated_clauses = solver.clause_allocator.num_allocated_clauses();
+    let mut context = ConflictAnalysisContext {
+        clausal_propagator: &mut solver.clausal_propagator,
+        variable_literal_mappings: &solver.variable_literal_mappings,
+        assignments_integer: &mut solver.assignments_integer,
+        assignments_propositional: &mut solver.assignments_propositional,
+        internal_parameters: &mut SolverOptions {
+            random_seed: 42,
+            conflict_resolver: ConflictResolutionStrategy::UniqueImplicationPoint,
+            minimisation_strategy: NogoodMinimisationStrategy::default(),
+            use_non_generic_conflict_explanation: true,
+            use_non_generic_propagation_explanation: true,
+            proof: Proof::default(),
+            max_proof_bytes: None,
+            time_propagators: false,
+            restart_interval: None,
+            root_probing: ProbingOptions::default(),
+            verbose_optimisation: false,
+            assert_level: AssertLevel::default(),
+            propagation_loop_threshold: Some(100_000),
+            expected_num_propositional_variables: 0,
+        },
+        assumptions: &vec![],
+        solver_state: &mut state,
+        brancher: &mut DummyBrancher,
+        clause_allocator: &mut solver.clause_allocator,
+        explanation_clause_manager: &mut ExplanationClauseManager::default(),
+        reason_store: &mut solver.reason_store,
+        counters: &mut Counters::default(),
+        propositional_trail_index: &mut 0,
+        propagator_queue: &mut PropagatorQueue::new(0),
+        watch_list_cp: &mut solver.watch_list,
+        propagators: &mut solver.propagators,
+        sat_trail_synced_position: &mut 0,
+        cp_trail_synced_position: &mut 0,
+    };
+
+    // The clause (~b \/ c) is conflicting since `c` was propagated to false by (~a \/ ~c)
+    assert_eq!(context.get_conflict_nogood().literals, vec![!c, b]);
+
+    // Resolving the conflict on `b` and `~c` leads to the learned clause (~a)
+    assert_eq!(context.get_reason(b).literals, vec![a]);
+    assert_eq!(context.get_reason(!c).literals, vec![a]);
+
+    assert!(context.explanation_clause_manager.is_empty());
+    assert_eq!(
+        context.clause_allocator.num_allocated_clauses(),
+        num_allocated_clauses
+    );
+}
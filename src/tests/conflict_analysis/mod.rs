@@ -1,2 +1,3 @@
 pub(crate) mod all_decision_learning;
+pub(crate) mod binary_clauses;
 pub(crate) mod unique_implication_point;
@@ -0,0 +1,120 @@
+#![cfg(test)]
+
+use crate::engine::ConstraintSatisfactionSolver;
+use crate::engine::SatisfactionSolverOptions;
+use crate::predicate;
+
+#[test]
+fn sparse_domain_with_large_gap_only_creates_literals_for_its_values() {
+    let mut solver = ConstraintSatisfactionSolver::default();
+    let num_variables_before = solver
+        .assignments_propositional
+        .num_propositional_variables();
+
+    let values = vec![0, 1_000_000];
+    let domain_id = solver.create_new_integer_variable_sparse(values.clone(), None);
+
+    let num_created_variables = solver
+        .assignments_propositional
+        .num_propositional_variables()
+        - num_variables_before;
+    assert!(num_created_variables as usize <= values.len());
+
+    assert_eq!(0, solver.assignments_integer.get_lower_bound(domain_id));
+    assert_eq!(
+        1_000_000,
+        solver.assignments_integer.get_upper_bound(domain_id)
+    );
+    assert!(!solver
+        .assignments_integer
+        .is_value_in_domain(domain_id, 500_000));
+}
+
+#[test]
+fn sparse_domain_bound_literals_are_shared_within_a_gap() {
+    let mut solver = ConstraintSatisfactionSolver::default();
+    let domain_id = solver.create_new_integer_variable_sparse(vec![0, 1_000_000], None);
+
+    let at_least_upper_bound = solver.get_literal(predicate![domain_id >= 1_000_000]);
+    assert!(solver
+        .assignments_propositional
+        .is_literal_unassigned(at_least_upper_bound));
+
+    for value in [1, 500_000, 999_999] {
+        assert_eq!(
+            at_least_upper_bound,
+            solver.get_literal(predicate![domain_id >= value])
+        );
+        assert_eq!(
+            solver.get_literal(predicate![domain_id <= 0]),
+            solver.get_literal(predicate![domain_id <= value])
+        );
+    }
+
+    assert_eq!(
+        solver.assignments_propositional.true_literal,
+        solver.get_literal(predicate![domain_id >= 0])
+    );
+    assert_eq!(
+        solver.assignments_propositional.false_literal,
+        solver.get_literal(predicate![domain_id >= 1_000_001])
+    );
+    assert_eq!(
+        solver.assignments_propositional.true_literal,
+        solver.get_literal(predicate![domain_id <= 1_000_000])
+    );
+}
+
+#[test]
+fn sparse_domain_equality_literals_only_exist_for_its_values() {
+    let mut solver = ConstraintSatisfactionSolver::default();
+    let domain_id = solver.create_new_integer_variable_sparse(vec![-5, 0, 1_000_000], None);
+
+    for value in [-5, 0, 1_000_000] {
+        assert!(solver
+            .assignments_propositional
+            .is_literal_unassigned(solver.get_literal(predicate![domain_id == value])));
+    }
+
+    for value in [-6, -4, -1, 1, 999_999, 1_000_001] {
+        assert_eq!(
+            solver.assignments_propositional.false_literal,
+            solver.get_literal(predicate![domain_id == value])
+        );
+        assert_eq!(
+            solver.assignments_propositional.true_literal,
+            solver.get_literal(predicate![domain_id != value])
+        );
+    }
+
+    // The equality literal of a value in the middle is linked to the bound literals around it
+    let equals_zero = solver.get_literal(predicate![domain_id == 0]);
+    assert_ne!(equals_zero, solver.get_literal(predicate![domain_id >= 0]));
+    assert_eq!(
+        solver.get_literal(predicate![domain_id >= -4]),
+        solver.get_literal(predicate![domain_id >= 0])
+    );
+}
+
+#[test]
+fn expected_number_of_propositional_variables_is_reserved_up_front() {
+    const NUM_LITERALS: usize = 100_000;
+
+    let options = SatisfactionSolverOptions::builder()
+        .with_expected_num_propositional_variables(NUM_LITERALS)
+        .build()
+        .expect("the options are valid");
+    let mut solver = ConstraintSatisfactionSolver::new(options);
+    let capacity = solver.propositional_variable_capacity();
+    assert!(capacity > NUM_LITERALS);
+
+    let _ = solver.new_literal_batch(NUM_LITERALS);
+    assert_eq!(
+        solver.propositional_variable_capacity(),
+        capacity,
+        "no reallocation is needed for the expected number of variables"
+    );
+
+    for _ in solver.new_literals().take(NUM_LITERALS / 2) {}
+    assert!(solver.propositional_variable_capacity() > capacity);
+}
@@ -0,0 +1,4 @@
+pub(crate) mod domains;
+pub(crate) mod notifications;
+pub(crate) mod propagation;
+pub(crate) mod simplification;
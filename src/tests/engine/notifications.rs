@@ -0,0 +1,347 @@
+#![cfg(test)]
+
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::num::NonZero;
+use std::rc::Rc;
+
+use crate::asserts::AssertLevel;
+use crate::basic_types::PropagationStatusCP;
+use crate::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
+use crate::branching::Brancher;
+use crate::branching::InDomainMin;
+use crate::branching::InputOrder;
+use crate::branching::SelectionContext;
+use crate::engine::cp::domain_events::DomainEvents;
+use crate::engine::cp::propagation::DomainChange;
+use crate::engine::cp::propagation::EnqueueDecision;
+use crate::engine::cp::propagation::LocalId;
+use crate::engine::cp::propagation::PropagationContext;
+use crate::engine::cp::propagation::PropagationContextMut;
+use crate::engine::cp::propagation::Propagator;
+use crate::engine::cp::propagation::PropagatorInitialisationContext;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::cp::IntDomainEvent;
+use crate::engine::predicates::predicate::Predicate;
+use crate::engine::variables::DomainId;
+use crate::engine::variables::Literal;
+use crate::engine::ConstraintSatisfactionSolver;
+use crate::engine::SatisfactionSolverOptions;
+use crate::predicates::PropositionalConjunction;
+use crate::termination::Indefinite;
+
+type BacktrackNotification = (LocalId, IntDomainEvent, i32, i32);
+
+/// Records the restored bounds of the variable whenever it is notified of a backtrack event.
+struct BacktrackRecorder {
+    watched: DomainId,
+    unwatched: DomainId,
+    notifications: Rc<RefCell<Vec<BacktrackNotification>>>,
+}
+
+impl Propagator for BacktrackRecorder {
+    fn name(&self) -> &str {
+        "BacktrackRecorder"
+    }
+
+    fn propagate(&self, _context: PropagationContextMut) -> PropagationStatusCP {
+        Ok(())
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        context.register(self.watched, DomainEvents::ANY_INT);
+        context.register(self.unwatched, DomainEvents::ANY_INT);
+        context.register_for_backtrack_events(self.watched, DomainEvents::BOUNDS, LocalId::from(0));
+        Ok(())
+    }
+
+    fn notify_backtrack(
+        &mut self,
+        context: PropagationContext,
+        local_id: LocalId,
+        event: IntDomainEvent,
+    ) {
+        self.notifications.borrow_mut().push((
+            local_id,
+            event,
+            context.lower_bound(&self.watched),
+            context.upper_bound(&self.watched),
+        ));
+    }
+}
+
+#[test]
+fn backtrack_events_are_delivered_only_for_subscribed_variables() {
+    let mut solver = ConstraintSatisfactionSolver::default();
+    let x = solver.create_new_integer_variable(0, 10, None);
+    let y = solver.create_new_integer_variable(0, 10, None);
+
+    let notifications = Rc::new(RefCell::new(vec![]));
+    let result = solver.add_propagator(
+        BacktrackRecorder {
+            watched: x,
+            unwatched: y,
+            notifications: Rc::clone(&notifications),
+        },
+        NonZero::new(1).unwrap(),
+    );
+    assert!(result.is_ok());
+
+    solver.declare_new_decision_level();
+    let _ = solver.assignments_integer.tighten_lower_bound(x, 3, None);
+    let _ = solver.assignments_integer.tighten_upper_bound(y, 5, None);
+
+    let mut brancher =
+        IndependentVariableValueBrancher::new(InputOrder::new(vec![x, y]), InDomainMin);
+    solver.backtrack(0, &mut brancher);
+
+    assert_eq!(
+        *notifications.borrow(),
+        vec![(LocalId::from(0), IntDomainEvent::LowerBound, 0, 10)]
+    );
+}
+
+/// Records the bound changes of which it is notified.
+struct BoundChangeRecorder {
+    watched: DomainId,
+    changes: Rc<RefCell<Vec<(IntDomainEvent, i32, i32)>>>,
+}
+
+impl Propagator for BoundChangeRecorder {
+    fn name(&self) -> &str {
+        "BoundChangeRecorder"
+    }
+
+    fn propagate(&self, _context: PropagationContextMut) -> PropagationStatusCP {
+        Ok(())
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        context.register(self.watched, DomainEvents::LOWER_BOUND);
+        Ok(())
+    }
+
+    fn notify_change(
+        &mut self,
+        _context: PropagationContext,
+        change: DomainChange,
+    ) -> EnqueueDecision {
+        self.changes
+            .borrow_mut()
+            .push((change.event, change.old_bound, change.new_bound));
+        EnqueueDecision::Enqueue
+    }
+}
+
+#[test]
+fn notified_changes_contain_old_and_new_lower_bounds() {
+    let mut solver = ConstraintSatisfactionSolver::default();
+    let x = solver.create_new_integer_variable(0, 10, None);
+
+    let changes = Rc::new(RefCell::new(vec![]));
+    let result = solver.add_propagator(
+        BoundChangeRecorder {
+            watched: x,
+            changes: Rc::clone(&changes),
+        },
+        NonZero::new(1).unwrap(),
+    );
+    assert!(result.is_ok());
+    let mut brancher = IndependentVariableValueBrancher::new(InputOrder::new(vec![x]), InDomainMin);
+
+    solver.declare_new_decision_level();
+    let _ = solver.assignments_integer.tighten_lower_bound(x, 2, None);
+    solver.propagate_enqueued(&mut Indefinite);
+    // Multiple tightenings before the notification are reported as a single change
+    let _ = solver.assignments_integer.tighten_lower_bound(x, 5, None);
+    let _ = solver.assignments_integer.tighten_lower_bound(x, 7, None);
+    solver.propagate_enqueued(&mut Indefinite);
+    solver.backtrack(0, &mut brancher);
+
+    solver.declare_new_decision_level();
+    let _ = solver.assignments_integer.tighten_lower_bound(x, 3, None);
+    solver.propagate_enqueued(&mut Indefinite);
+    solver.declare_new_decision_level();
+    let _ = solver.assignments_integer.tighten_lower_bound(x, 4, None);
+    solver.propagate_enqueued(&mut Indefinite);
+    // The change to 5 is undone before the propagator is notified
+    let _ = solver.assignments_integer.tighten_lower_bound(x, 5, None);
+    solver.backtrack(1, &mut brancher);
+    let _ = solver.assignments_integer.tighten_lower_bound(x, 6, None);
+    solver.propagate_enqueued(&mut Indefinite);
+
+    assert_eq!(
+        *changes.borrow(),
+        vec![
+            (IntDomainEvent::LowerBound, 0, 2),
+            (IntDomainEvent::LowerBound, 2, 7),
+            (IntDomainEvent::LowerBound, 0, 3),
+            (IntDomainEvent::LowerBound, 3, 4),
+            (IntDomainEvent::LowerBound, 3, 6),
+        ]
+    );
+}
+
+#[test]
+fn no_backtrack_events_are_delivered_for_changes_on_other_variables() {
+    let mut solver = ConstraintSatisfactionSolver::default();
+    let x = solver.create_new_integer_variable(0, 10, None);
+    let y = solver.create_new_integer_variable(0, 10, None);
+
+    let notifications = Rc::new(RefCell::new(vec![]));
+    let result = solver.add_propagator(
+        BacktrackRecorder {
+            watched: x,
+            unwatched: y,
+            notifications: Rc::clone(&notifications),
+        },
+        NonZero::new(1).unwrap(),
+    );
+    assert!(result.is_ok());
+
+    solver.declare_new_decision_level();
+    let _ = solver.assignments_integer.tighten_lower_bound(y, 2, None);
+    let _ = solver.assignments_integer.tighten_upper_bound(y, 2, None);
+
+    let mut brancher =
+        IndependentVariableValueBrancher::new(InputOrder::new(vec![x, y]), InDomainMin);
+    solver.backtrack(0, &mut brancher);
+
+    assert!(notifications.borrow().is_empty());
+}
+
+/// Counts the number of times that it is propagated, without changing any domain.
+struct LiteralWakeUpCounter {
+    literal: Literal,
+    events: DomainEvents,
+    num_wake_ups: Rc<Cell<usize>>,
+}
+
+impl Propagator for LiteralWakeUpCounter {
+    fn name(&self) -> &str {
+        "LiteralWakeUpCounter"
+    }
+
+    fn propagate(&self, _context: PropagationContextMut) -> PropagationStatusCP {
+        self.num_wake_ups.set(self.num_wake_ups.get() + 1);
+        Ok(())
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        let _ = context.register_literal(self.literal, self.events, LocalId::from(0));
+        Ok(())
+    }
+}
+
+/// Assigns a literal watched with the provided events true, false, true and false, each time at
+/// a new decision level followed by a restart, and returns the number of wake-ups per
+/// assignment.
+fn literal_wake_ups(events: DomainEvents) -> Vec<usize> {
+    // The fixed point checks of the more expensive assertion levels also invoke the propagator
+    let mut solver = ConstraintSatisfactionSolver::new(SatisfactionSolverOptions {
+        assert_level: AssertLevel::Moderate,
+        ..Default::default()
+    });
+    let literal = Literal::new(solver.create_new_propositional_variable(None), true);
+
+    let num_wake_ups = Rc::new(Cell::new(0));
+    let result = solver.add_propagator(
+        LiteralWakeUpCounter {
+            literal,
+            events,
+            num_wake_ups: Rc::clone(&num_wake_ups),
+        },
+        NonZero::new(1).unwrap(),
+    );
+    assert!(result.is_ok());
+
+    let mut brancher = IndependentVariableValueBrancher::new(
+        InputOrder::new(vec![literal.get_propositional_variable()]),
+        InDomainMin,
+    );
+
+    [literal, !literal, literal, !literal]
+        .into_iter()
+        .map(|decision| {
+            num_wake_ups.set(0);
+
+            solver.declare_new_decision_level();
+            solver
+                .assignments_propositional
+                .enqueue_decision_literal(decision);
+            solver.propagate_enqueued(&mut Indefinite);
+            assert!(solver.state.no_conflict());
+            solver.backtrack(0, &mut brancher);
+
+            num_wake_ups.get()
+        })
+        .collect()
+}
+
+#[test]
+fn literal_watched_for_assigned_true_only_wakes_up_when_true() {
+    assert_eq!(
+        literal_wake_ups(DomainEvents::ASSIGNED_TRUE),
+        vec![1, 0, 1, 0]
+    );
+}
+
+#[test]
+fn literal_watched_for_assigned_false_only_wakes_up_when_false() {
+    assert_eq!(
+        literal_wake_ups(DomainEvents::ASSIGNED_FALSE),
+        vec![0, 1, 0, 1]
+    );
+}
+
+#[test]
+fn literal_watched_for_any_bool_wakes_up_for_both_polarities() {
+    assert_eq!(literal_wake_ups(DomainEvents::ANY_BOOL), vec![1, 1, 1, 1]);
+}
+
+/// Records the unassignment notifications it receives.
+#[derive(Default)]
+struct UnassignmentRecorder {
+    unassigned_integers: Vec<(DomainId, i32)>,
+}
+
+impl Brancher for UnassignmentRecorder {
+    fn next_decision(&mut self, _context: &mut SelectionContext) -> Option<Predicate> {
+        None
+    }
+
+    fn on_unassign_integer(&mut self, variable: DomainId, value: i32) {
+        self.unassigned_integers.push((variable, value));
+    }
+}
+
+#[test]
+fn brancher_is_notified_of_unfixed_variables_in_reverse_trail_order() {
+    let mut solver = ConstraintSatisfactionSolver::default();
+    let x = solver.create_new_integer_variable(0, 5, None);
+    let y = solver.create_new_integer_variable(0, 5, None);
+    let z = solver.create_new_integer_variable(0, 5, None);
+
+    solver.declare_new_decision_level();
+    let _ = solver.assignments_integer.tighten_lower_bound(x, 2, None);
+    let _ = solver.assignments_integer.tighten_upper_bound(x, 2, None);
+    // `z` is not fixed, so the brancher should not be notified of it
+    let _ = solver.assignments_integer.tighten_lower_bound(z, 1, None);
+
+    solver.declare_new_decision_level();
+    let _ = solver.assignments_integer.tighten_upper_bound(y, 4, None);
+    let _ = solver.assignments_integer.tighten_lower_bound(y, 4, None);
+
+    let mut brancher = UnassignmentRecorder::default();
+    solver.backtrack(0, &mut brancher);
+    assert_eq!(brancher.unassigned_integers, vec![(y, 4), (x, 2)]);
+}
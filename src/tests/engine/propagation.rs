@@ -0,0 +1,266 @@
+#![cfg(test)]
+
+use std::cell::Cell;
+use std::num::NonZero;
+
+use crate::asserts;
+use crate::asserts::AssertLevel;
+use crate::asserts::ASSERT_LEVEL_DEFINITION;
+use crate::basic_types::CSPSolverExecutionFlag;
+use crate::basic_types::PropagationStatusCP;
+use crate::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
+use crate::branching::InDomainMin;
+use crate::branching::InDomainSplit;
+use crate::branching::InputOrder;
+use crate::engine::cp::domain_events::DomainEvents;
+use crate::engine::cp::propagation::PropagationContextMut;
+use crate::engine::cp::propagation::Propagator;
+use crate::engine::cp::propagation::PropagatorInitialisationContext;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::sat::NUM_STATE_CHECKS;
+use crate::engine::variables::DomainId;
+use crate::engine::variables::Literal;
+use crate::engine::ConstraintSatisfactionSolver;
+use crate::engine::SatisfactionSolverOptions;
+use crate::predicate;
+use crate::predicates::PropositionalConjunction;
+use crate::propagators::count::CountPropagator;
+use crate::propagators::ReifiedPropagator;
+use crate::results::solution_iterator::IteratedSolution;
+use crate::results::solution_iterator::SolutionIterator;
+use crate::termination::Indefinite;
+
+/// Removes the self-loops of a successor-based representation of a graph (with 1-based
+/// nodes), but only during initialisation.
+struct SelfLoopRemover {
+    successors: Box<[DomainId]>,
+}
+
+impl Propagator for SelfLoopRemover {
+    fn name(&self) -> &str {
+        "SelfLoopRemover"
+    }
+
+    fn propagate(&self, _context: PropagationContextMut) -> PropagationStatusCP {
+        Ok(())
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        for (index, successor) in self.successors.iter().enumerate() {
+            context
+                .remove(
+                    successor,
+                    index as i32 + 1,
+                    PropositionalConjunction::default(),
+                )
+                .map_err(|_| PropositionalConjunction::default())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn root_propagations_during_initialisation_are_applied() {
+    let mut solver = ConstraintSatisfactionSolver::default();
+    let successors = (0..3)
+        .map(|_| solver.create_new_integer_variable(1, 3, None))
+        .collect::<Box<[_]>>();
+
+    let result = solver.add_propagator(
+        SelfLoopRemover {
+            successors: successors.clone(),
+        },
+        NonZero::new(1).unwrap(),
+    );
+    assert!(result.is_ok());
+
+    for (index, &successor) in successors.iter().enumerate() {
+        assert!(!solver
+            .assignments_integer
+            .is_value_in_domain(successor, index as i32 + 1));
+        // The propositional view is updated as well
+        let literal = solver.get_literal(predicate![successor == index as i32 + 1]);
+        assert_eq!(solver.get_literal_value(literal), Some(false));
+    }
+}
+
+#[test]
+fn root_conflict_during_initialisation_is_reported() {
+    let mut solver = ConstraintSatisfactionSolver::default();
+    let successors = [
+        solver.create_new_integer_variable(1, 1, None),
+        solver.create_new_integer_variable(1, 2, None),
+    ];
+
+    let result = solver.add_propagator(
+        SelfLoopRemover {
+            successors: successors.into(),
+        },
+        NonZero::new(1).unwrap(),
+    );
+    assert!(result.is_err());
+    assert!(solver.state.is_infeasible());
+}
+
+#[test]
+fn reified_propagators_do_not_propagate_during_initialisation() {
+    let mut solver = ConstraintSatisfactionSolver::default();
+    let successor = solver.create_new_integer_variable(1, 2, None);
+    let reification_literal = solver.create_new_propositional_variable(None);
+
+    let result = solver.add_propagator(
+        ReifiedPropagator::new(
+            SelfLoopRemover {
+                successors: [successor].into(),
+            },
+            Literal::new(reification_literal, true),
+        ),
+        NonZero::new(1).unwrap(),
+    );
+    assert!(result.is_ok());
+    assert!(solver.assignments_integer.is_value_in_domain(successor, 1));
+}
+
+/// Reports a conflict once `value` is no longer within the bounds of `variable`, without
+/// performing any propagation.
+struct ContainsValue {
+    variable: DomainId,
+    value: i32,
+}
+
+impl Propagator for ContainsValue {
+    fn name(&self) -> &str {
+        "ContainsValue"
+    }
+
+    fn propagate(&self, context: PropagationContextMut) -> PropagationStatusCP {
+        let lower_bound = context.lower_bound(&self.variable);
+        let upper_bound = context.upper_bound(&self.variable);
+
+        if lower_bound > self.value {
+            Err(
+                PropositionalConjunction::from(vec![predicate!(self.variable >= lower_bound)])
+                    .into(),
+            )
+        } else if upper_bound < self.value {
+            Err(
+                PropositionalConjunction::from(vec![predicate!(self.variable <= upper_bound)])
+                    .into(),
+            )
+        } else {
+            Ok(())
+        }
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        context.register(self.variable, DomainEvents::BOUNDS);
+        Ok(())
+    }
+}
+
+#[test]
+fn splitting_decisions_reach_value_in_logarithmic_number_of_decisions() {
+    let mut solver = ConstraintSatisfactionSolver::default();
+    let x = solver.create_new_integer_variable(0, 1000, None);
+
+    let result = solver.add_propagator(
+        ContainsValue {
+            variable: x,
+            value: 937,
+        },
+        NonZero::new(1).unwrap(),
+    );
+    assert!(result.is_ok());
+
+    let mut brancher =
+        IndependentVariableValueBrancher::new(InputOrder::new(vec![x]), InDomainSplit);
+    let flag = solver.solve(&mut Indefinite, &mut brancher);
+
+    assert!(matches!(flag, CSPSolverExecutionFlag::Feasible));
+    assert_eq!(solver.get_assigned_integer_value(&x), Some(937));
+    // Every decision halves the domain, and every wrong decision is undone by a single
+    // conflict; hence at most two decisions are made per bisection of [0, 1000]
+    let statistics = solver.statistics();
+    assert!(statistics.decisions <= 2 * 10);
+    assert!(statistics.decisions >= 10);
+}
+
+#[test]
+fn reason_buffers_are_reused_across_propagate_backtrack_cycles() {
+    let mut solver = ConstraintSatisfactionSolver::default();
+    let variables = (0..6)
+        .map(|_| solver.create_new_integer_variable(0, 2, None))
+        .collect::<Vec<_>>();
+    let rhs = solver.create_new_integer_variable(4, 4, None);
+
+    // Once four of the variables are fixed to 1, the remaining variables cannot take the
+    // value 1; each of these removals has a reason consisting of five predicates
+    let result = solver.add_propagator(
+        CountPropagator::new(variables.clone().into(), 1, rhs),
+        NonZero::new(1).unwrap(),
+    );
+    assert!(result.is_ok());
+
+    let mut brancher =
+        IndependentVariableValueBrancher::new(InputOrder::new(variables), InDomainMin);
+    let mut termination = Indefinite;
+    let mut solution_iterator = SolutionIterator::new(&mut solver, &mut brancher, &mut termination);
+    let mut num_solutions = 0;
+    while let IteratedSolution::Solution(_) = solution_iterator.next_solution() {
+        num_solutions += 1;
+    }
+    drop(solution_iterator);
+
+    // Choose the 4 variables which are 1, the others are either 0 or 2
+    assert_eq!(num_solutions, 15 * 4);
+    assert!(solver.reason_store.num_reused_buffers() > 0);
+}
+
+#[test]
+fn clausal_state_is_only_checked_from_the_advanced_assert_level() {
+    let count_state_checks = |assert_level| {
+        let num_checks_before = NUM_STATE_CHECKS.with(Cell::get);
+
+        let mut solver = ConstraintSatisfactionSolver::new(SatisfactionSolverOptions {
+            assert_level,
+            ..Default::default()
+        });
+        let x = solver.create_new_integer_variable(0, 10, None);
+        let result = solver.add_propagator(
+            ContainsValue {
+                variable: x,
+                value: 7,
+            },
+            NonZero::new(1).unwrap(),
+        );
+        assert!(result.is_ok());
+
+        let mut brancher =
+            IndependentVariableValueBrancher::new(InputOrder::new(vec![x]), InDomainSplit);
+        let flag = solver.solve(&mut Indefinite, &mut brancher);
+        assert!(matches!(flag, CSPSolverExecutionFlag::Feasible));
+
+        NUM_STATE_CHECKS.with(Cell::get) - num_checks_before
+    };
+
+    assert_eq!(count_state_checks(AssertLevel::Moderate), 0);
+    assert!(count_state_checks(AssertLevel::Advanced) > 0);
+}
+
+#[test]
+fn default_assert_level_is_determined_at_compile_time() {
+    assert_eq!(
+        SatisfactionSolverOptions::default().assert_level as u8,
+        ASSERT_LEVEL_DEFINITION
+    );
+
+    let _ = ConstraintSatisfactionSolver::default();
+    assert_eq!(asserts::assert_level(), ASSERT_LEVEL_DEFINITION);
+}
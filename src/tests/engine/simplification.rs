@@ -0,0 +1,151 @@
+#![cfg(test)]
+
+use std::num::NonZero;
+
+use crate::engine::cp::propagation::PropagatorId;
+use crate::engine::variables::Literal;
+use crate::engine::ConstraintSatisfactionSolver;
+use crate::predicate;
+use crate::propagators::arithmetic::linear_less_or_equal::LinearLessOrEqualPropagator;
+
+fn new_literal(solver: &mut ConstraintSatisfactionSolver) -> Literal {
+    Literal::new(solver.create_new_propositional_variable(None), true)
+}
+
+#[test]
+fn simplify_root_removes_satisfied_learned_clauses_and_shrinks_learned_clauses() {
+    let mut solver = ConstraintSatisfactionSolver::default();
+    let [a, b, c, d, e] = std::array::from_fn(|_| new_literal(&mut solver));
+
+    let _ = solver.clausal_propagator.add_clause_unchecked(
+        vec![a, b, c],
+        true,
+        &mut solver.clause_allocator,
+    );
+    let learned = solver
+        .clausal_propagator
+        .add_clause_unchecked(vec![!a, d, e], true, &mut solver.clause_allocator)
+        .unwrap();
+    let original = solver
+        .clausal_propagator
+        .add_clause_unchecked(vec![!a, !d, !e], false, &mut solver.clause_allocator)
+        .unwrap();
+    assert!(solver.add_clause([a]).is_ok());
+    assert_eq!(solver.model_summary().num_learned_clauses, 2);
+
+    solver.simplify_root();
+
+    assert_eq!(solver.model_summary().num_learned_clauses, 1);
+    assert_eq!(
+        solver.clause_allocator[learned].get_literal_slice(),
+        &[d, e]
+    );
+    // The original clauses define the problem, so they are kept intact
+    let original_literals = solver.clause_allocator[original].get_literal_slice();
+    assert_eq!(original_literals.len(), 3);
+    assert!(original_literals.contains(&!a));
+
+    // The shrunk clause is still propagated
+    assert!(solver.add_clause([!d]).is_ok());
+    assert!(solver.assignments_propositional.is_literal_assigned_true(e));
+}
+
+#[test]
+fn simplify_root_keeps_learned_clauses_which_are_reasons() {
+    let mut solver = ConstraintSatisfactionSolver::default();
+    let [a, b, c] = std::array::from_fn(|_| new_literal(&mut solver));
+
+    let _ = solver.clausal_propagator.add_clause_unchecked(
+        vec![a, b, c],
+        true,
+        &mut solver.clause_allocator,
+    );
+    assert!(solver.add_clause([!b]).is_ok());
+    assert!(solver.add_clause([!c]).is_ok());
+    assert!(solver.assignments_propositional.is_literal_assigned_true(a));
+
+    solver.simplify_root();
+
+    assert_eq!(solver.model_summary().num_learned_clauses, 1);
+}
+
+#[test]
+fn entailed_propagators_are_reactivated_when_rolling_back() {
+    let mut solver = ConstraintSatisfactionSolver::default();
+    let x = solver.create_new_integer_variable(0, 10, None);
+    let y = solver.create_new_integer_variable(0, 10, None);
+
+    let result = solver.add_propagator(
+        LinearLessOrEqualPropagator::new([x, y].into(), 12),
+        NonZero::new(1).unwrap(),
+    );
+    assert!(result.is_ok());
+    let propagator = PropagatorId(0);
+
+    let snapshot = solver.snapshot();
+    let x_at_most_5 = solver.get_literal(predicate![x <= 5]);
+    let y_at_most_7 = solver.get_literal(predicate![y <= 7]);
+    assert!(solver
+        .add_clauses([vec![x_at_most_5], vec![y_at_most_7]])
+        .is_ok());
+
+    solver.simplify_root();
+    assert!(solver.is_propagator_entailed(propagator));
+
+    solver.rollback_to(snapshot);
+    assert!(!solver.is_propagator_entailed(propagator));
+
+    // The reactivated propagator is enqueued again when its variables change
+    let y_at_least_9 = solver.get_literal(predicate![y >= 9]);
+    assert!(solver.add_clause([y_at_least_9]).is_ok());
+    assert_eq!(solver.get_upper_bound(&x), 3);
+}
+
+#[test]
+fn simplify_root_keeps_clauses_while_snapshots_exist() {
+    let mut solver = ConstraintSatisfactionSolver::default();
+    let [a, b, c] = std::array::from_fn(|_| new_literal(&mut solver));
+
+    let _ = solver.clausal_propagator.add_clause_unchecked(
+        vec![a, b, c],
+        true,
+        &mut solver.clause_allocator,
+    );
+    let _ = solver.snapshot();
+    assert!(solver.add_clause([a]).is_ok());
+
+    solver.simplify_root();
+
+    assert_eq!(solver.model_summary().num_learned_clauses, 1);
+}
+
+#[test]
+fn size_snapshot_counts_the_literals_and_watchers_of_clauses() {
+    let mut solver = ConstraintSatisfactionSolver::default();
+    let [a, b, c, d, e, f] = std::array::from_fn(|_| new_literal(&mut solver));
+    assert!(solver.add_clause([a, b, c]).is_ok());
+    let before = solver.size_snapshot();
+
+    // A literal takes 4 bytes and each of the two watchers of a clause takes 8 bytes; the
+    // slot of the clause was already reserved when the first clause was allocated
+    assert!(solver.add_clause([d, e, f]).is_ok());
+    let after = solver.size_snapshot();
+    assert_eq!(after.clause_bytes() - before.clause_bytes(), 12);
+    assert_eq!(after.watch_list_bytes() - before.watch_list_bytes(), 16);
+
+    // A binary clause is stored as two implications of 4 bytes each
+    solver
+        .clausal_propagator
+        .add_permanent_implication_unchecked(a, d);
+    let binary = solver.size_snapshot();
+    assert_eq!(binary.clause_bytes(), after.clause_bytes());
+    assert_eq!(binary.watch_list_bytes() - after.watch_list_bytes(), 8);
+
+    assert_eq!(
+        solver.approximate_memory_usage(),
+        binary.clause_bytes()
+            + binary.assignment_bytes()
+            + binary.watch_list_bytes()
+            + binary.reason_bytes()
+    );
+}
@@ -0,0 +1,645 @@
+#![cfg(test)]
+//! Differential tests which compare the [`Solver`] against a naive reference solver on randomly
+//! generated models.
+//!
+//! Every model has 4 to 8 variables with domains of at most 6 values, and a random mix of linear,
+//! element, all-different, and clause constraints. The reference solver enumerates the solutions
+//! of a [`FuzzModel`] by recursively assigning the variables in order, and backtracks as soon as a
+//! constraint over assigned variables is violated. The [`Solver`] and the reference solver should
+//! agree on:
+//! - whether the model is satisfiable;
+//! - the complete set of solutions, as enumerated by the [`SolutionIterator`];
+//! - the optimum of a random linear objective, for every [`OptimisationStrategy`].
+//!
+//! Every test runs a fixed range of seeds within a time budget. A mismatch reports the seed with
+//! which the model was generated, together with the model in MiniZinc syntax to reproduce it.
+//!
+//! [`SolutionIterator`]: crate::results::solution_iterator::SolutionIterator
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::num::NonZero;
+use std::time::Duration;
+use std::time::Instant;
+
+use clap::ValueEnum;
+use rand::rngs::SmallRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use rand::SeedableRng;
+
+use crate::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
+use crate::branching::InDomainMin;
+use crate::branching::InputOrder;
+use crate::constraints;
+use crate::optimisation::OptimisationStrategy;
+use crate::predicate;
+use crate::results::solution_iterator::IteratedSolution;
+use crate::results::OptimisationResult;
+use crate::results::ProblemSolution;
+use crate::results::SatisfactionResult;
+use crate::termination::Indefinite;
+use crate::tests::harness::enumerate_assignments;
+use crate::variables::DomainId;
+use crate::variables::TransformableVariable;
+use crate::ConstraintOperationError;
+use crate::Solver;
+
+/// The number of seeds which is run by every test, unless the [`TIME_BUDGET`] is exceeded.
+const NUM_SEEDS: u64 = 300;
+/// The time after which a test stops generating new models.
+const TIME_BUDGET: Duration = Duration::from_secs(20);
+/// Models with more solutions are skipped when comparing the sets of solutions, since enumerating
+/// them with the solution iterator takes too long.
+const MAX_ENUMERATED_SOLUTIONS: usize = 200;
+
+/// A literal of a [`FuzzConstraint::Clause`]: `[variable == value]` or `[variable != value]`.
+#[derive(Clone, Copy, Debug)]
+struct FuzzLiteral {
+    variable: usize,
+    value: i32,
+    is_equality: bool,
+}
+
+impl FuzzLiteral {
+    fn is_satisfied(&self, assignment: &[i32]) -> bool {
+        (assignment[self.variable] == self.value) == self.is_equality
+    }
+}
+
+/// The constraints of a [`FuzzModel`]; the variables are referred to by their index.
+#[derive(Clone, Debug)]
+enum FuzzConstraint {
+    /// `sum(coefficient * variable) <= rhs`
+    LinearLessEqual {
+        terms: Vec<(i32, usize)>,
+        rhs: i32,
+    },
+    /// `sum(coefficient * variable) = rhs`
+    LinearEqual {
+        terms: Vec<(i32, usize)>,
+        rhs: i32,
+    },
+    /// `array[index] = rhs`, where the array is indexed from 1 and the domain of `index` is
+    /// within `[1, array.len()]`.
+    Element {
+        index: usize,
+        array: Vec<usize>,
+        rhs: usize,
+    },
+    AllDifferent(Vec<usize>),
+    Clause(Vec<FuzzLiteral>),
+}
+
+impl FuzzConstraint {
+    /// Returns the variables over which the constraint is defined.
+    fn scope(&self) -> Vec<usize> {
+        match self {
+            FuzzConstraint::LinearLessEqual { terms, .. }
+            | FuzzConstraint::LinearEqual { terms, .. } => {
+                terms.iter().map(|&(_, variable)| variable).collect()
+            }
+            FuzzConstraint::Element { index, array, rhs } => {
+                array.iter().copied().chain([*index, *rhs]).collect()
+            }
+            FuzzConstraint::AllDifferent(variables) => variables.clone(),
+            FuzzConstraint::Clause(literals) => {
+                literals.iter().map(|literal| literal.variable).collect()
+            }
+        }
+    }
+
+    fn is_satisfied(&self, assignment: &[i32]) -> bool {
+        let evaluate = |terms: &[(i32, usize)]| {
+            terms
+                .iter()
+                .map(|&(coefficient, variable)| coefficient * assignment[variable])
+                .sum::<i32>()
+        };
+
+        match self {
+            FuzzConstraint::LinearLessEqual { terms, rhs } => evaluate(terms) <= *rhs,
+            FuzzConstraint::LinearEqual { terms, rhs } => evaluate(terms) == *rhs,
+            FuzzConstraint::Element { index, array, rhs } => {
+                let index = assignment[*index];
+                index >= 1
+                    && index as usize <= array.len()
+                    && assignment[array[index as usize - 1]] == assignment[*rhs]
+            }
+            FuzzConstraint::AllDifferent(variables) => {
+                variables.iter().enumerate().all(|(i, &x)| {
+                    variables[i + 1..]
+                        .iter()
+                        .all(|&y| assignment[x] != assignment[y])
+                })
+            }
+            FuzzConstraint::Clause(literals) => literals
+                .iter()
+                .any(|literal| literal.is_satisfied(assignment)),
+        }
+    }
+
+    /// Posts the constraint to the `solver`, where `variables[i]` is the solver variable of the
+    /// `i`th variable of the model.
+    ///
+    /// The element and all-different constraints are posted as decompositions, which do not
+    /// depend on the propagators that are implemented as part of the assignments.
+    fn post(
+        &self,
+        solver: &mut Solver,
+        variables: &[DomainId],
+        tag: NonZero<u32>,
+    ) -> Result<(), ConstraintOperationError> {
+        let to_terms = |terms: &[(i32, usize)]| {
+            terms
+                .iter()
+                .map(|&(coefficient, variable)| variables[variable].scaled(coefficient))
+                .collect::<Vec<_>>()
+        };
+
+        match self {
+            FuzzConstraint::LinearLessEqual { terms, rhs } => solver
+                .add_constraint(constraints::less_than_or_equals(to_terms(terms), *rhs))
+                .post(tag),
+            FuzzConstraint::LinearEqual { terms, rhs } => solver
+                .add_constraint(constraints::equals(to_terms(terms), *rhs))
+                .post(tag),
+            FuzzConstraint::Element { index, array, rhs } => solver
+                .add_constraint(constraints::element_decomposition(
+                    variables[*index],
+                    array.iter().map(|&x| variables[x]).collect::<Vec<_>>(),
+                    variables[*rhs],
+                ))
+                .post(tag),
+            FuzzConstraint::AllDifferent(array) => solver
+                .add_constraint(constraints::all_different_decomposition(
+                    array.iter().map(|&x| variables[x]),
+                ))
+                .post(tag),
+            FuzzConstraint::Clause(literals) => {
+                let literals = literals
+                    .iter()
+                    .map(|literal| {
+                        let variable = variables[literal.variable];
+                        let predicate = if literal.is_equality {
+                            predicate![variable == literal.value]
+                        } else {
+                            predicate![variable != literal.value]
+                        };
+                        solver.get_literal(predicate)
+                    })
+                    .collect::<Vec<_>>();
+                solver
+                    .add_constraint(constraints::clause(literals))
+                    .post(tag)
+            }
+        }
+    }
+}
+
+/// A randomly generated model which can be solved by both the [`Solver`] and the naive reference
+/// solver.
+#[derive(Clone, Debug)]
+struct FuzzModel {
+    /// The values in the domain of every variable, in increasing order.
+    domains: Vec<Vec<i32>>,
+    constraints: Vec<FuzzConstraint>,
+    /// The coefficients of the linear objective which is minimised.
+    objective: Vec<i32>,
+}
+
+impl FuzzModel {
+    fn generate(seed: u64) -> FuzzModel {
+        let mut rng = SmallRng::seed_from_u64(seed);
+
+        let num_variables = rng.gen_range(4..=8);
+        let mut domains = (0..num_variables)
+            .map(|_| {
+                let lower_bound = rng.gen_range(-2..=3);
+                let candidates = (lower_bound..lower_bound + 8).collect::<Vec<_>>();
+                let size = rng.gen_range(1..=6);
+                let mut values = candidates
+                    .choose_multiple(&mut rng, size)
+                    .copied()
+                    .collect::<Vec<_>>();
+                values.sort();
+                values
+            })
+            .collect::<Vec<_>>();
+
+        let num_constraints = rng.gen_range(2..=num_variables / 2 + 1);
+        let constraints = (0..num_constraints)
+            .map(|_| generate_constraint(&mut rng, &mut domains))
+            .collect();
+
+        let objective = (0..num_variables).map(|_| rng.gen_range(-3..=3)).collect();
+
+        FuzzModel {
+            domains,
+            constraints,
+            objective,
+        }
+    }
+
+    fn is_satisfied(&self, assignment: &[i32]) -> bool {
+        self.constraints
+            .iter()
+            .all(|constraint| constraint.is_satisfied(assignment))
+    }
+
+    fn objective_value(&self, assignment: &[i32]) -> i32 {
+        self.objective
+            .iter()
+            .zip(assignment)
+            .map(|(coefficient, value)| coefficient * value)
+            .sum()
+    }
+
+    /// Enumerates all solutions of the model in lexicographic order, using a naive recursive
+    /// search which assigns the variables in order.
+    fn reference_solutions(&self) -> Vec<Vec<i32>> {
+        let scopes = self
+            .constraints
+            .iter()
+            .map(FuzzConstraint::scope)
+            .collect::<Vec<_>>();
+
+        // A constraint is checked as soon as all variables in its scope are assigned
+        enumerate_assignments(&self.domains, &|domains| {
+            let assignment = domains.iter().map(|values| values[0]).collect::<Vec<_>>();
+            self.constraints
+                .iter()
+                .zip(&scopes)
+                .filter(|(_, scope)| scope.iter().all(|&variable| domains[variable].len() == 1))
+                .all(|(constraint, _)| constraint.is_satisfied(&assignment))
+                .then(|| domains.to_vec())
+        })
+    }
+
+    /// Creates a [`Solver`] for the model; returns the solver, the variables of the model, and
+    /// whether all constraints could be posted (i.e. the model is not found to be infeasible at
+    /// the root).
+    fn to_solver(&self) -> (Solver, Vec<DomainId>, bool) {
+        let mut solver = Solver::default();
+        let variables = self
+            .domains
+            .iter()
+            .map(|values| solver.new_sparse_integer(values.clone()))
+            .collect::<Vec<_>>();
+
+        let is_posted = self
+            .constraints
+            .iter()
+            .enumerate()
+            .all(|(idx, constraint)| {
+                let tag = NonZero::new(idx as u32 + 1).unwrap();
+                constraint.post(&mut solver, &variables, tag).is_ok()
+            });
+
+        (solver, variables, is_posted)
+    }
+}
+
+/// Generates a constraint over the variables with the given `domains`; an element constraint
+/// restricts the domain of its index to the indices of its array.
+fn generate_constraint(rng: &mut SmallRng, domains: &mut [Vec<i32>]) -> FuzzConstraint {
+    let num_variables = domains.len();
+    let pick_variables = |rng: &mut SmallRng, min: usize, max: usize| {
+        let count = rng.gen_range(min..=max.min(num_variables));
+        let mut variables = (0..num_variables).collect::<Vec<_>>();
+        variables.shuffle(rng);
+        variables.truncate(count);
+        variables
+    };
+
+    match rng.gen_range(0..5) {
+        kind @ (0 | 1) => {
+            let terms = pick_variables(rng, 2, 4)
+                .into_iter()
+                .map(|variable| {
+                    let coefficient = *[-3, -2, -1, 1, 2, 3].choose(rng).unwrap();
+                    (coefficient, variable)
+                })
+                .collect::<Vec<_>>();
+
+            // The rhs is attained by a random assignment, which makes the constraint likely to be
+            // neither trivially satisfied nor trivially violated
+            let attained = terms
+                .iter()
+                .map(|&(coefficient, variable)| {
+                    coefficient * *domains[variable].choose(rng).unwrap()
+                })
+                .sum::<i32>();
+
+            if kind == 0 {
+                FuzzConstraint::LinearLessEqual {
+                    terms,
+                    rhs: attained + rng.gen_range(0..=2),
+                }
+            } else {
+                FuzzConstraint::LinearEqual {
+                    terms,
+                    rhs: attained,
+                }
+            }
+        }
+        2 => {
+            let variables = pick_variables(rng, 3, 5);
+            let (index, rhs, array) = (variables[0], variables[1], variables[2..].to_vec());
+
+            let num_indices = array.len() as i32;
+            domains[index].retain(|&value| (1..=num_indices).contains(&value));
+            if domains[index].is_empty() {
+                domains[index].push(rng.gen_range(1..=num_indices));
+            }
+
+            FuzzConstraint::Element { index, array, rhs }
+        }
+        3 => FuzzConstraint::AllDifferent(pick_variables(rng, 2, 4)),
+        _ => FuzzConstraint::Clause(
+            pick_variables(rng, 1, 3)
+                .into_iter()
+                .map(|variable| FuzzLiteral {
+                    variable,
+                    value: *domains[variable].choose(rng).unwrap(),
+                    is_equality: rng.gen_bool(0.5),
+                })
+                .collect(),
+        ),
+    }
+}
+
+/// Writes the model in MiniZinc syntax, such that a failing seed can be reproduced outside of the
+/// test suite.
+impl Display for FuzzModel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let write_terms = |f: &mut Formatter<'_>, terms: &[(i32, usize)]| {
+            let terms = terms
+                .iter()
+                .map(|(coefficient, variable)| format!("{coefficient} * x{variable}"))
+                .collect::<Vec<_>>();
+            write!(f, "{}", terms.join(" + "))
+        };
+        let write_array = |f: &mut Formatter<'_>, variables: &[usize]| {
+            let variables = variables
+                .iter()
+                .map(|variable| format!("x{variable}"))
+                .collect::<Vec<_>>();
+            write!(f, "[{}]", variables.join(", "))
+        };
+
+        for (variable, values) in self.domains.iter().enumerate() {
+            let values = values.iter().map(i32::to_string).collect::<Vec<_>>();
+            writeln!(f, "var {{{}}}: x{variable};", values.join(", "))?;
+        }
+
+        for constraint in &self.constraints {
+            write!(f, "constraint ")?;
+            match constraint {
+                FuzzConstraint::LinearLessEqual { terms, rhs } => {
+                    write_terms(f, terms)?;
+                    write!(f, " <= {rhs}")?;
+                }
+                FuzzConstraint::LinearEqual { terms, rhs } => {
+                    write_terms(f, terms)?;
+                    write!(f, " = {rhs}")?;
+                }
+                FuzzConstraint::Element { index, array, rhs } => {
+                    write_array(f, array)?;
+                    write!(f, "[x{index}] = x{rhs}")?;
+                }
+                FuzzConstraint::AllDifferent(variables) => {
+                    write!(f, "all_different(")?;
+                    write_array(f, variables)?;
+                    write!(f, ")")?;
+                }
+                FuzzConstraint::Clause(literals) => {
+                    let literals = literals
+                        .iter()
+                        .map(|literal| {
+                            let comparison = if literal.is_equality { "=" } else { "!=" };
+                            format!("x{} {comparison} {}", literal.variable, literal.value)
+                        })
+                        .collect::<Vec<_>>();
+                    write!(f, "{}", literals.join(" \\/ "))?;
+                }
+            }
+            writeln!(f, ";")?;
+        }
+
+        let objective = self
+            .objective
+            .iter()
+            .enumerate()
+            .map(|(variable, coefficient)| (*coefficient, variable))
+            .collect::<Vec<_>>();
+        write!(f, "solve minimize ")?;
+        write_terms(f, &objective)?;
+        writeln!(f, ";")
+    }
+}
+
+/// Runs `test` on the models generated from the seeds `0..NUM_SEEDS`, until the [`TIME_BUDGET`]
+/// is exceeded. The `test` returns a description of the mismatch (if any), which is reported
+/// together with the seed and the model.
+fn run_seeds(test: impl Fn(&FuzzModel) -> Result<(), String>) {
+    let start = Instant::now();
+
+    for seed in 0..NUM_SEEDS {
+        if start.elapsed() > TIME_BUDGET {
+            break;
+        }
+
+        let model = FuzzModel::generate(seed);
+        if let Err(mismatch) = test(&model) {
+            panic!("{mismatch}\nThe model was generated with seed {seed}:\n{model}");
+        }
+    }
+}
+
+fn brancher(
+    variables: &[DomainId],
+) -> IndependentVariableValueBrancher<DomainId, InputOrder<DomainId>, InDomainMin> {
+    IndependentVariableValueBrancher::new(InputOrder::new(variables.to_vec()), InDomainMin)
+}
+
+#[test]
+fn satisfiability_agrees_with_reference() {
+    run_seeds(|model| {
+        let is_satisfiable = !model.reference_solutions().is_empty();
+
+        let (mut solver, variables, is_posted) = model.to_solver();
+        if !is_posted {
+            return match is_satisfiable {
+                true => Err("The satisfiable model is infeasible at the root".to_owned()),
+                false => Ok(()),
+            };
+        }
+
+        match solver.satisfy(&mut brancher(&variables), &mut Indefinite) {
+            SatisfactionResult::Satisfiable(solution) => {
+                let assignment = variables
+                    .iter()
+                    .map(|&variable| solution.get_integer_value(variable))
+                    .collect::<Vec<_>>();
+
+                if !is_satisfiable {
+                    Err(format!(
+                        "The unsatisfiable model has solution {assignment:?}"
+                    ))
+                } else if !model.is_satisfied(&assignment) {
+                    Err(format!("The solution {assignment:?} violates the model"))
+                } else {
+                    Ok(())
+                }
+            }
+            SatisfactionResult::Unsatisfiable if is_satisfiable => {
+                Err("The satisfiable model is reported to be unsatisfiable".to_owned())
+            }
+            SatisfactionResult::Unsatisfiable => Ok(()),
+            SatisfactionResult::Unknown(reason) => {
+                Err(format!("The search was terminated ({reason:?})"))
+            }
+        }
+    });
+}
+
+#[test]
+fn solution_sets_agree_with_reference() {
+    run_seeds(|model| {
+        let expected = model.reference_solutions();
+        if expected.len() > MAX_ENUMERATED_SOLUTIONS {
+            return Ok(());
+        }
+
+        let (mut solver, variables, is_posted) = model.to_solver();
+        let mut solutions = vec![];
+
+        if is_posted {
+            let mut brancher = brancher(&variables);
+            let mut termination = Indefinite;
+            let mut solution_iterator =
+                solver.get_solution_iterator(&mut brancher, &mut termination);
+
+            loop {
+                match solution_iterator.next_solution() {
+                    IteratedSolution::Solution(solution) => solutions.push(
+                        variables
+                            .iter()
+                            .map(|&variable| solution.get_integer_value(variable))
+                            .collect::<Vec<_>>(),
+                    ),
+                    IteratedSolution::Finished | IteratedSolution::Unsatisfiable => break,
+                    IteratedSolution::Unknown(reason) => {
+                        return Err(format!("The search was terminated ({reason:?})"))
+                    }
+                }
+            }
+        }
+
+        let num_solutions = solutions.len();
+        solutions.sort();
+        solutions.dedup();
+        if solutions.len() != num_solutions {
+            return Err("The solution iterator reported a solution more than once".to_owned());
+        }
+
+        if solutions != expected {
+            return Err(format!(
+                "The solver found {} solutions, but there are {}; the first difference is {:?}",
+                solutions.len(),
+                expected.len(),
+                first_difference(&solutions, &expected),
+            ));
+        }
+
+        Ok(())
+    });
+}
+
+#[test]
+fn optima_agree_with_reference() {
+    run_seeds(|model| {
+        let expected = model
+            .reference_solutions()
+            .iter()
+            .map(|assignment| model.objective_value(assignment))
+            .min();
+
+        for &strategy in OptimisationStrategy::value_variants() {
+            let (mut solver, mut variables, is_posted) = model.to_solver();
+            let optimum = if is_posted {
+                let (lower_bound, upper_bound) = model.domains.iter().zip(&model.objective).fold(
+                    (0, 0),
+                    |(lower_bound, upper_bound), (values, &coefficient)| {
+                        let first = coefficient * values[0];
+                        let last = coefficient * values[values.len() - 1];
+                        (lower_bound + first.min(last), upper_bound + first.max(last))
+                    },
+                );
+                let objective = solver.new_bounded_integer(lower_bound, upper_bound);
+                let terms = variables
+                    .iter()
+                    .zip(&model.objective)
+                    .filter(|(_, &coefficient)| coefficient != 0)
+                    .map(|(variable, &coefficient)| variable.scaled(coefficient))
+                    .chain([objective.scaled(-1)])
+                    .collect::<Vec<_>>();
+                if solver
+                    .add_constraint(constraints::equals(terms, 0))
+                    .post(NonZero::new(model.constraints.len() as u32 + 1).unwrap())
+                    .is_err()
+                {
+                    return Err("The objective definition is infeasible at the root".to_owned());
+                }
+                variables.push(objective);
+
+                match solver.minimise_with_strategy(
+                    &mut brancher(&variables),
+                    &mut Indefinite,
+                    objective,
+                    strategy,
+                ) {
+                    OptimisationResult::Optimal(solution) => {
+                        Some(solution.get_integer_value(objective))
+                    }
+                    OptimisationResult::Unsatisfiable => None,
+                    result => {
+                        return Err(format!(
+                            "Minimising with {strategy} did not terminate with a proof: {result:?}"
+                        ))
+                    }
+                }
+            } else {
+                None
+            };
+
+            if optimum != expected {
+                return Err(format!(
+                    "Minimising with {strategy} found the optimum {optimum:?}, but it is \
+                     {expected:?}"
+                ));
+            }
+        }
+
+        Ok(())
+    });
+}
+
+#[test]
+#[should_panic(expected = "The model was generated with seed 0:\nvar {")]
+fn mismatches_report_the_seed_and_the_model() {
+    run_seeds(|_| Err("A mismatch".to_owned()));
+}
+
+/// Returns the first solution which occurs in only one of the sorted lists of solutions.
+fn first_difference<'a>(left: &'a [Vec<i32>], right: &'a [Vec<i32>]) -> Option<&'a Vec<i32>> {
+    left.iter()
+        .filter(|solution| right.binary_search(solution).is_err())
+        .chain(
+            right
+                .iter()
+                .filter(|solution| left.binary_search(solution).is_err()),
+        )
+        .next()
+}
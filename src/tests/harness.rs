@@ -0,0 +1,333 @@
+#![cfg(test)]
+//! A harness which cross-validates the filtering of a propagator for a global constraint against a
+//! decomposition of that constraint on randomly generated instances; see
+//! [`assert_equivalent_filtering`].
+//!
+//! It also contains the helpers which are shared by the tests for enumerating solutions, either
+//! using the solution iterator of a [`Solver`] ([`enumerate_solutions`]) or using a naive search
+//! over explicit domains ([`enumerate_assignments`]).
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use rand::rngs::SmallRng;
+use rand::Rng;
+use rand::SeedableRng;
+
+use crate::basic_types::Inconsistency;
+use crate::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
+use crate::branching::InDomainMin;
+use crate::branching::InputOrder;
+use crate::engine::test_helper::TestSolver;
+use crate::engine::variables::DomainId;
+use crate::engine::variables::IntegerVariable;
+use crate::results::solution_iterator::IteratedSolution;
+use crate::results::ProblemSolution;
+use crate::termination::Indefinite;
+use crate::Solver;
+
+/// Specifies the random domains which are generated by [`assert_equivalent_filtering`].
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct DomainSpec {
+    /// The number of variables of the constraint.
+    pub(crate) num_variables: usize,
+    /// The smallest value which can be in a domain.
+    pub(crate) lower_bound: i32,
+    /// The largest value which can be in a domain.
+    pub(crate) upper_bound: i32,
+    /// The probability with which a value is removed from a domain; a domain keeps at least one
+    /// value.
+    pub(crate) hole_probability: f64,
+}
+
+impl DomainSpec {
+    fn generate(&self, rng: &mut SmallRng) -> Vec<Vec<i32>> {
+        (0..self.num_variables)
+            .map(|_| {
+                let mut values = (self.lower_bound..=self.upper_bound)
+                    .filter(|_| !rng.gen_bool(self.hole_probability))
+                    .collect::<Vec<_>>();
+                if values.is_empty() {
+                    values.push(rng.gen_range(self.lower_bound..=self.upper_bound));
+                }
+                values
+            })
+            .collect()
+    }
+}
+
+/// The difference in propagation strength between the global constraint and its decomposition,
+/// which is reported by [`assert_equivalent_filtering`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct FilteringComparison {
+    /// The number of instances which were generated.
+    pub(crate) num_samples: usize,
+    /// The number of values which were removed by the global constraint at the root.
+    pub(crate) num_values_pruned_by_global: usize,
+    /// The number of values which were removed by the decomposition at the root.
+    pub(crate) num_values_pruned_by_decomposition: usize,
+    /// The number of instances on which the decomposition removed a value which the global
+    /// constraint kept.
+    pub(crate) num_samples_pruned_less_by_global: usize,
+}
+
+impl Display for FilteringComparison {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "over {} samples, the global constraint pruned {} values and the decomposition pruned \
+             {} values; the global constraint pruned less on {} samples",
+            self.num_samples,
+            self.num_values_pruned_by_global,
+            self.num_values_pruned_by_decomposition,
+            self.num_samples_pruned_less_by_global
+        )
+    }
+}
+
+/// Posts a constraint over the provided variables in the [`TestSolver`].
+pub(crate) trait ConstraintBuilder: Fn(&mut TestSolver, &[DomainId]) -> PostResult {}
+
+impl<F: Fn(&mut TestSolver, &[DomainId]) -> PostResult> ConstraintBuilder for F {}
+
+/// The result of posting a constraint using a [`ConstraintBuilder`].
+pub(crate) type PostResult = Result<(), Inconsistency>;
+
+/// Cross-validates the propagator(s) posted by `global_constraint_builder` against the
+/// decomposition posted by `decomposition_builder` on `samples` instances whose domains are
+/// generated according to `domain_spec`, using a random generator seeded with `seed`.
+///
+/// For every instance, the following is asserted:
+/// - Both models have exactly the same solutions; these are enumerated using a recursive search
+///   which fixes one variable at a time and propagates to a fixed point in a fresh [`TestSolver`]
+///   for every node.
+/// - The global constraint is sound at the root: after propagating to a fixed point, it keeps
+///   every value which occurs in a solution of the decomposition.
+///
+/// When the global constraint prunes less than the decomposition, this is not considered to be an
+/// error; instead, it is counted in the returned [`FilteringComparison`].
+pub(crate) fn assert_equivalent_filtering(
+    global_constraint_builder: impl ConstraintBuilder,
+    decomposition_builder: impl ConstraintBuilder,
+    domain_spec: DomainSpec,
+    samples: usize,
+    seed: u64,
+) -> FilteringComparison {
+    let mut rng = SmallRng::seed_from_u64(seed);
+    let mut comparison = FilteringComparison {
+        num_samples: samples,
+        ..Default::default()
+    };
+
+    for _ in 0..samples {
+        let domains = domain_spec.generate(&mut rng);
+
+        let decomposition_solutions = enumerate_assignments(&domains, &|domains| {
+            propagate_at_root(&decomposition_builder, domains)
+        });
+        let global_solutions = enumerate_assignments(&domains, &|domains| {
+            propagate_at_root(&global_constraint_builder, domains)
+        });
+        assert_eq!(
+            global_solutions, decomposition_solutions,
+            "the solutions of the global constraint differ from those of the decomposition on \
+             the domains {domains:?}"
+        );
+
+        let global_domains = propagate_at_root(&global_constraint_builder, &domains);
+        let decomposition_domains = propagate_at_root(&decomposition_builder, &domains);
+
+        match &global_domains {
+            Some(global_domains) => {
+                for solution in &decomposition_solutions {
+                    for (index, value) in solution.iter().enumerate() {
+                        assert!(
+                            global_domains[index].contains(value),
+                            "the global constraint removed the value {value} of variable \
+                             {index}, which occurs in the solution {solution:?} (domains \
+                             {domains:?})"
+                        );
+                    }
+                }
+            }
+            None => assert!(
+                decomposition_solutions.is_empty(),
+                "the global constraint reported a conflict at the root on the domains \
+                 {domains:?}, but there are solutions"
+            ),
+        }
+
+        let num_values_pruned_by_global = num_pruned_values(&domains, global_domains.as_deref());
+        let num_values_pruned_by_decomposition =
+            num_pruned_values(&domains, decomposition_domains.as_deref());
+        comparison.num_values_pruned_by_global += num_values_pruned_by_global;
+        comparison.num_values_pruned_by_decomposition += num_values_pruned_by_decomposition;
+
+        let is_pruned_less = match (&global_domains, &decomposition_domains) {
+            (Some(_), None) => true,
+            (Some(global_domains), Some(decomposition_domains)) => global_domains
+                .iter()
+                .zip(decomposition_domains)
+                .any(|(global_domain, decomposition_domain)| {
+                    global_domain
+                        .iter()
+                        .any(|value| !decomposition_domain.contains(value))
+                }),
+            (None, _) => false,
+        };
+        comparison.num_samples_pruned_less_by_global += usize::from(is_pruned_less);
+    }
+
+    comparison
+}
+
+/// Creates a [`TestSolver`] with the provided `domains`, posts the constraint and propagates to a
+/// fixed point; returns the resulting domains, or [`None`] if a conflict is detected.
+pub(crate) fn propagate_at_root(
+    builder: &impl ConstraintBuilder,
+    domains: &[Vec<i32>],
+) -> Option<Vec<Vec<i32>>> {
+    let mut solver = TestSolver::default();
+    let variables = domains
+        .iter()
+        .map(|values| solver.new_sparse_variable(values))
+        .collect::<Vec<_>>();
+
+    builder(&mut solver, &variables).ok()?;
+    solver.propagate_until_fixed_point().ok()?;
+
+    Some(
+        variables
+            .iter()
+            .map(|&variable| {
+                (solver.lower_bound(variable)..=solver.upper_bound(variable))
+                    .filter(|&value| solver.contains(variable, value))
+                    .collect()
+            })
+            .collect(),
+    )
+}
+
+/// Enumerates the solutions of the model in `solver` using the solution iterator, branching over
+/// `variables`; returns the values of `variables` in every solution, in lexicographic order.
+///
+/// It is asserted that the solution iterator does not report a solution more than once.
+pub(crate) fn enumerate_solutions<Var: IntegerVariable + 'static>(
+    solver: &mut Solver,
+    variables: &[Var],
+) -> Vec<Vec<i32>> {
+    let mut brancher =
+        IndependentVariableValueBrancher::new(InputOrder::new(variables.to_vec()), InDomainMin);
+    let mut termination = Indefinite;
+    let mut solution_iterator = solver.get_solution_iterator(&mut brancher, &mut termination);
+
+    let mut solutions = vec![];
+    while let IteratedSolution::Solution(solution) = solution_iterator.next_solution() {
+        solutions.push(
+            variables
+                .iter()
+                .map(|variable| solution.get_integer_value(variable.clone()))
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    solutions.sort();
+    assert!(
+        solutions.windows(2).all(|pair| pair[0] != pair[1]),
+        "the solution iterator reported a solution more than once"
+    );
+    solutions
+}
+
+/// Enumerates the assignments to variables with the provided `domains` which are accepted by
+/// `filter`, in lexicographic order if the values of every domain are sorted.
+///
+/// The search fixes the first unfixed variable to each of its values in turn. In every node,
+/// `filter` is called with the current domains; it returns the domains without the values which
+/// cannot be part of a solution, or [`None`] if the node contains no solutions.
+pub(crate) fn enumerate_assignments(
+    domains: &[Vec<i32>],
+    filter: &impl Fn(&[Vec<i32>]) -> Option<Vec<Vec<i32>>>,
+) -> Vec<Vec<i32>> {
+    let mut solutions = vec![];
+    enumerate_assignments_recursively(domains, filter, &mut solutions);
+    solutions
+}
+
+fn enumerate_assignments_recursively(
+    domains: &[Vec<i32>],
+    filter: &impl Fn(&[Vec<i32>]) -> Option<Vec<Vec<i32>>>,
+    solutions: &mut Vec<Vec<i32>>,
+) {
+    let Some(domains) = filter(domains) else {
+        return;
+    };
+
+    let Some(unfixed_index) = domains.iter().position(|values| values.len() > 1) else {
+        solutions.push(domains.iter().map(|values| values[0]).collect());
+        return;
+    };
+
+    for &value in &domains[unfixed_index] {
+        let mut branch_domains = domains.clone();
+        branch_domains[unfixed_index] = vec![value];
+        enumerate_assignments_recursively(&branch_domains, filter, solutions);
+    }
+}
+
+fn num_pruned_values(domains: &[Vec<i32>], pruned_domains: Option<&[Vec<i32>]>) -> usize {
+    let num_values = domains.iter().map(Vec::len).sum::<usize>();
+    let num_remaining_values = pruned_domains
+        .map(|pruned_domains| pruned_domains.iter().map(Vec::len).sum::<usize>())
+        .unwrap_or(0);
+
+    num_values - num_remaining_values
+}
+
+mod tests {
+    use super::*;
+    use crate::propagators::arithmetic::linear_not_equal::LinearNotEqualPropagator;
+    use crate::variables::TransformableVariable;
+
+    const DOMAIN_SPEC: DomainSpec = DomainSpec {
+        num_variables: 3,
+        lower_bound: 0,
+        upper_bound: 3,
+        hole_probability: 0.3,
+    };
+
+    /// Posts `x_0 != x_1`.
+    fn not_equals(solver: &mut TestSolver, variables: &[DomainId]) -> PostResult {
+        let _ = solver.new_propagator(LinearNotEqualPropagator::new(
+            [variables[0].scaled(1), variables[1].scaled(-1)].into(),
+            0,
+        ))?;
+        Ok(())
+    }
+
+    /// Posts `x_0 != x_1` and `x_0 != 0`, i.e. a constraint which is stronger than `x_0 != x_1`.
+    fn not_equals_and_nonzero(solver: &mut TestSolver, variables: &[DomainId]) -> PostResult {
+        not_equals(solver, variables)?;
+        let _ = solver.new_propagator(LinearNotEqualPropagator::new([variables[0]].into(), 0))?;
+        Ok(())
+    }
+
+    #[test]
+    fn identical_models_are_equivalent() {
+        let comparison = assert_equivalent_filtering(not_equals, not_equals, DOMAIN_SPEC, 20, 42);
+
+        assert_eq!(comparison.num_samples, 20);
+        assert_eq!(
+            comparison.num_values_pruned_by_global,
+            comparison.num_values_pruned_by_decomposition
+        );
+        assert_eq!(comparison.num_samples_pruned_less_by_global, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "the solutions of the global constraint differ")]
+    fn different_solutions_are_detected() {
+        let _ =
+            assert_equivalent_filtering(not_equals_and_nonzero, not_equals, DOMAIN_SPEC, 20, 42);
+    }
+}
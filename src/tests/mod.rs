@@ -1,5 +1,9 @@
 pub(crate) mod conflict_analysis;
 pub(crate) mod encodings;
+pub(crate) mod engine;
+pub(crate) mod fuzz;
+pub(crate) mod harness;
 pub(crate) mod minimisation;
 pub(crate) mod proof_checking;
 pub(crate) mod propagators;
+pub(crate) mod solver;
@@ -10,10 +10,16 @@ use crate::model::Constraint;
 use crate::model::Model;
 use crate::proof;
 use crate::proof::processing::Processor;
+use crate::proof::ProcessingError;
+use crate::proof::ProcessingReport;
 use crate::proof::ProofLiterals;
 use crate::variables::Literal;
 
 fn example_processor() -> Processor {
+    Processor::from(example_model())
+}
+
+fn example_model() -> Model {
     let mut model = Model::default();
 
     let x = model.new_interval_variable("x", 0, 1);
@@ -51,7 +57,31 @@ fn example_processor() -> Processor {
         rhs: 0,
     });
 
-    Processor::from(model.clone())
+    model
+}
+
+fn example_definitions() -> LiteralDefinitions<String> {
+    let literals = r#"
+    1 [x >= 1]
+    2 [y >= 2]
+    3 [y >= 1]
+    4 [z >= 1]
+    "#;
+
+    LiteralDefinitions::<String>::parse(literals.as_bytes()).unwrap()
+}
+
+fn process_example(
+    scaffold: &str,
+    definitions: LiteralDefinitions<String>,
+) -> anyhow::Result<ProcessingReport> {
+    proof::process_scaffold(
+        &example_model(),
+        scaffold.as_bytes(),
+        definitions,
+        Vec::new(),
+        Format::Text,
+    )
 }
 
 #[test]
@@ -110,9 +140,9 @@ fn test_inference_introduction() {
     let literal_6 = Literal::u32_to_literal(6);
     let literal_7 = Literal::u32_to_literal(7);
     let nogoods = vec![
-        vec![literal_2.clone(), literal_7.clone()],
-        vec![literal_6.clone(), literal_2.clone()],
-        vec![literal_2.clone()],
+        vec![literal_2, literal_7],
+        vec![literal_6, literal_2],
+        vec![literal_2],
     ];
 
     let mut buf = Vec::new();
@@ -139,11 +169,87 @@ fn test_inference_introduction() {
     let lines_proof = proof_text.trim().lines();
     let lines_expected = expected_proof.trim().lines();
     assert_eq!(lines_proof.clone().count(), lines_expected.clone().count());
-    for (proof_l, expected_l) in lines_proof.into_iter().zip(lines_expected.into_iter()) {
+    for (proof_l, expected_l) in lines_proof.zip(lines_expected) {
         assert_superset(proof_l, expected_l)
     }
 }
 
+#[test]
+fn test_process_scaffold() {
+    let scaffold = r#"
+        n 1 -1 2
+        n 2 -3 4
+        n 3 -1 -2
+        n 4 -1
+        n 5
+        c UNSAT
+    "#;
+
+    let report = process_example(scaffold, example_definitions()).unwrap();
+
+    assert_eq!(report.input_steps, 5);
+    assert_eq!(report.trimmed_steps, 3);
+    assert_eq!(report.introduced_inferences, 6);
+}
+
+#[test]
+fn process_scaffold_without_conclusion_is_an_error() {
+    let scaffold = r#"
+        n 1 -1 2
+        n 2 -3 4
+    "#;
+
+    let error = process_example(scaffold, example_definitions()).unwrap_err();
+
+    assert!(matches!(
+        error.downcast_ref::<ProcessingError>(),
+        Some(ProcessingError::MissingConclusion)
+    ));
+}
+
+#[test]
+fn process_scaffold_with_syntax_error_is_an_error() {
+    let scaffold = r#"
+        n 1 -1 2
+        x 2 -3 4
+        c UNSAT
+    "#;
+
+    let error = process_example(scaffold, example_definitions()).unwrap_err();
+
+    assert!(matches!(
+        error.downcast_ref::<ProcessingError>(),
+        Some(ProcessingError::UnparseableScaffold(_))
+    ));
+}
+
+#[test]
+fn process_scaffold_with_unknown_variable_is_an_error() {
+    let definitions = LiteralDefinitions::<String>::parse("1 [w >= 1]".as_bytes()).unwrap();
+
+    let error = process_example("n 1 -1\nc UNSAT\n", definitions).unwrap_err();
+
+    assert!(matches!(
+        error.downcast_ref::<ProcessingError>(),
+        Some(ProcessingError::UnknownVariable(name)) if name == "w"
+    ));
+}
+
+#[test]
+fn process_scaffold_with_undefined_objective_bound_is_an_error() {
+    let scaffold = r#"
+        n 1 -1 2
+        c 7
+    "#;
+
+    let error = process_example(scaffold, example_definitions()).unwrap_err();
+
+    assert!(matches!(
+        error.downcast_ref::<ProcessingError>(),
+        Some(ProcessingError::ConclusionMismatch(code)) if code.get() == 7
+    ));
+}
+
 fn assert_superset(proof: &str, expected: &str) {
     let proof = proof.trim();
     let expected = expected.trim();
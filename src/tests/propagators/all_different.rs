@@ -2,6 +2,10 @@
 
 use crate::engine::test_helper::TestSolver;
 use crate::propagators::all_different::AllDifferentPropagator;
+use crate::propagators::arithmetic::linear_not_equal::LinearNotEqualPropagator;
+use crate::tests::harness::assert_equivalent_filtering;
+use crate::tests::harness::DomainSpec;
+use crate::variables::TransformableVariable;
 
 #[test]
 fn test_bounds_propagation() {
@@ -42,3 +46,36 @@ fn test_holes_are_punched() {
 
     solver.assert_bounds(x4, 4, 4);
 }
+
+#[test]
+fn filtering_is_equivalent_to_pairwise_not_equals() {
+    let comparison = assert_equivalent_filtering(
+        |solver, variables| {
+            let _ = solver.new_propagator(AllDifferentPropagator::new(variables.into()))?;
+            Ok(())
+        },
+        |solver, variables| {
+            for (index, &variable) in variables.iter().enumerate() {
+                for &other in &variables[index + 1..] {
+                    let _ = solver.new_propagator(LinearNotEqualPropagator::new(
+                        [variable.scaled(1), other.scaled(-1)].into(),
+                        0,
+                    ))?;
+                }
+            }
+            Ok(())
+        },
+        DomainSpec {
+            num_variables: 5,
+            lower_bound: 0,
+            upper_bound: 5,
+            hole_probability: 0.4,
+        },
+        50,
+        11,
+    );
+    assert_eq!(
+        comparison.num_samples_pruned_less_by_global, 0,
+        "the propagator should prune at least as much as the pairwise not equals; {comparison}"
+    );
+}
@@ -0,0 +1,72 @@
+#![cfg(test)]
+
+use crate::conjunction;
+use crate::engine::test_helper::TestSolver;
+use crate::predicate;
+use crate::propagators::arithmetic::binary_less_or_equal::BinaryLessOrEqualPropagator;
+
+#[test]
+fn bounds_are_propagated_in_both_directions() {
+    let mut solver = TestSolver::default();
+
+    let x = solver.new_variable(2, 10);
+    let y = solver.new_variable(0, 8);
+
+    let _ = solver
+        .new_propagator(BinaryLessOrEqualPropagator::new(x, y, 3))
+        .expect("no empty domain");
+
+    solver.assert_bounds(x, 2, 5);
+    solver.assert_bounds(y, 5, 8);
+}
+
+#[test]
+fn lower_bound_of_rhs_is_explained_by_single_predicate() {
+    let mut solver = TestSolver::default();
+
+    let x = solver.new_variable(0, 10);
+    let y = solver.new_variable(0, 20);
+
+    let propagator = solver
+        .new_propagator(BinaryLessOrEqualPropagator::new(x, y, 3))
+        .expect("no empty domain");
+
+    solver.increase_lower_bound(x, 4);
+    solver.propagate(propagator).expect("no empty domain");
+
+    solver.assert_bounds(y, 7, 20);
+
+    let reason = solver.get_reason_int(predicate![y >= 7].try_into().unwrap());
+    assert_eq!(conjunction!([x >= 4]), reason.clone());
+}
+
+#[test]
+fn upper_bound_of_lhs_is_explained_by_single_predicate() {
+    let mut solver = TestSolver::default();
+
+    let x = solver.new_variable(0, 10);
+    let y = solver.new_variable(0, 20);
+
+    let propagator = solver
+        .new_propagator(BinaryLessOrEqualPropagator::new(x, y, -2))
+        .expect("no empty domain");
+
+    solver.decrease_upper_bound(y, 5);
+    solver.propagate(propagator).expect("no empty domain");
+
+    solver.assert_bounds(x, 0, 7);
+
+    let reason = solver.get_reason_int(predicate![x <= 7].try_into().unwrap());
+    assert_eq!(conjunction!([y <= 5]), reason.clone());
+}
+
+#[test]
+fn conflicting_bounds_are_detected() {
+    let mut solver = TestSolver::default();
+
+    let x = solver.new_variable(5, 10);
+    let y = solver.new_variable(0, 6);
+
+    let result = solver.new_propagator(BinaryLessOrEqualPropagator::new(x, y, 2));
+    assert!(result.is_err());
+}
@@ -1,6 +1,9 @@
 #![cfg(test)]
 use crate::engine::test_helper::TestSolver;
 use crate::propagators::circuit::DfsCircuitPropagator;
+use crate::tests::harness::assert_equivalent_filtering;
+use crate::tests::propagators::circuit::post_circuit_decomposition;
+use crate::tests::propagators::circuit::CIRCUIT_DOMAIN_SPEC;
 
 #[test]
 fn detects_failure() {
@@ -35,3 +38,21 @@ fn detect_simple_dfs() {
     assert!(!solver.contains(f, 1));
     assert!(!solver.contains(g, 4));
 }
+
+#[test]
+fn filtering_is_equivalent_to_decomposition() {
+    let comparison = assert_equivalent_filtering(
+        |solver, successors| {
+            let _ = solver.new_propagator(DfsCircuitPropagator::new(successors.into()))?;
+            Ok(())
+        },
+        post_circuit_decomposition,
+        CIRCUIT_DOMAIN_SPEC,
+        50,
+        17,
+    );
+    assert_eq!(
+        comparison.num_samples_pruned_less_by_global, 0,
+        "the propagator should prune at least as much as the decomposition; {comparison}"
+    );
+}
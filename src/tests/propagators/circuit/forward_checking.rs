@@ -1,6 +1,9 @@
 #![cfg(test)]
 use crate::engine::test_helper::TestSolver;
 use crate::propagators::circuit::ForwardCheckingCircuitPropagator;
+use crate::tests::harness::assert_equivalent_filtering;
+use crate::tests::propagators::circuit::post_circuit_decomposition;
+use crate::tests::propagators::circuit::CIRCUIT_DOMAIN_SPEC;
 
 #[test]
 fn detects_failure() {
@@ -31,3 +34,22 @@ fn detects_simple_prevent() {
     // No self-loops
     assert!(!solver.contains(c, 3));
 }
+
+#[test]
+fn filtering_is_equivalent_to_decomposition() {
+    let comparison = assert_equivalent_filtering(
+        |solver, successors| {
+            let _ =
+                solver.new_propagator(ForwardCheckingCircuitPropagator::new(successors.into()))?;
+            Ok(())
+        },
+        post_circuit_decomposition,
+        CIRCUIT_DOMAIN_SPEC,
+        50,
+        23,
+    );
+    assert_eq!(
+        comparison.num_samples_pruned_less_by_global, 0,
+        "the propagator should prune at least as much as the decomposition; {comparison}"
+    );
+}
@@ -1,2 +1,117 @@
+#![cfg(test)]
 pub(crate) mod dfs;
 pub(crate) mod forward_checking;
+
+use crate::basic_types::PropagationStatusCP;
+use crate::engine::cp::domain_events::DomainEvents;
+use crate::engine::cp::propagation::PropagationContextMut;
+use crate::engine::cp::propagation::Propagator;
+use crate::engine::cp::propagation::PropagatorInitialisationContext;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::test_helper::TestSolver;
+use crate::engine::variables::DomainId;
+use crate::predicate;
+use crate::predicates::PropositionalConjunction;
+use crate::propagators::arithmetic::linear_not_equal::LinearNotEqualPropagator;
+use crate::tests::harness::enumerate_assignments;
+use crate::tests::harness::propagate_at_root;
+use crate::tests::harness::DomainSpec;
+use crate::tests::harness::PostResult;
+use crate::variables::TransformableVariable;
+
+/// The domains of the successor variables in the cross-validation of the circuit propagators;
+/// the successors are 1-indexed.
+pub(crate) const CIRCUIT_DOMAIN_SPEC: DomainSpec = DomainSpec {
+    num_variables: 5,
+    lower_bound: 1,
+    upper_bound: 5,
+    hole_probability: 0.4,
+};
+
+/// Posts a decomposition of `circuit(successors)`: the successors are pairwise different, and a
+/// checker rejects the assignments which consist of multiple sub-circuits.
+pub(crate) fn post_circuit_decomposition(
+    solver: &mut TestSolver,
+    successors: &[DomainId],
+) -> PostResult {
+    for (index, &successor) in successors.iter().enumerate() {
+        let _ = solver.new_propagator(LinearNotEqualPropagator::new(
+            [successor].into(),
+            index as i32 + 1,
+        ))?;
+
+        for &other in &successors[index + 1..] {
+            let _ = solver.new_propagator(LinearNotEqualPropagator::new(
+                [successor.scaled(1), other.scaled(-1)].into(),
+                0,
+            ))?;
+        }
+    }
+
+    let _ = solver.new_propagator(SingleCircuitChecker {
+        successors: successors.into(),
+    })?;
+    Ok(())
+}
+
+/// Reports a conflict when all successors are fixed and the cycle starting at the first node does
+/// not visit every node; it does not propagate otherwise.
+struct SingleCircuitChecker {
+    successors: Box<[DomainId]>,
+}
+
+impl Propagator for SingleCircuitChecker {
+    fn name(&self) -> &str {
+        "SingleCircuitChecker"
+    }
+
+    fn propagate(&self, context: PropagationContextMut) -> PropagationStatusCP {
+        if !self
+            .successors
+            .iter()
+            .all(|successor| context.is_fixed(successor))
+        {
+            return Ok(());
+        }
+
+        let mut node = 0;
+        for num_visited in 1..=self.successors.len() {
+            node = context.lower_bound(&self.successors[node]) as usize - 1;
+            if node == 0 && num_visited < self.successors.len() {
+                let conflict: PropositionalConjunction = self
+                    .successors
+                    .iter()
+                    .map(|&successor| {
+                        let value = context.lower_bound(&successor);
+                        predicate![successor == value]
+                    })
+                    .collect();
+                return Err(conflict.into());
+            }
+        }
+
+        Ok(())
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        for &successor in self.successors.iter() {
+            context.register(successor, DomainEvents::ASSIGN);
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn decomposition_accepts_exactly_the_single_circuits() {
+    let solutions = enumerate_assignments(&vec![vec![1, 2, 3, 4]; 4], &|domains| {
+        propagate_at_root(&post_circuit_decomposition, domains)
+    });
+
+    // There are (4 - 1)! circuits through 4 nodes
+    assert_eq!(solutions.len(), 6);
+    assert!(solutions.contains(&vec![2, 3, 4, 1]));
+    assert!(!solutions.contains(&vec![2, 1, 4, 3]));
+}
@@ -0,0 +1,278 @@
+#![cfg(test)]
+
+use crate::conjunction;
+use crate::engine::test_helper::TestSolver;
+use crate::predicate;
+use crate::propagators::count::CountPropagator;
+
+#[test]
+fn rhs_is_bounded_by_fixed_and_possible_occurrences() {
+    let mut solver = TestSolver::default();
+
+    let x0 = solver.new_variable(2, 2);
+    let x1 = solver.new_variable(0, 1);
+    let x2 = solver.new_variable(1, 3);
+    let rhs = solver.new_variable(0, 3);
+
+    let _ = solver
+        .new_propagator(CountPropagator::new([x0, x1, x2].into(), 2, rhs))
+        .expect("no empty domains");
+
+    solver.assert_bounds(rhs, 1, 2);
+
+    let reason = solver.get_reason_int(predicate![rhs >= 1].try_into().unwrap());
+    assert_eq!(conjunction!([x0 == 2]), reason.clone());
+
+    let reason = solver.get_reason_int(predicate![rhs <= 2].try_into().unwrap());
+    assert_eq!(conjunction!([x1 != 2]), reason.clone());
+}
+
+#[test]
+fn reached_upper_bound_removes_value_from_undecided_variables() {
+    let mut solver = TestSolver::default();
+
+    let x0 = solver.new_variable(2, 2);
+    let x1 = solver.new_variable(1, 3);
+    let x2 = solver.new_variable(0, 2);
+    let rhs = solver.new_variable(0, 1);
+
+    let _ = solver
+        .new_propagator(CountPropagator::new([x0, x1, x2].into(), 2, rhs))
+        .expect("no empty domains");
+
+    solver.assert_bounds(rhs, 1, 1);
+    assert!(!solver.contains(x1, 2));
+    solver.assert_bounds(x2, 0, 1);
+
+    let reason = solver.get_reason_int(predicate![x1 != 2].try_into().unwrap());
+    assert_eq!(conjunction!([x0 == 2] & [rhs <= 1]), reason.clone());
+}
+
+#[test]
+fn reached_lower_bound_fixes_undecided_variables_to_value() {
+    let mut solver = TestSolver::default();
+
+    let x0 = solver.new_variable(0, 1);
+    let x1 = solver.new_variable(1, 3);
+    let x2 = solver.new_variable(0, 2);
+    let rhs = solver.new_variable(2, 3);
+
+    let _ = solver
+        .new_propagator(CountPropagator::new([x0, x1, x2].into(), 2, rhs))
+        .expect("no empty domains");
+
+    solver.assert_bounds(rhs, 2, 2);
+    solver.assert_bounds(x1, 2, 2);
+    solver.assert_bounds(x2, 2, 2);
+
+    let reason = solver.get_reason_int(predicate![x2 >= 2].try_into().unwrap());
+    assert_eq!(conjunction!([x0 != 2] & [rhs >= 2]), reason.clone());
+}
+
+#[test]
+fn too_many_occurrences_is_conflicting() {
+    let mut solver = TestSolver::default();
+
+    let x0 = solver.new_variable(2, 2);
+    let x1 = solver.new_variable(2, 2);
+    let rhs = solver.new_variable(0, 1);
+
+    let result = solver.new_propagator(CountPropagator::new([x0, x1].into(), 2, rhs));
+    assert!(result.is_err());
+}
+
+#[test]
+fn reached_upper_bound_removes_all_values_from_undecided_variables() {
+    let mut solver = TestSolver::default();
+
+    let x0 = solver.new_variable(3, 3);
+    let x1 = solver.new_variable(0, 3);
+    let x2 = solver.new_variable(0, 0);
+    let rhs = solver.new_variable(0, 1);
+
+    let _ = solver
+        .new_propagator(CountPropagator::with_values(
+            [x0, x1, x2].into(),
+            [1, 3].into(),
+            rhs,
+        ))
+        .expect("no empty domains");
+
+    solver.assert_domain(x1, vec![0, 2]);
+
+    // The variable `x2` which cannot take any of the values does not establish the count
+    let reason = solver.get_reason_int(predicate![x1 != 1].try_into().unwrap());
+    assert_eq!(conjunction!([x0 == 3] & [rhs <= 1]), reason.clone());
+}
+
+#[test]
+fn reached_lower_bound_restricts_undecided_variables_to_values() {
+    let mut solver = TestSolver::default();
+
+    let x0 = solver.new_variable(0, 2);
+    let x1 = solver.new_variable(2, 5);
+    let x2 = solver.new_variable(4, 4);
+    let rhs = solver.new_variable(2, 3);
+
+    let _ = solver
+        .new_propagator(CountPropagator::with_values(
+            [x0, x1, x2].into(),
+            [1, 3].into(),
+            rhs,
+        ))
+        .expect("no empty domains");
+
+    solver.assert_bounds(rhs, 2, 2);
+    solver.assert_bounds(x0, 1, 1);
+    solver.assert_bounds(x1, 3, 3);
+
+    let reason = solver.get_reason_int(predicate![x0 >= 1].try_into().unwrap());
+    assert_eq!(
+        conjunction!([x2 != 1] & [x2 != 3] & [rhs >= 2]),
+        reason.clone()
+    );
+}
+
+#[test]
+fn removed_values_are_part_of_the_reason_for_restricting_to_values() {
+    let mut solver = TestSolver::default();
+
+    let x0 = solver.new_variable(0, 4);
+    let x1 = solver.new_variable(3, 3);
+    let x2 = solver.new_variable(5, 5);
+    let rhs = solver.new_variable(2, 3);
+    solver.remove(x0, 1).expect("non-empty domain");
+
+    let _ = solver
+        .new_propagator(CountPropagator::with_values(
+            [x0, x1, x2].into(),
+            [1, 3].into(),
+            rhs,
+        ))
+        .expect("no empty domains");
+
+    solver.assert_bounds(x0, 3, 3);
+
+    // The smallest value is not in the domain of `x0`, which is required for the lower bound
+    let reason = solver.get_reason_int(predicate![x0 >= 3].try_into().unwrap());
+    assert_eq!(
+        conjunction!([x2 != 1] & [x2 != 3] & [x0 != 1] & [rhs >= 2]),
+        reason.clone()
+    );
+    let reason = solver.get_reason_int(predicate![x0 <= 3].try_into().unwrap());
+    assert_eq!(
+        conjunction!([x2 != 1] & [x2 != 3] & [rhs >= 2]),
+        reason.clone()
+    );
+}
+
+#[test]
+fn counter_bounds_are_updated_incrementally() {
+    let mut solver = TestSolver::default();
+
+    let x0 = solver.new_variable(0, 2);
+    let x1 = solver.new_variable(0, 2);
+    let x2 = solver.new_variable(0, 2);
+    let rhs = solver.new_variable(0, 3);
+
+    let propagator = solver
+        .new_propagator(CountPropagator::new([x0, x1, x2].into(), 1, rhs))
+        .expect("no empty domains");
+    solver.assert_bounds(rhs, 0, 3);
+
+    solver.increase_lower_bound(x0, 1);
+    solver.decrease_upper_bound(x0, 1);
+    solver.increase_lower_bound(x1, 2);
+    solver
+        .notify_and_propagate(propagator)
+        .expect("no empty domains");
+
+    solver.assert_bounds(rhs, 1, 2);
+
+    let reason = solver.get_reason_int(predicate![rhs >= 1].try_into().unwrap());
+    assert_eq!(conjunction!([x0 == 1]), reason.clone());
+    let reason = solver.get_reason_int(predicate![rhs <= 2].try_into().unwrap());
+    assert_eq!(conjunction!([x1 != 1]), reason.clone());
+}
+
+/// Computes the smallest and largest supported value of the variables in the array (followed by
+/// those of `rhs`) by enumerating all assignments, or [`None`] if there is no solution.
+fn supported_bounds(
+    domains: &[(i32, i32)],
+    values: &[i32],
+    rhs: (i32, i32),
+) -> Option<Vec<(i32, i32)>> {
+    let mut supported = vec![(i32::MAX, i32::MIN); domains.len() + 1];
+    let mut assignment = domains.iter().map(|&(lb, _)| lb).collect::<Vec<_>>();
+
+    loop {
+        let count = assignment
+            .iter()
+            .filter(|value| values.contains(value))
+            .count() as i32;
+
+        if rhs.0 <= count && count <= rhs.1 {
+            for (bounds, &value) in supported.iter_mut().zip(assignment.iter().chain([&count])) {
+                bounds.0 = bounds.0.min(value);
+                bounds.1 = bounds.1.max(value);
+            }
+        }
+
+        let Some(index) = (0..domains.len()).find(|&i| assignment[i] < domains[i].1) else {
+            break;
+        };
+        assignment[index] += 1;
+        for i in 0..index {
+            assignment[i] = domains[i].0;
+        }
+    }
+
+    (supported[0].0 <= supported[0].1).then_some(supported)
+}
+
+#[test]
+fn propagation_matches_enumeration_on_small_domains() {
+    let domains = [(0, 0), (1, 1), (0, 2), (1, 3), (2, 3)];
+    let rhs_domains = [(0, 0), (1, 1), (0, 3), (2, 3), (3, 3)];
+
+    for values in [vec![1], vec![1, 3]] {
+        for &d0 in &domains {
+            for &d1 in &domains {
+                for &d2 in &domains {
+                    for &rhs_domain in &rhs_domains {
+                        let array_domains = [d0, d1, d2];
+                        let expected = supported_bounds(&array_domains, &values, rhs_domain);
+
+                        let mut solver = TestSolver::default();
+                        let array = array_domains
+                            .iter()
+                            .map(|&(lb, ub)| solver.new_variable(lb, ub))
+                            .collect::<Vec<_>>();
+                        let rhs = solver.new_variable(rhs_domain.0, rhs_domain.1);
+
+                        let result = solver
+                            .new_propagator(CountPropagator::with_values(
+                                array.clone().into(),
+                                values.clone().into(),
+                                rhs,
+                            ))
+                            .and_then(|_| solver.propagate_until_fixed_point());
+
+                        let Some(expected) = expected else {
+                            assert!(
+                                result.is_err(),
+                                "{array_domains:?} with rhs {rhs_domain:?} has no solution"
+                            );
+                            continue;
+                        };
+                        assert!(result.is_ok());
+
+                        for (&variable, (lb, ub)) in array.iter().chain([&rhs]).zip(expected) {
+                            solver.assert_bounds(variable, lb, ub);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,2 +1,3 @@
 pub(crate) mod energetic_reasoning;
+pub(crate) mod overload_checking;
 pub(crate) mod time_table;
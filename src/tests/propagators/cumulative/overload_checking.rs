@@ -0,0 +1,142 @@
+#![cfg(test)]
+
+use std::num::NonZero;
+
+use crate::basic_types::ConflictInfo;
+use crate::basic_types::Inconsistency;
+use crate::conjunction;
+use crate::constraints;
+use crate::constraints::CumulativeImpl;
+use crate::constraints::CumulativeOptions;
+use crate::engine::test_helper::TestSolver;
+use crate::propagators::cumulative::OverloadCheckingPropagator;
+use crate::ConstraintOperationError;
+use crate::Solver;
+
+const OVERLOAD_CHECKING_ONLY: CumulativeOptions = CumulativeOptions {
+    time_table: false,
+    overload_checking: true,
+};
+
+#[test]
+fn overload_without_compulsory_parts_is_detected_at_root() {
+    let mut solver = TestSolver::default();
+
+    // Three tasks of duration 2 which all have to execute within [0, 4); since the latest start
+    // time (2) of every task equals its earliest completion time, none of the tasks has a
+    // compulsory part and time-tabling cannot detect that the resource is overloaded
+    let s1 = solver.new_variable(0, 2);
+    let s2 = solver.new_variable(0, 2);
+    let s3 = solver.new_variable(0, 2);
+
+    let result = solver.new_propagator(OverloadCheckingPropagator::new(
+        [s1, s2, s3].into(),
+        [2, 2, 2].into(),
+        [1, 1, 1].into(),
+        1,
+    ));
+
+    let Err(Inconsistency::Other(ConflictInfo::Explanation(reason))) = result else {
+        panic!("expected the overload to be detected");
+    };
+    assert_eq!(
+        conjunction!([s1 >= 0] & [s1 <= 2] & [s2 >= 0] & [s2 <= 2] & [s3 >= 0] & [s3 <= 2]),
+        reason
+    );
+}
+
+#[test]
+fn overload_is_explained_using_the_window_of_the_task_interval() {
+    let mut solver = TestSolver::default();
+
+    let s1 = solver.new_variable(0, 10);
+    let s2 = solver.new_variable(0, 10);
+    let s3 = solver.new_variable(0, 10);
+
+    let propagator = solver
+        .new_propagator(OverloadCheckingPropagator::new(
+            [s1, s2, s3].into(),
+            [3, 2, 4].into(),
+            [2, 2, 1].into(),
+            2,
+        ))
+        .expect("no overload");
+
+    // The first two tasks now have to execute within [2, 6), requiring 10 units of energy while
+    // only 8 units are available; the third task does not contribute to the window
+    solver.increase_lower_bound(s1, 2);
+    solver.increase_lower_bound(s2, 3);
+    solver.decrease_upper_bound(s1, 3);
+    solver.decrease_upper_bound(s2, 4);
+
+    let result = solver.propagate(propagator);
+
+    let Err(Inconsistency::Other(ConflictInfo::Explanation(reason))) = result else {
+        panic!("expected the overload to be detected");
+    };
+    assert_eq!(
+        conjunction!([s1 >= 2] & [s1 <= 3] & [s2 >= 2] & [s2 <= 4]),
+        reason
+    );
+}
+
+#[test]
+fn resource_without_overload_is_not_conflicting() {
+    let mut solver = TestSolver::default();
+
+    let s1 = solver.new_variable(0, 4);
+    let s2 = solver.new_variable(0, 4);
+    let s3 = solver.new_variable(0, 4);
+
+    let _ = solver
+        .new_propagator(OverloadCheckingPropagator::new(
+            [s1, s2, s3].into(),
+            [2, 2, 2].into(),
+            [1, 1, 1].into(),
+            1,
+        ))
+        .expect("no overload");
+
+    solver.assert_bounds(s1, 0, 4);
+    solver.assert_bounds(s2, 0, 4);
+    solver.assert_bounds(s3, 0, 4);
+}
+
+#[test]
+fn overload_checking_is_enabled_through_cumulative_options() {
+    let mut solver = Solver::default();
+    let start_times = [
+        solver.new_bounded_integer(0, 2),
+        solver.new_bounded_integer(0, 2),
+        solver.new_bounded_integer(0, 2),
+    ];
+
+    let result = solver
+        .add_constraint(
+            constraints::cumulative(
+                CumulativeImpl::TimeTable,
+                start_times,
+                [2, 2, 2],
+                [1, 1, 1],
+                1,
+            )
+            .with_options(OVERLOAD_CHECKING_ONLY),
+        )
+        .post(NonZero::new(1).unwrap());
+
+    assert!(matches!(
+        result,
+        Err(ConstraintOperationError::InfeasiblePropagator)
+    ));
+}
+
+#[test]
+fn cumulative_options_default_to_time_table_only() {
+    assert_eq!(
+        CumulativeOptions::default(),
+        CumulativeOptions {
+            time_table: true,
+            overload_checking: false,
+        }
+    );
+}
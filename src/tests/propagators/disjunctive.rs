@@ -0,0 +1,139 @@
+#![cfg(test)]
+
+use std::num::NonZero;
+
+use crate::conjunction;
+use crate::constraints;
+use crate::engine::test_helper::TestSolver;
+use crate::predicate;
+use crate::propagators::disjunctive::DisjunctivePropagator;
+use crate::tests::harness::enumerate_solutions;
+use crate::variables::DomainId;
+use crate::Solver;
+
+#[test]
+fn overload_is_detected_at_root() {
+    let mut solver = TestSolver::default();
+
+    let a = solver.new_variable(0, 3);
+    let b = solver.new_variable(0, 3);
+    let c = solver.new_variable(0, 3);
+
+    let result = solver.new_propagator(DisjunctivePropagator::new(
+        [a, b, c].into(),
+        [2, 2, 2].into(),
+    ));
+    assert!(result.is_err());
+}
+
+#[test]
+fn overload_explanation_only_contains_responsible_tasks() {
+    let mut solver = TestSolver::default();
+
+    let a = solver.new_variable(0, 20);
+    let b = solver.new_variable(2, 3);
+    let c = solver.new_variable(2, 3);
+
+    // Tasks b and c have to execute in [2, 6), which is too short for both of them
+    let propagator = solver
+        .new_propagator(DisjunctivePropagator::new(
+            [a, b, c].into(),
+            [1, 3, 3].into(),
+        ))
+        .expect_err("expected an overload");
+
+    assert_eq!(
+        propagator,
+        conjunction!([b >= 2] & [b <= 3] & [c >= 2] & [c <= 3]).into()
+    );
+}
+
+#[test]
+fn detectable_precedence_updates_earliest_start() {
+    let mut solver = TestSolver::default();
+
+    let a = solver.new_variable(0, 10);
+    let b = solver.new_variable(0, 1);
+
+    let _ = solver
+        .new_propagator(DisjunctivePropagator::new([a, b].into(), [4, 3].into()))
+        .expect("no conflict");
+
+    // Task a cannot finish before b has to start, so b precedes a
+    solver.assert_bounds(a, 3, 10);
+    solver.assert_bounds(b, 0, 1);
+
+    let reason = solver.get_reason_int(predicate![a >= 3].try_into().unwrap());
+    assert_eq!(conjunction!([a >= 0] & [b >= 0] & [b <= 1]), reason.clone());
+}
+
+#[test]
+fn pruning_agrees_with_decomposition() {
+    let mut solver = TestSolver::default();
+    let a = solver.new_variable(0, 10);
+    let b = solver.new_variable(0, 1);
+    let c = solver.new_variable(1, 3);
+    let _ = solver
+        .new_propagator(DisjunctivePropagator::new(
+            [a, b, c].into(),
+            [4, 3, 2].into(),
+        ))
+        .expect("no conflict");
+
+    let mut decomposed = Solver::default();
+    let tasks = [(0, 10), (0, 1), (1, 3)].map(|(lb, ub)| decomposed.new_bounded_integer(lb, ub));
+    let _ = decomposed
+        .add_constraint(constraints::disjunctive_decomposition(tasks, [4, 3, 2]))
+        .post(NonZero::new(1).unwrap());
+
+    // Task b precedes c, which forces c to start at 3; both precede a, which can thus start at 5
+    // at the earliest
+    solver.assert_bounds(c, 3, 3);
+    solver.assert_bounds(a, 5, 10);
+
+    // The propagator only updates the earliest start times
+    for (task, variable) in [a, b, c].into_iter().enumerate() {
+        assert_eq!(
+            decomposed.lower_bound(&tasks[task]),
+            solver.lower_bound(variable)
+        );
+    }
+}
+
+/// Creates three tasks on the same resource which should be scheduled within `[0, 8)`.
+fn create_tasks(solver: &mut Solver) -> [DomainId; 3] {
+    [0, 1, 2].map(|task| solver.new_bounded_integer(0, 7 - task))
+}
+
+const DURATIONS: [i32; 3] = [1, 2, 3];
+
+#[test]
+fn solutions_agree_with_decomposition() {
+    let mut solver = Solver::default();
+    let tasks = create_tasks(&mut solver);
+    let _ = solver
+        .add_constraint(constraints::disjunctive(tasks, DURATIONS))
+        .post(NonZero::new(1).unwrap());
+    let global_solutions = enumerate_solutions(&mut solver, &tasks);
+
+    let mut solver = Solver::default();
+    let tasks = create_tasks(&mut solver);
+    let _ = solver
+        .add_constraint(constraints::disjunctive_decomposition(tasks, DURATIONS))
+        .post(NonZero::new(1).unwrap());
+    let decomposed_solutions = enumerate_solutions(&mut solver, &tasks);
+
+    let expected_solutions = (0..8)
+        .flat_map(|a| (0..7).flat_map(move |b| (0..6).map(move |c| vec![a, b, c])))
+        .filter(|starts| {
+            (0..3).all(|i| {
+                (i + 1..3).all(|j| {
+                    starts[i] + DURATIONS[i] <= starts[j] || starts[j] + DURATIONS[j] <= starts[i]
+                })
+            })
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(global_solutions, expected_solutions);
+    assert_eq!(decomposed_solutions, expected_solutions);
+}
@@ -0,0 +1,172 @@
+#![cfg(test)]
+
+use std::num::NonZero;
+
+use crate::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
+use crate::branching::InDomainMin;
+use crate::branching::InputOrder;
+use crate::conjunction;
+use crate::constraints;
+use crate::engine::test_helper::TestSolver;
+use crate::predicate;
+use crate::propagators::arithmetic::integer_multiplication::IntegerMultiplicationPropagator;
+use crate::results::solution_iterator::IteratedSolution;
+use crate::results::ProblemSolution;
+use crate::termination::Indefinite;
+use crate::Solver;
+
+#[test]
+fn bounds_of_product_with_negative_and_positive_factor() {
+    let mut solver = TestSolver::default();
+
+    let a = solver.new_variable(-2, -1);
+    let b = solver.new_variable(3, 4);
+    let c = solver.new_variable(-20, 20);
+
+    let _ = solver
+        .new_propagator(IntegerMultiplicationPropagator::new(a, b, c))
+        .expect("no empty domain");
+
+    solver.assert_bounds(c, -8, -3);
+
+    let reason = solver.get_reason_int(predicate![c >= -8].try_into().unwrap());
+    assert_eq!(
+        conjunction!([a >= -2] & [a <= -1] & [b >= 3] & [b <= 4]),
+        reason.clone()
+    );
+}
+
+#[test]
+fn division_by_negative_factor_rounds_towards_the_feasible_values() {
+    let mut solver = TestSolver::default();
+
+    let a = solver.new_variable(-10, 10);
+    let b = solver.new_variable(-3, -2);
+    let c = solver.new_variable(4, 6);
+
+    let _ = solver
+        .new_propagator(IntegerMultiplicationPropagator::new(a, b, c))
+        .expect("no empty domain");
+
+    solver.assert_bounds(a, -3, -2);
+
+    let reason = solver.get_reason_int(predicate![a <= -2].try_into().unwrap());
+    assert_eq!(
+        conjunction!([c >= 4] & [c <= 6] & [b >= -3] & [b <= -2]),
+        reason.clone()
+    );
+}
+
+#[test]
+fn factor_containing_zero_does_not_tighten_the_other_factor() {
+    let mut solver = TestSolver::default();
+
+    let a = solver.new_variable(-5, 5);
+    let b = solver.new_variable(-1, 2);
+    let c = solver.new_variable(3, 3);
+
+    let _ = solver
+        .new_propagator(IntegerMultiplicationPropagator::new(a, b, c))
+        .expect("no empty domain");
+
+    solver.assert_bounds(a, -5, 5);
+    solver.assert_bounds(b, -1, 2);
+}
+
+#[test]
+fn fixed_factors_with_wrong_product_are_conflicting() {
+    let mut solver = TestSolver::default();
+
+    let a = solver.new_variable(-2, -2);
+    let b = solver.new_variable(3, 3);
+    let c = solver.new_variable(6, 6);
+
+    let result = solver.new_propagator(IntegerMultiplicationPropagator::new(a, b, c));
+    assert!(result.is_err());
+}
+
+/// All intervals `[lower_bound, upper_bound]` contained in `[-4, 4]`.
+fn all_intervals() -> impl Iterator<Item = (i32, i32)> + Clone {
+    (-4..=4).flat_map(|lower_bound| {
+        (lower_bound..=4).map(move |upper_bound| (lower_bound, upper_bound))
+    })
+}
+
+#[test]
+fn fixed_point_is_consistent_with_brute_force_filtering() {
+    for (a_min, a_max) in all_intervals() {
+        for (b_min, b_max) in all_intervals() {
+            for (c_min, c_max) in all_intervals() {
+                let supports = (a_min..=a_max)
+                    .flat_map(|a| (b_min..=b_max).map(move |b| (a, b, a * b)))
+                    .filter(|&(_, _, c)| c_min <= c && c <= c_max)
+                    .collect::<Vec<_>>();
+
+                let mut solver = TestSolver::default();
+                let a = solver.new_variable(a_min, a_max);
+                let b = solver.new_variable(b_min, b_max);
+                let c = solver.new_variable(c_min, c_max);
+
+                let domains = format!(
+                    "a in [{a_min}, {a_max}], b in [{b_min}, {b_max}], c in [{c_min}, {c_max}]"
+                );
+
+                let result = solver
+                    .new_propagator(IntegerMultiplicationPropagator::new(a, b, c))
+                    .map_err(|_| ())
+                    .and_then(|_| solver.propagate_until_fixed_point().map_err(|_| ()));
+
+                if result.is_err() {
+                    assert!(supports.is_empty(), "conflict while supported: {domains}");
+                    continue;
+                }
+
+                // The propagator should never remove a supported value
+                for (a_value, b_value, c_value) in supports.iter().copied() {
+                    assert!(
+                        solver.contains(a, a_value)
+                            && solver.contains(b, b_value)
+                            && solver.contains(c, c_value),
+                        "({a_value}, {b_value}, {c_value}) was removed: {domains}"
+                    );
+                }
+
+                // Once the factors are fixed, the propagator should enforce the product exactly
+                if solver.lower_bound(a) == solver.upper_bound(a)
+                    && solver.lower_bound(b) == solver.upper_bound(b)
+                {
+                    let product = solver.lower_bound(a) * solver.lower_bound(b);
+                    solver.assert_bounds(c, product, product);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn times_has_one_solution_per_pair_of_factors() {
+    let mut solver = Solver::default();
+    let a = solver.new_bounded_integer(-2, 2);
+    let b = solver.new_bounded_integer(-2, 2);
+    let c = solver.new_bounded_integer(-10, 10);
+
+    let _ = solver
+        .add_constraint(constraints::times(a, b, c))
+        .post(NonZero::new(1).unwrap());
+
+    let mut brancher =
+        IndependentVariableValueBrancher::new(InputOrder::new(vec![a, b, c]), InDomainMin);
+    let mut termination = Indefinite;
+    let mut solution_iterator = solver.get_solution_iterator(&mut brancher, &mut termination);
+
+    let mut num_solutions = 0;
+    while let IteratedSolution::Solution(solution) = solution_iterator.next_solution() {
+        assert_eq!(
+            solution.get_integer_value(a) * solution.get_integer_value(b),
+            solution.get_integer_value(c)
+        );
+        num_solutions += 1;
+    }
+
+    assert_eq!(num_solutions, 25);
+}
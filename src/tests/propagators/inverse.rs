@@ -0,0 +1,161 @@
+#![cfg(test)]
+
+use std::num::NonZero;
+
+use crate::conjunction;
+use crate::constraints;
+use crate::constraints::InvalidInverse;
+use crate::engine::test_helper::TestSolver;
+use crate::predicate;
+use crate::propagators::inverse::InversePropagator;
+use crate::tests::harness::enumerate_assignments;
+use crate::tests::harness::propagate_at_root;
+use crate::tests::harness::PostResult;
+use crate::variables::DomainId;
+use crate::ConstraintOperationError;
+use crate::Solver;
+
+/// Posts the inverse constraint over the first and second half of `variables`.
+fn post_inverse(solver: &mut TestSolver, variables: &[DomainId]) -> PostResult {
+    let (f, g) = variables.split_at(variables.len() / 2);
+    let _ = solver.new_propagator(InversePropagator::new(f.into(), g.into()))?;
+    Ok(())
+}
+
+#[test]
+fn assignment_of_f_fixes_g() {
+    let mut solver = TestSolver::default();
+    let f = [0, 1, 2].map(|_| solver.new_variable(0, 2));
+    let g = [0, 1, 2].map(|_| solver.new_variable(0, 2));
+
+    let propagator = solver
+        .new_propagator(InversePropagator::new(f.into(), g.into()))
+        .expect("no empty domains");
+
+    solver.increase_lower_bound(f[0], 2);
+    solver
+        .notify_and_propagate(propagator)
+        .expect("no empty domains");
+
+    solver.assert_bounds(g[2], 0, 0);
+
+    let reason = solver.get_reason_int(predicate![g[2] <= 0].try_into().unwrap());
+    assert_eq!(conjunction!([f[0] == 2]), reason.clone());
+}
+
+#[test]
+fn assignment_of_g_fixes_f() {
+    let mut solver = TestSolver::default();
+    let f = [0, 1, 2].map(|_| solver.new_variable(0, 2));
+    let g = [0, 1, 2].map(|_| solver.new_variable(0, 2));
+
+    let propagator = solver
+        .new_propagator(InversePropagator::new(f.into(), g.into()))
+        .expect("no empty domains");
+
+    solver.decrease_upper_bound(g[1], 0);
+    solver
+        .notify_and_propagate(propagator)
+        .expect("no empty domains");
+
+    solver.assert_bounds(f[0], 1, 1);
+
+    let reason = solver.get_reason_int(predicate![f[0] >= 1].try_into().unwrap());
+    assert_eq!(conjunction!([g[1] == 0]), reason.clone());
+}
+
+#[test]
+fn removal_from_f_is_channelled_to_g() {
+    let mut solver = TestSolver::default();
+    let f = [0, 1, 2].map(|_| solver.new_variable(0, 2));
+    let g = [0, 1, 2].map(|_| solver.new_variable(0, 2));
+
+    let propagator = solver
+        .new_propagator(InversePropagator::new(f.into(), g.into()))
+        .expect("no empty domains");
+
+    solver.remove(f[2], 1).expect("no empty domain");
+    solver
+        .notify_and_propagate(propagator)
+        .expect("no empty domains");
+
+    solver.assert_domain(g[1], vec![0, 1]);
+
+    let reason = solver.get_reason_int(predicate![g[1] != 2].try_into().unwrap());
+    assert_eq!(conjunction!([f[2] != 1]), reason.clone());
+}
+
+#[test]
+fn removal_from_g_is_channelled_to_f() {
+    let mut solver = TestSolver::default();
+    let f = [0, 1, 2].map(|_| solver.new_variable(0, 2));
+    let g = [0, 1, 2].map(|_| solver.new_variable(0, 2));
+
+    let propagator = solver
+        .new_propagator(InversePropagator::new(f.into(), g.into()))
+        .expect("no empty domains");
+
+    solver.remove(g[0], 1).expect("no empty domain");
+    solver
+        .notify_and_propagate(propagator)
+        .expect("no empty domains");
+
+    solver.assert_domain(f[1], vec![1, 2]);
+
+    let reason = solver.get_reason_int(predicate![f[1] != 0].try_into().unwrap());
+    assert_eq!(conjunction!([g[0] != 1]), reason.clone());
+}
+
+#[test]
+fn solutions_are_pairs_of_inverse_permutations() {
+    let domains = vec![vec![0, 1, 2]; 6];
+
+    let solutions = enumerate_assignments(&domains, &|domains| {
+        propagate_at_root(&post_inverse, domains)
+    });
+
+    assert_eq!(solutions.len(), 6);
+    for solution in solutions {
+        let (f, g) = solution.split_at(3);
+        for (i, &j) in f.iter().enumerate() {
+            assert_eq!(g[j as usize], i as i32);
+        }
+    }
+}
+
+#[test]
+fn invalid_arrays_are_rejected() {
+    let post = |f_length: usize, g_length: usize, upper_bound: i32| {
+        let mut solver = Solver::default();
+        let f = (0..f_length)
+            .map(|_| solver.new_bounded_integer(0, upper_bound))
+            .collect::<Vec<_>>();
+        let g = (0..g_length)
+            .map(|_| solver.new_bounded_integer(0, upper_bound))
+            .collect::<Vec<_>>();
+        solver
+            .add_constraint(constraints::inverse(f, g))
+            .post(NonZero::new(1).unwrap())
+    };
+
+    assert!(matches!(
+        post(3, 2, 1),
+        Err(ConstraintOperationError::InvalidInverse(
+            InvalidInverse::MismatchedLengths {
+                f_length: 3,
+                g_length: 2
+            }
+        ))
+    ));
+    assert!(matches!(
+        post(3, 3, 3),
+        Err(ConstraintOperationError::InvalidInverse(
+            InvalidInverse::ValueOutOfRange {
+                upper_bound: 3,
+                n: 3,
+                ..
+            }
+        ))
+    ));
+    assert!(post(3, 3, 2).is_ok());
+}
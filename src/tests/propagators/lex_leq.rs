@@ -0,0 +1,215 @@
+#![cfg(test)]
+
+use std::num::NonZero;
+
+use crate::conjunction;
+use crate::constraints;
+use crate::engine::test_helper::TestSolver;
+use crate::predicate;
+use crate::propagators::lex_leq::LexLeqPropagator;
+use crate::tests::harness::enumerate_solutions;
+use crate::variables::DomainId;
+use crate::Solver;
+
+#[test]
+fn first_position_is_bounded_by_other_sequence() {
+    let mut solver = TestSolver::default();
+
+    let x0 = solver.new_variable(1, 3);
+    let x1 = solver.new_variable(0, 3);
+    let y0 = solver.new_variable(0, 2);
+    let y1 = solver.new_variable(0, 3);
+
+    let _ = solver
+        .new_propagator(LexLeqPropagator::new([x0, x1].into(), [y0, y1].into()))
+        .expect("no empty domains");
+
+    solver.assert_bounds(x0, 1, 2);
+    solver.assert_bounds(y0, 1, 2);
+    solver.assert_bounds(x1, 0, 3);
+    solver.assert_bounds(y1, 0, 3);
+}
+
+#[test]
+fn equal_prefix_is_skipped_and_included_in_explanation() {
+    let mut solver = TestSolver::default();
+
+    let x0 = solver.new_variable(2, 2);
+    let x1 = solver.new_variable(1, 3);
+    let y0 = solver.new_variable(2, 2);
+    let y1 = solver.new_variable(0, 2);
+
+    let _ = solver
+        .new_propagator(LexLeqPropagator::new([x0, x1].into(), [y0, y1].into()))
+        .expect("no empty domains");
+
+    solver.assert_bounds(x1, 1, 2);
+
+    let reason = solver.get_reason_int(predicate![x1 <= 2].try_into().unwrap());
+    assert_eq!(
+        conjunction!([x0 == 2] & [y0 == 2] & [y1 <= 2]),
+        reason.clone()
+    );
+}
+
+#[test]
+fn forced_larger_suffix_makes_first_position_strict() {
+    let mut solver = TestSolver::default();
+
+    let x0 = solver.new_variable(0, 3);
+    let x1 = solver.new_variable(2, 3);
+    let y0 = solver.new_variable(0, 3);
+    let y1 = solver.new_variable(0, 1);
+
+    let _ = solver
+        .new_propagator(LexLeqPropagator::new([x0, x1].into(), [y0, y1].into()))
+        .expect("no empty domains");
+
+    solver.assert_bounds(x0, 0, 2);
+    solver.assert_bounds(y0, 1, 3);
+
+    let reason = solver.get_reason_int(predicate![x0 <= 2].try_into().unwrap());
+    assert_eq!(
+        conjunction!([x1 >= 2] & [y1 <= 1] & [y0 <= 3]),
+        reason.clone()
+    );
+}
+
+#[test]
+fn suffix_which_becomes_forced_larger_makes_first_position_strict() {
+    let mut solver = TestSolver::default();
+
+    let x0 = solver.new_variable(0, 3);
+    let x1 = solver.new_variable(1, 3);
+    let x2 = solver.new_variable(2, 3);
+    let y0 = solver.new_variable(0, 3);
+    let y1 = solver.new_variable(0, 3);
+    let y2 = solver.new_variable(0, 1);
+
+    let propagator = solver
+        .new_propagator(LexLeqPropagator::new(
+            [x0, x1, x2].into(),
+            [y0, y1, y2].into(),
+        ))
+        .expect("no empty domains");
+    solver.assert_bounds(x0, 0, 3);
+
+    // The second position can now at best be equal, so the third position forces the suffix
+    // of `xs` to be larger
+    solver.decrease_upper_bound(y1, 1);
+    solver
+        .notify_and_propagate(propagator)
+        .expect("no empty domains");
+
+    solver.assert_bounds(x0, 0, 2);
+    let reason = solver.get_reason_int(predicate![x0 <= 2].try_into().unwrap());
+    assert_eq!(
+        conjunction!([x1 >= 1] & [x2 >= 2] & [y1 <= 1] & [y2 <= 1] & [y0 <= 3]),
+        reason.clone()
+    );
+}
+
+#[test]
+fn checked_position_which_becomes_forced_larger_is_the_new_beta() {
+    let mut solver = TestSolver::default();
+
+    let x0 = solver.new_variable(0, 3);
+    let x1 = solver.new_variable(1, 3);
+    let x2 = solver.new_variable(0, 3);
+    let y0 = solver.new_variable(0, 3);
+    let y1 = solver.new_variable(0, 1);
+    let y2 = solver.new_variable(0, 3);
+
+    let propagator = solver
+        .new_propagator(LexLeqPropagator::new(
+            [x0, x1, x2].into(),
+            [y0, y1, y2].into(),
+        ))
+        .expect("no empty domains");
+    solver.assert_bounds(x0, 0, 3);
+
+    // The second position was already checked to be at best equal, now it is forced to be larger
+    solver.decrease_upper_bound(y1, 0);
+    solver
+        .notify_and_propagate(propagator)
+        .expect("no empty domains");
+
+    solver.assert_bounds(x0, 0, 2);
+    let reason = solver.get_reason_int(predicate![x0 <= 2].try_into().unwrap());
+    assert_eq!(
+        conjunction!([x1 >= 1] & [y1 <= 0] & [y0 <= 3]),
+        reason.clone()
+    );
+}
+
+#[test]
+fn larger_first_position_is_conflicting() {
+    let mut solver = TestSolver::default();
+
+    let x0 = solver.new_variable(2, 3);
+    let y0 = solver.new_variable(0, 1);
+
+    let result = solver.new_propagator(LexLeqPropagator::new([x0].into(), [y0].into()));
+    assert!(result.is_err());
+}
+
+/// Creates two interchangeable rows of two variables each which differ in the first position.
+fn create_symmetric_model(solver: &mut Solver) -> [[DomainId; 2]; 2] {
+    let rows = [0, 1].map(|_| [0, 1].map(|_| solver.new_bounded_integer(0, 2)));
+    let _ = solver
+        .add_constraint(constraints::binary_not_equals(rows[0][0], rows[1][0]))
+        .post(NonZero::new(1).unwrap());
+    rows
+}
+
+#[test]
+fn lex_leq_removes_row_symmetry() {
+    let mut solver = Solver::default();
+    let rows = create_symmetric_model(&mut solver);
+    let all_solutions = enumerate_solutions(&mut solver, rows.as_flattened());
+
+    let mut solver = Solver::default();
+    let rows = create_symmetric_model(&mut solver);
+    let _ = solver
+        .add_constraint(constraints::lex_leq(rows[0].to_vec(), rows[1].to_vec()))
+        .post(NonZero::new(1).unwrap());
+    let symmetry_broken_solutions = enumerate_solutions(&mut solver, rows.as_flattened());
+
+    assert_eq!(all_solutions.len(), 54);
+    assert_eq!(symmetry_broken_solutions.len() * 2, all_solutions.len());
+
+    for solution in all_solutions {
+        let (first, second) = solution.split_at(2);
+        let swapped = [second, first].concat();
+        assert!(
+            symmetry_broken_solutions.contains(&solution)
+                || symmetry_broken_solutions.contains(&swapped)
+        );
+    }
+}
+
+#[test]
+fn solutions_agree_with_brute_force() {
+    let mut solver = Solver::default();
+    let xs = [0, 1, 2].map(|_| solver.new_bounded_integer(0, 1));
+    let ys = [0, 1, 2].map(|_| solver.new_bounded_integer(0, 1));
+    let _ = solver
+        .add_constraint(constraints::lex_leq(xs.to_vec(), ys.to_vec()))
+        .post(NonZero::new(1).unwrap());
+
+    // Branching over `ys` first relaxes the bounds of `xs` in many different orders while
+    // backtracking, which exercises the restoration of the pointers
+    let variables = ys.into_iter().chain(xs).collect::<Vec<_>>();
+    let solutions = enumerate_solutions(&mut solver, &variables);
+
+    let expected_solutions = (0..64)
+        .map(|code| {
+            (0..6)
+                .rev()
+                .map(|digit| code >> digit & 1)
+                .collect::<Vec<_>>()
+        })
+        .filter(|solution| solution[3..] <= solution[..3])
+        .collect::<Vec<_>>();
+    assert_eq!(solutions, expected_solutions);
+}
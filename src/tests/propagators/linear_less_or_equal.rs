@@ -0,0 +1,159 @@
+#![cfg(test)]
+
+use crate::conjunction;
+use crate::engine::test_helper::TestSolver;
+use crate::predicate;
+use crate::predicates::IntegerPredicate;
+use crate::propagators::arithmetic::linear_less_or_equal::LinearLessOrEqualPropagator;
+use crate::variables::TransformableVariable;
+
+#[test]
+fn literal_variables_are_propagated_with_literal_explanations() {
+    let mut solver = TestSolver::default();
+
+    let a = solver.new_literal();
+    let b = solver.new_literal();
+    let c = solver.new_literal();
+
+    let x = solver.new_literal_variable(a);
+    let y = solver.new_literal_variable(b);
+    let z = solver.new_literal_variable(c);
+
+    let propagator = solver
+        .new_propagator(LinearLessOrEqualPropagator::new([x, y, z].into(), 2))
+        .expect("no empty domain");
+
+    solver.increase_lower_bound(x, 1);
+    solver.increase_lower_bound(y, 1);
+    solver.propagate(propagator).expect("no empty domain");
+
+    solver.assert_bounds(z, 0, 0);
+
+    let reason = solver.get_reason_int(predicate![z <= 0].try_into().unwrap());
+    assert_eq!(conjunction!([x >= 1] & [y >= 1]), reason.clone());
+
+    let get_literal = |solver: &TestSolver, predicate: IntegerPredicate| {
+        solver.variable_literal_mappings.get_literal(
+            predicate,
+            &solver.assignments_propositional,
+            &solver.assignments_integer,
+        )
+    };
+    assert_eq!(
+        get_literal(&solver, predicate![x >= 1].try_into().unwrap()),
+        a
+    );
+    assert_eq!(
+        get_literal(&solver, predicate![y >= 1].try_into().unwrap()),
+        b
+    );
+    assert_eq!(
+        get_literal(&solver, predicate![z <= 0].try_into().unwrap()),
+        !c
+    );
+}
+
+#[test]
+fn explanations_are_only_computed_when_requested() {
+    let mut solver = TestSolver::default();
+
+    let x = solver.new_variable(0, 10);
+    let y = solver.new_variable(0, 10);
+    let z = solver.new_variable(0, 10);
+
+    let propagator = solver
+        .new_propagator(LinearLessOrEqualPropagator::new([x, y, z].into(), 10))
+        .expect("no empty domain");
+
+    solver.increase_lower_bound(x, 3);
+    solver.increase_lower_bound(y, 4);
+    solver.propagate(propagator).expect("no empty domain");
+
+    solver.assert_bounds(z, 0, 3);
+    assert_eq!(0, solver.reason_store.num_lazy_reasons_computed());
+
+    // The explanation uses the bounds at the time of propagation
+    solver.increase_lower_bound(x, 5);
+
+    let reason = solver.get_reason_int(predicate![z <= 3].try_into().unwrap());
+    assert_eq!(conjunction!([x >= 3] & [y >= 4]), reason.clone());
+    assert_eq!(1, solver.reason_store.num_lazy_reasons_computed());
+}
+
+/// Computes, using exact 64-bit arithmetic, the smallest and largest supported value of every
+/// variable for the constraint `\sum scales_i * x_i <= rhs` with `x_i` in `domains_i`.
+fn supported_bounds(scales: &[i64], domains: &[(i32, i32)], rhs: i64) -> Vec<(i32, i32)> {
+    let mut supported = vec![(i32::MAX, i32::MIN); domains.len()];
+    let mut assignment = domains.iter().map(|&(lb, _)| lb).collect::<Vec<_>>();
+
+    loop {
+        let lhs: i64 = scales
+            .iter()
+            .zip(&assignment)
+            .map(|(&scale, &value)| scale * value as i64)
+            .sum();
+
+        if lhs <= rhs {
+            for (bounds, &value) in supported.iter_mut().zip(&assignment) {
+                bounds.0 = bounds.0.min(value);
+                bounds.1 = bounds.1.max(value);
+            }
+        }
+
+        let Some(index) = (0..domains.len()).find(|&i| assignment[i] < domains[i].1) else {
+            return supported;
+        };
+        assignment[index] += 1;
+        for i in 0..index {
+            assignment[i] = domains[i].0;
+        }
+    }
+}
+
+#[test]
+fn large_terms_do_not_overflow_the_sum_of_bounds() {
+    let mut solver = TestSolver::default();
+
+    let scales = [3, 3, 3, -3];
+    let domains = [(333_333_330, 333_333_340); 4];
+    let rhs = 1_999_999_959;
+
+    let variables = domains
+        .iter()
+        .map(|&(lb, ub)| solver.new_variable(lb, ub))
+        .collect::<Vec<_>>();
+    let terms = variables
+        .iter()
+        .zip(scales)
+        .map(|(&variable, scale)| variable.scaled(scale))
+        .collect::<Vec<_>>();
+
+    // The sum of the lower bounds of the positive terms (2_999_999_970) exceeds i32::MAX
+    let _ = solver
+        .new_propagator(LinearLessOrEqualPropagator::new(terms.into(), rhs))
+        .expect("no empty domain");
+
+    let expected = supported_bounds(&scales.map(i64::from), &domains, rhs as i64);
+    assert_eq!(expected[0], (333_333_330, 333_333_333));
+    assert_eq!(expected[3], (333_333_337, 333_333_340));
+
+    for (&variable, (lb, ub)) in variables.iter().zip(expected) {
+        solver.assert_bounds(variable, lb, ub);
+    }
+}
+
+#[test]
+fn large_terms_which_violate_the_constraint_are_detected() {
+    let mut solver = TestSolver::default();
+
+    let x = solver.new_variable(333_333_330, 333_333_340);
+    let y = solver.new_variable(-333_333_340, -333_333_330);
+
+    // The smallest value of every term is 999_999_990, so the smallest value of the left-hand
+    // side exceeds i32::MAX; when wrapping around, it would appear to satisfy the constraint
+    let result = solver.new_propagator(LinearLessOrEqualPropagator::new(
+        [x.scaled(3), y.scaled(-3), x.scaled(3)].into(),
+        0,
+    ));
+    assert!(result.is_err());
+}
@@ -0,0 +1,49 @@
+#![cfg(test)]
+
+use crate::engine::test_helper::TestSolver;
+use crate::propagators::arithmetic::linear_not_equal::LinearNotEqualPropagator;
+use crate::variables::TransformableVariable;
+
+#[test]
+fn large_fixed_terms_do_not_overflow_the_removed_value() {
+    let mut solver = TestSolver::default();
+
+    let x = solver.new_variable(333_333_330, 333_333_330);
+    let y = solver.new_variable(333_333_330, 333_333_330);
+    let z = solver.new_variable(333_333_330, 333_333_330);
+    let w = solver.new_variable(333_333_330, 333_333_340);
+
+    // The sum of the fixed terms (2_999_999_970) exceeds i32::MAX
+    let rhs = 1_999_999_950;
+    let _ = solver
+        .new_propagator(LinearNotEqualPropagator::new(
+            [x.scaled(3), y.scaled(3), z.scaled(3), w.scaled(-3)].into(),
+            rhs,
+        ))
+        .expect("no empty domain");
+
+    let removed = (3 * 3 * 333_333_330_i64 - rhs as i64) / 3;
+    assert_eq!(removed, 333_333_340);
+    solver.assert_bounds(w, 333_333_330, 333_333_339);
+}
+
+#[test]
+fn values_outside_of_i32_range_are_not_removed() {
+    let mut solver = TestSolver::default();
+
+    let x = solver.new_variable(999_999_990, 999_999_990);
+    let y = solver.new_variable(999_999_990, 999_999_990);
+    let z = solver.new_variable(-10, 10);
+
+    // The value which z cannot take is i32::MAX + 1_999_999_980, which exceeds the i32 range
+    let _ = solver
+        .new_propagator(LinearNotEqualPropagator::new(
+            [x.scaled(-1), y.scaled(-1), z.scaled(1)].into(),
+            i32::MAX,
+        ))
+        .expect("no empty domain");
+
+    for value in -10..=10 {
+        assert!(solver.contains(z, value));
+    }
+}
@@ -1,5 +1,15 @@
 pub(crate) mod all_different;
+pub(crate) mod binary_less_or_equal;
 pub(crate) mod circuit;
+pub(crate) mod count;
 pub(crate) mod cumulative;
+pub(crate) mod disjunctive;
 pub(crate) mod element;
+pub(crate) mod integer_multiplication;
+pub(crate) mod inverse;
+pub(crate) mod lex_leq;
+pub(crate) mod linear_less_or_equal;
+pub(crate) mod linear_not_equal;
 pub(crate) mod maximum;
+pub(crate) mod regular;
+pub(crate) mod value_precede;
@@ -0,0 +1,236 @@
+#![cfg(test)]
+
+use std::num::NonZero;
+
+use crate::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
+use crate::branching::InDomainMin;
+use crate::branching::InputOrder;
+use crate::conjunction;
+use crate::constraints;
+use crate::constraints::InvalidAutomaton;
+use crate::engine::test_helper::TestSolver;
+use crate::predicate;
+use crate::propagators::regular::RegularPropagator;
+use crate::results::solution_iterator::IteratedSolution;
+use crate::results::ProblemSolution;
+use crate::termination::Indefinite;
+use crate::tests::harness::enumerate_assignments;
+use crate::tests::harness::propagate_at_root;
+use crate::tests::harness::PostResult;
+use crate::variables::DomainId;
+use crate::ConstraintOperationError;
+use crate::Solver;
+
+/// The automaton over the alphabet `{0, 1}` which accepts the words without three consecutive
+/// 1s; state `q` indicates that the word read so far ends with `q` consecutive 1s.
+const NO_THREE_ONES_TRANSITIONS: [(usize, i32, usize); 5] =
+    [(0, 0, 0), (0, 1, 1), (1, 0, 0), (1, 1, 2), (2, 0, 0)];
+
+fn no_three_ones(xs: &[DomainId]) -> RegularPropagator<DomainId> {
+    RegularPropagator::new(xs.into(), 3, 2, &NO_THREE_ONES_TRANSITIONS, 0, &[0, 1, 2])
+}
+
+fn post_no_three_ones(solver: &mut TestSolver, xs: &[DomainId]) -> PostResult {
+    let _ = solver.new_propagator(no_three_ones(xs))?;
+    Ok(())
+}
+
+fn brute_force_no_three_ones(num_variables: usize) -> Vec<Vec<i32>> {
+    (0..1 << num_variables)
+        .map(|word: u32| {
+            (0..num_variables)
+                .rev()
+                .map(|position| ((word >> position) & 1) as i32)
+                .collect::<Vec<_>>()
+        })
+        .filter(|word| !word.windows(3).any(|window| window == [1, 1, 1]))
+        .collect()
+}
+
+#[test]
+fn value_completing_forbidden_pattern_is_removed_after_fixing_prefix() {
+    let mut solver = TestSolver::default();
+    let xs = (0..6)
+        .map(|_| solver.new_variable(0, 1))
+        .collect::<Vec<_>>();
+
+    let propagator = solver
+        .new_propagator(no_three_ones(&xs))
+        .expect("no empty domains");
+    for &x in &xs {
+        solver.assert_bounds(x, 0, 1);
+    }
+
+    solver.increase_lower_bound(xs[0], 1);
+    solver.increase_lower_bound(xs[1], 1);
+    solver
+        .notify_and_propagate(propagator)
+        .expect("no empty domains");
+
+    solver.assert_bounds(xs[2], 0, 0);
+    for &x in &xs[3..] {
+        solver.assert_bounds(x, 0, 1);
+    }
+
+    let reason = solver.get_reason_int(predicate![xs[2] != 1].try_into().unwrap());
+    assert_eq!(conjunction!([xs[0] != 0] & [xs[1] != 0]), reason.clone());
+}
+
+#[test]
+fn value_is_removed_before_fixed_suffix() {
+    let mut solver = TestSolver::default();
+    let xs = (0..6)
+        .map(|_| solver.new_variable(0, 1))
+        .collect::<Vec<_>>();
+
+    let propagator = solver
+        .new_propagator(no_three_ones(&xs))
+        .expect("no empty domains");
+
+    solver.increase_lower_bound(xs[4], 1);
+    solver.increase_lower_bound(xs[5], 1);
+    solver
+        .notify_and_propagate(propagator)
+        .expect("no empty domains");
+
+    solver.assert_bounds(xs[3], 0, 0);
+    for &x in &xs[..3] {
+        solver.assert_bounds(x, 0, 1);
+    }
+}
+
+#[test]
+fn values_outside_of_alphabet_are_removed() {
+    let mut solver = TestSolver::default();
+    let xs = (0..3)
+        .map(|_| solver.new_variable(-1, 3))
+        .collect::<Vec<_>>();
+
+    let _ = solver
+        .new_propagator(no_three_ones(&xs))
+        .expect("no empty domains");
+
+    for &x in &xs {
+        solver.assert_bounds(x, 0, 1);
+    }
+}
+
+#[test]
+fn word_without_accepting_path_is_conflicting() {
+    let mut solver = TestSolver::default();
+    let xs = (0..4)
+        .map(|_| solver.new_variable(0, 1))
+        .collect::<Vec<_>>();
+
+    let propagator = solver
+        .new_propagator(no_three_ones(&xs))
+        .expect("no empty domains");
+
+    for &x in &xs[..3] {
+        solver.increase_lower_bound(x, 1);
+    }
+    let result = solver.notify_and_propagate(propagator);
+    assert!(result.is_err());
+}
+
+#[test]
+fn solutions_match_brute_force() {
+    let domains = vec![vec![0, 1]; 6];
+
+    let solutions = enumerate_assignments(&domains, &|domains| {
+        propagate_at_root(&post_no_three_ones, domains)
+    });
+
+    assert_eq!(solutions, brute_force_no_three_ones(6));
+}
+
+#[test]
+fn number_of_solutions_found_by_search_matches_brute_force() {
+    let mut solver = Solver::default();
+    let xs = (0..6)
+        .map(|_| solver.new_bounded_integer(0, 1))
+        .collect::<Vec<_>>();
+    solver
+        .add_constraint(constraints::regular(
+            xs.clone(),
+            3,
+            2,
+            NO_THREE_ONES_TRANSITIONS.to_vec(),
+            0,
+            vec![0, 1, 2],
+        ))
+        .post(NonZero::new(1).unwrap())
+        .expect("the automaton is valid");
+
+    let mut brancher =
+        IndependentVariableValueBrancher::new(InputOrder::new(xs.clone()), InDomainMin);
+    let mut termination = Indefinite;
+    let mut solution_iterator = solver.get_solution_iterator(&mut brancher, &mut termination);
+
+    let mut solutions = vec![];
+    while let IteratedSolution::Solution(solution) = solution_iterator.next_solution() {
+        solutions.push(
+            xs.iter()
+                .map(|&x| solution.get_integer_value(x))
+                .collect::<Vec<_>>(),
+        );
+    }
+    solutions.sort();
+
+    assert_eq!(solutions.len(), 44);
+    assert_eq!(solutions, brute_force_no_three_ones(6));
+}
+
+#[test]
+fn invalid_automata_are_rejected() {
+    let post = |transitions: Vec<(usize, i32, usize)>, initial_state, accepting_states| {
+        let mut solver = Solver::default();
+        let xs = (0..3)
+            .map(|_| solver.new_bounded_integer(0, 1))
+            .collect::<Vec<_>>();
+        solver
+            .add_constraint(constraints::regular(
+                xs,
+                3,
+                2,
+                transitions,
+                initial_state,
+                accepting_states,
+            ))
+            .post(NonZero::new(1).unwrap())
+    };
+
+    assert!(matches!(
+        post(vec![(0, 0, 3)], 0, vec![0]),
+        Err(ConstraintOperationError::InvalidAutomaton(
+            InvalidAutomaton::StateOutOfRange {
+                state: 3,
+                num_states: 3
+            }
+        ))
+    ));
+    assert!(matches!(
+        post(vec![(0, 0, 0)], 5, vec![0]),
+        Err(ConstraintOperationError::InvalidAutomaton(
+            InvalidAutomaton::StateOutOfRange { state: 5, .. }
+        ))
+    ));
+    assert!(matches!(
+        post(vec![(0, 2, 0)], 0, vec![0]),
+        Err(ConstraintOperationError::InvalidAutomaton(
+            InvalidAutomaton::SymbolOutOfRange {
+                symbol: 2,
+                alphabet_size: 2
+            }
+        ))
+    ));
+    assert!(matches!(
+        post(vec![(0, 1, 0), (0, 1, 1)], 0, vec![0]),
+        Err(ConstraintOperationError::InvalidAutomaton(
+            InvalidAutomaton::NonDeterministic {
+                state: 0,
+                symbol: 1
+            }
+        ))
+    ));
+}
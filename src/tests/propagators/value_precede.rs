@@ -0,0 +1,85 @@
+#![cfg(test)]
+
+use std::num::NonZero;
+
+use crate::conjunction;
+use crate::constraints;
+use crate::engine::test_helper::TestSolver;
+use crate::predicate;
+use crate::propagators::value_precede::ValuePrecedePropagator;
+use crate::tests::harness::enumerate_solutions;
+use crate::Solver;
+
+#[test]
+fn t_is_removed_up_to_first_support_of_s() {
+    let mut solver = TestSolver::default();
+
+    let x0 = solver.new_variable(1, 2);
+    let x1 = solver.new_variable(0, 2);
+    let x2 = solver.new_variable(0, 2);
+
+    let _ = solver
+        .new_propagator(ValuePrecedePropagator::new(0, 1, [x0, x1, x2].into()))
+        .expect("no empty domains");
+
+    solver.assert_domain(x0, vec![2]);
+    solver.assert_domain(x1, vec![0, 2]);
+    solver.assert_domain(x2, vec![0, 1, 2]);
+
+    let reason = solver.get_reason_int(predicate![x1 != 1].try_into().unwrap());
+    assert_eq!(conjunction!([x0 != 0]), reason.clone());
+}
+
+#[test]
+fn last_support_before_t_is_fixed_to_s() {
+    let mut solver = TestSolver::default();
+
+    let x0 = solver.new_variable(0, 2);
+    let x1 = solver.new_variable(1, 2);
+    let x2 = solver.new_variable(1, 1);
+
+    let _ = solver
+        .new_propagator(ValuePrecedePropagator::new(0, 1, [x0, x1, x2].into()))
+        .expect("no empty domains");
+
+    solver.assert_bounds(x0, 0, 0);
+
+    let reason = solver.get_reason_int(predicate![x0 <= 0].try_into().unwrap());
+    assert_eq!(conjunction!([x1 != 0] & [x2 == 1]), reason.clone());
+}
+
+#[test]
+fn t_without_preceding_s_is_conflicting() {
+    let mut solver = TestSolver::default();
+
+    let x0 = solver.new_variable(1, 2);
+    let x1 = solver.new_variable(1, 1);
+
+    let result = solver.new_propagator(ValuePrecedePropagator::new(0, 1, [x0, x1].into()));
+    assert!(result.is_err());
+}
+
+#[test]
+fn value_precede_removes_value_symmetry() {
+    let mut solver = Solver::default();
+    let xs = [0, 1, 2].map(|_| solver.new_bounded_integer(0, 1));
+    let all_solutions = enumerate_solutions(&mut solver, &xs);
+
+    let mut solver = Solver::default();
+    let xs = [0, 1, 2].map(|_| solver.new_bounded_integer(0, 1));
+    let _ = solver
+        .add_constraint(constraints::value_precede(0, 1, xs.to_vec()))
+        .post(NonZero::new(1).unwrap());
+    let symmetry_broken_solutions = enumerate_solutions(&mut solver, &xs);
+
+    assert_eq!(all_solutions.len(), 8);
+    assert_eq!(symmetry_broken_solutions.len() * 2, all_solutions.len());
+
+    for solution in all_solutions {
+        let swapped = solution.iter().map(|value| 1 - value).collect::<Vec<_>>();
+        assert!(
+            symmetry_broken_solutions.contains(&solution)
+                || symmetry_broken_solutions.contains(&swapped)
+        );
+    }
+}
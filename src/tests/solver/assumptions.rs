@@ -0,0 +1,370 @@
+#![cfg(test)]
+
+use std::num::NonZero;
+
+use crate::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
+use crate::branching::Brancher;
+use crate::branching::InDomainMin;
+use crate::branching::InputOrder;
+use crate::constraints;
+use crate::predicate;
+use crate::predicates::Predicate;
+use crate::results::unsatisfiable::CoreExtractionOptions;
+use crate::results::ProblemSolution;
+use crate::results::PropagationProbeResult;
+use crate::results::SatisfactionResultUnderAssumptions;
+use crate::termination::Indefinite;
+use crate::tests::solver::search::propositional_brancher;
+use crate::variables::Literal;
+use crate::Solver;
+
+pub(crate) fn extract_core(
+    solver: &mut Solver,
+    brancher: &mut impl Brancher,
+    assumptions: &[Literal],
+    options: CoreExtractionOptions,
+) -> Box<[Literal]> {
+    match solver.satisfy_under_assumptions(brancher, &mut Indefinite, assumptions) {
+        SatisfactionResultUnderAssumptions::UnsatisfiableUnderAssumptions(mut unsatisfiable) => {
+            unsatisfiable.extract_core_options(options)
+        }
+        _ => panic!("expected the problem to be unsatisfiable under the assumptions"),
+    }
+}
+
+#[test]
+fn duplicated_assumptions_result_in_deduplicated_core() {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 5);
+
+    let equals_one = solver.get_literal(predicate!(x == 1));
+    let at_most_zero = solver.get_literal(predicate!(x <= 0));
+    let assumptions = [equals_one, equals_one, at_most_zero, equals_one];
+
+    let mut brancher = propositional_brancher(&[equals_one, at_most_zero]);
+    let core = extract_core(
+        &mut solver,
+        &mut brancher,
+        &assumptions,
+        CoreExtractionOptions::default(),
+    );
+
+    assert_eq!(core.len(), 2);
+    assert!(core.contains(&!equals_one));
+    assert!(core.contains(&!at_most_zero));
+}
+
+#[test]
+fn assumption_false_at_root_is_a_core_by_itself() {
+    let mut solver = Solver::default();
+    let a = solver.new_literal();
+    let b = solver.new_literal();
+    let c = solver.new_literal();
+    let _ = solver.add_clause([!c]);
+
+    let mut brancher = propositional_brancher(&[a, b, c]);
+    let core = extract_core(
+        &mut solver,
+        &mut brancher,
+        &[a, b, c],
+        CoreExtractionOptions::default(),
+    );
+
+    assert_eq!(core.as_ref(), &[!c]);
+}
+
+#[test]
+fn false_assumption_is_a_core_by_itself() {
+    let mut solver = Solver::default();
+    let a = solver.new_literal();
+    let b = solver.new_literal();
+    let false_literal = solver.get_literal(Predicate::False);
+
+    let mut brancher = propositional_brancher(&[a, b]);
+    let core = extract_core(
+        &mut solver,
+        &mut brancher,
+        &[a, false_literal, b],
+        CoreExtractionOptions::default(),
+    );
+
+    assert_eq!(core.as_ref(), &[!false_literal]);
+    assert_eq!(core.as_ref(), &[solver.get_literal(Predicate::True)]);
+}
+
+#[test]
+fn true_assumption_is_skipped() {
+    let mut solver = Solver::default();
+    let a = solver.new_literal();
+    let b = solver.new_literal();
+    let _ = solver.add_clause([!a, !b]);
+    let true_literal = solver.get_literal(Predicate::True);
+
+    let mut brancher = propositional_brancher(&[a, b]);
+    {
+        let result =
+            solver.satisfy_under_assumptions(&mut brancher, &mut Indefinite, &[true_literal, a]);
+        let SatisfactionResultUnderAssumptions::Satisfiable(solution) = result else {
+            panic!("expected the assumptions to be satisfiable");
+        };
+        assert!(solution.get_literal_value(a));
+        assert!(!solution.get_literal_value(b));
+    }
+
+    let core = extract_core(
+        &mut solver,
+        &mut brancher,
+        &[true_literal, a, true_literal, b],
+        CoreExtractionOptions::default(),
+    );
+    assert_eq!(core.len(), 2);
+    assert!(core.contains(&!a));
+    assert!(core.contains(&!b));
+}
+
+#[test]
+fn literal_assumptions_keep_their_polarity() {
+    let mut solver = Solver::default();
+    let a = solver.new_literal();
+
+    assert_eq!(solver.get_literal(Predicate::Literal(a)), a);
+    assert_eq!(solver.get_literal(Predicate::Literal(!a)), !a);
+
+    let mut brancher = propositional_brancher(&[a]);
+    for assumption in [a, !a] {
+        let assumption_literal = solver.get_literal(Predicate::Literal(assumption));
+        let result =
+            solver.satisfy_under_assumptions(&mut brancher, &mut Indefinite, &[assumption_literal]);
+        let SatisfactionResultUnderAssumptions::Satisfiable(solution) = result else {
+            panic!("expected the assumption to be satisfiable");
+        };
+        assert_eq!(solution.get_literal_value(a), assumption == a);
+    }
+}
+
+#[test]
+fn core_of_mixed_assumptions_has_negated_polarities() {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 5);
+    let reification_literal = solver.new_literal();
+    let at_least_three = solver.get_literal(predicate!(x >= 3));
+    // If the reification literal is false, then `x >= 3`
+    let _ = solver.add_clause([reification_literal, at_least_three]);
+
+    let assumptions = [
+        solver.get_literal(Predicate::Literal(!reification_literal)),
+        solver.get_literal(predicate!(x <= 1)),
+    ];
+    let mut brancher = propositional_brancher(&[reification_literal, at_least_three]);
+    let core = extract_core(
+        &mut solver,
+        &mut brancher,
+        &assumptions,
+        CoreExtractionOptions::default(),
+    );
+
+    // The core is a clause over the negated assumptions
+    assert_eq!(core.len(), 2);
+    assert!(core.contains(&reification_literal));
+    assert!(!core.contains(&!reification_literal));
+    assert!(core.contains(&solver.get_literal(predicate!(x >= 2))));
+    assert!(core.contains(&!assumptions[1]));
+}
+
+#[test]
+fn minimisation_removes_unnecessary_assumptions_from_core() {
+    let mut solver = Solver::default();
+    let a = solver.new_literal();
+    let b = solver.new_literal();
+    let c = solver.new_literal();
+    // The first clause propagates `!c` once `a` and `b` are assumed, but `a` is not needed
+    let _ = solver.add_clause([!a, !b, !c]);
+    let _ = solver.add_clause([!b, !c]);
+
+    let mut brancher = propositional_brancher(&[a, b, c]);
+    let core = extract_core(
+        &mut solver,
+        &mut brancher,
+        &[a, b, c],
+        CoreExtractionOptions::default(),
+    );
+    assert_eq!(core.len(), 3);
+
+    let core = extract_core(
+        &mut solver,
+        &mut brancher,
+        &[a, b, c],
+        CoreExtractionOptions {
+            minimise: true,
+            ..Default::default()
+        },
+    );
+    assert_eq!(core.len(), 2);
+    assert!(core.contains(&!b));
+    assert!(core.contains(&!c));
+}
+
+#[test]
+fn minimisation_statistics_record_removed_literals() {
+    let mut solver = Solver::default();
+    let a = solver.new_literal();
+    let b = solver.new_literal();
+    let c = solver.new_literal();
+    let _ = solver.add_clause([!a, !b, !c]);
+    let _ = solver.add_clause([!b, !c]);
+
+    let mut brancher = propositional_brancher(&[a, b, c]);
+    let _ = extract_core(
+        &mut solver,
+        &mut brancher,
+        &[a, b, c],
+        CoreExtractionOptions::default(),
+    );
+    assert_eq!(solver.get_minimisation_statistics().count(), 0);
+
+    let _ = extract_core(
+        &mut solver,
+        &mut brancher,
+        &[a, b, c],
+        CoreExtractionOptions {
+            minimise: true,
+            ..Default::default()
+        },
+    );
+
+    let statistics = solver.get_minimisation_statistics();
+    assert_eq!(statistics.count(), 1);
+    assert_eq!(statistics.sum(), 1);
+    assert_eq!(statistics.max(), Some(1));
+}
+
+#[test]
+fn minimisation_with_exhausted_conflict_budget_returns_the_extracted_core() {
+    let mut solver = Solver::default();
+    let a = solver.new_literal();
+    let b = solver.new_literal();
+    let c = solver.new_literal();
+    let _ = solver.add_clause([!a, !b, !c]);
+    let _ = solver.add_clause([!b, !c]);
+
+    let mut brancher = propositional_brancher(&[a, b, c]);
+    let core = extract_core(
+        &mut solver,
+        &mut brancher,
+        &[a, b, c],
+        CoreExtractionOptions {
+            minimise: true,
+            minimisation_conflict_budget: Some(0),
+            ..Default::default()
+        },
+    );
+
+    assert_eq!(core.len(), 3);
+}
+
+#[test]
+fn minimisation_keeps_necessary_assumptions_of_not_equals_example() {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 2);
+    let y = solver.new_bounded_integer(0, 2);
+    let z = solver.new_bounded_integer(0, 2);
+    for (first, second) in [(x, y), (x, z), (y, z)] {
+        let _ = solver
+            .add_constraint(constraints::binary_not_equals(first, second))
+            .post(NonZero::new(1).unwrap());
+    }
+
+    // All three assumptions are required; without any of them `y` can take a value which
+    // differs from `x`
+    let assumptions = [
+        solver.get_literal(predicate!(x == 1)),
+        solver.get_literal(predicate!(y <= 1)),
+        solver.get_literal(predicate!(y != 0)),
+    ];
+
+    let mut brancher =
+        IndependentVariableValueBrancher::new(InputOrder::new(vec![x, y, z]), InDomainMin);
+    let core = extract_core(
+        &mut solver,
+        &mut brancher,
+        &assumptions,
+        CoreExtractionOptions {
+            minimise: true,
+            ..Default::default()
+        },
+    );
+
+    assert_eq!(core.len(), 3);
+    assert!(assumptions
+        .into_iter()
+        .all(|literal| core.contains(&!literal)));
+}
+
+#[test]
+fn propagating_clauses_under_assumptions() {
+    let mut solver = Solver::default();
+    let a = solver.new_literal();
+    let b = solver.new_literal();
+    let c = solver.new_literal();
+    let _ = solver.add_clause([!a, b]);
+    let _ = solver.add_clause([!b, !c]);
+
+    assert_eq!(
+        solver.propagate_under_assumptions(&[a]),
+        PropagationProbeResult::Consistent {
+            implied: vec![b, !c]
+        }
+    );
+    assert_eq!(
+        solver.propagate_under_assumptions(&[c, a]),
+        PropagationProbeResult::Conflict(vec![c, a])
+    );
+}
+
+#[test]
+fn conflict_under_assumptions_is_explained_by_subset_of_assumptions() {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 5);
+    let y = solver.new_bounded_integer(0, 5);
+    let z = solver.new_bounded_integer(0, 5);
+    let _ = solver
+        .add_constraint(constraints::less_than_or_equals([x, y], 5))
+        .post(NonZero::new(1).unwrap());
+
+    let x_at_least_4 = solver.get_literal(predicate![x >= 4]);
+    let y_at_least_2 = solver.get_literal(predicate![y >= 2]);
+    let z_at_least_3 = solver.get_literal(predicate![z >= 3]);
+    let assumptions = [z_at_least_3, x_at_least_4, y_at_least_2];
+
+    let PropagationProbeResult::Conflict(core) = solver.propagate_under_assumptions(&assumptions)
+    else {
+        panic!("expected the assumptions to be conflicting");
+    };
+
+    assert!(core.iter().all(|literal| assumptions.contains(literal)));
+    assert!(core.contains(&x_at_least_4));
+    assert!(core.contains(&y_at_least_2));
+    assert!(!core.contains(&z_at_least_3));
+}
+
+#[test]
+fn propagating_under_assumptions_reports_implied_literals() {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 5);
+    let y = solver.new_bounded_integer(0, 5);
+    let _ = solver
+        .add_constraint(constraints::less_than_or_equals([x, y], 5))
+        .post(NonZero::new(1).unwrap());
+
+    let x_at_least_4 = solver.get_literal(predicate![x >= 4]);
+    let y_at_most_1 = solver.get_literal(predicate![y <= 1]);
+
+    let PropagationProbeResult::Consistent { implied } =
+        solver.propagate_under_assumptions(&[x_at_least_4])
+    else {
+        panic!("expected the assumptions to be consistent");
+    };
+
+    assert!(implied.contains(&y_at_most_1));
+    assert!(!implied.contains(&x_at_least_4));
+}
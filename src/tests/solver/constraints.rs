@@ -0,0 +1,374 @@
+#![cfg(test)]
+
+use std::cell::Cell;
+use std::num::NonZero;
+use std::rc::Rc;
+
+use crate::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
+use crate::branching::InDomainMin;
+use crate::branching::InputOrder;
+use crate::constraints;
+use crate::constraints::SubCircuitElimination;
+use crate::engine::conflict_analysis::ConflictAnalysisContext;
+use crate::engine::conflict_analysis::ConflictResolver;
+use crate::engine::conflict_analysis::LearnedNogood;
+use crate::engine::conflict_analysis::NoLearning;
+use crate::options::SolverOptions;
+use crate::predicate;
+use crate::results::unsatisfiable::CoreExtractionOptions;
+use crate::results::ProblemSolution;
+use crate::results::SatisfactionResult;
+use crate::results::SatisfactionResultUnderAssumptions;
+use crate::termination::Indefinite;
+use crate::tests::harness::enumerate_solutions;
+use crate::tests::solver::assumptions::extract_core;
+use crate::variables::AffineView;
+use crate::variables::DomainId;
+use crate::variables::TransformableVariable;
+use crate::ConstraintOperationError;
+use crate::Solver;
+
+#[test]
+fn removed_constraint_no_longer_restricts_the_solutions() {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 5);
+    let equals_three = solver.get_literal(predicate!(x == 3));
+
+    let handle = solver
+        .add_removable_constraint(constraints::not_equals([x], 3), NonZero::new(1).unwrap())
+        .expect("no root conflict");
+
+    let mut brancher = IndependentVariableValueBrancher::new(InputOrder::new(vec![x]), InDomainMin);
+    let core = extract_core(
+        &mut solver,
+        &mut brancher,
+        &[equals_three],
+        CoreExtractionOptions::default(),
+    );
+    assert_eq!(core.as_ref(), &[!equals_three]);
+
+    solver.remove_constraint(handle).expect("no root conflict");
+
+    let value =
+        match solver.satisfy_under_assumptions(&mut brancher, &mut Indefinite, &[equals_three]) {
+            SatisfactionResultUnderAssumptions::Satisfiable(solution) => {
+                solution.get_integer_value(x)
+            }
+            _ => panic!("expected the value 3 to be reachable after removing the constraint"),
+        };
+    assert_eq!(value, 3);
+}
+
+#[test]
+fn among_counts_are_maintained_while_backtracking() {
+    let mut solver = Solver::default();
+    let variables = (0..3)
+        .map(|_| solver.new_bounded_integer(0, 2))
+        .collect::<Vec<_>>();
+    let result = solver
+        .add_constraint(constraints::among(variables.clone(), [0, 2], 1, 2))
+        .post(NonZero::new(1).unwrap());
+    assert!(result.is_ok());
+
+    // Either one (3 * 2 ways) or two (3 * 2 * 2 ways) of the variables take 0 or 2
+    assert_eq!(enumerate_solutions(&mut solver, &variables).len(), 18);
+}
+
+#[test]
+fn among_with_empty_bounds_is_infeasible() {
+    let mut solver = Solver::default();
+    let variables = (0..3)
+        .map(|_| solver.new_bounded_integer(0, 2))
+        .collect::<Vec<_>>();
+
+    let result = solver
+        .add_constraint(constraints::among(variables, [1], 2, 1))
+        .post(NonZero::new(1).unwrap());
+    assert!(result.is_err());
+}
+
+#[test]
+fn linear_expression_folds_constants_into_rhs() {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 3);
+    let y = solver.new_bounded_integer(0, 3);
+
+    // x + 5 - y - 3 = 1, i.e. y = x + 1
+    let _ = solver
+        .add_constraint(
+            constraints::sum([x])
+                .plus_constant(5)
+                .minus(y)
+                .plus_constant(-3)
+                .equals(1),
+        )
+        .post(NonZero::new(1).unwrap());
+
+    assert_eq!(enumerate_solutions(&mut solver, &[x, y]).len(), 3);
+}
+
+#[test]
+fn linear_expression_greater_than_or_equals() {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 3);
+    let y = solver.new_bounded_integer(0, 3);
+
+    let _ = solver
+        .add_constraint(constraints::sum([x, y]).greater_than_or_equals(5))
+        .post(NonZero::new(1).unwrap());
+
+    assert_eq!(enumerate_solutions(&mut solver, &[x, y]).len(), 3);
+}
+
+#[test]
+fn weighted_sum_uses_coefficients() {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 3);
+    let y = solver.new_bounded_integer(0, 3);
+
+    // 2x - y = 0
+    let _ = solver
+        .add_constraint(constraints::weighted_sum(&[(2, x), (-1, y)], 0))
+        .post(NonZero::new(1).unwrap());
+
+    assert_eq!(enumerate_solutions(&mut solver, &[x, y]).len(), 2);
+}
+
+#[test]
+fn empty_linear_expression_which_holds_is_not_posted() {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 3);
+
+    let result = solver
+        .add_constraint(
+            constraints::sum(Vec::<DomainId>::new())
+                .plus_constant(2)
+                .less_than_or_equals(3),
+        )
+        .post(NonZero::new(1).unwrap());
+    assert!(result.is_ok());
+
+    assert_eq!(enumerate_solutions(&mut solver, &[x]).len(), 4);
+}
+
+#[test]
+fn empty_linear_expression_which_does_not_hold_is_infeasible() {
+    let mut solver = Solver::default();
+
+    let result = solver
+        .add_constraint(
+            constraints::sum(Vec::<DomainId>::new())
+                .plus_constant(i32::MAX)
+                .plus_constant(i32::MAX)
+                .equals(0),
+        )
+        .post(NonZero::new(1).unwrap());
+    assert!(result.is_err());
+}
+
+#[test]
+fn empty_linear_expression_which_does_not_hold_falsifies_reification_literal() {
+    let mut solver = Solver::default();
+    let reification_literal = solver.new_literal();
+
+    let result = solver
+        .add_constraint(constraints::sum(Vec::<DomainId>::new()).not_equals(0))
+        .implied_by(reification_literal, NonZero::new(1).unwrap());
+    assert!(result.is_ok());
+
+    assert!(solver.add_clause([reification_literal]).is_err());
+}
+
+#[test]
+fn linear_constraints_accept_mixed_terms() {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(3, 3);
+    let y = solver.new_bounded_integer(0, 3);
+    let z = solver.new_bounded_integer(0, 3);
+    let tag = NonZero::new(1).unwrap();
+
+    // 3 - 2y <= 1
+    assert!(solver
+        .add_constraint(constraints::less_than_or_equals(
+            [x.into(), y.scaled(-2)],
+            1
+        ))
+        .post(tag)
+        .is_ok());
+    assert_eq!(solver.lower_bound(&y), 1);
+
+    // 6 - y + z <= 4
+    let terms: Vec<AffineView<DomainId>> = vec![x.scaled(2), y.scaled(-1), z.into()];
+    assert!(solver
+        .add_constraint(constraints::less_than_or_equals(terms, 4))
+        .post(tag)
+        .is_ok());
+    assert_eq!(solver.lower_bound(&y), 2);
+    assert_eq!(solver.upper_bound(&z), 1);
+
+    // z - y <= -3
+    assert!(solver
+        .add_constraint(constraints::weighted_sum_less_than_or_equals(
+            &[(-1, y), (1, z)],
+            -3
+        ))
+        .post(tag)
+        .is_ok());
+    assert_eq!(solver.lower_bound(&y), 3);
+    assert_eq!(solver.upper_bound(&z), 0);
+}
+
+#[test]
+fn empty_linear_constraints_are_decided_when_posted() {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 3);
+    let reification_literal = solver.new_literal();
+    let tag = NonZero::new(1).unwrap();
+
+    assert!(solver
+        .add_constraint(constraints::equals(Vec::<DomainId>::new(), 0))
+        .post(tag)
+        .is_ok());
+    assert!(solver
+        .add_constraint(constraints::not_equals(Vec::<DomainId>::new(), 2))
+        .post(tag)
+        .is_ok());
+    assert!(solver
+        .add_constraint(constraints::less_than_or_equals(Vec::<DomainId>::new(), 0))
+        .post(tag)
+        .is_ok());
+    assert!(solver
+        .add_constraint(constraints::equals(Vec::<DomainId>::new(), 1))
+        .implied_by(reification_literal, tag)
+        .is_ok());
+    assert_eq!(solver.get_literal_value(reification_literal), Some(false));
+    assert_eq!(enumerate_solutions(&mut solver, &[x]).len(), 4);
+
+    assert!(solver
+        .add_constraint(constraints::less_than_or_equals(Vec::<DomainId>::new(), -1))
+        .post(tag)
+        .is_err());
+}
+
+#[test]
+fn circuit_over_a_single_node_is_a_self_loop() {
+    for sub_circuit_elimination in [
+        SubCircuitElimination::Decomposition,
+        SubCircuitElimination::ForwardChecking,
+        SubCircuitElimination::Dfs,
+    ] {
+        let mut solver = Solver::default();
+        let successor = solver.new_bounded_integer(1, 3);
+
+        let result = solver
+            .add_constraint(constraints::circuit(
+                [successor.into()],
+                sub_circuit_elimination,
+                true,
+                true,
+            ))
+            .post(NonZero::new(1).unwrap());
+        assert!(result.is_ok());
+
+        assert_eq!(solver.lower_bound(&successor), 1);
+        assert_eq!(solver.upper_bound(&successor), 1);
+    }
+}
+
+#[test]
+fn element_over_a_single_element_fixes_the_index() {
+    let mut solver = Solver::default();
+    let index = solver.new_bounded_integer(0, 3);
+    let element = solver.new_bounded_integer(2, 5);
+    let rhs = solver.new_bounded_integer(0, 3);
+
+    let result = solver
+        .add_constraint(constraints::element_decomposition(index, [element], rhs))
+        .post(NonZero::new(1).unwrap());
+    assert!(result.is_ok());
+
+    assert_eq!(solver.lower_bound(&index), 1);
+    assert_eq!(solver.upper_bound(&index), 1);
+    assert_eq!(solver.lower_bound(&rhs), 2);
+    assert_eq!(solver.upper_bound(&element), 3);
+}
+
+/// A [`ConflictResolver`] which counts the conflicts it resolves and otherwise behaves as
+/// [`NoLearning`].
+struct CountingResolver {
+    num_resolved_conflicts: Rc<Cell<u64>>,
+}
+
+impl ConflictResolver for CountingResolver {
+    fn resolve_conflict(&mut self, context: &mut ConflictAnalysisContext) -> Option<LearnedNogood> {
+        self.num_resolved_conflicts
+            .set(self.num_resolved_conflicts.get() + 1);
+        NoLearning.resolve_conflict(context)
+    }
+
+    fn process(
+        &mut self,
+        learned_nogood: Option<LearnedNogood>,
+        context: &mut ConflictAnalysisContext,
+    ) -> Result<(), ()> {
+        NoLearning.process(learned_nogood, context)
+    }
+}
+
+#[test]
+fn conflicts_are_resolved_by_the_provided_conflict_resolver() {
+    let num_resolved_conflicts = Rc::new(Cell::new(0));
+    let mut solver = Solver::with_options_and_conflict_resolver(
+        SolverOptions::default(),
+        Box::new(CountingResolver {
+            num_resolved_conflicts: Rc::clone(&num_resolved_conflicts),
+        }),
+    );
+
+    // Three pairwise different variables with only two values; the not-equals propagators
+    // only detect this once the variables are fixed, so search is required
+    let xs = [0, 1, 2].map(|_| solver.new_bounded_integer(0, 1));
+    let tag = NonZero::new(1).unwrap();
+    for (i, j) in [(0, 1), (0, 2), (1, 2)] {
+        solver
+            .add_constraint(constraints::binary_not_equals(xs[i], xs[j]))
+            .post(tag)
+            .expect("no root conflict");
+    }
+
+    let mut brancher =
+        IndependentVariableValueBrancher::new(InputOrder::new(xs.to_vec()), InDomainMin);
+    let result = solver.satisfy(&mut brancher, &mut Indefinite);
+
+    assert!(matches!(result, SatisfactionResult::Unsatisfiable));
+    // Every conflict is resolved by the provided resolver, except for the final conflict at
+    // the root which proves infeasibility
+    assert!(num_resolved_conflicts.get() > 0);
+    assert_eq!(
+        num_resolved_conflicts.get() + 1,
+        solver.statistics().conflicts
+    );
+}
+
+#[test]
+fn maximum_over_an_empty_array_is_rejected() {
+    let mut solver = Solver::default();
+    let rhs = solver.new_bounded_integer(0, 3);
+    let tag = NonZero::new(1).unwrap();
+
+    let result = solver
+        .add_constraint(constraints::maximum(Vec::<DomainId>::new(), rhs))
+        .post(tag);
+    assert!(matches!(
+        result,
+        Err(ConstraintOperationError::EmptyMaximum)
+    ));
+
+    let result = solver
+        .add_constraint(constraints::maximum_decomposition(Vec::new(), rhs))
+        .post(tag);
+    assert!(matches!(
+        result,
+        Err(ConstraintOperationError::EmptyMaximum)
+    ));
+}
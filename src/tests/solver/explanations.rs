@@ -0,0 +1,210 @@
+#![cfg(test)]
+
+use std::num::NonZero;
+
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::basic_types::Solution;
+use crate::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
+use crate::branching::InDomainMin;
+use crate::branching::InputOrder;
+use crate::constraints;
+use crate::engine::cp::propagation::PropagationContextMut;
+use crate::engine::cp::propagation::Propagator;
+use crate::engine::cp::propagation::PropagatorInitialisationContext;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::predicate;
+use crate::results::solution_iterator::IteratedSolution;
+use crate::termination::Indefinite;
+use crate::variables::DomainId;
+use crate::variables::TransformableVariable;
+use crate::Solver;
+use crate::ViolatedConstraint;
+
+#[test]
+fn explanation_of_root_conflict_contains_the_contradictory_constraints() {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 10);
+    let y = solver.new_bounded_integer(0, 10);
+    let z = solver.new_bounded_integer(0, 10);
+    let tag = |tag| NonZero::new(tag).unwrap();
+
+    let _ = solver
+        .add_constraint(constraints::less_than_or_equals([x], 8))
+        .post(tag(1));
+    // x + y <= 3
+    let _ = solver
+        .add_constraint(constraints::less_than_or_equals([x, y], 3))
+        .post(tag(2));
+    let _ = solver
+        .add_constraint(constraints::less_than_or_equals([y, z], 12))
+        .post(tag(3));
+    // x + y >= 10
+    let _ = solver
+        .add_constraint(constraints::less_than_or_equals(
+            [x.scaled(-1), y.scaled(-1)],
+            -10,
+        ))
+        .post(tag(4));
+    let _ = solver
+        .add_constraint(constraints::not_equals([z], 5))
+        .post(tag(5));
+    solver.name_constraint(tag(2), "at_most_three");
+
+    let mut brancher =
+        IndependentVariableValueBrancher::new(InputOrder::new(vec![x, y, z]), InDomainMin);
+    let explanation = solver
+        .explain_unsat(&mut brancher, &mut Indefinite)
+        .expect("the problem is unsatisfiable");
+
+    assert_eq!(explanation.tags, vec![tag(2), tag(4)]);
+    assert_eq!(
+        explanation.names,
+        vec![Some("at_most_three".to_owned()), None]
+    );
+}
+
+#[test]
+fn explanation_of_conflict_found_during_search_contains_the_contradictory_constraints() {
+    let mut solver = Solver::default();
+    let variables = [0; 5].map(|_| solver.new_bounded_integer(0, 1));
+    let [a, b, x, y, z] = variables;
+    let tag = |tag| NonZero::new(tag).unwrap();
+
+    let _ = solver
+        .add_constraint(constraints::less_than_or_equals([a, b], 1))
+        .post(tag(1));
+    // The variables x, y and z can not be pairwise different, but this is only detected
+    // during search
+    let _ = solver
+        .add_constraint(constraints::binary_not_equals(x, y))
+        .post(tag(2));
+    let _ = solver
+        .add_constraint(constraints::binary_not_equals(y, z))
+        .post(tag(3));
+    let _ = solver
+        .add_constraint(constraints::binary_not_equals(x, z))
+        .post(tag(4));
+    let _ = solver
+        .add_constraint(constraints::not_equals([a, b], 0))
+        .post(tag(5));
+
+    let mut brancher =
+        IndependentVariableValueBrancher::new(InputOrder::new(variables.to_vec()), InDomainMin);
+    let explanation = solver
+        .explain_unsat(&mut brancher, &mut Indefinite)
+        .expect("the problem is unsatisfiable");
+
+    assert_eq!(explanation.tags, vec![tag(2), tag(3), tag(4)]);
+    assert_eq!(explanation.names, vec![None; 3]);
+}
+
+#[test]
+fn no_explanation_for_satisfiable_problem() {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 3);
+    let y = solver.new_bounded_integer(0, 3);
+    let _ = solver
+        .add_constraint(constraints::binary_not_equals(x, y))
+        .post(NonZero::new(1).unwrap());
+
+    let mut brancher =
+        IndependentVariableValueBrancher::new(InputOrder::new(vec![x, y]), InDomainMin);
+    assert_eq!(solver.explain_unsat(&mut brancher, &mut Indefinite), None);
+}
+
+/// A faulty implementation of `x != y` which never propagates or detects conflicts, but
+/// which does correctly check whether a solution satisfies it.
+struct BrokenNotEquals {
+    x: DomainId,
+    y: DomainId,
+}
+
+impl Propagator for BrokenNotEquals {
+    fn name(&self) -> &str {
+        "BrokenNotEquals"
+    }
+
+    fn propagate(&self, _context: PropagationContextMut) -> PropagationStatusCP {
+        Ok(())
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        _context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        Ok(())
+    }
+
+    fn check(&self, context: PropagationContextMut) -> bool {
+        context.lower_bound(&self.x) != context.lower_bound(&self.y)
+    }
+}
+
+pub(crate) fn first_solution(solver: &mut Solver, variables: Vec<DomainId>) -> Solution {
+    let mut brancher =
+        IndependentVariableValueBrancher::new(InputOrder::new(variables), InDomainMin);
+    let mut termination = Indefinite;
+    let mut solution_iterator = solver.get_solution_iterator(&mut brancher, &mut termination);
+    match solution_iterator.next_solution() {
+        IteratedSolution::Solution(solution) => solution.into(),
+        _ => panic!("expected the problem to be satisfiable"),
+    }
+}
+
+#[test]
+fn verify_solution_reports_the_broken_propagator() {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 3);
+    let y = solver.new_bounded_integer(0, 3);
+    let tag = NonZero::new(2).unwrap();
+    let _ = solver
+        .add_constraint(constraints::less_than_or_equals([x, y], 6))
+        .post(NonZero::new(1).unwrap());
+    assert!(solver.add_propagator(BrokenNotEquals { x, y }, tag).is_ok());
+
+    let solution = first_solution(&mut solver, vec![x, y]);
+
+    assert_eq!(
+        solver.verify_solution(&solution),
+        Err(ViolatedConstraint::Propagator {
+            name: "BrokenNotEquals".to_owned(),
+            tag,
+        })
+    );
+}
+
+#[test]
+fn verify_solution_accepts_solution_of_correct_model() {
+    let mut solver = Solver::default();
+    let variables = [0; 3].map(|_| solver.new_bounded_integer(0, 2));
+    let [x, y, z] = variables;
+    let a = solver.new_literal();
+    let b = solver.new_literal();
+    let _ = solver
+        .add_constraint(constraints::binary_not_equals(x, y))
+        .post(NonZero::new(1).unwrap());
+    let _ = solver
+        .add_constraint(constraints::less_than_or_equals([y, z], 2))
+        .post(NonZero::new(2).unwrap());
+    let _ = solver.add_clause([a, b]);
+    let _ = solver.add_clause([!a, !b, solver.get_literal(predicate!(x >= 1))]);
+
+    let solution = first_solution(&mut solver, variables.to_vec());
+
+    assert_eq!(solver.verify_solution(&solution), Ok(()));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "BrokenNotEquals")]
+fn satisfy_checks_the_solution_in_debug_builds() {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 3);
+    let y = solver.new_bounded_integer(0, 3);
+    let _ = solver.add_propagator(BrokenNotEquals { x, y }, NonZero::new(1).unwrap());
+
+    let mut brancher =
+        IndependentVariableValueBrancher::new(InputOrder::new(vec![x, y]), InDomainMin);
+    let _ = solver.satisfy(&mut brancher, &mut Indefinite);
+}
@@ -0,0 +1,9 @@
+pub(crate) mod assumptions;
+pub(crate) mod constraints;
+pub(crate) mod explanations;
+pub(crate) mod optimisation;
+pub(crate) mod proof;
+pub(crate) mod root;
+pub(crate) mod search;
+pub(crate) mod statistics;
+pub(crate) mod variables;
@@ -0,0 +1,403 @@
+#![cfg(test)]
+
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::num::NonZero;
+use std::rc::Rc;
+use std::time::Duration;
+
+use crate::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
+use crate::branching::Brancher;
+use crate::branching::InDomainMin;
+use crate::branching::InDomainSplitReversed;
+use crate::branching::InputOrder;
+use crate::branching::SelectionContext;
+use crate::constraints;
+use crate::optimisation::OptimisationStrategy;
+use crate::options::SolverOptions;
+use crate::predicate;
+use crate::predicates::Predicate;
+use crate::results::OptimisationResult;
+use crate::results::ProblemSolution;
+use crate::statistics::OptimisationIteration;
+use crate::statistics::SearchStatistics;
+use crate::termination::Indefinite;
+use crate::termination::TerminationCondition;
+use crate::termination::TerminationReason;
+use crate::termination::TimeBudget;
+use crate::variables::DomainId;
+use crate::variables::TransformableVariable;
+use crate::Solver;
+
+/// Creates a solver for minimising `x` subject to `x + y >= 6` with `x` in `[0, 10]` and `y`
+/// in `[0, y_max]`, together with a counter of the number of solutions which are reported.
+fn warm_start_solver(y_max: i32) -> (Solver, DomainId, DomainId, Rc<Cell<usize>>) {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 10);
+    let y = solver.new_bounded_integer(0, y_max);
+
+    let _ = solver
+        .add_constraint(constraints::less_than_or_equals(
+            [x.scaled(-1), y.scaled(-1)],
+            -6,
+        ))
+        .post(NonZero::new(1).unwrap());
+
+    let num_solutions = Rc::new(Cell::new(0));
+    let counter = Rc::clone(&num_solutions);
+    solver.with_solution_callback(move |_| counter.set(counter.get() + 1));
+
+    (solver, x, y, num_solutions)
+}
+
+#[test]
+fn final_solution_callback_is_only_called_for_the_returned_solution() {
+    let (mut solver, x, y, _) = warm_start_solver(10);
+
+    let intermediate_values = Rc::new(RefCell::new(vec![]));
+    let callback_values = Rc::clone(&intermediate_values);
+    solver.with_solution_callback(move |arguments| {
+        assert!(!arguments.is_final);
+        callback_values
+            .borrow_mut()
+            .push(arguments.solution.get_integer_value(x));
+    });
+    let final_values = Rc::new(RefCell::new(vec![]));
+    let callback_values = Rc::clone(&final_values);
+    solver.with_final_solution_callback(move |arguments| {
+        assert!(arguments.is_final);
+        callback_values
+            .borrow_mut()
+            .push(arguments.solution.get_integer_value(x));
+    });
+
+    let mut brancher =
+        IndependentVariableValueBrancher::new(InputOrder::new(vec![x, y]), InDomainSplitReversed);
+    let result = solver.minimise(&mut brancher, &mut Indefinite, x);
+    assert_eq!(result.objective_value(&x), Some(0));
+
+    assert!(intermediate_values.borrow().len() > 1);
+    assert_eq!(intermediate_values.borrow().last(), Some(&0));
+    assert_eq!(*final_values.borrow(), vec![0]);
+}
+
+#[test]
+fn warm_start_from_optimal_solution_finds_no_improving_solutions() {
+    let (mut solver, x, y, _) = warm_start_solver(4);
+    let mut brancher =
+        IndependentVariableValueBrancher::new(InputOrder::new(vec![x, y]), InDomainMin);
+    let OptimisationResult::Optimal(optimal_solution) =
+        solver.minimise(&mut brancher, &mut Indefinite, x)
+    else {
+        panic!("expected the problem to be solved to optimality");
+    };
+    assert_eq!(optimal_solution.get_integer_value(x), 2);
+
+    let (mut solver, x, y, num_solutions) = warm_start_solver(4);
+    let mut brancher =
+        IndependentVariableValueBrancher::new(InputOrder::new(vec![x, y]), InDomainMin);
+    let result =
+        solver.minimise_with_initial_solution(&mut brancher, &mut Indefinite, x, &optimal_solution);
+
+    let OptimisationResult::Optimal(solution) = result else {
+        panic!("expected optimality to be proven");
+    };
+    assert_eq!(solution.get_integer_value(x), 2);
+    assert_eq!(num_solutions.get(), 0);
+}
+
+#[test]
+fn warm_start_from_infeasible_solution_falls_back_to_cold_start() {
+    let (mut solver, x, y, _) = warm_start_solver(4);
+    let mut brancher =
+        IndependentVariableValueBrancher::new(InputOrder::new(vec![x, y]), InDomainMin);
+    let OptimisationResult::Optimal(previous_solution) =
+        solver.minimise(&mut brancher, &mut Indefinite, x)
+    else {
+        panic!("expected the problem to be solved to optimality");
+    };
+
+    // In the modified model, `y = 4` is no longer allowed
+    let (mut solver, x, y, num_solutions) = warm_start_solver(3);
+    let mut brancher =
+        IndependentVariableValueBrancher::new(InputOrder::new(vec![x, y]), InDomainMin);
+    let result = solver.minimise_with_initial_solution(
+        &mut brancher,
+        &mut Indefinite,
+        x,
+        &previous_solution,
+    );
+
+    let OptimisationResult::Optimal(solution) = result else {
+        panic!("expected optimality to be proven");
+    };
+    assert_eq!(solution.get_integer_value(x), 3);
+    assert!(num_solutions.get() > 0);
+}
+
+/// A [`Brancher`] which assigns a randomly selected unfixed variable to its lower bound.
+#[derive(Debug)]
+struct RandomVariableBrancher(Vec<DomainId>);
+
+impl Brancher for RandomVariableBrancher {
+    fn next_decision(&mut self, context: &mut SelectionContext) -> Option<Predicate> {
+        let unfixed = self
+            .0
+            .iter()
+            .copied()
+            .filter(|variable| !context.is_integer_fixed(variable))
+            .collect::<Vec<_>>();
+        if unfixed.is_empty() {
+            return None;
+        }
+
+        let variable = unfixed[context.random().generate_usize_in_range(0..unfixed.len())];
+        Some(predicate!(variable <= context.lower_bound(&variable)))
+    }
+}
+
+fn minimise_with_random_seed(random_seed: u64) -> (SearchStatistics, Vec<i32>) {
+    let mut solver = Solver::with_options(SolverOptions {
+        random_seed,
+        ..Default::default()
+    });
+    let xs = (0..6)
+        .map(|_| solver.new_bounded_integer(0, 5))
+        .collect::<Vec<_>>();
+    let objective = solver.new_bounded_integer(0, 30);
+
+    let weights = [3, -2, 4, 1, -3, 2];
+    let _ = solver
+        .add_constraint(
+            constraints::sum(
+                xs.iter()
+                    .zip(weights)
+                    .map(|(x, weight)| constraints::linear_term(weight, *x)),
+            )
+            .minus(objective)
+            .equals(0),
+        )
+        .post(NonZero::new(1).unwrap());
+    let _ = solver
+        .add_constraint(constraints::sum(xs.iter().copied()).greater_than_or_equals(12))
+        .post(NonZero::new(2).unwrap());
+
+    let mut brancher = RandomVariableBrancher(xs.clone());
+    let OptimisationResult::Optimal(solution) =
+        solver.minimise(&mut brancher, &mut Indefinite, objective)
+    else {
+        panic!("expected the problem to be solved to optimality");
+    };
+
+    let values = xs
+        .iter()
+        .chain([&objective])
+        .map(|&variable| solution.get_integer_value(variable))
+        .collect();
+    (solver.statistics(), values)
+}
+
+#[test]
+fn same_random_seed_gives_identical_search() {
+    let (first_statistics, first_solution) = minimise_with_random_seed(7);
+    let (second_statistics, second_solution) = minimise_with_random_seed(7);
+
+    assert!(first_statistics.decisions > 0);
+    assert_eq!(first_statistics, second_statistics);
+    assert_eq!(first_solution, second_solution);
+}
+
+#[test]
+fn every_optimisation_iteration_is_reported() {
+    let mut solver = Solver::with_options(SolverOptions {
+        verbose_optimisation: true,
+        ..Default::default()
+    });
+    let x = solver.new_bounded_integer(4, 10);
+
+    let iterations = Rc::new(RefCell::new(vec![]));
+    let recorded = Rc::clone(&iterations);
+    solver.with_iteration_callback(move |iteration: &OptimisationIteration| {
+        recorded.borrow_mut().push(*iteration)
+    });
+
+    // Splitting towards the upper half ensures that multiple improving solutions are found
+    let mut brancher =
+        IndependentVariableValueBrancher::new(InputOrder::new(vec![x]), InDomainSplitReversed);
+    let result = solver.minimise(&mut brancher, &mut Indefinite, x);
+    assert!(matches!(result, OptimisationResult::Optimal(_)));
+
+    let iterations = iterations.borrow();
+    let (last, improving) = iterations.split_last().unwrap();
+    assert!(improving.len() >= 3);
+    assert!(improving
+        .windows(2)
+        .all(|pair| pair[1].upper_bound < pair[0].upper_bound));
+    assert!(improving.iter().all(|iteration| iteration.lower_bound == 4));
+    assert!(iterations
+        .iter()
+        .enumerate()
+        .all(|(index, iteration)| iteration.iteration == index));
+
+    assert_eq!(last.lower_bound, 4);
+    assert_eq!(last.upper_bound, 4);
+}
+
+/// Creates a solver which minimises `objective = x + y` subject to `x + y >= 37`, and records
+/// the iterations of the optimisation procedure (i.e. the number of solve calls).
+fn dichotomic_search_solver() -> (
+    Solver,
+    Vec<DomainId>,
+    DomainId,
+    Rc<RefCell<Vec<OptimisationIteration>>>,
+) {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 50);
+    let y = solver.new_bounded_integer(0, 50);
+    let objective = solver.new_bounded_integer(0, 100);
+
+    let _ = solver
+        .add_constraint(constraints::sum([x, y]).minus(objective).equals(0))
+        .post(NonZero::new(1).unwrap());
+    let _ = solver
+        .add_constraint(constraints::sum([x, y]).greater_than_or_equals(37))
+        .post(NonZero::new(2).unwrap());
+
+    let iterations = Rc::new(RefCell::new(vec![]));
+    let recorded = Rc::clone(&iterations);
+    solver.with_iteration_callback(move |iteration: &OptimisationIteration| {
+        recorded.borrow_mut().push(*iteration)
+    });
+
+    (solver, vec![x, y], objective, iterations)
+}
+
+#[test]
+fn dichotomic_search_finds_the_optimum_in_fewer_calls_than_linear_search() {
+    let optimise = |strategy| {
+        let (mut solver, variables, objective, iterations) = dichotomic_search_solver();
+        // Splitting towards the upper half makes linear search improve in small steps
+        let mut brancher = IndependentVariableValueBrancher::new(
+            InputOrder::new(variables),
+            InDomainSplitReversed,
+        );
+        let result =
+            solver.minimise_with_strategy(&mut brancher, &mut Indefinite, objective, strategy);
+        let OptimisationResult::Optimal(solution) = result else {
+            panic!("expected an optimal solution, got {result:?}");
+        };
+        let num_calls = iterations.borrow().len();
+        (solution.get_integer_value(objective), num_calls)
+    };
+
+    let (linear_optimum, linear_calls) = optimise(OptimisationStrategy::LinearSatUnsat);
+    let (dichotomic_optimum, dichotomic_calls) = optimise(OptimisationStrategy::Dichotomic);
+
+    assert_eq!(linear_optimum, 37);
+    assert_eq!(dichotomic_optimum, 37);
+    assert!(
+        dichotomic_calls < linear_calls,
+        "dichotomic search used {dichotomic_calls} calls, linear search used {linear_calls}"
+    );
+}
+
+#[test]
+fn dichotomic_search_narrows_the_bounds_until_they_meet() {
+    let (mut solver, variables, objective, iterations) = dichotomic_search_solver();
+    let mut brancher =
+        IndependentVariableValueBrancher::new(InputOrder::new(variables), InDomainSplitReversed);
+    let result = solver.minimise_dichotomic(&mut brancher, &mut Indefinite, objective);
+    assert!(matches!(result, OptimisationResult::Optimal(_)));
+
+    let iterations = iterations.borrow();
+    assert!(iterations.len() > 1);
+    assert!(iterations.windows(2).all(|pair| {
+        pair[0].lower_bound <= pair[1].lower_bound && pair[1].upper_bound <= pair[0].upper_bound
+    }));
+    let last = iterations.last().unwrap();
+    assert_eq!((last.lower_bound, last.upper_bound), (37, 37));
+
+    // The optimum is posted such that subsequent solve calls respect it
+    assert_eq!(solver.upper_bound(&objective), 37);
+}
+
+#[test]
+fn maximising_with_dichotomic_search_finds_the_optimum() {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 20);
+    let y = solver.new_bounded_integer(0, 20);
+    let _ = solver
+        .add_constraint(constraints::sum([x, y]).less_than_or_equals(23))
+        .post(NonZero::new(1).unwrap());
+
+    let mut brancher =
+        IndependentVariableValueBrancher::new(InputOrder::new(vec![x, y]), InDomainMin);
+    let result = solver.maximise_dichotomic(&mut brancher, &mut Indefinite, x);
+    let OptimisationResult::Optimal(solution) = result else {
+        panic!("expected an optimal solution, got {result:?}");
+    };
+    assert_eq!(solution.get_integer_value(x), 20);
+}
+
+#[test]
+fn dichotomic_search_with_fixed_objective_needs_a_single_call() {
+    let (mut solver, variables, _, iterations) = dichotomic_search_solver();
+    let objective = solver.new_bounded_integer(5, 5);
+
+    let mut brancher =
+        IndependentVariableValueBrancher::new(InputOrder::new(variables), InDomainMin);
+    let result = solver.minimise_dichotomic(&mut brancher, &mut Indefinite, objective);
+    assert!(matches!(result, OptimisationResult::Optimal(_)));
+    assert_eq!(iterations.borrow().len(), 1);
+}
+
+/// Stops the search once the provided number of iterations has been recorded.
+struct AfterIterations(Rc<RefCell<Vec<OptimisationIteration>>>, usize);
+
+impl TerminationCondition for AfterIterations {
+    fn should_stop(&mut self) -> bool {
+        self.0.borrow().len() >= self.1
+    }
+}
+
+#[test]
+fn terminated_dichotomic_search_reports_the_remaining_interval() {
+    let (mut solver, variables, objective, iterations) = dichotomic_search_solver();
+    let mut brancher =
+        IndependentVariableValueBrancher::new(InputOrder::new(variables), InDomainSplitReversed);
+    let mut termination = AfterIterations(Rc::clone(&iterations), 2);
+
+    let result = solver.minimise_dichotomic(&mut brancher, &mut termination, objective);
+    let OptimisationResult::Satisfiable(solution) = result else {
+        panic!("expected the search to be terminated, got {result:?}");
+    };
+
+    let iterations = iterations.borrow();
+    let last = iterations.last().unwrap();
+    assert_eq!(
+        last.upper_bound,
+        solution.get_integer_value(objective) as i64
+    );
+    assert!(last.lower_bound < last.upper_bound);
+    assert!(last.lower_bound <= 37);
+}
+
+#[test]
+fn termination_reason_of_optimisation_is_reported() {
+    let (mut solver, variables, objective, _) = dichotomic_search_solver();
+    let mut brancher =
+        IndependentVariableValueBrancher::new(InputOrder::new(variables), InDomainMin);
+
+    for strategy in [
+        OptimisationStrategy::LinearSatUnsat,
+        OptimisationStrategy::Dichotomic,
+    ] {
+        let mut termination = TimeBudget::starting_now(Duration::ZERO);
+        let result =
+            solver.minimise_with_strategy(&mut brancher, &mut termination, objective, strategy);
+        assert!(matches!(
+            result,
+            OptimisationResult::Unknown(TerminationReason::TimeBudget)
+        ));
+    }
+}
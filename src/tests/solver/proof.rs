@@ -0,0 +1,265 @@
+#![cfg(test)]
+
+use std::fs::File;
+use std::num::NonZero;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
+use crate::branching::InDomainMin;
+use crate::branching::InputOrder;
+use crate::constraints;
+use crate::options::SolverOptions;
+use crate::proof::Proof;
+use crate::results::SatisfactionResult;
+use crate::termination::Indefinite;
+use crate::Solver;
+
+/// Creates a solver for an unsatisfiable problem which logs its proof to a file in the
+/// temporary directory; the path of the proof is returned as well.
+fn unsatisfiable_solver_with_proof(name: &str, max_proof_bytes: Option<u64>) -> (Solver, PathBuf) {
+    let path = std::env::temp_dir().join(format!("munchkin-{name}-{}.drcp", std::process::id()));
+    let proof = Proof::new(File::create(&path).unwrap(), path.with_extension("lits"));
+
+    let mut solver = Solver::with_options(SolverOptions {
+        proof,
+        max_proof_bytes,
+        ..Default::default()
+    });
+    let b = solver.new_literal();
+    let c = solver.new_literal();
+
+    let _ = solver.add_clause([b, c]);
+    let _ = solver.add_clause([b, !c]);
+    let _ = solver.add_clause([!b, c]);
+    let _ = solver.add_clause([!b, !c]);
+
+    let mut brancher = IndependentVariableValueBrancher::new(
+        InputOrder::new(vec![
+            b.get_propositional_variable(),
+            c.get_propositional_variable(),
+        ]),
+        InDomainMin,
+    );
+    let result = solver.satisfy(&mut brancher, &mut Indefinite);
+    assert!(matches!(result, SatisfactionResult::Unsatisfiable));
+
+    solver.conclude_proof_unsat();
+
+    (solver, path)
+}
+
+fn read_and_remove_proof(path: &Path) -> String {
+    let proof = std::fs::read_to_string(path).unwrap();
+    let _ = std::fs::remove_file(path);
+    let _ = std::fs::remove_file(path.with_extension("lits"));
+    proof
+}
+
+#[test]
+fn tag_artifacts_are_recorded_per_tag() {
+    let path = std::env::temp_dir().join(format!(
+        "munchkin-tag-artifacts-{}.drcp",
+        std::process::id()
+    ));
+    let proof = Proof::new(File::create(&path).unwrap(), path.with_extension("lits"));
+    let mut solver = Solver::with_options(SolverOptions {
+        proof,
+        ..Default::default()
+    });
+    let x = solver.new_bounded_integer(0, 5);
+    let y = solver.new_bounded_integer(0, 5);
+    let [a, b, c] = std::array::from_fn(|_| solver.new_literal());
+    let linear_tag = NonZero::new(1).unwrap();
+    let clause_tag = NonZero::new(2).unwrap();
+
+    assert!(solver
+        .add_constraint(constraints::less_than_or_equals([x, y], 5))
+        .post(linear_tag)
+        .is_ok());
+    assert!(solver
+        .add_constraint(constraints::clause([a, b]))
+        .post(clause_tag)
+        .is_ok());
+    // Once `c` holds at the root, the clause `!c \/ a` is reduced to the unit `a`
+    assert!(solver.add_clause([c]).is_ok());
+    assert!(solver
+        .add_constraint(constraints::clause([!c, a]))
+        .post(clause_tag)
+        .is_ok());
+
+    let linear_artifacts = solver.tag_artifacts(linear_tag).unwrap();
+    assert_eq!(linear_artifacts.num_propagators(), 1);
+    assert_eq!(linear_artifacts.num_clauses(), 0);
+    assert!(linear_artifacts.unit_literals().is_empty());
+
+    let clause_artifacts = solver.tag_artifacts(clause_tag).unwrap();
+    assert_eq!(clause_artifacts.num_propagators(), 0);
+    assert_eq!(clause_artifacts.num_clauses(), 1);
+    assert_eq!(clause_artifacts.unit_literals(), &[a]);
+
+    assert!(solver.tag_artifacts(NonZero::new(3).unwrap()).is_none());
+
+    let _ = read_and_remove_proof(&path);
+}
+
+#[test]
+fn tag_artifacts_are_not_recorded_without_proof() {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 5);
+    let tag = NonZero::new(1).unwrap();
+
+    assert!(solver
+        .add_constraint(constraints::less_than_or_equals([x], 3))
+        .post(tag)
+        .is_ok());
+
+    assert!(solver.tag_artifacts(tag).is_none());
+}
+
+#[test]
+fn proof_statistics_are_none_without_proof() {
+    let solver = Solver::default();
+
+    assert_eq!(solver.proof_statistics(), None);
+}
+
+#[test]
+fn proof_statistics_count_logged_steps() {
+    let (solver, path) = unsatisfiable_solver_with_proof("complete", None);
+    let proof = read_and_remove_proof(&path);
+
+    let statistics = solver.proof_statistics().unwrap();
+    assert!(statistics.nogood_steps > 0);
+    assert!(!statistics.is_truncated);
+    assert!(proof.lines().any(|line| line.starts_with("c ")));
+    assert_eq!(
+        statistics.bytes_written,
+        proof.len() as u64 - "c UNSAT\n".len() as u64
+    );
+}
+
+#[test]
+fn proof_exceeding_maximum_size_is_not_concluded() {
+    let (solver, path) = unsatisfiable_solver_with_proof("truncated", Some(2));
+    let proof = read_and_remove_proof(&path);
+
+    let statistics = solver.proof_statistics().unwrap();
+    assert!(statistics.is_truncated);
+    assert_eq!(statistics.nogood_steps, 1);
+    assert_eq!(statistics.bytes_written, proof.len() as u64);
+    assert!(!proof.lines().any(|line| line.starts_with("c ")));
+}
+
+#[test]
+fn root_propagations_while_posting_are_logged_to_proof() {
+    let path = std::env::temp_dir().join(format!(
+        "munchkin-root-propagations-{}.drcp",
+        std::process::id()
+    ));
+    let proof = Proof::new(File::create(&path).unwrap(), path.with_extension("lits"));
+    let mut solver = Solver::with_options(SolverOptions {
+        proof,
+        ..Default::default()
+    });
+
+    let x = solver.new_named_bounded_integer(2, 5, "x");
+    let y = solver.new_named_bounded_integer(0, 5, "y");
+    let result = solver
+        .add_constraint(constraints::binary_less_than_or_equals(x, y, 3))
+        .post(NonZero::new(1).unwrap());
+    assert!(result.is_ok());
+    assert_eq!(solver.lower_bound(&y), 5);
+    assert_eq!(solver.upper_bound(&x), 2);
+
+    let statistics = solver.proof_statistics().unwrap();
+    drop(solver);
+    let proof = read_and_remove_proof(&path);
+
+    // Every propagation is logged as an inference which is used as the hint of a nogood
+    assert_eq!(statistics.inference_steps, 2);
+    assert_eq!(statistics.nogood_steps, 2);
+    let inferences = proof
+        .lines()
+        .filter(|line| line.starts_with("i "))
+        .collect::<Vec<_>>();
+    assert_eq!(inferences.len(), 2);
+    assert!(inferences
+        .iter()
+        .all(|line| line.contains("c:1") && line.contains("l:linear")));
+}
+
+#[test]
+fn root_units_from_clauses_are_logged_to_proof() {
+    let path =
+        std::env::temp_dir().join(format!("munchkin-root-units-{}.drcp", std::process::id()));
+    let proof = Proof::new(File::create(&path).unwrap(), path.with_extension("lits"));
+    let mut solver = Solver::with_options(SolverOptions {
+        proof,
+        ..Default::default()
+    });
+
+    let a = solver.new_literal();
+    let b = solver.new_literal();
+    assert!(solver.add_clause([!a]).is_ok());
+    assert!(solver.add_clause([a, b]).is_ok());
+    assert_eq!(solver.get_literal_value(b), Some(true));
+
+    let statistics = solver.proof_statistics().unwrap();
+    drop(solver);
+    let _ = read_and_remove_proof(&path);
+
+    // Only `b` is derived from the clause `a \/ b`; the unit clause `!a` is an axiom
+    assert_eq!(statistics.inference_steps, 1);
+    assert_eq!(statistics.nogood_steps, 1);
+}
+
+#[test]
+fn fixed_integers_are_logged_to_proof() {
+    let path =
+        std::env::temp_dir().join(format!("munchkin-fix-integer-{}.drcp", std::process::id()));
+    let proof = Proof::new(File::create(&path).unwrap(), path.with_extension("lits"));
+    let mut solver = Solver::with_options(SolverOptions {
+        proof,
+        ..Default::default()
+    });
+
+    let x = solver.new_bounded_integer(0, 5);
+    assert!(solver.fix_integer(x, 3).is_ok());
+
+    let statistics = solver.proof_statistics().unwrap();
+    drop(solver);
+    let _ = read_and_remove_proof(&path);
+
+    assert_eq!(statistics.inference_steps, 1);
+    assert_eq!(statistics.nogood_steps, 1);
+}
+
+#[test]
+fn compacted_bounds_are_logged_to_proof() {
+    let path = std::env::temp_dir().join(format!(
+        "munchkin-compact-domains-{}.drcp",
+        std::process::id()
+    ));
+    let proof = Proof::new(File::create(&path).unwrap(), path.with_extension("lits"));
+    let mut solver = Solver::with_options(SolverOptions {
+        proof,
+        ..Default::default()
+    });
+
+    let x = solver.new_bounded_integer(0, 100);
+    let result = solver
+        .add_constraint(constraints::less_than_or_equals([x], 50))
+        .post(NonZero::new(1).unwrap());
+    assert!(result.is_ok());
+    assert!(solver.compact_domains().is_ok());
+
+    let statistics = solver.proof_statistics().unwrap();
+    drop(solver);
+    let _ = read_and_remove_proof(&path);
+
+    // The propagation while posting is logged as an inference and a nogood, and the
+    // compaction logs the upper bound as a fact
+    assert_eq!(statistics.inference_steps, 1);
+    assert_eq!(statistics.nogood_steps, 2);
+}
@@ -0,0 +1,368 @@
+#![cfg(test)]
+
+use std::num::NonZero;
+
+use crate::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
+use crate::branching::InDomainMin;
+use crate::branching::InputOrder;
+use crate::constraints;
+use crate::options::ProbingOptions;
+use crate::options::SolverOptions;
+use crate::predicate;
+use crate::predicates::Bound;
+use crate::results::SatisfactionResult;
+use crate::results::SatisfactionResultUnderAssumptions;
+use crate::termination::Indefinite;
+use crate::tests::harness::enumerate_solutions;
+use crate::variables::DomainId;
+use crate::ConstraintOperationError;
+use crate::Solver;
+
+/// Creates a solver in which `[x <= 5]` implies both a literal and its negation; since this is
+/// not detected by propagation alone, `x` is only fixed to `[6, 10]` by probing.
+fn solver_with_failed_literal(root_probing: ProbingOptions) -> (Solver, DomainId) {
+    let mut solver = Solver::with_options(SolverOptions {
+        root_probing,
+        ..Default::default()
+    });
+    let x = solver.new_bounded_integer(0, 10);
+    let at_most_five = solver.get_literal(predicate!(x <= 5));
+    let c = solver.new_literal();
+
+    let _ = solver.add_clause([!at_most_five, c]);
+    let _ = solver.add_clause([!at_most_five, !c]);
+
+    (solver, x)
+}
+
+/// Solves without making decisions, which leaves variables unfixed; this is done under (empty)
+/// assumptions since [`Solver::satisfy`] checks that every variable is fixed in debug builds.
+fn solve_without_decisions(solver: &mut Solver) {
+    let mut brancher =
+        IndependentVariableValueBrancher::new(InputOrder::<DomainId>::new(vec![]), InDomainMin);
+    let result = solver.satisfy_under_assumptions(&mut brancher, &mut Indefinite, &[]);
+    assert!(matches!(
+        result,
+        SatisfactionResultUnderAssumptions::Satisfiable(_)
+    ));
+}
+
+#[test]
+fn root_probing_asserts_negation_of_failed_probe() {
+    let (mut solver, x) = solver_with_failed_literal(ProbingOptions {
+        enabled: true,
+        ..Default::default()
+    });
+    assert_eq!(solver.lower_bound(&x), 0);
+
+    solve_without_decisions(&mut solver);
+
+    assert_eq!(solver.lower_bound(&x), 6);
+    assert_eq!(solver.upper_bound(&x), 10);
+    assert_eq!(solver.statistics().decisions, 0);
+}
+
+#[test]
+fn root_probing_is_disabled_by_default() {
+    let (mut solver, x) = solver_with_failed_literal(ProbingOptions::default());
+
+    solve_without_decisions(&mut solver);
+
+    assert_eq!(solver.lower_bound(&x), 0);
+}
+
+#[test]
+fn root_probing_respects_the_probe_budget() {
+    let (mut solver, x) = solver_with_failed_literal(ProbingOptions {
+        enabled: true,
+        max_probes: 0,
+        ..Default::default()
+    });
+
+    solve_without_decisions(&mut solver);
+
+    assert_eq!(solver.lower_bound(&x), 0);
+}
+
+#[test]
+fn root_facts_are_exported_unless_they_follow_from_the_initial_domain() {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 10);
+    let y = solver.new_sparse_integer(vec![0, 2, 4, 6]);
+    let _ = solver
+        .add_constraint(constraints::sum([x]).greater_than_or_equals(3))
+        .post(NonZero::new(1).unwrap());
+    let _ = solver
+        .add_constraint(constraints::not_equals([x], 5))
+        .post(NonZero::new(2).unwrap());
+    let _ = solver
+        .add_constraint(constraints::less_than_or_equals([y], 5))
+        .post(NonZero::new(3).unwrap());
+
+    assert_eq!(
+        solver.export_learned_unit_facts(&[x, y]),
+        vec![predicate![x >= 3], predicate![x != 5], predicate![y <= 4]]
+    );
+}
+
+#[test]
+fn imported_facts_are_entailed_and_reduce_the_search() {
+    // The variables are pairwise different with a sum of at least 14, which entails that each
+    // of them is at least 2; this is not detected at the root
+    let create_solver = || {
+        let mut solver = Solver::default();
+        let variables = (0..4)
+            .map(|_| solver.new_bounded_integer(0, 5))
+            .collect::<Vec<_>>();
+        for (index, &variable) in variables.iter().enumerate() {
+            for &other in &variables[index + 1..] {
+                let _ = solver
+                    .add_constraint(constraints::binary_not_equals(variable, other))
+                    .post(NonZero::new(1).unwrap());
+            }
+        }
+        let _ = solver
+            .add_constraint(constraints::sum(variables.clone()).greater_than_or_equals(14))
+            .post(NonZero::new(2).unwrap());
+        (solver, variables)
+    };
+
+    // The main solver has additionally derived the entailed lower bounds
+    let (mut main_solver, variables) = create_solver();
+    for &variable in &variables {
+        let _ = main_solver
+            .add_constraint(constraints::sum([variable]).greater_than_or_equals(2))
+            .post(NonZero::new(3).unwrap());
+    }
+    let facts = main_solver.export_learned_unit_facts(&variables);
+    assert_eq!(facts.len(), variables.len());
+
+    let solve = |solver: &mut Solver| {
+        let mut brancher =
+            IndependentVariableValueBrancher::new(InputOrder::new(variables.clone()), InDomainMin);
+        let result = solver.satisfy(&mut brancher, &mut Indefinite);
+        assert!(matches!(result, SatisfactionResult::Satisfiable(_)));
+        solver.statistics().conflicts
+    };
+
+    let (mut without_facts, _) = create_solver();
+    let (mut with_facts, _) = create_solver();
+    assert!(with_facts.import_facts(&facts).is_ok());
+    assert!(variables
+        .iter()
+        .all(|variable| with_facts.lower_bound(variable) == 2));
+
+    let conflicts_without_facts = solve(&mut without_facts);
+    let conflicts_with_facts = solve(&mut with_facts);
+    assert!(
+        conflicts_with_facts < conflicts_without_facts,
+        "{conflicts_with_facts} conflicts with the facts, {conflicts_without_facts} without"
+    );
+}
+
+#[test]
+fn importing_contradictory_facts_fails() {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 10);
+
+    assert!(solver
+        .import_facts(&[predicate![x >= 5], predicate![x <= 4]])
+        .is_err());
+}
+
+#[test]
+fn rolling_back_removes_contradictory_clause() {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 3);
+    let y = solver.new_bounded_integer(0, 3);
+    let _ = solver
+        .add_constraint(constraints::binary_not_equals(x, y))
+        .post(NonZero::new(1).unwrap());
+
+    let snapshot = solver.snapshot();
+    // The blocking clauses which are added while counting are removed again by rolling back
+    assert_eq!(enumerate_solutions(&mut solver, &[x, y]).len(), 12);
+    solver.rollback_to(snapshot);
+
+    let _ = solver.add_clause([solver.get_literal(predicate!(x <= 1))]);
+    assert!(solver
+        .add_clause([solver.get_literal(predicate!(x >= 2))])
+        .is_err());
+    let mut brancher =
+        IndependentVariableValueBrancher::new(InputOrder::new(vec![x, y]), InDomainMin);
+    assert!(matches!(
+        solver.satisfy(&mut brancher, &mut Indefinite),
+        SatisfactionResult::Unsatisfiable
+    ));
+
+    solver.rollback_to(snapshot);
+
+    assert_eq!(solver.lower_bound(&x), 0);
+    assert_eq!(solver.upper_bound(&x), 3);
+    assert_eq!(enumerate_solutions(&mut solver, &[x, y]).len(), 12);
+}
+
+#[test]
+fn variables_created_after_snapshot_are_unconstrained_after_rolling_back() {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 2);
+    let y = solver.new_bounded_integer(0, 2);
+    let _ = solver
+        .add_constraint(constraints::binary_not_equals(x, y))
+        .post(NonZero::new(1).unwrap());
+
+    let snapshot = solver.snapshot();
+    let z = solver.new_bounded_integer(0, 3);
+    let _ = solver
+        .add_constraint(constraints::binary_equals(x, z))
+        .post(NonZero::new(2).unwrap());
+    let _ = solver
+        .add_constraint(constraints::less_than_or_equals([y], 0))
+        .post(NonZero::new(3).unwrap());
+    assert_eq!(enumerate_solutions(&mut solver, &[x, y, z]).len(), 2);
+
+    solver.rollback_to(snapshot);
+
+    assert_eq!(enumerate_solutions(&mut solver, &[x, y, z]).len(), 6 * 4);
+}
+
+#[test]
+fn rolling_back_invalidates_later_snapshots_but_not_earlier_ones() {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 4);
+
+    let first = solver.snapshot();
+    let _ = solver.add_clause([solver.get_literal(predicate!(x <= 2))]);
+    let _ = solver.snapshot();
+    let _ = solver.add_clause([solver.get_literal(predicate!(x >= 1))]);
+
+    solver.rollback_to(first);
+    assert_eq!(enumerate_solutions(&mut solver, &[x]).len(), 5);
+
+    solver.rollback_to(first);
+    let _ = solver.add_clause([solver.get_literal(predicate!(x >= 4))]);
+    assert_eq!(enumerate_solutions(&mut solver, &[x]).len(), 1);
+}
+
+#[test]
+fn propagate_reports_the_tightened_bounds_and_fixed_literals() {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 5);
+    let y = solver.new_bounded_integer(0, 5);
+    let a = solver.new_literal();
+    let b = solver.new_literal();
+    let _ = solver
+        .add_constraint(constraints::less_than_or_equals([x, y], 3))
+        .post(NonZero::new(1).unwrap());
+    let _ = solver
+        .add_constraint(constraints::sum([x]).greater_than_or_equals(2))
+        .post(NonZero::new(2).unwrap());
+
+    let summary = solver.propagate().expect("the root is feasible");
+    assert_eq!(
+        summary.tightened_bounds,
+        vec![predicate![x >= 2], predicate![x <= 3], predicate![y <= 1]]
+    );
+    assert!(summary.fixed_literals.is_empty());
+
+    let _ = solver.add_clause([a]);
+    let _ = solver.add_clause([!a, !b]);
+
+    let summary = solver.propagate().expect("the root is feasible");
+    assert!(summary.tightened_bounds.contains(&predicate![y <= 1]));
+    assert_eq!(summary.fixed_literals, vec![a, !b]);
+}
+
+#[test]
+fn propagate_reports_infeasibility_at_the_root() {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 5);
+    let _ = solver
+        .add_constraint(constraints::sum([x]).greater_than_or_equals(3))
+        .post(NonZero::new(1).unwrap());
+    let _ = solver
+        .add_constraint(constraints::less_than_or_equals([x], 2))
+        .post(NonZero::new(2).unwrap());
+
+    assert!(solver.propagate().is_err());
+
+    let mut brancher = IndependentVariableValueBrancher::new(InputOrder::new(vec![x]), InDomainMin);
+    let result = solver.satisfy(&mut brancher, &mut Indefinite);
+    assert!(matches!(result, SatisfactionResult::Unsatisfiable));
+}
+
+#[test]
+fn fixing_integer_outside_initial_domain_returns_descriptive_error() {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 10);
+    let y = solver.new_sparse_integer(vec![1, 3, 5]);
+
+    let result = solver.fix_integer(x, 11);
+    let Err(error) = result else {
+        panic!("11 is not in the domain of x");
+    };
+    assert!(matches!(
+        error,
+        ConstraintOperationError::ValueOutsideDomain {
+            variable,
+            value: 11,
+            lower_bound: 0,
+            upper_bound: 10,
+        } if variable == x
+    ));
+    assert_eq!(
+        error.to_string(),
+        format!(
+            "The value 11 is outside of the initial domain of {x}, which has the bounds [0, 10]"
+        )
+    );
+    assert!(matches!(
+        solver.fix_integer(y, 2),
+        Err(ConstraintOperationError::ValueOutsideDomain { value: 2, .. })
+    ));
+
+    let mut brancher =
+        IndependentVariableValueBrancher::new(InputOrder::new(vec![x, y]), InDomainMin);
+    let result = solver.satisfy(&mut brancher, &mut Indefinite);
+    assert!(matches!(result, SatisfactionResult::Satisfiable(_)));
+}
+
+#[test]
+fn fixing_integer_is_reflected_in_bounds() {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 10);
+    let y = solver.new_bounded_integer(0, 10);
+    let _ = solver
+        .add_constraint(constraints::less_than_or_equals([x, y], 10))
+        .post(NonZero::new(1).unwrap());
+
+    assert!(solver.fix_integer(x, 7).is_ok());
+    assert_eq!(solver.lower_bound(&x), 7);
+    assert_eq!(solver.upper_bound(&x), 7);
+    assert_eq!(solver.upper_bound(&y), 3);
+}
+
+#[test]
+fn adding_bounds_is_checked_against_initial_domain() {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 10);
+
+    assert!(solver.add_bound(x, Bound::Lower(3)).is_ok());
+    assert!(solver.add_bound(x, Bound::Upper(8)).is_ok());
+    assert_eq!(solver.lower_bound(&x), 3);
+    assert_eq!(solver.upper_bound(&x), 8);
+
+    assert!(matches!(
+        solver.add_bound(x, Bound::Upper(-1)),
+        Err(ConstraintOperationError::ValueOutsideDomain { value: -1, .. })
+    ));
+    assert!(matches!(
+        solver.add_bound(x, Bound::Lower(11)),
+        Err(ConstraintOperationError::ValueOutsideDomain { value: 11, .. })
+    ));
+    // A bound which is within the initial domain but conflicts with the root is infeasible
+    assert!(matches!(
+        solver.add_bound(x, Bound::Upper(2)),
+        Err(ConstraintOperationError::InfeasibleClause)
+    ));
+}
@@ -0,0 +1,537 @@
+#![cfg(test)]
+
+use std::num::NonZero;
+use std::time::Duration;
+
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::basic_types::Solution;
+use crate::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
+use crate::branching::branchers::propagator_guided_brancher::PropagatorGuidedBrancher;
+use crate::branching::Brancher;
+use crate::branching::InDomainMin;
+use crate::branching::InputOrder;
+use crate::constraints;
+use crate::engine::cp::propagation::PropagationContext;
+use crate::engine::cp::propagation::PropagationContextMut;
+use crate::engine::cp::propagation::Propagator;
+use crate::engine::cp::propagation::PropagatorInitialisationContext;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::predicate;
+use crate::predicates::Predicate;
+use crate::results::solution_iterator::IteratedSolution;
+use crate::results::solution_iterator::SolutionCount;
+use crate::results::BoundedSolveResult;
+use crate::results::ProblemSolution;
+use crate::results::SatisfactionResult;
+use crate::results::SatisfactionResultUnderAssumptions;
+use crate::results::UnfixedVariable;
+use crate::termination::ConflictBudget;
+use crate::termination::Indefinite;
+use crate::termination::MemoryBudget;
+use crate::termination::TerminationCondition;
+use crate::termination::TerminationReason;
+use crate::termination::TimeBudget;
+use crate::variables::DomainId;
+use crate::variables::Literal;
+use crate::variables::PropositionalVariable;
+use crate::variables::TransformableVariable;
+use crate::ConstraintOperationError;
+use crate::Solver;
+
+pub(crate) fn propositional_brancher(
+    literals: &[Literal],
+) -> IndependentVariableValueBrancher<
+    PropositionalVariable,
+    InputOrder<PropositionalVariable>,
+    InDomainMin,
+> {
+    IndependentVariableValueBrancher::new(
+        InputOrder::new(
+            literals
+                .iter()
+                .map(|literal| literal.get_propositional_variable())
+                .collect(),
+        ),
+        InDomainMin,
+    )
+}
+
+/// Creates a solver in which three variables with domain `[0, size - 1]` are pairwise
+/// different, which has `size * (size - 1) * (size - 2)` solutions.
+fn pairwise_different_solver(size: i32) -> (Solver, Vec<DomainId>) {
+    let mut solver = Solver::default();
+    let variables = (0..3)
+        .map(|_| solver.new_bounded_integer(0, size - 1))
+        .collect::<Vec<_>>();
+    for (i, j) in [(0, 1), (0, 2), (1, 2)] {
+        let _ = solver
+            .add_constraint(constraints::binary_not_equals(variables[i], variables[j]))
+            .post(NonZero::new(1).unwrap());
+    }
+    (solver, variables)
+}
+
+#[test]
+fn count_remaining_counts_all_solutions() {
+    let (mut solver, variables) = pairwise_different_solver(3);
+    let mut brancher =
+        IndependentVariableValueBrancher::new(InputOrder::new(variables), InDomainMin);
+    let mut termination = Indefinite;
+
+    let solution_iterator = solver.get_solution_iterator(&mut brancher, &mut termination);
+
+    assert_eq!(solution_iterator.count_remaining(), SolutionCount::Exact(6));
+}
+
+#[test]
+fn count_remaining_excludes_iterated_solutions() {
+    let (mut solver, variables) = pairwise_different_solver(3);
+    let mut brancher =
+        IndependentVariableValueBrancher::new(InputOrder::new(variables), InDomainMin);
+    let mut termination = Indefinite;
+
+    let mut solution_iterator = solver.get_solution_iterator(&mut brancher, &mut termination);
+    assert!(matches!(
+        solution_iterator.next_solution(),
+        IteratedSolution::Solution(_)
+    ));
+
+    assert_eq!(solution_iterator.count_remaining(), SolutionCount::Exact(5));
+}
+
+#[test]
+fn count_up_to_stops_at_the_cap() {
+    let (mut solver, variables) = pairwise_different_solver(3);
+    let mut brancher =
+        IndependentVariableValueBrancher::new(InputOrder::new(variables.clone()), InDomainMin);
+    let mut termination = Indefinite;
+
+    let solution_iterator = solver.get_solution_iterator(&mut brancher, &mut termination);
+    assert_eq!(solution_iterator.count_up_to(4), SolutionCount::AtLeast(4));
+
+    let (mut solver, variables) = pairwise_different_solver(3);
+    let mut brancher =
+        IndependentVariableValueBrancher::new(InputOrder::new(variables), InDomainMin);
+    let solution_iterator = solver.get_solution_iterator(&mut brancher, &mut termination);
+    assert_eq!(solution_iterator.count_up_to(10), SolutionCount::Exact(6));
+}
+
+#[test]
+fn count_of_unsatisfiable_problem_is_zero() {
+    let (mut solver, variables) = pairwise_different_solver(2);
+    let mut brancher =
+        IndependentVariableValueBrancher::new(InputOrder::new(variables), InDomainMin);
+    let mut termination = Indefinite;
+
+    let solution_iterator = solver.get_solution_iterator(&mut brancher, &mut termination);
+
+    assert_eq!(solution_iterator.count_remaining(), SolutionCount::Exact(0));
+}
+
+/// A [`TerminationCondition`] which triggers after it has been polled a given number of times.
+#[derive(Debug)]
+struct AfterPolls(u32);
+
+impl TerminationCondition for AfterPolls {
+    fn should_stop(&mut self) -> bool {
+        self.0 = self.0.saturating_sub(1);
+        self.0 == 0
+    }
+}
+
+#[test]
+fn count_is_unknown_when_terminated() {
+    let (mut solver, variables) = pairwise_different_solver(3);
+    let mut brancher =
+        IndependentVariableValueBrancher::new(InputOrder::new(variables), InDomainMin);
+    let mut termination = AfterPolls(5);
+
+    let solution_iterator = solver.get_solution_iterator(&mut brancher, &mut termination);
+
+    let SolutionCount::Unknown(num_solutions) = solution_iterator.count_remaining() else {
+        panic!("expected the count to be interrupted");
+    };
+    assert!(num_solutions < 6);
+}
+
+/// Creates the clauses of the unsatisfiable problem of placing `num_holes + 1` pigeons into
+/// `num_holes` holes, and returns the variables indicating the placement of the pigeons.
+pub(crate) fn pigeon_hole_solver(num_holes: usize) -> (Solver, Vec<Literal>) {
+    let mut solver = Solver::default();
+    let placements = (0..(num_holes + 1) * num_holes)
+        .map(|_| solver.new_literal())
+        .collect::<Vec<_>>();
+    let placement = |pigeon: usize, hole: usize| placements[pigeon * num_holes + hole];
+
+    for pigeon in 0..=num_holes {
+        let _ = solver.add_clause((0..num_holes).map(|hole| placement(pigeon, hole)));
+    }
+    for hole in 0..num_holes {
+        for pigeon in 0..=num_holes {
+            for other in pigeon + 1..=num_holes {
+                let _ = solver.add_clause([!placement(pigeon, hole), !placement(other, hole)]);
+            }
+        }
+    }
+
+    (solver, placements)
+}
+
+/// Creates the clauses of placing `n` non-attacking queens on an `n` by `n` board, and returns
+/// the variables indicating whether a square contains a queen.
+fn queens_solver(n: usize) -> (Solver, Vec<Literal>) {
+    let mut solver = Solver::default();
+    let squares = (0..n * n).map(|_| solver.new_literal()).collect::<Vec<_>>();
+
+    for row in 0..n {
+        let _ = solver.add_clause((0..n).map(|column| squares[row * n + column]));
+    }
+    for (square, other) in
+        (0..n * n).flat_map(|square| (square + 1..n * n).map(move |other| (square, other)))
+    {
+        let (row, column) = ((square / n) as i32, (square % n) as i32);
+        let (other_row, other_column) = ((other / n) as i32, (other % n) as i32);
+        if row == other_row
+            || column == other_column
+            || (row - other_row).abs() == (column - other_column).abs()
+        {
+            let _ = solver.add_clause([!squares[square], !squares[other]]);
+        }
+    }
+
+    (solver, squares)
+}
+
+/// Solves the problem with [`Solver::solve_bounded`] and [`Solver::resume_bounded`], using the
+/// same decision limit for every call; returns the result and the number of suspensions.
+fn solve_with_suspensions(
+    solver: &mut Solver,
+    literals: &[Literal],
+    decision_limit: u64,
+) -> (BoundedSolveResult, usize) {
+    let mut brancher = propositional_brancher(literals);
+    let mut num_suspensions = 0;
+
+    let mut result = solver.solve_bounded(&mut brancher, &mut Indefinite, decision_limit);
+    while let BoundedSolveResult::Suspended(suspended_search) = result {
+        num_suspensions += 1;
+        result = solver.resume_bounded(
+            suspended_search,
+            &mut brancher,
+            &mut Indefinite,
+            decision_limit,
+        );
+    }
+
+    (result, num_suspensions)
+}
+
+#[test]
+fn resumed_search_finds_the_same_solution_as_uninterrupted_search() {
+    let (mut solver, squares) = queens_solver(8);
+    let mut brancher = propositional_brancher(&squares);
+    let expected = solver
+        .satisfy(&mut brancher, &mut Indefinite)
+        .into_solution()
+        .expect("there are 8 non-attacking queens");
+    let num_decisions = solver.statistics().decisions;
+    assert!(num_decisions >= 8);
+
+    let (mut solver, squares) = queens_solver(8);
+    let (result, num_suspensions) =
+        solve_with_suspensions(&mut solver, &squares, num_decisions.div_ceil(4));
+
+    let BoundedSolveResult::Satisfiable(solution) = result else {
+        panic!("expected the resumed search to find a solution");
+    };
+    assert_eq!(num_suspensions, 3);
+    assert_eq!(solver.statistics().decisions, num_decisions);
+    for &square in &squares {
+        assert_eq!(
+            solution.get_literal_value(square),
+            expected.get_literal_value(square)
+        );
+    }
+}
+
+#[test]
+fn resumed_search_proves_unsatisfiability_with_the_same_decisions() {
+    let (mut solver, placements) = pigeon_hole_solver(4);
+    let mut brancher = propositional_brancher(&placements);
+    let result = solver.satisfy(&mut brancher, &mut Indefinite);
+    assert!(matches!(result, SatisfactionResult::Unsatisfiable));
+    let num_decisions = solver.statistics().decisions;
+
+    let (mut solver, placements) = pigeon_hole_solver(4);
+    let (result, num_suspensions) =
+        solve_with_suspensions(&mut solver, &placements, num_decisions.div_ceil(4));
+
+    assert!(matches!(result, BoundedSolveResult::Unsatisfiable));
+    assert_eq!(num_suspensions, 3);
+    assert_eq!(solver.statistics().decisions, num_decisions);
+}
+
+#[test]
+fn constraints_cannot_be_added_while_search_is_suspended() {
+    let (mut solver, squares) = queens_solver(4);
+    let mut brancher = propositional_brancher(&squares);
+
+    let BoundedSolveResult::Suspended(suspended_search) =
+        solver.solve_bounded(&mut brancher, &mut Indefinite, 1)
+    else {
+        panic!("expected the search to be suspended after a single decision");
+    };
+    assert_eq!(suspended_search.num_decisions(), 1);
+    assert!(matches!(
+        solver.add_clause([squares[0], squares[1]]),
+        Err(ConstraintOperationError::SuspendedSearch)
+    ));
+
+    solver.abandon_bounded(suspended_search, &mut brancher);
+    assert!(solver.add_clause([squares[0], squares[1]]).is_ok());
+}
+
+#[test]
+#[should_panic(expected = "the suspended search has been abandoned")]
+fn abandoned_search_cannot_be_resumed() {
+    let (mut solver, squares) = queens_solver(4);
+    let mut brancher = propositional_brancher(&squares);
+
+    let BoundedSolveResult::Suspended(abandoned_search) =
+        solver.solve_bounded(&mut brancher, &mut Indefinite, 1)
+    else {
+        panic!("expected the search to be suspended after a single decision");
+    };
+    let _ = solver.solve_bounded(&mut brancher, &mut Indefinite, 1);
+
+    let _ = solver.resume_bounded(abandoned_search, &mut brancher, &mut Indefinite, 1);
+}
+
+#[test]
+fn conflict_budget_is_reported_as_termination_reason() {
+    let (mut solver, placements) = pigeon_hole_solver(5);
+    let mut brancher = propositional_brancher(&placements);
+    let mut termination = ConflictBudget::with_limit(3);
+
+    let result = solver.satisfy(&mut brancher, &mut termination);
+
+    assert!(matches!(
+        result,
+        SatisfactionResult::Unknown(TerminationReason::ConflictBudget)
+    ));
+    assert_eq!(solver.statistics().conflicts, 3);
+}
+
+#[test]
+fn time_budget_is_reported_as_termination_reason() {
+    let (mut solver, placements) = pigeon_hole_solver(5);
+    let mut brancher = propositional_brancher(&placements);
+    let mut termination = TimeBudget::starting_now(Duration::ZERO);
+
+    {
+        let result = solver.satisfy_under_assumptions(&mut brancher, &mut termination, &[]);
+        assert!(matches!(
+            result,
+            SatisfactionResultUnderAssumptions::Unknown(TerminationReason::TimeBudget)
+        ));
+    }
+
+    let mut solution_iterator = solver.get_solution_iterator(&mut brancher, &mut termination);
+    assert!(matches!(
+        solution_iterator.next_solution(),
+        IteratedSolution::Unknown(TerminationReason::TimeBudget)
+    ));
+}
+
+/// A [`TerminationCondition`] which does not report why it triggered.
+struct Immediately;
+
+impl TerminationCondition for Immediately {
+    fn should_stop(&mut self) -> bool {
+        true
+    }
+}
+
+#[test]
+fn termination_without_reason_is_unspecified() {
+    let (mut solver, placements) = pigeon_hole_solver(5);
+    let mut brancher = propositional_brancher(&placements);
+
+    let result = solver.satisfy(&mut brancher, &mut Immediately);
+
+    assert!(matches!(
+        result,
+        SatisfactionResult::Unknown(TerminationReason::Unspecified)
+    ));
+}
+
+#[test]
+fn boxed_brancher_and_termination_solve_the_problem() {
+    let (mut solver, placements) = pigeon_hole_solver(4);
+    let mut brancher: Box<dyn Brancher> = Box::new(propositional_brancher(&placements));
+    let mut termination: Box<dyn TerminationCondition> = Box::new(Indefinite);
+
+    let result = solver.satisfy(&mut brancher, &mut termination);
+
+    assert!(matches!(result, SatisfactionResult::Unsatisfiable));
+}
+
+#[test]
+fn boxed_termination_reports_the_reason_of_the_boxed_condition() {
+    let (mut solver, placements) = pigeon_hole_solver(5);
+    let mut brancher: Box<dyn Brancher> = Box::new(propositional_brancher(&placements));
+    let mut termination: Box<dyn TerminationCondition> = Box::new(ConflictBudget::with_limit(1));
+
+    let result = solver.satisfy(&mut brancher, &mut termination);
+
+    assert!(matches!(
+        result,
+        SatisfactionResult::Unknown(TerminationReason::ConflictBudget)
+    ));
+}
+
+#[test]
+fn large_memory_budget_does_not_terminate_the_search() {
+    let (mut solver, placements) = pigeon_hole_solver(5);
+    let mut brancher = propositional_brancher(&placements);
+    let mut termination = MemoryBudget::with_limit_bytes(usize::MAX);
+
+    let result = solver.satisfy(&mut brancher, &mut termination);
+
+    assert!(matches!(result, SatisfactionResult::Unsatisfiable));
+    assert_eq!(termination.describe_trigger(), None);
+}
+
+/// Creates a solver with the variable `x` and the unconstrained variable `y`, together with a
+/// brancher which only branches over `x`.
+fn solver_with_ignored_variable() -> (
+    Solver,
+    IndependentVariableValueBrancher<DomainId, InputOrder<DomainId>, InDomainMin>,
+    DomainId,
+    DomainId,
+) {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(1, 3);
+    let y = solver.new_bounded_integer(-2, 5);
+    let brancher = IndependentVariableValueBrancher::new(InputOrder::new(vec![x]), InDomainMin);
+
+    (solver, brancher, x, y)
+}
+
+#[test]
+fn unfixed_variables_in_a_solution_are_reported() {
+    let (mut solver, mut brancher, x, y) = solver_with_ignored_variable();
+
+    let SatisfactionResultUnderAssumptions::Satisfiable(solution) =
+        solver.satisfy_under_assumptions(&mut brancher, &mut Indefinite, &[])
+    else {
+        panic!("the problem should be satisfiable");
+    };
+
+    assert!(ProblemSolution::is_fixed(&solution, x));
+    assert_eq!(solution.get_integer_value(x), 1);
+    assert_eq!(solution.try_get_integer_value(x), Ok(1));
+
+    assert!(!ProblemSolution::is_fixed(&solution, y));
+    assert_eq!(solution.get_integer_value(y), -2);
+    assert_eq!(
+        solution.try_get_integer_value(y),
+        Err(UnfixedVariable {
+            lower_bound: -2,
+            upper_bound: 5
+        })
+    );
+    assert_eq!(
+        solution
+            .try_get_integer_value(y.scaled(-1))
+            .unwrap_err()
+            .lower_bound,
+        -5
+    );
+}
+
+/// Suggests the decision `[x == 2]` while `x` is unfixed, and `[y == 1]` afterwards.
+struct HintingPropagator {
+    x: DomainId,
+    y: DomainId,
+}
+
+impl Propagator for HintingPropagator {
+    fn name(&self) -> &str {
+        "HintingPropagator"
+    }
+
+    fn propagate(&self, _context: PropagationContextMut) -> PropagationStatusCP {
+        Ok(())
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        _context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        Ok(())
+    }
+
+    fn next_decision(&self, context: PropagationContext) -> Option<Predicate> {
+        let (x, y) = (self.x, self.y);
+        if context.is_fixed(&x) {
+            Some(predicate![y == 1])
+        } else {
+            Some(predicate![x == 2])
+        }
+    }
+}
+
+fn first_guided_solution(solver: &mut Solver, variables: Vec<DomainId>) -> Solution {
+    let mut brancher = PropagatorGuidedBrancher::new(
+        solver.propagator_registry(),
+        IndependentVariableValueBrancher::new(InputOrder::new(variables), InDomainMin),
+    );
+    let mut termination = Indefinite;
+    let mut solution_iterator = solver.get_solution_iterator(&mut brancher, &mut termination);
+    match solution_iterator.next_solution() {
+        IteratedSolution::Solution(solution) => solution.into(),
+        _ => panic!("expected the problem to be satisfiable"),
+    }
+}
+
+#[test]
+fn propagator_decisions_guide_the_search() {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 5);
+    let y = solver.new_bounded_integer(0, 5);
+    let _ = solver.add_propagator(HintingPropagator { x, y }, NonZero::new(1).unwrap());
+
+    let registry = solver.propagator_registry();
+    assert_eq!(registry.len(), 1);
+
+    let solution = first_guided_solution(&mut solver, vec![x, y]);
+    assert_eq!(solution.get_integer_value(x), 2);
+    assert_eq!(solution.get_integer_value(y), 1);
+}
+
+#[test]
+fn assigned_propagator_decisions_are_skipped() {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 5);
+    let y = solver.new_bounded_integer(0, 5);
+    let _ = solver.add_propagator(HintingPropagator { x, y }, NonZero::new(1).unwrap());
+    // The suggestion `[x == 2]` is falsified at the root, so the inner brancher decides on `x`;
+    // once `x` is fixed, the suggestion `[y == 1]` is followed.
+    let _ = solver
+        .add_constraint(constraints::less_than_or_equals([x], 1))
+        .post(NonZero::new(2).unwrap());
+
+    let solution = first_guided_solution(&mut solver, vec![x, y]);
+    assert_eq!(solution.get_integer_value(x), 0);
+    assert_eq!(solution.get_integer_value(y), 1);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "the brancher does not branch over it")]
+fn satisfy_checks_that_all_variables_are_fixed() {
+    let (mut solver, mut brancher, _, _) = solver_with_ignored_variable();
+    let _ = solver.satisfy(&mut brancher, &mut Indefinite);
+}
@@ -0,0 +1,130 @@
+#![cfg(test)]
+
+use std::num::NonZero;
+
+use crate::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
+use crate::branching::InDomainMin;
+use crate::branching::InputOrder;
+use crate::constraints;
+use crate::results::SatisfactionResultUnderAssumptions;
+use crate::statistics::ModelSummary;
+use crate::statistics::SearchStatistics;
+use crate::termination::Indefinite;
+use crate::Solver;
+
+#[test]
+fn conflicts_increase_monotonically_across_solve_calls() {
+    let mut solver = Solver::default();
+    let b = solver.new_literal();
+    let c = solver.new_literal();
+
+    let _ = solver.add_clause([b, c]);
+    let _ = solver.add_clause([b, !c]);
+    let _ = solver.add_clause([!b, c]);
+    let _ = solver.add_clause([!b, !c]);
+
+    let mut brancher = IndependentVariableValueBrancher::new(
+        InputOrder::new(vec![
+            b.get_propositional_variable(),
+            c.get_propositional_variable(),
+        ]),
+        InDomainMin,
+    );
+
+    let is_unsatisfiable = matches!(
+        solver.satisfy_under_assumptions(&mut brancher, &mut Indefinite, &[]),
+        SatisfactionResultUnderAssumptions::Unsatisfiable
+    );
+    assert!(is_unsatisfiable);
+    let first = solver.statistics();
+    assert!(first.conflicts > 0);
+    assert!(first.peak_decision_level > 0);
+
+    let _ = solver.satisfy_under_assumptions(&mut brancher, &mut Indefinite, &[]);
+    let second = solver.statistics();
+    assert!(second.conflicts >= first.conflicts);
+    assert!(second.decisions >= first.decisions);
+}
+
+#[test]
+fn statistics_snapshot_is_copy() {
+    fn assert_copy<T: Copy>() {}
+    assert_copy::<SearchStatistics>();
+
+    let solver = Solver::default();
+    let snapshot = solver.statistics();
+    let copy = snapshot;
+    assert_eq!(snapshot, copy);
+}
+
+#[test]
+fn model_summary_reports_the_size_of_the_problem() {
+    let mut solver = Solver::default();
+
+    // A domain with `n` values is encoded using `n - 1` lower-bound literals and `n - 2`
+    // equality literals for the values strictly between the bounds
+    let x = solver.new_bounded_integer(0, 3);
+    let y = solver.new_bounded_integer(1, 5);
+    let a = solver.new_literal();
+    let b = solver.new_literal();
+    let c = solver.new_literal();
+
+    let num_encoding_clauses = solver.model_summary().num_original_clauses;
+
+    let tag = NonZero::new(1).unwrap();
+    let _ = solver
+        .add_constraint(constraints::binary_less_than(x, y, 0))
+        .post(tag);
+    let _ = solver
+        .add_constraint(constraints::binary_less_than(y, x, -5))
+        .post(tag);
+    let _ = solver
+        .add_constraint(constraints::less_than_or_equals([x, y], 6))
+        .post(tag);
+
+    let _ = solver.add_clause([a, b]);
+    let _ = solver.add_clause([a, b, c]);
+    // Unit clauses are applied at the root rather than stored
+    let _ = solver.add_clause([c]);
+
+    let summary = solver.model_summary();
+    assert_eq!(
+        summary,
+        ModelSummary {
+            num_integer_variables: 2,
+            num_user_propositional_variables: 3,
+            num_encoding_propositional_variables: 5 + 7,
+            propagators: vec![("BinaryLeq".to_owned(), 2), ("LinearLeq".to_owned(), 1)],
+            num_original_clauses: num_encoding_clauses + 2,
+            num_learned_clauses: 0,
+        }
+    );
+
+    assert_eq!(solver.num_integer_variables(), 2);
+    // The solver contains one additional variable which is always true
+    assert_eq!(solver.num_propositional_variables(), 3 + 12 + 1);
+    assert_eq!(solver.num_propagators(), 3);
+    assert_eq!(solver.num_clauses(), num_encoding_clauses + 2);
+}
+
+#[test]
+fn model_summary_is_displayed_per_category() {
+    let summary = ModelSummary {
+        num_integer_variables: 2,
+        num_user_propositional_variables: 3,
+        num_encoding_propositional_variables: 12,
+        propagators: vec![("BinaryLeq".to_owned(), 2), ("LinearLeq".to_owned(), 1)],
+        num_original_clauses: 20,
+        num_learned_clauses: 4,
+    };
+
+    assert_eq!(
+        summary.to_string(),
+        "integer variables: 2\n\
+         propositional variables: 3 user, 12 encoding\n\
+         propagators: 3\n  \
+         BinaryLeq: 2\n  \
+         LinearLeq: 1\n\
+         clauses: 20 original, 4 learned"
+    );
+}
@@ -0,0 +1,181 @@
+#![cfg(test)]
+
+use std::num::NonZero;
+
+use crate::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
+use crate::branching::InDomainMin;
+use crate::branching::InputOrder;
+use crate::constraints;
+use crate::predicate;
+use crate::predicates::IntegerPredicate;
+use crate::results::solution_iterator::IteratedSolution;
+use crate::results::ProblemSolution;
+use crate::termination::Indefinite;
+use crate::tests::solver::search::propositional_brancher;
+use crate::Solver;
+
+fn holds(predicate: IntegerPredicate, value: i32) -> bool {
+    match predicate {
+        IntegerPredicate::LowerBound { lower_bound, .. } => value >= lower_bound,
+        IntegerPredicate::UpperBound { upper_bound, .. } => value <= upper_bound,
+        IntegerPredicate::NotEqual {
+            not_equal_constant, ..
+        } => value != not_equal_constant,
+        IntegerPredicate::Equal {
+            equality_constant, ..
+        } => value == equality_constant,
+    }
+}
+
+#[test]
+fn canonical_predicate_round_trips_for_all_predicate_forms() {
+    let mut solver = Solver::default();
+    let (lower_bound, upper_bound) = (-2, 3);
+    let x = solver.new_bounded_integer(lower_bound, upper_bound);
+
+    for value in lower_bound..=upper_bound {
+        let mut predicates = vec![predicate!(x == value), predicate!(x != value)];
+        if value > lower_bound {
+            predicates.push(predicate!(x >= value));
+        }
+        if value < upper_bound {
+            predicates.push(predicate!(x <= value));
+        }
+
+        for predicate in predicates {
+            let predicate: IntegerPredicate = predicate.try_into().unwrap();
+            let literal = solver.get_literal(predicate.into());
+
+            let canonical = solver
+                .get_canonical_predicate(literal)
+                .expect("literal should represent a predicate");
+            assert_eq!(canonical.get_domain(), x);
+            assert!(
+                (lower_bound..=upper_bound)
+                    .all(|value| holds(predicate, value) == holds(canonical, value)),
+                "{canonical} is not equivalent to {predicate}"
+            );
+
+            if predicate.is_equality_predicate() {
+                assert_eq!(canonical, predicate);
+            }
+        }
+    }
+}
+
+#[test]
+fn get_predicates_respects_polarity() {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 5);
+
+    let literal = solver.get_literal(predicate!(x == 2));
+    let positive = solver.get_predicates(literal).collect::<Vec<_>>();
+    let negative = solver.get_predicates(!literal).collect::<Vec<_>>();
+
+    assert_eq!(positive, vec![predicate!(x == 2).try_into().unwrap()]);
+    assert_eq!(negative, vec![predicate!(x != 2).try_into().unwrap()]);
+    assert_eq!(
+        solver.get_canonical_predicate(!literal),
+        Some(predicate!(x != 2).try_into().unwrap())
+    );
+}
+
+#[test]
+fn literals_without_predicates_have_no_canonical_predicate() {
+    let mut solver = Solver::default();
+    let literal = solver.new_literal();
+
+    assert_eq!(solver.get_predicates(literal).count(), 0);
+    assert_eq!(solver.get_canonical_predicate(literal), None);
+}
+
+#[test]
+fn channel_indicators_propagate_to_the_variable() {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 5);
+    let indicators = solver.channel_indicators(x, &[1, 3]);
+
+    let _ = solver.add_clause([indicators[1]]);
+
+    assert_eq!(solver.lower_bound(&x), 3);
+    assert_eq!(solver.upper_bound(&x), 3);
+    assert_eq!(solver.get_literal_value(indicators[0]), Some(false));
+}
+
+#[test]
+fn channel_indicators_are_propagated_by_the_variable() {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 5);
+    let indicators = solver.channel_indicators(x, &[1, 3]);
+
+    let _ = solver.add_clause([solver.get_literal(predicate!(x >= 2))]);
+    assert_eq!(solver.get_literal_value(indicators[0]), Some(false));
+    assert_eq!(solver.get_literal_value(indicators[1]), None);
+
+    let _ = solver.add_clause([solver.get_literal(predicate!(x <= 3))]);
+    let _ = solver.add_clause([solver.get_literal(predicate!(x != 2))]);
+    assert_eq!(solver.get_literal_value(indicators[1]), Some(true));
+}
+
+#[test]
+fn literals_as_integers_in_linear_constraint() {
+    let mut solver = Solver::default();
+    let a = solver.new_literal();
+    let b = solver.new_literal();
+    let c = solver.new_literal();
+
+    // `b` is fixed before its integer variable is created and `a` afterwards
+    let _ = solver.add_clause([b]);
+    let x = solver.literal_as_integer(a);
+    let y = solver.literal_as_integer(b);
+    let z = solver.literal_as_integer(c);
+    let _ = solver.add_clause([a]);
+
+    assert_eq!(solver.lower_bound(&y), 1);
+
+    let _ = solver
+        .add_constraint(constraints::less_than_or_equals([x, y, z], 2))
+        .post(NonZero::new(1).unwrap());
+
+    assert_eq!(solver.lower_bound(&x), 1);
+    assert_eq!(solver.upper_bound(&z), 0);
+
+    let mut brancher = propositional_brancher(&[a, b, c]);
+    let mut termination = Indefinite;
+    let mut solution_iterator = solver.get_solution_iterator(&mut brancher, &mut termination);
+
+    let mut num_solutions = 0;
+    while let IteratedSolution::Solution(solution) = solution_iterator.next_solution() {
+        assert!(solution.get_literal_value(a));
+        assert!(solution.get_literal_value(b));
+        assert!(!solution.get_literal_value(c));
+        assert_eq!(solution.get_integer_value(z), 0);
+        num_solutions += 1;
+    }
+
+    assert_eq!(num_solutions, 1);
+}
+
+#[test]
+fn sparse_integer_with_large_gap_enumerates_its_values() {
+    let mut solver = Solver::default();
+    let x = solver.new_sparse_integer(vec![1_000_000, 0]);
+
+    let mut brancher = IndependentVariableValueBrancher::new(InputOrder::new(vec![x]), InDomainMin);
+    let mut termination = Indefinite;
+    let mut solution_iterator = solver.get_solution_iterator(&mut brancher, &mut termination);
+
+    let mut values = vec![];
+    while let IteratedSolution::Solution(solution) = solution_iterator.next_solution() {
+        values.push(solution.get_integer_value(x));
+    }
+
+    assert_eq!(values, vec![0, 1_000_000]);
+}
+
+#[test]
+#[should_panic(expected = "does not fit in an i32")]
+fn domains_whose_width_does_not_fit_are_rejected() {
+    let mut solver = Solver::default();
+    let _ = solver.new_bounded_integer(-2_000_000_000, 2_000_000_000);
+}
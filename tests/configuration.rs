@@ -0,0 +1,207 @@
+//! Integration tests which exercise the public API of the [`Solver`] (sparse domains, reified
+//! constraints, the solution iterator and solving under assumptions) on the configuration problem
+//! of `examples/configuration.rs`.
+
+use std::num::NonZero;
+
+use munchkin::predicates::Predicate;
+use munchkin::prelude::*;
+use munchkin::results::solution_iterator::IteratedSolution;
+use munchkin::results::SatisfactionResult;
+use munchkin::results::SatisfactionResultUnderAssumptions;
+
+struct Configuration {
+    cores: DomainId,
+    memory: DomainId,
+    gpus: DomainId,
+    workstation: Literal,
+    decision_variables: Vec<DomainId>,
+}
+
+fn create_model(solver: &mut Solver) -> Configuration {
+    let cores = solver.new_named_sparse_integer(vec![2, 4, 8], "cores");
+    let memory = solver.new_named_sparse_integer(vec![8, 16, 32], "memory");
+    let gpus = solver.new_named_bounded_integer(0, 1, "gpus");
+    let workstation = solver.new_named_literal("workstation");
+    let workstation_as_integer = solver.literal_as_integer(workstation);
+
+    solver
+        .add_constraint(constraints::less_than_or_equals(
+            [cores.scaled(-4), memory.scaled(-1)],
+            -64,
+        ))
+        .reify(workstation, NonZero::new(1).unwrap())
+        .expect("the reified constraint is consistent");
+
+    let has_gpu = solver.get_literal(predicate![gpus >= 1]);
+    solver
+        .add_clause([!has_gpu, workstation])
+        .expect("the clause is consistent");
+
+    solver
+        .add_constraint(constraints::less_than_or_equals(
+            [cores.scaled(50), memory.scaled(10), gpus.scaled(400)],
+            1200,
+        ))
+        .post(NonZero::new(2).unwrap())
+        .expect("the budget constraint is consistent");
+
+    Configuration {
+        cores,
+        memory,
+        gpus,
+        workstation,
+        decision_variables: vec![cores, memory, gpus, workstation_as_integer],
+    }
+}
+
+fn brancher(
+    configuration: &Configuration,
+) -> IndependentVariableValueBrancher<DomainId, InputOrder<DomainId>, InDomainMin> {
+    IndependentVariableValueBrancher::new(
+        InputOrder::new(configuration.decision_variables.clone()),
+        InDomainMin,
+    )
+}
+
+/// Returns the (cores, memory, gpus, workstation) of every solution found by the solution
+/// iterator, together with how the iteration ended.
+fn enumerate(
+    solver: &mut Solver,
+    configuration: &Configuration,
+) -> (Vec<(i32, i32, i32, bool)>, &'static str) {
+    let mut brancher = brancher(configuration);
+    let mut termination = Indefinite;
+    let mut solution_iterator = solver.get_solution_iterator(&mut brancher, &mut termination);
+
+    let mut solutions = vec![];
+    loop {
+        match solution_iterator.next_solution() {
+            IteratedSolution::Solution(solution) => solutions.push((
+                solution.get_integer_value(configuration.cores),
+                solution.get_integer_value(configuration.memory),
+                solution.get_integer_value(configuration.gpus),
+                solution.get_literal_value(configuration.workstation),
+            )),
+            IteratedSolution::Finished => return (solutions, "finished"),
+            IteratedSolution::Unsatisfiable => return (solutions, "unsatisfiable"),
+            IteratedSolution::Unknown(_) => return (solutions, "unknown"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sparse_domains_have_the_bounds_of_their_values() {
+        let mut solver = Solver::default();
+        let configuration = create_model(&mut solver);
+
+        assert_eq!(solver.lower_bound(&configuration.cores), 2);
+        assert_eq!(solver.upper_bound(&configuration.cores), 8);
+        assert_eq!(solver.lower_bound(&configuration.memory), 8);
+        assert_eq!(solver.upper_bound(&configuration.memory), 32);
+    }
+
+    #[test]
+    fn sparse_domains_skip_their_holes_when_tightened() {
+        let mut solver = Solver::default();
+        let configuration = create_model(&mut solver);
+
+        let mut brancher = brancher(&configuration);
+        let assumptions = [
+            solver.get_literal(predicate![configuration.cores >= 3]),
+            solver.get_literal(predicate![configuration.memory <= 31]),
+        ];
+        match solver.satisfy_under_assumptions(&mut brancher, &mut Indefinite, &assumptions) {
+            SatisfactionResultUnderAssumptions::Satisfiable(solution) => {
+                assert_eq!(solution.get_integer_value(configuration.cores), 4);
+                assert_eq!(solution.get_integer_value(configuration.memory), 8);
+            }
+            _ => panic!("the assumptions should be satisfiable"),
+        };
+    }
+
+    #[test]
+    fn solution_iterator_enumerates_every_configuration() {
+        let mut solver = Solver::default();
+        let configuration = create_model(&mut solver);
+
+        let (solutions, outcome) = enumerate(&mut solver, &configuration);
+
+        assert_eq!(outcome, "finished");
+        assert_eq!(solutions.len(), 10);
+        for (index, solution) in solutions.iter().enumerate() {
+            assert!(!solutions[index + 1..].contains(solution));
+        }
+        for &(cores, memory, gpus, workstation) in &solutions {
+            assert!([2, 4, 8].contains(&cores));
+            assert!([8, 16, 32].contains(&memory));
+            assert_eq!(workstation, 4 * cores + memory >= 64);
+            assert!(gpus == 0 || workstation);
+            assert!(50 * cores + 10 * memory + 400 * gpus <= 1200);
+        }
+        assert!(solutions.contains(&(8, 32, 1, true)));
+    }
+
+    #[test]
+    fn rolling_back_removes_the_blocking_clauses_of_the_solution_iterator() {
+        let mut solver = Solver::default();
+        let configuration = create_model(&mut solver);
+
+        let snapshot = solver.snapshot();
+        let _ = enumerate(&mut solver, &configuration);
+        solver.rollback_to(snapshot);
+
+        let mut brancher = brancher(&configuration);
+        assert!(matches!(
+            solver.satisfy(&mut brancher, &mut Indefinite),
+            SatisfactionResult::Satisfiable(_)
+        ));
+    }
+
+    #[test]
+    fn satisfiable_assumptions_are_respected_by_the_solution() {
+        let mut solver = Solver::default();
+        let configuration = create_model(&mut solver);
+
+        let mut brancher = brancher(&configuration);
+        let assumptions = [solver.get_literal(predicate![configuration.gpus >= 1])];
+        match solver.satisfy_under_assumptions(&mut brancher, &mut Indefinite, &assumptions) {
+            SatisfactionResultUnderAssumptions::Satisfiable(solution) => {
+                assert_eq!(solution.get_integer_value(configuration.cores), 8);
+                assert_eq!(solution.get_integer_value(configuration.memory), 32);
+                assert!(solution.get_literal_value(configuration.workstation));
+            }
+            _ => panic!("the assumptions should be satisfiable"),
+        };
+    }
+
+    #[test]
+    fn core_contains_only_the_conflicting_assumptions() {
+        let mut solver = Solver::default();
+        let configuration = create_model(&mut solver);
+
+        let requirements: [Predicate; 3] = [
+            predicate![configuration.gpus >= 1],
+            predicate![configuration.memory <= 16],
+            predicate![configuration.cores >= 4],
+        ];
+        let assumptions = requirements.map(|requirement| solver.get_literal(requirement));
+
+        let mut brancher = brancher(&configuration);
+        let core =
+            match solver.satisfy_under_assumptions(&mut brancher, &mut Indefinite, &assumptions) {
+                SatisfactionResultUnderAssumptions::UnsatisfiableUnderAssumptions(mut result) => {
+                    result.extract_core()
+                }
+                _ => panic!("the assumptions should be unsatisfiable"),
+            };
+
+        assert!(core.contains(&!assumptions[0]));
+        assert!(core.contains(&!assumptions[1]));
+        assert!(!core.contains(&!assumptions[2]));
+    }
+}